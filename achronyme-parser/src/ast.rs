@@ -85,6 +85,13 @@ pub enum Stmt {
         names: Vec<InputDecl>,
         span: Span,
     },
+    /// Circuit output: `output name = expr` — a public wire constrained to
+    /// equal `expr`, laid out after the declared inputs.
+    OutputDecl {
+        name: String,
+        value: Expr,
+        span: Span,
+    },
     FnDecl {
         name: String,
         params: Vec<TypedParam>,
@@ -158,6 +165,9 @@ pub struct InputDecl {
     pub name: String,
     pub array_size: Option<usize>,
     pub type_ann: Option<TypeAnnotation>,
+    /// `witness x = 5`: compile-time constant used when the prover
+    /// supplies no value. Adds no constraints.
+    pub default: Option<Expr>,
 }
 
 /// A block of statements (e.g., `{ ... }`).
@@ -276,6 +286,9 @@ pub enum Expr {
     While {
         id: ExprId,
         condition: Box<Expr>,
+        /// Iteration bound from `while cond max N { ... }`. Circuits unroll
+        /// exactly `N` guarded iterations; VM execution ignores it.
+        max: Option<u64>,
         body: Block,
         span: Span,
     },
@@ -14,14 +14,7 @@ impl<'a> Lexer<'a> {
             self.advance(); // consume 'i'
             return self.lex_bigint_lit(start);
         }
-        while let Some(ch) = self.peek() {
-            if ch.is_ascii_digit() {
-                self.advance();
-            } else {
-                break;
-            }
-        }
-        let lexeme = self.ascii_str(&self.source[start.0..self.pos])?.to_string();
+        let lexeme = self.lex_digits(|ch| ch.is_ascii_digit())?;
         Ok(Token {
             kind: TokenKind::Integer,
             span: self.make_span(start),
@@ -39,56 +32,29 @@ impl<'a> Lexer<'a> {
         let lexeme = match next {
             b'x' => {
                 self.advance(); // consume 'x'
-                let digit_start = self.pos;
-                while let Some(ch) = self.peek() {
-                    if ch.is_ascii_hexdigit() {
-                        self.advance();
-                    } else {
-                        break;
-                    }
-                }
-                if self.pos == digit_start {
+                let digits = self.lex_digits(|ch| ch.is_ascii_hexdigit())?;
+                if digits.is_empty() {
                     return Err(ParseError::new(
                         "expected hex digits after 0px",
                         start.1,
                         start.2,
                     ));
                 }
-                let digits = self.ascii_str(&self.source[digit_start..self.pos])?;
                 format!("x{digits}")
             }
             b'b' => {
                 self.advance(); // consume 'b'
-                let digit_start = self.pos;
-                while let Some(ch) = self.peek() {
-                    if ch == b'0' || ch == b'1' {
-                        self.advance();
-                    } else {
-                        break;
-                    }
-                }
-                if self.pos == digit_start {
+                let digits = self.lex_digits(|ch| ch == b'0' || ch == b'1')?;
+                if digits.is_empty() {
                     return Err(ParseError::new(
                         "expected binary digits after 0pb",
                         start.1,
                         start.2,
                     ));
                 }
-                let digits = self.ascii_str(&self.source[digit_start..self.pos])?;
                 format!("b{digits}")
             }
-            ch if ch.is_ascii_digit() => {
-                let digit_start = self.pos;
-                while let Some(ch) = self.peek() {
-                    if ch.is_ascii_digit() {
-                        self.advance();
-                    } else {
-                        break;
-                    }
-                }
-                self.ascii_str(&self.source[digit_start..self.pos])?
-                    .to_string()
-            }
+            ch if ch.is_ascii_digit() || ch == b'_' => self.lex_digits(|ch| ch.is_ascii_digit())?,
             _ => {
                 return Err(ParseError::new(
                     "expected digits after 0p",
@@ -138,62 +104,38 @@ impl<'a> Lexer<'a> {
         let lexeme = match radix_ch {
             b'x' => {
                 self.advance();
-                let digit_start = self.pos;
-                while let Some(ch) = self.peek() {
-                    if ch.is_ascii_hexdigit() {
-                        self.advance();
-                    } else {
-                        break;
-                    }
-                }
-                if self.pos == digit_start {
+                let digits = self.lex_digits(|ch| ch.is_ascii_hexdigit())?;
+                if digits.is_empty() {
                     return Err(ParseError::new(
                         "expected hex digits after 0i<width>x",
                         start.1,
                         start.2,
                     ));
                 }
-                let digits = self.ascii_str(&self.source[digit_start..self.pos])?;
                 format!("{width_str}x{digits}")
             }
             b'd' => {
                 self.advance();
-                let digit_start = self.pos;
-                while let Some(ch) = self.peek() {
-                    if ch.is_ascii_digit() {
-                        self.advance();
-                    } else {
-                        break;
-                    }
-                }
-                if self.pos == digit_start {
+                let digits = self.lex_digits(|ch| ch.is_ascii_digit())?;
+                if digits.is_empty() {
                     return Err(ParseError::new(
                         "expected decimal digits after 0i<width>d",
                         start.1,
                         start.2,
                     ));
                 }
-                let digits = self.ascii_str(&self.source[digit_start..self.pos])?;
                 format!("{width_str}d{digits}")
             }
             b'b' => {
                 self.advance();
-                let digit_start = self.pos;
-                while let Some(ch) = self.peek() {
-                    if ch == b'0' || ch == b'1' {
-                        self.advance();
-                    } else {
-                        break;
-                    }
-                }
-                if self.pos == digit_start {
+                let digits = self.lex_digits(|ch| ch == b'0' || ch == b'1')?;
+                if digits.is_empty() {
                     return Err(ParseError::new(
                         "expected binary digits after 0i<width>b",
                         start.1,
                         start.2,
                     ));
                 }
-                let digits = self.ascii_str(&self.source[digit_start..self.pos])?;
                 format!("{width_str}b{digits}")
             }
             _ => {
@@ -211,6 +153,51 @@ impl<'a> Lexer<'a> {
         })
     }
 
+    /// Consume a run of digits accepted by `is_digit`, allowing single `_`
+    /// separators between digits (`1_000_000`, `0pxFF_FF`). Returns the
+    /// digits with the separators stripped, so literal parsing downstream
+    /// never sees them; the result is empty if no digit follows. A leading,
+    /// trailing or doubled `_` is an error at the offending underscore.
+    fn lex_digits(&mut self, is_digit: impl Fn(u8) -> bool) -> Result<String, ParseError> {
+        let mut digits = String::new();
+        while let Some(ch) = self.peek() {
+            if is_digit(ch) {
+                digits.push(ch as char);
+                self.advance();
+            } else if ch == b'_' {
+                let (line, col) = (self.line, self.col);
+                if digits.is_empty() {
+                    return Err(ParseError::new(
+                        "numeric literal cannot start with `_`",
+                        line,
+                        col,
+                    ));
+                }
+                self.advance();
+                match self.peek() {
+                    Some(b'_') => {
+                        return Err(ParseError::new(
+                            "numeric literal cannot contain consecutive `_`",
+                            self.line,
+                            self.col,
+                        ));
+                    }
+                    Some(next) if is_digit(next) => {}
+                    _ => {
+                        return Err(ParseError::new(
+                            "numeric literal cannot end with `_`",
+                            line,
+                            col,
+                        ));
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+        Ok(digits)
+    }
+
     pub(super) fn lex_ident(&mut self, start: (usize, usize, usize)) -> Result<Token, ParseError> {
         while let Some(ch) = self.peek() {
             if ch.is_ascii_alphanumeric() || ch == b'_' {
@@ -222,6 +209,7 @@ impl<'a> Lexer<'a> {
         let lexeme = self.ascii_str(&self.source[start.0..self.pos])?.to_string();
         let kind = match lexeme.as_str() {
             "let" => TokenKind::Let,
+            "const" => TokenKind::Const,
             "mut" => TokenKind::Mut,
             "if" => TokenKind::If,
             "else" => TokenKind::Else,
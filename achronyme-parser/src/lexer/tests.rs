@@ -206,3 +206,58 @@ fn token_span_multiline() {
     assert_eq!(tokens[1].span.line_start, 2);
     assert_eq!(tokens[1].span.line_end, 2);
 }
+
+#[test]
+fn digit_separators_are_stripped() {
+    let lexemes = |src: &str| -> Vec<(TokenKind, String)> {
+        Lexer::tokenize(src)
+            .unwrap()
+            .into_iter()
+            .filter(|t| t.kind != TokenKind::Eof)
+            .map(|t| (t.kind, t.lexeme))
+            .collect()
+    };
+    assert_eq!(
+        lexemes("1_000_000"),
+        vec![(TokenKind::Integer, "1000000".to_string())]
+    );
+    assert_eq!(
+        lexemes("0pxFF_FF 0p1_000 0pb1010_0101"),
+        vec![
+            (TokenKind::FieldLit, "xFFFF".to_string()),
+            (TokenKind::FieldLit, "1000".to_string()),
+            (TokenKind::FieldLit, "b10100101".to_string()),
+        ]
+    );
+    assert_eq!(
+        lexemes("0i256xdead_beef 0i512d1_000 0i256b1_0"),
+        vec![
+            (TokenKind::BigIntLit, "256xdeadbeef".to_string()),
+            (TokenKind::BigIntLit, "512d1000".to_string()),
+            (TokenKind::BigIntLit, "256b10".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn misplaced_digit_separators_are_rejected() {
+    let err = |src: &str| Lexer::tokenize(src).unwrap_err();
+
+    let e = err("1_");
+    assert!(e.message.contains("cannot end with `_`"), "{}", e.message);
+    assert_eq!((e.line, e.col), (1, 2));
+
+    let e = err("1__2");
+    assert!(e.message.contains("consecutive `_`"), "{}", e.message);
+    assert_eq!((e.line, e.col), (1, 3));
+
+    let e = err("0px_FF");
+    assert!(e.message.contains("cannot start with `_`"), "{}", e.message);
+    assert_eq!((e.line, e.col), (1, 4));
+
+    let e = err("x = 0i256d12_");
+    assert!(e.message.contains("cannot end with `_`"), "{}", e.message);
+    assert_eq!((e.line, e.col), (1, 13));
+
+    assert!(Lexer::tokenize("0p_1").is_err());
+}
@@ -186,6 +186,7 @@ impl<'a> Lexer<'a> {
                 }
             }
             b';' => (TokenKind::Semicolon, ";"),
+            b'?' => (TokenKind::Question, "?"),
             _ => {
                 return Err(ParseError::new(
                     format!("unexpected character `{}`", ch as char),
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use crate::ast::*;
 use crate::diagnostic::{Diagnostic, SpanRange};
 use crate::error::ParseError;
@@ -25,6 +27,12 @@ pub(super) struct Parser {
     /// [`alloc_expr_id`], so the first allocated id is `ExprId(1)` —
     /// never the reserved [`ExprId::SYNTHETIC`] (which is `0`).
     pub(super) next_expr_id: u32,
+    /// Top-level `const` declarations seen so far, already evaluated.
+    /// Later references to these names parse as integer literals.
+    pub(super) consts: HashMap<String, u64>,
+    /// Block depth of the enclosing `circuit` body, if any. `output`
+    /// declarations are only accepted at exactly this depth.
+    pub(super) circuit_body_depth: Option<usize>,
 }
 
 impl Parser {
@@ -48,6 +56,8 @@ impl Parser {
             expr_depth: 0,
             errors: Vec::new(),
             next_expr_id: 0,
+            consts: HashMap::new(),
+            circuit_body_depth: None,
         }
     }
 
@@ -160,9 +170,17 @@ impl Parser {
     }
 
     /// Skip tokens until a synchronization point is found.
-    /// Sync tokens: `;`, `}`, `fn`, `let`, `mut`, `public`, `witness`, `import`, `export`, `EOF`.
+    /// Sync tokens: `;`, `}`, `fn`, `let`, `mut`, `public`, `witness`, `import`, `export`, `EOF`,
+    /// and the first token on a later line than the one where recovery started.
     pub(super) fn synchronize(&mut self) {
+        let start = self.pos;
+        let line = self.peek().span.line_start;
         loop {
+            // A new line usually starts a new statement; stop there so one
+            // bad expression statement doesn't swallow the rest of the file.
+            if self.pos > start && self.peek().span.line_start > line {
+                break;
+            }
             match self.peek_kind() {
                 TokenKind::Eof => break,
                 TokenKind::Semicolon => {
@@ -175,6 +193,7 @@ impl Parser {
                 }
                 TokenKind::Fn
                 | TokenKind::Let
+                | TokenKind::Const
                 | TokenKind::Mut
                 | TokenKind::Public
                 | TokenKind::Witness
@@ -202,6 +221,7 @@ impl Parser {
     pub(super) fn do_parse_program(&mut self) -> Result<Program, ParseError> {
         let mut stmts = Vec::new();
         while !self.at(&TokenKind::Eof) {
+            let start = self.pos;
             match self.parse_stmt() {
                 Ok(stmt) => {
                     stmts.push(stmt);
@@ -210,6 +230,11 @@ impl Parser {
                     let error_span = self.span();
                     let abort = self.record_error(&err);
                     self.synchronize();
+                    // A stray top-level `}` stops `synchronize` without being
+                    // consumed; skip it so the loop makes progress.
+                    if self.pos == start {
+                        self.advance();
+                    }
                     stmts.push(Stmt::Error { span: error_span });
                     if abort {
                         break;
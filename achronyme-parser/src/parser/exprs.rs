@@ -94,12 +94,41 @@ impl Parser {
                 continue;
             }
 
+            // Ternary `cond ? a : b`: binds loosest of all and is
+            // right-associative, so `a ? b : c ? d : e` nests in the else arm.
+            if self.at(&TokenKind::Question) && min_bp == 0 {
+                lhs = self.parse_ternary(lhs)?;
+                continue;
+            }
+
             break;
         }
 
         Ok(lhs)
     }
 
+    /// Parse the `? a : b` tail of a ternary and desugar it to
+    /// `if cond { a } else { b }` so later stages only see `Expr::If`.
+    fn parse_ternary(&mut self, condition: Expr) -> Result<Expr, ParseError> {
+        let sp = condition.span().clone();
+        self.advance(); // eat `?`
+        let then_expr = self.parse_expr_bp(0)?;
+        self.expect(&TokenKind::Colon)?;
+        let else_expr = self.parse_expr_bp(0)?;
+        let arm = |expr: Expr| Block {
+            span: expr.span().clone(),
+            stmts: vec![Stmt::Expr(expr)],
+        };
+        let id = self.alloc_expr_id();
+        Ok(Expr::If {
+            id,
+            condition: Box::new(condition),
+            then_block: arm(then_expr),
+            else_branch: Some(ElseBranch::Block(arm(else_expr))),
+            span: self.span_to_prev(&sp),
+        })
+    }
+
     fn parse_prefix(&mut self) -> Result<Expr, ParseError> {
         match self.peek_kind() {
             TokenKind::Minus | TokenKind::Not => {
@@ -236,6 +265,13 @@ impl Parser {
                     });
                 }
                 let id = self.alloc_expr_id();
+                if let Some(value) = self.consts.get(&tok.lexeme) {
+                    return Ok(Expr::Number {
+                        id,
+                        value: value.to_string(),
+                        span: self.span_to_prev(&sp),
+                    });
+                }
                 Ok(Expr::Ident {
                     id,
                     name: tok.lexeme,
@@ -309,7 +345,7 @@ impl Parser {
         // Syntax: `(hash: Public, root: Public Field)`
         let mut params = Vec::new();
         while !self.at(&TokenKind::RParen) {
-            let param_name = self.expect_ident()?;
+            let param_name = self.expect_binder_ident()?;
             self.expect(&TokenKind::Colon)?;
             let type_ann = self.parse_type()?;
 
@@ -3,6 +3,7 @@ use crate::error::ParseError;
 use crate::token::TokenKind;
 
 use super::Parser;
+use crate::parser::stmts::consts::const_eval;
 use crate::parser::tables::tok_display;
 
 impl Parser {
@@ -136,11 +137,40 @@ impl Parser {
         let sp = self.span();
         self.advance(); // eat `while`
         let condition = Box::new(self.parse_expr()?);
+        // Contextual `max N` bound: an integer literal or a `const` name.
+        let max = if self.at(&TokenKind::Ident) && self.peek().lexeme == "max" {
+            self.advance(); // eat `max`
+            let tok = self.advance().clone();
+            let lexeme = match self.consts.get(&tok.lexeme) {
+                Some(v) => v.to_string(),
+                None if tok.kind == TokenKind::Integer => tok.lexeme.clone(),
+                None => {
+                    return Err(ParseError::new(
+                        format!(
+                            "expected an integer bound after `max`, found `{}`",
+                            tok.lexeme
+                        ),
+                        tok.span.line_start,
+                        tok.span.col_start,
+                    ))
+                }
+            };
+            Some(lexeme.parse::<u64>().map_err(|e| {
+                ParseError::new(
+                    format!("invalid loop bound: {e}"),
+                    tok.span.line_start,
+                    tok.span.col_start,
+                )
+            })?)
+        } else {
+            None
+        };
         let body = self.parse_block_inner()?;
         let id = self.alloc_expr_id();
         Ok(Expr::While {
             id,
             condition,
+            max,
             body,
             span: self.span_to_prev(&sp),
         })
@@ -149,14 +179,21 @@ impl Parser {
     pub(super) fn parse_for(&mut self) -> Result<Expr, ParseError> {
         let sp = self.span();
         self.advance(); // eat `for`
-        let var = self.expect_ident()?;
+        let var = self.expect_binder_ident()?;
         self.expect(&TokenKind::In)?;
 
-        // Try range: `integer..integer` or `integer..expr`
-        let iterable = if self.at(&TokenKind::Integer) && self.lookahead(1) == &TokenKind::DotDot {
+        // Try range: `integer..integer` or `integer..expr`. A `const`
+        // name is accepted wherever the integer is.
+        let start_is_int = self.at(&TokenKind::Integer)
+            || (self.at(&TokenKind::Ident) && self.consts.contains_key(&self.peek().lexeme));
+        let iterable = if start_is_int && self.lookahead(1) == &TokenKind::DotDot {
             let start_tok = self.advance().clone();
             self.advance(); // eat `..`
-            let start: u64 = start_tok.lexeme.parse().map_err(|e| {
+            let start_lexeme = match self.consts.get(&start_tok.lexeme) {
+                Some(v) => v.to_string(),
+                None => start_tok.lexeme.clone(),
+            };
+            let start: u64 = start_lexeme.parse().map_err(|e| {
                 ParseError::new(
                     format!("invalid range start: {e}"),
                     start_tok.span.line_start,
@@ -175,11 +212,15 @@ impl Parser {
                 })?;
                 ForIterable::Range { start, end }
             } else {
-                // Expression end bound: `0..n`, `0..n+1`, `0..(n*2)`
+                // Expression end bound: `0..n`, `0..n+1`, `0..(n*2)`.
+                // Bounds built only from `const` names fold to a literal range.
                 let end_expr = self.parse_expr()?;
-                ForIterable::ExprRange {
-                    start,
-                    end: Box::new(end_expr),
+                match const_eval(&end_expr) {
+                    Some(end) => ForIterable::Range { start, end },
+                    None => ForIterable::ExprRange {
+                        start,
+                        end: Box::new(end_expr),
+                    },
                 }
             }
         } else {
@@ -213,7 +254,7 @@ impl Parser {
         let sp = self.span();
         self.advance(); // eat `fn`
         let name = if self.at(&TokenKind::Ident) {
-            Some(self.expect_ident()?)
+            Some(self.expect_binder_ident()?)
         } else {
             None
         };
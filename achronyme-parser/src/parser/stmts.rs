@@ -1,3 +1,4 @@
+pub(super) mod consts;
 mod declarations;
 mod import_export;
 mod misc;
@@ -19,6 +20,7 @@ impl Parser {
             TokenKind::Import => self.parse_import(),
             TokenKind::Export => self.parse_export(),
             TokenKind::Let => self.parse_let_decl(),
+            TokenKind::Const => self.parse_const_decl(),
             TokenKind::Mut => self.parse_mut_decl(),
             TokenKind::Public => self.parse_public_decl(),
             TokenKind::Witness => self.parse_witness_decl(),
@@ -53,6 +55,13 @@ impl Parser {
                 })
             }
             TokenKind::Circuit => self.parse_circuit_decl(),
+            TokenKind::Ident
+                if self.peek().lexeme == "output"
+                    && matches!(self.lookahead(1), TokenKind::Ident)
+                    && matches!(self.lookahead(2), TokenKind::Assign) =>
+            {
+                self.parse_output_decl()
+            }
             TokenKind::Prove
                 // prove name(...) { ... } -> desugar to let name = prove name(...) { ... }
                 // prove(...) { ... } or prove { ... } -> expression statement
@@ -0,0 +1,115 @@
+use crate::ast::*;
+use crate::error::ParseError;
+use crate::parser::core::Parser;
+use crate::token::TokenKind;
+
+impl Parser {
+    /// `const NAME = <expr>` — a named compile-time integer.
+    ///
+    /// The right-hand side must fold to a non-negative integer using only
+    /// literals, earlier `const` names, `+`, `-` and `*`. The value is
+    /// recorded in [`Parser::consts`] so later references parse as literals
+    /// (array sizes, range bounds, `range_check` bits, exponents), and the
+    /// declaration itself is emitted as a `let` of that literal.
+    pub(super) fn parse_const_decl(&mut self) -> Result<Stmt, ParseError> {
+        let sp = self.span();
+        if self.block_depth > 0 {
+            return Err(ParseError::new(
+                "const declarations are only allowed at the top level",
+                sp.line_start,
+                sp.col_start,
+            ));
+        }
+        self.advance(); // eat `const`
+        let name = self.expect_binder_ident()?;
+        self.expect(&TokenKind::Assign)?;
+        let value = self.parse_expr()?;
+        let Some(folded) = const_eval(&value) else {
+            let vs = value.span();
+            return Err(ParseError::new(
+                format!(
+                    "const `{name}` must be a compile-time integer \
+                     (literals, other consts, `+`, `-`, `*`)"
+                ),
+                vs.line_start,
+                vs.col_start,
+            ));
+        };
+        self.consts.insert(name.clone(), folded);
+        let id = self.alloc_expr_id();
+        Ok(Stmt::LetDecl {
+            name,
+            type_ann: None,
+            value: Expr::Number {
+                id,
+                value: folded.to_string(),
+                span: value.span().clone(),
+            },
+            span: self.span_to_prev(&sp),
+        })
+    }
+
+    /// Expect the identifier introduced by a binder (`const`, `let`, `mut`,
+    /// `for`, `output`, `fn` names, parameters, inputs) and reject it if it names a `const`.
+    pub(in crate::parser) fn expect_binder_ident(&mut self) -> Result<String, ParseError> {
+        let span = self.span();
+        let name = self.expect_ident()?;
+        self.check_not_const(&name, &span)?;
+        Ok(name)
+    }
+
+    /// Reject rebinding a `const` name: its uses are already literals,
+    /// so a shadowing binding would silently never be read.
+    pub(in crate::parser) fn check_not_const(
+        &self,
+        name: &str,
+        span: &Span,
+    ) -> Result<(), ParseError> {
+        if self.consts.contains_key(name) {
+            return Err(ParseError::new(
+                format!("`{name}` is already declared as a const"),
+                span.line_start,
+                span.col_start,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parse the `N` inside an array suffix `[N]`: an integer literal or a
+    /// constant expression over `const` names.
+    pub(in crate::parser) fn parse_array_size(&mut self) -> Result<usize, ParseError> {
+        let tok = self.peek().clone();
+        let expr = self.parse_expr()?;
+        const_eval(&expr)
+            .and_then(|n| usize::try_from(n).ok())
+            .ok_or_else(|| {
+                ParseError::new(
+                    format!(
+                        "invalid array size: expected an integer literal or const, found `{}`",
+                        tok.lexeme
+                    ),
+                    tok.span.line_start,
+                    tok.span.col_start,
+                )
+            })
+    }
+}
+
+/// Fold an integer expression built from literals, `+`, `-` and `*`.
+/// Returns `None` for anything else, on overflow, or when a subtraction
+/// would go negative. `const` names are literals by the time this runs.
+pub(in crate::parser) fn const_eval(expr: &Expr) -> Option<u64> {
+    match expr {
+        Expr::Number { value, .. } => value.parse().ok(),
+        Expr::BinOp { op, lhs, rhs, .. } => {
+            let (l, r) = (const_eval(lhs)?, const_eval(rhs)?);
+            match op {
+                BinOp::Add => l.checked_add(r),
+                BinOp::Sub => l.checked_sub(r),
+                BinOp::Mul => l.checked_mul(r),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -8,7 +8,7 @@ impl Parser {
     pub(super) fn parse_let_decl(&mut self) -> Result<Stmt, ParseError> {
         let sp = self.span();
         self.advance(); // eat `let`
-        let name = self.expect_ident()?;
+        let name = self.expect_binder_ident()?;
         let type_ann = self.try_parse_type_annotation()?;
         self.expect(&TokenKind::Assign)?;
         let value = self.parse_expr()?;
@@ -23,7 +23,7 @@ impl Parser {
     pub(super) fn parse_mut_decl(&mut self) -> Result<Stmt, ParseError> {
         let sp = self.span();
         self.advance(); // eat `mut`
-        let name = self.expect_ident()?;
+        let name = self.expect_binder_ident()?;
         let type_ann = self.try_parse_type_annotation()?;
         self.expect(&TokenKind::Assign)?;
         let value = self.parse_expr()?;
@@ -45,6 +45,29 @@ impl Parser {
         })
     }
 
+    /// `output name = expr`. `output` is contextual (only a keyword when
+    /// followed by `name =`), so existing identifiers named `output` keep
+    /// working.
+    pub(super) fn parse_output_decl(&mut self) -> Result<Stmt, ParseError> {
+        let sp = self.span();
+        if self.circuit_body_depth != Some(self.block_depth) {
+            return Err(ParseError::new(
+                "output declarations are only allowed at the top level of a circuit body",
+                sp.line_start,
+                sp.col_start,
+            ));
+        }
+        self.advance(); // eat `output`
+        let name = self.expect_binder_ident()?;
+        self.expect(&TokenKind::Assign)?;
+        let value = self.parse_expr()?;
+        Ok(Stmt::OutputDecl {
+            name,
+            value,
+            span: self.span_to_prev(&sp),
+        })
+    }
+
     pub(super) fn parse_witness_decl(&mut self) -> Result<Stmt, ParseError> {
         let sp = self.span();
         self.advance(); // eat `witness`
@@ -65,34 +88,32 @@ impl Parser {
     }
 
     fn parse_input_decl(&mut self) -> Result<InputDecl, ParseError> {
-        let name = self.expect_ident()?;
+        let name = self.expect_binder_ident()?;
         let array_size = if self.eat(&TokenKind::LBracket) {
-            let tok = self.expect(&TokenKind::Integer)?;
-            let size: usize = tok.lexeme.parse().map_err(|_| {
-                ParseError::new(
-                    format!("invalid array size: {}", tok.lexeme),
-                    tok.span.line_start,
-                    tok.span.col_start,
-                )
-            })?;
-            // Need to clone span info before expecting
+            let size = self.parse_array_size()?;
             self.expect(&TokenKind::RBracket)?;
             Some(size)
         } else {
             None
         };
         let type_ann = self.try_parse_type_annotation()?;
+        let default = if self.eat(&TokenKind::Assign) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
         Ok(InputDecl {
             name,
             array_size,
             type_ann,
+            default,
         })
     }
 
     pub(super) fn parse_fn_decl(&mut self) -> Result<Stmt, ParseError> {
         let sp = self.span();
         self.advance(); // eat `fn`
-        let name = self.expect_ident()?;
+        let name = self.expect_binder_ident()?;
         self.expect(&TokenKind::LParen)?;
         let params = self.parse_param_list()?;
         self.expect(&TokenKind::RParen)?;
@@ -112,7 +133,7 @@ impl Parser {
     pub(super) fn parse_prove_decl(&mut self) -> Result<Stmt, ParseError> {
         let sp = self.span();
         self.advance(); // eat `prove`
-        let name = self.expect_ident()?;
+        let name = self.expect_binder_ident()?;
         let params = self.parse_prove_params()?;
         let body = self.parse_block_inner()?;
         let span = self.span_to_prev(&sp);
@@ -152,7 +173,7 @@ impl Parser {
                 ));
             }
 
-            let param_name = self.expect_ident()?;
+            let param_name = self.expect_binder_ident()?;
             self.expect(&TokenKind::Colon)?;
             let type_ann = self.parse_type()?;
 
@@ -177,7 +198,10 @@ impl Parser {
         }
         self.expect(&TokenKind::RParen)?;
 
-        let body = self.parse_block_inner()?;
+        let outer = self.circuit_body_depth.replace(self.block_depth + 1);
+        let body = self.parse_block_inner();
+        self.circuit_body_depth = outer;
+        let body = body?;
 
         Ok(Stmt::CircuitDecl {
             name,
@@ -190,11 +214,11 @@ impl Parser {
     pub(in crate::parser) fn parse_param_list(&mut self) -> Result<Vec<TypedParam>, ParseError> {
         let mut params = Vec::new();
         if !self.at(&TokenKind::RParen) {
-            let name = self.expect_ident()?;
+            let name = self.expect_binder_ident()?;
             let type_ann = self.try_parse_type_annotation()?;
             params.push(TypedParam { name, type_ann });
             while self.eat(&TokenKind::Comma) {
-                let name = self.expect_ident()?;
+                let name = self.expect_binder_ident()?;
                 let type_ann = self.try_parse_type_annotation()?;
                 params.push(TypedParam { name, type_ann });
             }
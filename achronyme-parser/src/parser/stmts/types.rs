@@ -128,14 +128,7 @@ impl Parser {
 
         // Check for array syntax: `[N]`
         let array_size = if self.eat(&TokenKind::LBracket) {
-            let size_tok = self.expect(&TokenKind::Integer)?;
-            let size: usize = size_tok.lexeme.parse().map_err(|_| {
-                ParseError::new(
-                    format!("invalid array size: {}", size_tok.lexeme),
-                    size_tok.span.line_start,
-                    size_tok.span.col_start,
-                )
-            })?;
+            let size = self.parse_array_size()?;
             self.expect(&TokenKind::RBracket)?;
             Some(size)
         } else {
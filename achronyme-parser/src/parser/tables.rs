@@ -59,6 +59,7 @@ pub(super) fn kind_name(kind: &TokenKind) -> &'static str {
         TokenKind::BigIntLit => "bigint literal",
         TokenKind::StringLit => "string",
         TokenKind::Let => "let",
+        TokenKind::Const => "const",
         TokenKind::Mut => "mut",
         TokenKind::If => "if",
         TokenKind::Else => "else",
@@ -111,6 +112,7 @@ pub(super) fn kind_name(kind: &TokenKind) -> &'static str {
         TokenKind::Colon => ":",
         TokenKind::ColonColon => "::",
         TokenKind::Semicolon => ";",
+        TokenKind::Question => "?",
         TokenKind::Eof => "end of file",
     }
 }
@@ -13,6 +13,8 @@ pub(crate) fn has_errors(source: &str) -> bool {
     !errors.is_empty()
 }
 
+#[path = "tests/consts.rs"]
+mod consts;
 #[path = "tests/depth_limits.rs"]
 mod depth_limits;
 #[path = "tests/expr_ids.rs"]
@@ -21,6 +23,8 @@ mod expr_ids;
 mod expressions_control;
 #[path = "tests/import_export.rs"]
 mod import_export;
+#[path = "tests/outputs.rs"]
+mod outputs;
 #[path = "tests/recovery.rs"]
 mod recovery;
 #[path = "tests/statements_literals.rs"]
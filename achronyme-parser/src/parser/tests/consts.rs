@@ -0,0 +1,130 @@
+use super::*;
+
+#[test]
+fn parse_const_decl_folds_expression() {
+    let prog = parse_ok("const A = 2\nconst B = A * 3 + 1");
+    match &prog.stmts[1] {
+        Stmt::LetDecl {
+            name,
+            value: Expr::Number { value, .. },
+            ..
+        } => {
+            assert_eq!(name, "B");
+            assert_eq!(value, "7");
+        }
+        other => panic!("expected folded LetDecl, got {other:?}"),
+    }
+}
+
+#[test]
+fn const_as_input_array_size() {
+    let prog = parse_ok("const N = 4\nwitness arr[N]");
+    match &prog.stmts[1] {
+        Stmt::WitnessDecl { names, .. } => assert_eq!(names[0].array_size, Some(4)),
+        other => panic!("expected WitnessDecl, got {other:?}"),
+    }
+}
+
+#[test]
+fn const_as_type_array_size() {
+    let prog = parse_ok("const N = 3\ncircuit c(xs: Witness Field[N + 1]) { }");
+    match &prog.stmts[1] {
+        Stmt::CircuitDecl { params, .. } => {
+            let ta = params[0].type_ann.as_ref().unwrap();
+            assert_eq!(ta.array_size, Some(4));
+        }
+        other => panic!("expected CircuitDecl, got {other:?}"),
+    }
+}
+
+#[test]
+fn const_range_bounds_fold_to_literal_range() {
+    for (src, expected) in [
+        ("const N = 4\nfor i in 0..N { i }", (0, 4)),
+        ("const N = 4\nfor i in 1..N - 1 { i }", (1, 3)),
+        ("const S = 2\nconst N = 4\nfor i in S..N { i }", (2, 4)),
+    ] {
+        let prog = parse_ok(src);
+        match prog.stmts.last() {
+            Some(Stmt::Expr(Expr::For {
+                iterable: ForIterable::Range { start, end },
+                ..
+            })) => assert_eq!((*start, *end), expected, "{src}"),
+            other => panic!("expected literal Range for {src}, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn const_reference_parses_as_number() {
+    let prog = parse_ok("const BITS = 8\nrange_check(x, BITS)");
+    match &prog.stmts[1] {
+        Stmt::Expr(Expr::Call { args, .. }) => match &args[1].value {
+            Expr::Number { value, .. } => assert_eq!(value, "8"),
+            other => panic!("expected Number, got {other:?}"),
+        },
+        other => panic!("expected Call, got {other:?}"),
+    }
+}
+
+#[test]
+fn const_rejects_non_constant_rhs() {
+    assert!(has_errors("let x = 1\nconst N = x"));
+    assert!(has_errors("const N = 8 / 2"));
+    assert!(has_errors("const N = 2 - 3"));
+}
+
+#[test]
+fn const_only_at_top_level() {
+    assert!(has_errors("fn f() { const N = 1 }"));
+}
+
+#[test]
+fn const_cannot_be_rebound() {
+    assert!(has_errors("const N = 1\nconst N = 2"));
+    assert!(has_errors("const N = 1\nlet N = 2"));
+    assert!(has_errors("const N = 1\nfor N in 0..3 { }"));
+}
+
+/// `src` shadows `const N`: it must be rejected, and parse cleanly once
+/// the const is renamed out of the way.
+fn rejects_const_shadow(src: &str) {
+    assert!(has_errors(src), "{src}");
+    let renamed = src.replacen("const N", "const M", 1);
+    assert!(!has_errors(&renamed), "{renamed}");
+}
+
+#[test]
+fn const_cannot_be_shadowed_by_fn() {
+    rejects_const_shadow("const N = 1\nfn N() { 0 }");
+    rejects_const_shadow("const N = 1\nfn f(N) { N }");
+    rejects_const_shadow("const N = 1\nfn f(x, N: Field) { N }");
+}
+
+#[test]
+fn const_cannot_be_shadowed_by_closure_param() {
+    rejects_const_shadow("const N = 1\nlet f = fn(N) { N }");
+    rejects_const_shadow("const N = 1\nlet f = fn N() { 0 }");
+}
+
+#[test]
+fn const_cannot_be_shadowed_by_circuit_param() {
+    rejects_const_shadow("const N = 1\ncircuit c(N: Witness) { N }");
+}
+
+#[test]
+fn const_cannot_be_shadowed_by_prove_param() {
+    rejects_const_shadow("const N = 1\nprove (N: Public) { N }");
+    rejects_const_shadow("const N = 1\nprove N() { }");
+}
+
+#[test]
+fn const_cannot_be_shadowed_by_input_decl() {
+    rejects_const_shadow("const N = 1\npublic N");
+    rejects_const_shadow("const N = 1\nwitness a, N[2]");
+}
+
+#[test]
+fn array_size_must_be_constant() {
+    assert!(has_errors("witness arr[n]"));
+}
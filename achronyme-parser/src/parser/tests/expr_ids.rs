@@ -9,7 +9,8 @@ fn walk_exprs(prog: &Program, mut visit: impl FnMut(&Expr)) {
             Stmt::LetDecl { value, .. }
             | Stmt::MutDecl { value, .. }
             | Stmt::Expr(value)
-            | Stmt::Print { value, .. } => walk_expr(value, visit),
+            | Stmt::Print { value, .. }
+            | Stmt::OutputDecl { value, .. } => walk_expr(value, visit),
             Stmt::Assignment { target, value, .. } => {
                 walk_expr(target, visit);
                 walk_expr(value, visit);
@@ -21,9 +22,12 @@ fn walk_exprs(prog: &Program, mut visit: impl FnMut(&Expr)) {
             }
             Stmt::FnDecl { body, .. } | Stmt::CircuitDecl { body, .. } => walk_block(body, visit),
             Stmt::Export { inner, .. } => walk_stmt(inner, visit),
-            Stmt::PublicDecl { .. }
-            | Stmt::WitnessDecl { .. }
-            | Stmt::Break { .. }
+            Stmt::PublicDecl { names, .. } | Stmt::WitnessDecl { names, .. } => {
+                for default in names.iter().filter_map(|d| d.default.as_ref()) {
+                    walk_expr(default, visit);
+                }
+            }
+            Stmt::Break { .. }
             | Stmt::Continue { .. }
             | Stmt::Import { .. }
             | Stmt::SelectiveImport { .. }
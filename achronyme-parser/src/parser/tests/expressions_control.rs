@@ -183,6 +183,58 @@ fn reject_chained_comparisons() {
     assert!(!has_errors("a < b && b < c"));
 }
 
+/// Unwrap the single expression of a desugared ternary arm.
+fn arm_expr(block: &Block) -> &Expr {
+    match block.stmts.as_slice() {
+        [Stmt::Expr(e)] => e,
+        other => panic!("expected a single expression arm, got {other:?}"),
+    }
+}
+
+#[test]
+fn parse_ternary_desugars_to_if() {
+    let prog = parse_ok("a ? b : c");
+    match &prog.stmts[0] {
+        Stmt::Expr(Expr::If {
+            condition,
+            then_block,
+            else_branch: Some(ElseBranch::Block(else_block)),
+            ..
+        }) => {
+            assert!(matches!(condition.as_ref(), Expr::Ident { name, .. } if name == "a"));
+            assert!(matches!(arm_expr(then_block), Expr::Ident { name, .. } if name == "b"));
+            assert!(matches!(arm_expr(else_block), Expr::Ident { name, .. } if name == "c"));
+        }
+        other => panic!("expected If, got {other:?}"),
+    }
+}
+
+#[test]
+fn parse_ternary_is_right_associative_and_loosest() {
+    let prog = parse_ok("a || b ? x + 1 : c ? y : z");
+    match &prog.stmts[0] {
+        Stmt::Expr(Expr::If {
+            condition,
+            then_block,
+            else_branch: Some(ElseBranch::Block(else_block)),
+            ..
+        }) => {
+            assert!(matches!(
+                condition.as_ref(),
+                Expr::BinOp { op: BinOp::Or, .. }
+            ));
+            assert!(matches!(
+                arm_expr(then_block),
+                Expr::BinOp { op: BinOp::Add, .. }
+            ));
+            assert!(matches!(arm_expr(else_block), Expr::If { .. }));
+        }
+        other => panic!("expected If, got {other:?}"),
+    }
+    assert!(!has_errors("f(a ? b : c, d)"));
+    assert!(has_errors("a ? b"));
+}
+
 #[test]
 fn parse_unary_ops() {
     let prog = parse_ok("-x");
@@ -348,6 +400,28 @@ fn parse_while_loop() {
     }
 }
 
+#[test]
+fn parse_while_loop_with_max_bound() {
+    let prog = parse_ok("while x != 0 max 8 { x = x - 1 }");
+    match &prog.stmts[0] {
+        Stmt::Expr(Expr::While { max, .. }) => assert_eq!(*max, Some(8)),
+        other => panic!("expected While, got {other:?}"),
+    }
+    let prog = parse_ok("const N = 4\nwhile x max N { 1 }");
+    match &prog.stmts[1] {
+        Stmt::Expr(Expr::While { max, .. }) => assert_eq!(*max, Some(4)),
+        other => panic!("expected While, got {other:?}"),
+    }
+    let prog = parse_ok("while x { 1 }");
+    assert!(matches!(
+        &prog.stmts[0],
+        Stmt::Expr(Expr::While { max: None, .. })
+    ));
+
+    let (_, errors) = parse_program("while x max n { 1 }");
+    assert!(errors[0].message.contains("integer bound after `max`"));
+}
+
 #[test]
 fn parse_forever_loop() {
     let prog = parse_ok("forever { 1 }");
@@ -0,0 +1,36 @@
+use super::*;
+
+fn circuit_body(prog: &Program) -> &Block {
+    match &prog.stmts[0] {
+        Stmt::CircuitDecl { body, .. } => body,
+        other => panic!("expected CircuitDecl, got {other:?}"),
+    }
+}
+
+#[test]
+fn parse_output_decl_in_circuit() {
+    let prog = parse_ok("circuit sq(x: Witness) { output y = x * x }");
+    match &circuit_body(&prog).stmts[0] {
+        Stmt::OutputDecl { name, value, .. } => {
+            assert_eq!(name, "y");
+            assert!(matches!(value, Expr::BinOp { .. }));
+        }
+        other => panic!("expected OutputDecl, got {other:?}"),
+    }
+}
+
+#[test]
+fn output_outside_circuit_body_is_rejected() {
+    assert!(has_errors("output y = 1"));
+    assert!(has_errors("fn f(x) { output y = x }"));
+    assert!(has_errors(
+        "circuit c(x: Witness) { if true { output y = x } }"
+    ));
+}
+
+#[test]
+fn output_remains_a_plain_identifier() {
+    let prog = parse_ok("let output = 3\noutput = output + 1");
+    assert!(matches!(&prog.stmts[0], Stmt::LetDecl { name, .. } if name == "output"));
+    assert!(matches!(&prog.stmts[1], Stmt::Assignment { .. }));
+}
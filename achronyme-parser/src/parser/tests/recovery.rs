@@ -84,3 +84,32 @@ fn recovery_interleaved_good_and_bad() {
         .collect();
     assert_eq!(good.len(), 3);
 }
+
+#[test]
+fn recovery_at_line_boundaries() {
+    // Expression statements have no keyword to sync on; each line still
+    // gets its own error and the valid line between them parses.
+    let source = "assert_eq(x +)\nfoo(1)\nassert_eq(y, *)";
+    let (prog, errors) = parse_program(source);
+    assert_eq!(errors.len(), 2);
+    assert_eq!(prog.stmts.len(), 3);
+    assert!(matches!(&prog.stmts[0], Stmt::Error { .. }));
+    assert!(matches!(&prog.stmts[1], Stmt::Expr(_)));
+    assert!(matches!(&prog.stmts[2], Stmt::Error { .. }));
+}
+
+#[test]
+fn recovery_skips_stray_top_level_rbrace() {
+    let source = "let x = 1\n}\nlet y = 2";
+    let (prog, errors) = parse_program(source);
+    assert_eq!(errors.len(), 1);
+    let names: Vec<_> = prog
+        .stmts
+        .iter()
+        .filter_map(|s| match s {
+            Stmt::LetDecl { name, .. } => Some(name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(names, ["x", "y"]);
+}
@@ -102,3 +102,40 @@ fn parse_nested_call() {
 // ========================================================================
 // Type annotation tests
 // ========================================================================
+
+#[test]
+fn parse_literals_with_digit_separators() {
+    let prog = parse_ok("1_000_000\n0pxFF_FF\n0i256d1_000");
+    match &prog.stmts[0] {
+        Stmt::Expr(Expr::Number { value, .. }) => assert_eq!(value, "1000000"),
+        other => panic!("expected Number, got {other:?}"),
+    }
+    match &prog.stmts[1] {
+        Stmt::Expr(Expr::FieldLit { value, radix, .. }) => {
+            assert_eq!(value, "FFFF");
+            assert_eq!(*radix, FieldRadix::Hex);
+        }
+        other => panic!("expected FieldLit, got {other:?}"),
+    }
+    match &prog.stmts[2] {
+        Stmt::Expr(Expr::BigIntLit { value, .. }) => assert_eq!(value, "1000"),
+        other => panic!("expected BigIntLit, got {other:?}"),
+    }
+}
+
+#[test]
+fn parse_rejects_misplaced_digit_separators() {
+    for src in [
+        "let x = 1_",
+        "let x = 1__2",
+        "let x = 0px_FF",
+        "let x = 0pb1_",
+    ] {
+        let (_, errors) = parse_program(src);
+        assert_eq!(errors.len(), 1, "{src}: {errors:?}");
+        assert!(errors[0].message.contains('_'), "{src}: {errors:?}");
+    }
+    // The diagnostic points at the offending underscore.
+    let (_, errors) = parse_program("let x = 1__2");
+    assert_eq!(errors[0].primary_span.col_start, 11);
+}
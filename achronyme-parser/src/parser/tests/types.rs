@@ -84,6 +84,20 @@ fn parse_witness_array_with_type() {
     }
 }
 
+#[test]
+fn parse_input_decl_with_default() {
+    let prog = parse_ok("witness k = 5, flag: Bool = 1, x");
+    match &prog.stmts[0] {
+        Stmt::WitnessDecl { names, .. } => {
+            assert!(matches!(names[0].default, Some(Expr::Number { .. })));
+            assert_eq!(names[1].type_ann, Some(TypeAnnotation::bool()));
+            assert!(names[1].default.is_some());
+            assert!(names[2].default.is_none());
+        }
+        other => panic!("expected WitnessDecl, got {other:?}"),
+    }
+}
+
 #[test]
 fn parse_public_without_type() {
     let prog = parse_ok("public x");
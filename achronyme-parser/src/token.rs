@@ -20,6 +20,7 @@ pub enum TokenKind {
 
     // Keywords
     Let,
+    Const,
     Mut,
     If,
     Else,
@@ -78,6 +79,7 @@ pub enum TokenKind {
     Colon,
     ColonColon,
     Semicolon,
+    Question,
 
     // End of file
     Eof,
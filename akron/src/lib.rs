@@ -12,7 +12,7 @@ pub use machine::prove::ProveError;
 pub use machine::value_ops::ValueOps;
 pub use machine::{
     CallFrame, CircomCallError, CircomCallResult, CircomOutputValue, CircomWitnessHandler,
-    ProveHandler, ProveResult, VerifyHandler, MAX_FRAMES, VM,
+    GcSummary, ProveHandler, ProveResult, VerifyHandler, MAX_FRAMES, VM,
 };
 pub use opcode::OpCode;
 pub mod module;
@@ -4,7 +4,7 @@ use crate::specs::{
 use crate::{CallFrame, VM};
 use byteorder::{LittleEndian, ReadBytesExt};
 use memory::field::PrimeId;
-use memory::{Closure, Function, Value};
+use memory::{BinaryHeader, BinaryHeaderError, Closure, Function, Value};
 use std::io::Read;
 
 #[derive(Debug)]
@@ -32,6 +32,15 @@ impl From<std::io::Error> for LoaderError {
     }
 }
 
+impl From<BinaryHeaderError> for LoaderError {
+    fn from(e: BinaryHeaderError) -> Self {
+        match e {
+            BinaryHeaderError::Io(e) => LoaderError::Io(e),
+            other => LoaderError::Format(other.to_string()),
+        }
+    }
+}
+
 impl From<memory::ArenaError> for LoaderError {
     fn from(e: memory::ArenaError) -> Self {
         LoaderError::Security(format!("heap allocation failed: {e}"))
@@ -76,27 +85,11 @@ impl VM {
     /// # Security
     /// This method includes checks against "Allocation Bomb" attacks.
     pub fn load_executable<R: Read>(&mut self, reader: &mut R) -> Result<(), LoaderError> {
-        let mut magic = [0u8; 4];
-        reader.read_exact(&mut magic)?;
-        let version = magic[3];
-        if &magic[..3] != b"ACH" || !matches!(version, 0x09..=0x0B) {
-            return Err(LoaderError::Format(
-                "Invalid binary magic or version".to_string(),
-            ));
-        }
-
-        // v0x0B+: PrimeId byte after magic, before max_slots
-        let prime_id = if version >= 0x0B {
-            let b = reader.read_u8()?;
-            PrimeId::from_byte(b).ok_or_else(|| {
-                LoaderError::Format(format!("unknown PrimeId byte 0x{b:02x} in bytecode header"))
-            })?
-        } else {
-            PrimeId::Bn254
-        };
+        let header = BinaryHeader::read(reader)?;
+        let version = header.version;
+        let prime_id = header.prime_id;
         self.prime_id = prime_id;
-
-        let max_slots = reader.read_u16::<LittleEndian>()?;
+        let max_slots = header.max_slots;
 
         // --- String Table ---
         let str_count = reader.read_u32::<LittleEndian>()?;
@@ -15,6 +15,12 @@ fn map_bigint_err(e: BigIntError) -> RuntimeError {
     }
 }
 
+/// Reduce a wrapped `i64` result to the Int range by keeping its low
+/// 60 bits, sign-extended (two's complement modulo 2^60).
+fn wrap_i60(x: i64) -> i64 {
+    (x << 4) >> 4
+}
+
 /// Trait for arithmetic instruction handlers
 pub trait ArithmeticOps {
     fn handle_arithmetic(
@@ -48,6 +54,9 @@ impl ArithmeticOps for super::vm::VM {
                         Some(result) if (I60_MIN..=I60_MAX).contains(&result) => {
                             self.set_reg(base, a, Value::int(result))?;
                         }
+                        _ if self.wrapping_int => {
+                            self.set_reg(base, a, Value::int(wrap_i60(ib.wrapping_add(ic))))?;
+                        }
                         _ => {
                             return Err(RuntimeError::IntegerOverflow);
                         }
@@ -95,6 +104,9 @@ impl ArithmeticOps for super::vm::VM {
                         Some(result) if (I60_MIN..=I60_MAX).contains(&result) => {
                             self.set_reg(base, a, Value::int(result))?;
                         }
+                        _ if self.wrapping_int => {
+                            self.set_reg(base, a, Value::int(wrap_i60(ib.wrapping_sub(ic))))?;
+                        }
                         _ => {
                             return Err(RuntimeError::IntegerOverflow);
                         }
@@ -137,6 +149,9 @@ impl ArithmeticOps for super::vm::VM {
                         Some(result) if (I60_MIN..=I60_MAX).contains(&result) => {
                             self.set_reg(base, a, Value::int(result))?;
                         }
+                        _ if self.wrapping_int => {
+                            self.set_reg(base, a, Value::int(wrap_i60(ib.wrapping_mul(ic))))?;
+                        }
                         _ => {
                             return Err(RuntimeError::IntegerOverflow);
                         }
@@ -371,6 +386,9 @@ impl ArithmeticOps for super::vm::VM {
                         Some(result) if (I60_MIN..=I60_MAX).contains(&result) => {
                             self.set_reg(base, a, Value::int(result))?;
                         }
+                        _ if self.wrapping_int => {
+                            self.set_reg(base, a, Value::int(wrap_i60(ib.wrapping_neg())))?;
+                        }
                         _ => {
                             return Err(RuntimeError::IntegerOverflow);
                         }
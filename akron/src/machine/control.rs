@@ -84,7 +84,7 @@ impl ControlFlowOps for super::vm::VM {
                         .checked_add(a)
                         .filter(|&d| d < crate::machine::vm::STACK_MAX)
                         .ok_or(RuntimeError::StackOverflow)?;
-                    if self.frames.len() >= crate::machine::vm::MAX_FRAMES {
+                    if self.frames.len() >= self.max_call_depth {
                         return Err(RuntimeError::StackOverflow);
                     }
                     self.frames.push(crate::machine::frame::CallFrame {
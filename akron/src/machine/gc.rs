@@ -1,5 +1,27 @@
 use memory::Value;
 
+/// Collector counters returned by [`VM::gc_stats`](super::vm::VM::gc_stats).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GcSummary {
+    /// Completed collections.
+    pub collections: u64,
+    /// Objects currently allocated on the heap.
+    pub live_objects: usize,
+    /// Objects freed across all collections.
+    pub freed: u64,
+}
+
+impl super::vm::VM {
+    /// Snapshot of the collector's counters.
+    pub fn gc_stats(&self) -> GcSummary {
+        GcSummary {
+            collections: self.heap.stats.collections,
+            live_objects: self.heap.live_objects(),
+            freed: self.heap.stats.total_freed_objects,
+        }
+    }
+}
+
 /// Trait for garbage collection operations
 pub trait GarbageCollector {
     fn collect_garbage(&mut self);
@@ -37,6 +59,12 @@ impl GarbageCollector for super::vm::VM {
         self.heap.sweep();
         // Threshold is set by sweep() with hysteresis — no override needed.
 
+        // Drop interner entries whose string was just swept: the handle may
+        // be reused for a different string. Strings reachable from live
+        // values were marked above and keep their entries.
+        let heap = &self.heap;
+        self.interner.retain(|_, h| !heap.is_string_free(*h));
+
         let elapsed = start.elapsed().as_nanos() as u64;
         self.heap.stats.collections += 1;
         self.heap.stats.total_gc_time_ns += elapsed;
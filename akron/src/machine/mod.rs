@@ -27,5 +27,6 @@ mod vm;
 // Public API
 pub use circom::{CircomCallError, CircomCallResult, CircomOutputValue, CircomWitnessHandler};
 pub use frame::CallFrame;
+pub use gc::GcSummary;
 pub use prove::{ProveError, ProveHandler, ProveResult, VerifyHandler};
 pub use vm::{MAX_FRAMES, VM};
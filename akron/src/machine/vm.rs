@@ -37,6 +37,17 @@ pub struct VM {
     /// it reaches zero. `u64::MAX` means unlimited (no budget).
     pub instruction_budget: u64,
 
+    /// Maximum number of active call frames. A call that would push a
+    /// frame beyond this depth fails with `RuntimeError::StackOverflow`
+    /// instead of recursing further. Defaults to [`MAX_FRAMES`]; the
+    /// register stack is bounded independently by [`STACK_MAX`].
+    pub max_call_depth: usize,
+
+    /// Int overflow behavior for `Add`, `Sub`, `Mul` and `Neg`. `false`
+    /// (the default) fails with `RuntimeError::IntegerOverflow` when a
+    /// result leaves the i60 range; `true` wraps it modulo 2^60 instead.
+    pub wrapping_int: bool,
+
     // Passive Debug Symbols (Sidecar)
     pub debug_symbols: Option<HashMap<u16, String>>,
 
@@ -97,6 +108,8 @@ impl VM {
             open_upvalues: None,
             stress_mode: false,
             instruction_budget: u64::MAX,
+            max_call_depth: MAX_FRAMES,
+            wrapping_int: false,
             debug_symbols: None,
             prove_handler: None,
             verify_handler: None,
@@ -220,7 +233,7 @@ impl VM {
         // the frame is popped.
         let dest_reg = new_base;
 
-        if self.frames.len() >= MAX_FRAMES {
+        if self.frames.len() >= self.max_call_depth {
             return Err(RuntimeError::StackOverflow);
         }
 
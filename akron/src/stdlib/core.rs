@@ -3,7 +3,7 @@ use crate::machine::value_ops::ValueOps;
 use crate::machine::VM;
 use ach_macros::{ach_module, ach_native};
 use constraints::poseidon::poseidon_hash;
-use constraints::PoseidonParamsProvider;
+use constraints::{CircuitHasher, PoseidonHasher, PoseidonParamsProvider};
 use memory::{FieldElement, Value};
 
 /// Extract a FieldElement from a VM Value (Int or Field).
@@ -204,13 +204,11 @@ pub mod core_impl {
             ));
         }
         let params = memory::Bn254Fr::default_poseidon_t3();
-        let first = extract_fe(vm, &args[0])?;
-        let second = extract_fe(vm, &args[1])?;
-        let mut acc = poseidon_hash(&params, first, second);
-        for arg in &args[2..] {
-            let fe = extract_fe(vm, arg)?;
-            acc = poseidon_hash(&params, acc, fe);
-        }
+        let inputs = args
+            .iter()
+            .map(|arg| extract_fe(vm, arg))
+            .collect::<Result<Vec<_>, _>>()?;
+        let acc = PoseidonHasher::new(&params).native_hash_n(&inputs);
         let handle = vm.heap.alloc_field(acc)?;
         Ok(Value::field(handle))
     }
@@ -267,6 +265,12 @@ pub mod core_impl {
             "bytes_allocated".into(),
             Value::int(vm.heap.bytes_allocated as i64),
         );
+        let summary = vm.gc_stats();
+        map.insert(
+            "live_objects".into(),
+            Value::int(summary.live_objects as i64),
+        );
+        map.insert("objects_freed".into(), Value::int(summary.freed as i64));
         let handle = vm.heap.alloc_map(map)?;
         Ok(Value::map(handle))
     }
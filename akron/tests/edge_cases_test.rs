@@ -14,6 +14,11 @@ use memory::{Closure, Function, Value};
 
 /// Compile and run source, returning the VM or the RuntimeError.
 fn run(source: &str) -> Result<VM, RuntimeError> {
+    run_with(source, |_| {})
+}
+
+/// Like [`run`], but lets `setup` configure the VM before interpreting.
+fn run_with(source: &str, setup: impl FnOnce(&mut VM)) -> Result<VM, RuntimeError> {
     let mut compiler = Compiler::new();
     let bytecode = compiler
         .compile(source)
@@ -53,6 +58,7 @@ fn run(source: &str) -> Result<VM, RuntimeError> {
         dest_reg: 0,
     });
 
+    setup(&mut vm);
     vm.interpret()?;
     Ok(vm)
 }
@@ -236,6 +242,38 @@ fn frame_depth_limit_before_stack_max() {
     );
 }
 
+// ======================================================================
+// 1c. Configurable call depth — recursive script hits max_call_depth
+// ======================================================================
+
+const COUNTDOWN: &str = r#"
+fn depth(n) {
+    if n == 0 {
+        return 0
+    }
+    return depth(n - 1) + 1
+}
+let x = depth(100)
+"#;
+
+#[test]
+fn recursion_within_default_depth_succeeds() {
+    let vm = run(COUNTDOWN).expect("depth 100 fits the default limit");
+    assert_eq!(vm.stack[0].as_int(), Some(100));
+}
+
+#[test]
+fn recursion_beyond_max_call_depth_is_stack_overflow() {
+    let err = expect_err(
+        run_with(COUNTDOWN, |vm| vm.max_call_depth = 32),
+        "depth 100 should exceed a 32-frame limit",
+    );
+    assert!(
+        matches!(err, RuntimeError::StackOverflow),
+        "expected StackOverflow, got {err:?}"
+    );
+}
+
 // ======================================================================
 // 2. GC stress — many allocations under stress mode
 // ======================================================================
@@ -250,3 +250,24 @@ fn all_invalid_opcodes_rejected() {
         );
     }
 }
+
+// ======================================================================
+// 19. Malicious bytecode — JumpIfFalse to out-of-bounds IP
+// ======================================================================
+
+#[test]
+fn malicious_bytecode_jump_if_false_oob() {
+    // The target is validated before the condition is read, so even a
+    // truthy register cannot smuggle an out-of-bounds IP through.
+    for cond in [Value::false_val(), Value::true_val()] {
+        let chunk = vec![
+            encode_abx(OpCode::LoadConst.as_u8(), 0, 0),
+            encode_abx(OpCode::JumpIfFalse.as_u8(), 0, 9999),
+        ];
+        let err = expect_err(run_raw(chunk, vec![cond], 4), "OOB jump should error");
+        assert!(
+            matches!(err, RuntimeError::OutOfBounds(_)),
+            "expected OutOfBounds, got {err:?}"
+        );
+    }
+}
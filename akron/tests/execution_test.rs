@@ -1,6 +1,6 @@
 use akron::{CallFrame, VM};
 use akronc::Compiler;
-use memory::Function;
+use memory::{Function, Value};
 
 #[test]
 fn test_execution_end_to_end() {
@@ -96,3 +96,58 @@ fn test_mod_compilation() {
         panic!("Expected 1 result from 7 % 2, got {:?}", result);
     }
 }
+
+/// Compile + run a source string and return the value left in R0.
+fn eval(source: &str) -> Value {
+    let mut compiler = Compiler::new();
+    let bytecode = compiler.compile(source).expect("Compilation failed");
+    let main_func = compiler.compilers.last().expect("No main compiler");
+
+    let mut vm = VM::new();
+    let func = Function {
+        name: "main".to_string(),
+        arity: 0,
+        max_slots: main_func.max_slots,
+        chunk: bytecode,
+        constants: main_func.constants.clone(),
+        upvalue_info: Vec::new(),
+        line_info: Vec::new(),
+    };
+    let func_idx = vm.heap.alloc_function(func).expect("alloc");
+    let closure_idx = vm
+        .heap
+        .alloc_closure(memory::Closure {
+            function: func_idx,
+            upvalues: Vec::new(),
+        })
+        .expect("alloc");
+    vm.frames.push(CallFrame {
+        closure: closure_idx,
+        ip: 0,
+        base: 0,
+        dest_reg: 0,
+    });
+    vm.interpret().expect("Runtime error");
+    vm.stack[0]
+}
+
+#[test]
+fn test_block_expression_value() {
+    assert_eq!(eval("let x = { let a = 1; a + 2 }\nx").as_int(), Some(3));
+    // Block locals are released: the temporaries around the block and the
+    // outer `a` keep their registers.
+    assert_eq!(
+        eval("let a = 7\nlet x = 1 + { let a = 2; a * 3 } + a\nx * 10 + a").as_int(),
+        Some(147)
+    );
+    assert_eq!(
+        eval("let x = { let a = 1; { let b = a + 1; b * 10 } }\nx").as_int(),
+        Some(20)
+    );
+}
+
+#[test]
+fn test_block_without_trailing_expression_is_nil() {
+    assert!(eval("let x = { let a = 1 }\nx").is_nil());
+    assert!(eval("mut y = 0\nlet x = { y = 5 }\nx").is_nil());
+}
@@ -2,14 +2,14 @@ use akron::{CallFrame, VM};
 use akronc::Compiler;
 use memory::Function;
 
-/// Helper: compile and run Achronyme source with stress_mode enabled.
-fn run_stress(source: &str) -> Result<VM, String> {
+/// Helper: compile and run Achronyme source on a VM tuned by `configure`.
+fn run_configured(source: &str, configure: impl FnOnce(&mut VM)) -> Result<VM, String> {
     let mut compiler = Compiler::new();
     let bytecode = compiler.compile(source).map_err(|e| format!("{e:?}"))?;
     let main_func = compiler.compilers.last().expect("No main compiler");
 
     let mut vm = VM::new();
-    vm.stress_mode = true;
+    configure(&mut vm);
     vm.import_strings(compiler.interner.strings);
 
     for proto in &compiler.prototypes {
@@ -42,57 +42,25 @@ fn run_stress(source: &str) -> Result<VM, String> {
         dest_reg: 0,
     });
 
-    vm.interpret().map_err(|e| format!("{e:?}"))?;
+    vm.interpret().map_err(|e| format!("{e}"))?;
     Ok(vm)
 }
 
+/// Helper: compile and run Achronyme source with stress_mode enabled.
+fn run_stress(source: &str) -> Result<VM, String> {
+    run_configured(source, |vm| vm.stress_mode = true)
+}
+
 fn result_int(vm: &VM) -> i64 {
     vm.stack[0].as_int().expect("expected int in R[0]")
 }
 
 /// Helper: compile and run with a heap limit, returning error string on failure.
 fn run_with_heap_limit(source: &str, max_heap_bytes: usize) -> Result<VM, String> {
-    let mut compiler = Compiler::new();
-    let bytecode = compiler.compile(source).map_err(|e| format!("{e:?}"))?;
-    let main_func = compiler.compilers.last().expect("No main compiler");
-
-    let mut vm = VM::new();
-    vm.heap.max_heap_bytes = max_heap_bytes;
-    vm.instruction_budget = 200_000;
-    vm.import_strings(compiler.interner.strings);
-
-    for proto in &compiler.prototypes {
-        let handle = vm.heap.alloc_function(proto.clone()).expect("alloc");
-        vm.prototypes.push(handle);
-    }
-
-    let func = Function {
-        name: "main".to_string(),
-        arity: 0,
-        chunk: bytecode,
-        constants: main_func.constants.clone(),
-        max_slots: main_func.max_slots,
-        upvalue_info: vec![],
-        line_info: vec![],
-    };
-    let func_idx = vm.heap.alloc_function(func).expect("alloc");
-    let closure_idx = vm
-        .heap
-        .alloc_closure(memory::Closure {
-            function: func_idx,
-            upvalues: vec![],
-        })
-        .expect("alloc");
-
-    vm.frames.push(CallFrame {
-        closure: closure_idx,
-        ip: 0,
-        base: 0,
-        dest_reg: 0,
-    });
-
-    vm.interpret().map_err(|e| format!("{e}"))?;
-    Ok(vm)
+    run_configured(source, |vm| {
+        vm.heap.max_heap_bytes = max_heap_bytes;
+        vm.instruction_budget = 200_000;
+    })
 }
 
 fn result_string_list(vm: &VM) -> Vec<String> {
@@ -218,6 +186,68 @@ while i < 100 {
     );
 }
 
+#[test]
+fn test_object_count_triggers_collection() {
+    // 5000 two-element lists stay far below the 1MB byte threshold, so
+    // only the live-object trigger can start a collection.
+    let vm = run_configured(
+        r#"mut i = 0
+while i < 5000 {
+    let tmp = [i, i]
+    i = i + 1
+}"#,
+        |vm| vm.heap.next_gc_object_threshold = 1000,
+    )
+    .unwrap();
+    let stats = vm.gc_stats();
+    assert!(stats.collections > 0, "object threshold should trigger GC");
+    assert!(stats.freed > 0, "short-lived lists should be freed");
+    assert!(stats.live_objects < 5000, "{stats:?}");
+}
+
+#[test]
+fn test_reachable_objects_survive_collection() {
+    let vm = run_stress(
+        r#"mut keep = []
+mut i = 0
+while i < 50 {
+    keep.push([i, "item_" + i])
+    let garbage = [i, i, i]
+    i = i + 1
+}
+mut total = 0
+for pair in keep {
+    total = total + pair[0]
+}
+let x = total + keep[49][1].len()"#,
+    )
+    .unwrap();
+    // 0 + 1 + ... + 49 = 1225, plus len("item_49") = 7
+    assert_eq!(result_int(&vm), 1232);
+    assert!(vm.gc_stats().freed > 0);
+}
+
+#[test]
+fn test_interned_map_keys_survive_repeated_iteration() {
+    // Iterating a map interns its keys; once the key list is garbage the
+    // interner must not hand out a swept (or reused) string handle.
+    let vm = run_stress(
+        r#"let m = { "alpha": 1, "beta": 2 }
+mut total = 0
+mut round = 0
+while round < 10 {
+    for k in m {
+        let noise = "noise_" + round
+        total = total + m[k]
+    }
+    round = round + 1
+}
+let x = total"#,
+    )
+    .unwrap();
+    assert_eq!(result_int(&vm), 30);
+}
+
 #[test]
 fn test_gc_stats_peak_heap_positive() {
     let vm = run_stress(r#"let x = [1, 2, 3]"#).unwrap();
@@ -233,10 +263,10 @@ fn test_native_gc_stats_returns_map() {
         r#"let s = gc_stats()
 assert(typeof(s) == "Map")
 let k = s.keys()
-assert(k.len() == 5)"#,
+assert(k.len() == 7)"#,
     )
     .unwrap();
-    // If we get here without error, the native returned a valid Map with 5 keys
+    // If we get here without error, the native returned a valid Map with 7 keys
     let _ = vm;
 }
 
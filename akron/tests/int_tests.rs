@@ -32,7 +32,17 @@ fn run_simple(chunk: Vec<u32>, constants: Vec<Value>) -> VM {
 }
 
 fn run_fallible(chunk: Vec<u32>, constants: Vec<Value>) -> Result<VM, RuntimeError> {
+    run_on(VM::new(), chunk, constants)
+}
+
+/// Run with Int overflow wrapping enabled.
+fn run_wrapping(chunk: Vec<u32>, constants: Vec<Value>) -> VM {
     let mut vm = VM::new();
+    vm.wrapping_int = true;
+    run_on(vm, chunk, constants).expect("Runtime error")
+}
+
+fn run_on(mut vm: VM, chunk: Vec<u32>, constants: Vec<Value>) -> Result<VM, RuntimeError> {
     let func = Function {
         name: "test".to_string(),
         arity: 0,
@@ -107,6 +117,39 @@ fn test_int_multiplication_overflow_errors() {
     assert!(matches!(result, Err(RuntimeError::IntegerOverflow)));
 }
 
+/// `op(lhs, rhs)` with wrapping enabled, as an Int.
+fn wrapping_binop(op: OpCode, lhs: i64, rhs: i64) -> i64 {
+    let chunk = vec![
+        encode_abx(OpCode::LoadConst.as_u8(), 0, 0),
+        encode_abx(OpCode::LoadConst.as_u8(), 1, 1),
+        encode_abc(op.as_u8(), 2, 0, 1),
+        encode_abc(OpCode::Return.as_u8(), 2, 0, 0),
+    ];
+    let vm = run_wrapping(chunk, vec![Value::int(lhs), Value::int(rhs)]);
+    vm.stack[2].as_int().expect("Int result")
+}
+
+#[test]
+fn test_int_overflow_wraps_in_wrapping_mode() {
+    assert_eq!(wrapping_binop(OpCode::Add, I60_MAX, 1), I60_MIN);
+    assert_eq!(wrapping_binop(OpCode::Sub, I60_MIN, 1), I60_MAX);
+    assert_eq!(wrapping_binop(OpCode::Mul, I60_MAX, 2), -2);
+    // In-range results are unaffected.
+    assert_eq!(wrapping_binop(OpCode::Add, 40, 2), 42);
+    assert_eq!(wrapping_binop(OpCode::Mul, -6, 7), -42);
+}
+
+#[test]
+fn test_neg_i60_min_wraps_in_wrapping_mode() {
+    let chunk = vec![
+        encode_abx(OpCode::LoadConst.as_u8(), 0, 0),
+        encode_abc(OpCode::Neg.as_u8(), 1, 0, 0),
+        encode_abc(OpCode::Return.as_u8(), 1, 0, 0),
+    ];
+    let vm = run_wrapping(chunk, vec![Value::int(I60_MIN)]);
+    assert_eq!(vm.stack[1].as_int(), Some(I60_MIN));
+}
+
 #[test]
 fn test_neg_i60_min_overflow_errors() {
     let chunk = vec![
@@ -9,6 +9,11 @@ fn eval(source: &str) -> Value {
     let main_func = compiler.compilers.last().expect("No main compiler");
 
     let mut vm = VM::new();
+    vm.import_strings(compiler.interner.strings);
+    for proto in &compiler.prototypes {
+        let handle = vm.heap.alloc_function(proto.clone()).expect("alloc");
+        vm.prototypes.push(handle);
+    }
     let func = Function {
         name: "main".to_string(),
         arity: 0,
@@ -43,7 +48,6 @@ fn eval_bool(source: &str) -> bool {
         .unwrap_or_else(|| panic!("expected bool, got {:?}", val))
 }
 
-#[allow(dead_code)]
 fn eval_int(source: &str) -> i64 {
     let val = eval(source);
     val.as_int()
@@ -176,6 +180,41 @@ fn vm_or_true_true() {
     assert!(eval_bool("true || true"));
 }
 
+// ============================================================================
+// Literal short-circuit folding
+// ============================================================================
+
+/// Calling `crash()` aborts the run, so a passing test proves the
+/// operand was never evaluated.
+const CRASH: &str = "fn crash() { assert(false) }\n";
+
+#[test]
+fn vm_or_true_skips_rhs() {
+    assert!(eval_bool(&format!("{CRASH}true || crash()")));
+}
+
+#[test]
+fn vm_and_false_skips_rhs() {
+    assert!(!eval_bool(&format!("{CRASH}false && crash()")));
+}
+
+#[test]
+fn vm_and_false_with_variable_is_false() {
+    assert!(!eval_bool("let x = true\nfalse && x"));
+}
+
+#[test]
+fn vm_literal_lhs_yields_rhs() {
+    assert_eq!(eval_int("true && 7"), 7);
+    assert_eq!(eval_int("false || 7"), 7);
+}
+
+#[test]
+fn vm_not_literal_folds() {
+    assert!(eval_bool("!false"));
+    assert!(!eval_bool("!true"));
+}
+
 // ============================================================================
 // Combined expressions
 // ============================================================================
@@ -203,3 +242,26 @@ fn vm_chained_or() {
     assert!(eval_bool("false || false || true"));
     assert!(!eval_bool("false || false || false"));
 }
+
+// ============================================================================
+// Control flow (Jump / JumpIfFalse)
+// ============================================================================
+
+#[test]
+fn vm_if_false_takes_else_branch() {
+    assert_eq!(eval_int("if false { 1 } else { 2 }"), 2);
+    assert_eq!(eval_int("if true { 1 } else { 2 }"), 1);
+}
+
+#[test]
+fn vm_while_countdown() {
+    // Loop body runs 5 times, then the back-edge exits with n == 0.
+    let source = "mut n = 5
+mut steps = 0
+while n > 0 {
+    n = n - 1
+    steps = steps + 1
+}
+let x = steps * 10 + n";
+    assert_eq!(eval_int(source), 50);
+}
@@ -1,4 +1,5 @@
 use super::common::{run_source, run_source_with_prove};
+use akron::{ProveError, RuntimeError};
 use akronc::Compiler;
 use memory::FieldElement;
 
@@ -104,6 +105,20 @@ fn prove_failing_constraint() {
     }
 }
 
+#[test]
+fn prove_error_renders_failing_phase() {
+    let err = ProveError::ProofGeneration("Groth16 setup failed: empty circuit".into());
+    assert_eq!(
+        err.to_string(),
+        "proof generation: Groth16 setup failed: empty circuit"
+    );
+    let runtime = RuntimeError::prove_block_failed(err);
+    assert_eq!(
+        runtime.to_string(),
+        "prove block failed: proof generation: Groth16 setup failed: empty circuit"
+    );
+}
+
 #[test]
 fn prove_int_promotion() {
     // Integer values should be promoted to FieldElement
@@ -72,6 +72,28 @@ fn result_string_list(vm: &VM) -> Vec<String> {
         .collect()
 }
 
+// =============================================================================
+// + — concatenation
+// =============================================================================
+
+#[test]
+fn test_concat_literals() {
+    let vm = run_source(r#"let x = "foo" + "bar""#).unwrap();
+    assert_eq!(result_string(&vm), "foobar");
+}
+
+#[test]
+fn test_concat_multibyte_len() {
+    let vm = run_source(r#"let x = ("caf" + "é").len()"#).unwrap();
+    assert_eq!(result_int(&vm), 4);
+}
+
+#[test]
+fn test_list_len() {
+    let vm = run_source("let x = [1, 2, 3].len()").unwrap();
+    assert_eq!(result_int(&vm), 3);
+}
+
 // =============================================================================
 // len() — char count
 // =============================================================================
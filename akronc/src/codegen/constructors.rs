@@ -113,6 +113,7 @@ impl Compiler {
             fn_decl_asts: Vec::new(),
             resolver_outer_functions: None,
             prime_id: memory::field::PrimeId::Bn254,
+            max_constants: crate::function_compiler::MAX_CONSTANTS,
             resolved_program: None,
             resolver_symbol_table: None,
             resolver_root_module: None,
@@ -135,6 +135,12 @@ pub struct Compiler {
     /// Prime field for ProveIR serialization. Defaults to BN254.
     pub prime_id: memory::field::PrimeId,
 
+    /// Per-function constant pool limit. Defaults to
+    /// [`MAX_CONSTANTS`](crate::function_compiler::MAX_CONSTANTS), the
+    /// most a 16-bit `LoadConst` operand can address; larger values are
+    /// clamped to it.
+    pub max_constants: usize,
+
     // ── Resolver shadow-dispatch ───────────────────────────────────
     /// Annotation map produced by [`resolve::annotate_program`].
     /// Populated either automatically by [`Compiler::compile`] (for
@@ -14,24 +14,52 @@ use memory::Value;
 
 use super::Compiler;
 use crate::error::CompilerError;
-use crate::function_compiler::FunctionCompiler;
+use crate::function_compiler::{FunctionCompiler, MAX_CONSTANTS};
 
 impl Compiler {
     // Wrappers for FunctionCompiler
     pub fn alloc_reg(&mut self) -> Result<u8, CompilerError> {
-        self.current()?.alloc_reg()
+        let result = self.current()?.alloc_reg();
+        self.locate_overflow(result)
     }
 
     pub fn alloc_contiguous(&mut self, count: u8) -> Result<u8, CompilerError> {
-        self.current()?.alloc_contiguous(count)
+        let result = self.current()?.alloc_contiguous(count);
+        self.locate_overflow(result)
+    }
+
+    /// `FunctionCompiler` has no span information; attach the span of the
+    /// expression being compiled so register exhaustion points at source.
+    fn locate_overflow(&self, result: Result<u8, CompilerError>) -> Result<u8, CompilerError> {
+        result.map_err(|e| match e {
+            CompilerError::RegisterOverflow(None) => {
+                CompilerError::RegisterOverflow(self.cur_span())
+            }
+            other => other,
+        })
     }
 
     pub fn free_reg(&mut self, reg: u8) -> Result<(), CompilerError> {
         self.current()?.free_reg(reg)
     }
 
+    /// Add `val` to the current function's constant pool, reusing the slot
+    /// of an identical constant. Fails once the pool would exceed
+    /// `max_constants` (capped at [`MAX_CONSTANTS`]).
     pub fn add_constant(&mut self, val: Value) -> Result<usize, CompilerError> {
-        Ok(self.current()?.add_constant(val))
+        let limit = self.max_constants.min(MAX_CONSTANTS);
+        let span = self.cur_span();
+        let func = self.current()?;
+        let idx = func.add_constant(val);
+        if idx < limit {
+            return Ok(idx);
+        }
+        Err(CompilerError::TooManyConstants {
+            function: func.name.clone(),
+            count: idx + 1,
+            limit,
+            span,
+        })
     }
 
     pub fn add_upvalue(&mut self, is_local: bool, index: u8) -> Result<u8, CompilerError> {
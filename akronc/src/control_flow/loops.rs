@@ -64,9 +64,6 @@ pub(super) fn compile_for(
             for i in *start..*end {
                 let r = compiler.alloc_reg()?;
                 let ci = compiler.add_constant(Value::int(i as i64))?;
-                if ci > 0xFFFF {
-                    return Err(CompilerError::TooManyConstants(compiler.cur_span()));
-                }
                 compiler.emit_abx(OpCode::LoadConst, r, ci as u16)?;
             }
 
@@ -177,6 +177,7 @@ pub(super) fn compile_prove(
                     name: param.name.clone(),
                     array_size,
                     type_ann: ta.cloned(),
+                    default: None,
                 }],
                 span: body.span.clone(),
             });
@@ -270,9 +271,6 @@ pub(super) fn compile_prove(
             let key_handle = compiler.intern_string(cap_name);
             let key_val = Value::string(key_handle);
             let key_idx = compiler.add_constant(key_val)?;
-            if key_idx > 0xFFFF {
-                return Err(CompilerError::TooManyConstants(compiler.cur_span()));
-            }
             compiler.emit_abx(OpCode::LoadConst, key_reg, key_idx as u16)?;
 
             if let Some((idx, local_reg)) = compiler.resolve_local(cap_name) {
@@ -314,9 +312,6 @@ pub(super) fn compile_prove(
     let ir_handle = compiler.intern_bytes(ir_bytes);
     let ir_val = Value::bytes(ir_handle);
     let ir_idx = compiler.add_constant(ir_val)?;
-    if ir_idx > 0xFFFF {
-        return Err(CompilerError::TooManyConstants(compiler.cur_span()));
-    }
 
     // 6. Emit Prove R[map_reg], K[ir_idx]
     compiler.emit_abx(OpCode::Prove, map_reg, ir_idx as u16)?;
@@ -426,9 +421,6 @@ pub(super) fn compile_circuit_call(
             let key_handle = compiler.intern_string(key);
             let key_val = Value::string(key_handle);
             let key_idx = compiler.add_constant(key_val)?;
-            if key_idx > 0xFFFF {
-                return Err(CompilerError::TooManyConstants(span_box(span)));
-            }
             compiler.emit_abx(OpCode::LoadConst, key_reg, key_idx as u16)?;
 
             // Value: compile expression
@@ -18,7 +18,16 @@ pub enum CompilerError {
     ParseError(String),
     UnknownOperator(String, OptSpan),
     InvalidNumber(OptSpan),
-    TooManyConstants(OptSpan),
+    /// A function's constant pool outgrew its limit. `count` is the number
+    /// of distinct constants the function needed when it overflowed.
+    TooManyConstants {
+        function: String,
+        count: usize,
+        limit: usize,
+        span: OptSpan,
+    },
+    /// The global slot table (16-bit indices) is full.
+    TooManyGlobals(OptSpan),
     UnexpectedRule(String, OptSpan),
     RegisterOverflow(OptSpan),
     CompilerLimitation(String, OptSpan),
@@ -66,8 +75,18 @@ impl fmt::Display for CompilerError {
             CompilerError::InvalidNumber(span) => {
                 write!(f, "{}invalid number literal", fmt_span(span))
             }
-            CompilerError::TooManyConstants(span) => {
-                write!(f, "{}too many constants (limit 65536)", fmt_span(span))
+            CompilerError::TooManyConstants {
+                function,
+                count,
+                limit,
+                span,
+            } => write!(
+                f,
+                "{}too many constants in `{function}`: {count} distinct constants exceed the limit of {limit}",
+                fmt_span(span)
+            ),
+            CompilerError::TooManyGlobals(span) => {
+                write!(f, "{}too many globals (limit {})", fmt_span(span), u16::MAX)
             }
             CompilerError::UnexpectedRule(msg, span) => {
                 write!(f, "{}unexpected rule: {msg}", fmt_span(span))
@@ -150,7 +169,8 @@ impl CompilerError {
         let span = match self {
             CompilerError::UnknownOperator(_, s)
             | CompilerError::InvalidNumber(s)
-            | CompilerError::TooManyConstants(s)
+            | CompilerError::TooManyConstants { span: s, .. }
+            | CompilerError::TooManyGlobals(s)
             | CompilerError::UnexpectedRule(_, s)
             | CompilerError::RegisterOverflow(s)
             | CompilerError::CompilerLimitation(_, s)
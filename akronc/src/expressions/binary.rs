@@ -39,6 +39,17 @@ impl BinaryCompiler for Compiler {
     }
 
     fn compile_and(&mut self, lhs: &Expr, rhs: &Expr) -> Result<u8, CompilerError> {
+        // Literal left operand: `false && x` never evaluates `x`, and
+        // `true && x` is just `x`.
+        match lhs {
+            Expr::Bool { value: false, .. } => {
+                self.compile_discarded(rhs)?;
+                return self.compile_expr(lhs);
+            }
+            Expr::Bool { value: true, .. } => return self.compile_expr(rhs),
+            _ => {}
+        }
+
         let left_reg = self.compile_expr(lhs)?;
 
         // Short-circuit: if left is false, skip right
@@ -54,6 +65,17 @@ impl BinaryCompiler for Compiler {
     }
 
     fn compile_or(&mut self, lhs: &Expr, rhs: &Expr) -> Result<u8, CompilerError> {
+        // Literal left operand: `true || x` never evaluates `x`, and
+        // `false || x` is just `x`.
+        match lhs {
+            Expr::Bool { value: true, .. } => {
+                self.compile_discarded(rhs)?;
+                return self.compile_expr(lhs);
+            }
+            Expr::Bool { value: false, .. } => return self.compile_expr(rhs),
+            _ => {}
+        }
+
         let left_reg = self.compile_expr(lhs)?;
 
         // Short-circuit: if left is true, skip right
@@ -70,3 +92,31 @@ impl BinaryCompiler for Compiler {
         Ok(left_reg)
     }
 }
+
+impl Compiler {
+    /// Compile `expr` only for its diagnostics (undefined names, arity
+    /// errors, …) and drop the emitted code. Used for the operand a
+    /// literal short-circuit never evaluates.
+    fn compile_discarded(&mut self, expr: &Expr) -> Result<(), CompilerError> {
+        let func = self.current_ref()?;
+        let mark = func.bytecode.len();
+        let breaks: Vec<usize> = func
+            .loop_stack
+            .iter()
+            .map(|ctx| ctx.break_jumps.len())
+            .collect();
+
+        let reg = self.compile_expr(expr)?;
+        self.free_reg(reg)?;
+
+        // A `break` in the dropped code registered a jump the enclosing
+        // loop would otherwise patch.
+        let func = self.current()?;
+        func.bytecode.truncate(mark);
+        func.line_info.truncate(mark);
+        for (ctx, len) in func.loop_stack.iter_mut().zip(breaks) {
+            ctx.break_jumps.truncate(len);
+        }
+        Ok(())
+    }
+}
@@ -19,9 +19,6 @@ impl Compiler {
         }
         let reg = self.alloc_reg()?;
         let const_idx = self.add_constant(Value::int(val))?;
-        if const_idx > 0xFFFF {
-            return Err(CompilerError::TooManyConstants(self.cur_span()));
-        }
         self.emit_abx(OpCode::LoadConst, reg, const_idx as u16)?;
         Ok(reg)
     }
@@ -42,9 +39,6 @@ impl Compiler {
         let val = Value::field(handle);
         let const_idx = self.add_constant(val)?;
         let reg = self.alloc_reg()?;
-        if const_idx > 0xFFFF {
-            return Err(CompilerError::TooManyConstants(self.cur_span()));
-        }
         self.emit_abx(OpCode::LoadConst, reg, const_idx as u16)?;
         Ok(reg)
     }
@@ -71,9 +65,6 @@ impl Compiler {
         let val = Value::bigint(handle);
         let const_idx = self.add_constant(val)?;
         let reg = self.alloc_reg()?;
-        if const_idx > 0xFFFF {
-            return Err(CompilerError::TooManyConstants(self.cur_span()));
-        }
         self.emit_abx(OpCode::LoadConst, reg, const_idx as u16)?;
         Ok(reg)
     }
@@ -84,9 +75,6 @@ impl Compiler {
         let val = Value::string(handle);
         let const_idx = self.add_constant(val)?;
         let reg = self.alloc_reg()?;
-        if const_idx > 0xFFFF {
-            return Err(CompilerError::TooManyConstants(self.cur_span()));
-        }
         self.emit_abx(OpCode::LoadConst, reg, const_idx as u16)?;
         Ok(reg)
     }
@@ -157,7 +145,10 @@ impl Compiler {
         }
 
         if count > 255 {
-            return Err(CompilerError::TooManyConstants(self.cur_span()));
+            return Err(CompilerError::CompilerLimitation(
+                format!("list literal has {count} elements (maximum is 255)"),
+                self.cur_span(),
+            ));
         }
 
         self.emit_abc(OpCode::BuildList, target_reg, start_reg, count as u8)?;
@@ -174,7 +165,10 @@ impl Compiler {
         let count = pairs.len();
 
         if count > 127 {
-            return Err(CompilerError::TooManyConstants(self.cur_span()));
+            return Err(CompilerError::CompilerLimitation(
+                format!("map literal has {count} entries (maximum is 127)"),
+                self.cur_span(),
+            ));
         }
 
         let target_reg = self.alloc_reg()?;
@@ -199,9 +193,6 @@ impl Compiler {
             let key_val = Value::string(key_handle);
             let const_idx = self.add_constant(key_val)?;
 
-            if const_idx > 0xFFFF {
-                return Err(CompilerError::TooManyConstants(self.cur_span()));
-            }
             self.emit_abx(OpCode::LoadConst, key_reg, const_idx as u16)?;
 
             // Value
@@ -337,9 +328,6 @@ impl Compiler {
         let handle = self.intern_string(method);
         let val = Value::string(handle);
         let const_idx = self.add_constant(val)?;
-        if const_idx > 0xFFFF {
-            return Err(CompilerError::TooManyConstants(self.cur_span()));
-        }
         self.emit_abx(OpCode::LoadConst, name_reg, const_idx as u16)?;
 
         // 5. Emit MethodCall: A=name_reg (result), B=recv_reg, C=arg_count
@@ -385,9 +373,6 @@ impl Compiler {
         let val = Value::string(handle);
         let const_idx = self.add_constant(val)?;
         let r = self.alloc_reg()?;
-        if const_idx > 0xFFFF {
-            return Err(CompilerError::TooManyConstants(self.cur_span()));
-        }
         self.emit_abx(OpCode::LoadConst, r, const_idx as u16)?;
         Ok(r)
     }
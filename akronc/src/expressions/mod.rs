@@ -73,6 +73,17 @@ impl ExpressionCompiler for Compiler {
 
             // === Unary operations ===
             Expr::UnaryOp { op, operand, .. } => {
+                // `!true` / `!false` fold to the opposite literal.
+                if let (UnaryOp::Not, Expr::Bool { value, .. }) = (op, operand.as_ref()) {
+                    let reg = self.alloc_reg()?;
+                    let load = if *value {
+                        OpCode::LoadFalse
+                    } else {
+                        OpCode::LoadTrue
+                    };
+                    self.emit_abx(load, reg, 0)?;
+                    return Ok(reg);
+                }
                 let reg = self.compile_expr(operand)?;
                 match op {
                     UnaryOp::Neg => self.emit_abc(OpCode::Neg, reg, reg, 0)?,
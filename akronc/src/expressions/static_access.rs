@@ -15,17 +15,11 @@ impl Compiler {
             ("Int", "MAX") => {
                 let val = Value::int(memory::I60_MAX);
                 let const_idx = self.add_constant(val)?;
-                if const_idx > 0xFFFF {
-                    return Err(CompilerError::TooManyConstants(self.cur_span()));
-                }
                 self.emit_abx(OpCode::LoadConst, reg, const_idx as u16)?;
             }
             ("Int", "MIN") => {
                 let val = Value::int(memory::I60_MIN);
                 let const_idx = self.add_constant(val)?;
-                if const_idx > 0xFFFF {
-                    return Err(CompilerError::TooManyConstants(self.cur_span()));
-                }
                 self.emit_abx(OpCode::LoadConst, reg, const_idx as u16)?;
             }
             // Field::ZERO, Field::ONE
@@ -33,9 +27,6 @@ impl Compiler {
                 let handle = self.intern_field(memory::FieldElement::ZERO);
                 let val = Value::field(handle);
                 let const_idx = self.add_constant(val)?;
-                if const_idx > 0xFFFF {
-                    return Err(CompilerError::TooManyConstants(self.cur_span()));
-                }
                 self.emit_abx(OpCode::LoadConst, reg, const_idx as u16)?;
             }
             ("Field", "ONE") => {
@@ -43,9 +34,6 @@ impl Compiler {
                 let handle = self.intern_field(fe);
                 let val = Value::field(handle);
                 let const_idx = self.add_constant(val)?;
-                if const_idx > 0xFFFF {
-                    return Err(CompilerError::TooManyConstants(self.cur_span()));
-                }
                 self.emit_abx(OpCode::LoadConst, reg, const_idx as u16)?;
             }
             // Field::ORDER — the BN254 Fr modulus as a string
@@ -55,9 +43,6 @@ impl Compiler {
                 let handle = self.intern_string(order_str);
                 let val = Value::string(handle);
                 let const_idx = self.add_constant(val)?;
-                if const_idx > 0xFFFF {
-                    return Err(CompilerError::TooManyConstants(self.cur_span()));
-                }
                 self.emit_abx(OpCode::LoadConst, reg, const_idx as u16)?;
             }
             // BigInt::from_bits — resolve to a global (native function)
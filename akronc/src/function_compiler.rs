@@ -1,9 +1,15 @@
+use std::collections::HashMap;
+
 use crate::error::CompilerError;
 use crate::types::{Local, LoopContext, UpvalueInfo};
 use akron::opcode::instruction::{encode_abc, encode_abx};
 use akron::opcode::OpCode;
 use memory::Value;
 
+/// Hard ceiling on a function's constant pool: `LoadConst` addresses it
+/// with a 16-bit operand.
+pub const MAX_CONSTANTS: usize = 1 << 16;
+
 /// State specific to ONE function being compiled
 pub struct FunctionCompiler {
     pub name: String,
@@ -12,6 +18,9 @@ pub struct FunctionCompiler {
     pub scope_depth: u32,
     pub bytecode: Vec<u32>,
     pub constants: Vec<Value>,
+    /// Index of each value in `constants`, so identical constants share
+    /// one slot without a linear scan.
+    constant_slots: HashMap<Value, usize>,
     pub upvalues: Vec<UpvalueInfo>,
     pub loop_stack: Vec<LoopContext>,
 
@@ -35,6 +44,7 @@ impl FunctionCompiler {
             scope_depth: 0,
             bytecode: Vec::new(),
             constants: Vec::new(),
+            constant_slots: HashMap::new(),
             upvalues: Vec::new(),
             loop_stack: Vec::new(),
             reg_top: arity, // Reserve R0..R(arity-1) for arguments
@@ -83,12 +93,14 @@ impl FunctionCompiler {
         Ok(())
     }
 
+    /// Return the pool index of `val`, appending it if no identical
+    /// constant exists yet. Does not enforce a limit; see
+    /// [`Compiler::add_constant`](crate::Compiler::add_constant).
     pub fn add_constant(&mut self, val: Value) -> usize {
-        if let Some(idx) = self.constants.iter().position(|c| c == &val) {
-            return idx;
-        }
-        self.constants.push(val);
-        self.constants.len() - 1
+        *self.constant_slots.entry(val).or_insert_with(|| {
+            self.constants.push(val);
+            self.constants.len() - 1
+        })
     }
 
     pub fn add_upvalue(&mut self, is_local: bool, index: u8) -> u8 {
@@ -38,7 +38,7 @@ impl FunctionDefinitionCompiler for Compiler {
 
         let global_idx = if name.is_some() {
             if self.next_global_idx == u16::MAX {
-                return Err(CompilerError::TooManyConstants(self.cur_span()));
+                return Err(CompilerError::TooManyGlobals(self.cur_span()));
             }
             let idx = self.next_global_idx;
             self.next_global_idx += 1;
@@ -327,12 +327,9 @@ pub(super) fn full_circuit(
     let handle = compiler.intern_bytes(ir_bytes);
     let val = Value::bytes(handle);
     let idx = compiler.add_constant(val)?;
-    if idx > 0xFFFF {
-        return Err(CompilerError::TooManyConstants(span_box(span)));
-    }
 
     if compiler.next_global_idx == u16::MAX {
-        return Err(CompilerError::TooManyConstants(span_box(span)));
+        return Err(CompilerError::TooManyGlobals(span_box(span)));
     }
     let global_idx = compiler.next_global_idx;
     compiler.next_global_idx += 1;
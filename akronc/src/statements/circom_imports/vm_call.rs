@@ -240,9 +240,6 @@ impl CircomVmCallEmitter for Compiler {
         let handle_idx = self.intern_circom_handle(handle);
         let handle_const_val = Value::circom_handle(handle_idx);
         let const_idx = self.add_constant(handle_const_val)?;
-        if const_idx > 0xFFFF {
-            return Err(CompilerError::TooManyConstants(self.cur_span()));
-        }
 
         // --- Emit the register sequence ---
         //
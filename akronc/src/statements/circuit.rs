@@ -43,6 +43,7 @@ pub(super) fn compile_circuit_decl(
             name: param.name.clone(),
             array_size: ta.array_size,
             type_ann: Some(ta.clone()),
+            default: None,
         };
         match vis {
             Visibility::Public => stmts.push(Stmt::PublicDecl {
@@ -88,13 +89,10 @@ pub(super) fn compile_circuit_decl(
     let handle = compiler.intern_bytes(ir_bytes);
     let val = Value::bytes(handle);
     let idx = compiler.add_constant(val)?;
-    if idx > 0xFFFF {
-        return Err(CompilerError::TooManyConstants(span_box(span)));
-    }
 
     // Bind the circuit name as a global pointing to the bytes constant
     if compiler.next_global_idx == u16::MAX {
-        return Err(CompilerError::TooManyConstants(span_box(span)));
+        return Err(CompilerError::TooManyGlobals(span_box(span)));
     }
     let global_idx = compiler.next_global_idx;
     compiler.next_global_idx += 1;
@@ -164,12 +162,9 @@ pub(super) fn compile_import_circuit(
     let handle = compiler.intern_bytes(ir_bytes);
     let val = Value::bytes(handle);
     let idx = compiler.add_constant(val)?;
-    if idx > 0xFFFF {
-        return Err(CompilerError::TooManyConstants(span_box(span)));
-    }
 
     if compiler.next_global_idx == u16::MAX {
-        return Err(CompilerError::TooManyConstants(span_box(span)));
+        return Err(CompilerError::TooManyGlobals(span_box(span)));
     }
     let global_idx = compiler.next_global_idx;
     compiler.next_global_idx += 1;
@@ -64,7 +64,7 @@ impl DeclarationCompiler for Compiler {
             });
         } else {
             if self.next_global_idx == u16::MAX {
-                return Err(CompilerError::TooManyConstants(self.cur_span()));
+                return Err(CompilerError::TooManyGlobals(self.cur_span()));
             }
             let idx = self.next_global_idx;
             self.next_global_idx += 1;
@@ -118,7 +118,7 @@ impl DeclarationCompiler for Compiler {
             });
         } else {
             if self.next_global_idx == u16::MAX {
-                return Err(CompilerError::TooManyConstants(self.cur_span()));
+                return Err(CompilerError::TooManyGlobals(self.cur_span()));
             }
             let idx = self.next_global_idx;
             self.next_global_idx += 1;
@@ -185,9 +185,6 @@ impl DeclarationCompiler for Compiler {
                 let key_val = Value::string(handle);
                 let const_idx = self.add_constant(key_val)?;
                 let key_reg = self.alloc_reg()?;
-                if const_idx > 0xFFFF {
-                    return Err(CompilerError::TooManyConstants(self.cur_span()));
-                }
                 self.emit_abx(OpCode::LoadConst, key_reg, const_idx as u16)?;
 
                 let val_reg = self.compile_expr(value)?;
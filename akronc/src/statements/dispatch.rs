@@ -62,6 +62,7 @@ pub(crate) fn stmt_span(stmt: &Stmt) -> Option<&Span> {
         | Stmt::FnDecl { span, .. }
         | Stmt::PublicDecl { span, .. }
         | Stmt::WitnessDecl { span, .. }
+        | Stmt::OutputDecl { span, .. }
         | Stmt::Break { span }
         | Stmt::Continue { span }
         | Stmt::Import { span, .. }
@@ -207,6 +208,10 @@ impl StatementCompiler for Compiler {
                     span_box(span),
                 ))
             }
+            Stmt::OutputDecl { span, .. } => Err(CompilerError::CompileError(
+                "`output` declarations are only allowed inside a circuit body".into(),
+                span_box(span),
+            )),
             Stmt::Import {
                 path, alias, span, ..
             } => self.compile_import(path, alias, span),
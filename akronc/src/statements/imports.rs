@@ -120,9 +120,6 @@ pub(super) fn compile_import(
         let key_handle = compiler.intern_string(name);
         let key_val = Value::string(key_handle);
         let const_idx = compiler.add_constant(key_val)?;
-        if const_idx > 0xFFFF {
-            return Err(CompilerError::TooManyConstants(span_box(span)));
-        }
         compiler.emit_abx(OpCode::LoadConst, key_reg, const_idx as u16)?;
 
         // Value: load from the mangled global
@@ -155,7 +152,7 @@ pub(super) fn compile_import(
 
     // Bind the map to the alias as a global
     if compiler.next_global_idx == u16::MAX {
-        return Err(CompilerError::TooManyConstants(span_box(span)));
+        return Err(CompilerError::TooManyGlobals(span_box(span)));
     }
     let idx = compiler.next_global_idx;
     compiler.next_global_idx += 1;
@@ -306,7 +303,7 @@ pub(super) fn compile_selective_import(
 
         if compiler.next_global_idx == u16::MAX {
             compiler.free_reg(tmp_reg)?;
-            return Err(CompilerError::TooManyConstants(span_box(span)));
+            return Err(CompilerError::TooManyGlobals(span_box(span)));
         }
         let new_idx = compiler.next_global_idx;
         compiler.next_global_idx += 1;
@@ -64,7 +64,7 @@ fn every_vm_registry_entry_has_a_real_native() {
 }
 
 /// Every ProveIR-available registry entry must have a valid
-/// `ProveIrLowerHandle` within the dispatch table bounds (0..10).
+/// `ProveIrLowerHandle` within the dispatch table bounds (0..29).
 #[test]
 fn every_prove_ir_registry_entry_has_a_valid_handle() {
     let reg = BuiltinRegistry::default();
@@ -132,6 +132,25 @@ fn production_prove_ir_only_set() {
         "assert_eq",
         "int_div",
         "int_mod",
+        "merkle_verify_arity",
+        "fold",
+        "poseidon_domain",
+        "poseidon2",
+        "inv",
+        "neg",
+        "merkle_root",
+        "assert_all_eq",
+        "assert_in_set",
+        "popcount",
+        "is_power_of_two",
+        "bool_and",
+        "bool_or",
+        "bool_xor",
+        "bool_not",
+        "assert_bool",
+        "dot",
+        "map",
+        "bits",
     ];
     expected.sort_unstable();
 
@@ -0,0 +1,66 @@
+//! Constant pool: identical constants share a slot, and overflowing the
+//! per-function limit reports where and by how much.
+
+use akronc::{Compiler, CompilerError};
+use memory::Value;
+
+#[test]
+fn identical_numeric_literals_share_one_slot() {
+    let mut compiler = Compiler::new();
+    compiler
+        .compile("let a = 12345\nlet b = 12345\nlet c = 678")
+        .unwrap();
+    let constants = &compiler.compilers.last().unwrap().constants;
+    let slots = |v: Value| constants.iter().filter(|c| **c == v).count();
+    assert_eq!(slots(Value::int(12345)), 1);
+    assert_eq!(slots(Value::int(678)), 1);
+}
+
+#[test]
+fn overflowing_the_pool_names_the_function_and_count() {
+    let lets: String = (0..10)
+        .map(|i| format!("let x{i} = {}\n", 1000 + i))
+        .collect();
+    let mut compiler = Compiler::new();
+    compiler.max_constants = 4;
+    let err = compiler
+        .compile(&format!("fn f() {{\n{lets}x0\n}}"))
+        .unwrap_err();
+    match &err {
+        CompilerError::TooManyConstants {
+            function,
+            count,
+            limit,
+            span,
+        } => {
+            assert_eq!(function, "f");
+            assert_eq!(*count, 5);
+            assert_eq!(*limit, 4);
+            // Points at the fifth `let`, whose literal overflowed.
+            assert_eq!(span.as_ref().expect("span").line_start, 6);
+        }
+        other => panic!("expected TooManyConstants, got {other:?}"),
+    }
+    assert!(
+        err.to_string()
+            .contains("too many constants in `f`: 5 distinct constants exceed the limit of 4"),
+        "{err}"
+    );
+}
+
+#[test]
+fn repeated_literals_do_not_count_against_the_limit() {
+    let lets: String = (0..10).map(|i| format!("let x{i} = 7\n")).collect();
+    let mut compiler = Compiler::new();
+    compiler.max_constants = 1;
+    assert!(compiler
+        .compile(&format!("fn f() {{\n{lets}x0\n}}"))
+        .is_ok());
+}
+
+#[test]
+fn limit_is_clamped_to_the_operand_range() {
+    let mut compiler = Compiler::new();
+    compiler.max_constants = usize::MAX;
+    assert!(compiler.compile("let a = 1\nlet b = 2").is_ok());
+}
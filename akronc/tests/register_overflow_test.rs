@@ -0,0 +1,54 @@
+//! Register exhaustion must surface as `CompilerError::RegisterOverflow`
+//! rather than panicking — each frame has 255 registers (8-bit operands).
+
+use akronc::{Compiler, CompilerError};
+
+fn compile(source: &str) -> Result<Vec<u32>, CompilerError> {
+    Compiler::new().compile(source)
+}
+
+#[test]
+fn wide_list_literal_overflows_cleanly() {
+    let items: Vec<String> = (0..300).map(|i| i.to_string()).collect();
+    let source = format!("let x = [{}]", items.join(", "));
+    let err = compile(&source).unwrap_err();
+    assert!(
+        matches!(err, CompilerError::RegisterOverflow(Some(_))),
+        "expected RegisterOverflow with a span, got {err:?}"
+    );
+}
+
+#[test]
+fn wide_map_literal_overflows_cleanly() {
+    // 127 pairs is the BuildMap limit; with a few live locals the 254
+    // contiguous key/value registers no longer fit in the frame.
+    let entries: Vec<String> = (0..127).map(|i| format!("k{i}: 0")).collect();
+    let source = format!(
+        "fn f() {{\nlet a = 1\nlet b = 2\nlet m = {{{}}}\nm\n}}",
+        entries.join(", ")
+    );
+    let err = compile(&source).unwrap_err();
+    assert!(
+        matches!(err, CompilerError::RegisterOverflow(_)),
+        "expected RegisterOverflow, got {err:?}"
+    );
+}
+
+#[test]
+fn too_many_live_locals_overflows_cleanly() {
+    let lets: String = (0..300).map(|i| format!("let x{i} = {i}\n")).collect();
+    let source = format!("fn f() {{\n{lets}x0\n}}");
+    let err = compile(&source).unwrap_err();
+    assert!(
+        matches!(err, CompilerError::RegisterOverflow(_)),
+        "expected RegisterOverflow, got {err:?}"
+    );
+    assert!(format!("{err}").to_lowercase().contains("register"));
+}
+
+#[test]
+fn literal_within_budget_still_compiles() {
+    let items: Vec<String> = (0..200).map(|i| i.to_string()).collect();
+    let source = format!("let x = [{}]", items.join(", "));
+    assert!(compile(&source).is_ok());
+}
@@ -0,0 +1,55 @@
+//! Logical operators with a literal left operand fold at compile time
+//! instead of emitting a runtime branch.
+
+use akron::opcode::{instruction::decode_opcode, OpCode};
+use akronc::Compiler;
+
+fn ops(source: &str) -> Vec<u8> {
+    let mut compiler = Compiler::new();
+    let bytecode = compiler.compile(source).expect("Failed to compile");
+    bytecode.iter().map(|inst| decode_opcode(*inst)).collect()
+}
+
+#[test]
+fn literal_lhs_emits_no_branch() {
+    for source in [
+        "let x = true\nfalse && x",
+        "let x = true\ntrue && x",
+        "let x = true\ntrue || x",
+        "let x = true\nfalse || x",
+    ] {
+        let ops = ops(source);
+        assert!(!ops.contains(&OpCode::JumpIfFalse.as_u8()), "{source}");
+        assert!(!ops.contains(&OpCode::Jump.as_u8()), "{source}");
+    }
+}
+
+#[test]
+fn variable_lhs_keeps_the_branch() {
+    let ops = ops("let x = true\nx && false");
+    assert!(ops.contains(&OpCode::JumpIfFalse.as_u8()));
+}
+
+#[test]
+fn not_literal_emits_no_lognot() {
+    assert!(!ops("!true").contains(&OpCode::LogNot.as_u8()));
+    assert!(ops("let x = true\n!x").contains(&OpCode::LogNot.as_u8()));
+}
+
+#[test]
+fn skipped_operand_is_still_checked() {
+    let err = Compiler::new().compile("true || nope").unwrap_err();
+    assert!(err.to_string().contains("nope"), "{err}");
+    assert!(Compiler::new().compile("false && nope").is_err());
+}
+
+#[test]
+fn break_in_skipped_operand_is_dropped() {
+    // The folded loop must compile exactly like one without the dead
+    // operand; a leftover `break` jump would get patched over live code.
+    let compile = |source: &str| Compiler::new().compile(source).expect("Failed to compile");
+    assert_eq!(
+        compile("while true {\ntrue || { break }\nbreak\n}"),
+        compile("while true {\ntrue\nbreak\n}")
+    );
+}
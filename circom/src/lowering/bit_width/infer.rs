@@ -115,7 +115,9 @@ pub fn infer_expr(expr: &CircuitExpr, ctx: &InferenceCtx<'_>) -> BitWidth {
 
         // ---- Hashes / Merkle / RangeCheck ----
         CircuitExpr::PoseidonHash { .. } | CircuitExpr::PoseidonMany(_) => BitWidth::Field,
-        CircuitExpr::MerkleVerify { .. } => BitWidth::Exact(1),
+        CircuitExpr::MerkleVerify { .. } | CircuitExpr::MerkleVerifyArity { .. } => {
+            BitWidth::Exact(1)
+        }
         CircuitExpr::RangeCheck { bits, .. } => {
             // RangeCheck is itself a typed bound — the inferred width
             // mirrors the constraint exactly. (RangeCheck appears
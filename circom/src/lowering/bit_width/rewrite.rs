@@ -57,7 +57,8 @@ pub fn rewrite_num_bits_in_expr(expr: &mut CircuitExpr, ctx: &InferenceCtx<'_>)
                 rewrite_num_bits_in_expr(a, ctx);
             }
         }
-        CircuitExpr::MerkleVerify { root, leaf, .. } => {
+        CircuitExpr::MerkleVerify { root, leaf, .. }
+        | CircuitExpr::MerkleVerifyArity { root, leaf, .. } => {
             rewrite_num_bits_in_expr(root, ctx);
             rewrite_num_bits_in_expr(leaf, ctx);
         }
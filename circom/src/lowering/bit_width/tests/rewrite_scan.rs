@@ -93,10 +93,12 @@ fn scan_detects_bool_constraint() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![make_bool_assertion("c_out_0")],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let widths = scan_bool_constraints(&prove_ir);
     assert_eq!(widths.get("c_out_0").copied(), Some(BitWidth::Exact(1)));
@@ -109,6 +111,7 @@ fn scan_detects_swapped_assertion_sides() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![ir_forge::types::CircuitNode::AssertEq {
             lhs: CircuitExpr::Const(FieldConst::zero()),
@@ -126,6 +129,7 @@ fn scan_detects_swapped_assertion_sides() {
         }],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let widths = scan_bool_constraints(&prove_ir);
     assert_eq!(widths.get("bit").copied(), Some(BitWidth::Exact(1)));
@@ -138,6 +142,7 @@ fn scan_handles_commuted_mul_factors() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![ir_forge::types::CircuitNode::AssertEq {
             lhs: CircuitExpr::BinOp {
@@ -155,6 +160,7 @@ fn scan_handles_commuted_mul_factors() {
         }],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let widths = scan_bool_constraints(&prove_ir);
     assert_eq!(widths.get("b").copied(), Some(BitWidth::Exact(1)));
@@ -166,6 +172,7 @@ fn scan_recurses_into_for_loops() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![ir_forge::types::CircuitNode::For {
             var: "i".to_string(),
@@ -175,6 +182,7 @@ fn scan_recurses_into_for_loops() {
         }],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let widths = scan_bool_constraints(&prove_ir);
     assert_eq!(widths.get("nested_bit").copied(), Some(BitWidth::Exact(1)));
@@ -186,6 +194,7 @@ fn scan_ignores_non_bool_assertions() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![ir_forge::types::CircuitNode::AssertEq {
             lhs: CircuitExpr::Var("x".into()),
@@ -195,6 +204,7 @@ fn scan_ignores_non_bool_assertions() {
         }],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let widths = scan_bool_constraints(&prove_ir);
     assert!(widths.is_empty());
@@ -209,6 +219,7 @@ fn scan_pipeline_tightens_shift_via_bool_signals() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![
             make_bool_assertion("b"),
@@ -223,6 +234,7 @@ fn scan_pipeline_tightens_shift_via_bool_signals() {
         ],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let widths = scan_bool_constraints(&prove_ir);
     let ctx = InferenceCtx {
@@ -194,6 +194,13 @@ pub(super) fn fold_expr(
             leaf,
             path: _,
             indices: _,
+        }
+        | CircuitExpr::MerkleVerifyArity {
+            root,
+            leaf,
+            path: _,
+            indices: _,
+            arity: _,
         } => {
             fold_expr(root, kav, env);
             fold_expr(leaf, kav, env);
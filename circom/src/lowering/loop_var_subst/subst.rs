@@ -228,6 +228,18 @@ fn subst_expr(expr: &mut CircuitExpr, t: u32, v: u64, ph: &str, vs: &str) {
             subst_name(path, ph, vs);
             subst_name(indices, ph, vs);
         }
+        CircuitExpr::MerkleVerifyArity {
+            root,
+            leaf,
+            path,
+            indices,
+            arity: _,
+        } => {
+            subst_expr(root, t, v, ph, vs);
+            subst_expr(leaf, t, v, ph, vs);
+            subst_name(path, ph, vs);
+            subst_name(indices, ph, vs);
+        }
         CircuitExpr::ArrayIndex { array, index } => {
             subst_name(array, ph, vs);
             subst_expr(index, t, v, ph, vs);
@@ -332,16 +332,20 @@ fn poc_prove_ir(body: Vec<CircuitNode>) -> ProveIR {
             name: "out".into(),
             array_size: Some(ir_forge::types::ArraySize::Literal(4)),
             ir_type: IrType::Field,
+            default: None,
         }],
         witness_inputs: vec![ProveInputDecl {
             name: "x".into(),
             array_size: None,
             ir_type: IrType::Field,
+            default: None,
         }],
+        outputs: vec![],
         captures: vec![],
         body,
         capture_arrays: Vec::<CaptureArrayDef>::new(),
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     }
 }
 
@@ -55,6 +55,7 @@ impl OutputSignal {
             name: self.name.clone(),
             array_size,
             ir_type: IrType::Field,
+            default: None,
         }
     }
 }
@@ -314,6 +315,7 @@ fn make_input_decl(name: &str, dims: &[ResolvedDim]) -> ProveInputDecl {
         name: name.to_string(),
         array_size: dims_to_array_size(dims),
         ir_type: IrType::Field,
+        default: None,
     }
 }
 
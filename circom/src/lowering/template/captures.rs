@@ -195,7 +195,8 @@ fn collect_expr_captures<'a>(expr: &'a CircuitExpr, captures: &mut HashSet<&'a s
             }
         }
         CircuitExpr::RangeCheck { value, .. } => collect_expr_captures(value, captures),
-        CircuitExpr::MerkleVerify { root, leaf, .. } => {
+        CircuitExpr::MerkleVerify { root, leaf, .. }
+        | CircuitExpr::MerkleVerifyArity { root, leaf, .. } => {
             collect_expr_captures(root, captures);
             collect_expr_captures(leaf, captures);
         }
@@ -213,10 +213,12 @@ pub(crate) fn lower_template_with_ctx<'a>(
             witness_inputs: layout.witness_inputs,
             captures,
             body,
+            outputs: vec![],
             capture_arrays: Vec::new(),
             // Shared bodies promoted out of `body` during lowering;
             // referenced by `CircuitNode::ComponentCall` instances.
             component_bodies: ctx.component_bodies.clone(),
+            shadowed_lets: Vec::new(),
         },
         output_names,
     })
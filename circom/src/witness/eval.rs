@@ -257,6 +257,7 @@ pub(super) fn eval_hint<F: FieldBackend>(
         | CircuitExpr::PoseidonMany(_)
         | CircuitExpr::RangeCheck { .. }
         | CircuitExpr::MerkleVerify { .. }
+        | CircuitExpr::MerkleVerifyArity { .. }
         | CircuitExpr::ArrayLen(_) => None,
     }
 }
@@ -443,6 +443,7 @@ impl Compiler {
             | CircuitExpr::PoseidonMany(_)
             | CircuitExpr::RangeCheck { .. }
             | CircuitExpr::MerkleVerify { .. }
+            | CircuitExpr::MerkleVerifyArity { .. }
             | CircuitExpr::ArrayLen(_) => TExpr::Unevaluable,
         };
         self.push(compiled)
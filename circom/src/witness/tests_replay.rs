@@ -22,6 +22,7 @@ fn prove_ir(body: Vec<CircuitNode>, bodies: &[(&str, Vec<CircuitNode>)]) -> Prov
         name: None,
         public_inputs: Vec::new(),
         witness_inputs: Vec::new(),
+        outputs: vec![],
         captures: Vec::new(),
         body,
         capture_arrays: Vec::new(),
@@ -29,6 +30,7 @@ fn prove_ir(body: Vec<CircuitNode>, bodies: &[(&str, Vec<CircuitNode>)]) -> Prov
             .iter()
             .map(|(k, b)| (k.to_string(), b.clone()))
             .collect(),
+        shadowed_lets: Vec::new(),
     }
 }
 
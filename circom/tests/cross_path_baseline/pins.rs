@@ -86,16 +86,16 @@ pub(crate) fn pin_pedersen_8() -> FrozenBaseline {
 pub(crate) fn pin_escalarmulfix_253() -> FrozenBaseline {
     FrozenBaseline {
         pre_o1_hash: [
-            8, 52, 148, 216, 41, 153, 46, 152, 92, 155, 189, 54, 144, 227, 141, 190, 35, 124, 57,
-            106, 168, 142, 245, 176, 32, 41, 148, 92, 35, 75, 169, 169,
+            212, 226, 127, 10, 195, 236, 10, 23, 172, 231, 110, 70, 149, 32, 65, 125, 244, 91, 120,
+            213, 64, 234, 174, 184, 46, 67, 34, 203, 230, 3, 188, 139,
         ],
-        pre_o1_count: 27,
+        pre_o1_count: 26,
         post_o1_hash: [
-            228, 230, 80, 210, 118, 228, 106, 181, 76, 203, 19, 68, 189, 116, 36, 68, 99, 143, 30,
-            104, 138, 44, 36, 236, 246, 150, 16, 56, 131, 216, 21, 117,
+            189, 72, 93, 61, 24, 104, 220, 185, 219, 101, 5, 219, 194, 44, 18, 95, 59, 31, 255,
+            169, 26, 210, 64, 57, 99, 177, 182, 167, 205, 205, 199, 105,
         ],
         post_o1_count: 11,
-        num_variables: 44,
+        num_variables: 43,
         public_inputs: vec!["out_0".into(), "out_1".into()],
     }
 }
@@ -103,16 +103,16 @@ pub(crate) fn pin_escalarmulfix_253() -> FrozenBaseline {
 pub(crate) fn pin_escalarmulany_254() -> FrozenBaseline {
     FrozenBaseline {
         pre_o1_hash: [
-            246, 160, 73, 112, 140, 17, 214, 216, 137, 204, 185, 102, 177, 17, 204, 234, 241, 250,
-            51, 134, 191, 164, 218, 93, 148, 62, 7, 122, 165, 211, 205, 231,
+            175, 10, 185, 107, 12, 157, 45, 153, 221, 195, 105, 150, 24, 196, 250, 216, 187, 33,
+            188, 200, 169, 21, 125, 224, 129, 181, 21, 76, 37, 167, 112, 25,
         ],
-        pre_o1_count: 5325,
+        pre_o1_count: 5095,
         post_o1_hash: [
-            170, 59, 95, 44, 114, 177, 134, 98, 20, 201, 138, 61, 6, 235, 142, 86, 11, 12, 35, 78,
-            189, 231, 113, 41, 28, 9, 97, 156, 180, 151, 247, 73,
+            238, 26, 88, 58, 146, 96, 139, 216, 145, 139, 77, 131, 20, 155, 165, 121, 156, 191,
+            168, 44, 223, 104, 254, 104, 178, 211, 239, 147, 151, 244, 118, 239,
         ],
         post_o1_count: 2310,
-        num_variables: 5582,
+        num_variables: 5352,
         public_inputs: vec!["out_0".into(), "out_1".into()],
     }
 }
@@ -120,16 +120,16 @@ pub(crate) fn pin_escalarmulany_254() -> FrozenBaseline {
 pub(crate) fn pin_poseidon_2() -> FrozenBaseline {
     FrozenBaseline {
         pre_o1_hash: [
-            10, 36, 63, 36, 171, 14, 78, 202, 254, 82, 238, 72, 249, 65, 129, 161, 53, 175, 58,
-            247, 57, 84, 45, 85, 122, 194, 235, 2, 168, 85, 53, 178,
+            166, 46, 109, 233, 136, 161, 240, 112, 2, 147, 194, 178, 164, 101, 50, 40, 38, 201, 19,
+            98, 89, 80, 178, 29, 198, 200, 87, 131, 55, 231, 194, 200,
         ],
-        pre_o1_count: 491,
+        pre_o1_count: 345,
         post_o1_hash: [
-            155, 15, 251, 46, 236, 19, 201, 89, 251, 223, 195, 198, 231, 90, 74, 111, 143, 180,
-            211, 114, 108, 97, 185, 104, 99, 19, 105, 1, 65, 75, 254, 155,
+            102, 202, 215, 144, 220, 106, 246, 205, 114, 83, 41, 185, 84, 231, 189, 196, 204, 57,
+            61, 208, 90, 115, 199, 193, 55, 141, 54, 2, 113, 147, 83, 218,
         ],
         post_o1_count: 240,
-        num_variables: 494,
+        num_variables: 348,
         public_inputs: vec!["inputs_0".into(), "inputs_1".into(), "out".into()],
     }
 }
@@ -137,16 +137,16 @@ pub(crate) fn pin_poseidon_2() -> FrozenBaseline {
 pub(crate) fn pin_mimcsponge() -> FrozenBaseline {
     FrozenBaseline {
         pre_o1_hash: [
-            21, 105, 6, 67, 74, 40, 158, 193, 201, 7, 18, 192, 184, 196, 52, 95, 74, 88, 34, 20,
-            154, 59, 164, 25, 222, 238, 171, 19, 106, 13, 234, 132,
+            5, 232, 44, 170, 141, 62, 126, 131, 229, 50, 241, 29, 203, 219, 69, 133, 140, 198, 185,
+            250, 106, 107, 199, 224, 189, 132, 33, 138, 84, 61, 78, 84,
         ],
-        pre_o1_count: 2581,
+        pre_o1_count: 1811,
         post_o1_hash: [
-            209, 57, 95, 246, 81, 199, 174, 43, 149, 79, 248, 22, 47, 132, 165, 241, 34, 19, 50,
-            27, 154, 52, 143, 147, 33, 94, 35, 228, 122, 106, 158, 80,
+            148, 58, 152, 187, 68, 172, 174, 29, 243, 196, 129, 127, 202, 114, 143, 103, 80, 68,
+            42, 156, 66, 88, 154, 151, 6, 159, 11, 96, 126, 191, 17, 170,
         ],
         post_o1_count: 1317,
-        num_variables: 2585,
+        num_variables: 1815,
         public_inputs: vec!["outs_0".into()],
     }
 }
@@ -154,16 +154,16 @@ pub(crate) fn pin_mimcsponge() -> FrozenBaseline {
 pub(crate) fn pin_babyjubjub() -> FrozenBaseline {
     FrozenBaseline {
         pre_o1_hash: [
-            94, 33, 189, 175, 173, 144, 34, 119, 31, 155, 206, 153, 226, 25, 118, 123, 4, 182, 67,
-            196, 110, 107, 245, 62, 191, 106, 20, 136, 13, 177, 210, 48,
+            184, 58, 252, 47, 53, 206, 205, 55, 94, 144, 222, 112, 84, 178, 49, 98, 200, 131, 81,
+            150, 58, 27, 73, 4, 142, 107, 51, 117, 73, 233, 38, 195,
         ],
-        pre_o1_count: 30,
+        pre_o1_count: 29,
         post_o1_hash: [
-            255, 1, 85, 58, 96, 215, 109, 91, 15, 212, 178, 94, 43, 27, 183, 11, 14, 102, 111, 132,
-            199, 103, 30, 0, 105, 117, 224, 248, 152, 95, 24, 38,
+            250, 199, 155, 249, 161, 41, 251, 121, 211, 164, 63, 219, 19, 110, 18, 179, 163, 126,
+            43, 139, 244, 169, 184, 102, 236, 103, 140, 233, 236, 117, 29, 71,
         ],
         post_o1_count: 15,
-        num_variables: 34,
+        num_variables: 33,
         public_inputs: vec!["xout".into(), "yout".into()],
     }
 }
@@ -188,16 +188,16 @@ pub(crate) fn pin_pedersen_old_8() -> FrozenBaseline {
 pub(crate) fn pin_eddsaposeidon() -> FrozenBaseline {
     FrozenBaseline {
         pre_o1_hash: [
-            46, 60, 63, 224, 235, 241, 9, 142, 21, 20, 136, 59, 192, 205, 235, 241, 38, 31, 226,
-            81, 139, 249, 252, 4, 67, 3, 48, 16, 103, 117, 21, 224,
+            47, 144, 129, 95, 136, 255, 28, 87, 162, 131, 7, 240, 54, 213, 179, 33, 23, 193, 112,
+            73, 17, 143, 49, 102, 220, 101, 222, 183, 185, 209, 216, 212,
         ],
-        pre_o1_count: 9719,
+        pre_o1_count: 9207,
         post_o1_hash: [
-            240, 149, 78, 219, 114, 124, 39, 198, 10, 186, 154, 82, 176, 223, 127, 71, 178, 235,
-            41, 124, 181, 152, 33, 150, 57, 189, 64, 229, 57, 205, 255, 189,
+            51, 188, 234, 219, 86, 154, 50, 86, 147, 24, 176, 14, 64, 193, 231, 228, 35, 162, 183,
+            184, 182, 76, 195, 195, 148, 227, 10, 187, 137, 100, 95, 103,
         ],
         post_o1_count: 3965,
-        num_variables: 10410,
+        num_variables: 9898,
         public_inputs: vec!["dummy".into()],
     }
 }
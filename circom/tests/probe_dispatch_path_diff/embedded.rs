@@ -60,6 +60,7 @@ fn probe_embedded_sha256(n_bits: u64, all_const: bool, label: &str) {
                 name: format!("user_in_{i}"),
                 array_size: None,
                 ir_type: IrType::Field,
+                default: None,
             });
         }
     }
@@ -68,10 +69,12 @@ fn probe_embedded_sha256(n_bits: u64, all_const: bool, label: &str) {
         name: Some("dispatch_probe".into()),
         public_inputs: vec![],
         witness_inputs,
+        outputs: vec![],
         captures: vec![],
         body: instantiation.body,
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
 
     let captures = HashMap::<String, FieldElement<Bn254Fr>>::new();
@@ -140,6 +143,7 @@ fn probe_embedded_sha256_one_const(n_bits: u64, label: &str) {
             name: format!("user_in_{i}"),
             array_size: None,
             ir_type: IrType::Field,
+            default: None,
         });
     }
 
@@ -147,10 +151,12 @@ fn probe_embedded_sha256_one_const(n_bits: u64, label: &str) {
         name: Some("dispatch_probe".into()),
         public_inputs: vec![],
         witness_inputs,
+        outputs: vec![],
         captures: vec![],
         body: instantiation.body,
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
 
     let captures = HashMap::<String, FieldElement<Bn254Fr>>::new();
@@ -197,6 +203,7 @@ fn probe_embedded_sha256_8_var_dump_first_30() {
             name: format!("user_in_{i}"),
             array_size: None,
             ir_type: IrType::Field,
+            default: None,
         });
     }
 
@@ -204,10 +211,12 @@ fn probe_embedded_sha256_8_var_dump_first_30() {
         name: Some("dispatch_probe".into()),
         public_inputs: vec![],
         witness_inputs,
+        outputs: vec![],
         captures: vec![],
         body: instantiation.body,
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
 
     let captures = HashMap::<String, FieldElement<Bn254Fr>>::new();
@@ -259,10 +268,12 @@ fn probe_embedded_sha256_8_const_track_low_ssa() {
         name: Some("dispatch_probe".into()),
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: instantiation.body,
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
 
     let captures = HashMap::<String, FieldElement<Bn254Fr>>::new();
@@ -388,10 +399,12 @@ fn probe_embedded_sha256_8_const_dump_first_30() {
         name: Some("dispatch_probe".into()),
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: instantiation.body,
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
 
     let captures = HashMap::<String, FieldElement<Bn254Fr>>::new();
@@ -130,6 +130,7 @@ fn probe_ach_dispatch_sha256_64() {
             name: format!("user_in_{i}"),
             array_size: None,
             ir_type: IrType::Field,
+            default: None,
         });
     }
 
@@ -137,10 +138,12 @@ fn probe_ach_dispatch_sha256_64() {
         name: Some("dispatch_probe".into()),
         public_inputs: vec![],
         witness_inputs,
+        outputs: vec![],
         captures: vec![],
         body: instantiation.body,
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
 
     eprintln!();
@@ -52,6 +52,9 @@ pub enum Commands {
         /// Print circuit constraint stats for each prove block
         #[arg(long)]
         circuit_stats: bool,
+        /// Wrap Int overflow modulo 2^60 instead of failing with "integer overflow"
+        #[arg(long)]
+        wrapping: bool,
     },
     /// Disassemble a source file or binary
     Disassemble {
@@ -104,7 +107,7 @@ pub enum Commands {
         #[arg(long)]
         input_file: Vec<String>,
         /// Disable IR optimization passes
-        #[arg(long)]
+        #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
         no_optimize: Option<bool>,
         /// Backend: "r1cs" (default) or "plonkish"
         #[arg(long)]
@@ -136,8 +139,11 @@ pub enum Commands {
     },
     /// Compile a circuit to .r1cs (and optionally generate .wtns)
     Circuit {
-        /// Path to the source file (.ach). If omitted, uses [project].entry from achronyme.toml
+        /// Path to the source file (.ach), or `-` to read stdin. If omitted, uses [project].entry from achronyme.toml
         path: Option<String>,
+        /// Read the circuit source from stdin (same as passing `-` as the path)
+        #[arg(long, conflicts_with = "path")]
+        stdin: bool,
         /// Output .r1cs file path
         #[arg(long)]
         r1cs: Option<String>,
@@ -147,12 +153,18 @@ pub enum Commands {
         /// Input values as name=value pairs (comma-separated, decimal or 0x hex)
         #[arg(long)]
         inputs: Option<String>,
-        /// Input values from a TOML file (arrays supported natively)
+        /// Input values from a TOML or CSV file (arrays supported natively)
         #[arg(long)]
         input_file: Option<String>,
-        /// Disable IR optimization passes
+        /// Format of --input-file: "toml" or "csv" (default: from the file extension)
         #[arg(long)]
+        input_format: Option<String>,
+        /// Disable IR optimization passes
+        #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
         no_optimize: Option<bool>,
+        /// IR optimization level: 0 (none), 1 (constant folding + DCE), 2 (all passes, default)
+        #[arg(long)]
+        opt_level: Option<u8>,
         /// Backend: "r1cs" (default) or "plonkish"
         #[arg(long)]
         backend: Option<String>,
@@ -165,11 +177,142 @@ pub enum Commands {
         /// Export Plonkish circuit to JSON (includes witness if --inputs is provided)
         #[arg(long)]
         plonkish_json: Option<String>,
+        /// Also write the R1CS constraints as human-readable JSON to the given path
+        #[arg(long)]
+        emit_r1cs_json: Option<String>,
+        /// Also write the circom `.sym` file naming each wire to the given path
+        #[arg(long)]
+        emit_sym: Option<String>,
         /// Dump the SSA IR (after optimization) and exit without compiling to constraints
         #[arg(long)]
         dump_ir: bool,
         /// Print circuit constraint stats breakdown
         #[arg(long)]
         circuit_stats: bool,
+        /// Print every witness wire as `index: label = value` (r1cs backend, requires inputs)
+        #[arg(long)]
+        dump_witness: bool,
+        /// Compile the circuit twice and fail if the IR or R1CS wire layout differ
+        #[arg(long)]
+        check_determinism: bool,
+        /// Compile the `circuit` or `export fn` of this name (for files holding several)
+        #[arg(long)]
+        entry: Option<String>,
+        /// Cap on unrolled loop iterations (default: 10000, at most 1000000)
+        #[arg(long)]
+        max_unroll: Option<u64>,
+    },
+    /// Recompile a circuit (and its imports) on every change, printing constraint counts
+    Watch {
+        /// Path to the source file (.ach). If omitted, uses [project].entry from achronyme.toml
+        path: Option<String>,
+        /// Disable IR optimization passes
+        #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+        no_optimize: Option<bool>,
+        /// IR optimization level: 0 (none), 1 (constant folding + DCE), 2 (all passes, default)
+        #[arg(long)]
+        opt_level: Option<u8>,
+        /// Compile the `circuit` or `export fn` of this name (for files holding several)
+        #[arg(long)]
+        entry: Option<String>,
+        /// Cap on unrolled loop iterations (default: 10000, at most 1000000)
+        #[arg(long)]
+        max_unroll: Option<u64>,
+    },
+    /// Print a circuit's source annotated with the constraints each line emits
+    Profile {
+        /// Path to the source file (.ach). If omitted, uses [project].entry from achronyme.toml
+        path: Option<String>,
+        /// Disable IR optimization passes
+        #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+        no_optimize: Option<bool>,
+        /// IR optimization level: 0 (none), 1 (constant folding + DCE), 2 (all passes, default)
+        #[arg(long)]
+        opt_level: Option<u8>,
+        /// Compile the `circuit` or `export fn` of this name (for files holding several)
+        #[arg(long)]
+        entry: Option<String>,
+        /// Cap on unrolled loop iterations (default: 10000, at most 1000000)
+        #[arg(long)]
+        max_unroll: Option<u64>,
+    },
+    /// Report a circuit's constraint and variable counts without inputs or a witness
+    Analyze {
+        /// Path to the source file (.ach). If omitted, uses [project].entry from achronyme.toml
+        path: Option<String>,
+        /// Disable IR optimization passes
+        #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+        no_optimize: Option<bool>,
+        /// IR optimization level: 0 (none), 1 (constant folding + DCE), 2 (all passes, default)
+        #[arg(long)]
+        opt_level: Option<u8>,
+        /// Compile the `circuit` or `export fn` of this name (for files holding several)
+        #[arg(long)]
+        entry: Option<String>,
+        /// Cap on unrolled loop iterations (default: 10000, at most 1000000)
+        #[arg(long)]
+        max_unroll: Option<u64>,
     },
+    /// Parse and lower a file, reporting every diagnostic without running it
+    Check {
+        /// Path to the source file (.ach). If omitted, uses [project].entry from achronyme.toml
+        path: Option<String>,
+        /// Diagnostic output format: human, json (one JSON array), or short. Overrides --error-format
+        #[arg(long)]
+        format: Option<String>,
+    },
+    /// Show the constraints a builtin or operator expands to (e.g. `mux`, `range_check`, `<`)
+    Explain {
+        /// Builtin or operator to explain
+        builtin: String,
+        /// Bit width for `range_check`, or operand bound for comparisons
+        #[arg(long)]
+        bits: Option<u32>,
+    },
+    /// List every builtin with its arity, where it can be called, and a summary
+    ListBuiltins,
+    /// Print the active prime field's modulus, bit size, two-adicity and generator
+    FieldInfo,
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::{Cli, Commands};
+
+    fn circuit_no_optimize(args: &[&str]) -> Option<bool> {
+        let cli = Cli::try_parse_from(args).unwrap();
+        match cli.command {
+            Commands::Circuit { no_optimize, .. } => no_optimize,
+            _ => panic!("expected the circuit command"),
+        }
+    }
+
+    #[test]
+    fn no_optimize_is_a_bare_flag() {
+        assert_eq!(
+            circuit_no_optimize(&["ach", "circuit", "c.ach", "--no-optimize"]),
+            Some(true)
+        );
+        // A following positional is the path, not the flag's value.
+        let cli = Cli::try_parse_from(["ach", "circuit", "--no-optimize", "c.ach"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Circuit { no_optimize: Some(true), path: Some(ref p), .. } if p == "c.ach"
+        ));
+        // An explicit value still overrides `optimize` from achronyme.toml.
+        assert_eq!(
+            circuit_no_optimize(&["ach", "circuit", "c.ach", "--no-optimize=false"]),
+            Some(false)
+        );
+        assert_eq!(circuit_no_optimize(&["ach", "circuit", "c.ach"]), None);
+    }
+
+    #[test]
+    fn stdin_conflicts_with_a_path() {
+        let cli = Cli::try_parse_from(["ach", "circuit", "--stdin"]).unwrap();
+        assert!(matches!(cli.command, Commands::Circuit { stdin: true, .. }));
+        assert!(Cli::try_parse_from(["ach", "circuit", "c.ach", "--stdin"]).is_err());
+    }
 }
@@ -0,0 +1,133 @@
+//! `ach analyze` — estimate a circuit's cost without inputs.
+//!
+//! Runs the shared ProveIR → Lysis → optimize → R1CS pipeline, but only
+//! `compile_ir`: no input map is needed and no
+//! witness is built, so a circuit can be sized before test inputs exist.
+//! Prints the final constraint and variable counts (after linear
+//! constraint elimination, i.e. the `.r1cs` size) and the matrices'
+//! nonzero entries, together with the taint-analysis warnings
+//! `ach circuit` would report.
+
+use std::fs;
+
+use anyhow::{Context, Result};
+use constraints::PoseidonParamsProvider;
+use ir::passes::taint::TaintWarning;
+use ir::passes::OptLevel;
+use memory::field::PrimeId;
+use memory::FieldBackend;
+
+use super::circuit::pipeline::{compile_r1cs_file, CompileOptions};
+use super::ErrorFormat;
+
+/// Cost summary and analysis warnings for one circuit.
+#[derive(Debug)]
+pub struct AnalysisReport {
+    /// R1CS constraints after linear elimination.
+    pub constraints: usize,
+    /// R1CS variables, including the constant `ONE` wire.
+    pub variables: usize,
+    /// Nonzero entries of the `A`, `B` and `C` matrices. Prover cost
+    /// tracks these, so equal constraint counts can still prove at
+    /// different speeds.
+    pub nonzero_entries: (usize, usize, usize),
+    /// Public inputs declared by the circuit.
+    pub public_inputs: usize,
+    /// Witness inputs declared by the circuit.
+    pub witness_inputs: usize,
+    /// Under-constrained and unused input warnings.
+    pub warnings: Vec<TaintWarning>,
+}
+
+impl std::fmt::Display for AnalysisReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "constraints:    {}", self.constraints)?;
+        writeln!(f, "variables:      {}", self.variables)?;
+        let (a, b, c) = self.nonzero_entries;
+        writeln!(f, "nonzero A/B/C:  {a} / {b} / {c} ({} total)", a + b + c)?;
+        writeln!(f, "public inputs:  {}", self.public_inputs)?;
+        writeln!(f, "witness inputs: {}", self.witness_inputs)?;
+        match self.warnings.len() {
+            0 => writeln!(f, "no warnings"),
+            1 => writeln!(f, "1 warning"),
+            n => writeln!(f, "{n} warnings"),
+        }
+    }
+}
+
+/// Entry point for `ach analyze`: print the report, with each warning
+/// rendered as a diagnostic on stderr.
+pub fn analyze_command(
+    path: &str,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    error_format: ErrorFormat,
+) -> Result<()> {
+    let report = analyze_circuit(path, opts, prime_id, error_format)?;
+    if !report.warnings.is_empty() {
+        let source =
+            fs::read_to_string(path).with_context(|| format!("cannot read source file: {path}"))?;
+        for w in &report.warnings {
+            let span = w
+                .span()
+                .cloned()
+                .unwrap_or_else(|| diagnostics::SpanRange::point(0, 0, 0));
+            let diag = diagnostics::Diagnostic::warning(w.to_string(), span);
+            super::emit_diagnostic(&diag, &source, error_format);
+        }
+    }
+    print!("{report}");
+    Ok(())
+}
+
+/// Compile `path` to constraints and return its cost and warnings.
+/// Nothing is written to disk.
+pub fn analyze_circuit(
+    path: &str,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    error_format: ErrorFormat,
+) -> Result<AnalysisReport> {
+    match prime_id {
+        PrimeId::Bn254 => analyze_inner::<memory::Bn254Fr>(path, opts, prime_id, error_format),
+        PrimeId::Bls12_381 => {
+            analyze_inner::<memory::Bls12_381Fr>(path, opts, prime_id, error_format)
+        }
+        PrimeId::Goldilocks => {
+            analyze_inner::<memory::GoldilocksFr>(path, opts, prime_id, error_format)
+        }
+        other => Err(anyhow::anyhow!(
+            "prime `{}` is not supported for circuit compilation",
+            other.name()
+        )),
+    }
+}
+
+fn analyze_inner<F: FieldBackend + PoseidonParamsProvider>(
+    path: &str,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    error_format: ErrorFormat,
+) -> Result<AnalysisReport> {
+    let (_, program, mut compiler) = compile_r1cs_file::<F>(path, opts, prime_id, error_format)?;
+    if opts.opt_level > OptLevel::O0 {
+        compiler.optimize_r1cs();
+    }
+
+    let count_inputs = |vis: ir::Visibility| {
+        program
+            .iter()
+            .filter(
+                |i| matches!(i, ir::Instruction::Input { visibility, .. } if *visibility == vis),
+            )
+            .count()
+    };
+    Ok(AnalysisReport {
+        constraints: compiler.cs.num_constraints(),
+        variables: compiler.cs.num_variables(),
+        nonzero_entries: compiler.cs.num_nonzero_entries(),
+        public_inputs: count_inputs(ir::Visibility::Public),
+        witness_inputs: count_inputs(ir::Visibility::Witness),
+        warnings: ir::passes::analyze(&program),
+    })
+}
@@ -0,0 +1,178 @@
+//! `ach check` — report a file's diagnostics without running it.
+//!
+//! Parses the file, compiles it for the VM and, when it declares a
+//! circuit, lowers that circuit through ProveIR → Lysis and runs taint
+//! analysis. Every stage that can run does, so one invocation surfaces
+//! parse errors, compile errors and under-constrained input warnings
+//! together. With `--format json` the result is a single JSON array of
+//! `{severity, message, line, col, code}` objects for editor tooling.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use achronyme_parser::ast::Stmt;
+use anyhow::{Context, Result};
+use diagnostics::{Diagnostic, Severity, SpanRange};
+use ir_forge::ProveIrCompiler;
+use memory::field::PrimeId;
+use memory::FieldBackend;
+
+use super::ErrorFormat;
+
+/// Entry point for `ach check`. Fails when any diagnostic is an error.
+pub fn check_command(
+    path: &str,
+    prime_id: PrimeId,
+    error_format: ErrorFormat,
+    circom_lib_dirs: &[PathBuf],
+) -> Result<()> {
+    let diags = check_file(path, prime_id, circom_lib_dirs)?;
+    if error_format == ErrorFormat::Json {
+        println!("{}", diagnostics_to_json(&diags));
+    } else {
+        let source =
+            fs::read_to_string(path).with_context(|| format!("cannot read source file: {path}"))?;
+        for d in &diags {
+            super::emit_diagnostic(d, &source, error_format);
+        }
+    }
+    match diags
+        .iter()
+        .filter(|d| d.severity == Severity::Error)
+        .count()
+    {
+        0 => Ok(()),
+        1 => Err(anyhow::anyhow!("{path}: 1 error")),
+        n => Err(anyhow::anyhow!("{path}: {n} errors")),
+    }
+}
+
+/// Collect the diagnostics for `path`, in source order.
+pub fn check_file(
+    path: &str,
+    prime_id: PrimeId,
+    circom_lib_dirs: &[PathBuf],
+) -> Result<Vec<Diagnostic>> {
+    let source =
+        fs::read_to_string(path).with_context(|| format!("cannot read source file: {path}"))?;
+    let source_path = Path::new(path);
+
+    let (program, parse_diags) = achronyme_parser::parse_program(&source);
+    let mut diags = parse_diags;
+    if diags.iter().any(|d| d.severity == Severity::Error) {
+        // Later stages would only re-report the first parse error.
+        sort_diagnostics(&mut diags);
+        return Ok(diags);
+    }
+
+    let mut compiler = super::new_compiler();
+    compiler.prime_id = prime_id;
+    compiler.base_path = Some(source_path.parent().unwrap_or(Path::new(".")).to_path_buf());
+    compiler.circom_lib_dirs = circom_lib_dirs.to_vec();
+    if let Ok(canonical) = source_path.canonicalize() {
+        compiler.compiling_modules.insert(canonical);
+    }
+    let compile_error = compiler.compile(&source).err().map(|e| e.to_diagnostic());
+    // The compiler re-reports parser warnings; `push_unique` drops them.
+    for w in compiler.take_warnings() {
+        push_unique(&mut diags, w);
+    }
+
+    let declares_circuit = program
+        .stmts
+        .iter()
+        .any(|s| matches!(s, Stmt::CircuitDecl { .. }));
+    let circuit_diags = match prime_id {
+        _ if !declares_circuit => Vec::new(),
+        PrimeId::Bn254 => check_circuit::<memory::Bn254Fr>(&source, source_path),
+        PrimeId::Bls12_381 => check_circuit::<memory::Bls12_381Fr>(&source, source_path),
+        PrimeId::Goldilocks => check_circuit::<memory::GoldilocksFr>(&source, source_path),
+        _ => Vec::new(),
+    };
+
+    // The VM compiler lowers circuit declarations too, but reports their
+    // errors against the whole declaration. Prefer the precise report.
+    if let Some(err) = compile_error {
+        let outer = &err.primary_span;
+        let covered = circuit_diags.iter().any(|d| {
+            d.severity == Severity::Error
+                && outer.byte_start <= d.primary_span.byte_start
+                && d.primary_span.byte_end <= outer.byte_end
+        });
+        if !covered {
+            push_unique(&mut diags, err);
+        }
+    }
+    for d in circuit_diags {
+        push_unique(&mut diags, d);
+    }
+
+    sort_diagnostics(&mut diags);
+    Ok(diags)
+}
+
+/// Lower the circuit and run taint analysis on the result, keeping the
+/// lowering's own warnings.
+fn check_circuit<F: FieldBackend + constraints::PoseidonParamsProvider>(
+    source: &str,
+    path: &Path,
+) -> Vec<Diagnostic> {
+    let (prove_ir, mut diags) =
+        match ProveIrCompiler::<F>::compile_circuit_with_warnings(source, Some(path)) {
+            Ok(p) => p,
+            Err(e) => return vec![e.to_diagnostic()],
+        };
+    let mut program = match prove_ir.instantiate_lysis::<F>(&std::collections::HashMap::new()) {
+        Ok(p) => p,
+        Err(ir_forge::LysisInstantiateError::Instantiate(e)) => return vec![e.to_diagnostic()],
+        Err(other) => {
+            return vec![Diagnostic::error(
+                other.to_string(),
+                SpanRange::point(0, 0, 0),
+            )]
+        }
+    };
+    ir::passes::optimize(&mut program);
+    diags.extend(ir::passes::analyze(&program).into_iter().map(|w| {
+        let span = w
+            .span()
+            .cloned()
+            .unwrap_or_else(|| SpanRange::point(0, 0, 0));
+        Diagnostic::warning(w.to_string(), span)
+    }));
+    diags
+}
+
+/// Append `diag` unless the same message was already reported at the
+/// same position by an earlier stage.
+fn push_unique(diags: &mut Vec<Diagnostic>, diag: Diagnostic) {
+    let key = |d: &Diagnostic| (d.primary_span.line_start, d.primary_span.col_start);
+    if !diags
+        .iter()
+        .any(|d| d.message == diag.message && key(d) == key(&diag))
+    {
+        diags.push(diag);
+    }
+}
+
+fn sort_diagnostics(diags: &mut [Diagnostic]) {
+    diags.sort_by_key(|d| (d.primary_span.line_start, d.primary_span.col_start));
+}
+
+/// Render `diags` as one JSON array of `{severity, message, line, col,
+/// code}` objects.
+pub fn diagnostics_to_json(diags: &[Diagnostic]) -> String {
+    let entries: Vec<serde_json::Value> = diags
+        .iter()
+        .map(|d| {
+            serde_json::json!({
+                "severity": d.severity.to_string(),
+                "message": d.message,
+                "line": d.primary_span.line_start,
+                "col": d.primary_span.col_start,
+                "code": d.code,
+            })
+        })
+        .collect();
+    serde_json::Value::Array(entries).to_string()
+}
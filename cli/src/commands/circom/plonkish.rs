@@ -81,12 +81,11 @@ pub(super) fn run_plonkish_pipeline<F: FieldBackend + PoseidonParamsProvider>(
                 ));
             }
             let cache_dir = crate::cache_dir();
-            // Safe cast: validated F == Bn254Fr above
-            let compiler_bn254 = unsafe {
-                std::mem::transmute::<PlonkishCompiler<F>, PlonkishCompiler<memory::Bn254Fr>>(
-                    compiler,
-                )
-            };
+            // F == Bn254Fr was validated above, so the downcast succeeds.
+            let compiler: Box<dyn std::any::Any> = Box::new(compiler);
+            let compiler_bn254 = *compiler
+                .downcast::<PlonkishCompiler<memory::Bn254Fr>>()
+                .map_err(|_| anyhow::anyhow!("plonkish compiler is not over BN254"))?;
             let result = proving::halo2_proof::generate_plonkish_proof(compiler_bn254, &cache_dir)
                 .map_err(|e| anyhow::anyhow!("Plonkish proof generation error: {e}"))?;
 
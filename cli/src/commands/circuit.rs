@@ -1,10 +1,13 @@
 mod bn254;
+mod determinism;
 mod entry;
 mod inputs;
+pub(crate) mod pipeline;
 mod plonkish;
 mod r1cs;
 
-pub use entry::circuit_command;
+pub use entry::{circuit_command, CircuitOptions, STDIN_PATH};
+pub use pipeline::CompileOptions;
 
 #[cfg(test)]
 mod tests;
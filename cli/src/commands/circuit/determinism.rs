@@ -0,0 +1,85 @@
+//! `ach circuit --check-determinism` — compile twice and diff.
+//!
+//! The lowering passes keep a lot of state in `HashMap`/`HashSet`, and
+//! every map built in-process gets its own random hash seed. Running the
+//! whole pipeline twice therefore exposes any place where iteration order
+//! leaks into the IR or the R1CS wire layout, which would make `.r1cs`
+//! files (and snarkjs keys derived from them) differ between builds.
+
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use constraints::{write_r1cs, PoseidonParamsProvider};
+use ir::passes::OptLevel;
+use memory::field::PrimeId;
+use memory::FieldBackend;
+
+use super::pipeline::{compile_r1cs, CompileOptions};
+
+/// Everything a build must reproduce exactly: the optimized IR text, the
+/// wire labels in index order and the serialized `.r1cs` bytes.
+struct Build {
+    ir: String,
+    labels: Vec<String>,
+    r1cs: Vec<u8>,
+    constraints: usize,
+}
+
+fn build<F: FieldBackend + PoseidonParamsProvider>(
+    source: &str,
+    path: Option<&Path>,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    render: &dyn Fn(ir_forge::ProveIrError) -> anyhow::Error,
+) -> Result<Build> {
+    let (program, mut compiler) = compile_r1cs::<F>(source, path, opts, prime_id, render)?;
+    if opts.opt_level > OptLevel::O0 {
+        compiler.optimize_r1cs();
+    }
+
+    Ok(Build {
+        ir: program.to_string(),
+        labels: compiler.witness_labels(),
+        r1cs: write_r1cs(&compiler.cs, prime_id),
+        constraints: compiler.cs.num_constraints(),
+    })
+}
+
+/// Compile `source` twice and fail on the first artifact that differs.
+/// Returns the constraint count on success.
+pub(super) fn check_determinism<F: FieldBackend + PoseidonParamsProvider>(
+    source: &str,
+    path: Option<&Path>,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    render: &dyn Fn(ir_forge::ProveIrError) -> anyhow::Error,
+) -> Result<usize> {
+    let first = build::<F>(source, path, opts, prime_id, render)?;
+    let second = build::<F>(source, path, opts, prime_id, render)?;
+
+    if first.ir != second.ir {
+        let line = first
+            .ir
+            .lines()
+            .zip(second.ir.lines())
+            .position(|(a, b)| a != b)
+            .unwrap_or_else(|| first.ir.lines().count().min(second.ir.lines().count()));
+        bail!(
+            "nondeterministic lowering: IR differs between two builds at line {}",
+            line + 1
+        );
+    }
+    if first.labels != second.labels {
+        let wire = first
+            .labels
+            .iter()
+            .zip(&second.labels)
+            .position(|(a, b)| a != b)
+            .unwrap_or_else(|| first.labels.len().min(second.labels.len()));
+        bail!("nondeterministic wire layout: wire {wire} differs between two builds");
+    }
+    if first.r1cs != second.r1cs {
+        bail!("nondeterministic constraints: .r1cs bytes differ between two builds");
+    }
+    Ok(first.constraints)
+}
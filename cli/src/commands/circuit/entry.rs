@@ -1,36 +1,100 @@
 use std::collections::HashMap;
 use std::fs;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use constraints::PoseidonParamsProvider;
-use ir_forge::ProveIrCompiler;
+use ir::passes::OptLevel;
 use memory::field::PrimeId;
 use memory::{FieldBackend, FieldElement};
 
 use super::super::ErrorFormat;
 use super::bn254::Bn254Ops;
-use super::inputs::{parse_inputs, parse_inputs_toml};
+use super::determinism;
+use super::inputs::{parse_input_file, parse_inputs, validate_array_inputs};
+use super::pipeline::{self, CompileOptions};
 use super::plonkish::run_plonkish_pipeline;
 use super::r1cs::run_r1cs_pipeline;
 use crate::style::Styler;
 
-#[allow(clippy::too_many_arguments)]
-pub fn circuit_command(
-    path: &str,
-    r1cs_path: &str,
-    wtns_path: &str,
-    inputs: Option<&str>,
-    input_file: Option<&str>,
-    no_optimize: bool,
-    backend: &str,
-    prime_id: PrimeId,
-    prove: bool,
-    solidity_path: Option<&str>,
-    plonkish_json_path: Option<&str>,
-    dump_ir: bool,
-    circuit_stats: bool,
-    error_format: ErrorFormat,
-) -> Result<()> {
+/// Source path that makes [`circuit_command`] read the circuit from stdin
+/// (`ach circuit -` or `ach circuit --stdin`).
+pub const STDIN_PATH: &str = "-";
+
+/// Name diagnostics and progress output use for a circuit read from stdin.
+const STDIN_NAME: &str = "<stdin>";
+
+/// Settings for [`circuit_command`], one field per `ach circuit` flag.
+/// [`Default`] matches the CLI defaults: R1CS over BN254 at `-O2`,
+/// writing `circuit.r1cs` / `witness.wtns`, with every optional output off.
+#[derive(Clone, Debug)]
+pub struct CircuitOptions<'a> {
+    pub r1cs_path: &'a str,
+    pub wtns_path: &'a str,
+    pub inputs: Option<&'a str>,
+    pub input_file: Option<&'a str>,
+    pub input_format: Option<&'a str>,
+    pub opt_level: OptLevel,
+    pub backend: &'a str,
+    pub prime_id: PrimeId,
+    pub prove: bool,
+    pub solidity_path: Option<&'a str>,
+    pub plonkish_json_path: Option<&'a str>,
+    pub r1cs_json_path: Option<&'a str>,
+    pub sym_path: Option<&'a str>,
+    pub dump_ir: bool,
+    pub circuit_stats: bool,
+    pub dump_witness: bool,
+    pub check_determinism: bool,
+    pub entry: Option<&'a str>,
+    pub max_unroll: Option<u64>,
+    pub error_format: ErrorFormat,
+}
+
+impl Default for CircuitOptions<'_> {
+    fn default() -> Self {
+        Self {
+            r1cs_path: "circuit.r1cs",
+            wtns_path: "witness.wtns",
+            inputs: None,
+            input_file: None,
+            input_format: None,
+            opt_level: OptLevel::O2,
+            backend: "r1cs",
+            prime_id: PrimeId::Bn254,
+            prove: false,
+            solidity_path: None,
+            plonkish_json_path: None,
+            r1cs_json_path: None,
+            sym_path: None,
+            dump_ir: false,
+            circuit_stats: false,
+            dump_witness: false,
+            check_determinism: false,
+            entry: None,
+            max_unroll: None,
+            error_format: ErrorFormat::Human,
+        }
+    }
+}
+
+pub fn circuit_command(path: &str, opts: &CircuitOptions) -> Result<()> {
+    let CircuitOptions {
+        backend,
+        prime_id,
+        solidity_path,
+        plonkish_json_path,
+        r1cs_json_path,
+        sym_path,
+        dump_witness,
+        check_determinism,
+        inputs,
+        input_file,
+        input_format,
+        max_unroll,
+        ..
+    } = *opts;
+
     // 0. Validate flag combinations early (before expensive IR lowering)
     if solidity_path.is_some() && backend != "r1cs" {
         return Err(anyhow::anyhow!(
@@ -50,69 +114,60 @@ pub fn circuit_command(
         ));
     }
 
+    if r1cs_json_path.is_some() && backend != "r1cs" {
+        return Err(anyhow::anyhow!(
+            "--emit-r1cs-json is only supported with the r1cs backend"
+        ));
+    }
+
+    if sym_path.is_some() && backend != "r1cs" {
+        return Err(anyhow::anyhow!(
+            "--emit-sym is only supported with the r1cs backend"
+        ));
+    }
+
     if !matches!(backend, "r1cs" | "plonkish") {
         return Err(anyhow::anyhow!(
             "unknown backend `{backend}` (use \"r1cs\" or \"plonkish\")"
         ));
     }
 
+    if dump_witness && backend != "r1cs" {
+        return Err(anyhow::anyhow!(
+            "--dump-witness is only supported with the r1cs backend"
+        ));
+    }
+
+    if check_determinism && backend != "r1cs" {
+        return Err(anyhow::anyhow!(
+            "--check-determinism is only supported with the r1cs backend"
+        ));
+    }
+
+    if dump_witness && inputs.is_none() && input_file.is_none() {
+        return Err(anyhow::anyhow!(
+            "--dump-witness requires --inputs or --input-file"
+        ));
+    }
+
+    if input_format.is_some() && input_file.is_none() {
+        return Err(anyhow::anyhow!("--input-format requires --input-file"));
+    }
+
     if inputs.is_some() && input_file.is_some() {
         return Err(anyhow::anyhow!(
             "--inputs and --input-file are mutually exclusive"
         ));
     }
 
+    let compile = CompileOptions::from_flags(opts.opt_level, opts.entry, max_unroll);
+
     // Dispatch on prime_id: one match at the CLI boundary, generics carry
     // the concrete field type through the rest of the pipeline.
     match prime_id {
-        PrimeId::Bn254 => circuit_command_inner::<memory::Bn254Fr>(
-            path,
-            r1cs_path,
-            wtns_path,
-            inputs,
-            input_file,
-            no_optimize,
-            backend,
-            prime_id,
-            prove,
-            solidity_path,
-            plonkish_json_path,
-            dump_ir,
-            circuit_stats,
-            error_format,
-        ),
-        PrimeId::Bls12_381 => circuit_command_inner::<memory::Bls12_381Fr>(
-            path,
-            r1cs_path,
-            wtns_path,
-            inputs,
-            input_file,
-            no_optimize,
-            backend,
-            prime_id,
-            prove,
-            solidity_path,
-            plonkish_json_path,
-            dump_ir,
-            circuit_stats,
-            error_format,
-        ),
-        PrimeId::Goldilocks => circuit_command_inner::<memory::GoldilocksFr>(
-            path,
-            r1cs_path,
-            wtns_path,
-            inputs,
-            input_file,
-            no_optimize,
-            backend,
-            prime_id,
-            prove,
-            solidity_path,
-            plonkish_json_path,
-            dump_ir,
-            circuit_stats,
-            error_format,
-        ),
+        PrimeId::Bn254 => circuit_command_inner::<memory::Bn254Fr>(path, opts, &compile),
+        PrimeId::Bls12_381 => circuit_command_inner::<memory::Bls12_381Fr>(path, opts, &compile),
+        PrimeId::Goldilocks => circuit_command_inner::<memory::GoldilocksFr>(path, opts, &compile),
         other => Err(anyhow::anyhow!(
             "prime `{}` is not supported for circuit compilation",
             other.name()
@@ -120,28 +175,37 @@ pub fn circuit_command(
     }
 }
 
-#[allow(clippy::too_many_arguments)]
 fn circuit_command_inner<F: FieldBackend + PoseidonParamsProvider + Bn254Ops>(
     path: &str,
-    r1cs_path: &str,
-    wtns_path: &str,
-    inputs: Option<&str>,
-    input_file: Option<&str>,
-    no_optimize: bool,
-    backend: &str,
-    prime_id: PrimeId,
-    prove: bool,
-    solidity_path: Option<&str>,
-    plonkish_json_path: Option<&str>,
-    dump_ir: bool,
-    circuit_stats: bool,
-    error_format: ErrorFormat,
+    opts: &CircuitOptions,
+    compile: &CompileOptions,
 ) -> Result<()> {
+    let CircuitOptions {
+        r1cs_path,
+        wtns_path,
+        inputs,
+        input_file,
+        input_format,
+        backend,
+        prime_id,
+        prove,
+        solidity_path,
+        plonkish_json_path,
+        r1cs_json_path,
+        sym_path,
+        dump_ir,
+        circuit_stats,
+        dump_witness,
+        check_determinism,
+        error_format,
+        ..
+    } = *opts;
+
     // Resolve inputs from either --inputs or --input-file into a unified map.
-    let resolved_inputs: Option<HashMap<String, FieldElement<F>>> = if let Some(raw) = inputs {
+    let mut resolved_inputs: Option<HashMap<String, FieldElement<F>>> = if let Some(raw) = inputs {
         Some(parse_inputs::<F>(raw)?)
-    } else if let Some(toml_path) = input_file {
-        Some(parse_inputs_toml::<F>(toml_path)?)
+    } else if let Some(file) = input_file {
+        Some(parse_input_file::<F>(file, input_format)?)
     } else {
         None
     };
@@ -149,30 +213,37 @@ fn circuit_command_inner<F: FieldBackend + PoseidonParamsProvider + Bn254Ops>(
     let style = Styler::from_env(&error_format);
     let verbose = style.is_verbose(&error_format);
 
-    let source =
-        fs::read_to_string(path).with_context(|| format!("cannot read source file: {path}"))?;
+    // A stdin circuit has no file: imports resolve against no directory,
+    // artifacts land in the working directory, and diagnostics name it
+    // `<stdin>`.
+    let from_stdin = path == STDIN_PATH;
+    let (source, source_path, display_path) = if from_stdin {
+        let source = std::io::read_to_string(std::io::stdin())
+            .context("cannot read circuit source from stdin")?;
+        (source, None, STDIN_NAME)
+    } else {
+        let source =
+            fs::read_to_string(path).with_context(|| format!("cannot read source file: {path}"))?;
+        (source, Some(Path::new(path)), path)
+    };
 
-    let source_dir = std::path::Path::new(path)
-        .parent()
-        .unwrap_or(std::path::Path::new("."))
+    let source_dir = source_path
+        .and_then(Path::parent)
+        .unwrap_or(Path::new("."))
         .to_path_buf();
 
     let render_prove_ir_error = |e: ir_forge::ProveIrError| -> anyhow::Error {
-        let diag = e.to_diagnostic();
+        let mut diag = e.to_diagnostic();
+        if from_stdin && diag.primary_span.file.is_none() {
+            diag.primary_span.file = Some(PathBuf::from(STDIN_NAME));
+        }
         let rendered = super::super::render_diagnostic(&diag, &source, error_format);
         anyhow::anyhow!("{rendered}")
     };
 
-    let render_lysis_instantiate_error = |e: ir_forge::LysisInstantiateError| -> anyhow::Error {
-        match e {
-            ir_forge::LysisInstantiateError::Instantiate(inner) => render_prove_ir_error(inner),
-            other => anyhow::anyhow!("{other}"),
-        }
-    };
-
-    let file_name = std::path::Path::new(path)
+    let file_name = Path::new(display_path)
         .file_name()
-        .unwrap_or(std::ffi::OsStr::new(path))
+        .unwrap_or(std::ffi::OsStr::new(display_path))
         .to_string_lossy();
 
     if verbose {
@@ -184,20 +255,34 @@ fn circuit_command_inner<F: FieldBackend + PoseidonParamsProvider + Bn254Ops>(
     }
 
     // 1. Compile to ProveIR and instantiate to IR SSA via Lysis.
-    let source_path = std::path::Path::new(path);
-    let prove_ir = ProveIrCompiler::<F>::compile_circuit(&source, Some(source_path))
-        .map_err(render_prove_ir_error)?;
-    let mut program = prove_ir
-        .instantiate_lysis(&std::collections::HashMap::new())
-        .map_err(render_lysis_instantiate_error)?;
+    if check_determinism {
+        let n = determinism::check_determinism::<F>(
+            &source,
+            source_path,
+            compile,
+            prime_id,
+            &render_prove_ir_error,
+        )?;
+        println!("{file_name}: deterministic ({n} constraints, 2 builds identical)");
+        return Ok(());
+    }
+    let (prove_ir, mut program, lower_warnings) =
+        pipeline::lower::<F>(&source, source_path, compile, &render_prove_ir_error)?;
+    for diag in &lower_warnings {
+        super::super::emit_diagnostic(diag, &source, error_format);
+    }
+    if let Some(map) = resolved_inputs.as_ref() {
+        validate_array_inputs(&prove_ir.public_inputs, map)?;
+        validate_array_inputs(&prove_ir.witness_inputs, map)?;
+    }
 
     if verbose {
         eprintln!("    {}: {} instructions", style.cyan("IR"), program.len());
     }
 
-    // 2. Optimize (unless --no-optimize)
-    if !no_optimize {
-        let stats = ir::passes::optimize(&mut program);
+    // 2. Optimize (unless --no-optimize / --opt-level 0)
+    if compile.opt_level > OptLevel::O0 {
+        let stats = ir::passes::optimize_with(&mut program, compile.opt_level);
         let eliminated = stats.const_fold_converted
             + stats.dce_eliminated
             + stats.tautological_asserts_eliminated;
@@ -267,7 +352,7 @@ fn circuit_command_inner<F: FieldBackend + PoseidonParamsProvider + Bn254Ops>(
 
     // 3. If --dump-ir, print the IR and exit
     if dump_ir {
-        println!("== Circuit IR for {} ==\n", path);
+        println!("== Circuit IR for {} ==\n", display_path);
         print!("{program}");
         let n = program.len();
         let n_inputs = program
@@ -282,6 +367,16 @@ fn circuit_command_inner<F: FieldBackend + PoseidonParamsProvider + Bn254Ops>(
         return Ok(());
     }
 
+    // Declared defaults (`witness x = 5`) fill inputs left unsupplied;
+    // `output` values are computed from the circuit unless supplied.
+    if let Some(map) = resolved_inputs.as_mut() {
+        for (name, value) in &program.input_defaults {
+            map.entry(name.clone()).or_insert(*value);
+        }
+        ir::eval::fill_outputs(&program, &prove_ir.outputs, map)
+            .map_err(|e| anyhow::anyhow!("cannot compute circuit output: {e}"))?;
+    }
+
     // 4. Analyze for under-constrained inputs
     let warnings = ir::passes::analyze(&program);
     for w in &warnings {
@@ -305,8 +400,8 @@ fn circuit_command_inner<F: FieldBackend + PoseidonParamsProvider + Bn254Ops>(
 
     // Circuit stats profiler
     if circuit_stats {
-        let name = std::path::Path::new(path)
-            .file_stem()
+        let name = source_path
+            .and_then(Path::file_stem)
             .map(|s| s.to_string_lossy().into_owned());
         let stats = ir::stats::CircuitStats::from_program(&program, &proven, name.as_deref());
         eprintln!("{stats}");
@@ -320,9 +415,12 @@ fn circuit_command_inner<F: FieldBackend + PoseidonParamsProvider + Bn254Ops>(
             resolved_inputs.as_ref(),
             prime_id,
             solidity_path,
+            r1cs_json_path,
+            sym_path,
             &style,
             verbose,
-            no_optimize,
+            compile.opt_level == OptLevel::O0,
+            dump_witness,
             &proven,
         ),
         "plonkish" => run_plonkish_pipeline(
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::fs;
 
 use anyhow::{Context, Result};
+use ir_forge::types::{ArraySize, ProveInputDecl};
 use memory::{FieldBackend, FieldElement};
 
 pub(super) fn parse_inputs<F: FieldBackend>(raw: &str) -> Result<HashMap<String, FieldElement<F>>> {
@@ -46,6 +47,104 @@ fn parse_field_value<F: FieldBackend>(name: &str, val_str: &str) -> Result<Field
     }
 }
 
+/// Parse an `--input-file` in `format` (`"toml"` or `"csv"`), or in the
+/// format implied by its extension when none is given (`.csv` is CSV,
+/// anything else TOML).
+pub(super) fn parse_input_file<F: FieldBackend>(
+    path: &str,
+    format: Option<&str>,
+) -> Result<HashMap<String, FieldElement<F>>> {
+    let format = format.unwrap_or_else(|| {
+        let is_csv = std::path::Path::new(path)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+        if is_csv {
+            "csv"
+        } else {
+            "toml"
+        }
+    });
+    match format {
+        "toml" => parse_inputs_toml(path),
+        "csv" => parse_inputs_csv(path),
+        other => Err(anyhow::anyhow!(
+            "unknown input format `{other}` (use \"toml\" or \"csv\")"
+        )),
+    }
+}
+
+/// Insert an input, rejecting a name that is already present — e.g. a
+/// repeated CSV column, or `path_0` next to an array `path` that expands
+/// to it.
+fn insert_unique<F: FieldBackend>(
+    map: &mut HashMap<String, FieldElement<F>>,
+    name: String,
+    value: FieldElement<F>,
+    path: &str,
+) -> Result<()> {
+    if map.contains_key(&name) {
+        return Err(anyhow::anyhow!(
+            "input `{name}` is given more than once in {path}"
+        ));
+    }
+    map.insert(name, value);
+    Ok(())
+}
+
+/// Parse a CSV input file: a header row of names and one row of values.
+///
+/// Array elements are columns named `path[0]`, `path[1]`, … (or
+/// `path_0`, `path_1`, …), which expand to `path_0 = …` as in TOML.
+/// Values may be double-quoted.
+pub(super) fn parse_inputs_csv<F: FieldBackend>(
+    path: &str,
+) -> Result<HashMap<String, FieldElement<F>>> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("cannot read input file: {path}"))?;
+    let mut rows = content.lines().filter(|l| !l.trim().is_empty());
+    let split = |line: &str| -> Vec<String> {
+        line.split(',')
+            .map(|cell| cell.trim().trim_matches('"').trim().to_string())
+            .collect()
+    };
+    let header = rows
+        .next()
+        .map(split)
+        .with_context(|| format!("empty CSV input file: {path}"))?;
+    let values = rows
+        .next()
+        .map(split)
+        .with_context(|| format!("CSV input file {path} has a header but no data row"))?;
+    if rows.next().is_some() {
+        return Err(anyhow::anyhow!(
+            "CSV input file {path} must have exactly one data row"
+        ));
+    }
+    if header.len() != values.len() {
+        return Err(anyhow::anyhow!(
+            "CSV input file {path} has {} columns in the header but {} values",
+            header.len(),
+            values.len()
+        ));
+    }
+
+    let mut map = HashMap::new();
+    for (column, value) in header.iter().zip(&values) {
+        let name = match column.strip_suffix(']').and_then(|c| c.split_once('[')) {
+            Some((array, index)) if index.parse::<usize>().is_ok() => format!("{array}_{index}"),
+            _ => column.clone(),
+        };
+        if name.is_empty() || name.contains(['[', ']']) {
+            return Err(anyhow::anyhow!(
+                "invalid CSV column name {column:?} in {path}"
+            ));
+        }
+        let fe = parse_field_value::<F>(&name, value)?;
+        insert_unique(&mut map, name, fe, path)?;
+    }
+    Ok(map)
+}
+
 /// Parse a TOML input file into a flat map of name → FieldElement.
 ///
 /// Scalars:  `name = "42"` or `name = "0xFF"`
@@ -64,7 +163,7 @@ pub(super) fn parse_inputs_toml<F: FieldBackend>(
         match value {
             toml::Value::String(s) => {
                 let fe = parse_field_value::<F>(key, s)?;
-                map.insert(key.clone(), fe);
+                insert_unique(&mut map, key.clone(), fe, path)?;
             }
             toml::Value::Integer(n) => {
                 let fe = if *n < 0 {
@@ -74,7 +173,7 @@ pub(super) fn parse_inputs_toml<F: FieldBackend>(
                 } else {
                     FieldElement::<F>::from_u64(*n as u64)
                 };
-                map.insert(key.clone(), fe);
+                insert_unique(&mut map, key.clone(), fe, path)?;
             }
             toml::Value::Array(arr) => {
                 for (i, elem) in arr.iter().enumerate() {
@@ -82,7 +181,7 @@ pub(super) fn parse_inputs_toml<F: FieldBackend>(
                     match elem {
                         toml::Value::String(s) => {
                             let fe = parse_field_value::<F>(&elem_name, s)?;
-                            map.insert(elem_name, fe);
+                            insert_unique(&mut map, elem_name, fe, path)?;
                         }
                         toml::Value::Integer(n) => {
                             let fe = if *n < 0 {
@@ -92,7 +191,7 @@ pub(super) fn parse_inputs_toml<F: FieldBackend>(
                             } else {
                                 FieldElement::<F>::from_u64(*n as u64)
                             };
-                            map.insert(elem_name, fe);
+                            insert_unique(&mut map, elem_name, fe, path)?;
                         }
                         _ => {
                             return Err(anyhow::anyhow!(
@@ -111,3 +210,41 @@ pub(super) fn parse_inputs_toml<F: FieldBackend>(
     }
     Ok(map)
 }
+
+/// Check that every literal-sized array input (`Field[N]`, `Bool[N]`)
+/// received all `N` elements (`name_0` … `name_{N-1}`).
+///
+/// Missing elements would otherwise surface later as an opaque witness
+/// error. Surplus ones (e.g. a TOML array that is too long) are left to
+/// witness generation, which ignores them with a warning.
+pub(super) fn validate_array_inputs<F: FieldBackend>(
+    decls: &[ProveInputDecl],
+    inputs: &HashMap<String, FieldElement<F>>,
+) -> Result<()> {
+    for decl in decls {
+        let Some(ArraySize::Literal(size)) = decl.array_size else {
+            continue;
+        };
+        let prefix = format!("{}_", decl.name);
+        let provided = inputs
+            .keys()
+            .filter_map(|k| k.strip_prefix(&prefix)?.parse::<usize>().ok())
+            .collect::<Vec<_>>();
+        if provided.is_empty() {
+            // Nothing supplied for this array: leave it to witness
+            // generation to report the missing input.
+            continue;
+        }
+        if let Some(missing) = (0..size).find(|i| !provided.contains(i)) {
+            let ty = decl.ir_type;
+            return Err(anyhow::anyhow!(
+                "input `{}` is declared {ty}[{size}] but only {} element(s) were provided \
+                 (missing `{}_{missing}`)",
+                decl.name,
+                provided.iter().filter(|&&i| i < size).count(),
+                decl.name
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,146 @@
+//! The compile pipeline shared by every command that turns a circuit into
+//! constraints: ProveIR → Lysis → optimize → R1CS.
+//!
+//! `ach circuit` drives the stages itself so it can report optimizer
+//! stats and branch to the Plonkish backend; `watch`, `profile`,
+//! `analyze`, `explain` and `--check-determinism` go through
+//! [`compile_r1cs`], so the `--opt-level`, `--entry` and `--max-unroll`
+//! flags mean the same thing everywhere.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use constraints::PoseidonParamsProvider;
+use diagnostics::Diagnostic;
+use ir::lower::MAX_UNROLL_ITERATIONS;
+use ir::passes::OptLevel;
+use ir_forge::{ProveIR, ProveIrCompiler, ProveIrError, MAX_LOOP_ITERATIONS};
+use memory::field::PrimeId;
+use memory::FieldBackend;
+use zkc::r1cs_backend::R1CSCompiler;
+
+use super::super::ErrorFormat;
+
+/// How a circuit is compiled, independent of what the command does with
+/// the result. [`Default`] matches the CLI defaults: `-O2`, the file's
+/// only circuit, and the standard unroll limit.
+#[derive(Clone, Copy, Debug)]
+pub struct CompileOptions<'a> {
+    pub opt_level: OptLevel,
+    pub entry: Option<&'a str>,
+    pub max_unroll: u64,
+}
+
+impl Default for CompileOptions<'_> {
+    fn default() -> Self {
+        Self {
+            opt_level: OptLevel::O2,
+            entry: None,
+            max_unroll: MAX_UNROLL_ITERATIONS,
+        }
+    }
+}
+
+impl<'a> CompileOptions<'a> {
+    /// Options from the raw command-line flags. An out-of-range
+    /// `--max-unroll` falls back to the default with a warning.
+    pub fn from_flags(
+        opt_level: OptLevel,
+        entry: Option<&'a str>,
+        max_unroll: Option<u64>,
+    ) -> Self {
+        Self {
+            opt_level,
+            entry,
+            max_unroll: resolve_max_unroll(max_unroll),
+        }
+    }
+}
+
+/// The default matches the legacy lowering's unroll limit; the flag may
+/// raise it up to the compiler's hard ceiling. Zero or anything past that
+/// can't be what the user meant, so keep the default rather than failing
+/// the build.
+fn resolve_max_unroll(max_unroll: Option<u64>) -> u64 {
+    match max_unroll {
+        Some(n) if n == 0 || n > MAX_LOOP_ITERATIONS => {
+            eprintln!(
+                "warning: ignoring --max-unroll {n} (expected 1..={MAX_LOOP_ITERATIONS}), \
+                 using the default of {MAX_UNROLL_ITERATIONS}"
+            );
+            MAX_UNROLL_ITERATIONS
+        }
+        Some(n) => n,
+        None => MAX_UNROLL_ITERATIONS,
+    }
+}
+
+/// Compile `source` to ProveIR and instantiate it to (unoptimized) IR,
+/// returning the lowering's non-fatal diagnostics alongside.
+/// `render` turns compile errors into the command's diagnostics.
+pub(crate) fn lower<F: FieldBackend>(
+    source: &str,
+    path: Option<&Path>,
+    opts: &CompileOptions,
+    render: &dyn Fn(ProveIrError) -> anyhow::Error,
+) -> Result<(ProveIR, ir::IrProgram<F>, Vec<Diagnostic>)> {
+    let (prove_ir, warnings) = ProveIrCompiler::<F>::compile_circuit_entry_with_max_unroll(
+        source,
+        path,
+        opts.entry,
+        opts.max_unroll,
+    )
+    .map_err(render)?;
+    let program = prove_ir
+        .instantiate_lysis(&std::collections::HashMap::new())
+        .map_err(|e| match e {
+            ir_forge::LysisInstantiateError::Instantiate(inner) => render(inner),
+            other => anyhow::anyhow!("{other}"),
+        })?;
+    Ok((prove_ir, program, warnings))
+}
+
+/// Lower and optimize `source`, then emit its R1CS constraints. Linear
+/// constraint elimination is left to the caller (`profile` attributes
+/// constraints before it runs); `ach circuit` runs it above `-O0`.
+pub(crate) fn compile_r1cs<F: FieldBackend + PoseidonParamsProvider>(
+    source: &str,
+    path: Option<&Path>,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    render: &dyn Fn(ProveIrError) -> anyhow::Error,
+) -> Result<(ir::IrProgram<F>, R1CSCompiler<F>)> {
+    let (_, mut program, _) = lower::<F>(source, path, opts, render)?;
+    if opts.opt_level > OptLevel::O0 {
+        ir::passes::optimize_with(&mut program, opts.opt_level);
+    }
+
+    let mut compiler = R1CSCompiler::<F>::new();
+    compiler.prime_id = prime_id;
+    compiler.set_proven_boolean(ir::passes::bool_prop::compute_proven_boolean(&program));
+    compiler
+        .compile_ir(&program)
+        .map_err(|e| anyhow::anyhow!("R1CS compilation error: {e}"))?;
+    Ok((program, compiler))
+}
+
+/// [`compile_r1cs`] on the file at `path`, rendering errors against its
+/// source in `error_format`. Returns the source alongside the result.
+pub(crate) fn compile_r1cs_file<F: FieldBackend + PoseidonParamsProvider>(
+    path: &str,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    error_format: ErrorFormat,
+) -> Result<(String, ir::IrProgram<F>, R1CSCompiler<F>)> {
+    let source =
+        fs::read_to_string(path).with_context(|| format!("cannot read source file: {path}"))?;
+    let render = |e: ProveIrError| -> anyhow::Error {
+        let diag = e.to_diagnostic();
+        let rendered = super::super::render_diagnostic(&diag, &source, error_format);
+        anyhow::anyhow!("{rendered}")
+    };
+    let (program, compiler) =
+        compile_r1cs::<F>(&source, Some(Path::new(path)), opts, prime_id, &render)?;
+    Ok((source, program, compiler))
+}
@@ -28,6 +28,9 @@ pub(super) fn run_plonkish_pipeline<F: FieldBackend + PoseidonParamsProvider + B
         compiler
             .compile_ir_with_witness(program, input_map)
             .map_err(|e| anyhow::anyhow!("Plonkish compilation error: {e}"))?;
+        for w in &compiler.input_warnings {
+            eprintln!("{}: {w}", style.warning("warning"));
+        }
 
         compiler
             .system
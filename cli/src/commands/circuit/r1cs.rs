@@ -3,7 +3,7 @@ use std::fs;
 
 use anyhow::{Context, Result};
 use constraints::PoseidonParamsProvider;
-use constraints::{write_r1cs, write_wtns};
+use constraints::{write_r1cs, write_r1cs_json, write_sym, write_wtns};
 use memory::field::PrimeId;
 use memory::{FieldBackend, FieldElement};
 use zkc::r1cs_backend::R1CSCompiler;
@@ -19,9 +19,12 @@ pub(super) fn run_r1cs_pipeline<F: FieldBackend + PoseidonParamsProvider + Bn254
     inputs: Option<&HashMap<String, FieldElement<F>>>,
     prime_id: PrimeId,
     solidity_path: Option<&str>,
+    r1cs_json_path: Option<&str>,
+    sym_path: Option<&str>,
     style: &Styler,
     verbose: bool,
     no_optimize: bool,
+    dump_witness: bool,
     proven: &std::collections::HashSet<ir::SsaVar>,
 ) -> Result<()> {
     let mut compiler = R1CSCompiler::<F>::new();
@@ -59,6 +62,9 @@ pub(super) fn run_r1cs_pipeline<F: FieldBackend + PoseidonParamsProvider + Bn254
         let mut witness_vec = compiler
             .compile_ir_with_witness(program, input_map)
             .map_err(|e| anyhow::anyhow!("R1CS compilation error: {e}"))?;
+        for w in &compiler.input_warnings {
+            eprintln!("{}: {w}", style.warning("warning"));
+        }
 
         // R1CS linear constraint elimination
         if !no_optimize {
@@ -82,6 +88,18 @@ pub(super) fn run_r1cs_pipeline<F: FieldBackend + PoseidonParamsProvider + Bn254
             }
         }
 
+        // Dump before verifying so a failing witness can still be inspected.
+        if dump_witness {
+            for (i, (label, value)) in compiler
+                .witness_labels()
+                .iter()
+                .zip(&witness_vec)
+                .enumerate()
+            {
+                println!("{i}: {label} = {value}");
+            }
+        }
+
         if let Err(e) = compiler.cs.verify(&witness_vec) {
             let mut msg = format!("witness verification failed: {e}");
             if let constraints::r1cs::ConstraintError::ConstraintUnsatisfied(idx) = &e {
@@ -217,6 +235,34 @@ pub(super) fn run_r1cs_pipeline<F: FieldBackend + PoseidonParamsProvider + Bn254
         }
     }
 
+    if let Some(json_path) = r1cs_json_path {
+        let json = write_r1cs_json(&compiler.cs, prime_id);
+        fs::write(json_path, &json).with_context(|| format!("cannot write {json_path}"))?;
+        if verbose {
+            eprintln!(
+                "    Wrote {} ({} bytes)",
+                style.bold(json_path),
+                format_number(json.len())
+            );
+        } else {
+            eprintln!("wrote {} ({} bytes)", json_path, json.len());
+        }
+    }
+
+    if let Some(sym_path) = sym_path {
+        let sym = write_sym(&compiler.witness_labels());
+        fs::write(sym_path, &sym).with_context(|| format!("cannot write {sym_path}"))?;
+        if verbose {
+            eprintln!(
+                "    Wrote {} ({} bytes)",
+                style.bold(sym_path),
+                format_number(sym.len())
+            );
+        } else {
+            eprintln!("wrote {} ({} bytes)", sym_path, sym.len());
+        }
+    }
+
     // Generate Solidity verifier if requested (BN254-only, validated by caller)
     if let Some(sol_path) = solidity_path {
         let cache_dir = crate::cache_dir();
@@ -2,7 +2,9 @@ use std::collections::HashMap;
 
 use memory::FieldElement;
 
-use super::inputs::{parse_inputs, parse_inputs_toml};
+use super::inputs::{
+    parse_input_file, parse_inputs, parse_inputs_csv, parse_inputs_toml, validate_array_inputs,
+};
 
 // Type alias to constrain F = Bn254Fr in tests (avoids turbofish noise).
 type Fe = FieldElement;
@@ -126,3 +128,122 @@ fn toml_invalid_type_rejected() {
 fn toml_file_not_found() {
     assert!(parse_inputs_toml::<memory::Bn254Fr>("/tmp/nonexistent_ach_inputs.toml").is_err());
 }
+
+#[test]
+fn toml_array_and_expanded_name_conflict() {
+    let f = write_toml("xs = [\"1\", \"2\"]\nxs_0 = \"3\"");
+    let err = parse_inputs_toml::<memory::Bn254Fr>(f.path().to_str().unwrap()).unwrap_err();
+    assert!(
+        err.to_string().contains("`xs_0` is given more than once"),
+        "{err}"
+    );
+}
+
+// --- parse_inputs_csv tests ---
+
+fn write_csv(content: &str) -> tempfile::NamedTempFile {
+    use std::io::Write;
+    let mut f = tempfile::NamedTempFile::with_suffix(".csv").unwrap();
+    f.write_all(content.as_bytes()).unwrap();
+    f.flush().unwrap();
+    f
+}
+
+#[test]
+fn csv_scalars_and_arrays() {
+    let f = write_csv("root,path[0],path[1],neg\n\"999\",0x2,3,-1\n");
+    let map: HashMap<String, Fe> = parse_inputs_csv(f.path().to_str().unwrap()).unwrap();
+    assert_eq!(map.len(), 4);
+    assert_eq!(map["root"], Fe::from_u64(999));
+    assert_eq!(map["path_0"], Fe::from_u64(2));
+    assert_eq!(map["path_1"], Fe::from_u64(3));
+    assert_eq!(map["neg"], Fe::from_u64(1).neg());
+}
+
+#[test]
+fn csv_duplicate_column_rejected() {
+    let f = write_csv("xs[0],xs_0\n1,2\n");
+    let err = parse_inputs_csv::<memory::Bn254Fr>(f.path().to_str().unwrap()).unwrap_err();
+    assert!(
+        err.to_string().contains("`xs_0` is given more than once"),
+        "{err}"
+    );
+}
+
+#[test]
+fn csv_requires_exactly_one_data_row() {
+    let f = write_csv("x\n");
+    assert!(parse_inputs_csv::<memory::Bn254Fr>(f.path().to_str().unwrap()).is_err());
+    let f = write_csv("x\n1\n2\n");
+    assert!(parse_inputs_csv::<memory::Bn254Fr>(f.path().to_str().unwrap()).is_err());
+}
+
+#[test]
+fn csv_column_count_mismatch_rejected() {
+    let f = write_csv("x,y\n1\n");
+    assert!(parse_inputs_csv::<memory::Bn254Fr>(f.path().to_str().unwrap()).is_err());
+}
+
+#[test]
+fn input_file_toml_and_csv_fixtures_agree() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../test/circuit");
+    let toml: HashMap<String, Fe> =
+        parse_input_file(&format!("{dir}/fold_sum.inputs.toml"), None).unwrap();
+    let csv: HashMap<String, Fe> =
+        parse_input_file(&format!("{dir}/fold_sum.inputs.csv"), None).unwrap();
+    assert_eq!(toml.len(), 5);
+    assert_eq!(toml, csv);
+}
+
+#[test]
+fn input_file_explicit_format_overrides_extension() {
+    let f = write_toml("x,y\n1,2\n");
+    let map: HashMap<String, Fe> =
+        parse_input_file(f.path().to_str().unwrap(), Some("csv")).unwrap();
+    assert_eq!(map["y"], Fe::from_u64(2));
+    let err =
+        parse_input_file::<memory::Bn254Fr>(f.path().to_str().unwrap(), Some("json")).unwrap_err();
+    assert!(
+        err.to_string().contains("unknown input format `json`"),
+        "{err}"
+    );
+}
+
+fn array_decl(name: &str, size: usize) -> ir_forge::types::ProveInputDecl {
+    ir_forge::types::ProveInputDecl {
+        name: name.into(),
+        array_size: Some(ir_forge::types::ArraySize::Literal(size)),
+        ir_type: ir::IrType::Bool,
+        default: None,
+    }
+}
+
+#[test]
+fn validate_array_inputs_exact_size_ok() {
+    let map: HashMap<String, Fe> = parse_inputs("bits_0=1,bits_1=0").unwrap();
+    assert!(validate_array_inputs(&[array_decl("bits", 2)], &map).is_ok());
+}
+
+#[test]
+fn validate_array_inputs_surplus_element_left_to_witness() {
+    // Witness generation warns about `bits_2`; it must not mask a real gap.
+    let map: HashMap<String, Fe> = parse_inputs("bits_0=1,bits_1=0,bits_2=1").unwrap();
+    assert!(validate_array_inputs(&[array_decl("bits", 2)], &map).is_ok());
+
+    let map: HashMap<String, Fe> = parse_inputs("bits_1=0,bits_2=1").unwrap();
+    let err = validate_array_inputs(&[array_decl("bits", 2)], &map)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Bool[2]"), "got: {err}");
+    assert!(err.contains("only 1 element(s)"), "got: {err}");
+    assert!(err.contains("missing `bits_0`"), "got: {err}");
+}
+
+#[test]
+fn validate_array_inputs_missing_element_rejected() {
+    let map: HashMap<String, Fe> = parse_inputs("bits_1=0").unwrap();
+    let err = validate_array_inputs(&[array_decl("bits", 2)], &map)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("missing `bits_0`"), "got: {err}");
+}
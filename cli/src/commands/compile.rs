@@ -30,17 +30,12 @@ pub fn compile_file(
 
         let mut file = fs::File::create(out_path).context("Failed to create output file")?;
 
-        file.write_all(b"ACH\x0B")?;
-
-        // PrimeId (v0x0B+): identifies which prime field was used
-        file.write_u8(prime_id.to_byte())?;
-
-        // Metadata
+        // Header: magic, version, flags, PrimeId, main max_slots
         let main_func = compiler
             .compilers
             .last()
             .ok_or_else(|| anyhow::anyhow!("compiler has no main function"))?;
-        file.write_u16::<LittleEndian>(main_func.max_slots)?;
+        memory::BinaryHeader::new(prime_id, main_func.max_slots).write(&mut file)?;
 
         // --- String Table ---
         let strings = &compiler.interner.strings;
@@ -1,12 +1,21 @@
 use akron::opcode::{instruction::*, OpCode};
+use akron::VM;
 use anyhow::{Context, Result};
+use memory::Value;
+use std::collections::HashMap;
+use std::fmt::Write as _;
 use std::fs;
+use std::io::Read;
 
 use achronyme_parser::ast::*;
 
 use super::ErrorFormat;
 
 pub fn disassemble_file(path: &str, error_format: ErrorFormat) -> Result<()> {
+    if is_achb(path)? {
+        return disassemble_binary(path);
+    }
+
     let content = fs::read_to_string(path).context("Failed to read file")?;
 
     // Parse AST to detect circuit mode
@@ -161,6 +170,34 @@ fn print_circuit_ir(
 // ---------------------------------------------------------------------------
 
 fn disassemble_vm(path: &str, source: &str, error_format: ErrorFormat) -> Result<()> {
+    let listing = source_listing(source, error_format)?;
+    print_listing(path, &listing);
+    Ok(())
+}
+
+/// Disassemble a compiled `.achb` binary without recompiling anything.
+fn disassemble_binary(path: &str) -> Result<()> {
+    let listing = binary_listing(path)?;
+    print_listing(path, &listing);
+    Ok(())
+}
+
+fn print_listing(path: &str, listing: &Listing) {
+    println!("== Disassembly of {} ==", path);
+    print!("{}", listing.instructions);
+    // ── Dump ProveIR for each PROVE instruction ─────────────────────────
+    print!("{}", listing.prove_blocks);
+}
+
+/// Rendered disassembly of a main function.
+struct Listing {
+    /// One line per bytecode instruction.
+    instructions: String,
+    /// ProveIR dump for every PROVE instruction (empty if none).
+    prove_blocks: String,
+}
+
+fn source_listing(source: &str, error_format: ErrorFormat) -> Result<Listing> {
     let mut compiler = super::new_compiler();
     let bytecode = compiler.compile(source).map_err(|e| {
         let rendered = super::render_compile_error(&e, source, error_format);
@@ -169,12 +206,86 @@ fn disassemble_vm(path: &str, source: &str, error_format: ErrorFormat) -> Result
 
     super::print_warnings(&mut compiler, source, error_format);
 
-    let mut inv_globals = std::collections::HashMap::new();
+    let main_func = compiler
+        .compilers
+        .last()
+        .ok_or_else(|| anyhow::anyhow!("compiler has no main function"))?;
+
+    let mut globals = HashMap::new();
     for (name, entry) in &compiler.global_symbols {
-        inv_globals.insert(entry.index, name);
+        globals.insert(entry.index, name.clone());
     }
 
-    println!("== Disassembly of {} ==", path);
+    Ok(Listing {
+        instructions: format_instructions(&bytecode, &main_func.constants, &globals, |h| {
+            compiler
+                .interner
+                .strings
+                .get(h as usize)
+                .map(String::as_str)
+        }),
+        prove_blocks: format_prove_blocks(&bytecode, &main_func.constants, |h| {
+            compiler
+                .bytes_interner
+                .blobs
+                .get(h as usize)
+                .map(Vec::as_slice)
+        }),
+    })
+}
+
+/// Constants, strings and ProveIR blobs come straight from the loaded
+/// heap; global names come from the debug-symbol sidecar when present.
+fn binary_listing(path: &str) -> Result<Listing> {
+    let mut vm = VM::new();
+    let main_func = super::load_achb(&mut vm, path)?;
+    let globals = vm.debug_symbols.clone().unwrap_or_default();
+
+    Ok(Listing {
+        instructions: format_instructions(&main_func.chunk, &main_func.constants, &globals, |h| {
+            vm.heap.get_string(h).map(String::as_str)
+        }),
+        prove_blocks: format_prove_blocks(&main_func.chunk, &main_func.constants, |h| {
+            vm.heap.get_bytes(h).map(Vec::as_slice)
+        }),
+    })
+}
+
+/// Return the instruction listing for `path` (source or `.achb`), without
+/// the header or the ProveIR dump.
+pub fn vm_listing(path: &str, error_format: ErrorFormat) -> Result<String> {
+    let listing = if is_achb(path)? {
+        binary_listing(path)?
+    } else {
+        let source = fs::read_to_string(path).context("Failed to read file")?;
+        source_listing(&source, error_format)?
+    };
+    Ok(listing.instructions)
+}
+
+/// Detect a compiled binary by its `ACH` magic. The loader validates the
+/// version byte, so anything starting with `ACH` is routed there.
+fn is_achb(path: &str) -> Result<bool> {
+    let mut file = fs::File::open(path).context("Failed to read file")?;
+    let mut magic = [0u8; 3];
+    match file.read_exact(&mut magic) {
+        Ok(()) => Ok(&magic == memory::binary::MAGIC),
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(false),
+        Err(e) => Err(e).context("Failed to read file"),
+    }
+}
+
+/// Render one line per instruction of `bytecode`.
+///
+/// `string_at` resolves a string handle from the constant pool; it is
+/// the compiler interner for source input and the VM heap for binaries.
+fn format_instructions<'a>(
+    bytecode: &[u32],
+    constants: &[Value],
+    globals: &HashMap<u16, String>,
+    string_at: impl Fn(u32) -> Option<&'a str>,
+) -> String {
+    let mut out = String::new();
     for (i, inst) in bytecode.iter().enumerate() {
         let op_byte = decode_opcode(*inst);
         let name = OpCode::from_u8(op_byte)
@@ -188,69 +299,61 @@ fn disassemble_vm(path: &str, source: &str, error_format: ErrorFormat) -> Result
 
         match OpCode::from_u8(op_byte) {
             Some(OpCode::LoadConst) => {
-                let main_func = compiler
-                    .compilers
-                    .last()
-                    .ok_or_else(|| anyhow::anyhow!("compiler has no main function"))?;
-                let val_opt = main_func.constants.get(bx as usize);
-
-                let val_str = if let Some(val) = val_opt {
-                    if val.is_string() {
+                let val_str = match constants.get(bx as usize) {
+                    Some(val) if val.is_string() => {
                         let handle = val.as_handle().unwrap();
-                        if let Some(s) = compiler.interner.strings.get(handle as usize) {
-                            format!("\"{}\"", s)
-                        } else {
-                            format!("{:?}", val)
+                        match string_at(handle) {
+                            Some(s) => format!("\"{}\"", s),
+                            None => format!("{:?}", val),
                         }
-                    } else {
-                        format!("{:?}", val)
                     }
-                } else {
-                    "None".to_string()
+                    Some(val) => format!("{:?}", val),
+                    None => "None".to_string(),
                 };
 
-                println!("{:04} {:<12} R{}, K[{}] ({})", i, name, a, bx, val_str);
+                let _ = writeln!(out, "{:04} {:<12} R{}, K[{}] ({})", i, name, a, bx, val_str);
             }
             Some(OpCode::Return) => {
-                println!("{:04} {:<12} R{}", i, name, a);
+                let _ = writeln!(out, "{:04} {:<12} R{}", i, name, a);
             }
             Some(OpCode::Add) | Some(OpCode::Sub) | Some(OpCode::Mul) | Some(OpCode::Div)
             | Some(OpCode::Pow) => {
-                println!("{:04} {:<12} R{}, R{}, R{}", i, name, a, b, c);
+                let _ = writeln!(out, "{:04} {:<12} R{}, R{}, R{}", i, name, a, b, c);
             }
             Some(OpCode::Move) | Some(OpCode::Neg) => {
-                println!("{:04} {:<12} R{}, R{}", i, name, a, b);
+                let _ = writeln!(out, "{:04} {:<12} R{}, R{}", i, name, a, b);
             }
             Some(OpCode::DefGlobalLet)
             | Some(OpCode::DefGlobalVar)
             | Some(OpCode::GetGlobal)
             | Some(OpCode::SetGlobal) => {
-                let sym_name = inv_globals.get(&bx).map(|s| s.as_str()).unwrap_or("?");
-                println!(
+                let sym_name = globals.get(&bx).map(|s| s.as_str()).unwrap_or("?");
+                let _ = writeln!(
+                    out,
                     "{:04} {:<12} R{}, Name[{}] ('{}')",
                     i, name, a, bx, sym_name
                 );
             }
             _ => {
-                println!("{:04} {:<12} A={} B={} C={} Bx={}", i, name, a, b, c, bx);
+                let _ = writeln!(
+                    out,
+                    "{:04} {:<12} A={} B={} C={} Bx={}",
+                    i, name, a, b, c, bx
+                );
             }
         }
     }
-
-    // ── Dump ProveIR for each PROVE instruction ─────────────────────────
-    dump_prove_blocks_from_bytecode(&bytecode, &compiler);
-
-    Ok(())
+    out
 }
 
 /// Scan compiled bytecode for PROVE instructions, deserialize the ProveIR
-/// from the constant pool, and print it.
-fn dump_prove_blocks_from_bytecode(bytecode: &[u32], compiler: &akronc::Compiler) {
-    let main_func = match compiler.compilers.last() {
-        Some(f) => f,
-        None => return,
-    };
-
+/// from the constant pool, and render it.
+fn format_prove_blocks<'a>(
+    bytecode: &[u32],
+    constants: &[Value],
+    blob_at: impl Fn(u32) -> Option<&'a [u8]>,
+) -> String {
+    let mut out = String::new();
     let mut block_num = 0u32;
     for (i, &inst) in bytecode.iter().enumerate() {
         if decode_opcode(inst) != OpCode::Prove.as_u8() {
@@ -259,35 +362,42 @@ fn dump_prove_blocks_from_bytecode(bytecode: &[u32], compiler: &akronc::Compiler
         block_num += 1;
         let bx = decode_bx(inst) as usize;
 
-        println!();
-        println!("  -- ProveIR block {} (instruction {:04}) --", block_num, i);
+        let _ = writeln!(out);
+        let _ = writeln!(
+            out,
+            "  -- ProveIR block {} (instruction {:04}) --",
+            block_num, i
+        );
 
-        let Some(val) = main_func.constants.get(bx) else {
-            println!("  (constant K[{bx}] not found)");
+        let Some(val) = constants.get(bx) else {
+            let _ = writeln!(out, "  (constant K[{bx}] not found)");
             continue;
         };
 
         if !val.is_bytes() {
-            println!("  (constant K[{bx}] is not bytes)");
+            let _ = writeln!(out, "  (constant K[{bx}] is not bytes)");
             continue;
         }
 
         let Some(handle) = val.as_handle() else {
-            println!("  (could not extract handle from K[{bx}])");
+            let _ = writeln!(out, "  (could not extract handle from K[{bx}])");
             continue;
         };
 
-        let Some(blob) = compiler.bytes_interner.blobs.get(handle as usize) else {
-            println!("  (bytes handle {handle} not found in interner)");
+        let Some(blob) = blob_at(handle) else {
+            let _ = writeln!(out, "  (bytes handle {handle} not found)");
             continue;
         };
 
         match ir_forge::ProveIR::from_bytes(blob) {
             Ok((prove_ir, prime_id)) => {
-                println!("  prime: {prime_id}");
-                print!("{prove_ir}");
+                let _ = writeln!(out, "  prime: {prime_id}");
+                let _ = write!(out, "{prove_ir}");
+            }
+            Err(e) => {
+                let _ = writeln!(out, "  (failed to deserialize ProveIR: {e})");
             }
-            Err(e) => println!("  (failed to deserialize ProveIR: {e})"),
         }
     }
+    out
 }
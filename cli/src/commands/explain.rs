@@ -0,0 +1,320 @@
+//! `ach explain` — show the constraints a builtin or operator costs.
+//!
+//! Compiles a minimal circuit that applies the builtin once to witness
+//! operands, through the shared ProveIR → Lysis → R1CS pipeline at `-O0`
+//! (so nothing is folded away). Setup
+//! statements such as the operand range checks behind `--bits` are
+//! compiled on their own first and their constraints skipped, so only the
+//! builtin's expansion is shown — including operators like `!=` or `||`
+//! that lower to other instructions.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::Result;
+use constraints::r1cs::LinearCombination;
+use constraints::PoseidonParamsProvider;
+use ir::passes::OptLevel;
+use memory::field::PrimeId;
+use memory::{FieldBackend, FieldElement};
+use zkc::r1cs_backend::R1CSCompiler;
+
+use super::circuit::pipeline::{compile_r1cs, CompileOptions};
+
+/// Constraints printed before the listing is elided.
+const MAX_SHOWN: usize = 12;
+
+/// Coefficients longer than this are abbreviated.
+const MAX_DIGITS: usize = 12;
+
+/// Bit width used for `range_check` when `--bits` is not given.
+const DEFAULT_RANGE_BITS: u32 = 8;
+
+/// One explainable builtin or operator.
+struct Template {
+    name: &'static str,
+    /// Circuit body over witnesses `a`, `b`, `c`. `{bits}` is replaced by
+    /// the bit width.
+    body: &'static str,
+    /// Operand range checks prepended when `--bits` is given, so the
+    /// comparison can use the bounded gadget.
+    bounded_prelude: Option<&'static str>,
+}
+
+const COMPARE_PRELUDE: &str = "range_check(a, {bits})\nrange_check(b, {bits})";
+
+const TEMPLATES: &[Template] = &[
+    Template {
+        name: "poseidon",
+        body: "let r = poseidon(a, b)",
+        bounded_prelude: None,
+    },
+    Template {
+        name: "poseidon2",
+        body: "let r = poseidon2(a, b)",
+        bounded_prelude: None,
+    },
+    Template {
+        name: "mux",
+        body: "let r = mux(c, a, b)",
+        bounded_prelude: None,
+    },
+    Template {
+        name: "range_check",
+        body: "range_check(a, {bits})",
+        bounded_prelude: None,
+    },
+    Template {
+        name: "assert_eq",
+        body: "assert_eq(a, b)",
+        bounded_prelude: None,
+    },
+    Template {
+        name: "assert",
+        body: "assert(c)",
+        bounded_prelude: None,
+    },
+    Template {
+        name: "*",
+        body: "let r = a * b",
+        bounded_prelude: None,
+    },
+    Template {
+        name: "/",
+        body: "let r = a / b",
+        bounded_prelude: None,
+    },
+    Template {
+        name: "==",
+        body: "let r = a == b",
+        bounded_prelude: None,
+    },
+    Template {
+        name: "!=",
+        body: "let r = a != b",
+        bounded_prelude: None,
+    },
+    Template {
+        name: "<",
+        body: "let r = a < b",
+        bounded_prelude: Some(COMPARE_PRELUDE),
+    },
+    Template {
+        name: "<=",
+        body: "let r = a <= b",
+        bounded_prelude: Some(COMPARE_PRELUDE),
+    },
+    Template {
+        name: ">",
+        body: "let r = a > b",
+        bounded_prelude: Some(COMPARE_PRELUDE),
+    },
+    Template {
+        name: ">=",
+        body: "let r = a >= b",
+        bounded_prelude: Some(COMPARE_PRELUDE),
+    },
+    Template {
+        name: "&&",
+        body: "let r = a && b",
+        bounded_prelude: None,
+    },
+    Template {
+        name: "||",
+        body: "let r = a || b",
+        bounded_prelude: None,
+    },
+    Template {
+        name: "!",
+        body: "let r = !c",
+        bounded_prelude: None,
+    },
+];
+
+/// The constraints one builtin expands to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// The builtin or operator explained.
+    pub builtin: String,
+    /// Circuit body the constraints were taken from.
+    pub body: String,
+    /// Each attributed constraint rendered as `(A) * (B) = (C)`.
+    pub constraints: Vec<String>,
+}
+
+impl std::fmt::Display for Explanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let n = self.constraints.len();
+        let plural = if n == 1 { "" } else { "s" };
+        writeln!(f, "{}: {n} constraint{plural}", self.builtin)?;
+        writeln!(f)?;
+        for line in self.body.lines() {
+            writeln!(f, "    {line}")?;
+        }
+        writeln!(f)?;
+        for c in self.constraints.iter().take(MAX_SHOWN) {
+            writeln!(f, "  {c}")?;
+        }
+        if n > MAX_SHOWN {
+            writeln!(f, "  ... {} more", n - MAX_SHOWN)?;
+        }
+        Ok(())
+    }
+}
+
+/// Entry point for `ach explain`: print the builtin's constraint listing.
+pub fn explain_command(builtin: &str, bits: Option<u32>, prime_id: PrimeId) -> Result<()> {
+    print!("{}", explain_builtin(builtin, bits, prime_id)?);
+    Ok(())
+}
+
+/// Compile `builtin` in isolation and return the constraints it emits.
+pub fn explain_builtin(builtin: &str, bits: Option<u32>, prime_id: PrimeId) -> Result<Explanation> {
+    match prime_id {
+        PrimeId::Bn254 => explain_inner::<memory::Bn254Fr>(builtin, bits, prime_id),
+        PrimeId::Bls12_381 => explain_inner::<memory::Bls12_381Fr>(builtin, bits, prime_id),
+        PrimeId::Goldilocks => explain_inner::<memory::GoldilocksFr>(builtin, bits, prime_id),
+        other => Err(anyhow::anyhow!(
+            "prime `{}` is not supported for circuit compilation",
+            other.name()
+        )),
+    }
+}
+
+fn explain_inner<F: FieldBackend + PoseidonParamsProvider>(
+    builtin: &str,
+    bits: Option<u32>,
+    prime_id: PrimeId,
+) -> Result<Explanation> {
+    let has_bits = bits.is_some();
+    let template = TEMPLATES
+        .iter()
+        .find(|t| t.name == builtin)
+        .ok_or_else(|| {
+            let names: Vec<&str> = TEMPLATES.iter().map(|t| t.name).collect();
+            anyhow::anyhow!(
+                "cannot explain `{builtin}` (expected one of: {})",
+                names.join(", ")
+            )
+        })?;
+
+    let bits = bits.unwrap_or(DEFAULT_RANGE_BITS).to_string();
+    let prelude = match (has_bits, template.bounded_prelude) {
+        (true, Some(prelude)) => prelude.replace("{bits}", &bits),
+        (true, None) if !template.body.contains("{bits}") => {
+            return Err(anyhow::anyhow!("`--bits` does not apply to `{builtin}`"))
+        }
+        _ => String::new(),
+    };
+    let statement = template.body.replace("{bits}", &bits);
+
+    let setup = compile::<F>(builtin, &prelude, prime_id)?
+        .cs
+        .num_constraints();
+    let body = if prelude.is_empty() {
+        statement
+    } else {
+        format!("{prelude}\n{statement}")
+    };
+    let compiler = compile::<F>(builtin, &body, prime_id)?;
+
+    let labels = compiler.witness_labels();
+    let mut seen = HashMap::new();
+    for label in &labels {
+        *seen.entry(label.as_str()).or_insert(0usize) += 1;
+    }
+    let name = |index: usize| {
+        let label = &labels[index];
+        if seen[label.as_str()] > 1 {
+            format!("{label}#{index}")
+        } else {
+            label.clone()
+        }
+    };
+    let constraints = compiler.cs.constraints()[setup..]
+        .iter()
+        .map(|c| {
+            format!(
+                "({}) * ({}) = ({})",
+                render_lc(&c.a, &name),
+                render_lc(&c.b, &name),
+                render_lc(&c.c, &name)
+            )
+        })
+        .collect();
+
+    Ok(Explanation {
+        builtin: builtin.to_string(),
+        body,
+        constraints,
+    })
+}
+
+/// Compile `body` inside a circuit over witnesses `a`, `b`, `c`.
+fn compile<F: FieldBackend + PoseidonParamsProvider>(
+    builtin: &str,
+    body: &str,
+    prime_id: PrimeId,
+) -> Result<R1CSCompiler<F>> {
+    let source = format!("circuit explain(a: Witness, b: Witness, c: Witness) {{\n{body}\n}}\n");
+    let opts = CompileOptions {
+        opt_level: OptLevel::O0,
+        ..CompileOptions::default()
+    };
+    let render = |e: ir_forge::ProveIrError| anyhow::anyhow!("cannot compile `{builtin}`: {e}");
+    let (_, compiler) = compile_r1cs::<F>(
+        &source,
+        Some(Path::new("explain.ach")),
+        &opts,
+        prime_id,
+        &render,
+    )?;
+    Ok(compiler)
+}
+
+/// Render `lc` as `2·x + y - 1`, naming wires with `name`.
+fn render_lc<F: FieldBackend>(
+    lc: &LinearCombination<F>,
+    name: &impl Fn(usize) -> String,
+) -> String {
+    let mut out = String::new();
+    for (var, coeff) in lc.terms() {
+        let (negative, magnitude) = signed(coeff);
+        let sep = match (out.is_empty(), negative) {
+            (true, false) => "",
+            (true, true) => "-",
+            (false, false) => " + ",
+            (false, true) => " - ",
+        };
+        out.push_str(sep);
+        let wire = (var.index() != 0).then(|| name(var.index()));
+        match (wire, magnitude.as_str()) {
+            (None, m) => out.push_str(m),
+            (Some(w), "1") => out.push_str(&w),
+            (Some(w), m) => out.push_str(&format!("{m}·{w}")),
+        }
+    }
+    if out.is_empty() {
+        out.push('0');
+    }
+    out
+}
+
+/// Split a coefficient into sign and decimal magnitude, reading values
+/// close to the modulus as small negatives. Magnitudes too long to read
+/// (round constants, MDS entries) keep only their leading and trailing
+/// digits.
+fn signed<F: FieldBackend>(coeff: &FieldElement<F>) -> (bool, String) {
+    let neg = coeff.neg();
+    let (negative, digits) = if neg.to_canonical()[1..].iter().all(|&l| l == 0) && !neg.is_zero() {
+        (true, neg.to_decimal_string())
+    } else {
+        (false, coeff.to_decimal_string())
+    };
+    if digits.len() > MAX_DIGITS {
+        let (head, tail) = (&digits[..4], &digits[digits.len() - 4..]);
+        (negative, format!("{head}…{tail}"))
+    } else {
+        (negative, digits)
+    }
+}
@@ -0,0 +1,60 @@
+//! `ach field-info` — print the active prime field and its properties.
+//!
+//! Every value comes from the field backend itself, so the listing
+//! describes exactly the arithmetic the compiler and prover use.
+
+use anyhow::Result;
+use memory::field::PrimeId;
+use memory::{BigInt, BigIntWidth, FieldBackend, FieldElement};
+
+pub fn field_info_command(prime_id: PrimeId) -> Result<()> {
+    print!("{}", render_field_info(prime_id)?);
+    Ok(())
+}
+
+/// One `key: value` line per property of the field selected by `prime_id`.
+pub fn render_field_info(prime_id: PrimeId) -> Result<String> {
+    match prime_id {
+        PrimeId::Bn254 => Ok(render_inner::<memory::Bn254Fr>()),
+        PrimeId::Bls12_381 => Ok(render_inner::<memory::Bls12_381Fr>()),
+        PrimeId::Goldilocks => Ok(render_inner::<memory::GoldilocksFr>()),
+        other => Err(anyhow::anyhow!(
+            "prime `{}` has no field backend",
+            other.name()
+        )),
+    }
+}
+
+fn render_inner<F: FieldBackend>() -> String {
+    let bytes = FieldElement::<F>::modulus_le_bytes();
+    let limbs: Vec<u64> = bytes
+        .chunks_exact(8)
+        .map(|c| u64::from_le_bytes(c.try_into().unwrap()))
+        .collect();
+    let two_adicity = two_adicity(&limbs);
+    let modulus = BigInt::from_limbs(limbs, BigIntWidth::W256).expect("4 limbs");
+
+    format!(
+        "prime:          {}\n\
+         modulus (hex):  0x{}\n\
+         modulus (dec):  {}\n\
+         bit size:       {}\n\
+         two-adicity:    {}\n\
+         generator:      {}\n",
+        FieldElement::<F>::prime_id(),
+        modulus.to_hex_string(),
+        modulus.to_decimal_string(),
+        FieldElement::<F>::modulus_bit_size(),
+        two_adicity,
+        FieldElement::<F>::multiplicative_generator().to_decimal_string(),
+    )
+}
+
+/// Largest `s` with `2^s` dividing `p - 1`, from the modulus limbs
+/// (little-endian). `p` is odd, so `p - 1` only clears bit 0.
+fn two_adicity(modulus_limbs: &[u64]) -> u32 {
+    let mut minus_one = modulus_limbs.to_vec();
+    minus_one[0] -= 1;
+    let zero_limbs = minus_one.iter().take_while(|&&limb| limb == 0).count();
+    zero_limbs as u32 * 64 + minus_one[zero_limbs].trailing_zeros()
+}
@@ -0,0 +1,37 @@
+//! `ach list-builtins` — print every builtin with its arity and a summary.
+//!
+//! Reads the production [`BuiltinRegistry`], the same table both compilers
+//! dispatch through, so the listing cannot drift from what they accept.
+
+use anyhow::Result;
+use resolve::{Availability, BuiltinRegistry};
+
+pub fn list_builtins_command() -> Result<()> {
+    print!("{}", render_builtins(&BuiltinRegistry::default()));
+    Ok(())
+}
+
+/// One line per builtin, sorted by name: name, arity, where it can be
+/// called (`vm`, `circuit` or `both`) and its description.
+pub fn render_builtins(registry: &BuiltinRegistry) -> String {
+    let mut entries: Vec<_> = registry.entries().iter().collect();
+    entries.sort_by_key(|e| e.name);
+    let width = entries.iter().map(|e| e.name.len()).max().unwrap_or(0);
+
+    let mut out = String::new();
+    for e in entries {
+        let context = match e.availability {
+            Availability::Vm => "vm",
+            Availability::ProveIr => "circuit",
+            Availability::Both => "both",
+        };
+        out.push_str(&format!(
+            "{:<width$}  {:<8}  {:<7}  {}\n",
+            e.name,
+            e.arity.describe(),
+            context,
+            e.description,
+        ));
+    }
+    out
+}
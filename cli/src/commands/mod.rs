@@ -1,9 +1,16 @@
+pub mod analyze;
+pub mod check;
 pub mod circom;
 pub mod circuit;
 pub mod compile;
 pub mod disassemble;
+pub mod explain;
+pub mod field_info;
 pub mod inspect;
+pub mod list_builtins;
+pub mod profile;
 pub mod run;
+pub mod watch;
 
 use akronc::{Compiler, CompilerError};
 use diagnostics::{ColorMode, Diagnostic, DiagnosticRenderer};
@@ -22,10 +29,33 @@ pub fn register_std_modules(vm: &mut akron::VM) -> Result<(), akron::error::Runt
     Ok(())
 }
 
+/// Load a compiled `.achb` binary into `vm` and return its main function.
+///
+/// The VM is left ready to `interpret()`: strings, fields, blobs and
+/// prototypes live on its heap and the main frame is pushed.
+pub fn load_achb(vm: &mut akron::VM, path: &str) -> anyhow::Result<memory::Function> {
+    use anyhow::Context;
+
+    let mut file = std::fs::File::open(path).context("Failed to open binary file")?;
+    vm.load_executable(&mut file)
+        .map_err(|e| anyhow::anyhow!("Loader error: {e}"))?;
+
+    let frame = vm
+        .frames
+        .last()
+        .ok_or_else(|| anyhow::anyhow!("Loader error: no main frame"))?;
+    vm.heap
+        .get_closure(frame.closure)
+        .and_then(|c| vm.heap.get_function(c.function))
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Loader error: main function missing from heap"))
+}
+
 /// Output format for compiler diagnostics.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum ErrorFormat {
     /// Rich output with source snippets and colors (default)
+    #[default]
     Human,
     /// JSON Lines — one JSON object per diagnostic (machine-readable)
     Json,
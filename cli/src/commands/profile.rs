@@ -0,0 +1,104 @@
+//! `ach profile` — attribute R1CS constraints to source lines.
+//!
+//! Runs the shared ProveIR → Lysis → optimize → R1CS pipeline (without
+//! writing artifacts), then prints the source
+//! with the number of constraints each line emitted in the left margin.
+//! Counts are taken before linear constraint elimination, so they add up
+//! to the emitted total rather than the final `.r1cs` size.
+
+use std::collections::BTreeMap;
+use std::fs;
+
+use anyhow::{Context, Result};
+use constraints::PoseidonParamsProvider;
+use memory::field::PrimeId;
+use memory::FieldBackend;
+
+use super::circuit::pipeline::{compile_r1cs_file, CompileOptions};
+use super::ErrorFormat;
+
+/// Per-line constraint counts for one circuit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineProfile {
+    /// 1-indexed source line → constraints emitted by that line.
+    pub lines: BTreeMap<usize, usize>,
+    /// All constraints emitted, including those with no source location.
+    pub total: usize,
+}
+
+impl LineProfile {
+    /// Constraints that could not be attributed to any source line.
+    pub fn unattributed(&self) -> usize {
+        self.total - self.lines.values().sum::<usize>()
+    }
+}
+
+/// Entry point for `ach profile`: print the annotated source listing.
+pub fn profile_command(
+    path: &str,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    error_format: ErrorFormat,
+) -> Result<()> {
+    let source =
+        fs::read_to_string(path).with_context(|| format!("cannot read source file: {path}"))?;
+    let profile = line_profile(path, opts, prime_id, error_format)?;
+    print!("{}", render_profile(&source, &profile));
+    Ok(())
+}
+
+/// Compile `path` and return its per-line constraint profile.
+pub fn line_profile(
+    path: &str,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    error_format: ErrorFormat,
+) -> Result<LineProfile> {
+    match prime_id {
+        PrimeId::Bn254 => line_profile_inner::<memory::Bn254Fr>(path, opts, prime_id, error_format),
+        PrimeId::Bls12_381 => {
+            line_profile_inner::<memory::Bls12_381Fr>(path, opts, prime_id, error_format)
+        }
+        PrimeId::Goldilocks => {
+            line_profile_inner::<memory::GoldilocksFr>(path, opts, prime_id, error_format)
+        }
+        other => Err(anyhow::anyhow!(
+            "prime `{}` is not supported for circuit compilation",
+            other.name()
+        )),
+    }
+}
+
+fn line_profile_inner<F: FieldBackend + PoseidonParamsProvider>(
+    path: &str,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    error_format: ErrorFormat,
+) -> Result<LineProfile> {
+    let (_, _, compiler) = compile_r1cs_file::<F>(path, opts, prime_id, error_format)?;
+    Ok(LineProfile {
+        lines: compiler.constraint_profile(),
+        total: compiler.cs.num_constraints(),
+    })
+}
+
+/// Render `source` with each line prefixed by its constraint count
+/// (blank when zero), followed by a total.
+pub fn render_profile(source: &str, profile: &LineProfile) -> String {
+    let width = profile.total.to_string().len().max(5);
+    let mut out = String::new();
+    for (idx, text) in source.lines().enumerate() {
+        let count = match profile.lines.get(&(idx + 1)) {
+            Some(n) => n.to_string(),
+            None => String::new(),
+        };
+        out.push_str(&format!("{count:>width$} | {:>4} | {text}\n", idx + 1));
+    }
+    out.push_str(&format!("{} constraints total", profile.total));
+    let unattributed = profile.unattributed();
+    if unattributed > 0 {
+        out.push_str(&format!(" ({unattributed} without a source line)"));
+    }
+    out.push('\n');
+    out
+}
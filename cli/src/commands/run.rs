@@ -2,25 +2,62 @@ use std::rc::Rc;
 
 use akron::{CallFrame, ValueOps, VM};
 use anyhow::{Context, Result};
+use memory::field::PrimeId;
 use memory::Function;
 use std::fs;
+use std::path::PathBuf;
 
 use super::ErrorFormat;
 use crate::prove_handler::{DefaultProveHandler, ProveBackend, SharedProveHandler};
 
-#[allow(clippy::too_many_arguments)]
-pub fn run_file(
-    path: &str,
-    stress_gc: bool,
-    ptau: Option<&str>,
-    prove_backend: &str,
-    prime_id: memory::field::PrimeId,
-    max_heap: Option<&str>,
-    gc_stats: bool,
-    circuit_stats: bool,
-    error_format: ErrorFormat,
-    circom_lib_dirs: &[std::path::PathBuf],
-) -> Result<()> {
+/// Settings for [`run_file`], one field per `ach run` flag or config key.
+/// [`Default`] matches the CLI defaults: R1CS proofs over BN254, no heap
+/// limit, checked `Int` arithmetic, and every diagnostic output off.
+#[derive(Clone, Debug)]
+pub struct RunOptions<'a> {
+    pub stress_gc: bool,
+    pub ptau: Option<&'a str>,
+    pub prove_backend: &'a str,
+    pub prime_id: PrimeId,
+    pub max_heap: Option<&'a str>,
+    pub gc_stats: bool,
+    pub circuit_stats: bool,
+    pub wrapping: bool,
+    pub error_format: ErrorFormat,
+    pub circom_lib_dirs: &'a [PathBuf],
+}
+
+impl Default for RunOptions<'_> {
+    fn default() -> Self {
+        Self {
+            stress_gc: false,
+            ptau: None,
+            prove_backend: "r1cs",
+            prime_id: PrimeId::Bn254,
+            max_heap: None,
+            gc_stats: false,
+            circuit_stats: false,
+            wrapping: false,
+            error_format: ErrorFormat::Human,
+            circom_lib_dirs: &[],
+        }
+    }
+}
+
+pub fn run_file(path: &str, opts: &RunOptions) -> Result<()> {
+    let RunOptions {
+        stress_gc,
+        ptau,
+        prove_backend,
+        prime_id,
+        max_heap,
+        gc_stats,
+        circuit_stats,
+        wrapping,
+        error_format,
+        circom_lib_dirs,
+    } = *opts;
+
     if ptau.is_some() {
         eprintln!(
             "Warning: --ptau is deprecated and ignored (native Groth16 backend does not use ptau files)"
@@ -32,12 +69,14 @@ pub fn run_file(
         _ => ProveBackend::R1cs,
     };
 
-    if path.ends_with(".achb") {
-        let mut file = fs::File::open(path).context("Failed to open binary file")?;
+    let bytes = fs::read(path).with_context(|| format!("cannot read source file: {path}"))?;
 
+    // A `.achb` renamed to anything else still runs as bytecode.
+    if path.ends_with(".achb") || memory::BinaryHeader::sniff(&bytes) {
         let mut vm = VM::new();
         super::register_std_modules(&mut vm)?;
         vm.stress_mode = stress_gc;
+        vm.wrapping_int = wrapping;
         if let Some(limit_str) = max_heap {
             let limit = parse_size(limit_str).ok_or_else(|| {
                 anyhow::anyhow!(
@@ -55,9 +94,7 @@ pub fn run_file(
         vm.verify_handler = Some(Box::new(SharedProveHandler(Rc::clone(&handler))));
         vm.prove_handler = Some(Box::new(SharedProveHandler(Rc::clone(&handler))));
 
-        // Use the new secure loader
-        vm.load_executable(&mut file)
-            .map_err(|e| anyhow::anyhow!("Loader error: {e}"))?;
+        super::load_achb(&mut vm, path)?;
 
         let result = vm.interpret();
         print_gc_stats(gc_stats, &vm);
@@ -72,7 +109,12 @@ pub fn run_file(
         }
         Ok(())
     } else {
-        let content = fs::read_to_string(path).context("Failed to source file")?;
+        let content = String::from_utf8(bytes).map_err(|e| {
+            anyhow::anyhow!(
+                "{path}: not valid UTF-8 source (invalid byte at offset {})",
+                e.utf8_error().valid_up_to()
+            )
+        })?;
         let mut compiler = super::new_compiler();
         compiler.prime_id = prime_id;
         let source_path = std::path::Path::new(path);
@@ -97,6 +139,7 @@ pub fn run_file(
         let mut vm = VM::new();
         super::register_std_modules(&mut vm)?;
         vm.stress_mode = stress_gc;
+        vm.wrapping_int = wrapping;
         if let Some(limit_str) = max_heap {
             let limit = parse_size(limit_str).ok_or_else(|| {
                 anyhow::anyhow!(
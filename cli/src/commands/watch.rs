@@ -0,0 +1,217 @@
+//! `ach watch` — recompile a circuit every time its source changes.
+//!
+//! Change detection is a plain mtime/length poll over the entry file and
+//! every module it (transitively) imports, so no filesystem-notify
+//! dependency is needed. Each rebuild runs the shared ProveIR → Lysis →
+//! optimize → R1CS pipeline (without writing artifacts) and prints one
+//! summary line. Compile errors are printed and the
+//! watcher keeps going.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use achronyme_parser::ast::Stmt;
+use anyhow::Result;
+use constraints::PoseidonParamsProvider;
+use ir::passes::OptLevel;
+use memory::field::PrimeId;
+use memory::FieldBackend;
+
+use super::circuit::pipeline::{compile_r1cs_file, CompileOptions};
+use super::ErrorFormat;
+
+/// How often the watched files are polled for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Summary of one successful rebuild.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildStats {
+    /// IR instructions after optimization.
+    pub instructions: usize,
+    /// R1CS constraints after linear elimination.
+    pub constraints: usize,
+    /// Public inputs declared by the circuit.
+    pub public_inputs: usize,
+    /// Witness inputs declared by the circuit.
+    pub witness_inputs: usize,
+}
+
+impl std::fmt::Display for BuildStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} constraints, {} instructions, {} public, {} witness",
+            self.constraints, self.instructions, self.public_inputs, self.witness_inputs
+        )
+    }
+}
+
+/// Entry point for `ach watch`: rebuild `path` on every change, forever.
+pub fn watch_command(
+    path: &str,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    error_format: ErrorFormat,
+) -> Result<()> {
+    let mut out = std::io::stdout();
+    watch_loop(
+        path,
+        opts,
+        prime_id,
+        error_format,
+        POLL_INTERVAL,
+        &mut out,
+        |_| true,
+    )
+}
+
+/// Build once, then poll for changes and rebuild until `keep_going`
+/// returns `false`. `keep_going` is called after each build with the
+/// number of builds done so far.
+///
+/// Every build writes exactly one line to `out`: `[n] <stats>` on
+/// success, `[n] error: ...` on failure.
+#[allow(clippy::too_many_arguments)]
+pub fn watch_loop<W: Write>(
+    path: &str,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    error_format: ErrorFormat,
+    interval: Duration,
+    out: &mut W,
+    mut keep_going: impl FnMut(usize) -> bool,
+) -> Result<()> {
+    let mut builds = 0;
+    loop {
+        // Snapshot before compiling so an edit made mid-build still
+        // triggers the next rebuild.
+        let snapshot = Snapshot::take(path);
+
+        builds += 1;
+        match compile_stats(path, opts, prime_id, error_format) {
+            Ok(stats) => writeln!(out, "[{builds}] {stats}")?,
+            Err(e) => writeln!(out, "[{builds}] error: {e}")?,
+        }
+        out.flush()?;
+
+        if !keep_going(builds) {
+            return Ok(());
+        }
+
+        while Snapshot::take(path) == snapshot {
+            std::thread::sleep(interval);
+        }
+    }
+}
+
+/// Run the circuit pipeline on `path` and return its constraint stats.
+/// Nothing is written to disk.
+pub fn compile_stats(
+    path: &str,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    error_format: ErrorFormat,
+) -> Result<BuildStats> {
+    match prime_id {
+        PrimeId::Bn254 => {
+            compile_stats_inner::<memory::Bn254Fr>(path, opts, prime_id, error_format)
+        }
+        PrimeId::Bls12_381 => {
+            compile_stats_inner::<memory::Bls12_381Fr>(path, opts, prime_id, error_format)
+        }
+        PrimeId::Goldilocks => {
+            compile_stats_inner::<memory::GoldilocksFr>(path, opts, prime_id, error_format)
+        }
+        other => Err(anyhow::anyhow!(
+            "prime `{}` is not supported for circuit compilation",
+            other.name()
+        )),
+    }
+}
+
+fn compile_stats_inner<F: FieldBackend + PoseidonParamsProvider>(
+    path: &str,
+    opts: &CompileOptions,
+    prime_id: PrimeId,
+    error_format: ErrorFormat,
+) -> Result<BuildStats> {
+    let (_, program, mut compiler) = compile_r1cs_file::<F>(path, opts, prime_id, error_format)?;
+    if opts.opt_level > OptLevel::O0 {
+        compiler.optimize_r1cs();
+    }
+
+    let count_inputs = |vis: ir::Visibility| {
+        program
+            .iter()
+            .filter(
+                |i| matches!(i, ir::Instruction::Input { visibility, .. } if *visibility == vis),
+            )
+            .count()
+    };
+    Ok(BuildStats {
+        instructions: program.len(),
+        constraints: compiler.cs.num_constraints(),
+        public_inputs: count_inputs(ir::Visibility::Public),
+        witness_inputs: count_inputs(ir::Visibility::Witness),
+    })
+}
+
+/// `path` plus every module it imports, transitively. Files that fail to
+/// read or parse are still listed (so fixing them triggers a rebuild) but
+/// contribute no further imports.
+pub fn watched_files(path: &str) -> Vec<PathBuf> {
+    let mut seen = HashSet::new();
+    let mut files = Vec::new();
+    let mut pending = vec![PathBuf::from(path)];
+    while let Some(file) = pending.pop() {
+        let key = file.canonicalize().unwrap_or_else(|_| file.clone());
+        if !seen.insert(key) {
+            continue;
+        }
+        if let Ok(source) = fs::read_to_string(&file) {
+            let dir = file.parent().unwrap_or(Path::new(".")).to_path_buf();
+            let (program, _errors) = achronyme_parser::parse_program(&source);
+            for stmt in &program.stmts {
+                if let Some(import) = import_path(stmt) {
+                    pending.push(dir.join(import));
+                }
+            }
+        }
+        files.push(file);
+    }
+    files
+}
+
+fn import_path(stmt: &Stmt) -> Option<&str> {
+    match stmt {
+        Stmt::Import { path, .. }
+        | Stmt::SelectiveImport { path, .. }
+        | Stmt::ImportCircuit { path, .. } => Some(path),
+        Stmt::Export { inner, .. } => import_path(inner),
+        _ => None,
+    }
+}
+
+/// Modification time and length of every watched file. A missing file
+/// is recorded as `None`, so deleting or re-creating it counts as a change.
+#[derive(PartialEq, Eq)]
+struct Snapshot(Vec<(PathBuf, Option<(SystemTime, u64)>)>);
+
+impl Snapshot {
+    fn take(path: &str) -> Self {
+        Snapshot(
+            watched_files(path)
+                .into_iter()
+                .map(|file| {
+                    let meta = fs::metadata(&file)
+                        .ok()
+                        .and_then(|m| Some((m.modified().ok()?, m.len())));
+                    (file, meta)
+                })
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,66 @@
+//! On-disk formats for BN254 Groth16 proofs.
+//!
+//! Two encodings are supported:
+//!
+//! - **Binary** ([`write_proof`] / [`read_proof`]): the 4-byte magic
+//!   `ACGP` (distinct from serialized ProveIR's `ACHP`), a format version
+//!   byte, then the compressed points π_A, π_B,
+//!   π_C (133 bytes total). Compact enough to store or transmit alongside
+//!   the public inputs.
+//! - **JSON** ([`write_proof_json`] / [`read_proof_json`]): the snarkjs
+//!   `proof.json` layout (`pi_a`, `pi_b`, `pi_c` with decimal string
+//!   coordinates), readable by `snarkjs groth16 verify` and on-chain
+//!   calldata tooling.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use proving::groth16_bn254::{self, Proof, PROOF_BYTES_LEN};
+
+/// Leading bytes of a binary proof file.
+const MAGIC: &[u8; 4] = b"ACGP";
+/// Current binary format version.
+const VERSION: u8 = 1;
+
+/// Write `proof` to `path` in the binary format.
+pub fn write_proof(path: &Path, proof: &Proof) -> Result<()> {
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 1 + PROOF_BYTES_LEN);
+    bytes.extend_from_slice(MAGIC);
+    bytes.push(VERSION);
+    bytes.extend_from_slice(&groth16_bn254::proof_to_bytes(proof));
+    fs::write(path, bytes).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Read a proof written by [`write_proof`].
+pub fn read_proof(path: &Path) -> Result<Proof> {
+    let bytes = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let Some(rest) = bytes.strip_prefix(MAGIC) else {
+        bail!("{} is not an Achronyme proof file", path.display());
+    };
+    match rest.split_first() {
+        Some((&VERSION, points)) => groth16_bn254::proof_from_bytes(points)
+            .map_err(anyhow::Error::msg)
+            .with_context(|| format!("malformed proof in {}", path.display())),
+        Some((version, _)) => bail!(
+            "{} uses proof format version {version}, expected {VERSION}",
+            path.display()
+        ),
+        None => bail!("{} is truncated", path.display()),
+    }
+}
+
+/// Write `proof` to `path` as snarkjs-compatible `proof.json`.
+pub fn write_proof_json(path: &Path, proof: &Proof) -> Result<()> {
+    fs::write(path, groth16_bn254::serialize_proof_json(proof))
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Read a snarkjs-compatible `proof.json`.
+pub fn read_proof_json(path: &Path) -> Result<Proof> {
+    let json =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    groth16_bn254::deserialize_proof_json(&json)
+        .map_err(anyhow::Error::msg)
+        .with_context(|| format!("malformed proof in {}", path.display()))
+}
@@ -1,6 +1,7 @@
 pub mod circom_handler;
 pub mod commands;
 pub mod config;
+pub mod groth16;
 pub mod init;
 pub mod prove_handler;
 pub mod style;
@@ -1,7 +1,9 @@
 use anyhow::Result;
 use clap::Parser;
+use cli::commands::circuit::CompileOptions;
 use cli::commands::ErrorFormat;
 use cli::config::{self, CliOverrides};
+use ir::passes::OptLevel;
 use memory::field::PrimeId;
 
 /// The compile pipeline allocates and frees millions of small IR nodes
@@ -78,22 +80,25 @@ fn main() -> Result<()> {
     match &cli.command {
         Commands::Init { .. } => unreachable!(),
 
-        Commands::Run { ptau, .. } => {
+        Commands::Run { ptau, wrapping, .. } => {
             let path = cfg.entry.as_deref().ok_or_else(|| {
                 anyhow::anyhow!("no input file specified and no `entry` in achronyme.toml")
             })?;
             validate_prime_backend(prime_id, &cfg.prove_backend)?;
             cli::commands::run::run_file(
                 path,
-                cfg.stress_gc,
-                ptau.as_deref(),
-                &cfg.prove_backend,
-                prime_id,
-                cfg.max_heap.as_deref(),
-                cfg.gc_stats,
-                cfg.circuit_stats,
-                ef,
-                &cfg.circom_lib_dirs,
+                &cli::commands::run::RunOptions {
+                    stress_gc: cfg.stress_gc,
+                    ptau: ptau.as_deref(),
+                    prove_backend: &cfg.prove_backend,
+                    prime_id,
+                    max_heap: cfg.max_heap.as_deref(),
+                    gc_stats: cfg.gc_stats,
+                    circuit_stats: cfg.circuit_stats,
+                    wrapping: *wrapping,
+                    error_format: ef,
+                    circom_lib_dirs: &cfg.circom_lib_dirs,
+                },
             )
         }
 
@@ -179,31 +184,115 @@ fn main() -> Result<()> {
         Commands::Circuit {
             inputs,
             input_file,
+            input_format,
+            no_optimize,
+            opt_level,
             prove,
             dump_ir,
+            dump_witness,
+            check_determinism,
+            stdin,
+            entry,
+            max_unroll,
+            emit_r1cs_json,
+            emit_sym,
             ..
         } => {
-            let path = cfg.entry.as_deref().ok_or_else(|| {
-                anyhow::anyhow!("no input file specified and no `entry` in achronyme.toml")
-            })?;
+            let path = if *stdin {
+                cli::commands::circuit::STDIN_PATH
+            } else {
+                cfg.entry.as_deref().ok_or_else(|| {
+                    anyhow::anyhow!("no input file specified and no `entry` in achronyme.toml")
+                })?
+            };
             validate_prime_backend(prime_id, &cfg.backend)?;
+            let opt_level = resolve_opt_level(*opt_level, *no_optimize, cfg.optimize)?;
             cli::commands::circuit::circuit_command(
                 path,
-                &cfg.r1cs_path,
-                &cfg.wtns_path,
-                inputs.as_deref(),
-                input_file.as_deref(),
-                !cfg.optimize,
-                &cfg.backend,
-                prime_id,
-                *prove,
-                cfg.solidity_path.as_deref(),
-                cfg.plonkish_json_path.as_deref(),
-                *dump_ir,
-                cfg.circuit_stats,
-                ef,
+                &cli::commands::circuit::CircuitOptions {
+                    r1cs_path: &cfg.r1cs_path,
+                    wtns_path: &cfg.wtns_path,
+                    inputs: inputs.as_deref(),
+                    input_file: input_file.as_deref(),
+                    input_format: input_format.as_deref(),
+                    opt_level,
+                    backend: &cfg.backend,
+                    prime_id,
+                    prove: *prove,
+                    solidity_path: cfg.solidity_path.as_deref(),
+                    plonkish_json_path: cfg.plonkish_json_path.as_deref(),
+                    r1cs_json_path: emit_r1cs_json.as_deref(),
+                    sym_path: emit_sym.as_deref(),
+                    dump_ir: *dump_ir,
+                    circuit_stats: cfg.circuit_stats,
+                    dump_witness: *dump_witness,
+                    check_determinism: *check_determinism,
+                    entry: entry.as_deref(),
+                    max_unroll: *max_unroll,
+                    error_format: ef,
+                },
             )
         }
+
+        Commands::Watch {
+            no_optimize,
+            opt_level,
+            entry,
+            max_unroll,
+            ..
+        } => {
+            let path = cfg.entry.as_deref().ok_or_else(|| {
+                anyhow::anyhow!("no input file specified and no `entry` in achronyme.toml")
+            })?;
+            let opt_level = resolve_opt_level(*opt_level, *no_optimize, cfg.optimize)?;
+            let opts = CompileOptions::from_flags(opt_level, entry.as_deref(), *max_unroll);
+            cli::commands::watch::watch_command(path, &opts, prime_id, ef)
+        }
+
+        Commands::Profile {
+            no_optimize,
+            opt_level,
+            entry,
+            max_unroll,
+            ..
+        } => {
+            let path = cfg.entry.as_deref().ok_or_else(|| {
+                anyhow::anyhow!("no input file specified and no `entry` in achronyme.toml")
+            })?;
+            let opt_level = resolve_opt_level(*opt_level, *no_optimize, cfg.optimize)?;
+            let opts = CompileOptions::from_flags(opt_level, entry.as_deref(), *max_unroll);
+            cli::commands::profile::profile_command(path, &opts, prime_id, ef)
+        }
+
+        Commands::Analyze {
+            no_optimize,
+            opt_level,
+            entry,
+            max_unroll,
+            ..
+        } => {
+            let path = cfg.entry.as_deref().ok_or_else(|| {
+                anyhow::anyhow!("no input file specified and no `entry` in achronyme.toml")
+            })?;
+            let opt_level = resolve_opt_level(*opt_level, *no_optimize, cfg.optimize)?;
+            let opts = CompileOptions::from_flags(opt_level, entry.as_deref(), *max_unroll);
+            cli::commands::analyze::analyze_command(path, &opts, prime_id, ef)
+        }
+
+        Commands::Check { .. } => {
+            let path = cfg.entry.as_deref().ok_or_else(|| {
+                anyhow::anyhow!("no input file specified and no `entry` in achronyme.toml")
+            })?;
+            cli::commands::check::check_command(path, prime_id, ef, &cfg.circom_lib_dirs)
+        }
+
+        Commands::Explain { builtin, bits } => {
+            cli::commands::explain::explain_command(builtin, *bits, prime_id)
+        }
+
+        Commands::ListBuiltins => cli::commands::list_builtins::list_builtins_command(),
+
+        Commands::FieldInfo => cli::commands::field_info::field_info_command(prime_id),
     }
 }
 
@@ -215,8 +304,15 @@ fn command_start_dir(cmd: &Commands) -> std::path::PathBuf {
         | Commands::Compile { path, .. }
         | Commands::Inspect { path, .. }
         | Commands::Circuit { path, .. }
-        | Commands::Circom { path, .. } => path.as_deref(),
-        Commands::Init { .. } => None,
+        | Commands::Circom { path, .. }
+        | Commands::Watch { path, .. }
+        | Commands::Profile { path, .. }
+        | Commands::Analyze { path, .. }
+        | Commands::Check { path, .. } => path.as_deref(),
+        Commands::Explain { .. }
+        | Commands::ListBuiltins
+        | Commands::FieldInfo
+        | Commands::Init { .. } => None,
     };
 
     if let Some(p) = path_arg {
@@ -364,10 +460,87 @@ fn build_overrides(cli: &Cli) -> CliOverrides {
             circuit_stats: *circuit_stats,
         },
 
+        Commands::Watch {
+            path, no_optimize, ..
+        }
+        | Commands::Profile {
+            path, no_optimize, ..
+        }
+        | Commands::Analyze {
+            path, no_optimize, ..
+        } => CliOverrides {
+            path: path.clone(),
+            error_format: cli.error_format.clone(),
+            prime: cli.prime.clone(),
+            backend: None,
+            prove_backend: None,
+            optimize: no_optimize.map(|no| !no),
+            r1cs_path: None,
+            wtns_path: None,
+            solidity_path: None,
+            plonkish_json_path: None,
+            max_heap: None,
+            stress_gc: false,
+            gc_stats: false,
+            circuit_stats: false,
+        },
+
+        Commands::Check { path, format } => CliOverrides {
+            path: path.clone(),
+            error_format: format.clone().or_else(|| cli.error_format.clone()),
+            prime: cli.prime.clone(),
+            backend: None,
+            prove_backend: None,
+            optimize: None,
+            r1cs_path: None,
+            wtns_path: None,
+            solidity_path: None,
+            plonkish_json_path: None,
+            max_heap: None,
+            stress_gc: false,
+            gc_stats: false,
+            circuit_stats: false,
+        },
+
+        Commands::Explain { .. } | Commands::ListBuiltins | Commands::FieldInfo => CliOverrides {
+            path: None,
+            error_format: cli.error_format.clone(),
+            prime: cli.prime.clone(),
+            backend: None,
+            prove_backend: None,
+            optimize: None,
+            r1cs_path: None,
+            wtns_path: None,
+            solidity_path: None,
+            plonkish_json_path: None,
+            max_heap: None,
+            stress_gc: false,
+            gc_stats: false,
+            circuit_stats: false,
+        },
+
         Commands::Init { .. } => unreachable!(),
     }
 }
 
+/// Resolve `--opt-level` / `--no-optimize` against the config's
+/// `optimize` setting. The two flags are mutually exclusive.
+fn resolve_opt_level(
+    opt_level: Option<u8>,
+    no_optimize: Option<bool>,
+    optimize: bool,
+) -> Result<OptLevel> {
+    match opt_level {
+        Some(_) if no_optimize == Some(true) => Err(anyhow::anyhow!(
+            "--no-optimize and --opt-level are mutually exclusive"
+        )),
+        Some(n) => OptLevel::from_level(n)
+            .ok_or_else(|| anyhow::anyhow!("invalid --opt-level `{n}` (expected 0, 1, or 2)")),
+        None if optimize => Ok(OptLevel::O2),
+        None => Ok(OptLevel::O0),
+    }
+}
+
 /// Validate that the (prime, backend) combination is supported.
 ///
 /// Goldilocks+r1cs is allowed for constraint generation and witness, but
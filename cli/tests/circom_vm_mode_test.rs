@@ -19,13 +19,10 @@
 //! compile_call → opcode → handler → `evaluate_template_witness`
 //! dispatch pipeline is wired correctly.
 
-use cli::commands::ErrorFormat;
-use memory::field::PrimeId;
+use cli::commands::run::RunOptions;
 use std::io::Write;
 use tempfile::TempDir;
 
-const EF: ErrorFormat = ErrorFormat::Human;
-
 struct CircomFixture {
     _dir: TempDir,
     dir_path: std::path::PathBuf,
@@ -54,18 +51,7 @@ fn write_fixture(circom_src: &str, ach_src: &str) -> CircomFixture {
 }
 
 fn run(fixture: &CircomFixture) -> anyhow::Result<()> {
-    cli::commands::run::run_file(
-        fixture.ach_path.to_str().unwrap(),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    )
+    cli::commands::run::run_file(fixture.ach_path.to_str().unwrap(), &RunOptions::default())
 }
 
 #[test]
@@ -305,17 +291,6 @@ assert(h == 0p{expected_hex})
     f.write_all(ach_src.as_bytes()).expect("write ach");
     f.flush().expect("flush");
 
-    cli::commands::run::run_file(
-        ach_path.to_str().unwrap(),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    )
-    .expect("Poseidon(2)([1, 2]) should match native poseidon(1, 2)");
+    cli::commands::run::run_file(ach_path.to_str().unwrap(), &RunOptions::default())
+        .expect("Poseidon(2)([1, 2]) should match native poseidon(1, 2)");
 }
@@ -1,11 +1,8 @@
 use std::io::Write;
 
-use cli::commands::ErrorFormat;
-use memory::field::PrimeId;
+use cli::commands::circuit::CircuitOptions;
 use tempfile::NamedTempFile;
 
-const EF: ErrorFormat = ErrorFormat::Human;
-
 fn fixture(name: &str) -> String {
     format!(
         "{}/test/circuit/{name}",
@@ -31,19 +28,12 @@ fn circuit_stats_basic_mul() {
     let tmpdir = tempfile::tempdir().unwrap();
     let result = cli::commands::circuit::circuit_command(
         src.path().to_str().unwrap(),
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        true, // circuit_stats
-        EF,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            circuit_stats: true,
+            ..Default::default()
+        },
     );
     assert!(result.is_ok(), "circuit_command failed: {:?}", result.err());
 }
@@ -53,19 +43,12 @@ fn circuit_stats_poseidon() {
     let tmpdir = tempfile::tempdir().unwrap();
     let result = cli::commands::circuit::circuit_command(
         &fixture("poseidon.ach"),
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        true, // circuit_stats
-        EF,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            circuit_stats: true,
+            ..Default::default()
+        },
     );
     assert!(result.is_ok(), "circuit_command failed: {:?}", result.err());
 }
@@ -75,19 +58,12 @@ fn circuit_stats_merkle() {
     let tmpdir = tempfile::tempdir().unwrap();
     let result = cli::commands::circuit::circuit_command(
         &fixture("merkle.ach"),
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        true, // circuit_stats
-        EF,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            circuit_stats: true,
+            ..Default::default()
+        },
     );
     assert!(result.is_ok(), "circuit_command failed: {:?}", result.err());
 }
@@ -97,19 +73,12 @@ fn circuit_stats_comparison() {
     let tmpdir = tempfile::tempdir().unwrap();
     let result = cli::commands::circuit::circuit_command(
         &fixture("comparison_ops.ach"),
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        true, // circuit_stats
-        EF,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            circuit_stats: true,
+            ..Default::default()
+        },
     );
     assert!(result.is_ok(), "circuit_command failed: {:?}", result.err());
 }
@@ -119,19 +88,12 @@ fn circuit_stats_disabled_no_crash() {
     let tmpdir = tempfile::tempdir().unwrap();
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false, // circuit_stats disabled
-        EF,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            circuit_stats: false,
+            ..Default::default()
+        },
     );
     assert!(result.is_ok());
 }
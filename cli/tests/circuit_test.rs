@@ -1,11 +1,10 @@
 use std::io::Write;
 
+use cli::commands::circuit::CircuitOptions;
 use cli::commands::ErrorFormat;
-use memory::field::PrimeId;
+use ir::passes::OptLevel;
 use tempfile::NamedTempFile;
 
-const EF: ErrorFormat = ErrorFormat::Human;
-
 #[path = "circuit_test/assert_message.rs"]
 mod assert_message;
 #[path = "circuit_test/basic.rs"]
@@ -20,6 +19,8 @@ mod flags;
 mod input_file;
 #[path = "circuit_test/plonkish_json.rs"]
 mod plonkish_json;
+#[path = "circuit_test/stdin.rs"]
+mod stdin;
 #[path = "circuit_test/witness.rs"]
 mod witness;
 
@@ -8,19 +8,11 @@ fn circuit_assert_eq_with_message_compiles() {
 
     let result = cli::commands::circuit::circuit_command(
         &fixture("assert_message.ach"),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            ..Default::default()
+        },
     );
     assert!(
         result.is_ok(),
@@ -42,19 +34,12 @@ fn circuit_assert_eq_message_shown_on_failure() {
 
     let result = cli::commands::circuit::circuit_command(
         src.path().to_str().unwrap(),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        Some("x=1,y=2"),
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            inputs: Some("x=1,y=2"),
+            ..Default::default()
+        },
     );
     let err = result.unwrap_err();
     let msg = format!("{err:?}");
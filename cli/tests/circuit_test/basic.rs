@@ -8,19 +8,11 @@ fn circuit_r1cs_basic_compilation() {
 
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            ..Default::default()
+        },
     );
     assert!(result.is_ok(), "circuit r1cs failed: {:?}", result.err());
     assert!(r1cs.exists(), "R1CS file was not created");
@@ -36,19 +28,12 @@ fn circuit_plonkish_basic_compilation() {
 
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        None,
-        None,
-        false,
-        "plonkish",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            backend: "plonkish",
+            ..Default::default()
+        },
     );
     assert!(
         result.is_ok(),
@@ -62,19 +47,11 @@ fn circuit_nonexistent_file_error() {
     let tmpdir = tempfile::tempdir().unwrap();
     let result = cli::commands::circuit::circuit_command(
         "/tmp/nonexistent_achronyme_test.ach",
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            ..Default::default()
+        },
     );
     assert!(result.is_err(), "nonexistent file should error");
 }
@@ -86,19 +63,11 @@ fn circuit_invalid_source_error() {
     let tmpdir = tempfile::tempdir().unwrap();
     let result = cli::commands::circuit::circuit_command(
         src.path().to_str().unwrap(),
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            ..Default::default()
+        },
     );
     assert!(result.is_err(), "invalid source should error");
 }
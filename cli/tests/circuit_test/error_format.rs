@@ -7,19 +7,12 @@ fn circuit_json_error_format() {
     let tmpdir = tempfile::tempdir().unwrap();
     let result = cli::commands::circuit::circuit_command(
         src.path().to_str().unwrap(),
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        ErrorFormat::Json,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            error_format: ErrorFormat::Json,
+            ..Default::default()
+        },
     );
     assert!(result.is_err());
 }
@@ -31,19 +24,12 @@ fn circuit_short_error_format() {
     let tmpdir = tempfile::tempdir().unwrap();
     let result = cli::commands::circuit::circuit_command(
         src.path().to_str().unwrap(),
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        ErrorFormat::Short,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            error_format: ErrorFormat::Short,
+            ..Default::default()
+        },
     );
     assert!(result.is_err());
 }
@@ -19,19 +19,12 @@ fn circuit_r1cs_poseidon() {
     let inputs = format!("expected={expected_dec},a=1,b=2,c=3");
     let result = cli::commands::circuit::circuit_command(
         &fixture("poseidon.ach"),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        Some(&inputs),
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            inputs: Some(&inputs),
+            ..Default::default()
+        },
     );
     assert!(
         result.is_ok(),
@@ -48,19 +41,12 @@ fn circuit_r1cs_range_check() {
 
     let result = cli::commands::circuit::circuit_command(
         &fixture("range_check.ach"),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        Some("x=200,y=60000"),
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            inputs: Some("x=200,y=60000"),
+            ..Default::default()
+        },
     );
     assert!(
         result.is_ok(),
@@ -78,19 +64,636 @@ fn circuit_r1cs_mux() {
     // mux(1, 42, 99) = 42
     let result = cli::commands::circuit::circuit_command(
         &fixture("mux.ach"),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        Some("out=42,cond=1,a=42,b=99"),
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            inputs: Some("out=42,cond=1,a=42,b=99"),
+            ..Default::default()
+        },
     );
     assert!(result.is_ok(), "mux circuit failed: {:?}", result.err());
 }
+
+/// Inputs for `merkle_arity4.ach`: the leaf sits at slot 2 of the first
+/// level and its parent at slot 0 of the second. `indices` overrides the
+/// selector blocks so negative tests can reuse the same tree.
+fn merkle_arity4_inputs(indices: [u64; 8]) -> String {
+    use constraints::poseidon::{poseidon_hash, PoseidonParams};
+    use memory::FieldElement;
+
+    let params = PoseidonParams::bn254_t3();
+    let fold = |children: [FieldElement; 4]| {
+        children[1..]
+            .iter()
+            .fold(children[0], |acc, c| poseidon_hash(&params, acc, *c))
+    };
+    let fe = FieldElement::from_u64;
+    let leaf = fe(7);
+    let path = [11, 12, 13, 21, 22, 23];
+    let node = fold([fe(11), fe(12), leaf, fe(13)]);
+    let root = fold([node, fe(21), fe(22), fe(23)]);
+
+    let mut inputs = format!("root={root},leaf={leaf}");
+    for (i, p) in path.iter().enumerate() {
+        inputs.push_str(&format!(",path_{i}={p}"));
+    }
+    for (i, h) in indices.iter().enumerate() {
+        inputs.push_str(&format!(",indices_{i}={h}"));
+    }
+    inputs
+}
+
+fn run_merkle_arity4(indices: [u64; 8]) -> anyhow::Result<()> {
+    let tmpdir = tempfile::tempdir().unwrap();
+    cli::commands::circuit::circuit_command(
+        &fixture("merkle_arity4.ach"),
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            inputs: Some(&merkle_arity4_inputs(indices)),
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn circuit_r1cs_merkle_arity4() {
+    let result = run_merkle_arity4([0, 0, 1, 0, 1, 0, 0, 0]);
+    assert!(
+        result.is_ok(),
+        "merkle_verify_arity circuit failed: {:?}",
+        result.err()
+    );
+}
+
+#[test]
+fn circuit_r1cs_merkle_arity4_wrong_position_rejected() {
+    // Leaf claimed at slot 1 instead of 2 → different root.
+    let result = run_merkle_arity4([0, 1, 0, 0, 1, 0, 0, 0]);
+    assert!(result.is_err(), "wrong position must not verify");
+}
+
+#[test]
+fn circuit_r1cs_merkle_arity4_non_one_hot_rejected() {
+    let result = run_merkle_arity4([0, 0, 1, 1, 1, 0, 0, 0]);
+    assert!(result.is_err(), "two selectors set must not verify");
+    let result = run_merkle_arity4([0, 0, 0, 0, 1, 0, 0, 0]);
+    assert!(result.is_err(), "no selector set must not verify");
+}
+
+#[test]
+fn circuit_merkle_arity_path_length_validated() {
+    let src = write_temp_source(
+        "circuit m(root: Public, leaf: Witness, path: Witness Field[4], indices: Witness Field[8]) {
+            merkle_verify_arity(root, leaf, path, indices, 4)
+        }",
+    );
+    let tmpdir = tempfile::tempdir().unwrap();
+    let result = cli::commands::circuit::circuit_command(
+        src.path().to_str().unwrap(),
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            ..Default::default()
+        },
+    );
+    let err = format!("{:?}", result.unwrap_err());
+    assert!(err.contains("multiple of arity - 1"), "got: {err}");
+}
+
+fn run_r1cs_fixture(name: &str, inputs: &str) -> anyhow::Result<()> {
+    let tmpdir = tempfile::tempdir().unwrap();
+    cli::commands::circuit::circuit_command(
+        &fixture(name),
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            inputs: Some(inputs),
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn circuit_r1cs_fold_add() {
+    // 3 + 5 + 7 + 11 = 26
+    let result = run_r1cs_fixture("fold_sum.ach", "out=26,xs_0=3,xs_1=5,xs_2=7,xs_3=11");
+    assert!(
+        result.is_ok(),
+        "fold add circuit failed: {:?}",
+        result.err()
+    );
+
+    let result = run_r1cs_fixture("fold_sum.ach", "out=27,xs_0=3,xs_1=5,xs_2=7,xs_3=11");
+    assert!(result.is_err(), "wrong sum must not verify");
+}
+
+#[test]
+fn circuit_r1cs_fold_poseidon() {
+    use constraints::poseidon::{poseidon_hash, PoseidonParams};
+    use memory::FieldElement;
+
+    let params = PoseidonParams::bn254_t3();
+    let expected = [4u64, 5, 6]
+        .iter()
+        .fold(FieldElement::from_u64(0), |acc, x| {
+            poseidon_hash(&params, acc, FieldElement::from_u64(*x))
+        });
+
+    let inputs = format!("out={expected},xs_0=4,xs_1=5,xs_2=6");
+    let result = run_r1cs_fixture("fold_poseidon.ach", &inputs);
+    assert!(
+        result.is_ok(),
+        "fold poseidon circuit failed: {:?}",
+        result.err()
+    );
+}
+
+#[test]
+fn circuit_fold_rejects_unknown_op() {
+    let src = write_temp_source(
+        "circuit f(out: Public, xs: Witness Field[2]) {
+            assert_eq(fold(xs, 0, \"sub\"), out)
+        }",
+    );
+    let tmpdir = tempfile::tempdir().unwrap();
+    let result = cli::commands::circuit::circuit_command(
+        src.path().to_str().unwrap(),
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            ..Default::default()
+        },
+    );
+    let err = format!("{:?}", result.unwrap_err());
+    assert!(err.contains("unknown fold op"), "got: {err}");
+}
+
+#[test]
+fn circuit_r1cs_assert_all_eq() {
+    let result = run_r1cs_fixture("assert_all_eq.ach", "x=7,y=7,z=7");
+    assert!(
+        result.is_ok(),
+        "assert_all_eq circuit failed: {:?}",
+        result.err()
+    );
+
+    let result = run_r1cs_fixture("assert_all_eq.ach", "x=7,y=7,z=8");
+    assert!(result.is_err(), "z differs, so the circuit must not verify");
+}
+
+#[test]
+fn circuit_r1cs_map_square() {
+    let result = run_r1cs_fixture(
+        "map_square.ach",
+        "out_0=4,out_1=25,out_2=49,xs_0=2,xs_1=5,xs_2=7",
+    );
+    assert!(result.is_ok(), "map circuit failed: {:?}", result.err());
+
+    let result = run_r1cs_fixture(
+        "map_square.ach",
+        "out_0=4,out_1=25,out_2=48,xs_0=2,xs_1=5,xs_2=7",
+    );
+    assert!(result.is_err(), "wrong square must not verify");
+}
+
+#[test]
+fn circuit_user_fn_named_map_takes_precedence() {
+    for src in [
+        "fn map(a, b) { return a + b }
+        circuit f(out: Public, x: Witness) {
+            assert_eq(map(x, 1), out)
+        }",
+        "circuit f(out: Public, x: Witness) {
+            fn map(a, b) { return a + b }
+            assert_eq(map(x, 1), out)
+        }",
+    ] {
+        let src = write_temp_source(src);
+        let tmpdir = tempfile::tempdir().unwrap();
+        let result = cli::commands::circuit::circuit_command(
+            src.path().to_str().unwrap(),
+            &CircuitOptions {
+                r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+                wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+                inputs: Some("out=3,x=2"),
+                ..Default::default()
+            },
+        );
+        assert!(result.is_ok(), "{:?}", result.err());
+    }
+}
+
+#[test]
+fn circuit_map_rejects_unknown_op_and_scalar() {
+    for (body, expected) in [
+        ("let ys = map(xs, \"cube\")", "unknown map op"),
+        ("let ys = map(x, \"square\")", "expected array"),
+    ] {
+        let src = write_temp_source(&format!(
+            "circuit f(x: Public, xs: Witness Field[2]) {{
+                {body}
+                assert_eq(ys[0], x)
+            }}"
+        ));
+        let tmpdir = tempfile::tempdir().unwrap();
+        let result = cli::commands::circuit::circuit_command(
+            src.path().to_str().unwrap(),
+            &CircuitOptions {
+                r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+                wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+                ..Default::default()
+            },
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(err.contains(expected), "{body}: got {err}");
+    }
+}
+
+#[test]
+fn circuit_r1cs_assert_in_set() {
+    let result = run_r1cs_fixture("assert_in_set.ach", "x=2");
+    assert!(
+        result.is_ok(),
+        "assert_in_set circuit failed: {:?}",
+        result.err()
+    );
+
+    let result = run_r1cs_fixture("assert_in_set.ach", "x=5");
+    assert!(
+        result.is_err(),
+        "5 is not in the set, so the circuit must not verify"
+    );
+}
+
+#[test]
+fn circuit_r1cs_bits() {
+    // 9 = 0b1001 → bs[0] = 1, bs[3] = 1
+    let result = run_r1cs_fixture("bits.ach", "b0=1,b3=1,x=9");
+    assert!(result.is_ok(), "bits circuit failed: {:?}", result.err());
+
+    // 16 does not fit in 4 bits; 6 = 0b0110 has b0 = 0
+    for inputs in ["b0=0,b3=0,x=16", "b0=1,b3=0,x=6"] {
+        let result = run_r1cs_fixture("bits.ach", inputs);
+        assert!(result.is_err(), "{inputs} must not verify");
+    }
+}
+
+#[test]
+fn circuit_r1cs_popcount_and_is_power_of_two() {
+    // popcount(7, 4) = 0b0111 → 3; 8 is a power of two
+    let result = run_r1cs_fixture("popcount.ach", "count=3,x=7,y=8");
+    assert!(
+        result.is_ok(),
+        "popcount circuit failed: {:?}",
+        result.err()
+    );
+
+    for inputs in ["count=2,x=7,y=8", "count=3,x=7,y=6", "count=3,x=7,y=0"] {
+        let result = run_r1cs_fixture("popcount.ach", inputs);
+        assert!(result.is_err(), "{inputs} must not verify");
+    }
+}
+
+#[test]
+fn circuit_r1cs_bool_builtins_truth_table() {
+    for (a, b) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+        let inputs = format!(
+            "and_out={},or_out={},xor_out={},not_out={},a={a},b={b}",
+            a & b,
+            a | b,
+            a ^ b,
+            1 - a
+        );
+        let result = run_r1cs_fixture("bool_builtins.ach", &inputs);
+        assert!(result.is_ok(), "{inputs}: {:?}", result.err());
+    }
+
+    // bool_xor(1, 1) == 0
+    let result = run_r1cs_fixture(
+        "bool_builtins.ach",
+        "and_out=1,or_out=1,xor_out=1,not_out=0,a=1,b=1",
+    );
+    assert!(result.is_err(), "bool_xor(1, 1) must be 0");
+}
+
+#[test]
+fn circuit_bool_xor_rejects_non_boolean_witness() {
+    // 2 + 0 - 2*2*0 = 2 satisfies the xor formula, but not a*(a-1) == 0.
+    let src = write_temp_source(
+        "circuit f(out: Public, a: Witness, b: Witness) {
+            assert_eq(bool_xor(a, b), out)
+        }",
+    );
+    let tmpdir = tempfile::tempdir().unwrap();
+    let result = cli::commands::circuit::circuit_command(
+        src.path().to_str().unwrap(),
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            inputs: Some("out=2,a=2,b=0"),
+            ..Default::default()
+        },
+    );
+    assert!(result.is_err(), "non-boolean operand must not verify");
+}
+
+#[test]
+fn circuit_assert_bool_rejects_non_boolean_witness() {
+    let src = write_temp_source(
+        "circuit f(flag: Witness) {
+            assert_bool(flag)
+        }",
+    );
+    for (flag, ok) in [("0", true), ("1", true), ("2", false)] {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let inputs = format!("flag={flag}");
+        let result = cli::commands::circuit::circuit_command(
+            src.path().to_str().unwrap(),
+            &CircuitOptions {
+                r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+                wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+                inputs: Some(&inputs),
+                ..Default::default()
+            },
+        );
+        assert_eq!(result.is_ok(), ok, "flag={flag}: {:?}", result.err());
+    }
+}
+
+#[test]
+fn circuit_r1cs_array_negate_and_scale() {
+    let result = run_r1cs_fixture(
+        "array_arith.ach",
+        "scaled_0=2,scaled_1=4,scaled_2=6,xs_0=1,xs_1=2,xs_2=3",
+    );
+    assert!(
+        result.is_ok(),
+        "array arithmetic circuit failed: {:?}",
+        result.err()
+    );
+
+    let result = run_r1cs_fixture(
+        "array_arith.ach",
+        "scaled_0=2,scaled_1=4,scaled_2=7,xs_0=1,xs_1=2,xs_2=3",
+    );
+    assert!(result.is_err(), "wrong scaled element must not verify");
+}
+
+#[test]
+fn circuit_r1cs_dot() {
+    // 1*4 + 2*5 + 3*6 = 32
+    let result = run_r1cs_fixture("dot.ach", "out=32,a_0=1,a_1=2,a_2=3,b_0=4,b_1=5,b_2=6");
+    assert!(result.is_ok(), "dot circuit failed: {:?}", result.err());
+
+    let result = run_r1cs_fixture("dot.ach", "out=33,a_0=1,a_1=2,a_2=3,b_0=4,b_1=5,b_2=6");
+    assert!(result.is_err(), "wrong inner product must not verify");
+}
+
+#[test]
+fn circuit_r1cs_merkle_root() {
+    use constraints::poseidon::{poseidon_hash, PoseidonParams};
+    use memory::FieldElement;
+
+    let params = PoseidonParams::bn254_t3();
+    let leaf = FieldElement::from_u64;
+    let root = poseidon_hash(
+        &params,
+        poseidon_hash(&params, leaf(10), leaf(20)),
+        poseidon_hash(&params, leaf(30), leaf(40)),
+    );
+
+    let leaves = "leaves_0=10,leaves_1=20,leaves_2=30,leaves_3=40";
+    let result = run_r1cs_fixture("merkle_root.ach", &format!("root={root},{leaves}"));
+    assert!(
+        result.is_ok(),
+        "merkle_root circuit failed: {:?}",
+        result.err()
+    );
+
+    // Swapping two leaves changes the root.
+    let swapped = "leaves_0=20,leaves_1=10,leaves_2=30,leaves_3=40";
+    let result = run_r1cs_fixture("merkle_root.ach", &format!("root={root},{swapped}"));
+    assert!(result.is_err(), "swapped leaves should not match the root");
+}
+
+#[test]
+fn circuit_merkle_root_rejects_non_power_of_two() {
+    let src = write_temp_source(
+        "circuit f(root: Public, leaves: Witness Field[3]) {
+            assert_eq(merkle_root(leaves), root)
+        }",
+    );
+    let tmpdir = tempfile::tempdir().unwrap();
+    let result = cli::commands::circuit::circuit_command(
+        src.path().to_str().unwrap(),
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            ..Default::default()
+        },
+    );
+    let err = format!("{:?}", result.unwrap_err());
+    assert!(err.contains("power-of-two number of leaves"), "got: {err}");
+}
+
+#[test]
+fn circuit_r1cs_poseidon_domain() {
+    use constraints::poseidon::{poseidon_hash, PoseidonParams};
+    use memory::FieldElement;
+
+    let (a, b) = (FieldElement::from_u64(1), FieldElement::from_u64(2));
+    let untagged = poseidon_hash(&PoseidonParams::bn254_t3(), a, b);
+    let tagged = poseidon_hash(
+        &PoseidonParams::bn254_t3().with_domain_tag(FieldElement::from_u64(7)),
+        a,
+        b,
+    );
+    assert_ne!(untagged, tagged);
+
+    let result = run_r1cs_fixture("poseidon_domain.ach", &format!("out={tagged},a=1,b=2"));
+    assert!(
+        result.is_ok(),
+        "poseidon_domain circuit failed: {:?}",
+        result.err()
+    );
+
+    let result = run_r1cs_fixture("poseidon_domain.ach", &format!("out={untagged},a=1,b=2"));
+    assert!(
+        result.is_err(),
+        "untagged digest must not verify under tag 7"
+    );
+}
+
+#[test]
+fn circuit_r1cs_poseidon2() {
+    use constraints::poseidon2::{poseidon2_hash, Poseidon2Params};
+    use memory::FieldElement;
+
+    let (a, b) = (FieldElement::from_u64(1), FieldElement::from_u64(2));
+    let digest = poseidon2_hash(&Poseidon2Params::bn254_t3(), a, b);
+
+    let result = run_r1cs_fixture("poseidon2.ach", &format!("out={digest},a=1,b=2"));
+    assert!(
+        result.is_ok(),
+        "poseidon2 circuit failed: {:?}",
+        result.err()
+    );
+
+    let result = run_r1cs_fixture("poseidon2.ach", &format!("out={digest},a=2,b=1"));
+    assert!(result.is_err(), "swapped inputs must not verify");
+}
+
+#[test]
+fn circuit_r1cs_inv_neg() {
+    let result = run_r1cs_fixture("inv_neg.ach", "out=7,x=7");
+    assert!(result.is_ok(), "inv/neg circuit failed: {:?}", result.err());
+
+    let err = run_r1cs_fixture("inv_neg.ach", "out=0,x=0").unwrap_err();
+    assert!(
+        format!("{err:?}").contains("division by zero"),
+        "got: {err:?}"
+    );
+}
+
+#[test]
+fn circuit_r1cs_fn_early_return() {
+    // scaled(only_seven(add(3, 4))) = 2 * 7 + 1
+    let result = run_r1cs_fixture("fn_early_return.ach", "out=15,x=3,y=4");
+    assert!(result.is_ok(), "early return failed: {:?}", result.err());
+
+    // add(3, 5) = 8 takes the `else { return 0 }` branch: 2 * 0 + 1
+    let result = run_r1cs_fixture("fn_early_return.ach", "out=1,x=3,y=5");
+    assert!(
+        result.is_ok(),
+        "else-branch return failed: {:?}",
+        result.err()
+    );
+
+    let result = run_r1cs_fixture("fn_early_return.ach", "out=14,x=3,y=4");
+    assert!(result.is_err(), "wrong output must not verify");
+}
+
+#[test]
+fn circuit_r1cs_const_sizes_and_bounds() {
+    // N = 4 elements, each range-checked to BITS = 8 bits.
+    let result = run_r1cs_fixture(
+        "const_sum.ach",
+        "out=100,arr_0=10,arr_1=20,arr_2=30,arr_3=40",
+    );
+    assert!(result.is_ok(), "const circuit failed: {:?}", result.err());
+
+    let result = run_r1cs_fixture(
+        "const_sum.ach",
+        "out=300,arr_0=10,arr_1=20,arr_2=30,arr_3=240",
+    );
+    assert!(result.is_ok(), "const circuit failed: {:?}", result.err());
+
+    // 256 does not fit in BITS = 8.
+    let result = run_r1cs_fixture(
+        "const_sum.ach",
+        "out=316,arr_0=10,arr_1=20,arr_2=30,arr_3=256",
+    );
+    assert!(result.is_err(), "range_check(_, BITS) must reject 256");
+}
+
+#[test]
+fn circuit_r1cs_output_is_public_wire() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let wtns = tmpdir.path().join("out.wtns");
+    let result = cli::commands::circuit::circuit_command(
+        &fixture("output_square.ach"),
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            inputs: Some("x=3"),
+            ..Default::default()
+        },
+    );
+    assert!(result.is_ok(), "output circuit failed: {:?}", result.err());
+
+    // wtns: magic, version, section count, then the header section
+    // (type, size, n8, prime, witness count) and the values section.
+    let bytes = std::fs::read(&wtns).unwrap();
+    let n8 = u32::from_le_bytes(bytes[24..28].try_into().unwrap()) as usize;
+    let values = 28 + n8 + 4 + 12;
+    // Wire 0 is the constant one; wire 1 is the first public signal.
+    let wire1 = &bytes[values + n8..values + 2 * n8];
+    assert_eq!(wire1[0], 9, "y = 3 * 3 must be public wire 1");
+    assert!(wire1[1..].iter().all(|b| *b == 0));
+
+    let result = run_r1cs_fixture("output_square.ach", "x=3,y=9");
+    assert!(
+        result.is_ok(),
+        "matching output rejected: {:?}",
+        result.err()
+    );
+
+    let result = run_r1cs_fixture("output_square.ach", "x=3,y=10");
+    assert!(result.is_err(), "wrong output must not verify");
+}
+
+#[test]
+fn circuit_r1cs_witness_default() {
+    // `k` is omitted, so its declared default of 3 is used.
+    let result = run_r1cs_fixture("witness_default.ach", "out=21,x=7");
+    assert!(
+        result.is_ok(),
+        "defaulted witness failed: {:?}",
+        result.err()
+    );
+
+    // `k` is still a witness: a supplied value replaces the default.
+    let result = run_r1cs_fixture("witness_default.ach", "out=28,x=7,k=4");
+    assert!(
+        result.is_ok(),
+        "overridden default failed: {:?}",
+        result.err()
+    );
+    let result = run_r1cs_fixture("witness_default.ach", "out=21,x=7,k=4");
+    assert!(result.is_err(), "overridden default must be checked");
+}
+
+#[test]
+fn circuit_r1cs_bounded_while() {
+    let result = run_r1cs_fixture("bounded_while.ach", "steps=5,n=5");
+    assert!(
+        result.is_ok(),
+        "countdown from 5 failed: {:?}",
+        result.err()
+    );
+    let result = run_r1cs_fixture("bounded_while.ach", "steps=8,n=8");
+    assert!(
+        result.is_ok(),
+        "countdown at the bound failed: {:?}",
+        result.err()
+    );
+
+    // Wrong step count, and a countdown that needs more than `max 8`.
+    assert!(run_r1cs_fixture("bounded_while.ach", "steps=4,n=5").is_err());
+    assert!(run_r1cs_fixture("bounded_while.ach", "steps=8,n=9").is_err());
+}
+
+#[test]
+fn circuit_r1cs_bounded_while_guards_exited_iterations() {
+    // 12/2 + 12/1; the two iterations after the exit divide by x = 0.
+    let result = run_r1cs_fixture("bounded_while_div.ach", "out=18,n=2");
+    assert!(
+        result.is_ok(),
+        "division after the exit was enforced: {:?}",
+        result.err()
+    );
+    assert!(run_r1cs_fixture("bounded_while_div.ach", "out=19,n=2").is_err());
+}
+
+#[test]
+fn circuit_r1cs_ternary() {
+    let result = run_r1cs_fixture("ternary.ach", "out=42,flag=1,x=42,y=99");
+    assert!(result.is_ok(), "ternary circuit failed: {:?}", result.err());
+
+    let result = run_r1cs_fixture("ternary.ach", "out=99,flag=0,x=42,y=99");
+    assert!(result.is_ok(), "ternary circuit failed: {:?}", result.err());
+
+    let result = run_r1cs_fixture("ternary.ach", "out=42,flag=0,x=42,y=99");
+    assert!(result.is_err(), "unselected branch must not verify");
+}
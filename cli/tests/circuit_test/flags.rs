@@ -8,19 +8,12 @@ fn circuit_no_optimize_flag() {
 
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        None,
-        None,
-        true, // --no-optimize
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            opt_level: OptLevel::O0,
+            ..Default::default()
+        },
     );
     assert!(
         result.is_ok(),
@@ -30,24 +23,48 @@ fn circuit_no_optimize_flag() {
     assert!(r1cs.exists());
 }
 
+#[test]
+fn circuit_no_optimize_generates_verified_witness() {
+    // The escape hatch must cover the whole pipeline: unoptimized IR
+    // through R1CS compile, witness generation and `cs.verify`.
+    let tmpdir = tempfile::tempdir().unwrap();
+    let compile = |opt_level, name: &str| {
+        let r1cs = tmpdir.path().join(format!("{name}.r1cs"));
+        let wtns = tmpdir.path().join(format!("{name}.wtns"));
+        cli::commands::circuit::circuit_command(
+            &fixture("fold_sum.ach"),
+            &CircuitOptions {
+                r1cs_path: r1cs.to_str().unwrap(),
+                wtns_path: wtns.to_str().unwrap(),
+                input_file: Some(&fixture("fold_sum.inputs.toml")),
+                opt_level,
+                ..Default::default()
+            },
+        )
+        .unwrap_or_else(|e| panic!("{name} build failed: {e}"));
+        assert!(wtns.exists(), "{name} build should write a witness");
+        std::fs::metadata(&r1cs).unwrap().len()
+    };
+
+    let unoptimized = compile(OptLevel::O0, "o0");
+    let optimized = compile(OptLevel::O2, "o2");
+    assert!(
+        unoptimized >= optimized,
+        "--no-optimize should not shrink the R1CS ({unoptimized} < {optimized} bytes)"
+    );
+}
+
 #[test]
 fn circuit_unknown_backend_error() {
     let tmpdir = tempfile::tempdir().unwrap();
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        None,
-        None,
-        false,
-        "unknown_backend",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            backend: "unknown_backend",
+            ..Default::default()
+        },
     );
     assert!(result.is_err(), "unknown backend should error");
     let err = format!("{}", result.unwrap_err());
@@ -62,19 +79,13 @@ fn circuit_solidity_with_plonkish_rejected() {
     let tmpdir = tempfile::tempdir().unwrap();
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        None,
-        None,
-        false,
-        "plonkish",
-        PrimeId::Bn254,
-        false,
-        Some("verifier.sol"),
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            backend: "plonkish",
+            solidity_path: Some("verifier.sol"),
+            ..Default::default()
+        },
     );
     assert!(
         result.is_err(),
@@ -92,22 +103,292 @@ fn circuit_prove_without_inputs_rejected() {
     let tmpdir = tempfile::tempdir().unwrap();
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        None,
-        None,
-        false,
-        "plonkish",
-        PrimeId::Bn254,
-        true, // --prove
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            backend: "plonkish",
+            prove: true,
+            ..Default::default()
+        },
     );
     assert!(
         result.is_err(),
         "--prove without --inputs should be rejected"
     );
 }
+
+#[test]
+fn circuit_dump_witness_requires_r1cs_and_inputs() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let run = |inputs: Option<&str>, backend: &str| {
+        cli::commands::circuit::circuit_command(
+            &fixture("basic_arithmetic.ach"),
+            &CircuitOptions {
+                r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+                wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+                inputs,
+                backend,
+                dump_witness: true,
+                ..Default::default()
+            },
+        )
+    };
+
+    let err = run(None, "r1cs").unwrap_err().to_string();
+    assert!(err.contains("--dump-witness requires"), "{err}");
+    let err = run(Some("out=42,a=6,b=7"), "plonkish")
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("only supported with the r1cs backend"),
+        "{err}"
+    );
+    let result = run(Some("out=42,a=6,b=7"), "r1cs");
+    assert!(result.is_ok(), "dump-witness failed: {:?}", result.err());
+}
+
+/// Many inputs of both visibilities, each feeding its own constraints, so
+/// any hash-order dependence in lowering would shuffle wires.
+fn many_inputs_source() -> String {
+    let params: Vec<String> = (0..32)
+        .map(|i| format!("p{i}: Public"))
+        .chain((0..32).map(|i| format!("w{i}: Witness")))
+        .collect();
+    let body: String = (0..32)
+        .map(|i| {
+            format!(
+                "    let t{i} = w{i} * w{j} + p{i}\n    range_check(w{i}, 8)\n    assert_eq(t{i} - p{i}, w{i} * w{j})\n",
+                j = (i + 1) % 32
+            )
+        })
+        .collect();
+    format!("circuit many({}) {{\n{body}}}\n", params.join(", "))
+}
+
+#[test]
+fn circuit_check_determinism_many_inputs() {
+    let src = write_temp_source(&many_inputs_source());
+    let tmpdir = tempfile::tempdir().unwrap();
+    let run = |name: &str, check_determinism: bool, backend: &str| {
+        let r1cs = tmpdir.path().join(format!("{name}.r1cs"));
+        cli::commands::circuit::circuit_command(
+            src.path().to_str().unwrap(),
+            &CircuitOptions {
+                r1cs_path: r1cs.to_str().unwrap(),
+                wtns_path: tmpdir.path().join(format!("{name}.wtns")).to_str().unwrap(),
+                backend,
+                check_determinism,
+                ..Default::default()
+            },
+        )
+        .map(|()| r1cs)
+    };
+
+    let checked = run("checked", true, "r1cs").unwrap();
+    assert!(
+        !checked.exists(),
+        "--check-determinism should not write files"
+    );
+
+    // Separate invocations must also agree byte for byte on the wire layout.
+    let first = std::fs::read(run("first", false, "r1cs").unwrap()).unwrap();
+    let second = std::fs::read(run("second", false, "r1cs").unwrap()).unwrap();
+    assert!(first == second, "r1cs output differs between two runs");
+
+    let err = run("plonkish", true, "plonkish").unwrap_err().to_string();
+    assert!(
+        err.contains("only supported with the r1cs backend"),
+        "{err}"
+    );
+}
+
+#[test]
+fn circuit_entry_selects_one_export_fn() {
+    let src = write_temp_source(
+        "export fn verify_membership(root: Public, leaf: Witness) {
+            assert_eq(root, leaf * leaf)
+        }
+        export fn check_sum(total: Public, a: Witness, b: Witness) {
+            assert_eq(total, a + b)
+        }",
+    );
+    let tmpdir = tempfile::tempdir().unwrap();
+    let run = |entry: &str, inputs: &str| {
+        cli::commands::circuit::circuit_command(
+            src.path().to_str().unwrap(),
+            &CircuitOptions {
+                r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+                wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+                inputs: Some(inputs),
+                entry: Some(entry),
+                ..Default::default()
+            },
+        )
+    };
+
+    // Only the selected function is compiled, so only its inputs are needed.
+    let result = run("verify_membership", "root=9,leaf=3");
+    assert!(result.is_ok(), "verify_membership: {:?}", result.err());
+    let result = run("check_sum", "total=5,a=2,b=3");
+    assert!(result.is_ok(), "check_sum: {:?}", result.err());
+    assert!(run("verify_membership", "root=10,leaf=3").is_err());
+
+    let err = format!("{:?}", run("missing", "root=9,leaf=3").unwrap_err());
+    assert!(
+        err.contains("no circuit or `export fn` named `missing`"),
+        "{err}"
+    );
+}
+
+#[test]
+fn circuit_emit_r1cs_json() {
+    let src = write_temp_source(
+        "circuit product(out: Public, a: Witness, b: Witness) {
+            assert_eq(a * b, out)
+        }",
+    );
+    let tmpdir = tempfile::tempdir().unwrap();
+    let r1cs = tmpdir.path().join("out.r1cs");
+    let json_path = tmpdir.path().join("out.json");
+    let run = |backend: &str| {
+        cli::commands::circuit::circuit_command(
+            src.path().to_str().unwrap(),
+            &CircuitOptions {
+                r1cs_path: r1cs.to_str().unwrap(),
+                wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+                backend,
+                r1cs_json_path: Some(json_path.to_str().unwrap()),
+                ..Default::default()
+            },
+        )
+    };
+
+    run("r1cs").unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+    let constraints = json["constraints"].as_array().unwrap();
+    let binary = std::fs::read(&r1cs).unwrap();
+    let header_constraints = u32::from_le_bytes(binary[84..88].try_into().unwrap());
+    assert_eq!(json["num_constraints"], header_constraints);
+    assert_eq!(constraints.len(), header_constraints as usize);
+
+    // Wires: ONE = 0, out = 1, a = 2, b = 3. The product is `a * b = out`.
+    let (a, b, out) = (
+        serde_json::json!({ "2": "1" }),
+        serde_json::json!({ "3": "1" }),
+        serde_json::json!({ "1": "1" }),
+    );
+    assert!(
+        constraints.iter().any(
+            |c| c["c"] == out && ((c["a"] == a && c["b"] == b) || (c["a"] == b && c["b"] == a))
+        ),
+        "{json}"
+    );
+
+    let err = run("plonkish").unwrap_err().to_string();
+    assert!(
+        err.contains("only supported with the r1cs backend"),
+        "{err}"
+    );
+}
+
+#[test]
+fn circuit_emit_sym() {
+    let src = write_temp_source(
+        "circuit product(out: Public, a: Witness, b: Witness, c: Witness) {
+            assert_eq(a * b * c, out)
+        }",
+    );
+    let tmpdir = tempfile::tempdir().unwrap();
+    let sym_path = tmpdir.path().join("out.sym");
+    let run = |backend: &str| {
+        cli::commands::circuit::circuit_command(
+            src.path().to_str().unwrap(),
+            &CircuitOptions {
+                r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+                wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+                backend,
+                opt_level: OptLevel::O0,
+                sym_path: Some(sym_path.to_str().unwrap()),
+                ..Default::default()
+            },
+        )
+    };
+
+    run("r1cs").unwrap();
+    let sym = std::fs::read_to_string(&sym_path).unwrap();
+    // Wires: ONE = 0, out = 1, a = 2, b = 3, c = 4.
+    for (i, name) in ["out", "a", "b", "c"].iter().enumerate() {
+        let line = format!("{0},{0},0,main.{name}", i + 1);
+        assert!(sym.lines().any(|l| l == line), "{line} missing:\n{sym}");
+    }
+    let names: Vec<&str> = sym.lines().map(|l| l.rsplit(',').next().unwrap()).collect();
+    let unique: std::collections::HashSet<_> = names.iter().collect();
+    assert_eq!(unique.len(), names.len(), "{sym}");
+
+    let err = run("plonkish").unwrap_err().to_string();
+    assert!(
+        err.contains("only supported with the r1cs backend"),
+        "{err}"
+    );
+}
+
+#[test]
+fn circuit_max_unroll_caps_loop_iterations() {
+    let src = write_temp_source(
+        "circuit unrolled(x: Witness) {
+            for i in 0..20000 {
+                assert_eq(x, x)
+            }
+        }",
+    );
+    let tmpdir = tempfile::tempdir().unwrap();
+    let run = |max_unroll: Option<u64>| {
+        cli::commands::circuit::circuit_command(
+            src.path().to_str().unwrap(),
+            &CircuitOptions {
+                r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+                wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+                opt_level: OptLevel::O0,
+                max_unroll,
+                ..Default::default()
+            },
+        )
+    };
+
+    run(Some(50_000)).unwrap();
+    let err = run(None).unwrap_err().to_string();
+    assert!(err.contains("10000"), "{err}");
+    // Out-of-range values fall back to the default with a warning.
+    let err = run(Some(0)).unwrap_err().to_string();
+    assert!(err.contains("10000"), "{err}");
+}
+
+#[test]
+fn circuit_max_unroll_caps_bounded_while() {
+    let src = write_temp_source(
+        "circuit unrolled(x: Witness) {
+            mut i = 0
+            while i < 3 max 20000 {
+                i = i + 1
+            }
+            assert_eq(x, x)
+        }",
+    );
+    let tmpdir = tempfile::tempdir().unwrap();
+    let run = |max_unroll: Option<u64>| {
+        cli::commands::circuit::circuit_command(
+            src.path().to_str().unwrap(),
+            &CircuitOptions {
+                r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+                wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+                max_unroll,
+                ..Default::default()
+            },
+        )
+    };
+
+    run(Some(50_000)).unwrap();
+    let err = run(None).unwrap_err().to_string();
+    assert!(err.contains("10000"), "{err}");
+}
@@ -10,24 +10,59 @@ fn circuit_input_file_toml() {
 
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        None,
-        Some(toml_path.to_str().unwrap()),
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            input_file: Some(toml_path.to_str().unwrap()),
+            ..Default::default()
+        },
     );
     assert!(result.is_ok(), "input-file failed: {:?}", result.err());
     assert!(wtns.exists(), "wtns should be created with input-file");
 }
 
+#[test]
+fn circuit_input_file_csv() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let wtns = tmpdir.path().join("out.wtns");
+
+    let result = cli::commands::circuit::circuit_command(
+        &fixture("fold_sum.ach"),
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            input_file: Some(&fixture("fold_sum.inputs.csv")),
+            ..Default::default()
+        },
+    );
+    assert!(result.is_ok(), "csv input-file failed: {:?}", result.err());
+    assert!(
+        wtns.exists(),
+        "wtns should be created with a csv input-file"
+    );
+}
+
+#[test]
+fn circuit_input_format_requires_input_file() {
+    let tmpdir = tempfile::tempdir().unwrap();
+
+    let result = cli::commands::circuit::circuit_command(
+        &fixture("basic_arithmetic.ach"),
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            inputs: Some("out=42,a=6,b=7"),
+            input_format: Some("csv"),
+            ..Default::default()
+        },
+    );
+    let err = result.unwrap_err().to_string();
+    assert!(
+        err.contains("--input-format requires --input-file"),
+        "{err}"
+    );
+}
+
 #[test]
 fn circuit_inputs_and_input_file_mutually_exclusive() {
     let tmpdir = tempfile::tempdir().unwrap();
@@ -36,19 +71,13 @@ fn circuit_inputs_and_input_file_mutually_exclusive() {
 
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        Some("out=42,a=6,b=7"),
-        Some(toml_path.to_str().unwrap()),
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            inputs: Some("out=42,a=6,b=7"),
+            input_file: Some(toml_path.to_str().unwrap()),
+            ..Default::default()
+        },
     );
     assert!(
         result.is_err(),
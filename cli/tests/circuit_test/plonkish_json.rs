@@ -9,19 +9,14 @@ fn circuit_plonkish_json_with_inputs() {
 
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        Some("out=42,a=6,b=7"),
-        None,
-        false,
-        "plonkish",
-        PrimeId::Bn254,
-        false,
-        None,
-        Some(json_path.to_str().unwrap()),
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            inputs: Some("out=42,a=6,b=7"),
+            backend: "plonkish",
+            plonkish_json_path: Some(json_path.to_str().unwrap()),
+            ..Default::default()
+        },
     );
     assert!(
         result.is_ok(),
@@ -43,19 +38,13 @@ fn circuit_plonkish_json_without_inputs() {
 
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        None,
-        None,
-        false,
-        "plonkish",
-        PrimeId::Bn254,
-        false,
-        None,
-        Some(json_path.to_str().unwrap()),
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            backend: "plonkish",
+            plonkish_json_path: Some(json_path.to_str().unwrap()),
+            ..Default::default()
+        },
     );
     assert!(
         result.is_ok(),
@@ -76,19 +65,12 @@ fn circuit_plonkish_json_with_r1cs_rejected() {
 
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        tmpdir.path().join("out.r1cs").to_str().unwrap(),
-        tmpdir.path().join("out.wtns").to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        Some(json_path.to_str().unwrap()),
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            plonkish_json_path: Some(json_path.to_str().unwrap()),
+            ..Default::default()
+        },
     );
     assert!(
         result.is_err(),
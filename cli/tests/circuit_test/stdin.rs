@@ -0,0 +1,82 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// Run `ach circuit <args>` in a scratch directory with `source` on stdin.
+fn ach_circuit_stdin(args: &[&str], source: &str) -> Output {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ach"))
+        .arg("--no-config")
+        .arg("circuit")
+        .args(args)
+        .current_dir(tmpdir.path())
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_BACKTRACE")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to spawn ach");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+const SQUARE: &str = "circuit square(out: Public, a: Witness) {\n    assert_eq(a * a, out)\n}\n";
+
+#[test]
+fn circuit_reads_source_from_stdin() {
+    for args in [&["-"][..], &["--stdin"][..]] {
+        let out = ach_circuit_stdin(args, SQUARE);
+        // The compile summary is progress output, written to stderr.
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(
+            out.status.success(),
+            "ach circuit {args:?} failed: {stderr}"
+        );
+        assert!(
+            stderr.contains("Compiling <stdin>..."),
+            "source name missing from output:\n{stderr}"
+        );
+        assert!(
+            stderr.contains("Constraints:    1"),
+            "constraint count missing from output:\n{stderr}"
+        );
+    }
+}
+
+#[test]
+fn circuit_prints_lowering_warnings() {
+    let source = "circuit c(out: Public, a: Witness) {\n\
+                  \x20   fn f(x) {\n\
+                  \x20       return x + 1\n\
+                  \x20       x * x\n\
+                  \x20   }\n\
+                  \x20   assert_eq(f(a), out)\n\
+                  }\n";
+    let out = ach_circuit_stdin(&["--stdin", "--error-format", "short"], source);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(out.status.success(), "{stderr}");
+    assert!(stderr.contains("unreachable code"), "{stderr}");
+}
+
+#[test]
+fn circuit_stdin_errors_name_stdin() {
+    let source = "circuit bad(out: Public, a: Witness) {\n    assert_eq(a * b, out)\n}\n";
+
+    let out = ach_circuit_stdin(&["--stdin", "--error-format", "short"], source);
+    assert!(!out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("<stdin>:2:19:"), "{stderr}");
+
+    let out = ach_circuit_stdin(&["-"], source);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("--> <stdin>:2:19"), "{stderr}");
+
+    let out = ach_circuit_stdin(&["--stdin", "--error-format", "json"], source);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains(r#""file_name":"<stdin>""#), "{stderr}");
+}
@@ -8,19 +8,12 @@ fn circuit_r1cs_with_witness() {
 
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        Some("out=42,a=6,b=7"),
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            inputs: Some("out=42,a=6,b=7"),
+            ..Default::default()
+        },
     );
     assert!(
         result.is_ok(),
@@ -45,19 +38,13 @@ fn circuit_plonkish_with_witness() {
 
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        Some("out=42,a=6,b=7"),
-        None,
-        false,
-        "plonkish",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            inputs: Some("out=42,a=6,b=7"),
+            backend: "plonkish",
+            ..Default::default()
+        },
     );
     assert!(
         result.is_ok(),
@@ -75,19 +62,90 @@ fn circuit_r1cs_wrong_witness_rejected() {
     // out=99 but a*b=42, constraint violation
     let result = cli::commands::circuit::circuit_command(
         &fixture("basic_arithmetic.ach"),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        Some("out=99,a=6,b=7"),
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            inputs: Some("out=99,a=6,b=7"),
+            ..Default::default()
+        },
     );
     assert!(result.is_err(), "wrong witness should fail verification");
 }
+
+fn run_bool_witness(inputs: &str) -> anyhow::Result<()> {
+    let src = write_temp_source(
+        "circuit b(out: Public, flag: Witness Bool, x: Witness) {
+            assert_eq(flag * x, out)
+        }",
+    );
+    let tmpdir = tempfile::tempdir().unwrap();
+    cli::commands::circuit::circuit_command(
+        src.path().to_str().unwrap(),
+        &CircuitOptions {
+            r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+            wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+            inputs: Some(inputs),
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn circuit_bool_witness_accepts_zero_and_one() {
+    assert!(run_bool_witness("out=5,flag=1,x=5").is_ok());
+    assert!(run_bool_witness("out=0,flag=0,x=5").is_ok());
+}
+
+#[test]
+fn circuit_bool_witness_two_rejected() {
+    // flag * x = 2 * 5 = 10 satisfies the assert_eq, so only the
+    // boolean enforcement on `flag` can reject this witness.
+    let result = run_bool_witness("out=10,flag=2,x=5");
+    assert!(result.is_err(), "Bool witness set to 2 must not verify");
+}
+
+#[test]
+fn circuit_array_input_missing_element_rejected() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let run = |inputs: &str| {
+        cli::commands::circuit::circuit_command(
+            &fixture("inner_product.ach"),
+            &CircuitOptions {
+                r1cs_path: tmpdir.path().join("out.r1cs").to_str().unwrap(),
+                wtns_path: tmpdir.path().join("out.wtns").to_str().unwrap(),
+                inputs: Some(inputs),
+                ..Default::default()
+            },
+        )
+    };
+
+    let b = "b_0=1,b_1=1,b_2=1,b_3=1";
+    let err = format!(
+        "{:?}",
+        run(&format!("out=3,a_0=1,a_1=1,a_3=1,{b}")).unwrap_err()
+    );
+    assert!(err.contains("declared Field[4]"), "got: {err}");
+    assert!(err.contains("missing `a_2`"), "got: {err}");
+}
+
+#[test]
+fn circuit_array_input_surplus_element_warns() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let inputs = "out=4,a_0=1,a_1=1,a_2=1,a_3=1,a_4=1,b_0=1,b_1=1,b_2=1,b_3=1";
+    for backend in ["r1cs", "plonkish"] {
+        let out = std::process::Command::new(env!("CARGO_BIN_EXE_ach"))
+            .arg("--no-config")
+            .args(["circuit", &fixture("inner_product.ach")])
+            .args(["--backend", backend, "--inputs", inputs])
+            .current_dir(tmpdir.path())
+            .env("NO_COLOR", "1")
+            .output()
+            .expect("failed to spawn ach");
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(out.status.success(), "{backend}: {stderr}");
+        assert!(
+            stderr.contains("warning: unexpected input `a_4`: past the end of its declared array"),
+            "{backend}: {stderr}"
+        );
+    }
+}
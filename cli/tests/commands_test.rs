@@ -1,4 +1,8 @@
+use cli::commands::circuit::CompileOptions;
+use cli::commands::run::RunOptions;
 use cli::commands::ErrorFormat;
+use ir::passes::taint::TaintWarning;
+use ir::passes::OptLevel;
 use memory::field::PrimeId;
 use std::io::Write;
 use tempfile::NamedTempFile;
@@ -33,7 +37,7 @@ fn compile_valid_source_with_output() {
     // Verify .achb was created with the ACH magic header
     let bytes = std::fs::read(&out_path).unwrap();
     assert!(bytes.len() >= 4, "output file too small");
-    assert_eq!(&bytes[..4], b"ACH\x0B", "wrong magic header");
+    assert_eq!(&bytes[..5], b"ACH\x0C\x00", "wrong magic header");
 }
 
 #[test]
@@ -84,36 +88,14 @@ fn compile_nonexistent_file_returns_error() {
 #[test]
 fn run_valid_arithmetic_source() {
     let src = write_temp_source("let x = 2 + 3\nprint(x)");
-    let result = cli::commands::run::run_file(
-        src.path().to_str().unwrap(),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    );
+    let result = cli::commands::run::run_file(src.path().to_str().unwrap(), &RunOptions::default());
     assert!(result.is_ok(), "run_file failed: {:?}", result.err());
 }
 
 #[test]
 fn run_source_with_runtime_error() {
     let src = write_temp_source("let x = 1 / 0");
-    let result = cli::commands::run::run_file(
-        src.path().to_str().unwrap(),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    );
+    let result = cli::commands::run::run_file(src.path().to_str().unwrap(), &RunOptions::default());
     assert!(result.is_err());
     let err = format!("{}", result.unwrap_err());
     assert!(
@@ -122,19 +104,57 @@ fn run_source_with_runtime_error() {
     );
 }
 
+#[test]
+fn run_prove_block_returns_verifiable_proof() {
+    // The prove block goes through IR → R1CS → Groth16 in the default
+    // handler; the script itself checks the returned proof value.
+    let src = write_temp_source(
+        r#"
+let a = 0p6
+let b = 0p7
+let c = 0p42
+let p = prove(c: Public) {
+    assert_eq(a * b, c)
+}
+assert(typeof(p) == "Proof")
+assert(verify_proof(p))
+"#,
+    );
+    let result = cli::commands::run::run_file(src.path().to_str().unwrap(), &RunOptions::default());
+    assert!(result.is_ok(), "prove block run failed: {:?}", result.err());
+}
+
+#[test]
+fn run_int_overflow_checked_and_wrapping() {
+    // I60_MAX + 1 wraps to I60_MIN.
+    let src = write_temp_source(
+        "fn inc(v) { v + 1 }\n\
+         let y = inc(576460752303423487)\n\
+         assert(y < 0)",
+    );
+    let run = |wrapping| {
+        cli::commands::run::run_file(
+            src.path().to_str().unwrap(),
+            &RunOptions {
+                wrapping,
+                ..Default::default()
+            },
+        )
+    };
+    let err = run(false).unwrap_err().to_string();
+    assert!(err.contains("integer overflow"), "got: {err}");
+    assert!(
+        run(true).is_ok(),
+        "wrapping run failed: {:?}",
+        run(true).err()
+    );
+}
+
 #[test]
 fn run_nonexistent_file_returns_error() {
     let result = cli::commands::run::run_file(
         "/tmp/nonexistent_achronyme_test.ach",
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
+        &RunOptions::default(),
     );
     assert!(result.is_err());
 }
@@ -154,18 +174,7 @@ fn run_compiled_binary() {
     )
     .expect("compile should succeed");
 
-    let result = cli::commands::run::run_file(
-        &out_path,
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    );
+    let result = cli::commands::run::run_file(&out_path, &RunOptions::default());
     assert!(
         result.is_ok(),
         "run compiled binary failed: {:?}",
@@ -173,6 +182,67 @@ fn run_compiled_binary() {
     );
 }
 
+#[test]
+fn run_binary_without_achb_extension() {
+    let src = write_temp_source("let x = 10\nprint(x)");
+    let out = tempfile::NamedTempFile::with_suffix(".bin").unwrap();
+    let out_path = out.path().to_str().unwrap().to_string();
+
+    cli::commands::compile::compile_file(
+        src.path().to_str().unwrap(),
+        Some(&out_path),
+        PrimeId::Bn254,
+        EF,
+    )
+    .expect("compile should succeed");
+
+    let result = cli::commands::run::run_file(&out_path, &RunOptions::default());
+    assert!(result.is_ok(), "run by magic failed: {:?}", result.err());
+}
+
+#[test]
+fn run_old_binary_version_is_descriptive() {
+    let mut f = NamedTempFile::with_suffix(".achb").unwrap();
+    f.write_all(b"ACH\x07\x00\x00").unwrap();
+    f.flush().unwrap();
+
+    let result = cli::commands::run::run_file(f.path().to_str().unwrap(), &RunOptions::default());
+    let err = result.unwrap_err().to_string();
+    assert!(
+        err.contains("binary format version 0x07 is too old"),
+        "{err}"
+    );
+}
+
+#[test]
+fn run_invalid_utf8_source_returns_error() {
+    let mut f = NamedTempFile::with_suffix(".ach").unwrap();
+    f.write_all(b"let x = 1\n\xff\xfe\n").unwrap();
+    f.flush().unwrap();
+
+    let result = cli::commands::run::run_file(f.path().to_str().unwrap(), &RunOptions::default());
+    let err = result.unwrap_err().to_string();
+    assert!(
+        err.contains("not valid UTF-8 source (invalid byte at offset 10)"),
+        "{err}"
+    );
+}
+
+#[test]
+fn run_empty_source_is_valid_program() {
+    let src = write_temp_source("");
+    let result = cli::commands::run::run_file(src.path().to_str().unwrap(), &RunOptions::default());
+    assert!(result.is_ok(), "empty source failed: {:?}", result.err());
+}
+
+#[test]
+fn run_missing_file_returns_error() {
+    let result =
+        cli::commands::run::run_file("/tmp/nonexistent_ach_source.ach", &RunOptions::default());
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("cannot read source file"), "{err}");
+}
+
 // ======================================================================
 // disassemble_file
 // ======================================================================
@@ -191,6 +261,29 @@ fn disassemble_invalid_source_returns_error() {
     assert!(result.is_err());
 }
 
+#[test]
+fn disassemble_compiled_binary_matches_source() {
+    let src = write_temp_source("let x = 1 + 2\nlet s = \"hi\"\nprint(x)\nprint(s)");
+    let src_path = src.path().to_str().unwrap().to_string();
+    let out = tempfile::NamedTempFile::with_suffix(".achb").unwrap();
+    let out_path = out.path().to_str().unwrap().to_string();
+
+    cli::commands::compile::compile_file(&src_path, Some(&out_path), PrimeId::Bn254, EF)
+        .expect("compile should succeed");
+
+    let from_source = cli::commands::disassemble::vm_listing(&src_path, EF).unwrap();
+    let from_binary = cli::commands::disassemble::vm_listing(&out_path, EF).unwrap();
+    assert!(!from_source.is_empty());
+    assert_eq!(from_source, from_binary);
+
+    let result = cli::commands::disassemble::disassemble_file(&out_path, EF);
+    assert!(
+        result.is_ok(),
+        "disassemble .achb failed: {:?}",
+        result.err()
+    );
+}
+
 // ======================================================================
 // error_format tests
 // ======================================================================
@@ -253,3 +346,598 @@ fn json_warning_format_produces_valid_json() {
         assert_eq!(parsed["level"], "warning");
     }
 }
+
+// ======================================================================
+// watch
+// ======================================================================
+
+#[test]
+fn watch_recompiles_on_change_and_survives_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("main.ach");
+    let path_str = path.to_str().unwrap().to_string();
+    std::fs::write(
+        &path,
+        "circuit c(z: Public, x: Witness, y: Witness) { assert_eq(x * y, z) }",
+    )
+    .unwrap();
+
+    let rewrite = |content: &str| {
+        std::fs::write(&path, content).unwrap();
+        // Bump the mtime explicitly so coarse filesystem clocks still
+        // register the edit.
+        let bumped = std::time::SystemTime::now() + std::time::Duration::from_secs(60);
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(bumped)
+            .unwrap();
+    };
+
+    let mut out = Vec::new();
+    cli::commands::watch::watch_loop(
+        &path_str,
+        &CompileOptions::default(),
+        PrimeId::Bn254,
+        EF,
+        std::time::Duration::from_millis(10),
+        &mut out,
+        |builds| {
+            match builds {
+                1 => rewrite(
+                    "circuit c(z: Public, x: Witness, y: Witness) { assert_eq(x * y * x, z) }",
+                ),
+                2 => rewrite("circuit c(z: Public) { assert_eq(undefined_var, z) }"),
+                3 => rewrite("circuit c(z: Public, x: Witness) { assert_eq(x * x, z) }"),
+                _ => return false,
+            }
+            true
+        },
+    )
+    .unwrap();
+
+    let out = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = out.lines().filter(|l| l.starts_with('[')).collect();
+    assert_eq!(lines.len(), 4, "expected four rebuilds, got:\n{out}");
+    assert!(lines[0].starts_with("[1] 1 constraints"), "{out}");
+    assert!(lines[1].starts_with("[2] 2 constraints"), "{out}");
+    assert!(lines[2].starts_with("[3] error:"), "{out}");
+    assert!(lines[3].starts_with("[4] "), "{out}");
+    assert!(lines[3].contains("1 public, 1 witness"), "{out}");
+}
+
+#[test]
+fn watch_tracks_imported_modules() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("lib.ach"),
+        "export fn double(a) { a + a }\n",
+    )
+    .unwrap();
+    let main = dir.path().join("main.ach");
+    std::fs::write(
+        &main,
+        "import \"./lib.ach\" as lib\npublic z\nwitness x\nassert_eq(lib.double(x), z)\n",
+    )
+    .unwrap();
+
+    let files = cli::commands::watch::watched_files(main.to_str().unwrap());
+    let names: Vec<_> = files
+        .iter()
+        .map(|f| f.file_name().unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(names, ["main.ach", "lib.ach"]);
+}
+
+// ======================================================================
+// profile
+// ======================================================================
+
+#[test]
+fn profile_attributes_poseidon_to_its_line() {
+    let src = write_temp_source(
+        "circuit c(out: Public, a: Witness, b: Witness) {\n\
+         \x20   let h = poseidon(a, b)\n\
+         \x20   assert_eq(h * a, out)\n\
+         }\n",
+    );
+    let profile = cli::commands::profile::line_profile(
+        src.path().to_str().unwrap(),
+        &CompileOptions::default(),
+        PrimeId::Bn254,
+        EF,
+    )
+    .unwrap();
+
+    let poseidon = profile.lines.get(&2).copied().unwrap_or(0);
+    assert!(
+        (350..=370).contains(&poseidon),
+        "poseidon line should carry ~360 constraints, got {poseidon}: {profile:?}"
+    );
+    let mul = profile.lines.get(&3).copied().unwrap_or(0);
+    assert!(mul < 5, "assert_eq line should be cheap, got {mul}");
+
+    let listing = cli::commands::profile::render_profile(
+        &std::fs::read_to_string(src.path()).unwrap(),
+        &profile,
+    );
+    assert!(
+        listing.contains(&format!("{poseidon} |    2 |     let h = poseidon(a, b)")),
+        "{listing}"
+    );
+    assert!(
+        listing.contains(&format!("{} constraints total", profile.total)),
+        "{listing}"
+    );
+}
+
+// ======================================================================
+// analyze
+// ======================================================================
+
+#[test]
+fn analyze_poseidon_circuit_without_inputs() {
+    let src = write_temp_source(
+        "circuit c(out: Public, a: Witness, b: Witness) {\n\
+         \x20   assert_eq(poseidon(a, b), out)\n\
+         }\n",
+    );
+    let report = cli::commands::analyze::analyze_circuit(
+        src.path().to_str().unwrap(),
+        &CompileOptions::default(),
+        PrimeId::Bn254,
+        EF,
+    )
+    .unwrap();
+
+    assert!(
+        (200..=370).contains(&report.constraints),
+        "poseidon should cost a few hundred constraints after linear elimination, got {report:?}"
+    );
+    assert!(report.variables > report.constraints, "{report:?}");
+    let (a, b, c) = report.nonzero_entries;
+    assert!(
+        a >= report.constraints && b >= report.constraints && c > 0,
+        "every row should have nonempty A and B: {report:?}"
+    );
+    // Linear elimination inlines substituted wires, so rows are denser
+    // than the raw gadget's, but the matrices stay far from dense.
+    let dense = 3 * report.constraints * report.variables;
+    assert!(
+        (a + b + c) * 10 < dense,
+        "poseidon R1CS should stay sparse: {report:?}"
+    );
+    assert_eq!((report.public_inputs, report.witness_inputs), (1, 2));
+    assert!(report.warnings.is_empty(), "{report:?}");
+
+    let printed = report.to_string();
+    assert!(
+        printed.contains(&format!("constraints:    {}", report.constraints)),
+        "{printed}"
+    );
+    assert!(
+        printed.contains(&format!("nonzero A/B/C:  {a} / {b} / {c}")),
+        "{printed}"
+    );
+    assert!(printed.ends_with("no warnings\n"), "{printed}");
+}
+
+#[test]
+fn assert_bool_then_mux_enforces_flag_once() {
+    // assert_bool(flag) emits flag * (flag - 1) == 0; bool_prop marks
+    // `flag` boolean, so the mux doesn't enforce it a second time.
+    let src = write_temp_source(
+        "circuit flag_only(flag: Witness) {\n\
+         \x20   assert_bool(flag)\n\
+         }\n\
+         circuit mux_only(out: Public, flag: Witness, a: Witness, b: Witness) {\n\
+         \x20   assert_eq(mux(flag, a, b), out)\n\
+         }\n\
+         circuit both(out: Public, flag: Witness, a: Witness, b: Witness) {\n\
+         \x20   assert_bool(flag)\n\
+         \x20   assert_eq(mux(flag, a, b), out)\n\
+         }\n",
+    );
+    let path = src.path().to_str().unwrap();
+    let constraints = |entry: &str| {
+        let opts = CompileOptions {
+            entry: Some(entry),
+            ..CompileOptions::default()
+        };
+        cli::commands::analyze::analyze_circuit(path, &opts, PrimeId::Bn254, EF)
+            .unwrap()
+            .constraints
+    };
+    assert_eq!(
+        constraints("both"),
+        constraints("flag_only") + constraints("mux_only") - 1
+    );
+}
+
+#[test]
+fn bool_builtins_skip_operands_already_boolean() {
+    // An operand gets its `x * (x - 1) == 0` once: not again after an
+    // `assert_bool`, and not at all when it is a Bool input or a
+    // comparison result. Each `*_bare` circuit spells out the constraints
+    // the builtin should cost.
+    let src = write_temp_source(
+        "circuit and_only(out: Public, a: Witness, b: Witness) {\n\
+         \x20   assert_eq(bool_and(a, b), out)\n\
+         }\n\
+         circuit asserted_first(out: Public, a: Witness, b: Witness) {\n\
+         \x20   assert_bool(a)\n\
+         \x20   assert_bool(b)\n\
+         \x20   assert_eq(bool_and(a, b), out)\n\
+         }\n\
+         circuit typed(out: Public, a: Witness Bool, b: Witness) {\n\
+         \x20   assert_eq(bool_and(a, b), out)\n\
+         }\n\
+         circuit typed_bare(out: Public, a: Witness Bool, b: Witness) {\n\
+         \x20   assert_bool(b)\n\
+         \x20   assert_eq(a * b, out)\n\
+         }\n\
+         circuit compared(out: Public, x: Witness, y: Witness, b: Witness) {\n\
+         \x20   let c = x < y\n\
+         \x20   assert_eq(bool_and(c, b), out)\n\
+         }\n\
+         circuit compared_bare(out: Public, x: Witness, y: Witness, b: Witness) {\n\
+         \x20   let c = x < y\n\
+         \x20   assert_bool(b)\n\
+         \x20   assert_eq(c * b, out)\n\
+         }\n",
+    );
+    let path = src.path().to_str().unwrap();
+    let constraints = |entry: &str, opt_level: OptLevel| {
+        let opts = CompileOptions {
+            opt_level,
+            entry: Some(entry),
+            ..CompileOptions::default()
+        };
+        cli::commands::analyze::analyze_circuit(path, &opts, PrimeId::Bn254, EF)
+            .unwrap()
+            .constraints
+    };
+    // -O0, so the optimizer can't hide a duplicate enforcement.
+    assert_eq!(
+        constraints("asserted_first", OptLevel::O0),
+        constraints("and_only", OptLevel::O0)
+    );
+    assert_eq!(
+        constraints("typed", OptLevel::O2),
+        constraints("typed_bare", OptLevel::O2)
+    );
+    assert_eq!(
+        constraints("compared", OptLevel::O2),
+        constraints("compared_bare", OptLevel::O2)
+    );
+}
+
+#[test]
+fn analyze_honours_compile_options() {
+    let src = write_temp_source(
+        "circuit small(x: Witness) {\n\
+         \x20   assert_eq(x * x, x)\n\
+         }\n\
+         circuit looped(out: Public, x: Witness) {\n\
+         \x20   mut acc = x\n\
+         \x20   for i in 0..50 {\n\
+         \x20       acc = acc * x + 1\n\
+         \x20   }\n\
+         \x20   assert_eq(acc, out)\n\
+         }\n",
+    );
+    let path = src.path().to_str().unwrap();
+    let analyze = |opts: CompileOptions| {
+        cli::commands::analyze::analyze_circuit(path, &opts, PrimeId::Bn254, EF)
+    };
+    let looped = CompileOptions {
+        entry: Some("looped"),
+        ..CompileOptions::default()
+    };
+
+    let report = analyze(looped).unwrap();
+    assert_eq!((report.public_inputs, report.witness_inputs), (1, 1));
+    let small = analyze(CompileOptions {
+        entry: Some("small"),
+        ..looped
+    })
+    .unwrap();
+    assert_eq!((small.public_inputs, small.witness_inputs), (0, 1));
+
+    let unoptimized = analyze(CompileOptions {
+        opt_level: OptLevel::O0,
+        ..looped
+    })
+    .unwrap();
+    assert!(
+        unoptimized.constraints > report.constraints,
+        "-O0 should skip linear elimination: {unoptimized:?} vs {report:?}"
+    );
+
+    let err = analyze(CompileOptions {
+        max_unroll: 10,
+        ..looped
+    })
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("10"), "{err}");
+}
+
+#[test]
+fn analyze_reports_unconstrained_input() {
+    let src = write_temp_source(
+        "circuit c(out: Public, a: Witness, b: Witness) {\n\
+         \x20   assert_eq(a * a, out)\n\
+         }\n",
+    );
+    let report = cli::commands::analyze::analyze_circuit(
+        src.path().to_str().unwrap(),
+        &CompileOptions::default(),
+        PrimeId::Bn254,
+        EF,
+    )
+    .unwrap();
+    assert_eq!(report.warnings.len(), 1, "{report:?}");
+    assert!(report.warnings[0].to_string().contains('b'), "{report:?}");
+}
+
+// ======================================================================
+// explain
+// ======================================================================
+
+#[test]
+fn explain_poseidon_reports_permutation_cost() {
+    let explanation =
+        cli::commands::explain::explain_builtin("poseidon", None, PrimeId::Bn254).unwrap();
+    let n = explanation.constraints.len();
+    assert!((350..=370).contains(&n), "{explanation}");
+    assert!(
+        explanation
+            .to_string()
+            .starts_with(&format!("poseidon: {n} constraints")),
+        "{explanation}"
+    );
+}
+
+#[test]
+fn explain_poseidon2_rejects_other_primes() {
+    let err = cli::commands::explain::explain_builtin("poseidon2", None, PrimeId::Goldilocks)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("no Poseidon2 parameters"), "{err}");
+}
+
+#[test]
+fn explain_range_check_scales_with_bits() {
+    let count = |bits| {
+        cli::commands::explain::explain_builtin("range_check", Some(bits), PrimeId::Bn254)
+            .unwrap()
+            .constraints
+            .len()
+    };
+    assert_eq!(count(16) - count(8), 8);
+}
+
+#[test]
+fn explain_rejects_unknown_builtin() {
+    let err = cli::commands::explain::explain_builtin("sha3", None, PrimeId::Bn254).unwrap_err();
+    assert!(err.to_string().contains("mux"), "{err}");
+}
+
+#[test]
+fn explain_bounded_comparison_skips_operand_range_checks() {
+    let lt = |bits| {
+        cli::commands::explain::explain_builtin("<", bits, PrimeId::Bn254)
+            .unwrap()
+            .constraints
+            .len()
+    };
+    // 9-bit decomposition of `b - a + 2^8 - 1` plus its binding constraint.
+    assert_eq!(lt(Some(8)), 11);
+    assert!(lt(None) > 700);
+}
+
+// ======================================================================
+// check
+// ======================================================================
+
+#[test]
+fn check_reports_parse_errors_as_json() {
+    let src = write_temp_source("let x = 1\nlet = 2\nlet y = 3\nfn f( {\n");
+    let diags = cli::commands::check::check_file(src.path().to_str().unwrap(), PrimeId::Bn254, &[])
+        .unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&cli::commands::check::diagnostics_to_json(&diags)).unwrap();
+    let entries = json.as_array().unwrap();
+    assert_eq!(entries.len(), 2, "{json}");
+    assert_eq!(entries[0]["severity"], "error");
+    assert_eq!(
+        (&entries[0]["line"], &entries[0]["col"]),
+        (&2.into(), &5.into())
+    );
+    assert!(
+        entries[0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("expected identifier"),
+        "{json}"
+    );
+    assert_eq!(entries[1]["line"], 4, "{json}");
+}
+
+#[test]
+fn check_reports_circuit_errors_and_warnings() {
+    let src = write_temp_source(
+        "circuit c(out: Public, a: Witness, b: Witness) {\n\
+         \x20   assert_eq(a * a, out)\n\
+         }\n",
+    );
+    let diags = cli::commands::check::check_file(src.path().to_str().unwrap(), PrimeId::Bn254, &[])
+        .unwrap();
+    assert_eq!(diags.len(), 1, "{diags:?}");
+    assert!(diags[0].message.contains("`b`"), "{diags:?}");
+
+    let src = write_temp_source(
+        "circuit c(out: Public, a: Witness) {\n\
+         \x20   let z = a % 2\n\
+         \x20   assert_eq(a, out)\n\
+         }\n",
+    );
+    let diags = cli::commands::check::check_file(src.path().to_str().unwrap(), PrimeId::Bn254, &[])
+        .unwrap();
+    // Reported once, at the `%` expression rather than the whole circuit.
+    assert_eq!(diags.len(), 1, "{diags:?}");
+    assert_eq!(diags[0].primary_span.line_start, 2);
+    assert!(diags[0].message.contains("modulo"), "{diags:?}");
+}
+
+#[test]
+fn check_knows_map_is_let_bound() {
+    let src = write_temp_source(
+        "circuit c(out: Public, xs: Witness Field[2]) {\n\
+         \x20   let ys = map(xs, \"square\")\n\
+         \x20   assert_eq(ys[0] + ys[1], out)\n\
+         }\n",
+    );
+    let diags = cli::commands::check::check_file(src.path().to_str().unwrap(), PrimeId::Bn254, &[])
+        .unwrap();
+    assert!(diags.is_empty(), "{diags:?}");
+
+    let src = write_temp_source(
+        "circuit c(out: Public, xs: Witness Field[2]) {\n\
+         \x20   assert_eq(map(xs, \"square\"), out)\n\
+         }\n",
+    );
+    let diags = cli::commands::check::check_file(src.path().to_str().unwrap(), PrimeId::Bn254, &[])
+        .unwrap();
+    assert_eq!(diags.len(), 1, "{diags:?}");
+    assert!(diags[0].message.contains("returns an array"), "{diags:?}");
+}
+
+#[test]
+fn circuit_commands_warn_about_shadowed_let() {
+    let src = write_temp_source(
+        "circuit c(out: Public, a: Witness, b: Witness) {\n\
+         \x20   let x = a * b\n\
+         \x20   let x = x + 1\n\
+         \x20   assert_eq(x, out)\n\
+         }\n",
+    );
+    let path = src.path().to_str().unwrap();
+    let diags = cli::commands::check::check_file(path, PrimeId::Bn254, &[]).unwrap();
+    assert_eq!(diags.len(), 1, "{diags:?}");
+    assert_eq!(
+        diags[0].message,
+        "`let x` shadows an earlier `x` in the same scope"
+    );
+    assert_eq!(diags[0].primary_span.line_start, 3);
+
+    let report = cli::commands::analyze::analyze_circuit(
+        path,
+        &CompileOptions::default(),
+        PrimeId::Bn254,
+        EF,
+    )
+    .unwrap();
+    assert!(
+        report
+            .warnings
+            .iter()
+            .any(|w| matches!(w, TaintWarning::Shadowed { name, .. } if name == "x")),
+        "{:?}",
+        report.warnings
+    );
+}
+
+#[test]
+fn circuit_constant_zero_divisor_reports_its_line() {
+    for body in ["assert_eq(a / 0, out)", "assert_eq(inv(0) * a, out)"] {
+        let src = write_temp_source(&format!(
+            "circuit c(out: Public, a: Witness) {{\n\
+             \x20   let b = a + 1\n\
+             \x20   {body}\n\
+             }}\n"
+        ));
+        let path = src.path().to_str().unwrap();
+        let diags = cli::commands::check::check_file(path, PrimeId::Bn254, &[]).unwrap();
+        let err = diags
+            .iter()
+            .find(|d| d.message.contains("division by zero"))
+            .unwrap_or_else(|| panic!("{body}: {diags:?}"));
+        assert_eq!(err.primary_span.line_start, 3, "{body}");
+
+        let err = cli::commands::analyze::analyze_circuit(
+            path,
+            &CompileOptions::default(),
+            PrimeId::Bn254,
+            EF,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("division by zero"),
+            "{body}: {err}"
+        );
+    }
+}
+
+#[test]
+fn check_warns_about_code_after_return_in_circuit_fn() {
+    let src = write_temp_source(
+        "circuit c(out: Public, a: Witness, b: Witness) {\n\
+         \x20   fn add(x, y) {\n\
+         \x20       return x + y\n\
+         \x20       x * y\n\
+         \x20   }\n\
+         \x20   assert_eq(add(a, b), out)\n\
+         }\n",
+    );
+    let diags = cli::commands::check::check_file(src.path().to_str().unwrap(), PrimeId::Bn254, &[])
+        .unwrap();
+    assert_eq!(diags.len(), 1, "{diags:?}");
+    assert_eq!(diags[0].message, "unreachable code");
+    assert_eq!(diags[0].primary_span.line_start, 4);
+}
+
+// ======================================================================
+// list-builtins
+// ======================================================================
+
+#[test]
+fn list_builtins_shows_arity_and_context() {
+    let out = cli::commands::list_builtins::render_builtins(&resolve::BuiltinRegistry::default());
+    assert_eq!(out.lines().count(), 40, "{out}");
+    let line = |name: &str| {
+        out.lines()
+            .find(|l| l.split_whitespace().next() == Some(name))
+            .unwrap_or_else(|| panic!("`{name}` missing:\n{out}"))
+            .split_whitespace()
+            .take(3)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(line("assert_eq"), ["assert_eq", "2..=3", "circuit"]);
+    assert_eq!(line("poseidon_many"), ["poseidon_many", "variadic", "both"]);
+    assert_eq!(line("print"), ["print", "variadic", "vm"]);
+    assert_eq!(line("map"), ["map", "2", "circuit"]);
+}
+
+// ======================================================================
+// field-info
+// ======================================================================
+
+#[test]
+fn field_info_prints_bn254_modulus() {
+    let out = cli::commands::field_info::render_field_info(PrimeId::Bn254).unwrap();
+    assert!(
+        out.contains("0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001"),
+        "{out}"
+    );
+    assert!(out
+        .contains("21888242871839275222246405745257275088548364400416034343698204186575808495617"));
+    assert!(out.contains("bit size:       254"), "{out}");
+    assert!(out.contains("two-adicity:    28"), "{out}");
+    assert!(out.contains("generator:      5"), "{out}");
+
+    let out = cli::commands::field_info::render_field_info(PrimeId::Goldilocks).unwrap();
+    assert!(out.contains("two-adicity:    32"), "{out}");
+}
@@ -37,16 +37,16 @@ pub(super) fn pin_proof_of_membership_membership_0() -> FrozenBaseline {
 pub(super) fn pin_circom_merkle_membership() -> FrozenBaseline {
     FrozenBaseline {
         pre_o1_hash: [
-            166, 73, 159, 70, 139, 195, 225, 185, 116, 158, 216, 76, 199, 20, 239, 59, 21, 71, 238,
-            73, 54, 77, 223, 158, 33, 121, 141, 176, 163, 184, 197, 159,
+            76, 200, 227, 137, 222, 229, 96, 89, 237, 229, 119, 122, 236, 169, 154, 32, 3, 148,
+            206, 199, 81, 52, 147, 107, 105, 102, 238, 209, 54, 179, 213, 40,
         ],
-        pre_o1_count: 1465,
+        pre_o1_count: 1029,
         post_o1_hash: [
-            209, 80, 150, 219, 154, 69, 147, 113, 205, 16, 12, 219, 35, 221, 232, 234, 177, 163,
-            165, 13, 63, 121, 232, 191, 63, 249, 66, 53, 188, 37, 1, 74,
+            65, 55, 210, 61, 208, 171, 104, 114, 206, 124, 185, 130, 172, 176, 240, 236, 9, 155,
+            180, 110, 64, 83, 114, 225, 112, 188, 63, 162, 185, 93, 129, 224,
         ],
         post_o1_count: 717,
-        num_variables: 1469,
+        num_variables: 1033,
         public_inputs: vec!["merkle_root".into()],
     }
 }
@@ -54,16 +54,16 @@ pub(super) fn pin_circom_merkle_membership() -> FrozenBaseline {
 pub(super) fn pin_circom_poseidon_chain() -> FrozenBaseline {
     FrozenBaseline {
         pre_o1_hash: [
-            137, 145, 158, 23, 212, 176, 214, 194, 125, 162, 2, 84, 217, 106, 35, 84, 120, 19, 90,
-            68, 199, 242, 241, 160, 22, 202, 24, 235, 150, 184, 93, 125,
+            88, 67, 125, 49, 106, 67, 206, 251, 28, 64, 44, 238, 112, 211, 119, 180, 66, 47, 123,
+            182, 124, 87, 36, 167, 154, 31, 213, 42, 167, 27, 110, 71,
         ],
-        pre_o1_count: 2421,
+        pre_o1_count: 1701,
         post_o1_hash: [
-            194, 29, 243, 0, 142, 237, 92, 68, 7, 196, 241, 49, 160, 107, 137, 74, 108, 21, 160,
-            83, 121, 52, 66, 129, 131, 148, 87, 23, 23, 212, 162, 14,
+            157, 29, 123, 108, 91, 33, 162, 189, 170, 133, 29, 199, 193, 93, 237, 175, 228, 130,
+            240, 87, 253, 126, 155, 208, 45, 197, 181, 101, 252, 124, 74, 197,
         ],
         post_o1_count: 1185,
-        num_variables: 2423,
+        num_variables: 1703,
         public_inputs: vec!["final_hash".into()],
     }
 }
@@ -126,16 +126,16 @@ pub(super) fn pin_tornado_mixer_withdrawal_2() -> FrozenBaseline {
 pub(super) fn pin_tornado_multifile_withdraw() -> FrozenBaseline {
     FrozenBaseline {
         pre_o1_hash: [
-            23, 166, 200, 155, 86, 117, 200, 29, 200, 238, 21, 201, 23, 36, 81, 132, 211, 164, 110,
-            72, 76, 51, 22, 235, 117, 216, 15, 78, 40, 49, 128, 37,
+            218, 55, 195, 53, 176, 53, 249, 172, 197, 209, 197, 204, 255, 217, 182, 242, 190, 128,
+            246, 97, 13, 208, 25, 1, 148, 81, 155, 176, 48, 248, 53, 140,
         ],
-        pre_o1_count: 2968,
+        pre_o1_count: 2086,
         post_o1_hash: [
-            243, 91, 28, 141, 172, 129, 76, 131, 36, 79, 228, 47, 23, 143, 128, 163, 127, 14, 39,
-            37, 184, 23, 81, 48, 144, 54, 98, 83, 146, 168, 46, 126,
+            163, 66, 77, 221, 6, 43, 222, 103, 214, 202, 226, 193, 177, 145, 180, 57, 239, 5, 133,
+            239, 54, 113, 49, 77, 248, 79, 185, 204, 46, 92, 53, 239,
         ],
         post_o1_count: 1453,
-        num_variables: 2979,
+        num_variables: 2097,
         public_inputs: vec!["root".into(), "nh".into()],
     }
 }
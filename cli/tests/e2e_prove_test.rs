@@ -229,6 +229,33 @@ assert_eq(a * b, c)
     assert!(result.is_err(), "expected error for wrong witness, got Ok");
 }
 
+#[test]
+fn e2e_groth16_witness_default_needs_no_input() {
+    let source = r#"
+witness a
+witness b = 7
+public c
+assert_eq(a * b, c)
+"#;
+    // `b` is not supplied: the declared default fills its wire.
+    let (compiler, witness) = lower_and_compile_r1cs(source, &[("a", 6), ("c", 42)]);
+
+    let cache_dir = tempfile::tempdir().unwrap();
+    let result = proving::groth16_bn254::generate_proof(&compiler.cs, &witness, cache_dir.path())
+        .expect("generate_proof failed");
+    match result {
+        ProveResult::Proof { public_json, .. } => {
+            let public: Vec<String> = serde_json::from_str(&public_json).unwrap();
+            assert_eq!(public, ["42"]);
+        }
+        ProveResult::VerifiedOnly => panic!("expected Proof, got VerifiedOnly"),
+    }
+
+    // A supplied value overrides the default.
+    let (compiler, witness) = lower_and_compile_r1cs(source, &[("a", 6), ("b", 2), ("c", 12)]);
+    compiler.cs.verify(&witness).unwrap();
+}
+
 // ============================================================================
 // Plonkish / KZG tests
 // ============================================================================
@@ -286,6 +313,77 @@ assert_eq(a * b, c)
     }
 }
 
+#[test]
+fn e2e_groth16_proof_file_roundtrip() {
+    use proving::groth16_bn254::{deserialize_proof_json, serialize_proof_json};
+
+    let source = r#"
+witness a
+witness b
+public c
+assert_eq(a * b, c)
+"#;
+    let (compiler, witness) = lower_and_compile_r1cs(source, &[("a", 6), ("b", 7), ("c", 42)]);
+
+    let cache_dir = tempfile::tempdir().unwrap();
+    let result = proving::groth16_bn254::generate_proof(&compiler.cs, &witness, cache_dir.path())
+        .expect("generate_proof failed");
+    let ProveResult::Proof {
+        proof_json,
+        public_json,
+        vkey_json,
+    } = result
+    else {
+        panic!("expected Proof");
+    };
+    let proof = deserialize_proof_json(&proof_json).unwrap();
+
+    let out = tempfile::tempdir().unwrap();
+    let bin_path = out.path().join("proof.bin");
+    let json_path = out.path().join("proof.json");
+    cli::groth16::write_proof(&bin_path, &proof).unwrap();
+    cli::groth16::write_proof_json(&json_path, &proof).unwrap();
+
+    assert_eq!(std::fs::metadata(&bin_path).unwrap().len(), 133);
+    assert_eq!(std::fs::read_to_string(&json_path).unwrap(), proof_json);
+
+    for read_back in [
+        cli::groth16::read_proof(&bin_path).unwrap(),
+        cli::groth16::read_proof_json(&json_path).unwrap(),
+    ] {
+        assert_eq!(read_back, proof);
+        let valid = proving::groth16_bn254::verify_proof_from_json(
+            &serialize_proof_json(&read_back),
+            &public_json,
+            &vkey_json,
+        )
+        .expect("verify_proof_from_json failed");
+        assert!(valid, "proof read back from disk should verify");
+    }
+
+    // Truncated files and foreign files are rejected.
+    let bytes = std::fs::read(&bin_path).unwrap();
+    std::fs::write(&bin_path, &bytes[..100]).unwrap();
+    assert!(cli::groth16::read_proof(&bin_path).is_err());
+    std::fs::write(&bin_path, &bytes[4..]).unwrap();
+    assert!(cli::groth16::read_proof(&bin_path).is_err());
+
+    // Proof files and serialized ProveIR must not be mistaken for each other.
+    assert!(ir_forge::ProveIR::from_bytes(&bytes).is_err());
+    let prove_ir = ir_forge::ProveIrCompiler::<memory::Bn254Fr>::compile_circuit(
+        "circuit c(x: Public) { assert_eq(x, x) }",
+        None,
+    )
+    .unwrap();
+    let ir_path = out.path().join("circuit.proveir");
+    std::fs::write(
+        &ir_path,
+        prove_ir.to_bytes(memory::field::PrimeId::Bn254).unwrap(),
+    )
+    .unwrap();
+    assert!(cli::groth16::read_proof(&ir_path).is_err());
+}
+
 // ============================================================================
 // Optimized-R1CS proving roundtrip
 // ============================================================================
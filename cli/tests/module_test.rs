@@ -1,9 +1,7 @@
-use cli::commands::ErrorFormat;
-use memory::field::PrimeId;
+use cli::commands::circuit::CircuitOptions;
+use cli::commands::run::RunOptions;
 use std::path::Path;
 
-const EF: ErrorFormat = ErrorFormat::Human;
-
 /// Resolve the path to a test fixture under test/modules/.
 fn fixture(name: &str) -> String {
     let manifest_dir = env!("CARGO_MANIFEST_DIR");
@@ -23,88 +21,35 @@ fn fixture(name: &str) -> String {
 #[test]
 fn import_basic_function_call() {
     // utils.ach exports add(a,b) and PI=3; main_vm.ach imports and calls utils.add(1,2)
-    let result = cli::commands::run::run_file(
-        &fixture("main_vm.ach"),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    );
+    let result = cli::commands::run::run_file(&fixture("main_vm.ach"), &RunOptions::default());
     assert!(result.is_ok(), "run_file failed: {:?}", result.err());
 }
 
 #[test]
 fn import_constants_access() {
-    let result = cli::commands::run::run_file(
-        &fixture("test_constants.ach"),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    );
+    let result =
+        cli::commands::run::run_file(&fixture("test_constants.ach"), &RunOptions::default());
     assert!(result.is_ok(), "run_file failed: {:?}", result.err());
 }
 
 #[test]
 fn import_internal_helper_function() {
     // internal_helper.ach: helper() is not exported, pub_fn() is and calls helper()
-    let result = cli::commands::run::run_file(
-        &fixture("test_internal.ach"),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    );
+    let result =
+        cli::commands::run::run_file(&fixture("test_internal.ach"), &RunOptions::default());
     assert!(result.is_ok(), "run_file failed: {:?}", result.err());
 }
 
 #[test]
 fn import_transitive() {
     // c.ach → b.ach → a.ach (transitive chain)
-    let result = cli::commands::run::run_file(
-        &fixture("transitive/c.ach"),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    );
+    let result = cli::commands::run::run_file(&fixture("transitive/c.ach"), &RunOptions::default());
     assert!(result.is_ok(), "run_file failed: {:?}", result.err());
 }
 
 #[test]
 fn import_circular_detected() {
-    let result = cli::commands::run::run_file(
-        &fixture("circular_a.ach"),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    );
+    let result = cli::commands::run::run_file(&fixture("circular_a.ach"), &RunOptions::default());
     assert!(result.is_err());
     let err = format!("{}", result.unwrap_err());
     assert!(
@@ -115,18 +60,8 @@ fn import_circular_detected() {
 
 #[test]
 fn import_module_not_found() {
-    let result = cli::commands::run::run_file(
-        &fixture("test_not_found.ach"),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    );
+    let result =
+        cli::commands::run::run_file(&fixture("test_not_found.ach"), &RunOptions::default());
     assert!(result.is_err());
     let err = format!("{}", result.unwrap_err());
     assert!(
@@ -138,18 +73,8 @@ fn import_module_not_found() {
 #[test]
 fn import_no_exports_module() {
     // Importing a module with no exports should work (empty namespace)
-    let result = cli::commands::run::run_file(
-        &fixture("test_no_exports.ach"),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    );
+    let result =
+        cli::commands::run::run_file(&fixture("test_no_exports.ach"), &RunOptions::default());
     assert!(result.is_ok(), "run_file failed: {:?}", result.err());
 }
 
@@ -161,50 +86,22 @@ fn import_no_exports_module() {
 fn selective_import_basic() {
     let result = cli::commands::run::run_file(
         &fixture("test_selective_import.ach"),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
+        &RunOptions::default(),
     );
     assert!(result.is_ok(), "run_file failed: {:?}", result.err());
 }
 
 #[test]
 fn export_list_via_namespace() {
-    let result = cli::commands::run::run_file(
-        &fixture("test_export_list.ach"),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    );
+    let result =
+        cli::commands::run::run_file(&fixture("test_export_list.ach"), &RunOptions::default());
     assert!(result.is_ok(), "run_file failed: {:?}", result.err());
 }
 
 #[test]
 fn mixed_selective_and_namespace_import() {
-    let result = cli::commands::run::run_file(
-        &fixture("test_mixed_imports.ach"),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    );
+    let result =
+        cli::commands::run::run_file(&fixture("test_mixed_imports.ach"), &RunOptions::default());
     assert!(result.is_ok(), "run_file failed: {:?}", result.err());
 }
 
@@ -212,15 +109,7 @@ fn mixed_selective_and_namespace_import() {
 fn selective_import_nonexistent_name() {
     let result = cli::commands::run::run_file(
         &fixture("test_selective_not_exported.ach"),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
+        &RunOptions::default(),
     );
     assert!(result.is_err());
     let err = format!("{}", result.unwrap_err());
@@ -234,15 +123,7 @@ fn selective_import_nonexistent_name() {
 fn duplicate_export_detected() {
     let result = cli::commands::run::run_file(
         &fixture("test_duplicate_export.ach"),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
+        &RunOptions::default(),
     );
     assert!(result.is_err());
     let err = format!("{}", result.unwrap_err());
@@ -254,18 +135,8 @@ fn duplicate_export_detected() {
 
 #[test]
 fn export_list_undefined_name() {
-    let result = cli::commands::run::run_file(
-        &fixture("test_bad_export_list.ach"),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    );
+    let result =
+        cli::commands::run::run_file(&fixture("test_bad_export_list.ach"), &RunOptions::default());
     assert!(result.is_err());
     let err = format!("{}", result.unwrap_err());
     assert!(
@@ -346,19 +217,11 @@ fn circuit_import_with_poseidon() {
 
     let result = cli::commands::circuit::circuit_command(
         &path,
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            ..Default::default()
+        },
     );
     assert!(result.is_ok(), "circuit_command failed: {:?}", result.err());
     assert!(r1cs.exists(), "R1CS file was not created");
@@ -379,19 +242,11 @@ fn circuit_import_not_found() {
     let wtns = tmpdir.path().join("out.wtns");
     let result = cli::commands::circuit::circuit_command(
         main_path.to_str().unwrap(),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            ..Default::default()
+        },
     );
     assert!(result.is_err(), "should fail for missing module");
 }
@@ -413,19 +268,11 @@ fn circuit_import_self_circular_detected() {
     let wtns = tmpdir.path().join("out.wtns");
     let result = cli::commands::circuit::circuit_command(
         main_path.to_str().unwrap(),
-        r1cs.to_str().unwrap(),
-        wtns.to_str().unwrap(),
-        None,
-        None,
-        false,
-        "r1cs",
-        PrimeId::Bn254,
-        false,
-        None,
-        None,
-        false,
-        false,
-        EF,
+        &CircuitOptions {
+            r1cs_path: r1cs.to_str().unwrap(),
+            wtns_path: wtns.to_str().unwrap(),
+            ..Default::default()
+        },
     );
     assert!(result.is_err(), "should detect circular import");
     let err_msg = format!("{:?}", result.unwrap_err());
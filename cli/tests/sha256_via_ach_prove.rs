@@ -4,10 +4,7 @@
 
 use std::io::Write;
 
-use cli::commands::ErrorFormat;
-use memory::field::PrimeId;
-
-const EF: ErrorFormat = ErrorFormat::Human;
+use cli::commands::run::RunOptions;
 
 fn workspace_root() -> std::path::PathBuf {
     std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
@@ -23,18 +20,7 @@ fn run_ach(source: &str) -> anyhow::Result<()> {
     f.write_all(source.as_bytes()).expect("write ach source");
     f.flush().expect("flush ach source");
 
-    cli::commands::run::run_file(
-        ach_path.to_str().unwrap(),
-        false,
-        None,
-        "r1cs",
-        PrimeId::Bn254,
-        None,
-        false,
-        false,
-        EF,
-        &[],
-    )
+    cli::commands::run::run_file(ach_path.to_str().unwrap(), &RunOptions::default())
 }
 
 fn sha256_source(circomlib: &std::path::Path, n_bits: usize, mix: bool) -> String {
@@ -1,10 +1,14 @@
 /// Binary export for R1CS and witness files (iden3/snarkjs format).
 ///
 /// Produces `.r1cs` (version 1) and `.wtns` (version 2) files that can be
-/// consumed directly by `snarkjs` for Groth16 proof generation.
+/// consumed directly by `snarkjs` for Groth16 proof generation, plus the
+/// text `.sym` file snarkjs uses to name wires when debugging, and a
+/// human-readable JSON dump of the constraints.
 use crate::r1cs::{ConstraintSystem, LinearCombination};
 use memory::field::PrimeId;
-use memory::{FieldBackend, FieldElement};
+use memory::{BigInt, BigIntWidth, FieldBackend, FieldElement};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
 
 // ============================================================================
 // Helpers
@@ -164,6 +168,117 @@ pub fn write_wtns<F: FieldBackend>(witness: &[FieldElement<F>], _prime_id: Prime
     buf
 }
 
+// ============================================================================
+// write_r1cs_json
+// ============================================================================
+
+fn lc_to_json<F: FieldBackend>(lc: &LinearCombination<F>) -> Value {
+    // Simplified like `write_lc`, so each wire appears at most once.
+    let terms: Map<String, Value> = lc
+        .simplify()
+        .terms
+        .iter()
+        .map(|(var, coeff)| (var.index().to_string(), coeff.to_decimal_string().into()))
+        .collect();
+    Value::Object(terms)
+}
+
+/// Serialize a `ConstraintSystem` to the `achronyme-r1cs-json-v1` format,
+/// a readable counterpart of [`write_r1cs`] for inspection and diffing.
+///
+/// The header carries the prime, its modulus and the wire/constraint
+/// counts. Each constraint `A * B = C` becomes
+/// `{ "a": { "<wire>": "<coeff>", ... }, "b": {...}, "c": {...} }`, with
+/// decimal coefficients and the same wire indices as the `.r1cs` file.
+///
+/// ```
+/// use constraints::{write_r1cs_json, ConstraintSystem, LinearCombination};
+/// use memory::field::PrimeId;
+///
+/// let mut cs: ConstraintSystem = ConstraintSystem::new();
+/// let a = cs.alloc_witness();
+/// cs.enforce(
+///     LinearCombination::from_variable(a),
+///     LinearCombination::from_variable(a),
+///     LinearCombination::from_variable(a),
+/// );
+///
+/// let json: serde_json::Value =
+///     serde_json::from_str(&write_r1cs_json(&cs, PrimeId::Bn254)).unwrap();
+/// assert_eq!(json["num_constraints"], 1);
+/// assert_eq!(json["constraints"][0]["a"]["1"], "1");
+/// ```
+pub fn write_r1cs_json<F: FieldBackend>(cs: &ConstraintSystem<F>, prime_id: PrimeId) -> String {
+    let limbs = FieldElement::<F>::modulus_le_bytes()
+        .chunks_exact(8)
+        .map(|c| u64::from_le_bytes(c.try_into().expect("8-byte chunk")))
+        .collect();
+    let modulus = BigInt::from_limbs(limbs, BigIntWidth::W256).expect("4 limbs");
+
+    let root = json!({
+        "format": "achronyme-r1cs-json-v1",
+        "prime": prime_id.name(),
+        "modulus": modulus.to_decimal_string(),
+        "num_variables": cs.num_variables(),
+        "num_pub_inputs": cs.num_pub_inputs(),
+        "num_constraints": cs.num_constraints(),
+        "constraints": cs
+            .constraints()
+            .iter()
+            .map(|con| json!({
+                "a": lc_to_json(&con.a),
+                "b": lc_to_json(&con.b),
+                "c": lc_to_json(&con.c),
+            }))
+            .collect::<Vec<_>>(),
+    });
+    serde_json::to_string_pretty(&root).expect("JSON serialization failed")
+}
+
+// ============================================================================
+// write_sym
+// ============================================================================
+
+/// Render the circom `.sym` symbol file for a circuit's wires.
+///
+/// `labels` names every wire, indexed like the witness (as returned by
+/// `R1CSCompiler::witness_labels`). Each wire after `ONE` becomes a line
+/// `labelIdx,varIdx,componentIdx,signalName`; Achronyme keeps wire
+/// indices stable through optimization and has a single component, so
+/// the line reads `i,i,0,main.<label>`.
+///
+/// Signal names must be unique, so a label shared by several wires (e.g.
+/// every `mul_result`) is numbered per occurrence like a circom array:
+/// `main.mul_result[0]`, `main.mul_result[1]`, …
+///
+/// ```
+/// use constraints::write_sym;
+///
+/// let labels = ["ONE", "out", "mul_result", "x", "mul_result"].map(String::from);
+/// assert_eq!(
+///     write_sym(&labels),
+///     "1,1,0,main.out\n2,2,0,main.mul_result[0]\n3,3,0,main.x\n4,4,0,main.mul_result[1]\n"
+/// );
+/// ```
+pub fn write_sym(labels: &[String]) -> String {
+    let mut totals: HashMap<&str, usize> = HashMap::new();
+    for label in labels.iter().skip(1) {
+        *totals.entry(label).or_default() += 1;
+    }
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    let mut out = String::new();
+    for (i, label) in labels.iter().enumerate().skip(1) {
+        if totals[label.as_str()] == 1 {
+            out.push_str(&format!("{i},{i},0,main.{label}\n"));
+        } else {
+            let k = seen.entry(label).or_default();
+            out.push_str(&format!("{i},{i},0,main.{label}[{k}]\n"));
+            *k += 1;
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -359,4 +474,56 @@ mod tests {
         let n_prv_in = u32::from_le_bytes(body[48..52].try_into().unwrap());
         assert_eq!(n_prv_in, 3); // a, b, c
     }
+
+    #[test]
+    fn test_r1cs_json_lists_every_constraint() {
+        let mut cs = make_mul_circuit();
+        // 3*a + 5 = c, so the coefficients are distinguishable
+        let a = crate::r1cs::Variable(2);
+        let c = crate::r1cs::Variable(1);
+        cs.enforce(
+            LinearCombination::from_variable(a) * FieldElement::from_u64(3)
+                + LinearCombination::from_constant(FieldElement::from_u64(5)),
+            LinearCombination::from_constant(FieldElement::ONE),
+            LinearCombination::from_variable(c),
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&write_r1cs_json(&cs, PrimeId::Bn254)).unwrap();
+        assert_eq!(json["prime"], "bn254");
+        assert_eq!(json["num_variables"], 4);
+        assert_eq!(json["num_pub_inputs"], 1);
+        assert_eq!(json["num_constraints"], cs.num_constraints());
+        let constraints = json["constraints"].as_array().unwrap();
+        assert_eq!(constraints.len(), cs.num_constraints());
+
+        assert_eq!(constraints[0]["a"], serde_json::json!({ "2": "1" }));
+        assert_eq!(constraints[0]["b"], serde_json::json!({ "3": "1" }));
+        assert_eq!(
+            constraints[1]["a"],
+            serde_json::json!({ "0": "5", "2": "3" })
+        );
+        assert_eq!(constraints[1]["c"], serde_json::json!({ "1": "1" }));
+    }
+
+    #[test]
+    fn test_sym_names_are_unique() {
+        let labels = ["ONE", "x", "bit_0", "bit_0", "bit_0", "y"].map(String::from);
+        let sym = write_sym(&labels);
+        let names: Vec<&str> = sym.lines().map(|l| l.rsplit(',').next().unwrap()).collect();
+        assert_eq!(
+            names,
+            [
+                "main.x",
+                "main.bit_0[0]",
+                "main.bit_0[1]",
+                "main.bit_0[2]",
+                "main.y"
+            ]
+        );
+        assert_eq!(
+            names.iter().collect::<std::collections::HashSet<_>>().len(),
+            names.len()
+        );
+    }
 }
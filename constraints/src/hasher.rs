@@ -0,0 +1,118 @@
+//! Pluggable 2-to-1 hashing for circuit gadgets.
+//!
+//! [`CircuitHasher`] pairs an R1CS gadget with its native counterpart, so
+//! tree-shaped gadgets (left-fold chains, Merkle paths) can be
+//! written once and instantiated with any hash:
+//!
+//! - [`PoseidonHasher`] — circomlibjs-compatible Poseidon, the hash behind
+//!   `poseidon`, `poseidon_many` and `merkle_verify`.
+//! - [`Poseidon2Hasher`] — Poseidon2 with the same `[0, left, right]`
+//!   sponge layout.
+//!
+//! Both hashers borrow their parameters, so building one per call is free.
+
+use memory::{FieldBackend, FieldElement};
+
+use crate::poseidon::{poseidon_hash, poseidon_hash_circuit, PoseidonParams};
+use crate::poseidon2::{poseidon2_hash, poseidon2_hash_circuit, Poseidon2Params};
+use crate::r1cs::{ConstraintSystem, Variable};
+
+#[cfg(test)]
+mod tests;
+
+/// A 2-to-1 hash usable both as an R1CS gadget and natively.
+///
+/// Implementations must keep the two sides in agreement: for any inputs,
+/// the output wire of [`hash2`](Self::hash2) must be satisfiable exactly by
+/// [`native_hash2`](Self::native_hash2) of the input values.
+pub trait CircuitHasher<F: FieldBackend> {
+    /// Synthesize `hash(left, right)` and return its output wire.
+    fn hash2(&self, cs: &mut ConstraintSystem<F>, left: Variable, right: Variable) -> Variable;
+
+    /// Compute `hash(left, right)` natively.
+    fn native_hash2(&self, left: FieldElement<F>, right: FieldElement<F>) -> FieldElement<F>;
+
+    /// Left-fold [`hash2`](Self::hash2) over `inputs`:
+    /// `hash(hash(hash(a, b), c), d)`, the `poseidon_many` shape.
+    ///
+    /// # Panics
+    ///
+    /// If `inputs` has fewer than two elements.
+    fn hash_n(&self, cs: &mut ConstraintSystem<F>, inputs: &[Variable]) -> Variable {
+        assert!(inputs.len() >= 2, "hash_n requires at least 2 inputs");
+        inputs[2..]
+            .iter()
+            .fold(self.hash2(cs, inputs[0], inputs[1]), |acc, x| {
+                self.hash2(cs, acc, *x)
+            })
+    }
+
+    /// Native counterpart of [`hash_n`](Self::hash_n).
+    ///
+    /// # Panics
+    ///
+    /// If `inputs` has fewer than two elements.
+    fn native_hash_n(&self, inputs: &[FieldElement<F>]) -> FieldElement<F> {
+        assert!(
+            inputs.len() >= 2,
+            "native_hash_n requires at least 2 inputs"
+        );
+        inputs[2..]
+            .iter()
+            .fold(self.native_hash2(inputs[0], inputs[1]), |acc, x| {
+                self.native_hash2(acc, *x)
+            })
+    }
+}
+
+/// Poseidon (t=3) under the given parameters.
+#[derive(Clone, Copy)]
+pub struct PoseidonHasher<'a, F: FieldBackend> {
+    params: &'a PoseidonParams<F>,
+}
+
+impl<'a, F: FieldBackend> PoseidonHasher<'a, F> {
+    pub fn new(params: &'a PoseidonParams<F>) -> Self {
+        Self { params }
+    }
+
+    pub fn params(&self) -> &'a PoseidonParams<F> {
+        self.params
+    }
+}
+
+impl<F: FieldBackend> CircuitHasher<F> for PoseidonHasher<'_, F> {
+    fn hash2(&self, cs: &mut ConstraintSystem<F>, left: Variable, right: Variable) -> Variable {
+        poseidon_hash_circuit(cs, self.params, left, right)
+    }
+
+    fn native_hash2(&self, left: FieldElement<F>, right: FieldElement<F>) -> FieldElement<F> {
+        poseidon_hash(self.params, left, right)
+    }
+}
+
+/// Poseidon2 (t=3) under the given parameters.
+#[derive(Clone, Copy)]
+pub struct Poseidon2Hasher<'a, F: FieldBackend> {
+    params: &'a Poseidon2Params<F>,
+}
+
+impl<'a, F: FieldBackend> Poseidon2Hasher<'a, F> {
+    pub fn new(params: &'a Poseidon2Params<F>) -> Self {
+        Self { params }
+    }
+
+    pub fn params(&self) -> &'a Poseidon2Params<F> {
+        self.params
+    }
+}
+
+impl<F: FieldBackend> CircuitHasher<F> for Poseidon2Hasher<'_, F> {
+    fn hash2(&self, cs: &mut ConstraintSystem<F>, left: Variable, right: Variable) -> Variable {
+        poseidon2_hash_circuit(cs, self.params, left, right)
+    }
+
+    fn native_hash2(&self, left: FieldElement<F>, right: FieldElement<F>) -> FieldElement<F> {
+        poseidon2_hash(self.params, left, right)
+    }
+}
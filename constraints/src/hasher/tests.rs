@@ -0,0 +1,75 @@
+use super::*;
+use crate::export::write_r1cs;
+use memory::field::PrimeId;
+use memory::Bn254Fr;
+
+fn fe(n: u64) -> FieldElement {
+    FieldElement::from_u64(n)
+}
+
+#[test]
+fn test_poseidon_hasher_matches_poseidon_hash() {
+    let params = PoseidonParams::bn254_t3();
+    let hasher = PoseidonHasher::new(&params);
+    assert_eq!(
+        hasher.native_hash2(fe(1), fe(2)),
+        poseidon_hash(&params, fe(1), fe(2))
+    );
+}
+
+#[test]
+fn test_poseidon2_hasher_matches_poseidon2_hash() {
+    let params = Poseidon2Params::bn254_t3();
+    let hasher = Poseidon2Hasher::new(&params);
+    assert_eq!(
+        hasher.native_hash2(fe(1), fe(2)),
+        poseidon2_hash(&params, fe(1), fe(2))
+    );
+}
+
+#[test]
+fn test_poseidon_hasher_circuit_matches_gadget() {
+    let params = PoseidonParams::bn254_t3();
+
+    let mut expected = ConstraintSystem::<Bn254Fr>::new();
+    let (l, r) = (expected.alloc_witness(), expected.alloc_witness());
+    let out_expected = poseidon_hash_circuit(&mut expected, &params, l, r);
+
+    let mut actual = ConstraintSystem::<Bn254Fr>::new();
+    let (l, r) = (actual.alloc_witness(), actual.alloc_witness());
+    let out_actual = PoseidonHasher::new(&params).hash2(&mut actual, l, r);
+
+    assert_eq!(out_actual, out_expected);
+    assert_eq!(
+        write_r1cs(&actual, PrimeId::Bn254),
+        write_r1cs(&expected, PrimeId::Bn254)
+    );
+}
+
+#[test]
+fn test_native_hash_n_is_poseidon_many_fold() {
+    let params = PoseidonParams::bn254_t3();
+    let inputs = [fe(3), fe(5), fe(7), fe(11)];
+    let expected = poseidon_hash(
+        &params,
+        poseidon_hash(&params, poseidon_hash(&params, fe(3), fe(5)), fe(7)),
+        fe(11),
+    );
+    assert_eq!(
+        PoseidonHasher::new(&params).native_hash_n(&inputs),
+        expected
+    );
+}
+
+#[test]
+fn test_hashers_are_interchangeable() {
+    let poseidon = PoseidonParams::bn254_t3();
+    let poseidon2 = Poseidon2Params::bn254_t3();
+    fn digest<H: CircuitHasher<Bn254Fr>>(hasher: &H) -> FieldElement {
+        hasher.native_hash_n(&[fe(1), fe(2)])
+    }
+    let a = digest(&PoseidonHasher::new(&poseidon));
+    let b = digest(&Poseidon2Hasher::new(&poseidon2));
+    assert_ne!(a, b);
+    assert_eq!(b, poseidon2_hash(&poseidon2, fe(1), fe(2)));
+}
@@ -1,14 +1,17 @@
 pub mod backend;
 pub mod export;
+pub mod hasher;
 pub mod plonkish;
 pub mod plonkish_export;
 pub mod poseidon;
+pub mod poseidon2;
 pub mod r1cs;
 pub mod r1cs_optimize;
 pub mod witness;
 
 pub use backend::ConstraintBackend;
-pub use export::{write_r1cs, write_wtns};
+pub use export::{write_r1cs, write_r1cs_json, write_sym, write_wtns};
+pub use hasher::{CircuitHasher, Poseidon2Hasher, PoseidonHasher};
 pub use plonkish_export::{validate_plonkish_json, write_plonkish_json};
 pub use poseidon::PoseidonParamsProvider;
 pub use r1cs::{ConstraintError, ConstraintSystem, LinearCombination, Variable};
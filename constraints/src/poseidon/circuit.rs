@@ -10,7 +10,7 @@ use super::PoseidonParams;
 /// α=7: x² → x³ → x⁶ → x⁷ (4 constraints)
 ///
 /// Returns the variable holding x^α.
-fn sbox_circuit<F: FieldBackend>(
+pub(crate) fn sbox_circuit<F: FieldBackend>(
     cs: &mut ConstraintSystem<F>,
     x: &LinearCombination<F>,
     alpha: u32,
@@ -123,12 +123,13 @@ pub fn poseidon_hash_circuit<F: FieldBackend>(
     left: Variable,
     right: Variable,
 ) -> Variable {
-    // Capacity variable (always 0) — constrained to prevent malicious provers
-    // from using non-zero capacity to forge hash results.
+    // Capacity variable (pinned to the domain tag, 0 by default) —
+    // constrained to prevent malicious provers from choosing the capacity
+    // to forge hash results.
     let capacity = cs.alloc_witness();
     cs.enforce_equal(
         LinearCombination::from_variable(capacity),
-        LinearCombination::zero(),
+        LinearCombination::from_constant(params.domain_tag),
     );
 
     // Input state: [capacity, left, right]
@@ -5,7 +5,7 @@
 
 use memory::{FieldBackend, FieldElement};
 
-pub(super) struct GrainLfsr {
+pub(crate) struct GrainLfsr {
     state: [bool; 80],
 }
 
@@ -13,6 +13,13 @@ impl GrainLfsr {
     /// Initialize from Poseidon parameters.
     /// Encoding: [field_type:2][sbox:4][field_size:12][t:12][R_F:10][R_P:10][padding:30]
     pub(super) fn new(field_size: u16, t: u16, r_f: u16, r_p: u16) -> Self {
+        Self::with_sbox(1, field_size, t, r_f, r_p)
+    }
+
+    /// Like [`Self::new`], with an explicit 4-bit S-box code. The reference
+    /// scripts use 0 for x^α, which is what Poseidon2 constants are
+    /// generated with.
+    pub(crate) fn with_sbox(sbox: u8, field_size: u16, t: u16, r_f: u16, r_p: u16) -> Self {
         let mut bits = [false; 80];
         let mut pos = 0;
 
@@ -20,10 +27,9 @@ impl GrainLfsr {
         bits[pos + 1] = true;
         pos += 2;
 
-        for i in 0..3 {
-            bits[pos + i] = false;
+        for i in 0..4 {
+            bits[pos + i] = (sbox >> (3 - i)) & 1 == 1;
         }
-        bits[pos + 3] = true;
         pos += 4;
 
         for i in 0..12 {
@@ -107,4 +113,24 @@ impl GrainLfsr {
             }
         }
     }
+
+    /// Generate the next field element exactly as the reference parameter
+    /// scripts do: `field_size` bits read most-significant first form the
+    /// integer, and values not below the modulus are rejected.
+    pub(crate) fn next_reference_element<F: FieldBackend>(
+        &mut self,
+        field_size: usize,
+    ) -> FieldElement<F> {
+        loop {
+            let mut le = [0u8; 32];
+            for bit_idx in (0..field_size).rev() {
+                if self.next_bit() {
+                    le[bit_idx / 8] |= 1 << (bit_idx % 8);
+                }
+            }
+            if let Some(fe) = FieldElement::<F>::from_le_bytes(&le) {
+                return fe;
+            }
+        }
+    }
 }
@@ -33,7 +33,7 @@
 /// [`PoseidonParams::bn254_t3_lfsr`] for reference and auditing.
 pub mod circuit;
 mod constants;
-mod lfsr;
+pub(crate) mod lfsr;
 pub mod native;
 mod params;
 
@@ -41,5 +41,5 @@ mod params;
 mod tests;
 
 pub use circuit::{poseidon_hash_circuit, poseidon_permutation_circuit};
-pub use native::{poseidon_hash, poseidon_hash_single, poseidon_permutation};
+pub use native::{poseidon_hash, poseidon_hash_many, poseidon_hash_single, poseidon_permutation};
 pub use params::{PoseidonParams, PoseidonParamsProvider};
@@ -1,6 +1,7 @@
 use memory::{FieldBackend, FieldElement};
 
 use super::PoseidonParams;
+use crate::hasher::{CircuitHasher, PoseidonHasher};
 
 /// Compute x^α in the field (S-box).
 ///
@@ -63,7 +64,7 @@ pub fn poseidon_permutation<F: FieldBackend>(
 
 /// Compute Poseidon hash of two field elements (2-to-1 hash).
 ///
-/// State: [capacity=0, input1, input2]
+/// State: [capacity=domain_tag, input1, input2]
 /// Output: state[0] after permutation (circomlibjs convention)
 pub fn poseidon_hash<F: FieldBackend>(
     params: &PoseidonParams<F>,
@@ -71,6 +72,7 @@ pub fn poseidon_hash<F: FieldBackend>(
     right: FieldElement<F>,
 ) -> FieldElement<F> {
     let mut state = vec![FieldElement::<F>::zero(); params.t];
+    state[0] = params.domain_tag;
     state[1] = left;
     state[2] = right;
     poseidon_permutation(params, &mut state);
@@ -79,14 +81,38 @@ pub fn poseidon_hash<F: FieldBackend>(
 
 /// Compute Poseidon hash of a single field element.
 ///
-/// State: [capacity=0, input, 0]
+/// State: [capacity=domain_tag, input, 0]
 /// Output: state[0] after permutation (circomlibjs convention)
 pub fn poseidon_hash_single<F: FieldBackend>(
     params: &PoseidonParams<F>,
     input: FieldElement<F>,
 ) -> FieldElement<F> {
     let mut state = vec![FieldElement::<F>::zero(); params.t];
+    state[0] = params.domain_tag;
     state[1] = input;
     poseidon_permutation(params, &mut state);
     state[0]
 }
+
+/// Compute the Poseidon hash of any number of field elements.
+///
+/// This is a left fold of the 2-to-1 hash, `hash(hash(hash(a, b), c), d)`,
+/// not a sponge absorbing the inputs at rate 2: the fold is what the
+/// in-circuit `poseidon_many` builtin synthesizes, and this is its native
+/// reference. Two or more inputs go through
+/// [`CircuitHasher::native_hash_n`]; a single input falls back to
+/// [`poseidon_hash_single`].
+///
+/// # Panics
+///
+/// If `inputs` is empty.
+pub fn poseidon_hash_many<F: FieldBackend>(
+    params: &PoseidonParams<F>,
+    inputs: &[FieldElement<F>],
+) -> FieldElement<F> {
+    match inputs {
+        [] => panic!("poseidon_hash_many requires at least 1 input"),
+        [single] => poseidon_hash_single(params, *single),
+        _ => PoseidonHasher::new(params).native_hash_n(inputs),
+    }
+}
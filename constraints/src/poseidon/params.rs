@@ -20,6 +20,9 @@ pub struct PoseidonParams<F: FieldBackend = memory::Bn254Fr> {
     pub round_constants: Vec<FieldElement<F>>,
     /// MDS matrix: t x t, stored row-major
     pub mds: Vec<Vec<FieldElement<F>>>,
+    /// Initial value of the capacity element. Zero for plain circomlib
+    /// Poseidon; see [`PoseidonParams::with_domain_tag`].
+    pub domain_tag: FieldElement<F>,
 }
 
 impl<F: FieldBackend> PoseidonParams<F> {
@@ -50,9 +53,41 @@ impl<F: FieldBackend> PoseidonParams<F> {
             alpha,
             round_constants,
             mds,
+            domain_tag: FieldElement::<F>::zero(),
         }
     }
 
+    /// Check the structural invariants the permutation relies on: a state
+    /// of at least 2 elements, an even non-zero number of full rounds, at
+    /// least one partial round, an S-box exponent above 1, `(r_f + r_p) * t`
+    /// round constants and a `t x t` MDS matrix.
+    ///
+    /// The fields are public, so this also catches parameters edited after
+    /// construction. It cannot detect a mistyped constant of the right
+    /// shape; the reference-vector tests cover that.
+    pub fn validate(&self) -> bool {
+        self.t >= 2
+            && self.r_f > 0
+            && self.r_f.is_multiple_of(2)
+            && self.r_p > 0
+            && self.alpha > 1
+            && self.round_constants.len() == (self.r_f + self.r_p) * self.t
+            && self.mds.len() == self.t
+            && self.mds.iter().all(|row| row.len() == self.t)
+    }
+
+    /// Return these parameters with the capacity element initialized to
+    /// `tag` instead of zero.
+    ///
+    /// Hashes computed under different tags are domain-separated: the same
+    /// inputs give unrelated digests, so a Merkle node can never be confused
+    /// with, say, a commitment built from the same permutation. Tag zero is
+    /// the circomlib-compatible default.
+    pub fn with_domain_tag(mut self, tag: FieldElement<F>) -> Self {
+        self.domain_tag = tag;
+        self
+    }
+
     /// Generate Poseidon parameters via Grain LFSR + Cauchy MDS.
     ///
     /// Paper-compliant (ePrint 2019/458, Appendix E). Constants depend on
@@ -138,7 +173,9 @@ impl PoseidonParams<memory::Bn254Fr> {
             .map(|row| row.iter().map(|h| fe_from_hex(h)).collect())
             .collect();
 
-        Self::new(3, 8, 57, 5, round_constants, mds)
+        let params = Self::new(3, 8, 57, 5, round_constants, mds);
+        debug_assert!(params.validate(), "circomlib BN254 Poseidon parameters");
+        params
     }
 
     /// Paper-compliant BN254 parameters: t=3, R_f=8, R_p=57, α=5
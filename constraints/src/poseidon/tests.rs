@@ -19,6 +19,27 @@ fn test_poseidon_params_construction() {
     assert_eq!(params.mds[0].len(), 3);
 }
 
+#[test]
+fn test_poseidon_params_validate() {
+    assert!(PoseidonParams::bn254_t3().validate());
+    assert!(PoseidonParams::bn254_t3_lfsr().validate());
+    assert!(PoseidonParams::<Bls12_381Fr>::bls12_381_t3().validate());
+    assert!(PoseidonParams::<GoldilocksFr>::goldilocks_t3().validate());
+
+    let tamper = |edit: fn(&mut PoseidonParams)| {
+        let mut params = PoseidonParams::bn254_t3();
+        edit(&mut params);
+        params.validate()
+    };
+    assert!(!tamper(|p| {
+        p.round_constants.pop();
+    }));
+    assert!(!tamper(|p| p.mds[1].truncate(2)));
+    assert!(!tamper(|p| p.r_p = 0));
+    assert!(!tamper(|p| p.r_f = 7));
+    assert!(!tamper(|p| p.alpha = 1));
+}
+
 #[test]
 fn test_sbox_alpha5() {
     // 2^5 = 32
@@ -94,6 +115,18 @@ fn test_poseidon_circomlibjs_reference_vector() {
         hash, expected,
         "poseidon(1, 2) must match circomlibjs reference"
     );
+
+    // circomlibjs poseidon([0, 0]), the first non-leaf zero value of
+    // Semaphore-style incremental Merkle trees.
+    let zero = FieldElement::zero();
+    assert_eq!(
+        poseidon_hash(&params, zero, zero),
+        FieldElement::from_decimal_str(
+            "14744269619966411208579211824598458697587494354926760081771325075741142829156",
+        )
+        .unwrap(),
+        "poseidon(0, 0) must match circomlibjs reference"
+    );
 }
 
 #[test]
@@ -110,16 +143,20 @@ fn test_poseidon_single() {
 }
 
 #[test]
-#[allow(clippy::needless_range_loop)]
 fn test_poseidon_circuit_matches_native() {
     // This is the critical test: R1CS computation must match native
-    let params = PoseidonParams::bn254_t3();
+    assert_circuit_matches_native(&PoseidonParams::bn254_t3());
+}
 
+/// Synthesize `poseidon_hash_circuit(1, 2)` under `params`, fill the witness
+/// by replaying the permutation natively, and verify every constraint.
+#[allow(clippy::needless_range_loop)]
+fn assert_circuit_matches_native(params: &PoseidonParams) {
     let left = FieldElement::from_u64(1);
     let right = FieldElement::from_u64(2);
 
     // 1. Compute native hash
-    let expected_hash = poseidon_hash(&params, left, right);
+    let expected_hash = poseidon_hash(params, left, right);
 
     // 2. Build R1CS circuit
     let mut cs = ConstraintSystem::new();
@@ -127,7 +164,7 @@ fn test_poseidon_circuit_matches_native() {
     let left_var = cs.alloc_witness();
     let right_var = cs.alloc_witness();
 
-    let computed_hash = poseidon_hash_circuit(&mut cs, &params, left_var, right_var);
+    let computed_hash = poseidon_hash_circuit(&mut cs, params, left_var, right_var);
 
     // Constrain: computed_hash == hash_output (public)
     cs.enforce_equal(
@@ -141,13 +178,13 @@ fn test_poseidon_circuit_matches_native() {
     wb.set(left_var, left);
     wb.set(right_var, right);
 
-    // Set capacity = 0
+    // Set capacity = domain tag (0 by default)
     // Capacity is the 4th variable allocated (after ONE=0, hash_output=1, left=2, right=3)
     let capacity_var = Variable(4);
-    wb.set(capacity_var, FieldElement::ZERO);
+    wb.set(capacity_var, params.domain_tag);
 
     // Compute all intermediate witness values by replaying the permutation
-    let mut state = vec![FieldElement::ZERO, left, right];
+    let mut state = vec![params.domain_tag, left, right];
     let total_rounds = params.r_f + params.r_p;
     let half_f = params.r_f / 2;
 
@@ -242,6 +279,69 @@ fn test_poseidon_constraint_count() {
     assert_eq!(cs.num_constraints(), 361);
 }
 
+// --- Domain separation ---
+
+#[test]
+fn test_domain_tag_zero_is_default() {
+    let params = PoseidonParams::bn254_t3();
+    let tagged = PoseidonParams::bn254_t3().with_domain_tag(FieldElement::ZERO);
+    let (a, b) = (FieldElement::from_u64(1), FieldElement::from_u64(2));
+    assert_eq!(poseidon_hash(&params, a, b), poseidon_hash(&tagged, a, b));
+    assert_eq!(
+        poseidon_hash_single(&params, a),
+        poseidon_hash_single(&tagged, a)
+    );
+}
+
+#[test]
+fn test_domain_tags_separate_digests() {
+    let (a, b) = (FieldElement::from_u64(1), FieldElement::from_u64(2));
+    let digests: Vec<FieldElement> = (0..4)
+        .map(|tag| {
+            let params = PoseidonParams::bn254_t3().with_domain_tag(FieldElement::from_u64(tag));
+            poseidon_hash(&params, a, b)
+        })
+        .collect();
+    for i in 0..digests.len() {
+        for j in (i + 1)..digests.len() {
+            assert_ne!(digests[i], digests[j], "tags {i} and {j} collide");
+        }
+    }
+    // Tag 0 is still the circomlibjs reference value.
+    assert_eq!(
+        digests[0],
+        FieldElement::from_decimal_str(
+            "7853200120776062878684798364095072458815029376092732009249414926327459813530",
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_domain_tagged_circuit_matches_native() {
+    let params = PoseidonParams::bn254_t3().with_domain_tag(FieldElement::from_u64(7));
+    assert_circuit_matches_native(&params);
+}
+
+#[test]
+fn test_domain_tagged_circuit_rejects_zero_capacity() {
+    let params = PoseidonParams::bn254_t3().with_domain_tag(FieldElement::from_u64(7));
+    let mut cs = ConstraintSystem::new();
+    let left = cs.alloc_witness();
+    let right = cs.alloc_witness();
+    let _hash = poseidon_hash_circuit(&mut cs, &params, left, right);
+
+    // Capacity is the wire right after the two inputs; an untagged
+    // (zero) capacity must violate the first gadget constraint.
+    let mut wb = WitnessBuilder::new(&cs);
+    wb.set(Variable(3), FieldElement::ZERO);
+    let witness = wb.build();
+    assert!(matches!(
+        cs.verify(&witness),
+        Err(crate::r1cs::ConstraintError::ConstraintUnsatisfied(0))
+    ));
+}
+
 // --- LFSR reference tests ---
 
 #[test]
@@ -438,3 +538,67 @@ fn test_goldilocks_poseidon_single() {
     assert_ne!(h, x);
     assert!(!h.is_zero());
 }
+
+/// `poseidon_hash_many` over `1..=n`. The vectors pin the left fold of the
+/// circomlibjs-checked 2-to-1 hash (not a sponge), so a drift in either
+/// side shows up.
+fn hash_many_1_to(n: u64) -> FieldElement {
+    let params = PoseidonParams::bn254_t3();
+    let inputs: Vec<FieldElement> = (1..=n).map(FieldElement::from_u64).collect();
+    poseidon_hash_many(&params, &inputs)
+}
+
+#[test]
+fn test_poseidon_hash_many_one_input() {
+    let params = PoseidonParams::bn254_t3();
+    let h = hash_many_1_to(1);
+    assert_eq!(h, poseidon_hash_single(&params, FieldElement::ONE));
+    assert_eq!(
+        h,
+        FieldElement::from_decimal_str(
+            "18423194802802147121294641945063302532319431080857859605204660473644265519999"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_poseidon_hash_many_three_inputs() {
+    let params = PoseidonParams::bn254_t3();
+    let fe = FieldElement::from_u64;
+    let h = hash_many_1_to(3);
+    assert_eq!(
+        h,
+        poseidon_hash(&params, poseidon_hash(&params, fe(1), fe(2)), fe(3))
+    );
+    assert_eq!(
+        h,
+        FieldElement::from_decimal_str(
+            "13816780880028945690020260331303642730075999758909899334839547418969502592169"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_poseidon_hash_many_seven_inputs() {
+    use crate::hasher::{CircuitHasher, PoseidonHasher};
+
+    let params = PoseidonParams::bn254_t3();
+    let inputs: Vec<FieldElement> = (1..=7).map(FieldElement::from_u64).collect();
+    let h = hash_many_1_to(7);
+    assert_eq!(h, PoseidonHasher::new(&params).native_hash_n(&inputs));
+    assert_eq!(
+        h,
+        FieldElement::from_decimal_str(
+            "6272990859907660384164338278717556123472882977015447020691864347816231898921"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+#[should_panic(expected = "at least 1 input")]
+fn test_poseidon_hash_many_rejects_empty() {
+    poseidon_hash_many(&PoseidonParams::bn254_t3(), &[]);
+}
@@ -0,0 +1,284 @@
+//! Poseidon2 permutation over the BN254 scalar field (t=3).
+//!
+//! Poseidon2 (ePrint 2023/323) keeps Poseidon's S-box and round schedule
+//! but replaces the dense MDS matrix with two cheap linear layers:
+//!
+//! - **External rounds** (R_F=8, split 4 + 4): round constants on every
+//!   element, S-box on every element, then `M_E = circ(2, 1, 1)`, i.e.
+//!   `x_i ← x_i + Σx`. `M_E` is also applied once before the first round.
+//! - **Internal rounds** (R_P=56): one round constant and one S-box on
+//!   `x_0`, then `M_I = J + diag(d)`, i.e. `x_i ← d_i·x_i + Σx` with
+//!   `d = [1, 1, 2]`.
+//!
+//! One partial round fewer than Poseidon (56 vs 57) and sparse linear
+//! layers make it cheaper natively and in R1CS: the gadget below keeps
+//! internal-round state as simplified linear combinations instead of
+//! materializing it, so it emits 244 constraints against Poseidon's 361.
+//! After linear-constraint elimination (O1) both reduce to their S-box
+//! multiplications: 240 vs 243.
+//!
+//! # Constant provenance
+//!
+//! [`Poseidon2Params::bn254_t3`] regenerates the HorizenLabs reference
+//! constants (`poseidon2_instance_bn256`, `RC3`) with the Grain LFSR:
+//! `R_F·t + R_P` elements, external rounds taking `t` each and internal
+//! rounds one. The reference permutation of `[0, 1, 2]` is pinned in the
+//! tests.
+//!
+//! [`poseidon2_hash`] uses the same sponge layout as
+//! [`crate::poseidon::poseidon_hash`]: state `[0, left, right]`, output
+//! `state[0]`.
+
+use memory::{Bn254Fr, FieldBackend, FieldElement};
+
+use crate::poseidon::circuit::sbox_circuit;
+use crate::poseidon::lfsr::GrainLfsr;
+use crate::poseidon::native::sbox;
+use crate::r1cs::{ConstraintSystem, LinearCombination, Variable};
+
+#[cfg(test)]
+mod tests;
+
+/// Poseidon2 parameters, generic over the prime field. Only the t=3
+/// external matrix is implemented.
+#[derive(Clone)]
+pub struct Poseidon2Params<F: FieldBackend = Bn254Fr> {
+    /// State width (always 3)
+    pub t: usize,
+    /// Number of external (full) rounds, split evenly around the internal ones
+    pub r_f: usize,
+    /// Number of internal (partial) rounds
+    pub r_p: usize,
+    /// S-box exponent
+    pub alpha: u32,
+    /// External round constants: r_f * t field elements
+    pub external_rc: Vec<FieldElement<F>>,
+    /// Internal round constants: one per internal round, added to `x_0`
+    pub internal_rc: Vec<FieldElement<F>>,
+    /// Diagonal `d` of the internal matrix `J + diag(d)`
+    pub internal_diag: Vec<FieldElement<F>>,
+    /// Initial value of the capacity element. Zero by default; see
+    /// [`Poseidon2Params::with_domain_tag`].
+    pub domain_tag: FieldElement<F>,
+}
+
+impl<F: FieldBackend> Poseidon2Params<F> {
+    /// Construct Poseidon2Params from explicit components.
+    pub fn new(
+        r_f: usize,
+        r_p: usize,
+        alpha: u32,
+        external_rc: Vec<FieldElement<F>>,
+        internal_rc: Vec<FieldElement<F>>,
+        internal_diag: Vec<FieldElement<F>>,
+    ) -> Self {
+        let t = 3;
+        assert_eq!(
+            external_rc.len(),
+            r_f * t,
+            "expected {} external round constants, got {}",
+            r_f * t,
+            external_rc.len()
+        );
+        assert_eq!(
+            internal_rc.len(),
+            r_p,
+            "expected {r_p} internal round constants, got {}",
+            internal_rc.len()
+        );
+        assert_eq!(
+            internal_diag.len(),
+            t,
+            "internal diagonal must have {t} elements"
+        );
+        Self {
+            t,
+            r_f,
+            r_p,
+            alpha,
+            external_rc,
+            internal_rc,
+            internal_diag,
+            domain_tag: FieldElement::<F>::zero(),
+        }
+    }
+
+    /// Return these parameters with the capacity element initialized to
+    /// `tag` instead of zero, as [`PoseidonParams::with_domain_tag`] does
+    /// for Poseidon.
+    ///
+    /// [`PoseidonParams::with_domain_tag`]: crate::poseidon::PoseidonParams::with_domain_tag
+    pub fn with_domain_tag(mut self, tag: FieldElement<F>) -> Self {
+        self.domain_tag = tag;
+        self
+    }
+}
+
+impl Poseidon2Params<Bn254Fr> {
+    /// Standard BN254 parameters: t=3, R_F=8, R_P=56, α=5, d=[1, 1, 2].
+    pub fn bn254_t3() -> Self {
+        let (t, r_f, r_p) = (3, 8, 56);
+        let mut grain = GrainLfsr::with_sbox(0, 254, t as u16, r_f as u16, r_p as u16);
+        let mut next = || grain.next_reference_element::<Bn254Fr>(254);
+
+        let half = r_f / 2 * t;
+        let mut external_rc: Vec<FieldElement> = (0..half).map(|_| next()).collect();
+        let internal_rc = (0..r_p).map(|_| next()).collect();
+        external_rc.extend((0..half).map(|_| next()));
+
+        let internal_diag = [1, 1, 2].map(FieldElement::from_u64).to_vec();
+        Self::new(r_f, r_p, 5, external_rc, internal_rc, internal_diag)
+    }
+}
+
+/// `M_E = circ(2, 1, 1)`: add the state sum to every element.
+fn external_linear<F: FieldBackend>(state: &mut [FieldElement<F>]) {
+    let sum = state
+        .iter()
+        .fold(FieldElement::<F>::zero(), |acc, x| acc.add(x));
+    for x in state.iter_mut() {
+        *x = x.add(&sum);
+    }
+}
+
+/// `M_I = J + diag(d)`: `x_i ← d_i·x_i + Σx`.
+fn internal_linear<F: FieldBackend>(params: &Poseidon2Params<F>, state: &mut [FieldElement<F>]) {
+    let sum = state
+        .iter()
+        .fold(FieldElement::<F>::zero(), |acc, x| acc.add(x));
+    for (x, d) in state.iter_mut().zip(&params.internal_diag) {
+        *x = x.mul(d).add(&sum);
+    }
+}
+
+/// Apply the Poseidon2 permutation to a state vector (in-place).
+pub fn poseidon2_permutation<F: FieldBackend>(
+    params: &Poseidon2Params<F>,
+    state: &mut [FieldElement<F>],
+) {
+    let half_f = params.r_f / 2;
+    let external_round = |state: &mut [FieldElement<F>], r: usize| {
+        for (i, x) in state.iter_mut().enumerate() {
+            *x = sbox(x.add(&params.external_rc[r * params.t + i]), params.alpha);
+        }
+        external_linear(state);
+    };
+
+    external_linear(state);
+    for r in 0..half_f {
+        external_round(state, r);
+    }
+    for rc in &params.internal_rc {
+        state[0] = sbox(state[0].add(rc), params.alpha);
+        internal_linear(params, state);
+    }
+    for r in half_f..params.r_f {
+        external_round(state, r);
+    }
+}
+
+/// Compute Poseidon2 hash of two field elements (2-to-1 hash).
+///
+/// State: [capacity=domain_tag, left, right]
+/// Output: state[0] after permutation
+pub fn poseidon2_hash<F: FieldBackend>(
+    params: &Poseidon2Params<F>,
+    left: FieldElement<F>,
+    right: FieldElement<F>,
+) -> FieldElement<F> {
+    let mut state = vec![params.domain_tag, left, right];
+    poseidon2_permutation(params, &mut state);
+    state[0]
+}
+
+/// Synthesize the Poseidon2 permutation as R1CS constraints.
+///
+/// Only S-boxes generate constraints. The linear layers are folded into
+/// linear combinations; internal rounds simplify rather than materialize
+/// them, so each LC grows by at most one term per round (the new S-box
+/// output) instead of doubling.
+pub fn poseidon2_permutation_circuit<F: FieldBackend>(
+    cs: &mut ConstraintSystem<F>,
+    params: &Poseidon2Params<F>,
+    input_vars: &[Variable],
+) -> Vec<Variable> {
+    let half_f = params.r_f / 2;
+    let mut state: Vec<LinearCombination<F>> = input_vars
+        .iter()
+        .map(|v| LinearCombination::from_variable(*v))
+        .collect();
+
+    let sum = |state: &[LinearCombination<F>]| {
+        state
+            .iter()
+            .fold(LinearCombination::zero(), |acc, x| acc + x.clone())
+    };
+    let external_linear = |state: &mut Vec<LinearCombination<F>>| {
+        let total = sum(state);
+        for x in state.iter_mut() {
+            let mut out = x.clone() + total.clone();
+            out.simplify_in_place();
+            *x = out;
+        }
+    };
+    let external_round =
+        |cs: &mut ConstraintSystem<F>, state: &mut Vec<LinearCombination<F>>, r: usize| {
+            for (i, x) in state.iter_mut().enumerate() {
+                let rc = params.external_rc[r * params.t + i];
+                let input = x.clone() + LinearCombination::from_constant(rc);
+                *x = LinearCombination::from_variable(sbox_circuit(cs, &input, params.alpha));
+            }
+            external_linear(state);
+        };
+
+    external_linear(&mut state);
+    for r in 0..half_f {
+        external_round(cs, &mut state, r);
+    }
+    for rc in &params.internal_rc {
+        let mut input = state[0].clone() + LinearCombination::from_constant(*rc);
+        input.simplify_in_place();
+        state[0] = LinearCombination::from_variable(sbox_circuit(cs, &input, params.alpha));
+
+        let total = sum(&state);
+        for (x, d) in state.iter_mut().zip(&params.internal_diag) {
+            let mut out = x.clone() * *d + total.clone();
+            out.simplify_in_place();
+            *x = out;
+        }
+    }
+    for r in half_f..params.r_f {
+        external_round(cs, &mut state, r);
+    }
+
+    // Materialize final state into variables
+    state
+        .into_iter()
+        .map(|lc| {
+            let out = cs.alloc_witness();
+            cs.enforce_equal(lc, LinearCombination::from_variable(out));
+            out
+        })
+        .collect()
+}
+
+/// Synthesize a complete Poseidon2 2-to-1 hash circuit.
+///
+/// Inputs: two field element variables
+/// Output: the hash variable
+pub fn poseidon2_hash_circuit<F: FieldBackend>(
+    cs: &mut ConstraintSystem<F>,
+    params: &Poseidon2Params<F>,
+    left: Variable,
+    right: Variable,
+) -> Variable {
+    // Capacity variable pinned to the domain tag, as in `poseidon_hash_circuit`.
+    let capacity = cs.alloc_witness();
+    cs.enforce_equal(
+        LinearCombination::from_variable(capacity),
+        LinearCombination::from_constant(params.domain_tag),
+    );
+
+    let output_vars = poseidon2_permutation_circuit(cs, params, &[capacity, left, right]);
+    output_vars[0]
+}
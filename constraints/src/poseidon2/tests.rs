@@ -0,0 +1,146 @@
+use super::*;
+use crate::poseidon::{poseidon_hash, poseidon_hash_circuit, PoseidonParams};
+use crate::witness::WitnessBuilder;
+
+fn fe(hex: &str) -> FieldElement {
+    FieldElement::from_hex_str(hex).unwrap()
+}
+
+// ============================================================================
+// Reference vectors (HorizenLabs poseidon2, poseidon2_instance_bn256)
+// ============================================================================
+
+#[test]
+fn test_poseidon2_first_round_constant() {
+    let params = Poseidon2Params::bn254_t3();
+    assert_eq!(
+        params.external_rc[0],
+        fe("0x1d066a255517b7fd8bddd3a93f7804ef7f8fcde48bb4c37a59a09a1a97052816")
+    );
+    assert_eq!(params.external_rc.len(), 24);
+    assert_eq!(params.internal_rc.len(), 56);
+}
+
+#[test]
+fn test_poseidon2_reference_permutation() {
+    let params = Poseidon2Params::bn254_t3();
+    let mut state = [0, 1, 2].map(FieldElement::from_u64);
+    poseidon2_permutation(&params, &mut state);
+    assert_eq!(
+        state,
+        [
+            fe("0x0bb61d24daca55eebcb1929a82650f328134334da98ea4f847f760054f4a3033"),
+            fe("0x303b6f7c86d043bfcbcc80214f26a30277a15d3f74ca654992defe7ff8d03570"),
+            fe("0x1ed25194542b12eef8617361c3ba7c52e660b145994427cc86296242cf766ec8"),
+        ]
+    );
+}
+
+#[test]
+fn test_poseidon2_hash_differs_from_poseidon() {
+    let one = FieldElement::from_u64(1);
+    let two = FieldElement::from_u64(2);
+    let h2 = poseidon2_hash(&Poseidon2Params::bn254_t3(), one, two);
+    assert_ne!(h2, poseidon_hash(&PoseidonParams::bn254_t3(), one, two));
+    assert_ne!(h2, poseidon2_hash(&Poseidon2Params::bn254_t3(), two, one));
+}
+
+// ============================================================================
+// R1CS gadget
+// ============================================================================
+
+/// Fill every witness variable the gadget allocated. Each constraint after
+/// the capacity pin defines its `C` side as a single fresh variable, so one
+/// pass in constraint order evaluates them all.
+fn solve(cs: &ConstraintSystem, mut witness: Vec<FieldElement>) -> Vec<FieldElement> {
+    for c in cs.constraints() {
+        if let [(out, coeff)] = c.c.terms() {
+            if *coeff == FieldElement::ONE {
+                let a = c.a.evaluate(&witness).unwrap();
+                let b = c.b.evaluate(&witness).unwrap();
+                witness[out.index()] = a.mul(&b);
+            }
+        }
+    }
+    witness
+}
+
+#[test]
+fn test_poseidon2_circuit_matches_native() {
+    let params = Poseidon2Params::bn254_t3();
+    let left = FieldElement::from_u64(1);
+    let right = FieldElement::from_u64(2);
+
+    let mut cs = ConstraintSystem::new();
+    let left_var = cs.alloc_witness();
+    let right_var = cs.alloc_witness();
+    let hash = poseidon2_hash_circuit(&mut cs, &params, left_var, right_var);
+
+    let mut wb = WitnessBuilder::new(&cs);
+    wb.set(left_var, left);
+    wb.set(right_var, right);
+    let witness = solve(&cs, wb.build());
+
+    assert!(cs.verify(&witness).is_ok(), "{:?}", cs.verify(&witness));
+    assert_eq!(witness[hash.index()], poseidon2_hash(&params, left, right));
+}
+
+#[test]
+fn test_poseidon2_domain_tag_separates_digests() {
+    let (one, two) = (FieldElement::from_u64(1), FieldElement::from_u64(2));
+    let plain = Poseidon2Params::bn254_t3();
+    let untagged = Poseidon2Params::bn254_t3().with_domain_tag(FieldElement::ZERO);
+    let tagged = Poseidon2Params::bn254_t3().with_domain_tag(FieldElement::from_u64(7));
+    assert_eq!(
+        poseidon2_hash(&plain, one, two),
+        poseidon2_hash(&untagged, one, two)
+    );
+    assert_ne!(
+        poseidon2_hash(&plain, one, two),
+        poseidon2_hash(&tagged, one, two)
+    );
+}
+
+#[test]
+fn test_poseidon2_domain_tagged_circuit_matches_native() {
+    let tag = FieldElement::from_u64(7);
+    let params = Poseidon2Params::bn254_t3().with_domain_tag(tag);
+    let (left, right) = (FieldElement::from_u64(1), FieldElement::from_u64(2));
+
+    let mut cs = ConstraintSystem::new();
+    let left_var = cs.alloc_witness();
+    let right_var = cs.alloc_witness();
+    let hash = poseidon2_hash_circuit(&mut cs, &params, left_var, right_var);
+
+    // Capacity is the wire right after the two inputs.
+    let mut wb = WitnessBuilder::new(&cs);
+    wb.set(left_var, left);
+    wb.set(right_var, right);
+    wb.set(Variable(3), tag);
+    let witness = solve(&cs, wb.build());
+
+    assert!(cs.verify(&witness).is_ok(), "{:?}", cs.verify(&witness));
+    assert_eq!(witness[hash.index()], poseidon2_hash(&params, left, right));
+}
+
+#[test]
+fn test_poseidon2_constraint_count() {
+    let mut cs = ConstraintSystem::new();
+    let left = cs.alloc_witness();
+    let right = cs.alloc_witness();
+    poseidon2_hash_circuit(&mut cs, &Poseidon2Params::bn254_t3(), left, right);
+
+    // S-boxes: (8 · 3 + 56) · 3 = 240, outputs: 3, capacity: 1
+    assert_eq!(cs.num_constraints(), 244);
+
+    let mut poseidon_cs = ConstraintSystem::new();
+    let left = poseidon_cs.alloc_witness();
+    let right = poseidon_cs.alloc_witness();
+    poseidon_hash_circuit(&mut poseidon_cs, &PoseidonParams::bn254_t3(), left, right);
+    assert!(
+        cs.num_constraints() * 4 < poseidon_cs.num_constraints() * 3,
+        "poseidon2: {}, poseidon: {}",
+        cs.num_constraints(),
+        poseidon_cs.num_constraints()
+    );
+}
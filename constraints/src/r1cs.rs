@@ -8,6 +8,7 @@
 ///   Index 0     = ONE (constant wire, always 1)
 ///   1..=n_pub   = public inputs (instance)
 ///   n_pub+1..   = private inputs + intermediate (witness)
+mod append;
 mod compact;
 mod error;
 mod linear_combination;
@@ -15,7 +16,7 @@ mod system;
 
 pub use error::ConstraintError;
 pub use linear_combination::{LinearCombination, Variable};
-pub use system::{Constraint, ConstraintSystem};
+pub use system::{Constraint, ConstraintEval, ConstraintSystem};
 
 #[cfg(test)]
 mod tests;
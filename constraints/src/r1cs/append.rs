@@ -0,0 +1,89 @@
+//! Composing constraint systems.
+//!
+//! [`ConstraintSystem::append`] relocates a separately built sub-circuit
+//! into another system's wire space so circuits can be assembled from
+//! independently compiled pieces.
+
+use memory::FieldBackend;
+
+use super::linear_combination::{LinearCombination, Variable};
+use super::system::{Constraint, ConstraintSystem};
+
+impl<F: FieldBackend> ConstraintSystem<F> {
+    /// Append `other`'s wires and constraints to this system.
+    ///
+    /// `shared` lists `(other_var, self_var)` pairs: each `other_var` is
+    /// identified with an existing wire of `self` instead of getting a
+    /// fresh one, which is how the two circuits are wired together. The
+    /// constant wire is always shared (`ONE` maps to `ONE`). Every other
+    /// wire of `other` — including its public inputs — is allocated as a
+    /// witness of `self`, since public inputs must stay contiguous at the
+    /// front of the wire layout. Expose a sub-circuit input publicly by
+    /// sharing it with a public input of `self`.
+    ///
+    /// Returns the relocation map: `map[i]` is the wire of `self` that now
+    /// carries `other`'s wire `i`. A witness for the merged system is this
+    /// system's witness extended with `other_witness[i]` at `map[i]` for
+    /// every newly allocated wire.
+    ///
+    /// Constraints are added through [`ConstraintSystem::enforce`], so
+    /// incremental collapse on `self` applies to them as usual.
+    ///
+    /// # Panics
+    ///
+    /// If a `shared` pair is out of range for its system, if a wire of
+    /// `other` is shared twice, if `ONE` is remapped, or if `other` has
+    /// dropped its rows (count-only mode) or folded rows away through
+    /// incremental collapse.
+    pub fn append(
+        &mut self,
+        other: ConstraintSystem<F>,
+        shared: &[(Variable, Variable)],
+    ) -> Vec<Variable> {
+        assert!(
+            other.constraint_retention_enabled() && !other.incremental_collapse_enabled(),
+            "cannot append a system whose rows were not retained verbatim"
+        );
+
+        let other_nv = other.num_variables();
+        let mut map: Vec<Option<Variable>> = vec![None; other_nv];
+        map[0] = Some(Variable::ONE);
+        for &(theirs, ours) in shared {
+            assert!(
+                theirs.index() < other_nv && ours.index() < self.num_variables(),
+                "shared wire pair ({}, {}) out of range",
+                theirs.index(),
+                ours.index()
+            );
+            assert!(
+                theirs != Variable::ONE || ours == Variable::ONE,
+                "the constant wire can only be shared with ONE"
+            );
+            assert!(
+                theirs == Variable::ONE || map[theirs.index()].is_none(),
+                "wire {} of the appended system is shared twice",
+                theirs.index()
+            );
+            map[theirs.index()] = Some(ours);
+        }
+        let map: Vec<Variable> = map
+            .into_iter()
+            .map(|slot| slot.unwrap_or_else(|| self.alloc_witness()))
+            .collect();
+
+        let relocate = |lc: LinearCombination<F>| -> LinearCombination<F> {
+            LinearCombination {
+                terms: lc
+                    .into_terms()
+                    .into_iter()
+                    .map(|(var, coeff)| (map[var.index()], coeff))
+                    .collect(),
+            }
+        };
+        for Constraint { a, b, c } in other.into_constraints() {
+            self.enforce(relocate(a), relocate(b), relocate(c));
+        }
+
+        map
+    }
+}
@@ -11,6 +11,11 @@ pub enum ConstraintError {
     BadConstantWire,
     /// Constraint at the given index is not satisfied (A * B != C).
     ConstraintUnsatisfied(usize),
+    /// A constraint index past the end of the system.
+    ConstraintIndexOutOfBounds {
+        index: usize,
+        num_constraints: usize,
+    },
 }
 
 impl fmt::Display for ConstraintError {
@@ -30,6 +35,13 @@ impl fmt::Display for ConstraintError {
             ConstraintError::ConstraintUnsatisfied(idx) => {
                 write!(f, "constraint {idx} unsatisfied")
             }
+            ConstraintError::ConstraintIndexOutOfBounds {
+                index,
+                num_constraints,
+            } => write!(
+                f,
+                "constraint index {index} out of bounds ({num_constraints} constraints)"
+            ),
         }
     }
 }
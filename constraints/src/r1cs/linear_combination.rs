@@ -235,6 +235,17 @@ impl<F: FieldBackend> LinearCombination<F> {
         Some(sum)
     }
 
+    /// True if this LC is the constant 0. Cheaper than comparing
+    /// `constant_value()` when the LC is already empty.
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty() || self.constant_value().is_some_and(|c| c.is_zero())
+    }
+
+    /// True if this LC is the constant 1.
+    pub fn is_one(&self) -> bool {
+        self.constant_value() == Some(FieldElement::<F>::one())
+    }
+
     /// If this LC is exactly `1 * var` where `var` is not the constant-one wire,
     /// return that variable. Otherwise return `None`.
     ///
@@ -2,6 +2,10 @@ use memory::{Bn254Fr, FieldBackend, FieldElement};
 
 use super::{ConstraintError, LinearCombination, Variable};
 
+/// The evaluated `(A, B, C)` rows of one constraint; see
+/// [`ConstraintSystem::eval_constraint`].
+pub type ConstraintEval<F = Bn254Fr> = (FieldElement<F>, FieldElement<F>, FieldElement<F>);
+
 /// A single R1CS constraint: A * B = C
 ///
 /// Where A, B, C are linear combinations of variables, and * means "the dot
@@ -287,6 +291,25 @@ impl<F: FieldBackend> ConstraintSystem<F> {
         &self.constraints
     }
 
+    /// Nonzero entries of the `A`, `B` and `C` matrices, counted the way
+    /// the `.r1cs` exporter writes them (duplicate variables merged, zero
+    /// coefficients dropped).
+    ///
+    /// Prover work scales with these, not with [`Self::num_constraints`]:
+    /// two systems with equal row counts can differ widely in density.
+    /// Only retained rows are counted, so count-only mode reports zeros.
+    pub fn num_nonzero_entries(&self) -> (usize, usize, usize) {
+        let nnz = |lc: &LinearCombination<F>| lc.simplify().terms().len();
+        self.constraints.iter().fold((0, 0, 0), |(a, b, c), row| {
+            (a + nnz(&row.a), b + nnz(&row.b), c + nnz(&row.c))
+        })
+    }
+
+    /// Consume the system and return its retained rows.
+    pub(crate) fn into_constraints(self) -> Vec<Constraint<F>> {
+        self.constraints
+    }
+
     /// Run linear constraint elimination on this constraint system.
     ///
     /// Identifies constraints where one side is a constant (i.e., linear
@@ -363,6 +386,41 @@ impl<F: FieldBackend> ConstraintSystem<F> {
         result
     }
 
+    /// Remove exact duplicate constraints, keeping the first occurrence of
+    /// each and the relative order of the survivors. Returns the number of
+    /// constraints removed.
+    ///
+    /// Two constraints are duplicates only when their A, B and C term lists
+    /// hold the same `(variable, coefficient)` pairs; term order within an
+    /// LC is ignored, but nothing else is normalized — repeated variables
+    /// are not merged, and `A * B = C` is not matched against `B * A = C`.
+    /// Dropping an identical row never changes which witnesses satisfy the
+    /// system.
+    ///
+    /// Row indices shift, so per-constraint side tables kept by the caller
+    /// (such as constraint origins) no longer line up afterwards.
+    pub fn dedup_constraints(&mut self) -> usize {
+        assert!(
+            self.retain_constraints,
+            "cannot deduplicate constraints after row retention has been disabled"
+        );
+        let canonical = |lc: &LinearCombination<F>| {
+            let mut terms: Vec<(usize, [u64; 4])> = lc
+                .terms()
+                .iter()
+                .map(|(var, coeff)| (var.index(), coeff.to_canonical()))
+                .collect();
+            terms.sort_unstable();
+            terms
+        };
+        let before = self.constraints.len();
+        let mut seen = std::collections::HashSet::with_capacity(before);
+        self.constraints
+            .retain(|c| seen.insert([canonical(&c.a), canonical(&c.b), canonical(&c.c)]));
+        self.constraint_count = self.constraints.len();
+        before - self.constraints.len()
+    }
+
     /// Verify that a witness satisfies all constraints.
     ///
     /// witness[0] must be ONE (the multiplicative identity).
@@ -383,10 +441,8 @@ impl<F: FieldBackend> ConstraintSystem<F> {
             return Err(ConstraintError::BadConstantWire);
         }
 
-        for (i, constraint) in self.constraints.iter().enumerate() {
-            let a_val = constraint.a.evaluate(witness)?;
-            let b_val = constraint.b.evaluate(witness)?;
-            let c_val = constraint.c.evaluate(witness)?;
+        for i in 0..self.constraints.len() {
+            let (a_val, b_val, c_val) = self.eval_constraint(i, witness)?;
 
             // Check: A * B == C
             let ab = a_val.mul(&b_val);
@@ -398,6 +454,50 @@ impl<F: FieldBackend> ConstraintSystem<F> {
         Ok(())
     }
 
+    /// Evaluate constraint `index` against `witness`, returning the values
+    /// of its `A`, `B` and `C` rows. The constraint holds iff `A·B = C`;
+    /// this is what [`Self::verify`] checks, exposed so tooling can show
+    /// why a gate fails.
+    ///
+    /// Only retained rows can be evaluated: in count-only mode every
+    /// index is out of bounds.
+    ///
+    /// ```
+    /// use constraints::{ConstraintSystem, LinearCombination};
+    /// use memory::FieldElement;
+    ///
+    /// let mut cs = ConstraintSystem::new();
+    /// let a = cs.alloc_witness();
+    /// let b = cs.alloc_witness();
+    /// cs.mul_lc(
+    ///     &LinearCombination::from_variable(a),
+    ///     &LinearCombination::from_variable(b),
+    /// );
+    /// let fe = FieldElement::from_u64;
+    /// let witness = [FieldElement::ONE, fe(6), fe(7), fe(41)];
+    /// let (a, b, c) = cs.eval_constraint(0, &witness).unwrap();
+    /// assert_eq!((a, b, c), (fe(6), fe(7), fe(41)));
+    /// assert_ne!(a.mul(&b), c);
+    /// ```
+    pub fn eval_constraint(
+        &self,
+        index: usize,
+        witness: &[FieldElement<F>],
+    ) -> Result<ConstraintEval<F>, ConstraintError> {
+        let constraint =
+            self.constraints
+                .get(index)
+                .ok_or(ConstraintError::ConstraintIndexOutOfBounds {
+                    index,
+                    num_constraints: self.constraints.len(),
+                })?;
+        Ok((
+            constraint.a.evaluate(witness)?,
+            constraint.b.evaluate(witness)?,
+            constraint.c.evaluate(witness)?,
+        ))
+    }
+
     /// Allocate a witness variable and constrain it to be the product of two LCs.
     /// Returns the new variable (= a * b in the field).
     ///
@@ -88,22 +88,28 @@ fn linear_combination_fast_shape_queries_match_simplified_semantics() {
     assert!(zero.is_constant());
     assert_eq!(zero.constant_value(), Some(FieldElement::zero()));
     assert_eq!(zero.as_single_variable(), None);
+    assert!(zero.is_zero() && !zero.is_one());
 
     let one: LinearCombination = LinearCombination::from_constant(FieldElement::from_u64(7));
     assert!(one.is_constant());
     assert_eq!(one.constant_value(), Some(FieldElement::from_u64(7)));
     assert_eq!(one.as_single_variable(), None);
+    assert!(!one.is_zero() && !one.is_one());
+    let unit: LinearCombination = LinearCombination::from_constant(FieldElement::one());
+    assert!(unit.is_one() && !unit.is_zero());
 
     let x: LinearCombination = LinearCombination::from_variable(Variable(3));
     assert!(!x.is_constant());
     assert_eq!(x.constant_value(), None);
     assert_eq!(x.as_single_variable(), Some(Variable(3)));
+    assert!(!x.is_zero() && !x.is_one());
 
     let cancelled: LinearCombination = LinearCombination::from_variable(Variable(3))
         - LinearCombination::from_variable(Variable(3));
     assert!(cancelled.is_constant());
     assert_eq!(cancelled.constant_value(), Some(FieldElement::zero()));
     assert_eq!(cancelled.as_single_variable(), None);
+    assert!(cancelled.is_zero());
 }
 
 #[test]
@@ -359,3 +365,213 @@ fn test_lc_constant_with_cancellation() {
     assert!(diff.is_constant());
     assert_eq!(diff.constant_value(), Some(FieldElement::from_u64(5)));
 }
+
+#[test]
+fn append_relocates_wires_and_shares_inputs() {
+    let fe = |n: u64| FieldElement::from_u64(n);
+
+    // Outer: c (public) = a * b
+    let mut outer: ConstraintSystem = ConstraintSystem::new();
+    let c = outer.alloc_input();
+    let a = outer.alloc_witness();
+    let b = outer.alloc_witness();
+    outer.enforce(
+        LinearCombination::from_variable(a),
+        LinearCombination::from_variable(b),
+        LinearCombination::from_variable(c),
+    );
+
+    // Sub-circuit: y (public) = x * x + 3, using the constant wire
+    let mut sub: ConstraintSystem = ConstraintSystem::new();
+    let y = sub.alloc_input();
+    let x = sub.alloc_witness();
+    let mut y_minus_3 = LinearCombination::from_variable(y);
+    y_minus_3.add_term(Variable::ONE, fe(3).neg());
+    sub.enforce(
+        LinearCombination::from_variable(x),
+        LinearCombination::from_variable(x),
+        y_minus_3,
+    );
+
+    // Feed the outer product into the sub-circuit's x.
+    let map = outer.append(sub, &[(x, c)]);
+
+    assert_eq!(map, vec![Variable::ONE, Variable(4), c]);
+    assert_eq!(outer.num_pub_inputs(), 1);
+    assert_eq!(outer.num_variables(), 5);
+    assert_eq!(outer.num_constraints(), 2);
+
+    // a=6, b=7 → c=42 → y = 42² + 3 = 1767
+    let witness = vec![fe(1), fe(42), fe(6), fe(7), fe(1767)];
+    assert!(outer.verify(&witness).is_ok());
+
+    let mut bad = witness.clone();
+    bad[4] = fe(1766);
+    assert_eq!(
+        outer.verify(&bad),
+        Err(ConstraintError::ConstraintUnsatisfied(1))
+    );
+}
+
+#[test]
+#[should_panic(expected = "shared twice")]
+fn append_rejects_duplicate_shared_wire() {
+    let mut outer: ConstraintSystem = ConstraintSystem::new();
+    let p = outer.alloc_witness();
+    let q = outer.alloc_witness();
+    let mut sub: ConstraintSystem = ConstraintSystem::new();
+    let x = sub.alloc_witness();
+    outer.append(sub, &[(x, p), (x, q)]);
+}
+
+#[test]
+fn dedup_constraints_drops_repeated_boolean_enforcement() {
+    let mut cs = ConstraintSystem::new();
+    let out = cs.alloc_input();
+    let x = cs.alloc_witness();
+    let y = cs.alloc_witness();
+
+    // x * (1 - x) = 0, emitted once per gadget that needs `x` boolean.
+    let enforce_bool = |cs: &mut ConstraintSystem| {
+        cs.enforce(
+            LinearCombination::from_variable(x),
+            LinearCombination::from_variable(Variable::ONE) - LinearCombination::from_variable(x),
+            LinearCombination::zero(),
+        );
+    };
+    enforce_bool(&mut cs);
+    cs.enforce(
+        LinearCombination::from_variable(x),
+        LinearCombination::from_variable(y),
+        LinearCombination::from_variable(out),
+    );
+    enforce_bool(&mut cs);
+    // Same LC terms in a different order still count as identical.
+    let mut one_minus_x = LinearCombination::zero();
+    one_minus_x.add_term(x, FieldElement::ONE.neg());
+    one_minus_x.add_term(Variable::ONE, FieldElement::ONE);
+    cs.enforce(
+        LinearCombination::from_variable(x),
+        one_minus_x,
+        LinearCombination::zero(),
+    );
+    // Operands swapped is a different row and is kept.
+    cs.enforce(
+        LinearCombination::from_variable(y),
+        LinearCombination::from_variable(x),
+        LinearCombination::from_variable(out),
+    );
+    assert_eq!(cs.num_constraints(), 5);
+
+    let witness = |x: u64, y: u64| {
+        vec![
+            FieldElement::ONE,
+            FieldElement::from_u64(x * y),
+            FieldElement::from_u64(x),
+            FieldElement::from_u64(y),
+        ]
+    };
+    assert!(cs.verify(&witness(1, 9)).is_ok());
+    assert!(cs.verify(&witness(2, 9)).is_err());
+
+    assert_eq!(cs.dedup_constraints(), 2);
+    assert_eq!(cs.num_constraints(), 3);
+    assert_eq!(cs.constraints().len(), 3);
+    assert!(cs.verify(&witness(1, 9)).is_ok());
+    assert!(cs.verify(&witness(0, 9)).is_ok());
+    assert!(cs.verify(&witness(2, 9)).is_err());
+
+    // Already deduplicated: a second pass is a no-op.
+    assert_eq!(cs.dedup_constraints(), 0);
+}
+
+#[test]
+fn num_nonzero_entries_merges_duplicates_and_drops_zeros() {
+    let mut cs: ConstraintSystem = ConstraintSystem::new();
+    let x = cs.alloc_witness();
+    let y = cs.alloc_witness();
+
+    // (x + x + 0*y) * (y) = (x + y + 3)
+    let mut a = LinearCombination::from_variable(x);
+    a.add_term(x, FieldElement::ONE);
+    a.add_term(y, FieldElement::ZERO);
+    let mut c = LinearCombination::from_variable(x);
+    c.add_term(y, FieldElement::ONE);
+    c.add_term(Variable::ONE, FieldElement::from_u64(3));
+    cs.enforce(a, LinearCombination::from_variable(y), c);
+
+    assert_eq!(cs.num_nonzero_entries(), (1, 1, 3));
+}
+
+#[test]
+fn num_nonzero_entries_of_poseidon_hash() {
+    let params = crate::poseidon::PoseidonParams::bn254_t3();
+    let mut cs: ConstraintSystem = ConstraintSystem::new();
+    let left = cs.alloc_witness();
+    let right = cs.alloc_witness();
+    crate::poseidon::poseidon_hash_circuit(&mut cs, &params, left, right);
+
+    let (a, b, c) = cs.num_nonzero_entries();
+    let rows = cs.num_constraints();
+    // S-box inputs are MDS-mixed state LCs, so A and B carry several
+    // terms per row, while C is (at most) the single output wire.
+    assert!(a >= rows && b >= rows, "A={a} B={b} rows={rows}");
+    assert!(a > b, "MDS-mixed A should be denser than B: A={a} B={b}");
+    assert!(
+        c <= rows,
+        "each C side is at most one wire: C={c} rows={rows}"
+    );
+    assert!(
+        a + b + c < 3 * params.t * rows,
+        "Poseidon R1CS should stay sparse"
+    );
+}
+
+#[test]
+fn eval_constraint_matches_hand_computation() {
+    // (2a + 3) * (b - 1) = c, with a = 5, b = 4, c = 39:
+    // A = 13, B = 3, C = 39, and 13 * 3 = 39.
+    let mut cs = ConstraintSystem::new();
+    let a = cs.alloc_witness();
+    let b = cs.alloc_witness();
+    let c = cs.alloc_witness();
+    let fe = FieldElement::from_u64;
+    let lhs = LinearCombination::from_variable(a) * fe(2) + LinearCombination::from_constant(fe(3));
+    let rhs = LinearCombination::from_variable(b) - LinearCombination::from_constant(fe(1));
+    cs.enforce(lhs, rhs, LinearCombination::from_variable(c));
+
+    let mut witness = vec![FieldElement::ONE, fe(5), fe(4), fe(39)];
+    assert_eq!(cs.eval_constraint(0, &witness), Ok((fe(13), fe(3), fe(39))));
+    assert!(cs.verify(&witness).is_ok());
+
+    // A wrong output keeps A and B and shows the mismatch in C.
+    witness[3] = fe(40);
+    assert_eq!(cs.eval_constraint(0, &witness), Ok((fe(13), fe(3), fe(40))));
+    assert_eq!(
+        cs.verify(&witness),
+        Err(ConstraintError::ConstraintUnsatisfied(0))
+    );
+}
+
+#[test]
+fn eval_constraint_checks_bounds() {
+    let mut cs = ConstraintSystem::new();
+    let x = cs.alloc_witness();
+    cs.enforce_equal(
+        LinearCombination::from_variable(x),
+        LinearCombination::from_constant(FieldElement::from_u64(7)),
+    );
+    let witness = [FieldElement::ONE, FieldElement::from_u64(7)];
+    assert_eq!(
+        cs.eval_constraint(1, &witness),
+        Err(ConstraintError::ConstraintIndexOutOfBounds {
+            index: 1,
+            num_constraints: 1,
+        })
+    );
+    // A short witness surfaces as an out-of-range variable, not a panic.
+    assert!(matches!(
+        cs.eval_constraint(0, &witness[..1]),
+        Err(ConstraintError::VariableOutOfBounds { variable: 1, .. })
+    ));
+}
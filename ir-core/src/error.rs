@@ -2,6 +2,8 @@ use std::fmt;
 
 use diagnostics::{Diagnostic, SpanRange};
 
+use crate::types::SsaVar;
+
 /// Boxed span to keep error enum small.
 pub type OptSpan = Option<Box<SpanRange>>;
 
@@ -45,6 +47,8 @@ pub enum IrError {
         got: usize,
         span: OptSpan,
     },
+    /// A division whose divisor is the constant zero.
+    DivisionByZero { span: OptSpan },
     /// A function calls itself (directly or mutually).
     RecursiveFunction(String),
     /// A type mismatch (e.g. scalar where array expected, or vice versa).
@@ -80,6 +84,14 @@ pub enum IrError {
     CircularImport(String),
     /// An error occurred while loading a module.
     ModuleLoadError(String),
+    /// The IR broke the SSA invariant (a var defined twice, or used
+    /// without a prior definition). Always a compiler bug; `pass` names
+    /// the optimization pass that produced it, when known.
+    MalformedIr {
+        pass: Option<String>,
+        var: SsaVar,
+        reason: String,
+    },
 }
 
 fn fmt_span(span: &OptSpan) -> String {
@@ -121,7 +133,10 @@ impl fmt::Display for IrError {
                 write!(f, "{}unbounded loops (while/forever) are not allowed in circuits (all iterations must be known at compile time for constraint generation)", fmt_span(span))
             }
             IrError::ParseError(diag) => {
-                write!(f, "parse error: {}", diag.message)
+                // `parse_error` builds location-less diagnostics at line 0.
+                let span =
+                    (diag.primary_span.line_start > 0).then(|| Box::new(diag.primary_span.clone()));
+                write!(f, "{}parse error: {}", fmt_span(&span), diag.message)
             }
             IrError::DuplicateInput(name) => {
                 write!(f, "duplicate input declaration: `{name}`")
@@ -161,6 +176,13 @@ impl fmt::Display for IrError {
                     fmt_span(span)
                 )
             }
+            IrError::DivisionByZero { span } => {
+                write!(
+                    f,
+                    "{}division by zero: the divisor is the constant 0",
+                    fmt_span(span)
+                )
+            }
             IrError::RecursiveFunction(name) => {
                 write!(f, "recursive function `{name}` is not allowed in circuits")
             }
@@ -196,6 +218,10 @@ impl fmt::Display for IrError {
             IrError::ModuleLoadError(msg) => {
                 write!(f, "module load error: {msg}")
             }
+            IrError::MalformedIr { pass, var, reason } => match pass {
+                Some(pass) => write!(f, "malformed IR after `{pass}`: {var} {reason}"),
+                None => write!(f, "malformed IR: {var} {reason}"),
+            },
         }
     }
 }
@@ -224,6 +250,7 @@ impl IrError {
             IrError::WrongArgumentCount { span, .. }
             | IrError::IndexOutOfBounds { span, .. }
             | IrError::ArrayLengthMismatch { span, .. }
+            | IrError::DivisionByZero { span }
             | IrError::TypeMismatch { span, .. }
             | IrError::AnnotationMismatch { span, .. } => span.as_deref().cloned(),
             _ => None,
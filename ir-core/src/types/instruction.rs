@@ -138,6 +138,24 @@ pub enum Instruction<F: FieldBackend = Bn254Fr> {
         lhs: SsaVar,
         rhs: SsaVar,
     },
+    /// Greater-than check: result = 1 if lhs > rhs, 0 otherwise.
+    ///
+    /// Same cost and signed-range contract as `IsLt` with the operands
+    /// swapped, but kept as its own instruction so `lhs` / `rhs` stay in
+    /// source order for range-bound lookups and diagnostics.
+    IsGt {
+        result: SsaVar,
+        lhs: SsaVar,
+        rhs: SsaVar,
+    },
+    /// Greater-or-equal check: result = 1 if lhs >= rhs, 0 otherwise.
+    ///
+    /// Source-order counterpart of `IsLe` with swapped operands; see `IsGt`.
+    IsGe {
+        result: SsaVar,
+        lhs: SsaVar,
+        rhs: SsaVar,
+    },
     /// Bounded less-than: result = 1 if lhs < rhs, 0 otherwise.
     /// Both operands proven to fit in `bitwidth` bits via prior RangeCheck.
     /// Uses n+1 bit decomposition (~n+3 constraints). Emitted by bound_inference pass.
@@ -235,6 +253,8 @@ impl<F: FieldBackend> Instruction<F> {
             | Instruction::IsNeq { result, .. }
             | Instruction::IsLt { result, .. }
             | Instruction::IsLe { result, .. }
+            | Instruction::IsGt { result, .. }
+            | Instruction::IsGe { result, .. }
             | Instruction::IsLtBounded { result, .. }
             | Instruction::IsLeBounded { result, .. }
             | Instruction::Assert { result, .. }
@@ -249,6 +269,39 @@ impl<F: FieldBackend> Instruction<F> {
         }
     }
 
+    /// The variant name, as printed by `Display` (e.g. `"PoseidonHash"`).
+    pub fn op_name(&self) -> &'static str {
+        match self {
+            Instruction::Const { .. } => "Const",
+            Instruction::Input { .. } => "Input",
+            Instruction::Add { .. } => "Add",
+            Instruction::Sub { .. } => "Sub",
+            Instruction::Mul { .. } => "Mul",
+            Instruction::Div { .. } => "Div",
+            Instruction::Neg { .. } => "Neg",
+            Instruction::Mux { .. } => "Mux",
+            Instruction::AssertEq { .. } => "AssertEq",
+            Instruction::PoseidonHash { .. } => "PoseidonHash",
+            Instruction::RangeCheck { .. } => "RangeCheck",
+            Instruction::Not { .. } => "Not",
+            Instruction::And { .. } => "And",
+            Instruction::Or { .. } => "Or",
+            Instruction::IsEq { .. } => "IsEq",
+            Instruction::IsNeq { .. } => "IsNeq",
+            Instruction::IsLt { .. } => "IsLt",
+            Instruction::IsLe { .. } => "IsLe",
+            Instruction::IsGt { .. } => "IsGt",
+            Instruction::IsGe { .. } => "IsGe",
+            Instruction::IsLtBounded { .. } => "IsLtBounded",
+            Instruction::IsLeBounded { .. } => "IsLeBounded",
+            Instruction::Assert { .. } => "Assert",
+            Instruction::Decompose { .. } => "Decompose",
+            Instruction::IntDiv { .. } => "IntDiv",
+            Instruction::IntMod { .. } => "IntMod",
+            Instruction::WitnessCall(_) => "WitnessCall",
+        }
+    }
+
     /// Returns additional result variables beyond the primary `result`.
     /// `Decompose` produces the bit variables; `WitnessCall` produces
     /// the secondary output slots (for array-return lifts).
@@ -290,6 +343,8 @@ impl<F: FieldBackend> Instruction<F> {
             | Instruction::IsNeq { lhs, rhs, .. }
             | Instruction::IsLt { lhs, rhs, .. }
             | Instruction::IsLe { lhs, rhs, .. }
+            | Instruction::IsGt { lhs, rhs, .. }
+            | Instruction::IsGe { lhs, rhs, .. }
             | Instruction::IsLtBounded { lhs, rhs, .. }
             | Instruction::IsLeBounded { lhs, rhs, .. } => vec![*lhs, *rhs],
             Instruction::Mux {
@@ -377,6 +432,12 @@ impl<F: FieldBackend> std::fmt::Display for Instruction<F> {
             Instruction::IsLe { result, lhs, rhs } => {
                 write!(f, "{result} = IsLe({lhs}, {rhs})")
             }
+            Instruction::IsGt { result, lhs, rhs } => {
+                write!(f, "{result} = IsGt({lhs}, {rhs})")
+            }
+            Instruction::IsGe { result, lhs, rhs } => {
+                write!(f, "{result} = IsGe({lhs}, {rhs})")
+            }
             Instruction::IsLtBounded {
                 result,
                 lhs,
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use diagnostics::SpanRange;
-use memory::{Bn254Fr, FieldBackend};
+use memory::{Bn254Fr, FieldBackend, FieldElement};
 
 use super::{Instruction, SsaVar};
 
@@ -66,6 +66,16 @@ pub struct IrProgram<F: FieldBackend = Bn254Fr> {
     pub var_types: HashMap<SsaVar, IrType>,
     pub input_spans: HashMap<String, SpanRange>,
     pub var_spans: HashMap<SsaVar, SpanRange>,
+    /// Values for inputs declared with a default (`witness x = 5`), used
+    /// when the caller supplies none. Witness-side only: no constraints.
+    pub input_defaults: HashMap<String, FieldElement<F>>,
+    /// Declared sizes of array inputs (`witness path[3]`), keyed by array
+    /// name. Each element is a separate `Input` named `{name}_{i}`.
+    pub input_arrays: HashMap<String, usize>,
+    /// `let` bindings that re-bound a name already `let`-bound in the same
+    /// block, in source order. Recorded by lowering for the taint analysis;
+    /// no effect on the constraints.
+    pub shadowed_lets: Vec<(String, SpanRange)>,
 }
 
 impl<F: FieldBackend> Default for IrProgram<F> {
@@ -83,6 +93,9 @@ impl<F: FieldBackend> IrProgram<F> {
             var_types: HashMap::new(),
             input_spans: HashMap::new(),
             var_spans: HashMap::new(),
+            input_defaults: HashMap::new(),
+            input_arrays: HashMap::new(),
+            shadowed_lets: Vec::new(),
         }
     }
 
@@ -130,6 +143,26 @@ impl<F: FieldBackend> IrProgram<F> {
         self.var_spans.get(&var)
     }
 
+    /// Record the default value for input `name`.
+    pub fn set_input_default(&mut self, name: String, value: FieldElement<F>) {
+        self.input_defaults.insert(name, value);
+    }
+
+    /// Record that input array `name` was declared with `size` elements.
+    pub fn set_input_array(&mut self, name: String, size: usize) {
+        self.input_arrays.insert(name, size);
+    }
+
+    /// The value for input `name`: the one in `inputs` if supplied, else
+    /// its declared default.
+    pub fn input_value<'a>(
+        &'a self,
+        inputs: &'a HashMap<String, FieldElement<F>>,
+        name: &str,
+    ) -> Option<&'a FieldElement<F>> {
+        inputs.get(name).or_else(|| self.input_defaults.get(name))
+    }
+
     /// Borrow the instruction stream as a read-only slice.
     pub fn instructions(&self) -> &[Instruction<F>] {
         &self.instructions
@@ -21,6 +21,8 @@ impl<F: FieldBackend> Instruction<F> {
             | Instruction::IsNeq { lhs, rhs, .. }
             | Instruction::IsLt { lhs, rhs, .. }
             | Instruction::IsLe { lhs, rhs, .. }
+            | Instruction::IsGt { lhs, rhs, .. }
+            | Instruction::IsGe { lhs, rhs, .. }
             | Instruction::IsLtBounded { lhs, rhs, .. }
             | Instruction::IsLeBounded { lhs, rhs, .. }
             | Instruction::AssertEq { lhs, rhs, .. }
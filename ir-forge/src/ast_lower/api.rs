@@ -1,6 +1,6 @@
 //! Public entry points on [`ProveIrCompiler`].
 //!
-//! Seven methods that drive a full compilation pass:
+//! Eight methods that drive a full compilation pass:
 //!
 //! - [`compile`] / [`compile_with_trace`] — thin wrappers that delegate
 //!   to [`compile_with_source_dir`].
@@ -11,7 +11,8 @@
 //!   pre-loading outer scope, fns, and (optionally) resolver state.
 //! - [`compile_circuit`] — circuit-mode entry (file-based, supports
 //!   imports + `circuit { … }` blocks). Runs the resolver-state path
-//!   when the source parses cleanly.
+//!   when the source parses cleanly. [`compile_circuit_with_warnings`]
+//!   also returns the walk's non-fatal diagnostics.
 //! - [`try_build_circuit_resolver_state`] — helper that builds the
 //!   (state, dispatch_by_symbol, module_by_key) bundle from a parsed
 //!   source + source dir. Returns `None` on any build error so the
@@ -25,12 +26,13 @@
 use std::path::Path;
 
 use achronyme_parser::ast::{Block, Program, Stmt};
+use diagnostics::Diagnostic;
 use memory::FieldBackend;
 
 use super::helpers::{program_to_block, to_span};
 use super::{
     CircuitResolverBundle, CompEnvValue, FnDef, OuterResolverState, OuterScope, OuterScopeEntry,
-    ProveIrCompiler,
+    ProveIrCompiler, MAX_LOOP_ITERATIONS,
 };
 use crate::error::ProveIrError;
 use crate::types::{CaptureArrayDef, ProveIR};
@@ -58,7 +60,8 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         block: &Block,
         outer_scope: &OuterScope,
     ) -> Result<(ProveIR, Vec<(AnnotationKey, SymbolId)>), ProveIrError> {
-        let (prove_ir, compiler) = Self::compile_into_instance(block, outer_scope, None, None)?;
+        let (prove_ir, compiler) =
+            Self::compile_into_instance(block, outer_scope, None, None, MAX_LOOP_ITERATIONS)?;
         Ok((prove_ir, compiler.resolver_hits))
     }
 
@@ -68,7 +71,14 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         source_dir: Option<std::path::PathBuf>,
         source_path: Option<std::path::PathBuf>,
     ) -> Result<ProveIR, ProveIrError> {
-        Self::compile_into_instance(block, outer_scope, source_dir, source_path).map(|(ir, _)| ir)
+        Self::compile_into_instance(
+            block,
+            outer_scope,
+            source_dir,
+            source_path,
+            MAX_LOOP_ITERATIONS,
+        )
+        .map(|(ir, _)| ir)
     }
 
     /// Worker shared by [`compile_with_source_dir`] and
@@ -80,9 +90,11 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         outer_scope: &OuterScope,
         source_dir: Option<std::path::PathBuf>,
         source_path: Option<std::path::PathBuf>,
+        max_loop_iterations: u64,
     ) -> Result<(ProveIR, Self), ProveIrError> {
         let mut compiler = Self::new();
         compiler.source_dir = source_dir;
+        compiler.max_loop_iterations = max_loop_iterations;
         if let Some(path) = source_path {
             compiler.compiling_modules.insert(path);
         }
@@ -193,6 +205,7 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
             name: None,
             public_inputs: std::mem::take(&mut compiler.public_inputs),
             witness_inputs: std::mem::take(&mut compiler.witness_inputs),
+            outputs: std::mem::take(&mut compiler.outputs),
             captures,
             body: std::mem::take(&mut compiler.body),
             capture_arrays,
@@ -200,6 +213,7 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
             // template instantiation; referenced by `ComponentCall`
             // nodes in `body`.
             component_bodies: std::mem::take(&mut compiler.component_bodies),
+            shadowed_lets: std::mem::take(&mut compiler.shadowed_lets),
         };
         Ok((prove_ir, compiler))
     }
@@ -224,6 +238,51 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         source: &str,
         source_path: Option<&Path>,
     ) -> Result<ProveIR, ProveIrError> {
+        Self::compile_circuit_with_warnings(source, source_path).map(|(ir, _)| ir)
+    }
+
+    /// Like [`compile_circuit`] but also returns the non-fatal
+    /// diagnostics collected during the walk (currently: code left
+    /// unreachable by an early `return` in an inlined function).
+    pub fn compile_circuit_with_warnings(
+        source: &str,
+        source_path: Option<&Path>,
+    ) -> Result<(ProveIR, Vec<Diagnostic>), ProveIrError> {
+        Self::compile_circuit_entry_with_warnings(source, source_path, None)
+    }
+
+    /// Like [`compile_circuit`], but with `entry` set the circuit is the
+    /// `circuit` declaration or `export fn` of that name, so one file can
+    /// hold several circuits. The entry's parameters become the circuit
+    /// inputs and need `Public` / `Witness` annotations, exactly like
+    /// `circuit` parameters.
+    pub fn compile_circuit_entry(
+        source: &str,
+        source_path: Option<&Path>,
+        entry: Option<&str>,
+    ) -> Result<ProveIR, ProveIrError> {
+        Self::compile_circuit_entry_with_warnings(source, source_path, entry).map(|(ir, _)| ir)
+    }
+
+    /// [`compile_circuit_entry`] plus the non-fatal diagnostics, as in
+    /// [`compile_circuit_with_warnings`].
+    pub fn compile_circuit_entry_with_warnings(
+        source: &str,
+        source_path: Option<&Path>,
+        entry: Option<&str>,
+    ) -> Result<(ProveIR, Vec<Diagnostic>), ProveIrError> {
+        Self::compile_circuit_entry_with_max_unroll(source, source_path, entry, MAX_LOOP_ITERATIONS)
+    }
+
+    /// [`compile_circuit_entry_with_warnings`] with a custom cap on
+    /// literal-range `for` loop iterations instead of
+    /// [`MAX_LOOP_ITERATIONS`].
+    pub fn compile_circuit_entry_with_max_unroll(
+        source: &str,
+        source_path: Option<&Path>,
+        entry: Option<&str>,
+        max_unroll: u64,
+    ) -> Result<(ProveIR, Vec<Diagnostic>), ProveIrError> {
         use achronyme_parser::ast::{InputDecl, Stmt, Visibility};
 
         let (program, errors) = achronyme_parser::parse_program(source);
@@ -253,14 +312,31 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         let mut outer_functions: Vec<Stmt> = Vec::new();
         let mut circuit_decl = None;
 
+        // Without an `entry`, the file's single `circuit` declaration is
+        // the circuit; with one, the `circuit` or `export fn` of that name.
+        let is_entry = |stmt: &Stmt| match (entry, stmt) {
+            (None, Stmt::CircuitDecl { .. }) => true,
+            (Some(entry), Stmt::CircuitDecl { name, .. }) => name == entry,
+            (Some(entry), Stmt::Export { inner, .. }) => {
+                matches!(inner.as_ref(), Stmt::FnDecl { name, .. } if name == entry)
+            }
+            _ => false,
+        };
+
         for stmt in &program.stmts {
             match stmt {
-                Stmt::CircuitDecl { .. } if circuit_decl.is_none() => {
+                _ if is_entry(stmt) && circuit_decl.is_none() => {
                     circuit_decl = Some(stmt);
                 }
-                Stmt::CircuitDecl { span, .. } => {
+                Stmt::CircuitDecl { span, .. } | Stmt::Export { span, .. } if is_entry(stmt) => {
+                    let description = match entry {
+                        Some(entry) => {
+                            format!("circuit entry `{entry}` is declared more than once")
+                        }
+                        None => "only one circuit declaration is allowed per file".into(),
+                    };
                     return Err(ProveIrError::UnsupportedOperation {
-                        description: "only one circuit declaration is allowed per file".into(),
+                        description,
                         span: to_span(span),
                     });
                 }
@@ -281,14 +357,27 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
             }
         }
 
-        if let Some(Stmt::CircuitDecl {
-            params,
-            body,
-            name,
-            span,
-            ..
-        }) = circuit_decl
-        {
+        let selected = match circuit_decl {
+            Some(Stmt::CircuitDecl {
+                params,
+                body,
+                name,
+                span,
+            }) => Some((params, body, name, span)),
+            Some(Stmt::Export { inner, .. }) => match inner.as_ref() {
+                Stmt::FnDecl {
+                    params,
+                    body,
+                    name,
+                    span,
+                    ..
+                } => Some((params, body, name, span)),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if let Some((params, body, name, span)) = selected {
             // Synthesize public/witness declarations from typed params
             let mut stmts = Vec::new();
             for param in params {
@@ -318,6 +407,7 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
                     name: param.name.clone(),
                     array_size: ta.array_size,
                     type_ann: Some(ta.clone()),
+                    default: None,
                 };
                 match vis {
                     Visibility::Public => stmts.push(Stmt::PublicDecl {
@@ -370,14 +460,22 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
                 resolver_state: resolver_state_for_scope,
                 ..Default::default()
             };
-            let mut prove_ir = Self::compile_with_source_dir(
+            let (mut prove_ir, compiler) = Self::compile_into_instance(
                 &circuit_block,
                 &outer_scope,
                 source_dir,
                 canonical_source,
+                max_unroll,
             )?;
             prove_ir.name = Some(name.clone());
-            return Ok(prove_ir);
+            return Ok((prove_ir, compiler.warnings));
+        }
+
+        if let Some(entry) = entry {
+            return Err(ProveIrError::UnsupportedOperation {
+                description: format!("no circuit or `export fn` named `{entry}` in this file"),
+                span: None,
+            });
         }
 
         // Flat format is no longer supported — require circuit declaration
@@ -17,14 +17,25 @@
 //! Per-builtin lowerings: `lower_poseidon`, `lower_poseidon_many`,
 //! `lower_mux`, `lower_range_check`, `lower_merkle_verify`,
 //! `lower_len`, `lower_assert_eq`, `lower_assert`, `lower_int_div`,
-//! `lower_int_mod`.
+//! `lower_int_mod`, `lower_merkle_verify_arity`, `lower_fold`,
+//! `lower_poseidon_domain`, `lower_poseidon2`, `lower_inv`, `lower_neg`,
+//! `lower_merkle_root`, `lower_assert_all_eq`, `lower_assert_in_set`,
+//! `lower_popcount`, `lower_is_power_of_two`, `lower_bool_and`,
+//! `lower_bool_or`, `lower_bool_xor`, `lower_bool_not`, `lower_assert_bool`,
+//! `lower_dot`, `lower_map_scalar`, `lower_bits_scalar`.
+//!
+//! `map` and `bits` return arrays, so `compile_let` binds
+//! `let ys = map(xs, op)` and `let bs = bits(x, n)` through `lower_map` and
+//! `lower_bits` directly; their table slots, `lower_map_scalar` and
+//! `lower_bits_scalar`, only reject uses outside a `let`.
 
 use achronyme_parser::ast::*;
 use diagnostics::SpanRange;
-use memory::FieldBackend;
+use memory::{FieldBackend, FieldElement};
 
-use super::super::helpers::to_span;
-use super::super::ProveIrCompiler;
+use super::super::helpers::{fold_const, to_span};
+use super::super::{CompEnvValue, ProveIrCompiler};
+use super::poseidon_expand::{Poseidon2Consts, PoseidonConsts};
 use crate::error::ProveIrError;
 use crate::types::*;
 
@@ -79,18 +90,37 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         type LowerFn<F> =
             fn(&mut ProveIrCompiler<F>, &[&Expr], &Span) -> Result<CircuitExpr, ProveIrError>;
 
-        const LOWERING_COUNT: usize = 10;
+        const LOWERING_COUNT: usize = 29;
         let lowerings: [LowerFn<F>; LOWERING_COUNT] = [
-            Self::lower_poseidon,      // 0
-            Self::lower_poseidon_many, // 1
-            Self::lower_mux,           // 2
-            Self::lower_range_check,   // 3
-            Self::lower_merkle_verify, // 4
-            Self::lower_len,           // 5
-            Self::lower_assert_eq,     // 6
-            Self::lower_assert,        // 7
-            Self::lower_int_div,       // 8
-            Self::lower_int_mod,       // 9
+            Self::lower_poseidon,            // 0
+            Self::lower_poseidon_many,       // 1
+            Self::lower_mux,                 // 2
+            Self::lower_range_check,         // 3
+            Self::lower_merkle_verify,       // 4
+            Self::lower_len,                 // 5
+            Self::lower_assert_eq,           // 6
+            Self::lower_assert,              // 7
+            Self::lower_int_div,             // 8
+            Self::lower_int_mod,             // 9
+            Self::lower_merkle_verify_arity, // 10
+            Self::lower_fold,                // 11
+            Self::lower_poseidon_domain,     // 12
+            Self::lower_poseidon2,           // 13
+            Self::lower_inv,                 // 14
+            Self::lower_neg,                 // 15
+            Self::lower_merkle_root,         // 16
+            Self::lower_assert_all_eq,       // 17
+            Self::lower_assert_in_set,       // 18
+            Self::lower_popcount,            // 19
+            Self::lower_is_power_of_two,     // 20
+            Self::lower_bool_and,            // 21
+            Self::lower_bool_or,             // 22
+            Self::lower_bool_xor,            // 23
+            Self::lower_bool_not,            // 24
+            Self::lower_assert_bool,         // 25
+            Self::lower_dot,                 // 26
+            Self::lower_map_scalar,          // 27
+            Self::lower_bits_scalar,         // 28
         ];
 
         let idx = handle.as_u32() as usize;
@@ -118,6 +148,73 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         })
     }
 
+    /// `poseidon_domain(tag, a, b)` — 2-to-1 Poseidon whose capacity
+    /// element starts at the constant `tag` instead of zero, so hashes
+    /// built for different purposes (Merkle nodes, commitments, …) never
+    /// collide. `poseidon_domain(0, a, b)` equals `poseidon(a, b)`.
+    /// `PoseidonHash` hard-wires a zero capacity, so the permutation is
+    /// expanded inline from the tagged parameters.
+    pub(super) fn lower_poseidon_domain(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        self.check_arity("poseidon_domain", 3, args.len(), span)?;
+        let tag = fold_const::<F>(&self.compile_expr(args[0])?).ok_or_else(|| {
+            ProveIrError::UnsupportedOperation {
+                description: "`poseidon_domain` tag must be a compile-time constant".into(),
+                span: to_span(span),
+            }
+        })?;
+        let consts =
+            PoseidonConsts::default_t3::<F>(FieldConst::from_field(tag)).ok_or_else(|| {
+                ProveIrError::UnsupportedOperation {
+                    description: format!(
+                        "`poseidon_domain` has no Poseidon parameters for prime `{}`",
+                        F::PRIME_ID.name()
+                    ),
+                    span: to_span(span),
+                }
+            })?;
+        let left = self.compile_expr(args[1])?;
+        let right = self.compile_expr(args[2])?;
+        let capacity = CircuitExpr::Const(consts.capacity);
+        let state = self.expand_poseidon_permutation(&consts, vec![capacity, left, right], span);
+        Ok(state
+            .into_iter()
+            .next()
+            .expect("Poseidon state is non-empty"))
+    }
+
+    /// `poseidon2(a, b)` — 2-to-1 Poseidon2 hash (state `[0, a, b]`,
+    /// output `state[0]`). Its sparse linear layers need no MDS
+    /// materialization, so it costs only the S-box multiplications.
+    /// BN254 only.
+    pub(super) fn lower_poseidon2(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        self.check_arity("poseidon2", 2, args.len(), span)?;
+        let consts = Poseidon2Consts::default_t3::<F>().ok_or_else(|| {
+            ProveIrError::UnsupportedOperation {
+                description: format!(
+                    "`poseidon2` has no Poseidon2 parameters for prime `{}`",
+                    F::PRIME_ID.name()
+                ),
+                span: to_span(span),
+            }
+        })?;
+        let left = self.compile_expr(args[0])?;
+        let right = self.compile_expr(args[1])?;
+        let capacity = CircuitExpr::Const(consts.capacity);
+        let state = self.expand_poseidon2_permutation(&consts, vec![capacity, left, right], span);
+        Ok(state
+            .into_iter()
+            .next()
+            .expect("Poseidon2 state is non-empty"))
+    }
+
     pub(super) fn lower_poseidon_many(
         &mut self,
         args: &[&Expr],
@@ -194,6 +291,356 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         })
     }
 
+    pub(super) fn lower_merkle_verify_arity(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        self.check_arity("merkle_verify_arity", 5, args.len(), span)?;
+        let root = self.compile_expr(args[0])?;
+        let leaf = self.compile_expr(args[1])?;
+        let path = self.extract_array_ident(args[2], span)?;
+        let indices = self.extract_array_ident(args[3], span)?;
+        let arity = self.extract_const_u64(args[4], span)?;
+        if !(2..=u32::MAX as u64).contains(&arity) {
+            return Err(ProveIrError::UnsupportedOperation {
+                description: format!("merkle_verify_arity arity must be at least 2, got {arity}"),
+                span: to_span(span),
+            });
+        }
+        Ok(CircuitExpr::MerkleVerifyArity {
+            root: Box::new(root),
+            leaf: Box::new(leaf),
+            path,
+            indices,
+            arity: arity as u32,
+        })
+    }
+
+    /// `fold(arr, init, op)` — thread an accumulator through the elements
+    /// of `arr`. Closures don't exist in circuits, so `op` is a string
+    /// literal naming the step: `"add"` (`acc + x`), `"mul"` (`acc * x`)
+    /// or `"poseidon"` (`poseidon(acc, x)`). The array length is static,
+    /// so the fold unrolls here into plain expressions.
+    pub(super) fn lower_fold(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        self.check_arity("fold", 3, args.len(), span)?;
+
+        enum FoldOp {
+            Add,
+            Mul,
+            Poseidon,
+        }
+        let op = match args[2] {
+            Expr::StringLit { value, .. } => match value.as_str() {
+                "add" => FoldOp::Add,
+                "mul" => FoldOp::Mul,
+                "poseidon" => FoldOp::Poseidon,
+                other => {
+                    return Err(ProveIrError::UnsupportedOperation {
+                        description: format!(
+                            "unknown fold op \"{other}\" (expected \"add\", \"mul\", or \"poseidon\")"
+                        ),
+                        span: to_span(span),
+                    });
+                }
+            },
+            _ => {
+                return Err(ProveIrError::UnsupportedOperation {
+                    description:
+                        "fold op must be a string literal: \"add\", \"mul\", or \"poseidon\"".into(),
+                    span: to_span(span),
+                });
+            }
+        };
+
+        let elems = self.array_arg_elems("fold", args[0], span)?;
+
+        let mut acc = self.compile_expr(args[1])?;
+        for elem in &elems {
+            let x = CircuitExpr::Var(elem.clone());
+            acc = match op {
+                FoldOp::Add => CircuitExpr::BinOp {
+                    op: CircuitBinOp::Add,
+                    lhs: Box::new(acc),
+                    rhs: Box::new(x),
+                },
+                FoldOp::Mul => CircuitExpr::BinOp {
+                    op: CircuitBinOp::Mul,
+                    lhs: Box::new(acc),
+                    rhs: Box::new(x),
+                },
+                FoldOp::Poseidon => CircuitExpr::PoseidonHash {
+                    left: Box::new(acc),
+                    right: Box::new(x),
+                },
+            };
+        }
+        Ok(acc)
+    }
+
+    /// `map(arr, op)` — apply a named unary transform to every element of
+    /// `arr`, returning the new elements. Like `fold`, `op` is a string
+    /// literal: `"neg"` (`-x`), `"square"` (`x * x`), `"inv"` (`1 / x`,
+    /// asserting `x != 0`) or `"not"` (`!x`). `arr` is a local array or an
+    /// array literal. Only valid as a `let` initializer, which binds the
+    /// result as an array.
+    pub(in crate::ast_lower) fn lower_map(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<Vec<CircuitExpr>, ProveIrError> {
+        self.check_arity("map", 2, args.len(), span)?;
+
+        enum MapOp {
+            Neg,
+            Square,
+            Inv,
+            Not,
+        }
+        let op = match args[1] {
+            Expr::StringLit { value, .. } => match value.as_str() {
+                "neg" => MapOp::Neg,
+                "square" => MapOp::Square,
+                "inv" => MapOp::Inv,
+                "not" => MapOp::Not,
+                other => {
+                    return Err(ProveIrError::UnsupportedOperation {
+                        description: format!(
+                            "unknown map op \"{other}\" \
+                             (expected \"neg\", \"square\", \"inv\", or \"not\")"
+                        ),
+                        span: to_span(span),
+                    });
+                }
+            },
+            _ => {
+                return Err(ProveIrError::UnsupportedOperation {
+                    description: "map op must be a string literal: \"neg\", \"square\", \
+                                  \"inv\", or \"not\""
+                        .into(),
+                    span: to_span(span),
+                });
+            }
+        };
+
+        let elems = self.array_or_literal_arg("map", args[0], span)?;
+
+        let mut mapped = Vec::with_capacity(elems.len());
+        for x in elems {
+            mapped.push(match op {
+                MapOp::Neg => CircuitExpr::UnaryOp {
+                    op: CircuitUnaryOp::Neg,
+                    operand: Box::new(x),
+                },
+                MapOp::Square => {
+                    let x = self.bind_operand("map", x, span);
+                    CircuitExpr::BinOp {
+                        op: CircuitBinOp::Mul,
+                        lhs: Box::new(x.clone()),
+                        rhs: Box::new(x),
+                    }
+                }
+                MapOp::Inv => CircuitExpr::BinOp {
+                    op: CircuitBinOp::Div,
+                    lhs: Box::new(CircuitExpr::Const(FieldConst::one())),
+                    rhs: Box::new(x),
+                },
+                MapOp::Not => CircuitExpr::UnaryOp {
+                    op: CircuitUnaryOp::Not,
+                    operand: Box::new(x),
+                },
+            });
+        }
+        Ok(mapped)
+    }
+
+    /// `map` in scalar position. Its result is an array, which only a
+    /// `let` can bind (`compile_let` routes `let ys = map(xs, op)` to
+    /// [`lower_map`](Self::lower_map)); the slot exists so the resolver
+    /// and `ach check` know the name. As in `compile_let`, a user
+    /// function named `map` takes precedence.
+    pub(super) fn lower_map_scalar(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        if self.has_function("map") {
+            return self.compile_user_fn_call("map", args, span);
+        }
+        self.check_arity("map", 2, args.len(), span)?;
+        Err(ProveIrError::UnsupportedOperation {
+            description: "`map` returns an array; bind its result with `let ys = map(xs, op)`"
+                .into(),
+            span: to_span(span),
+        })
+    }
+
+    /// `bits(x, n)` — decompose `x` into `n` boolean wires, LSB first.
+    /// The single `Decompose` constrains every bit to be boolean and
+    /// `Σ bit_i * 2^i == x`, so `x` is also range-checked to `n` bits.
+    /// Only valid as a `let` initializer, which binds the bits as an array.
+    pub(in crate::ast_lower) fn lower_bits(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<Vec<CircuitExpr>, ProveIrError> {
+        self.decompose_arg("bits", args, span)
+    }
+
+    /// Table slot for `bits` outside a `let` initializer.
+    pub(super) fn lower_bits_scalar(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        if self.has_function("bits") {
+            return self.compile_user_fn_call("bits", args, span);
+        }
+        self.check_arity("bits", 2, args.len(), span)?;
+        Err(ProveIrError::UnsupportedOperation {
+            description: "`bits` returns an array; bind its result with `let bs = bits(x, n)`"
+                .into(),
+            span: to_span(span),
+        })
+    }
+
+    /// `merkle_root(leaves)` — root of the binary Poseidon Merkle tree
+    /// over `leaves`, hashing adjacent pairs level by level. The number of
+    /// leaves must be a power of two; a single leaf is its own root.
+    pub(super) fn lower_merkle_root(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        self.check_arity("merkle_root", 1, args.len(), span)?;
+        let leaves = self.array_arg_elems("merkle_root", args[0], span)?;
+        if !leaves.len().is_power_of_two() {
+            return Err(ProveIrError::UnsupportedOperation {
+                description: format!(
+                    "merkle_root requires a power-of-two number of leaves, got {}",
+                    leaves.len()
+                ),
+                span: to_span(span),
+            });
+        }
+
+        let mut level: Vec<CircuitExpr> = leaves.into_iter().map(CircuitExpr::Var).collect();
+        while level.len() > 1 {
+            let mut pairs = level.into_iter();
+            let mut next = Vec::with_capacity(pairs.len() / 2);
+            while let (Some(left), Some(right)) = (pairs.next(), pairs.next()) {
+                next.push(CircuitExpr::PoseidonHash {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                });
+            }
+            level = next;
+        }
+        Ok(level.pop().expect("power-of-two length is non-zero"))
+    }
+
+    /// `dot(a, b)` — inner product of two equal-length arrays: one
+    /// multiplication per index, summed with linear additions.
+    pub(super) fn lower_dot(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        self.check_arity("dot", 2, args.len(), span)?;
+        let a = self.array_or_literal_arg("dot", args[0], span)?;
+        let b = self.array_or_literal_arg("dot", args[1], span)?;
+        if a.len() != b.len() {
+            return Err(ProveIrError::ArrayLengthMismatch {
+                expected: a.len(),
+                got: b.len(),
+                span: to_span(span),
+            });
+        }
+        Ok(sum_exprs(
+            a.into_iter()
+                .zip(b)
+                .map(|(x, y)| CircuitExpr::BinOp {
+                    op: CircuitBinOp::Mul,
+                    lhs: Box::new(x),
+                    rhs: Box::new(y),
+                })
+                .collect(),
+        ))
+    }
+
+    /// Elements of an array literal, local array or array expression
+    /// (`-arr`, `arr * k`) passed to `builtin`.
+    fn array_or_literal_arg(
+        &mut self,
+        builtin: &str,
+        arg: &Expr,
+        span: &Span,
+    ) -> Result<Vec<CircuitExpr>, ProveIrError> {
+        match arg {
+            Expr::Array { elements, .. } => {
+                if elements.is_empty() {
+                    return Err(ProveIrError::UnsupportedOperation {
+                        description: "empty arrays are not allowed in circuits".into(),
+                        span: to_span(span),
+                    });
+                }
+                elements.iter().map(|e| self.compile_expr(e)).collect()
+            }
+            Expr::Ident { .. } => Ok(self
+                .array_arg_elems(builtin, arg, span)?
+                .into_iter()
+                .map(CircuitExpr::Var)
+                .collect()),
+            Expr::UnaryOp { .. } | Expr::BinOp { .. } => {
+                self.compile_array_arith(arg)?
+                    .ok_or_else(|| ProveIrError::TypeMismatch {
+                        expected: "array".into(),
+                        got: "scalar".into(),
+                        span: to_span(span),
+                    })
+            }
+            _ => Err(ProveIrError::TypeMismatch {
+                expected: "array".into(),
+                got: "scalar".into(),
+                span: to_span(span),
+            }),
+        }
+    }
+
+    /// Element names of a local array passed as `builtin`'s first argument.
+    fn array_arg_elems(
+        &self,
+        builtin: &str,
+        arg: &Expr,
+        span: &Span,
+    ) -> Result<Vec<String>, ProveIrError> {
+        match arg {
+            Expr::Ident { name, .. } => match self.env.get(name.as_str()) {
+                Some(CompEnvValue::Array(elems)) => Ok(elems.clone()),
+                Some(CompEnvValue::Capture(_)) => Err(ProveIrError::UnsupportedOperation {
+                    description: format!(
+                        "captured array `{name}` cannot be passed to {builtin} directly; \
+                         bind it to a local array first: `let local = {name}`"
+                    ),
+                    span: to_span(span),
+                }),
+                _ => Err(ProveIrError::TypeMismatch {
+                    expected: "array".into(),
+                    got: "scalar".into(),
+                    span: to_span(span),
+                }),
+            },
+            _ => Err(ProveIrError::UnsupportedOperation {
+                description: format!("{builtin} requires an array variable as its first argument"),
+                span: to_span(span),
+            }),
+        }
+    }
+
     pub(super) fn lower_len(
         &mut self,
         args: &[&Expr],
@@ -203,6 +650,265 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         self.compile_len_call(args[0], span)
     }
 
+    /// `inv(x)` — multiplicative inverse, lowered as `1 / x`: one inverse
+    /// witness constrained by `x * inv = 1`, which also asserts `x != 0`.
+    pub(super) fn lower_inv(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        self.check_arity("inv", 1, args.len(), span)?;
+        let operand = self.compile_expr(args[0])?;
+        Ok(CircuitExpr::BinOp {
+            op: CircuitBinOp::Div,
+            lhs: Box::new(CircuitExpr::Const(FieldConst::one())),
+            rhs: Box::new(operand),
+        })
+    }
+
+    /// `neg(x)` — additive inverse `-x` (no constraints).
+    pub(super) fn lower_neg(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        self.check_arity("neg", 1, args.len(), span)?;
+        let operand = self.compile_expr(args[0])?;
+        Ok(CircuitExpr::UnaryOp {
+            op: CircuitUnaryOp::Neg,
+            operand: Box::new(operand),
+        })
+    }
+
+    /// `popcount(x, n)` — the number of set bits of `x`, as the sum of
+    /// its `n`-bit decomposition (so `x` is range-checked to `n` bits).
+    pub(super) fn lower_popcount(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        let bits = self.decompose_arg("popcount", args, span)?;
+        Ok(sum_exprs(bits))
+    }
+
+    /// `is_power_of_two(x, n)` — assert that `x` has exactly one set bit
+    /// among its low `n` bits, i.e. `popcount(x, n) == 1`. Zero fails.
+    pub(super) fn lower_is_power_of_two(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        let bits = self.decompose_arg("is_power_of_two", args, span)?;
+        self.body.push(CircuitNode::AssertEq {
+            lhs: sum_exprs(bits),
+            rhs: CircuitExpr::Const(FieldConst::one()),
+            message: Some("value is not a power of two".into()),
+            span: Some(SpanRange::from(span)),
+        });
+        Ok(CircuitExpr::Const(FieldConst::zero()))
+    }
+
+    /// Shared by `popcount`, `is_power_of_two` and `bits`: emit a `Decompose` of
+    /// `x` into `n` boolean wires under an internal name and return the
+    /// bits, LSB first.
+    fn decompose_arg(
+        &mut self,
+        builtin: &str,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<Vec<CircuitExpr>, ProveIrError> {
+        self.check_arity(builtin, 2, args.len(), span)?;
+        let value = self.compile_expr(args[0])?;
+        let num_bits = self.extract_const_u64(args[1], span)?;
+        if num_bits == 0 || num_bits >= F::MODULUS_BIT_SIZE as u64 {
+            return Err(ProveIrError::UnsupportedOperation {
+                description: format!(
+                    "{builtin}() bit count must be between 1 and {} \
+                     (a full-width decomposition is not unique)",
+                    F::MODULUS_BIT_SIZE - 1
+                ),
+                span: to_span(span),
+            });
+        }
+
+        let name = format!("${builtin}{}", self.inline_counter);
+        self.inline_counter = self.inline_counter.wrapping_add(1);
+        self.body.push(CircuitNode::Decompose {
+            name: name.clone(),
+            value,
+            num_bits: num_bits as u32,
+            span: Some(SpanRange::from(span)),
+        });
+        Ok((0..num_bits)
+            .map(|i| CircuitExpr::Var(format!("{name}_{i}")))
+            .collect())
+    }
+
+    /// `bool_and(a, b)` — `a * b` on boolean wires.
+    pub(super) fn lower_bool_and(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        let (lhs, rhs) = self.bool_operands("bool_and", args, span)?;
+        Ok(CircuitExpr::BoolOp {
+            op: CircuitBoolOp::And,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        })
+    }
+
+    /// `bool_or(a, b)` — `a + b - a * b` on boolean wires.
+    pub(super) fn lower_bool_or(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        let (lhs, rhs) = self.bool_operands("bool_or", args, span)?;
+        Ok(CircuitExpr::BoolOp {
+            op: CircuitBoolOp::Or,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        })
+    }
+
+    /// `bool_xor(a, b)` — `a + b - 2ab` on boolean wires: one
+    /// multiplication on top of the operands' enforcement.
+    pub(super) fn lower_bool_xor(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        let (lhs, rhs) = self.bool_operands("bool_xor", args, span)?;
+        let product = CircuitExpr::BinOp {
+            op: CircuitBinOp::Mul,
+            lhs: Box::new(lhs.clone()),
+            rhs: Box::new(rhs.clone()),
+        };
+        Ok(CircuitExpr::BinOp {
+            op: CircuitBinOp::Sub,
+            lhs: Box::new(CircuitExpr::BinOp {
+                op: CircuitBinOp::Add,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            }),
+            rhs: Box::new(CircuitExpr::BinOp {
+                op: CircuitBinOp::Mul,
+                lhs: Box::new(CircuitExpr::Const(FieldConst::from_u64(2))),
+                rhs: Box::new(product),
+            }),
+        })
+    }
+
+    /// `bool_not(a)` — `1 - a` on a boolean wire.
+    pub(super) fn lower_bool_not(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        self.check_arity("bool_not", 1, args.len(), span)?;
+        let operand = self.compile_expr(args[0])?;
+        let operand = self.enforce_bool("bool_not", operand, span)?;
+        Ok(CircuitExpr::UnaryOp {
+            op: CircuitUnaryOp::Not,
+            operand: Box::new(operand),
+        })
+    }
+
+    /// `assert_bool(x)` — enforce `x * (x - 1) == 0`. `bool_prop` picks
+    /// the pattern up, so later `mux`, `assert` and friends on `x` skip
+    /// their own boolean enforcement.
+    pub(super) fn lower_assert_bool(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        self.check_arity("assert_bool", 1, args.len(), span)?;
+        let operand = self.compile_expr(args[0])?;
+        self.enforce_bool("assert_bool", operand, span)?;
+        Ok(CircuitExpr::Const(FieldConst::zero()))
+    }
+
+    /// Both operands of a binary `bool_*` builtin, each enforced boolean.
+    fn bool_operands(
+        &mut self,
+        builtin: &str,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<(CircuitExpr, CircuitExpr), ProveIrError> {
+        self.check_arity(builtin, 2, args.len(), span)?;
+        let lhs = self.compile_expr(args[0])?;
+        let lhs = self.enforce_bool(builtin, lhs, span)?;
+        let rhs = self.compile_expr(args[1])?;
+        let rhs = self.enforce_bool(builtin, rhs, span)?;
+        Ok((lhs, rhs))
+    }
+
+    /// Constrain `operand` to 0 or 1 with `x * (x - 1) == 0`, the pattern
+    /// `bool_prop` recognizes, and return it bound once. Constants are
+    /// checked here instead, and comparison results are boolean by
+    /// construction, so neither costs a constraint. Instantiation drops
+    /// the constraint when the wire is already Bool-typed, so a Bool
+    /// input or an operand enforced by an earlier call isn't re-enforced.
+    fn enforce_bool(
+        &mut self,
+        builtin: &str,
+        operand: CircuitExpr,
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        if let Some(value) = fold_const::<F>(&operand) {
+            if !value.is_zero() && value != FieldElement::<F>::one() {
+                return Err(ProveIrError::UnsupportedOperation {
+                    description: format!("`{builtin}` operands must be boolean (0 or 1)"),
+                    span: to_span(span),
+                });
+            }
+            return Ok(operand);
+        }
+        if matches!(operand, CircuitExpr::Comparison { .. }) {
+            return Ok(operand);
+        }
+
+        let x = self.bind_operand(builtin, operand, span);
+        self.body.push(CircuitNode::AssertEq {
+            lhs: CircuitExpr::BinOp {
+                op: CircuitBinOp::Mul,
+                lhs: Box::new(x.clone()),
+                rhs: Box::new(CircuitExpr::BinOp {
+                    op: CircuitBinOp::Sub,
+                    lhs: Box::new(x.clone()),
+                    rhs: Box::new(CircuitExpr::Const(FieldConst::one())),
+                }),
+            },
+            rhs: CircuitExpr::Const(FieldConst::zero()),
+            message: None,
+            span: Some(SpanRange::from(span)),
+        });
+        Ok(x)
+    }
+
+    /// Bind a non-trivial `expr` to an internal `Let` so a builtin (or
+    /// array scaling) that uses it more than once doesn't re-emit it per
+    /// use.
+    pub(in crate::ast_lower) fn bind_operand(
+        &mut self,
+        builtin: &str,
+        expr: CircuitExpr,
+        span: &Span,
+    ) -> CircuitExpr {
+        if matches!(expr, CircuitExpr::Var(_) | CircuitExpr::Const(_)) {
+            return expr;
+        }
+        let name = format!("${builtin}{}", self.inline_counter);
+        self.inline_counter = self.inline_counter.wrapping_add(1);
+        self.body.push(CircuitNode::Let {
+            name: name.clone(),
+            value: expr,
+            span: Some(SpanRange::from(span)),
+        });
+        CircuitExpr::Var(name)
+    }
+
     pub(super) fn lower_assert_eq(
         &mut self,
         args: &[&Expr],
@@ -221,6 +927,73 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         Ok(CircuitExpr::Const(FieldConst::zero()))
     }
 
+    /// `assert_all_eq(a, b, c, ...)`: one `AssertEq` of the first argument
+    /// against each of the others. A non-trivial first argument is bound
+    /// once so it isn't re-emitted per comparison.
+    pub(super) fn lower_assert_all_eq(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        if args.len() < 2 {
+            return Err(ProveIrError::UnsupportedOperation {
+                description: format!(
+                    "`assert_all_eq` requires at least 2 arguments, got {}",
+                    args.len()
+                ),
+                span: to_span(span),
+            });
+        }
+        let first = self.compile_expr(args[0])?;
+        let first = self.bind_operand("assert_all_eq", first, span);
+        for arg in &args[1..] {
+            let rhs = self.compile_expr(arg)?;
+            self.body.push(CircuitNode::AssertEq {
+                lhs: first.clone(),
+                rhs,
+                message: None,
+                span: Some(SpanRange::from(span)),
+            });
+        }
+        Ok(CircuitExpr::Const(FieldConst::zero()))
+    }
+
+    /// `assert_in_set(x, [a, b, c])`: asserts `(x - a) * (x - b) * (x - c)
+    /// == 0`, one multiplication per extra set element, which is cheaper
+    /// than OR-ing equality checks. The set is an array literal or local
+    /// array, normally of constants. A non-trivial `x` is bound once.
+    pub(super) fn lower_assert_in_set(
+        &mut self,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        self.check_arity("assert_in_set", 2, args.len(), span)?;
+        let x = self.compile_expr(args[0])?;
+        let set = self.array_or_literal_arg("assert_in_set", args[1], span)?;
+        let x = self.bind_operand("assert_in_set", x, span);
+
+        let product = set
+            .into_iter()
+            .map(|member| CircuitExpr::BinOp {
+                op: CircuitBinOp::Sub,
+                lhs: Box::new(x.clone()),
+                rhs: Box::new(member),
+            })
+            .reduce(|acc, factor| CircuitExpr::BinOp {
+                op: CircuitBinOp::Mul,
+                lhs: Box::new(acc),
+                rhs: Box::new(factor),
+            })
+            .expect("array arguments are non-empty");
+        self.body.push(CircuitNode::AssertEq {
+            lhs: product,
+            rhs: CircuitExpr::Const(FieldConst::zero()),
+            message: None,
+            span: Some(SpanRange::from(span)),
+        });
+        Ok(CircuitExpr::Const(FieldConst::zero()))
+    }
+
     pub(super) fn lower_assert(
         &mut self,
         args: &[&Expr],
@@ -269,3 +1042,15 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         })
     }
 }
+
+/// Sum non-empty `terms` with linear `Add`s.
+fn sum_exprs(terms: Vec<CircuitExpr>) -> CircuitExpr {
+    terms
+        .into_iter()
+        .reduce(|acc, term| CircuitExpr::BinOp {
+            op: CircuitBinOp::Add,
+            lhs: Box::new(acc),
+            rhs: Box::new(term),
+        })
+        .expect("sum of at least one term")
+}
@@ -1,7 +1,9 @@
 //! Top-level call dispatch on [`ProveIrCompiler`].
 //!
 //! Owns [`ProveIrCompiler::compile_call`], which inspects the
-//! callee shape and routes to one of six cohesive submodules:
+//! callee shape and routes to one of six cohesive submodules
+//! (plus the [`poseidon_expand`] helper used by `poseidon_domain` and
+//! `poseidon2`):
 //!
 //! - [`static_access`] — `T::MEMBER` namespace reads
 //!   ([`compile_static_access`](ProveIrCompiler::compile_static_access)).
@@ -39,6 +41,7 @@ mod circom_call;
 mod circom_instantiate;
 mod circom_resolve;
 mod dispatch;
+mod poseidon_expand;
 mod static_access;
 
 impl<F: FieldBackend> ProveIrCompiler<F> {
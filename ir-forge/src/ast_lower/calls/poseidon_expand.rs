@@ -0,0 +1,285 @@
+//! Inline expansion of the Poseidon and Poseidon2 t=3 permutations into
+//! ProveIR.
+//!
+//! `CircuitExpr::PoseidonHash` always starts the sponge with a zero
+//! capacity, so builtins that need a different initial state
+//! (`poseidon_domain`) expand the permutation here instead: every
+//! S-box multiplication and every MDS output becomes a `Let` temp,
+//! which keeps each expression bounded and the R1CS cost equal to the
+//! S-box multiplications alone (linear steps are free).
+//!
+//! Constants and the initial capacity come from the same
+//! `default_poseidon_t3` parameters the R1CS and witness backends use,
+//! tagged through [`PoseidonParams::with_domain_tag`], so an expansion
+//! with tag zero reproduces `poseidon(a, b)` exactly.
+//!
+//! `poseidon2` has no dedicated IR node; its permutation is expanded the
+//! same way from [`Poseidon2Params::bn254_t3`], matching
+//! `constraints::poseidon2::poseidon2_hash`.
+
+use achronyme_parser::ast::Span;
+use constraints::poseidon::PoseidonParams;
+use constraints::poseidon2::Poseidon2Params;
+use constraints::PoseidonParamsProvider;
+use diagnostics::SpanRange;
+use memory::field::PrimeId;
+use memory::{Bls12_381Fr, Bn254Fr, FieldBackend, GoldilocksFr};
+
+use super::super::ProveIrCompiler;
+use crate::types::*;
+
+/// Poseidon parameters with constants pre-encoded as [`FieldConst`].
+pub(super) struct PoseidonConsts {
+    /// Initial capacity element, from [`PoseidonParams::domain_tag`].
+    pub(super) capacity: FieldConst,
+    t: usize,
+    r_f: usize,
+    r_p: usize,
+    alpha: u32,
+    round_constants: Vec<FieldConst>,
+    mds: Vec<Vec<FieldConst>>,
+}
+
+impl PoseidonConsts {
+    /// The default t=3 parameters for `F` with domain tag `tag`, or `None`
+    /// when the prime has no Poseidon configuration.
+    pub(super) fn default_t3<F: FieldBackend>(tag: FieldConst) -> Option<Self> {
+        match F::PRIME_ID {
+            PrimeId::Bn254 => Self::tagged(Bn254Fr::default_poseidon_t3(), tag),
+            PrimeId::Bls12_381 => Self::tagged(Bls12_381Fr::default_poseidon_t3(), tag),
+            PrimeId::Goldilocks => Self::tagged(GoldilocksFr::default_poseidon_t3(), tag),
+            _ => None,
+        }
+    }
+
+    fn tagged<G: FieldBackend>(params: PoseidonParams<G>, tag: FieldConst) -> Option<Self> {
+        Some(Self::encode(&params.with_domain_tag(tag.to_field::<G>()?)))
+    }
+
+    fn encode<G: FieldBackend>(params: &PoseidonParams<G>) -> Self {
+        Self {
+            capacity: FieldConst::from_field(params.domain_tag),
+            t: params.t,
+            r_f: params.r_f,
+            r_p: params.r_p,
+            alpha: params.alpha,
+            round_constants: params
+                .round_constants
+                .iter()
+                .map(|c| FieldConst::from_field(*c))
+                .collect(),
+            mds: params
+                .mds
+                .iter()
+                .map(|row| row.iter().map(|c| FieldConst::from_field(*c)).collect())
+                .collect(),
+        }
+    }
+}
+
+/// Poseidon2 parameters with constants pre-encoded as [`FieldConst`].
+pub(super) struct Poseidon2Consts {
+    /// Initial capacity element, from [`Poseidon2Params::domain_tag`].
+    pub(super) capacity: FieldConst,
+    r_f: usize,
+    alpha: u32,
+    external_rc: Vec<FieldConst>,
+    internal_rc: Vec<FieldConst>,
+    internal_diag: Vec<FieldConst>,
+}
+
+impl Poseidon2Consts {
+    /// The t=3 parameters for `F`, or `None` when the prime has no
+    /// Poseidon2 configuration (only BN254 has one).
+    pub(super) fn default_t3<F: FieldBackend>() -> Option<Self> {
+        match F::PRIME_ID {
+            PrimeId::Bn254 => Some(Self::encode(&Poseidon2Params::bn254_t3())),
+            _ => None,
+        }
+    }
+
+    fn encode<G: FieldBackend>(params: &Poseidon2Params<G>) -> Self {
+        let encode_all = |v: &[memory::FieldElement<G>]| -> Vec<FieldConst> {
+            v.iter().map(|c| FieldConst::from_field(*c)).collect()
+        };
+        Self {
+            capacity: FieldConst::from_field(params.domain_tag),
+            r_f: params.r_f,
+            alpha: params.alpha,
+            external_rc: encode_all(&params.external_rc),
+            internal_rc: encode_all(&params.internal_rc),
+            internal_diag: encode_all(&params.internal_diag),
+        }
+    }
+}
+
+impl<F: FieldBackend> ProveIrCompiler<F> {
+    /// Expand the Poseidon permutation over `state` (length `t`) and
+    /// return the final state. Intermediate values are bound to
+    /// `$poseidon{N}_…` temps in the current body.
+    pub(super) fn expand_poseidon_permutation(
+        &mut self,
+        consts: &PoseidonConsts,
+        mut state: Vec<CircuitExpr>,
+        span: &Span,
+    ) -> Vec<CircuitExpr> {
+        debug_assert_eq!(state.len(), consts.t);
+        let id = self.inline_counter;
+        self.inline_counter = self.inline_counter.wrapping_add(1);
+
+        let half_f = consts.r_f / 2;
+        for r in 0..consts.r_f + consts.r_p {
+            // 1. Add round constants
+            state = state
+                .into_iter()
+                .enumerate()
+                .map(|(i, s)| {
+                    add(
+                        s,
+                        CircuitExpr::Const(consts.round_constants[r * consts.t + i]),
+                    )
+                })
+                .collect();
+
+            // 2. S-box: all elements in full rounds, state[0] in partial rounds
+            let full = r < half_f || r >= half_f + consts.r_p;
+            let sboxes = if full { consts.t } else { 1 };
+            for (i, s) in state.iter_mut().take(sboxes).enumerate() {
+                let prefix = format!("$poseidon{id}_r{r}_s{i}");
+                let x = self.bind_temp(prefix.clone(), s.clone(), span);
+                *s = self.bind_pow(&x, consts.alpha, &prefix, span);
+            }
+
+            // 3. MDS, each output bound so partial rounds don't grow the tree
+            let old = state;
+            state = Vec::with_capacity(consts.t);
+            for (i, row) in consts.mds.iter().enumerate() {
+                let mut acc = mul(CircuitExpr::Const(row[0]), old[0].clone());
+                for (m, s) in row.iter().zip(&old).skip(1) {
+                    acc = add(acc, mul(CircuitExpr::Const(*m), s.clone()));
+                }
+                state.push(self.bind_temp(format!("$poseidon{id}_r{r}_m{i}"), acc, span));
+            }
+        }
+        state
+    }
+
+    /// Expand the Poseidon2 permutation over a t=3 `state` and return the
+    /// final state. S-box inputs and outputs and every linear-layer output
+    /// are bound to `$poseidon2_{N}_…` temps in the current body.
+    pub(super) fn expand_poseidon2_permutation(
+        &mut self,
+        consts: &Poseidon2Consts,
+        mut state: Vec<CircuitExpr>,
+        span: &Span,
+    ) -> Vec<CircuitExpr> {
+        debug_assert_eq!(state.len(), 3);
+        let id = self.inline_counter;
+        self.inline_counter = self.inline_counter.wrapping_add(1);
+        let t = state.len();
+        let half_f = consts.r_f / 2;
+
+        // External layer M_E = circ(2, 1, 1): x_i + Σx
+        let external = |this: &mut Self, state: Vec<CircuitExpr>, tag: &str| {
+            let sum = this.bind_temp(format!("$poseidon2_{id}_{tag}_sum"), sum(&state), span);
+            state
+                .into_iter()
+                .enumerate()
+                .map(|(i, s)| {
+                    this.bind_temp(
+                        format!("$poseidon2_{id}_{tag}_m{i}"),
+                        add(s, sum.clone()),
+                        span,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let external_round = |this: &mut Self, mut state: Vec<CircuitExpr>, r: usize| {
+            for (i, s) in state.iter_mut().enumerate() {
+                let prefix = format!("$poseidon2_{id}_e{r}_s{i}");
+                let rc = CircuitExpr::Const(consts.external_rc[r * t + i]);
+                let x = this.bind_temp(prefix.clone(), add(s.clone(), rc), span);
+                *s = this.bind_pow(&x, consts.alpha, &prefix, span);
+            }
+            external(this, state, &format!("e{r}"))
+        };
+
+        state = external(self, state, "pre");
+        for r in 0..half_f {
+            state = external_round(self, state, r);
+        }
+        for (r, rc) in consts.internal_rc.iter().enumerate() {
+            // S-box on x_0, then M_I = J + diag(d): d_i·x_i + Σx
+            let prefix = format!("$poseidon2_{id}_i{r}_s0");
+            let x = self.bind_temp(
+                prefix.clone(),
+                add(state[0].clone(), CircuitExpr::Const(*rc)),
+                span,
+            );
+            state[0] = self.bind_pow(&x, consts.alpha, &prefix, span);
+            let total = self.bind_temp(format!("$poseidon2_{id}_i{r}_sum"), sum(&state), span);
+            state = state
+                .into_iter()
+                .zip(&consts.internal_diag)
+                .enumerate()
+                .map(|(i, (s, d))| {
+                    let scaled = mul(CircuitExpr::Const(*d), s);
+                    self.bind_temp(
+                        format!("$poseidon2_{id}_i{r}_m{i}"),
+                        add(scaled, total.clone()),
+                        span,
+                    )
+                })
+                .collect();
+        }
+        for r in half_f..consts.r_f {
+            state = external_round(self, state, r);
+        }
+        state
+    }
+
+    /// `x^alpha` by left-to-right square-and-multiply, one `Let` per
+    /// multiplication (3 for α=5, 4 for α=7).
+    fn bind_pow(&mut self, x: &CircuitExpr, alpha: u32, prefix: &str, span: &Span) -> CircuitExpr {
+        let mut acc = x.clone();
+        let bits = u32::BITS - alpha.leading_zeros();
+        for (step, k) in (0..bits.saturating_sub(1)).rev().enumerate() {
+            acc = self.bind_temp(format!("{prefix}_sq{step}"), mul(acc.clone(), acc), span);
+            if (alpha >> k) & 1 == 1 {
+                acc = self.bind_temp(format!("{prefix}_mul{step}"), mul(acc, x.clone()), span);
+            }
+        }
+        acc
+    }
+
+    fn bind_temp(&mut self, name: String, value: CircuitExpr, span: &Span) -> CircuitExpr {
+        self.body.push(CircuitNode::Let {
+            name: name.clone(),
+            value,
+            span: Some(SpanRange::from(span)),
+        });
+        CircuitExpr::Var(name)
+    }
+}
+
+fn add(lhs: CircuitExpr, rhs: CircuitExpr) -> CircuitExpr {
+    CircuitExpr::BinOp {
+        op: CircuitBinOp::Add,
+        lhs: Box::new(lhs),
+        rhs: Box::new(rhs),
+    }
+}
+
+fn sum(terms: &[CircuitExpr]) -> CircuitExpr {
+    terms[1..]
+        .iter()
+        .fold(terms[0].clone(), |acc, x| add(acc, x.clone()))
+}
+
+fn mul(lhs: CircuitExpr, rhs: CircuitExpr) -> CircuitExpr {
+    CircuitExpr::BinOp {
+        op: CircuitBinOp::Mul,
+        lhs: Box::new(lhs),
+        rhs: Box::new(rhs),
+    }
+}
@@ -1,14 +1,15 @@
 //! Literal and identifier compilation.
 //!
-//! Three methods that turn the simplest `Expr` shapes into a
+//! Four methods that turn the simplest `Expr` shapes into a
 //! `CircuitExpr`:
 //!
 //! - `compile_number` — integer literals (with sign), rejecting decimals.
 //! - `compile_field_lit` — `0x…` / `0b…` / decimal field literals.
+//! - `compile_bigint_lit` — `0i256x…` style literals below the modulus.
 //! - `compile_ident` — environment lookup + capture tracking.
 
 use achronyme_parser::ast::*;
-use memory::{FieldBackend, FieldElement};
+use memory::{BigInt, BigIntWidth, FieldBackend, FieldElement};
 
 use super::super::helpers::to_span;
 use super::super::{CompEnvValue, ProveIrCompiler};
@@ -23,26 +24,15 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
                 span: to_span(span),
             });
         }
-        let (negative, digits) = if let Some(rest) = s.strip_prefix('-') {
-            (true, rest)
-        } else {
-            (false, s)
-        };
-        let fe = FieldElement::<F>::from_decimal_str(digits).ok_or_else(|| {
+        // A synthesized signed literal ("-3") maps straight to `p - 3`
+        // instead of wrapping the magnitude in a Neg.
+        let fe = FieldElement::<F>::from_signed_decimal_str(s).ok_or_else(|| {
             ProveIrError::UnsupportedOperation {
                 description: format!("invalid integer literal: {s}"),
                 span: to_span(span),
             }
         })?;
-        let fc = FieldConst::from_field(fe);
-        if negative {
-            Ok(CircuitExpr::UnaryOp {
-                op: CircuitUnaryOp::Neg,
-                operand: Box::new(CircuitExpr::Const(fc)),
-            })
-        } else {
-            Ok(CircuitExpr::Const(fc))
-        }
+        Ok(CircuitExpr::Const(FieldConst::from_field(fe)))
     }
 
     pub(super) fn compile_field_lit(
@@ -51,15 +41,58 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         radix: &FieldRadix,
         span: &Span,
     ) -> Result<CircuitExpr, ProveIrError> {
-        let fe = match radix {
-            FieldRadix::Decimal => FieldElement::<F>::from_decimal_str(value),
-            FieldRadix::Hex => FieldElement::<F>::from_hex_str(value),
-            FieldRadix::Binary => FieldElement::<F>::from_binary_str(value),
+        // Parsed at 256 bits so a value at or above the modulus is rejected
+        // instead of being silently reduced.
+        let bi = match radix {
+            FieldRadix::Decimal => BigInt::from_decimal_str(value, BigIntWidth::W256),
+            FieldRadix::Hex => BigInt::from_hex_str(value, BigIntWidth::W256),
+            FieldRadix::Binary => BigInt::from_binary_str(value, BigIntWidth::W256),
         }
         .ok_or_else(|| ProveIrError::UnsupportedOperation {
             description: format!("invalid field literal: {value}"),
             span: to_span(span),
         })?;
+        let fe = bi
+            .to_field::<F>()
+            .ok_or_else(|| ProveIrError::UnsupportedOperation {
+                description: "field literal is not below the field modulus".into(),
+                span: to_span(span),
+            })?;
+        Ok(CircuitExpr::Const(FieldConst::from_field(fe)))
+    }
+
+    pub(super) fn compile_bigint_lit(
+        &self,
+        value: &str,
+        width: u16,
+        radix: &BigIntRadix,
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        let w = match width {
+            256 => BigIntWidth::W256,
+            512 => BigIntWidth::W512,
+            _ => {
+                return Err(ProveIrError::UnsupportedOperation {
+                    description: format!("invalid BigInt width: {width}"),
+                    span: to_span(span),
+                })
+            }
+        };
+        let bi = match radix {
+            BigIntRadix::Hex => BigInt::from_hex_str(value, w),
+            BigIntRadix::Decimal => BigInt::from_decimal_str(value, w),
+            BigIntRadix::Binary => BigInt::from_binary_str(value, w),
+        }
+        .ok_or_else(|| ProveIrError::UnsupportedOperation {
+            description: format!("invalid {width}-bit BigInt literal: {value}"),
+            span: to_span(span),
+        })?;
+        let fe = bi
+            .to_field::<F>()
+            .ok_or_else(|| ProveIrError::UnsupportedOperation {
+                description: "BigInt constant is not below the field modulus".into(),
+                span: to_span(span),
+            })?;
         Ok(CircuitExpr::Const(FieldConst::from_field(fe)))
     }
 
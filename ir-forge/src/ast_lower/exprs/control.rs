@@ -9,6 +9,8 @@
 //! - `compile_block_as_expr` — runs statement-level compilation over a
 //!   block and returns its trailing expression as the result.
 
+use std::collections::HashSet;
+
 use achronyme_parser::ast::*;
 use diagnostics::SpanRange;
 use memory::FieldBackend;
@@ -151,30 +153,24 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
             return Ok(CircuitExpr::Const(FieldConst::zero()));
         }
 
-        // Compile all but the last statement normally
+        self.let_scopes.push(HashSet::new());
+
+        // Compile all but the last statement normally. A `return` here
+        // is nested in a branch or loop body; `compile_stmt` rejects it.
         for stmt in &stmts[..stmts.len() - 1] {
-            // Handle Return inside function body
-            if let Stmt::Return { value, .. } = stmt {
-                return match value {
-                    Some(expr) => self.compile_expr(expr),
-                    None => Ok(CircuitExpr::Const(FieldConst::zero())),
-                };
-            }
             self.compile_stmt(stmt)?;
         }
 
         // The last statement: if it's an Expr, return its value; otherwise compile and return ZERO
         let last = &stmts[stmts.len() - 1];
-        match last {
+        let result = match last {
             Stmt::Expr(expr) => self.compile_expr(expr),
-            Stmt::Return { value, .. } => match value {
-                Some(expr) => self.compile_expr(expr),
-                None => Ok(CircuitExpr::Const(FieldConst::zero())),
-            },
             other => {
                 self.compile_stmt(other)?;
                 Ok(CircuitExpr::Const(FieldConst::zero()))
             }
-        }
+        };
+        self.let_scopes.pop();
+        result
     }
 }
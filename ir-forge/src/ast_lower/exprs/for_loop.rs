@@ -31,9 +31,6 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         body: &Block,
         span: &Span,
     ) -> Result<CircuitExpr, ProveIrError> {
-        /// Maximum number of iterations allowed for literal ranges.
-        const MAX_LOOP_ITERATIONS: u64 = 1_000_000;
-
         // Carry-set detection: if the body re-assigns any mutable variable
         // declared *outside* this loop (and not redeclared / shadowed
         // inside), we must eager-unroll at lower time so each iteration's
@@ -53,10 +50,10 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         let range = match iterable {
             ForIterable::Range { start, end } => {
                 let iterations = end.saturating_sub(*start);
-                if iterations > MAX_LOOP_ITERATIONS {
+                if iterations > self.max_loop_iterations {
                     return Err(ProveIrError::RangeTooLarge {
                         iterations,
-                        max: MAX_LOOP_ITERATIONS,
+                        max: self.max_loop_iterations,
                         span: to_span(span),
                     });
                 }
@@ -205,11 +202,10 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         span: &Span,
     ) -> Result<CircuitExpr, ProveIrError> {
         let iterations = end.saturating_sub(start);
-        const MAX_LOOP_ITERATIONS: u64 = 1_000_000;
-        if iterations > MAX_LOOP_ITERATIONS {
+        if iterations > self.max_loop_iterations {
             return Err(ProveIrError::RangeTooLarge {
                 iterations,
-                max: MAX_LOOP_ITERATIONS,
+                max: self.max_loop_iterations,
                 span: to_span(span),
             });
         }
@@ -1,18 +1,20 @@
 //! User function inlining.
 //!
-//! Two methods that lower an `Expr::Call` of a user-declared function
+//! Three methods that lower an `Expr::Call` of a user-declared function
 //! by pasting its body into the current circuit context:
 //!
 //! - `compile_user_fn_call` — arity check, recursion guard, env
 //!   shadowing, parameter binding, body inlining, env restore.
+//! - `compile_fn_body` — inlines the body, honouring a single
+//!   top-level `return`.
 //! - `bind_array_fn_param` — array-parameter wiring (`LetArray` node
 //!   + per-element env entries).
 
 use achronyme_parser::ast::*;
-use diagnostics::SpanRange;
+use diagnostics::{Diagnostic, SpanRange};
 use memory::FieldBackend;
 
-use super::super::helpers::to_span;
+use super::super::helpers::{stmt_span, to_span};
 use super::super::{CompEnvValue, ProveIrCompiler};
 use crate::error::ProveIrError;
 use crate::types::*;
@@ -127,7 +129,7 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         }
 
         // Compile the function body, collecting the result
-        let result = self.compile_block_as_expr(&fn_def.body)?;
+        let result = self.compile_fn_body(&fn_def.body)?;
 
         // Restore env
         for (p, old_val) in saved {
@@ -153,6 +155,55 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         Ok(result)
     }
 
+    /// Inline a function body and return its value.
+    ///
+    /// A top-level `return expr` ends the body: its value is the result
+    /// and any statements after it are skipped with an "unreachable code"
+    /// warning. When the body ends in an `if`, a `return` ending one of its
+    /// branches is that branch's value, so `if c { return a } else {
+    /// return b }` selects between `a` and `b`. Any other nested return is
+    /// rejected by `compile_stmt`, since inlining cannot exit a function
+    /// early on a runtime condition.
+    pub(in crate::ast_lower) fn compile_fn_body(
+        &mut self,
+        body: &Block,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        let Some(pos) = body
+            .stmts
+            .iter()
+            .position(|s| matches!(s, Stmt::Return { .. }))
+        else {
+            if let Some(Stmt::Expr(Expr::If { .. })) = body.stmts.last() {
+                let mut body = body.clone();
+                tail_returns_to_values(&mut body);
+                return self.compile_block_as_expr(&body);
+            }
+            return self.compile_block_as_expr(body);
+        };
+
+        if let Some(next) = body.stmts.get(pos + 1) {
+            // Report once per source position, not once per call site.
+            let warning =
+                Diagnostic::warning("unreachable code", stmt_span(next).into()).with_code("W003");
+            if !self
+                .warnings
+                .iter()
+                .any(|w| w.primary_span == warning.primary_span)
+            {
+                self.warnings.push(warning);
+            }
+        }
+        for stmt in &body.stmts[..pos] {
+            self.compile_stmt(stmt)?;
+        }
+        match &body.stmts[pos] {
+            Stmt::Return {
+                value: Some(expr), ..
+            } => self.compile_expr(expr),
+            _ => Ok(CircuitExpr::Const(FieldConst::zero())),
+        }
+    }
+
     /// Bind an array parameter for function inlining.
     ///
     /// Resolves the argument as an array name in the environment, creates
@@ -237,3 +288,37 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         Ok(())
     }
 }
+
+/// Replace a trailing `return e` in `block` with the expression `e`,
+/// descending into the branches of a trailing `if`.
+fn tail_returns_to_values(block: &mut Block) {
+    match block.stmts.last_mut() {
+        Some(Stmt::Return {
+            value: Some(expr), ..
+        }) => {
+            let expr = expr.clone();
+            *block.stmts.last_mut().unwrap() = Stmt::Expr(expr);
+        }
+        Some(Stmt::Return { value: None, .. }) => {
+            block.stmts.pop();
+        }
+        Some(Stmt::Expr(if_expr @ Expr::If { .. })) => if_tail_returns_to_values(if_expr),
+        _ => {}
+    }
+}
+
+fn if_tail_returns_to_values(expr: &mut Expr) {
+    if let Expr::If {
+        then_block,
+        else_branch,
+        ..
+    } = expr
+    {
+        tail_returns_to_values(then_block);
+        match else_branch {
+            Some(ElseBranch::Block(block)) => tail_returns_to_values(block),
+            Some(ElseBranch::If(nested)) => if_tail_returns_to_values(nested),
+            None => {}
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Expression compilation on [`ProveIrCompiler`].
 //!
-//! The `compile_expr` dispatcher plus five per-concern submodules. Each
+//! The `compile_expr` dispatcher plus six per-concern submodules. Each
 //! submodule owns one `impl<F: FieldBackend> ProveIrCompiler<F> { ... }`
 //! block; cross-submodule calls go through `pub(super)` visibility,
 //! which here resolves up to this `exprs` module.
@@ -8,7 +8,7 @@
 //! ## Submodules
 //!
 //! - [`atoms`] — literal + identifier compilation (`compile_number`,
-//!   `compile_field_lit`, `compile_ident`).
+//!   `compile_field_lit`, `compile_bigint_lit`, `compile_ident`).
 //! - [`control`] — control-flow expressions (`compile_if_expr`,
 //!   `compile_index`, `compile_block_as_expr`).
 //! - [`for_loop`] — `compile_for_expr` plus its eager-unroll variants
@@ -19,6 +19,8 @@
 //!   (`compile_binop`, `compile_arith_binop`, `compile_comparison`,
 //!   `compile_bool_binop`, `compile_pow`, `compile_unary`,
 //!   `extract_const_u64`).
+//! - [`while_loop`] — `compile_bounded_while`, the guarded unroll of
+//!   `while cond max N { ... }`.
 //!
 //! Statement-level compilation lives in [`super::stmts`]; call dispatch
 //! and builtin lowering in [`super::calls`]; method lookups in
@@ -37,6 +39,7 @@ mod control;
 mod for_loop;
 mod inline;
 mod ops;
+mod while_loop;
 
 impl<F: FieldBackend> ProveIrCompiler<F> {
     /// Compile an AST expression into a `CircuitExpr`.
@@ -55,6 +58,13 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
             Expr::FieldLit {
                 value, radix, span, ..
             } => self.compile_field_lit(value, radix, span),
+            Expr::BigIntLit {
+                value,
+                width,
+                radix,
+                span,
+                ..
+            } => self.compile_bigint_lit(value, *width, radix, span),
             Expr::Bool { value: true, .. } => Ok(CircuitExpr::Const(FieldConst::one())),
             Expr::Bool { value: false, .. } => Ok(CircuitExpr::Const(FieldConst::zero())),
             Expr::Ident { name, span, .. } => self.compile_ident(name, span),
@@ -113,6 +123,13 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
             } => self.compile_index(object, index, span),
 
             // --- Rejections (same as IrLowering, with better messages) ---
+            Expr::While {
+                condition,
+                max: Some(max),
+                body,
+                span,
+                ..
+            } => self.compile_bounded_while(condition, *max, body, span),
             Expr::While { span, .. } | Expr::Forever { span, .. } => {
                 Err(ProveIrError::UnboundedLoop {
                     span: to_span(span),
@@ -141,10 +158,6 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
                 type_name: "map".into(),
                 span: to_span(span),
             }),
-            Expr::BigIntLit { span, .. } => Err(ProveIrError::TypeNotConstrainable {
-                type_name: "BigInt".into(),
-                span: to_span(span),
-            }),
             Expr::Array { span, .. } => Err(ProveIrError::TypeMismatch {
                 expected: "scalar expression".into(),
                 got: "array literal (use let binding for arrays)".into(),
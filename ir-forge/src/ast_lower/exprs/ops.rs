@@ -10,12 +10,14 @@
 //! - `extract_const_u64` — shared constant-folder used by exponents,
 //!   range-check widths, etc.
 //! - `compile_unary` — neg/not lowering with `--x`/`!!x` cancellation.
+//! - `compile_array_arith` — element-wise `-arr` and `arr * k` for array
+//!   `let` bindings and array-taking builtins.
 
 use achronyme_parser::ast::*;
 use memory::FieldBackend;
 
 use super::super::helpers::to_span;
-use super::super::ProveIrCompiler;
+use super::super::{CompEnvValue, ProveIrCompiler};
 use crate::error::ProveIrError;
 use crate::types::*;
 
@@ -111,6 +113,19 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         span: &Span,
     ) -> Result<CircuitExpr, ProveIrError> {
         let base = self.compile_expr(base_expr)?;
+        if let Expr::UnaryOp {
+            op: UnaryOp::Neg,
+            operand,
+            ..
+        } = exp_expr
+        {
+            if matches!(**operand, Expr::Number { ref value, .. } if value != "0") {
+                return Err(ProveIrError::UnsupportedOperation {
+                    description: "negative exponents are not supported in circuits".into(),
+                    span: to_span(span),
+                });
+            }
+        }
         let exp = self.extract_const_u64(exp_expr, span)?;
         Ok(CircuitExpr::Pow {
             base: Box::new(base),
@@ -173,4 +188,100 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
             operand: Box::new(inner),
         })
     }
+
+    /// Elements of an array-valued `-arr`, `arr * k` or `k * arr`, or
+    /// `None` when `expr` is not one. Negation and scaling apply
+    /// element-wise; multiplying two arrays is rejected since it could
+    /// mean element-wise or an inner product.
+    pub(in crate::ast_lower) fn compile_array_arith(
+        &mut self,
+        expr: &Expr,
+    ) -> Result<Option<Vec<CircuitExpr>>, ProveIrError> {
+        match expr {
+            Expr::UnaryOp {
+                op: UnaryOp::Neg,
+                operand,
+                ..
+            } => {
+                // --arr → arr, as in `compile_unary`.
+                if let Expr::UnaryOp {
+                    op: UnaryOp::Neg,
+                    operand: inner,
+                    ..
+                } = operand.as_ref()
+                {
+                    return self.array_operand(inner);
+                }
+                Ok(self.array_operand(operand)?.map(|elems| {
+                    elems
+                        .into_iter()
+                        .map(|x| CircuitExpr::UnaryOp {
+                            op: CircuitUnaryOp::Neg,
+                            operand: Box::new(x),
+                        })
+                        .collect()
+                }))
+            }
+            Expr::BinOp {
+                op: BinOp::Mul,
+                lhs,
+                rhs,
+                span,
+                ..
+            } => {
+                let (elems, scalar) = match (self.array_operand(lhs)?, self.array_operand(rhs)?) {
+                    (None, None) => return Ok(None),
+                    (Some(elems), None) => (elems, rhs),
+                    (None, Some(elems)) => (elems, lhs),
+                    (Some(_), Some(_)) => {
+                        return Err(ProveIrError::UnsupportedOperation {
+                            description: "cannot multiply two arrays: `*` only scales an array \
+                                          by a scalar; use `dot(a, b)` for the inner product, \
+                                          or compare arrays element by element with `assert_eq`"
+                                .into(),
+                            span: to_span(span),
+                        })
+                    }
+                };
+                let k = self.compile_expr(scalar)?;
+                let k = self.bind_operand("scale", k, span);
+                Ok(Some(
+                    elems
+                        .into_iter()
+                        .map(|x| CircuitExpr::BinOp {
+                            op: CircuitBinOp::Mul,
+                            lhs: Box::new(x),
+                            rhs: Box::new(k.clone()),
+                        })
+                        .collect(),
+                ))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Elements of an operand of `compile_array_arith`: a local array, an
+    /// array literal or a nested array expression. `None` for scalars.
+    fn array_operand(&mut self, expr: &Expr) -> Result<Option<Vec<CircuitExpr>>, ProveIrError> {
+        match expr {
+            Expr::Ident { name, .. } => match self.env.get(name.as_str()) {
+                Some(CompEnvValue::Array(elems)) => {
+                    Ok(Some(elems.iter().cloned().map(CircuitExpr::Var).collect()))
+                }
+                _ => Ok(None),
+            },
+            Expr::Array { elements, span, .. } => {
+                if elements.is_empty() {
+                    return Err(ProveIrError::UnsupportedOperation {
+                        description: "empty arrays are not allowed in circuits".into(),
+                        span: to_span(span),
+                    });
+                }
+                let compiled: Result<Vec<_>, _> =
+                    elements.iter().map(|e| self.compile_expr(e)).collect();
+                compiled.map(Some)
+            }
+            _ => self.compile_array_arith(expr),
+        }
+    }
 }
@@ -0,0 +1,324 @@
+//! Bounded `while cond max N { ... }` compilation.
+//!
+//! The loop is unrolled at lower time into exactly `N` guarded
+//! iterations. Each iteration binds the condition evaluated on the
+//! current state, lowers the body once, and merges every outer `mut`
+//! variable the body reassigned back through a `Mux` keyed on that
+//! condition. Once the condition is false the merged state stops
+//! changing, so it stays false: the iterations after the loop would have
+//! exited are no-ops. After the last iteration the condition is asserted
+//! false, so a loop that needs more than `N` iterations fails to prove
+//! instead of silently truncating.
+//!
+//! Only variable updates can be guarded this way. Assertions, indexed
+//! array writes and other side-effecting nodes would apply on every
+//! unrolled iteration, so they are rejected inside the body.
+//!
+//! Operations that constrain their operands still run on the frozen
+//! state after the exit, where they may not hold (`x / 0` once a
+//! countdown reaches zero). Each iteration therefore feeds them a
+//! harmless operand once its condition is false: divisors become 1,
+//! range-checked, decomposed and bitwise operands become 0, and dynamic
+//! array indices become 0. Comparisons and Merkle checks are not
+//! rewritten.
+
+use std::collections::HashMap;
+
+use achronyme_parser::ast::*;
+use diagnostics::SpanRange;
+use memory::FieldBackend;
+
+use super::super::helpers::to_span;
+use super::super::{CompEnvValue, ProveIrCompiler};
+use crate::error::ProveIrError;
+use crate::types::*;
+
+impl<F: FieldBackend> ProveIrCompiler<F> {
+    pub(super) fn compile_bounded_while(
+        &mut self,
+        condition: &Expr,
+        max: u64,
+        body: &Block,
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        if max > self.max_loop_iterations {
+            return Err(ProveIrError::RangeTooLarge {
+                iterations: max,
+                max: self.max_loop_iterations,
+                span: to_span(span),
+            });
+        }
+
+        // Body-local `mut` declarations are redeclared every iteration and
+        // never need merging; only variables live before the loop do.
+        let outer: Vec<String> = self.ssa_versions.keys().cloned().collect();
+
+        for _ in 0..max {
+            let cond = self.bind_while_cond(condition, span)?;
+
+            // Outer `mut` variables and their SSA names before this iteration.
+            let before: HashMap<String, String> = outer
+                .iter()
+                .filter_map(|name| match self.env.get(name.as_str()) {
+                    Some(CompEnvValue::Scalar(ssa)) => Some((name.clone(), ssa.clone())),
+                    _ => None,
+                })
+                .collect();
+
+            let saved_body = std::mem::take(&mut self.body);
+            let result = self.compile_block_as_expr(body);
+            let mut iteration = std::mem::replace(&mut self.body, saved_body);
+            result?;
+            if let Some(kind) = iteration.iter().find_map(unguarded_effect) {
+                return Err(ProveIrError::UnsupportedOperation {
+                    description: format!(
+                        "{kind} is not supported inside a bounded `while` body \
+                         (it would apply on every unrolled iteration); \
+                         only variable updates are guarded by the loop condition"
+                    ),
+                    span: to_span(span),
+                });
+            }
+            for node in &mut iteration {
+                guard_node(node, &cond);
+            }
+            self.body.extend(iteration);
+
+            // Sorted so the emitted node order is deterministic.
+            let mut changed: Vec<(&String, &String)> = before
+                .iter()
+                .filter(|(name, ssa)| {
+                    !matches!(self.env.get(name.as_str()), Some(CompEnvValue::Scalar(s)) if s == *ssa)
+                })
+                .collect();
+            changed.sort();
+            for (name, old_ssa) in changed {
+                let new_ssa = match self.env.get(name.as_str()) {
+                    Some(CompEnvValue::Scalar(s)) => s.clone(),
+                    _ => {
+                        return Err(ProveIrError::UnsupportedOperation {
+                            description: format!(
+                                "`{name}` changes shape inside a bounded `while` body"
+                            ),
+                            span: to_span(span),
+                        });
+                    }
+                };
+                self.merge_while_var(name, &cond, new_ssa, old_ssa, span)?;
+            }
+        }
+
+        // Running out of iterations with the condition still true means the
+        // bound was too small for this witness.
+        let cond = self.compile_expr(condition)?;
+        self.body.push(CircuitNode::Assert {
+            expr: CircuitExpr::UnaryOp {
+                op: CircuitUnaryOp::Not,
+                operand: Box::new(cond),
+            },
+            message: Some(format!("`while` loop did not finish within `max {max}`")),
+            span: Some(SpanRange::from(span)),
+        });
+
+        Ok(CircuitExpr::Const(FieldConst::zero()))
+    }
+
+    /// Bind the loop condition on the current state to a fresh temporary,
+    /// so the merge muxes of one iteration share a single evaluation.
+    fn bind_while_cond(
+        &mut self,
+        condition: &Expr,
+        span: &Span,
+    ) -> Result<CircuitExpr, ProveIrError> {
+        let value = self.compile_expr(condition)?;
+        let name = format!("$wcond{}", self.inline_counter);
+        self.inline_counter = self.inline_counter.wrapping_add(1);
+        self.body.push(CircuitNode::Let {
+            name: name.clone(),
+            value,
+            span: Some(SpanRange::from(span)),
+        });
+        Ok(CircuitExpr::Var(name))
+    }
+
+    /// Rebind `name` to `cond ? updated : previous` as its next SSA version.
+    fn merge_while_var(
+        &mut self,
+        name: &str,
+        cond: &CircuitExpr,
+        updated: String,
+        previous: &str,
+        span: &Span,
+    ) -> Result<(), ProveIrError> {
+        let version = self.ssa_versions[name].checked_add(1).ok_or_else(|| {
+            ProveIrError::UnsupportedOperation {
+                description: format!("SSA version overflow for `{name}` — too many reassignments"),
+                span: to_span(span),
+            }
+        })?;
+        self.ssa_versions.insert(name.to_string(), version);
+        let ssa_name = format!("{name}$v{version}");
+        self.body.push(CircuitNode::Let {
+            name: ssa_name.clone(),
+            value: CircuitExpr::Mux {
+                cond: Box::new(cond.clone()),
+                if_true: Box::new(CircuitExpr::Var(updated)),
+                if_false: Box::new(CircuitExpr::Var(previous.to_string())),
+            },
+            span: Some(SpanRange::from(span)),
+        });
+        self.env
+            .insert(name.to_string(), CompEnvValue::Scalar(ssa_name));
+        Ok(())
+    }
+}
+
+/// The first node (searching nested `if`/`for` bodies) whose effect a
+/// loop-condition mux cannot guard, described for the error message.
+fn unguarded_effect(node: &CircuitNode) -> Option<&'static str> {
+    match node {
+        CircuitNode::Let { .. } | CircuitNode::LetArray { .. } | CircuitNode::Decompose { .. } => {
+            None
+        }
+        CircuitNode::If {
+            then_body,
+            else_body,
+            ..
+        } => then_body.iter().chain(else_body).find_map(unguarded_effect),
+        CircuitNode::For { body, .. } => body.iter().find_map(unguarded_effect),
+        CircuitNode::AssertEq { .. } | CircuitNode::Assert { .. } => Some("an assertion"),
+        CircuitNode::LetIndexed { .. } => Some("an indexed array write"),
+        _ => Some("a side-effecting statement"),
+    }
+}
+
+/// Rewrite the operand checks in `node` so they hold vacuously when
+/// `active` is false. Only the node kinds [`unguarded_effect`] accepts
+/// can reach this.
+fn guard_node(node: &mut CircuitNode, active: &CircuitExpr) {
+    match node {
+        CircuitNode::Let { value, .. } => guard_expr(value, active),
+        CircuitNode::LetArray { elements, .. } => {
+            for e in elements {
+                guard_expr(e, active);
+            }
+        }
+        CircuitNode::Decompose { value, .. } => {
+            guard_expr(value, active);
+            when_active(value, active, 0);
+        }
+        CircuitNode::If {
+            cond,
+            then_body,
+            else_body,
+            ..
+        } => {
+            guard_expr(cond, active);
+            for n in then_body.iter_mut().chain(else_body) {
+                guard_node(n, active);
+            }
+        }
+        CircuitNode::For { body, .. } => {
+            for n in body {
+                guard_node(n, active);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn guard_expr(expr: &mut CircuitExpr, active: &CircuitExpr) {
+    match expr {
+        CircuitExpr::Const(_)
+        | CircuitExpr::Input(_)
+        | CircuitExpr::Capture(_)
+        | CircuitExpr::Var(_)
+        | CircuitExpr::LoopVar(_)
+        | CircuitExpr::ArrayLen(_) => {}
+        CircuitExpr::BinOp { op, lhs, rhs } => {
+            guard_expr(lhs, active);
+            guard_expr(rhs, active);
+            if *op == CircuitBinOp::Div {
+                when_active(rhs, active, 1);
+            }
+        }
+        CircuitExpr::UnaryOp { operand, .. } | CircuitExpr::Pow { base: operand, .. } => {
+            guard_expr(operand, active);
+        }
+        CircuitExpr::Comparison { lhs, rhs, .. }
+        | CircuitExpr::BoolOp { lhs, rhs, .. }
+        | CircuitExpr::PoseidonHash {
+            left: lhs,
+            right: rhs,
+        } => {
+            guard_expr(lhs, active);
+            guard_expr(rhs, active);
+        }
+        CircuitExpr::Mux {
+            cond,
+            if_true,
+            if_false,
+        } => {
+            guard_expr(cond, active);
+            guard_expr(if_true, active);
+            guard_expr(if_false, active);
+        }
+        CircuitExpr::PoseidonMany(args) => {
+            for a in args {
+                guard_expr(a, active);
+            }
+        }
+        CircuitExpr::MerkleVerify { root, leaf, .. }
+        | CircuitExpr::MerkleVerifyArity { root, leaf, .. } => {
+            guard_expr(root, active);
+            guard_expr(leaf, active);
+        }
+        CircuitExpr::RangeCheck { value, .. } => {
+            guard_expr(value, active);
+            when_active(value, active, 0);
+        }
+        CircuitExpr::ArrayIndex { index, .. } => {
+            guard_expr(index, active);
+            if !matches!(**index, CircuitExpr::LoopVar(_)) {
+                when_active(index, active, 0);
+            }
+        }
+        CircuitExpr::IntDiv { lhs, rhs, .. } | CircuitExpr::IntMod { lhs, rhs, .. } => {
+            guard_expr(lhs, active);
+            guard_expr(rhs, active);
+            when_active(lhs, active, 0);
+            when_active(rhs, active, 1);
+        }
+        CircuitExpr::BitAnd { lhs, rhs, .. }
+        | CircuitExpr::BitOr { lhs, rhs, .. }
+        | CircuitExpr::BitXor { lhs, rhs, .. } => {
+            guard_expr(lhs, active);
+            guard_expr(rhs, active);
+            when_active(lhs, active, 0);
+            when_active(rhs, active, 0);
+        }
+        CircuitExpr::BitNot { operand, .. } => {
+            guard_expr(operand, active);
+            when_active(operand, active, 0);
+        }
+        CircuitExpr::ShiftR { operand, shift, .. } | CircuitExpr::ShiftL { operand, shift, .. } => {
+            guard_expr(operand, active);
+            guard_expr(shift, active);
+            when_active(operand, active, 0);
+        }
+    }
+}
+
+/// Replace `operand` with `active ? operand : idle`. Constants already
+/// pass their checks on every iteration and are left alone.
+fn when_active(operand: &mut CircuitExpr, active: &CircuitExpr, idle: u64) {
+    if matches!(operand, CircuitExpr::Const(_)) {
+        return;
+    }
+    let value = std::mem::replace(operand, CircuitExpr::Const(FieldConst::zero()));
+    *operand = CircuitExpr::Mux {
+        cond: Box::new(active.clone()),
+        if_true: Box::new(value),
+        if_false: Box::new(CircuitExpr::Const(FieldConst::from_u64(idle))),
+    };
+}
@@ -6,14 +6,20 @@
 //!   element name (`arr[1][1]` in a `[2][2]` declaration → `"1_1"`).
 //! - [`to_span`] — adapt a parser [`Span`] to an [`OptSpan`] for IR
 //!   error reporting.
+//! - [`stmt_span`] — the source span of any statement.
 //! - [`annotation_to_ir_type`] — `TypeAnnotation::Field` → `IrType::Field`,
 //!   etc. Panics on `Int`/`String` (VM-only types are caller-rejected).
+//! - [`fold_const`] — evaluate a literal-only field expression (input
+//!   defaults, `poseidon_domain` tags).
 
-use achronyme_parser::ast::{BaseType, Block, Program, Span, TypeAnnotation};
+use achronyme_parser::ast::{BaseType, Block, Program, Span, Stmt, TypeAnnotation};
 use diagnostics::SpanRange;
 
 use ir_core::error::{span_box, OptSpan};
 use ir_core::IrType;
+use memory::{FieldBackend, FieldElement};
+
+use crate::types::{CircuitBinOp, CircuitExpr, CircuitUnaryOp};
 
 /// Convert a parsed Program into a Block (Programs don't carry their own span).
 pub(super) fn program_to_block(source: &str, program: Program) -> Block {
@@ -60,6 +66,31 @@ pub(super) fn to_span(span: &Span) -> OptSpan {
     span_box(Some(SpanRange::from(span)))
 }
 
+/// The source span of a statement.
+pub(super) fn stmt_span(stmt: &Stmt) -> &Span {
+    match stmt {
+        Stmt::LetDecl { span, .. }
+        | Stmt::MutDecl { span, .. }
+        | Stmt::Assignment { span, .. }
+        | Stmt::Print { span, .. }
+        | Stmt::Return { span, .. }
+        | Stmt::FnDecl { span, .. }
+        | Stmt::PublicDecl { span, .. }
+        | Stmt::WitnessDecl { span, .. }
+        | Stmt::OutputDecl { span, .. }
+        | Stmt::Break { span }
+        | Stmt::Continue { span }
+        | Stmt::Import { span, .. }
+        | Stmt::Export { span, .. }
+        | Stmt::SelectiveImport { span, .. }
+        | Stmt::ExportList { span, .. }
+        | Stmt::CircuitDecl { span, .. }
+        | Stmt::ImportCircuit { span, .. }
+        | Stmt::Error { span } => span,
+        Stmt::Expr(expr) => expr.span(),
+    }
+}
+
 /// Convert a TypeAnnotation to IrType.
 /// Only circuit types (Field, Bool) are valid here — Int/String are VM-only
 /// and surface as `TypeNotConstrainable` if a user writes them in a
@@ -77,3 +108,25 @@ pub(super) fn annotation_to_ir_type(
         }),
     }
 }
+
+/// Fold a field-arithmetic expression over literals, or `None` if it
+/// references anything that is only known at proving time.
+pub(super) fn fold_const<F: FieldBackend>(expr: &CircuitExpr) -> Option<FieldElement<F>> {
+    match expr {
+        CircuitExpr::Const(fc) => fc.to_field::<F>(),
+        CircuitExpr::UnaryOp {
+            op: CircuitUnaryOp::Neg,
+            operand,
+        } => Some(fold_const::<F>(operand)?.neg()),
+        CircuitExpr::BinOp { op, lhs, rhs } => {
+            let (l, r) = (fold_const::<F>(lhs)?, fold_const::<F>(rhs)?);
+            match op {
+                CircuitBinOp::Add => Some(l.add(&r)),
+                CircuitBinOp::Sub => Some(l.sub(&r)),
+                CircuitBinOp::Mul => Some(l.mul(&r)),
+                CircuitBinOp::Div => l.div(&r),
+            }
+        }
+        _ => None,
+    }
+}
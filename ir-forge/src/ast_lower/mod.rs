@@ -53,6 +53,11 @@ use resolve::{
     AnnotationKey, Availability, ModuleId, ResolvedProgram, ResolverState, SymbolId, SymbolTable,
 };
 
+/// Default cap on iterations of an unrolled `for` or bounded `while` loop,
+/// and the ceiling the CLI's `--max-unroll` accepts. Override per compile
+/// with [`ProveIrCompiler::compile_circuit_entry_with_max_unroll`].
+pub const MAX_LOOP_ITERATIONS: u64 = 1_000_000;
+
 // ---------------------------------------------------------------------------
 // Environment values
 // ---------------------------------------------------------------------------
@@ -241,6 +246,8 @@ pub struct ProveIrCompiler<F: FieldBackend = Bn254Fr> {
     public_inputs: Vec<ProveInputDecl>,
     /// Witness input declarations.
     witness_inputs: Vec<ProveInputDecl>,
+    /// Names declared with `output`, in source order.
+    outputs: Vec<String>,
     /// Directory of the source file being compiled (for resolving relative imports).
     source_dir: Option<std::path::PathBuf>,
     /// Module loader for resolving imports (shared across recursive loads).
@@ -299,6 +306,18 @@ pub struct ProveIrCompiler<F: FieldBackend = Bn254Fr> {
     /// [`resolver_root_module`] to form the annotation lookup key.
     /// `None` outside expression contexts.
     current_expr_id: Option<ExprId>,
+    /// Non-fatal diagnostics collected during the walk (e.g. statements
+    /// left unreachable by an early `return` in an inlined function).
+    /// Read back through [`ProveIrCompiler::compile_circuit_with_warnings`].
+    warnings: Vec<diagnostics::Diagnostic>,
+    /// Names `let`-bound in each open block, innermost last.
+    let_scopes: Vec<HashSet<String>>,
+    /// `let`s that re-bound a name from the same block. Carried into
+    /// `ProveIR::shadowed_lets`.
+    shadowed_lets: Vec<(String, diagnostics::SpanRange)>,
+    /// Iteration cap for literal-range `for` loops. Defaults to
+    /// [`MAX_LOOP_ITERATIONS`].
+    max_loop_iterations: u64,
     /// Phantom data for the field backend type parameter.
     _field: PhantomData<F>,
 }
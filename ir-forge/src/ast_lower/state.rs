@@ -32,6 +32,7 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
             component_bodies: HashMap::new(),
             public_inputs: Vec::new(),
             witness_inputs: Vec::new(),
+            outputs: Vec::new(),
             source_dir: None,
             module_loader: crate::module_loader::ModuleLoader::new(),
             compiling_modules: HashSet::new(),
@@ -44,6 +45,10 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
             fn_symbol_index: HashMap::new(),
             resolver_hits: Vec::new(),
             current_expr_id: None,
+            warnings: Vec::new(),
+            let_scopes: vec![HashSet::new()],
+            shadowed_lets: Vec::new(),
+            max_loop_iterations: super::MAX_LOOP_ITERATIONS,
             _field: PhantomData,
         }
     }
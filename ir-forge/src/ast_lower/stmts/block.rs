@@ -22,6 +22,7 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         match stmt {
             Stmt::PublicDecl { names, span } => self.compile_public_decl(names, span),
             Stmt::WitnessDecl { names, span } => self.compile_witness_decl(names, span),
+            Stmt::OutputDecl { name, value, span } => self.compile_output_decl(name, value, span),
             Stmt::LetDecl {
                 name,
                 type_ann,
@@ -76,8 +77,17 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
                 description: "continue is not supported in circuits".into(),
                 span: to_span(span),
             }),
+            Stmt::Return { span, .. } if self.call_stack.is_empty() => {
+                Err(ProveIrError::UnsupportedOperation {
+                    description: "return is not supported at the top level of a circuit".into(),
+                    span: to_span(span),
+                })
+            }
             Stmt::Return { span, .. } => Err(ProveIrError::UnsupportedOperation {
-                description: "return is not supported at the top level of a circuit".into(),
+                description: "return is only supported as a top-level statement of a \
+                              function body, or at the end of a branch of its final \
+                              if/else, in circuits (early returns cannot be inlined)"
+                    .into(),
                 span: to_span(span),
             }),
             Stmt::Import { path, alias, span } => self.compile_import(path, alias, span),
@@ -1,7 +1,8 @@
 use achronyme_parser::ast::*;
+use diagnostics::SpanRange;
 use memory::FieldBackend;
 
-use super::super::helpers::{annotation_to_ir_type, to_span};
+use super::super::helpers::{annotation_to_ir_type, fold_const, to_span};
 use super::super::{CompEnvValue, ProveIrCompiler};
 use crate::error::ProveIrError;
 use crate::types::*;
@@ -24,6 +25,54 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         self.compile_input_decl(names, span, false)
     }
 
+    /// `output name = expr`: a scalar public input appended after the
+    /// declared ones, constrained to equal `expr`. The name is recorded in
+    /// [`ProveIR::outputs`] so the prover computes its value instead of
+    /// supplying it.
+    pub(in crate::ast_lower) fn compile_output_decl(
+        &mut self,
+        name: &str,
+        value: &Expr,
+        span: &Span,
+    ) -> Result<(), ProveIrError> {
+        let already_declared = self
+            .public_inputs
+            .iter()
+            .chain(self.witness_inputs.iter())
+            .any(|d| d.name == name);
+        if already_declared {
+            return Err(ProveIrError::DuplicateInput {
+                name: name.to_string(),
+                span: to_span(span),
+            });
+        }
+        if self.env.contains_key(name) {
+            return Err(ProveIrError::UnsupportedOperation {
+                description: format!("output `{name}` shadows an existing binding"),
+                span: to_span(span),
+            });
+        }
+
+        // Compile before binding so `output y = y` is rejected as undeclared.
+        let rhs = self.compile_expr(value)?;
+        self.public_inputs.push(ProveInputDecl {
+            name: name.to_string(),
+            array_size: None,
+            ir_type: IrType::Field,
+            default: None,
+        });
+        self.outputs.push(name.to_string());
+        self.env
+            .insert(name.to_string(), CompEnvValue::Scalar(name.to_string()));
+        self.body.push(CircuitNode::AssertEq {
+            lhs: CircuitExpr::Var(name.to_string()),
+            rhs,
+            message: None,
+            span: Some(SpanRange::from(span)),
+        });
+        Ok(())
+    }
+
     /// Shared implementation for public/witness input declarations.
     pub(in crate::ast_lower) fn compile_input_decl(
         &mut self,
@@ -32,6 +81,18 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         is_public: bool,
     ) -> Result<(), ProveIrError> {
         for decl in names {
+            // Outputs take the public wires after every declared input, so
+            // a later public input would land between them.
+            if let Some(output) = self.outputs.first().filter(|_| is_public) {
+                return Err(ProveIrError::UnsupportedOperation {
+                    description: format!(
+                        "public input `{}` must be declared before output `{output}`",
+                        decl.name
+                    ),
+                    span: to_span(span),
+                });
+            }
+
             // Check for duplicate declarations across both public and witness inputs.
             // We check the input lists directly (not self.env) because env also
             // contains captures from the outer scope, which are legitimately
@@ -53,6 +114,32 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
                 None => IrType::Field,
             };
 
+            // Compile before binding so `witness x = x` is rejected as undeclared.
+            let default = match &decl.default {
+                None => None,
+                Some(_) if decl.array_size.is_some() => {
+                    return Err(ProveIrError::UnsupportedOperation {
+                        description: format!(
+                            "array input `{}` cannot have a default value",
+                            decl.name
+                        ),
+                        span: to_span(span),
+                    });
+                }
+                Some(expr) => match fold_const::<F>(&self.compile_expr(expr)?) {
+                    Some(value) => Some(FieldConst::from_field(value)),
+                    None => {
+                        return Err(ProveIrError::UnsupportedOperation {
+                            description: format!(
+                                "default value of input `{}` must be a compile-time constant",
+                                decl.name
+                            ),
+                            span: to_span(span),
+                        });
+                    }
+                },
+            };
+
             let inputs = if is_public {
                 &mut self.public_inputs
             } else {
@@ -64,6 +151,7 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
                     name: decl.name.clone(),
                     array_size: Some(ArraySize::Literal(size)),
                     ir_type,
+                    default: None,
                 });
                 let elem_names: Vec<String> =
                     (0..size).map(|i| format!("{}_{i}", decl.name)).collect();
@@ -78,6 +166,7 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
                     name: decl.name.clone(),
                     array_size: None,
                     ir_type,
+                    default,
                 });
                 self.env
                     .insert(decl.name.clone(), CompEnvValue::Scalar(decl.name.clone()));
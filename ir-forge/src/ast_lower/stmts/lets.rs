@@ -15,6 +15,8 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         value: &Expr,
         span: &Span,
     ) -> Result<(), ProveIrError> {
+        self.record_let_binding(name, span);
+
         // Circom template call bound to a let: `let r = T(args)(inputs)`.
         // Must run before the scalar fall-through so multi-output and
         // array-output templates can bind per-output env entries that
@@ -50,9 +52,30 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
                         .insert(name.to_string(), CompEnvValue::Array(elem_names));
                     return Ok(());
                 }
+                // map(arr, op) → element-wise LetArray, unless a user
+                // function of that name takes precedence.
+                if fn_name == "map" && !self.fn_table.contains_key(fn_name.as_str()) {
+                    let arg_vals: Vec<&Expr> = args.iter().map(|a| &a.value).collect();
+                    let elements = self.lower_map(&arg_vals, span)?;
+                    self.bind_let_array(name, elements, span);
+                    return Ok(());
+                }
+                // bits(x, n) → LetArray over a Decompose's bit wires.
+                if fn_name == "bits" && !self.fn_table.contains_key(fn_name.as_str()) {
+                    let arg_vals: Vec<&Expr> = args.iter().map(|a| &a.value).collect();
+                    let elements = self.lower_bits(&arg_vals, span)?;
+                    self.bind_let_array(name, elements, span);
+                    return Ok(());
+                }
             }
         }
 
+        // `-arr`, `arr * k` → element-wise LetArray
+        if let Some(elements) = self.compile_array_arith(value)? {
+            self.bind_let_array(name, elements, span);
+            return Ok(());
+        }
+
         // Array literal → LetArray
         if let Expr::Array {
             elements,
@@ -68,20 +91,7 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
             }
             let compiled: Result<Vec<_>, _> =
                 elements.iter().map(|e| self.compile_expr(e)).collect();
-            let compiled = compiled?;
-            let elem_names: Vec<String> =
-                (0..compiled.len()).map(|i| format!("{name}_{i}")).collect();
-            self.body.push(CircuitNode::LetArray {
-                name: name.to_string(),
-                elements: compiled,
-                span: Some(SpanRange::from(span)),
-            });
-            for ename in &elem_names {
-                self.env
-                    .insert(ename.clone(), CompEnvValue::Scalar(ename.clone()));
-            }
-            self.env
-                .insert(name.to_string(), CompEnvValue::Array(elem_names));
+            self.bind_let_array(name, compiled?, span);
             return Ok(());
         }
 
@@ -130,6 +140,41 @@ impl<F: FieldBackend> ProveIrCompiler<F> {
         Ok(())
     }
 
+    /// Note a `let` of `name` in the innermost block, recording it as
+    /// shadowed if that block already bound the name. Unrolled loop
+    /// bodies and repeated inlining revisit the same `let`, so each
+    /// source span is recorded once.
+    fn record_let_binding(&mut self, name: &str, span: &Span) {
+        let scope = self
+            .let_scopes
+            .last_mut()
+            .expect("the top-level scope is never popped");
+        if scope.insert(name.to_string()) {
+            return;
+        }
+        let entry = (name.to_string(), SpanRange::from(span));
+        if !self.shadowed_lets.contains(&entry) {
+            self.shadowed_lets.push(entry);
+        }
+    }
+
+    /// Bind `name` to a `LetArray` of `elements`, registering each
+    /// element as the scalar `{name}_{i}`.
+    fn bind_let_array(&mut self, name: &str, elements: Vec<CircuitExpr>, span: &Span) {
+        let elem_names: Vec<String> = (0..elements.len()).map(|i| format!("{name}_{i}")).collect();
+        self.body.push(CircuitNode::LetArray {
+            name: name.to_string(),
+            elements,
+            span: Some(SpanRange::from(span)),
+        });
+        for ename in &elem_names {
+            self.env
+                .insert(ename.clone(), CompEnvValue::Scalar(ename.clone()));
+        }
+        self.env
+            .insert(name.to_string(), CompEnvValue::Array(elem_names));
+    }
+
     /// Try to compile a function call that returns an array.
     ///
     /// Returns `Some(array_name_in_env)` if the call was handled as an
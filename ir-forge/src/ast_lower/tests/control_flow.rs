@@ -228,3 +228,89 @@ fn for_with_accumulator_circuit() {
         .iter()
         .any(|n| matches!(n, CircuitNode::AssertEq { .. })));
 }
+
+#[test]
+fn bounded_while_unrolls_guarded_iterations() {
+    let ir = compile_circuit(
+        "public out\nwitness n\nmut x = n\nwhile x != 0 max 3 { x = x - 1 }\nassert_eq(x, out)",
+    )
+    .unwrap();
+    let conds = ir
+        .body
+        .iter()
+        .filter(|n| matches!(n, CircuitNode::Let { name, .. } if name.starts_with("$wcond")))
+        .count();
+    assert_eq!(conds, 3, "one condition per unrolled iteration");
+    // Each iteration merges `x` through a Mux on that iteration's condition.
+    let merges: Vec<&str> = ir
+        .body
+        .iter()
+        .filter_map(|n| match n {
+            CircuitNode::Let {
+                name,
+                value: CircuitExpr::Mux { .. },
+                ..
+            } => Some(name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(merges, ["x$v2", "x$v4", "x$v6"]);
+    assert!(
+        ir.body.iter().any(|n| matches!(
+            n,
+            CircuitNode::Assert { message: Some(m), .. } if m.contains("max 3")
+        )),
+        "the exhausted condition must be asserted false"
+    );
+}
+
+#[test]
+fn bounded_while_guards_divisors_by_the_condition() {
+    let ir = compile_circuit(
+        "public out\nwitness n\nmut x = n\nmut acc = 0\n\
+         while x != 0 max 2 { acc = acc + 12 / x\nx = x - 1 }\nassert_eq(acc, out)",
+    )
+    .unwrap();
+    let divisors: Vec<&CircuitExpr> = ir
+        .body
+        .iter()
+        .filter_map(|n| match n {
+            CircuitNode::Let {
+                value: CircuitExpr::BinOp { rhs, .. },
+                ..
+            } => match rhs.as_ref() {
+                CircuitExpr::BinOp {
+                    op: CircuitBinOp::Div,
+                    rhs,
+                    ..
+                } => Some(rhs.as_ref()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    assert_eq!(divisors.len(), 2, "one division per unrolled iteration");
+    for d in divisors {
+        match d {
+            CircuitExpr::Mux { cond, if_false, .. } => {
+                assert!(matches!(cond.as_ref(), CircuitExpr::Var(c) if c.starts_with("$wcond")));
+                assert_eq!(**if_false, CircuitExpr::Const(FieldConst::one()));
+            }
+            other => panic!("divisor not guarded by the loop condition: {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn bounded_while_rejects_body_assertions() {
+    let err =
+        compile_circuit("witness n\nmut x = n\nwhile x != 0 max 3 { assert_eq(x, x)\nx = x - 1 }")
+            .unwrap_err();
+    assert!(err.to_string().contains("bounded `while` body"), "{err}");
+}
+
+#[test]
+fn while_without_max_is_unbounded() {
+    let err = compile_circuit("witness n\nmut x = n\nwhile x != 0 { x = x - 1 }").unwrap_err();
+    assert!(matches!(err, ProveIrError::UnboundedLoop { .. }), "{err}");
+}
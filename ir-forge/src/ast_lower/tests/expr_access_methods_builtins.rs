@@ -213,6 +213,18 @@ fn builtin_poseidon() {
     assert!(matches!(expr, CircuitExpr::PoseidonHash { .. }));
 }
 
+#[test]
+fn builtin_poseidon_domain_requires_constant_tag() {
+    let scope = [
+        ("t", CompEnvValue::Scalar("t".into())),
+        ("a", CompEnvValue::Scalar("a".into())),
+        ("b", CompEnvValue::Scalar("b".into())),
+    ];
+    compile_expr_with_scope("poseidon_domain(3 + 4, a, b)", &scope).unwrap();
+    let err = compile_expr_with_scope("poseidon_domain(t, a, b)", &scope).unwrap_err();
+    assert!(err.to_string().contains("compile-time constant"), "{err}");
+}
+
 #[test]
 fn builtin_poseidon_many() {
     let scope = [
@@ -252,6 +264,292 @@ fn builtin_range_check() {
     );
 }
 
+#[test]
+fn builtin_assert_all_eq_binds_first_argument_once() {
+    let ir = compile_circuit("public x\nwitness y\nwitness z\nassert_all_eq(x * 2, y, z)").unwrap();
+    let lets: Vec<&str> = ir
+        .body
+        .iter()
+        .filter_map(|n| match n {
+            CircuitNode::Let { name, .. } => Some(name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(lets.len(), 1, "{:?}", ir.body);
+    let asserts: Vec<(&CircuitExpr, &CircuitExpr)> = ir
+        .body
+        .iter()
+        .filter_map(|n| match n {
+            CircuitNode::AssertEq { lhs, rhs, .. } => Some((lhs, rhs)),
+            _ => None,
+        })
+        .collect();
+    let first = CircuitExpr::Var(lets[0].into());
+    assert_eq!(
+        asserts,
+        vec![
+            (&first, &CircuitExpr::Var("y".into())),
+            (&first, &CircuitExpr::Var("z".into())),
+        ]
+    );
+}
+
+#[test]
+fn builtin_assert_all_eq_needs_two_arguments() {
+    let err = compile_circuit("public x\nassert_all_eq(x)").unwrap_err();
+    assert!(err.to_string().contains("at least 2 arguments"), "{err}");
+}
+
+#[test]
+fn builtin_assert_in_set_asserts_product_is_zero() {
+    let ir = compile_circuit("public x\nassert_in_set(x, [1, 2, 3])").unwrap();
+    let asserts: Vec<(&CircuitExpr, &CircuitExpr)> = ir
+        .body
+        .iter()
+        .filter_map(|n| match n {
+            CircuitNode::AssertEq { lhs, rhs, .. } => Some((lhs, rhs)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(asserts.len(), 1, "{:?}", ir.body);
+    let (product, zero) = asserts[0];
+    assert_eq!(zero, &CircuitExpr::Const(FieldConst::zero()));
+    assert!(
+        matches!(
+            product,
+            CircuitExpr::BinOp {
+                op: CircuitBinOp::Mul,
+                ..
+            }
+        ),
+        "{product:?}"
+    );
+}
+
+#[test]
+fn builtin_assert_in_set_rejects_scalar_set() {
+    let err = compile_circuit("public x\nassert_in_set(x, 3)").unwrap_err();
+    assert!(err.to_string().contains("expected array"), "{err}");
+}
+
+#[test]
+fn builtin_popcount_sums_decomposed_bits() {
+    let ir = compile_circuit("public out\nwitness x\nassert_eq(popcount(x, 3), out)").unwrap();
+    let bits = ir
+        .body
+        .iter()
+        .find_map(|n| match n {
+            CircuitNode::Decompose { name, num_bits, .. } => Some((name.clone(), *num_bits)),
+            _ => None,
+        })
+        .expect("popcount emits a Decompose");
+    assert_eq!(bits.1, 3);
+    let sum = ir
+        .body
+        .iter()
+        .find_map(|n| match n {
+            CircuitNode::AssertEq { lhs, .. } => Some(lhs),
+            _ => None,
+        })
+        .unwrap();
+    let var = |i: u32| Box::new(CircuitExpr::Var(format!("{}_{i}", bits.0)));
+    assert_eq!(
+        sum,
+        &CircuitExpr::BinOp {
+            op: CircuitBinOp::Add,
+            lhs: Box::new(CircuitExpr::BinOp {
+                op: CircuitBinOp::Add,
+                lhs: var(0),
+                rhs: var(1),
+            }),
+            rhs: var(2),
+        }
+    );
+}
+
+#[test]
+fn builtin_is_power_of_two_asserts_one_set_bit() {
+    let ir = compile_circuit("witness x\nis_power_of_two(x, 8)").unwrap();
+    assert!(ir
+        .body
+        .iter()
+        .any(|n| matches!(n, CircuitNode::Decompose { num_bits: 8, .. })));
+    assert!(ir.body.iter().any(|n| matches!(
+        n,
+        CircuitNode::AssertEq { rhs, .. } if *rhs == CircuitExpr::Const(FieldConst::one())
+    )));
+}
+
+#[test]
+fn builtin_popcount_rejects_bad_bit_count() {
+    for bits in ["0", "254"] {
+        let err = compile_circuit(&format!("witness x\npopcount(x, {bits})")).unwrap_err();
+        assert!(err.to_string().contains("bit count"), "{err}");
+    }
+}
+
+#[test]
+fn builtin_bits_binds_decomposed_bits_as_array() {
+    let ir =
+        compile_circuit("public b\nwitness x\nlet bs = bits(x, 4)\nassert_eq(bs[3], b)").unwrap();
+    assert!(ir
+        .body
+        .iter()
+        .any(|n| matches!(n, CircuitNode::Decompose { num_bits: 4, .. })));
+    let elements = ir
+        .body
+        .iter()
+        .find_map(|n| match n {
+            CircuitNode::LetArray { name, elements, .. } if name == "bs" => Some(elements),
+            _ => None,
+        })
+        .expect("bits binds a LetArray");
+    assert_eq!(elements.len(), 4);
+}
+
+#[test]
+fn builtin_bits_rejects_scalar_use() {
+    let err = compile_circuit("public b\nwitness x\nassert_eq(bits(x, 4), b)").unwrap_err();
+    assert!(err.to_string().contains("returns an array"), "{err}");
+}
+
+#[test]
+fn builtin_bool_xor_enforces_both_operands() {
+    let ir = compile_circuit("public out\nwitness a\nwitness b\nassert_eq(bool_xor(a, b), out)")
+        .unwrap();
+    let enforced: Vec<&CircuitExpr> = ir
+        .body
+        .iter()
+        .filter_map(|n| match n {
+            CircuitNode::AssertEq {
+                lhs:
+                    CircuitExpr::BinOp {
+                        op: CircuitBinOp::Mul,
+                        lhs,
+                        ..
+                    },
+                rhs: CircuitExpr::Const(zero),
+                ..
+            } if *zero == FieldConst::zero() => Some(lhs.as_ref()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        enforced,
+        [&CircuitExpr::Var("a".into()), &CircuitExpr::Var("b".into())]
+    );
+}
+
+#[test]
+fn builtin_bool_ops_skip_enforcing_constants() {
+    let ir = compile_circuit("witness a\nassert_eq(bool_and(a, 1), bool_not(0))").unwrap();
+    let asserts = ir
+        .body
+        .iter()
+        .filter(|n| matches!(n, CircuitNode::AssertEq { .. }))
+        .count();
+    assert_eq!(
+        asserts, 2,
+        "one enforcement for `a`, one assert_eq: {:?}",
+        ir.body
+    );
+}
+
+#[test]
+fn builtin_bool_ops_reject_non_boolean_constant() {
+    let err = compile_circuit("witness a\nbool_or(a, 2)").unwrap_err();
+    assert!(err.to_string().contains("must be boolean"), "{err}");
+}
+
+#[test]
+fn builtin_assert_bool_emits_boolean_enforcement() {
+    let ir = compile_circuit("witness flag\nassert_bool(flag)").unwrap();
+    let Some(CircuitNode::AssertEq { lhs, rhs, .. }) = ir.body.first() else {
+        panic!("expected an AssertEq: {:?}", ir.body);
+    };
+    let flag = || Box::new(CircuitExpr::Var("flag".into()));
+    assert_eq!(
+        lhs,
+        &CircuitExpr::BinOp {
+            op: CircuitBinOp::Mul,
+            lhs: flag(),
+            rhs: Box::new(CircuitExpr::BinOp {
+                op: CircuitBinOp::Sub,
+                lhs: flag(),
+                rhs: Box::new(CircuitExpr::Const(FieldConst::one())),
+            }),
+        }
+    );
+    assert_eq!(rhs, &CircuitExpr::Const(FieldConst::zero()));
+}
+
+#[test]
+fn builtin_dot_folds_constant_arrays() {
+    let expr = compile_single_expr("dot([1, 2, 3], [4, 5, 6])").unwrap();
+    assert_eq!(
+        crate::ast_lower::helpers::fold_const::<Bn254Fr>(&expr),
+        Some(FieldElement::<Bn254Fr>::from_u64(32))
+    );
+}
+
+#[test]
+fn builtin_dot_rejects_length_mismatch() {
+    let err = compile_single_expr("dot([1, 2, 3], [4, 5])").unwrap_err();
+    assert!(
+        matches!(
+            err,
+            ProveIrError::ArrayLengthMismatch {
+                expected: 3,
+                got: 2,
+                ..
+            }
+        ),
+        "{err}"
+    );
+}
+
+#[test]
+fn builtin_map_square_lowers_each_element_once() {
+    let ir = compile_circuit("witness a\nlet ys = map([a + 1, 2], \"square\")").unwrap();
+    let bound = ir
+        .body
+        .iter()
+        .find_map(|n| match n {
+            CircuitNode::Let { name, value, .. } => Some((name.clone(), value)),
+            _ => None,
+        })
+        .expect("non-trivial element is bound");
+    assert!(
+        matches!(
+            bound.1,
+            CircuitExpr::BinOp {
+                op: CircuitBinOp::Add,
+                ..
+            }
+        ),
+        "{:?}",
+        bound.1
+    );
+    let Some(CircuitNode::LetArray { elements, .. }) = ir.body.last() else {
+        panic!("expected LetArray: {:?}", ir.body);
+    };
+    let x = Box::new(CircuitExpr::Var(bound.0));
+    assert_eq!(
+        elements[0],
+        CircuitExpr::BinOp {
+            op: CircuitBinOp::Mul,
+            lhs: x.clone(),
+            rhs: x,
+        }
+    );
+}
+
+#[test]
+fn builtin_map_outside_let_rejected() {
+    let err = compile_circuit("witness xs[2]\nassert_eq(map(xs, \"neg\"), 0)").unwrap_err();
+    assert!(err.to_string().contains("returns an array"), "{err}");
+}
+
 #[test]
 fn builtin_poseidon_wrong_arity() {
     let scope = [("a", CompEnvValue::Scalar("a".into()))];
@@ -20,6 +20,40 @@ fn negative_number() {
     );
 }
 
+#[test]
+fn signed_number_literal_is_single_field_constant() {
+    // The parser never produces a signed `Number`, but synthesized ASTs
+    // carrying an i64 (`value.to_string()`) can.
+    let (program, errors) = parse_program("3");
+    assert!(errors.is_empty(), "parse errors: {errors:?}");
+    let Stmt::Expr(expr) = program.stmts[0].clone() else {
+        panic!("expected expression statement");
+    };
+    let compile_as = |literal: &str| {
+        let mut expr = expr.clone();
+        let Expr::Number { value, .. } = &mut expr else {
+            panic!("expected number literal");
+        };
+        *value = literal.into();
+        ProveIrCompiler::<Bn254Fr>::new()
+            .compile_expr(&expr)
+            .unwrap()
+    };
+    assert_eq!(
+        compile_as("-3"),
+        CircuitExpr::Const(FieldConst::from_field(FieldElement::<Bn254Fr>::from_i64(
+            -3
+        )))
+    );
+    // -(2^70) doesn't fit in i64: its magnitude is parsed and negated.
+    let two_pow_70 =
+        FieldElement::<Bn254Fr>::from_u64(1 << 35).mul(&FieldElement::from_u64(1 << 35));
+    assert_eq!(
+        compile_as("-1180591620717411303424"),
+        CircuitExpr::Const(FieldConst::from_field(two_pow_70.neg()))
+    );
+}
+
 #[test]
 fn field_literal_decimal() {
     let expr = compile_single_expr("0p42").unwrap();
@@ -32,6 +66,30 @@ fn field_literal_hex() {
     assert_eq!(expr, CircuitExpr::Const(FieldConst::from_u64(255)));
 }
 
+#[test]
+fn field_literal_at_modulus_rejected() {
+    // The BN254 modulus itself: reducing it would silently yield 0.
+    let err =
+        compile_single_expr("0px30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001")
+            .unwrap_err();
+    assert!(err.to_string().contains("field modulus"), "{err}");
+}
+
+#[test]
+fn bigint_literal_200_bit_hex() {
+    let hex = "d0123456789abcdef0123456789abcdef0123456789abcdef0";
+    let expr = compile_single_expr(&format!("0i256x{hex}")).unwrap();
+    let fe = FieldElement::<Bn254Fr>::from_hex_str(hex).unwrap();
+    assert_eq!(expr, CircuitExpr::Const(FieldConst::from_field(fe)));
+}
+
+#[test]
+fn bigint_literal_above_modulus_rejected() {
+    // 2^256 - 1 fits a 512-bit BigInt but not the BN254 field.
+    let err = compile_single_expr(&format!("0i512x{}", "f".repeat(64))).unwrap_err();
+    assert!(err.to_string().contains("field modulus"), "{err}");
+}
+
 #[test]
 fn bool_true() {
     let expr = compile_single_expr("true").unwrap();
@@ -256,6 +314,20 @@ fn pow_variable_exponent_rejected() {
     assert!(matches!(err, ProveIrError::UnsupportedOperation { .. }));
 }
 
+#[test]
+fn pow_negative_exponent_rejected() {
+    let scope = [("x", CompEnvValue::Scalar("x".into()))];
+    let err = compile_expr_with_scope("x ^ -2", &scope).unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            ProveIrError::UnsupportedOperation { description, span: Some(_) }
+                if description == "negative exponents are not supported in circuits"
+        ),
+        "got: {err:?}"
+    );
+}
+
 // --- Rejections ---
 
 #[test]
@@ -61,6 +61,53 @@ fn fn_with_return() {
         .any(|n| matches!(n, CircuitNode::AssertEq { .. })));
 }
 
+#[test]
+fn fn_early_return_warns_about_unreachable_code() {
+    let source = crate::test_utils::wrap_flat_to_circuit(
+        "public out\nwitness x\nwitness y\n\
+         fn add(a, b) {\nreturn a + b\nlet dead = a * b\n}\n\
+         assert_eq(add(x, y) + add(y, x), out)",
+    );
+    let (ir, warnings) =
+        ProveIrCompiler::<memory::Bn254Fr>::compile_circuit_with_warnings(&source, None).unwrap();
+    assert!(!ir
+        .body
+        .iter()
+        .any(|n| matches!(n, CircuitNode::Let { name, .. } if name == "dead")));
+    // Two call sites, one source position: reported once.
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].message, "unreachable code");
+}
+
+#[test]
+fn fn_final_if_else_returns_are_branch_values() {
+    let ir = compile_circuit(
+        "public out\nwitness x\n\
+         fn f(a) {\nif a == 0 { return 1 } else { return a * 2 }\n}\n\
+         assert_eq(f(x), out)",
+    )
+    .unwrap();
+    assert!(ir.body.iter().any(|n| matches!(
+        n,
+        CircuitNode::AssertEq {
+            lhs: CircuitExpr::Mux { .. },
+            ..
+        }
+    )));
+}
+
+#[test]
+fn fn_conditional_return_errors() {
+    let err =
+        compile_circuit("witness x\nfn f(a) {\nif a == 0 { return 1 }\na\n}\nassert_eq(f(x), x)")
+            .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("top-level statement of a function body"),
+        "{err}"
+    );
+}
+
 #[test]
 fn fn_wrong_arity_errors() {
     let err = compile_circuit("public x\nfn f(a, b) { a + b }\nassert_eq(f(x), x)").unwrap_err();
@@ -27,6 +27,61 @@ fn stmt_public_decl_array() {
     assert_eq!(ir.public_inputs[0].array_size, Some(ArraySize::Literal(3)));
 }
 
+#[test]
+fn stmt_output_decl_appends_public_input_after_inputs() {
+    let ir = compile_circuit("public a\nwitness x\noutput y = x * x").unwrap();
+    let names: Vec<&str> = ir.public_inputs.iter().map(|d| d.name.as_str()).collect();
+    assert_eq!(names, ["a", "y"]);
+    assert_eq!(ir.outputs, ["y"]);
+    match ir.body.last() {
+        Some(CircuitNode::AssertEq { lhs, .. }) => {
+            assert_eq!(*lhs, CircuitExpr::Var("y".into()));
+        }
+        other => panic!("expected AssertEq, got {other:?}"),
+    }
+    ir.validate().unwrap();
+}
+
+#[test]
+fn stmt_output_decl_must_follow_public_inputs() {
+    let err = compile_circuit("circuit c(a: Public) {\noutput y = a * a\npublic b\n}").unwrap_err();
+    assert!(
+        err.to_string().contains("must be declared before output"),
+        "{err}"
+    );
+    // Witnesses sit after every public wire, so they may follow outputs.
+    let ir = compile_circuit(
+        "circuit c(a: Public) {\npublic b\noutput y = a * b\nwitness x\nassert_eq(x, y)\n}",
+    )
+    .unwrap();
+    let names: Vec<&str> = ir.public_inputs.iter().map(|d| d.name.as_str()).collect();
+    assert_eq!(names, ["a", "b", "y"]);
+}
+
+#[test]
+fn stmt_output_decl_rejects_duplicate_name() {
+    assert!(compile_circuit("witness x\noutput x = x").is_err());
+    assert!(compile_circuit("witness x\nlet y = x\noutput y = x").is_err());
+}
+
+#[test]
+fn stmt_input_default_folds_to_constant() {
+    let ir = compile_circuit("circuit t(a: Public) {\nwitness k = 2 * 3 + 1\nassert_eq(a, k)\n}")
+        .unwrap();
+    assert_eq!(ir.public_inputs[0].default, None);
+    assert_eq!(ir.witness_inputs[0].default, Some(FieldConst::from_u64(7)));
+    ir.validate().unwrap();
+}
+
+#[test]
+fn stmt_input_default_rejects_non_constant_and_arrays() {
+    let err = compile_circuit("circuit t(x: Witness) {\nwitness k = x + 1\n}").unwrap_err();
+    assert!(err.to_string().contains("compile-time constant"), "{err}");
+    assert!(compile_circuit("circuit t() {\nwitness k = k\n}").is_err());
+    let err = compile_circuit("circuit t() {\nwitness path[2] = 0\n}").unwrap_err();
+    assert!(err.to_string().contains("cannot have a default"), "{err}");
+}
+
 #[test]
 fn annotation_to_ir_type_rejects_int_directly() {
     // Defense-in-depth (Gap 2.4 hardening): the parser already rejects
@@ -88,6 +143,25 @@ fn stmt_let_scalar() {
     assert!(matches!(&ir.body[0], CircuitNode::Let { name, .. } if name == "y"));
 }
 
+#[test]
+fn stmt_let_records_shadowing_in_same_block() {
+    // The top-level `x` is shadowed; each unrolled loop iteration is a
+    // fresh block, and the `t` shadowing inside it is recorded once.
+    let ir = compile_circuit(
+        "public out\nwitness a\n\
+         let x = a\nlet x = x + 1\n\
+         for i in 0..3 {\n    let t = x * a\n    let t = t + i\n    assert_eq(t, t)\n}\n\
+         assert_eq(x, out)",
+    )
+    .unwrap();
+    let shadowed: Vec<&str> = ir
+        .shadowed_lets
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect();
+    assert_eq!(shadowed, ["x", "t"]);
+}
+
 #[test]
 fn stmt_let_array() {
     let ir = compile_circuit("let arr = [1, 2, 3]").unwrap();
@@ -100,6 +174,51 @@ fn stmt_let_array() {
     }
 }
 
+#[test]
+fn stmt_let_array_negated_and_scaled() {
+    let ir = compile_circuit("let xs = [1, 2]\nlet ns = -xs\nlet ds = 3 * xs").unwrap();
+    let elements = |target: &str| {
+        ir.body
+            .iter()
+            .find_map(|n| match n {
+                CircuitNode::LetArray { name, elements, .. } if name == target => {
+                    Some(elements.clone())
+                }
+                _ => None,
+            })
+            .unwrap()
+    };
+    let x = |i: usize| Box::new(CircuitExpr::Var(format!("xs_{i}")));
+    assert_eq!(
+        elements("ns"),
+        (0..2)
+            .map(|i| CircuitExpr::UnaryOp {
+                op: CircuitUnaryOp::Neg,
+                operand: x(i),
+            })
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        elements("ds"),
+        (0..2)
+            .map(|i| CircuitExpr::BinOp {
+                op: CircuitBinOp::Mul,
+                lhs: x(i),
+                rhs: Box::new(CircuitExpr::Const(FieldConst::from_u64(3))),
+            })
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn stmt_array_times_array_rejected() {
+    let err = compile_circuit("let xs = [1, 2]\nlet ys = xs * [3, 4]").unwrap_err();
+    assert!(
+        err.to_string().contains("cannot multiply two arrays"),
+        "{err}"
+    );
+}
+
 #[test]
 fn stmt_empty_array_rejected() {
     let err = compile_circuit("let arr = []").unwrap_err();
@@ -221,7 +221,8 @@ fn walk_expr(
             // The value is in constraint context, bits is a literal u32
             walk_expr(value, in_structural, structural, constraint);
         }
-        CircuitExpr::MerkleVerify { root, leaf, .. } => {
+        CircuitExpr::MerkleVerify { root, leaf, .. }
+        | CircuitExpr::MerkleVerifyArity { root, leaf, .. } => {
             walk_expr(root, in_structural, structural, constraint);
             walk_expr(leaf, in_structural, structural, constraint);
         }
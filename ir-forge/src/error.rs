@@ -203,6 +203,8 @@ pub enum ProveIrError {
         length: usize,
         span: OptSpan,
     },
+    /// A division whose divisor is the constant zero.
+    DivisionByZero { span: OptSpan },
     /// Array length mismatch.
     ArrayLengthMismatch {
         expected: usize,
@@ -286,7 +288,8 @@ impl fmt::Display for ProveIrError {
             Self::UnboundedLoop { .. } => write!(
                 f,
                 "unbounded loops (while/forever) are not allowed in circuits \
-                 (all iterations must be known at compile time)"
+                 (all iterations must be known at compile time; \
+                 bound a `while` with `while cond max N {{ ... }}`)"
             ),
             Self::WrongArgumentCount {
                 name,
@@ -306,6 +309,9 @@ impl fmt::Display for ProveIrError {
                 f,
                 "index {index} out of bounds for `{name}` (length {length})"
             ),
+            Self::DivisionByZero { .. } => {
+                write!(f, "division by zero: the divisor is the constant 0")
+            }
             Self::ArrayLengthMismatch { expected, got, .. } => {
                 write!(f, "array length mismatch: expected {expected}, got {got}")
             }
@@ -368,6 +374,7 @@ impl ProveIrError {
             | Self::UnboundedLoop { span }
             | Self::WrongArgumentCount { span, .. }
             | Self::IndexOutOfBounds { span, .. }
+            | Self::DivisionByZero { span }
             | Self::ArrayLengthMismatch { span, .. }
             | Self::TypeMismatch { span, .. }
             | Self::AnnotationMismatch { span, .. }
@@ -152,6 +152,9 @@ impl<F: FieldBackend> ExtendedIrProgram<F> {
             var_types: self.var_types,
             input_spans: self.input_spans,
             var_spans: self.var_spans,
+            input_defaults: HashMap::new(),
+            input_arrays: HashMap::new(),
+            shadowed_lets: Vec::new(),
         };
         for node in self.body {
             match node {
@@ -51,7 +51,7 @@ use super::ExtendedSink;
 use crate::error::ProveIrError;
 use crate::extended::ExtendedInstruction;
 use crate::extended_program::ExtendedIrProgram;
-use crate::types::ProveIR;
+use crate::types::{ArraySize, ProveIR};
 use ir_core::IrProgram;
 
 pub use bundles::{LysisDrainBundle, LysisSinkBundle};
@@ -66,6 +66,23 @@ use trace::{lysis_drain_trace, lysis_drain_trace_enabled};
 use walk::{assemble_extended, run_walk};
 
 impl ProveIR {
+    /// Carry declared input defaults and array sizes onto an instantiated
+    /// program. Both are read when the input map is checked at witness
+    /// time, not metadata, so lean programs keep them. Shadowed `let`s
+    /// come along for the taint analysis.
+    fn with_input_defaults<F: FieldBackend>(&self, mut program: IrProgram<F>) -> IrProgram<F> {
+        program.shadowed_lets = self.shadowed_lets.clone();
+        for decl in self.public_inputs.iter().chain(&self.witness_inputs) {
+            if let Some(value) = decl.default.as_ref().and_then(|d| d.to_field::<F>()) {
+                program.set_input_default(decl.name.clone(), value);
+            }
+            if let Some(ArraySize::Literal(size)) = decl.array_size {
+                program.set_input_array(decl.name.clone(), size);
+            }
+        }
+        program
+    }
+
     /// Extended-IR variant of [`Self::instantiate_lysis`] — produces a
     /// `Vec<ExtendedInstruction<F>>` body wrapped in an
     /// [`ExtendedIrProgram<F>`] for the Lysis lifter.
@@ -171,7 +188,7 @@ impl ProveIR {
         captures: &HashMap<String, FieldElement<F>>,
     ) -> Result<IrProgram<F>, LysisInstantiateError> {
         let extended = self.instantiate_extended::<F>(captures)?;
-        lower_extended_through_lysis(extended)
+        lower_extended_through_lysis(extended).map(|p| self.with_input_defaults(p))
     }
 
     /// Lysis variant of [`Self::instantiate_lysis`] with public-output
@@ -184,7 +201,7 @@ impl ProveIR {
         output_names: &HashSet<String>,
     ) -> Result<IrProgram<F>, LysisInstantiateError> {
         let extended = self.instantiate_with_outputs_extended::<F>(captures, output_names)?;
-        lower_extended_through_lysis(extended)
+        lower_extended_through_lysis(extended).map(|p| self.with_input_defaults(p))
     }
 
     /// Lean materializing variant of [`Self::instantiate_lysis`]: same
@@ -205,10 +222,10 @@ impl ProveIR {
         // executor cable; the stream is byte-identical. A walk that
         // emits symbolic nodes returns None and re-runs via the cable.
         if let Some(program) = instantiate_direct_lean::<F>(self, captures, None)? {
-            return Ok(program);
+            return Ok(self.with_input_defaults(program));
         }
         let extended = self.instantiate_extended_lean::<F>(captures)?;
-        lower_extended_through_lysis_lean(extended)
+        lower_extended_through_lysis_lean(extended).map(|p| self.with_input_defaults(p))
     }
 
     /// Lean materializing variant of
@@ -225,10 +242,10 @@ impl ProveIR {
         // extended entry's dispatch).
         let names = (!output_names.is_empty()).then_some(output_names);
         if let Some(program) = instantiate_direct_lean::<F>(self, captures, names)? {
-            return Ok(program);
+            return Ok(self.with_input_defaults(program));
         }
         let extended = self.instantiate_with_outputs_extended_lean::<F>(captures, output_names)?;
-        lower_extended_through_lysis_lean(extended)
+        lower_extended_through_lysis_lean(extended).map(|p| self.with_input_defaults(p))
     }
 
     /// Lean sink counterpart of
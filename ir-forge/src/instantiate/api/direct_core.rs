@@ -8,8 +8,8 @@
 //! [`DirectInternState::feed_plain`] performs, per instruction, the
 //! exact `intern_pure` / `fresh_id` / `emit_effect` call sequence the
 //! executor performs for the walker's lowering of it — including the
-//! walker's desugarings (`Not`, `And`, `Or`, `IsNeq`, `IsLe`,
-//! `IsLeBounded`, `Assert`) and its u8 bit-width guards, surfaced
+//! walker's desugarings (`Not`, `And`, `Or`, `IsGt`, `IsNeq`, `IsLe`,
+//! `IsGe`, `IsLeBounded`, `Assert`) and its u8 bit-width guards, surfaced
 //! through the same [`WalkError`] variants — so the interner assigns
 //! identical `NodeId`s and the materialized stream is byte-identical.
 //!
@@ -259,6 +259,14 @@ impl DirectInternState {
                     rhs: r,
                 });
             }
+            // Walker desugar: IsGt(x, y) = IsLt(y, x).
+            Instruction::IsGt { result, lhs, rhs } => {
+                self.pure_bin(sink, result, rhs, lhs, |l, r| InstructionKind::IsLt {
+                    result: NodeId::PLACEHOLDER,
+                    lhs: l,
+                    rhs: r,
+                });
+            }
             // Walker desugar: IsNeq(x, y) = Sub(one, IsEq(x, y)); `one`
             // interns before the IsEq (the walker resolves it first).
             Instruction::IsNeq { result, lhs, rhs } => {
@@ -296,6 +304,24 @@ impl DirectInternState {
                 });
                 self.bind(result, id);
             }
+            // Walker desugar: IsGe(x, y) = Sub(one, IsLt(x, y)).
+            Instruction::IsGe { result, lhs, rhs } => {
+                let one = self.one_id(sink);
+                let (Some(l), Some(r)) = (self.resolve(lhs), self.resolve(rhs)) else {
+                    return;
+                };
+                let lt = sink.intern_pure(InstructionKind::IsLt {
+                    result: NodeId::PLACEHOLDER,
+                    lhs: l,
+                    rhs: r,
+                });
+                let id = sink.intern_pure(InstructionKind::Sub {
+                    result: NodeId::PLACEHOLDER,
+                    lhs: one,
+                    rhs: lt,
+                });
+                self.bind(result, id);
+            }
             Instruction::IsLtBounded {
                 result,
                 lhs,
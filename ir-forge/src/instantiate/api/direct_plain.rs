@@ -21,8 +21,8 @@ pub(super) fn direct_plain_drain_enabled() -> bool {
 /// chunk is handed to `chunk_consumer` at seal time; the partial tail
 /// drains at `finalize`. Sharing `feed_plain` is what keeps this path
 /// byte-identical to the walker (and to the non-draining direct sink):
-/// the desugarings (`Not`, `And`, `Or`, `IsNeq`, `IsLe`,
-/// `IsLeBounded`, `Assert`) and u8 bit-width guards live in one place.
+/// the desugarings (`Not`, `And`, `Or`, `IsGt`, `IsNeq`, `IsLe`,
+/// `IsGe`, `IsLeBounded`, `Assert`) and u8 bit-width guards live in one place.
 ///
 /// Returns the number of Plain instructions fed, or the first
 /// [`WalkError`] surfaced through `feed_plain` (an undefined SSA
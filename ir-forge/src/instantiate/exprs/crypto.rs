@@ -93,24 +93,8 @@ impl<'a, F: FieldBackend> Instantiator<'a, F> {
         let root_var = self.emit_expr(root)?;
         let leaf_var = self.emit_expr(leaf)?;
 
-        let path_elems = match self.env.get(path) {
-            Some(InstEnvValue::Array(elems)) => elems.clone(),
-            _ => {
-                return Err(ProveIrError::UnsupportedOperation {
-                    description: format!("merkle_verify path `{path}` is not an array"),
-                    span: None,
-                });
-            }
-        };
-        let idx_elems = match self.env.get(indices) {
-            Some(InstEnvValue::Array(elems)) => elems.clone(),
-            _ => {
-                return Err(ProveIrError::UnsupportedOperation {
-                    description: format!("merkle_verify indices `{indices}` is not an array"),
-                    span: None,
-                });
-            }
-        };
+        let path_elems = self.merkle_array("merkle_verify", "path", path)?;
+        let idx_elems = self.merkle_array("merkle_verify", "indices", indices)?;
 
         if path_elems.len() != idx_elems.len() {
             return Err(ProveIrError::ArrayLengthMismatch {
@@ -159,4 +143,153 @@ impl<'a, F: FieldBackend> Instantiator<'a, F> {
         });
         Ok(v)
     }
+
+    /// `arity`-ary Merkle verification. Per level, `indices` holds a
+    /// one-hot block of `arity` selectors (the current node's position)
+    /// and `path` the `arity - 1` siblings in order. Slot `j` of the
+    /// node is `current` when `h[j]` is set, otherwise the sibling that
+    /// lands there: `s[j - 1]` if the current node sits before `j`,
+    /// else `s[j]`. Each selector feeds a Mux (which enforces it boolean)
+    /// and the block is constrained to sum to one.
+    pub(super) fn emit_merkle_verify_arity(
+        &mut self,
+        root: &CircuitExpr,
+        leaf: &CircuitExpr,
+        path: &str,
+        indices: &str,
+        arity: u32,
+    ) -> Result<SsaVar, ProveIrError> {
+        let root_var = self.emit_expr(root)?;
+        let leaf_var = self.emit_expr(leaf)?;
+        let path_elems = self.merkle_array("merkle_verify_arity", "path", path)?;
+        let idx_elems = self.merkle_array("merkle_verify_arity", "indices", indices)?;
+
+        let arity = arity as usize;
+        let siblings = arity - 1;
+        if path_elems.len() % siblings != 0 {
+            return Err(ProveIrError::UnsupportedOperation {
+                description: format!(
+                    "merkle_verify_arity path `{path}` has {} elements, \
+                     not a multiple of arity - 1 = {siblings}",
+                    path_elems.len()
+                ),
+                span: None,
+            });
+        }
+        let depth = path_elems.len() / siblings;
+        if idx_elems.len() != depth * arity {
+            return Err(ProveIrError::ArrayLengthMismatch {
+                expected: depth * arity,
+                got: idx_elems.len(),
+                span: None,
+            });
+        }
+
+        let one = self.emit_const(FieldElement::<F>::one());
+        let mut current = leaf_var;
+        for (sibs, sel) in path_elems.chunks(siblings).zip(idx_elems.chunks(arity)) {
+            // One-hot: exactly one selector set in this level's block.
+            let mut sum = sel[0];
+            for &h in &sel[1..] {
+                let v = self.fresh_var();
+                self.push_inst(Instruction::Add {
+                    result: v,
+                    lhs: sum,
+                    rhs: h,
+                });
+                sum = v;
+            }
+            let v = self.fresh_var();
+            self.push_inst(Instruction::AssertEq {
+                result: v,
+                lhs: sum,
+                rhs: one,
+                message: None,
+            });
+
+            let mut slots = Vec::with_capacity(arity);
+            // `before` = 1 once the current node has been placed to the
+            // left of slot j (prefix sum of the one-hot selectors).
+            let mut before = None;
+            for (j, &h) in sel.iter().enumerate() {
+                let sibling = match (j, before) {
+                    (0, _) => sibs[0],
+                    (j, _) if j == siblings => sibs[j - 1],
+                    (j, Some(b)) => {
+                        let v = self.fresh_var();
+                        self.push_inst(Instruction::Mux {
+                            result: v,
+                            cond: b,
+                            if_true: sibs[j - 1],
+                            if_false: sibs[j],
+                        });
+                        v
+                    }
+                    (_, None) => unreachable!("prefix sum is set after slot 0"),
+                };
+                let slot = self.fresh_var();
+                self.push_inst(Instruction::Mux {
+                    result: slot,
+                    cond: h,
+                    if_true: current,
+                    if_false: sibling,
+                });
+                slots.push(slot);
+
+                if j == siblings {
+                    break;
+                }
+                before = Some(match before {
+                    None => h,
+                    Some(b) => {
+                        let v = self.fresh_var();
+                        self.push_inst(Instruction::Add {
+                            result: v,
+                            lhs: b,
+                            rhs: h,
+                        });
+                        v
+                    }
+                });
+            }
+
+            // Node hash: left-fold like poseidon_many.
+            let mut acc = slots[0];
+            for &next in &slots[1..] {
+                let v = self.fresh_var();
+                self.push_inst(Instruction::PoseidonHash {
+                    result: v,
+                    left: acc,
+                    right: next,
+                });
+                acc = v;
+            }
+            current = acc;
+        }
+
+        let v = self.fresh_var();
+        self.push_inst(Instruction::AssertEq {
+            result: v,
+            lhs: current,
+            rhs: root_var,
+            message: None,
+        });
+        Ok(v)
+    }
+
+    /// Resolve a Merkle `path`/`indices` argument to its element wires.
+    fn merkle_array(
+        &self,
+        builtin: &str,
+        role: &str,
+        name: &str,
+    ) -> Result<Vec<SsaVar>, ProveIrError> {
+        match self.env.get(name) {
+            Some(InstEnvValue::Array(elems)) => Ok(elems.clone()),
+            _ => Err(ProveIrError::UnsupportedOperation {
+                description: format!("{builtin} {role} `{name}` is not an array"),
+                span: None,
+            }),
+        }
+    }
 }
@@ -60,6 +60,13 @@ impl<'a, F: FieldBackend> Instantiator<'a, F> {
                 path,
                 indices,
             } => self.emit_merkle_verify(root, leaf, path, indices),
+            CircuitExpr::MerkleVerifyArity {
+                root,
+                leaf,
+                path,
+                indices,
+                arity,
+            } => self.emit_merkle_verify_arity(root, leaf, path, indices, *arity),
             CircuitExpr::ArrayIndex { array, index } => self.emit_array_index(array, index),
             CircuitExpr::ArrayLen(name) => self.emit_array_len(name),
             CircuitExpr::Pow { base, exp } => {
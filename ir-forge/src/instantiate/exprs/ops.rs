@@ -23,6 +23,15 @@ impl<'a, F: FieldBackend> Instantiator<'a, F> {
         // small enough to survive large circuits (SHA-256).
         let lv = self.const_value_of(l);
         let rv = self.const_value_of(r);
+        // A constant-zero divisor has no inverse: reject it here with the
+        // node's source location instead of at R1CS time. Checked on the
+        // emitted operand, so a divisor that only folds to 0 here (`let k =
+        // 1 - 1`) is caught too.
+        if op == CircuitBinOp::Div && rv.is_some_and(|b| b.is_zero()) {
+            return Err(ProveIrError::DivisionByZero {
+                span: self.current_span.clone().map(Box::new),
+            });
+        }
         if let (Some(a), Some(b)) = (lv, rv) {
             let folded = match op {
                 CircuitBinOp::Add => Some(a.add(&b)),
@@ -119,7 +128,8 @@ impl<'a, F: FieldBackend> Instantiator<'a, F> {
         }
 
         // Lower at emission time: Not / And / Or / IsNeq / IsLe never
-        // appear in instantiate output. The Lysis lifter's Walker
+        // appear in instantiate output. (IsGt / IsGe do, so `>` and `>=`
+        // keep their operand order until the walker desugars them.) The Lysis lifter's Walker
         // desugars them to the same primitive forms (Sub, Mul, Add and
         // Mul minus Sub, IsEq and Sub, IsLt and Sub) at lift time, so
         // emitting them here would make the legacy and Lysis pipelines
@@ -168,12 +178,12 @@ impl<'a, F: FieldBackend> Instantiator<'a, F> {
                 v
             }
             CircuitCmpOp::Gt => {
-                // a > b → IsLt(b, a)
+                // Kept as written; the Lysis walker lowers it to IsLt(b, a).
                 let v = self.fresh_var();
-                self.push_inst(Instruction::IsLt {
+                self.push_inst(Instruction::IsGt {
                     result: v,
-                    lhs: r,
-                    rhs: l,
+                    lhs: l,
+                    rhs: r,
                 });
                 v
             }
@@ -200,15 +210,15 @@ impl<'a, F: FieldBackend> Instantiator<'a, F> {
                 self.lower_not(lt)
             }
             CircuitCmpOp::Ge => {
-                // a >= b → 1 - IsLt(a, b)
-                let lt = self.fresh_var();
-                self.push_inst(Instruction::IsLt {
-                    result: lt,
+                // Kept as written; the Lysis walker lowers it to
+                // 1 - IsLt(a, b).
+                let v = self.fresh_var();
+                self.push_inst(Instruction::IsGe {
+                    result: v,
                     lhs: l,
                     rhs: r,
                 });
-                self.set_type(lt, IrType::Bool);
-                self.lower_not(lt)
+                v
             }
         };
         self.set_type(v, IrType::Bool);
@@ -273,7 +273,8 @@ impl<'a, F: FieldBackend> Instantiator<'a, F> {
             CircuitExpr::PoseidonHash { .. }
             | CircuitExpr::PoseidonMany(_)
             | CircuitExpr::RangeCheck { .. }
-            | CircuitExpr::MerkleVerify { .. } => Err(ProveIrError::UnsupportedOperation {
+            | CircuitExpr::MerkleVerify { .. }
+            | CircuitExpr::MerkleVerifyArity { .. } => Err(ProveIrError::UnsupportedOperation {
                 description: "gadget expression not allowed in const eval".into(),
                 span: None,
             }),
@@ -4,7 +4,7 @@ use super::super::utils::fe_to_usize;
 use super::super::{InstEnvValue, Instantiator};
 use crate::error::ProveIrError;
 use crate::types::*;
-use ir_core::{Instruction, SsaVar, Visibility};
+use ir_core::{Instruction, IrType, SsaVar, Visibility};
 
 impl<'a, F: FieldBackend> Instantiator<'a, F> {
     pub(in crate::instantiate) fn emit_node(
@@ -64,6 +64,20 @@ impl<'a, F: FieldBackend> Instantiator<'a, F> {
             CircuitNode::AssertEq {
                 lhs, rhs, message, ..
             } => {
+                // `x * (x - 1) == 0` on a wire that is already Bool-typed
+                // (a Bool input, a comparison, or an earlier enforcement)
+                // adds nothing, so the `bool_*` builtins can enforce their
+                // operands on every call without paying for it twice.
+                let enforced = match bool_enforcement_operand(lhs, rhs) {
+                    Some(name) => {
+                        let x = self.emit_expr(&CircuitExpr::Var(name.to_string()))?;
+                        if self.get_type(x) == Some(IrType::Bool) {
+                            return Ok(());
+                        }
+                        Some(x)
+                    }
+                    None => None,
+                };
                 let l = self.emit_expr(lhs)?;
                 let r = self.emit_expr(rhs)?;
                 let v = self.fresh_var();
@@ -73,6 +87,9 @@ impl<'a, F: FieldBackend> Instantiator<'a, F> {
                     rhs: r,
                     message: message.clone(),
                 });
+                if let Some(x) = enforced {
+                    self.set_type(x, IrType::Bool);
+                }
             }
             CircuitNode::Assert { expr, message, .. } => {
                 let operand = self.emit_expr(expr)?;
@@ -344,3 +361,34 @@ impl<'a, F: FieldBackend> Instantiator<'a, F> {
         Ok(())
     }
 }
+
+/// The variable `x` when an `AssertEq` is the boolean enforcement
+/// `x * (x - 1) == 0` (the form `bool_prop` recognizes).
+fn bool_enforcement_operand<'e>(lhs: &'e CircuitExpr, rhs: &CircuitExpr) -> Option<&'e str> {
+    if !matches!(rhs, CircuitExpr::Const(c) if *c == FieldConst::zero()) {
+        return None;
+    }
+    let CircuitExpr::BinOp {
+        op: CircuitBinOp::Mul,
+        lhs: x,
+        rhs: minus_one,
+    } = lhs
+    else {
+        return None;
+    };
+    let CircuitExpr::Var(name) = x.as_ref() else {
+        return None;
+    };
+    match minus_one.as_ref() {
+        CircuitExpr::BinOp {
+            op: CircuitBinOp::Sub,
+            lhs: y,
+            rhs: one,
+        } if matches!(y.as_ref(), CircuitExpr::Var(n) if n == name)
+            && matches!(one.as_ref(), CircuitExpr::Const(c) if *c == FieldConst::one()) =>
+        {
+            Some(name)
+        }
+        _ => None,
+    }
+}
@@ -252,6 +252,7 @@ impl<'a, F: FieldBackend> Instantiator<'a, F> {
             | CircuitExpr::PoseidonMany(_)
             | CircuitExpr::RangeCheck { .. }
             | CircuitExpr::MerkleVerify { .. }
+            | CircuitExpr::MerkleVerifyArity { .. }
             | CircuitExpr::LoopVar(_) => false,
         }
     }
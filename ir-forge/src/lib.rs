@@ -35,7 +35,9 @@ pub mod types;
 #[cfg(any(test, feature = "test-support"))]
 pub mod test_utils;
 
-pub use ast_lower::{OuterResolverState, OuterScope, OuterScopeEntry, ProveIrCompiler};
+pub use ast_lower::{
+    OuterResolverState, OuterScope, OuterScopeEntry, ProveIrCompiler, MAX_LOOP_ITERATIONS,
+};
 pub use circom_interop::{
     CircomCallable, CircomDispatchError, CircomInputLayout, CircomInstantiation,
     CircomLibraryHandle, CircomTemplateOutput, CircomTemplateSignature,
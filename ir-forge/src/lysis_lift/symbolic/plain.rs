@@ -76,6 +76,13 @@ pub(super) fn emit_plain<F: FieldBackend>(
         Instruction::IsLe { result, lhs, rhs } => {
             bin_op(OpTag::IsLe, *result, *lhs, *rhs, tree, ssa_to_idx)
         }
+        // The walker lowers IsGt/IsGe to swapped IsLt/IsLe forms.
+        Instruction::IsGt { result, lhs, rhs } => {
+            bin_op(OpTag::IsLt, *result, *rhs, *lhs, tree, ssa_to_idx)
+        }
+        Instruction::IsGe { result, lhs, rhs } => {
+            bin_op(OpTag::IsLe, *result, *rhs, *lhs, tree, ssa_to_idx)
+        }
         Instruction::IsLtBounded {
             result,
             lhs,
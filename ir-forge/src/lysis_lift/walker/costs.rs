@@ -168,7 +168,7 @@ pub(super) fn extinst_summary<F: FieldBackend>(inst: &ExtendedInstruction<F>) ->
 ///
 /// - Most arithmetic ops cost 1 reg (the destination).
 /// - `Or` desugars to Add+Mul+Sub ⇒ 3 regs.
-/// - `IsNeq`/`IsLe`/`IsLeBounded` desugar to two ops ⇒ 2 regs.
+/// - `IsNeq`/`IsLe`/`IsGe`/`IsLeBounded` desugar to two ops ⇒ 2 regs.
 /// - `Decompose(num_bits)` allocates `num_bits` consecutive slots.
 /// - `WitnessCall` allocates one reg per output.
 /// - `LoopUnroll` body bodies are emitted *once* (regs shared across
@@ -303,6 +303,7 @@ pub(super) fn reg_cost_of_instruction<F: FieldBackend>(inst: &Instruction<F>) ->
         | Instruction::Mux { .. }
         | Instruction::IsEq { .. }
         | Instruction::IsLt { .. }
+        | Instruction::IsGt { .. }
         | Instruction::IsLtBounded { .. }
         | Instruction::PoseidonHash { .. }
         | Instruction::IntDiv { .. }
@@ -310,7 +311,10 @@ pub(super) fn reg_cost_of_instruction<F: FieldBackend>(inst: &Instruction<F>) ->
 
         // Multi-step desugarings.
         Instruction::Or { .. } => 3,
-        Instruction::IsNeq { .. } | Instruction::IsLe { .. } | Instruction::IsLeBounded { .. } => 2,
+        Instruction::IsNeq { .. }
+        | Instruction::IsLe { .. }
+        | Instruction::IsGe { .. }
+        | Instruction::IsLeBounded { .. } => 2,
 
         // Variable-cost ops.
         Instruction::Decompose { num_bits, .. } => *num_bits,
@@ -335,6 +339,7 @@ pub(super) fn instruction_needs_one<F: FieldBackend>(inst: &Instruction<F>) -> b
             | Instruction::Assert { .. }
             | Instruction::IsNeq { .. }
             | Instruction::IsLe { .. }
+            | Instruction::IsGe { .. }
             | Instruction::IsLeBounded { .. }
     )
 }
@@ -240,6 +240,8 @@ pub(super) fn record_last_use_in_instruction<F: FieldBackend>(
         | Instruction::IsLt { lhs, rhs, .. }
         | Instruction::IsNeq { lhs, rhs, .. }
         | Instruction::IsLe { lhs, rhs, .. }
+        | Instruction::IsGt { lhs, rhs, .. }
+        | Instruction::IsGe { lhs, rhs, .. }
         | Instruction::IsLtBounded { lhs, rhs, .. }
         | Instruction::IsLeBounded { lhs, rhs, .. }
         | Instruction::AssertEq { lhs, rhs, .. }
@@ -397,6 +399,8 @@ pub(crate) fn collect_operands_instruction<F: FieldBackend>(
         | Instruction::IsLt { lhs, rhs, .. }
         | Instruction::IsNeq { lhs, rhs, .. }
         | Instruction::IsLe { lhs, rhs, .. }
+        | Instruction::IsGt { lhs, rhs, .. }
+        | Instruction::IsGe { lhs, rhs, .. }
         | Instruction::IsLtBounded { lhs, rhs, .. }
         | Instruction::IsLeBounded { lhs, rhs, .. }
         | Instruction::AssertEq { lhs, rhs, .. }
@@ -207,6 +207,17 @@ impl<F: FieldBackend> Walker<F> {
                 });
                 self.bind(*result, dst);
             }
+            // Desugar: IsGt(x,y) = IsLt(y,x).
+            Instruction::IsGt { result, lhs, rhs } => {
+                let (l, r) = self.bin(*lhs, *rhs)?;
+                let dst = self.allocator.alloc()?;
+                self.push_op(Opcode::EmitIsLt {
+                    dst,
+                    lhs: r,
+                    rhs: l,
+                });
+                self.bind(*result, dst);
+            }
             // Desugar: IsNeq(x,y) = 1 - IsEq(x,y).
             Instruction::IsNeq { result, lhs, rhs } => {
                 let one = self.one()?;
@@ -243,6 +254,24 @@ impl<F: FieldBackend> Walker<F> {
                 });
                 self.bind(*result, dst);
             }
+            // Desugar: IsGe(x,y) = 1 - IsLt(x,y).
+            Instruction::IsGe { result, lhs, rhs } => {
+                let one = self.one()?;
+                let (l, r) = self.bin(*lhs, *rhs)?;
+                let lt = self.allocator.alloc()?;
+                self.push_op(Opcode::EmitIsLt {
+                    dst: lt,
+                    lhs: l,
+                    rhs: r,
+                });
+                let dst = self.allocator.alloc()?;
+                self.push_op(Opcode::EmitSub {
+                    dst,
+                    lhs: one,
+                    rhs: lt,
+                });
+                self.bind(*result, dst);
+            }
             // Preserve the bitwidth hint so the R1CS backend can emit the
             // same single `(bits + 1)` comparison decomposition as circom's
             // `LessThan(bits)`, instead of falling back to full-field ranges.
@@ -130,7 +130,7 @@ pub(super) fn placeholder_opcodes<F: FieldBackend>(
             lhs: 0,
             rhs: 0,
         }),
-        Instruction::IsLt { .. } => bin(Opcode::EmitIsLt {
+        Instruction::IsLt { .. } | Instruction::IsGt { .. } => bin(Opcode::EmitIsLt {
             dst: 0,
             lhs: 0,
             rhs: 0,
@@ -196,7 +196,10 @@ pub(super) fn placeholder_opcodes<F: FieldBackend>(
             Opcode::EmitAssertEq { lhs: 0, rhs: 0 }
         }),
 
-        Instruction::IsNeq { .. } | Instruction::IsLe { .. } | Instruction::IsLeBounded { .. } => {
+        Instruction::IsNeq { .. }
+        | Instruction::IsLe { .. }
+        | Instruction::IsGe { .. }
+        | Instruction::IsLeBounded { .. } => {
             let cmp = match inst {
                 Instruction::IsNeq { .. } => Opcode::EmitIsEq {
                     dst: 0,
@@ -231,6 +231,44 @@ fn desugars_is_le_to_is_lt_reversed_plus_sub() {
     assert_eq!(subs, 1);
 }
 
+#[test]
+fn desugars_is_gt_and_is_ge_to_is_lt() {
+    // IsGt(x,y) = IsLt(y, x); IsGe(x,y) = 1 - IsLt(x, y).
+    let body = vec![
+        plain(Instruction::Input {
+            result: ssa(0),
+            name: "a".into(),
+            visibility: IrVisibility::Witness,
+        }),
+        plain(Instruction::Input {
+            result: ssa(1),
+            name: "b".into(),
+            visibility: IrVisibility::Witness,
+        }),
+        plain(Instruction::IsGt {
+            result: ssa(2),
+            lhs: ssa(0),
+            rhs: ssa(1),
+        }),
+        plain(Instruction::IsGe {
+            result: ssa(3),
+            lhs: ssa(0),
+            rhs: ssa(1),
+        }),
+    ];
+    let out = run(&body);
+    let lts = out
+        .iter()
+        .filter(|i| matches!(i, lysis::InstructionKind::IsLt { .. }))
+        .count();
+    let subs = out
+        .iter()
+        .filter(|i| matches!(i, lysis::InstructionKind::Sub { .. }))
+        .count();
+    assert_eq!(lts, 2);
+    assert_eq!(subs, 1);
+}
+
 #[test]
 fn preserves_is_lt_bounded_bitwidth_hint() {
     let body = vec![
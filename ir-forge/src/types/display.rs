@@ -269,6 +269,16 @@ impl fmt::Display for CircuitExpr {
                 path,
                 indices,
             } => write!(f, "merkle_verify({root}, {leaf}, {path}, {indices})"),
+            CircuitExpr::MerkleVerifyArity {
+                root,
+                leaf,
+                path,
+                indices,
+                arity,
+            } => write!(
+                f,
+                "merkle_verify_arity({root}, {leaf}, {path}, {indices}, {arity})"
+            ),
             CircuitExpr::ArrayIndex { array, index } => write!(f, "{array}[{index}]"),
             CircuitExpr::ArrayLen(name) => write!(f, "{name}.len()"),
             CircuitExpr::Pow { base, exp } => write!(f, "({base} ^ {exp})"),
@@ -78,6 +78,17 @@ pub enum CircuitExpr {
         path: String,
         indices: String,
     },
+    /// Merkle membership verification over an `arity`-ary tree. Each
+    /// level consumes `arity - 1` siblings from `path` and a one-hot
+    /// block of `arity` selectors from `indices` placing the current
+    /// node among them.
+    MerkleVerifyArity {
+        root: Box<CircuitExpr>,
+        leaf: Box<CircuitExpr>,
+        path: String,
+        indices: String,
+        arity: u32,
+    },
 
     /// Array indexing: `array[index]`
     ArrayIndex {
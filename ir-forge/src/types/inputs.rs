@@ -8,12 +8,17 @@
 use ir_core::IrType;
 use serde::{Deserialize, Serialize};
 
+use super::FieldConst;
+
 /// An input declaration (public or witness).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProveInputDecl {
     pub name: String,
     pub array_size: Option<ArraySize>,
     pub ir_type: IrType,
+    /// Value used when the prover supplies none (`witness x = 5`).
+    /// Scalar inputs only; adds no constraints.
+    pub default: Option<FieldConst>,
 }
 
 /// Array size: either a compile-time literal or a captured value.
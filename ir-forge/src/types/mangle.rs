@@ -275,6 +275,19 @@ pub fn mangle_expr(
             path: mangle_name(prefix, path),
             indices: mangle_name(prefix, indices),
         },
+        CircuitExpr::MerkleVerifyArity {
+            root,
+            leaf,
+            path,
+            indices,
+            arity,
+        } => CircuitExpr::MerkleVerifyArity {
+            root: Box::new(mangle_expr(root, prefix, param_subs)),
+            leaf: Box::new(mangle_expr(leaf, prefix, param_subs)),
+            path: mangle_name(prefix, path),
+            indices: mangle_name(prefix, indices),
+            arity: *arity,
+        },
 
         // Array
         CircuitExpr::ArrayIndex { array, index } => CircuitExpr::ArrayIndex {
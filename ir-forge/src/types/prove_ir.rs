@@ -22,6 +22,10 @@ pub struct ProveIR {
     pub public_inputs: Vec<ProveInputDecl>,
     /// Variables only the prover knows (auto-inferred or explicit).
     pub witness_inputs: Vec<ProveInputDecl>,
+    /// Names of the `output` declarations, in source order. Each is also
+    /// a scalar entry at the tail of `public_inputs`; its value is
+    /// computed by the prover rather than supplied.
+    pub outputs: Vec<String>,
     /// Template parameters — values from outer scope that affect circuit
     /// structure or constraints.
     pub captures: Vec<CaptureDef>,
@@ -36,6 +40,11 @@ pub struct ProveIR {
     /// small `ComponentCall` in `body` rather than an inlined copy.
     /// Empty when no deferred component instances were emitted.
     pub component_bodies: std::collections::HashMap<String, Vec<CircuitNode>>,
+    /// `let` bindings that re-bound a name already `let`-bound in the same
+    /// block, in source order. Diagnostics only: copied onto
+    /// `IrProgram::shadowed_lets` at instantiation and not serialized.
+    #[serde(skip)]
+    pub shadowed_lets: Vec<(String, diagnostics::SpanRange)>,
 }
 
 /// Magic header bytes for serialized ProveIR.
@@ -47,7 +56,10 @@ pub(crate) const PROVE_IR_MAGIC: &[u8; 4] = b"ACHP";
 /// v4: added PrimeId byte after version (multi-prime support).
 /// v5: CircuitExpr::Const uses FieldConst ([u8;32] canonical LE) instead of FieldElement.
 /// v6: added `component_bodies` table to ProveIR (deferred component instances).
-pub const PROVE_IR_FORMAT_VERSION: u8 = 6;
+/// v7: added CircuitExpr::MerkleVerifyArity (shifts later variant tags).
+/// v8: added `outputs` field to ProveIR.
+/// v9: added `default` field to ProveInputDecl.
+pub const PROVE_IR_FORMAT_VERSION: u8 = 9;
 
 /// Maximum allowed size for deserialized ProveIR data (64 MB).
 /// Prevents allocation bombs from crafted length prefixes.
@@ -86,6 +98,19 @@ impl ProveIR {
             }
         }
 
+        // Every output must name a scalar public input.
+        for name in &self.outputs {
+            let is_scalar_public = self
+                .public_inputs
+                .iter()
+                .any(|d| &d.name == name && d.array_size.is_none());
+            if !is_scalar_public {
+                return Err(format!(
+                    "invalid ProveIR: output `{name}` is not a scalar public input"
+                ));
+            }
+        }
+
         // Body keys a `ComponentCall` is allowed to reference.
         let body_keys: std::collections::HashSet<&str> =
             self.component_bodies.keys().map(|s| s.as_str()).collect();
@@ -120,10 +145,13 @@ impl ProveIR {
         }
         let version = bytes[4];
         let (prime_id, payload) = match version {
-            3 | 4 => {
+            3 | 4 | 6 | 7 | 8 => {
                 // Legacy v3: no PrimeId byte, assume BN254
                 // Legacy v4: has PrimeId byte, uses FieldElement<Bn254Fr> layout
-                // Both use the old serialization format — require recompile.
+                // Legacy v6: CircuitExpr variant tags predate MerkleVerifyArity
+                // Legacy v7: ProveIR predates the `outputs` field
+                // Legacy v8: ProveInputDecl predates the `default` field
+                // All use an old serialization format — require recompile.
                 return Err(format!(
                     "ProveIR format version {version} is no longer supported \
                      (current: {PROVE_IR_FORMAT_VERSION}). Please recompile the source."
@@ -268,6 +296,9 @@ fn validate_expr(expr: &CircuitExpr) -> Result<(), String> {
                 "invalid ProveIR: range_check bits={bits} (must be 1..={MAX_RANGE_CHECK_BITS})"
             ))
         }
+        CircuitExpr::MerkleVerifyArity { arity, .. } if *arity < 2 => Err(format!(
+            "invalid ProveIR: merkle_verify_arity arity={arity} (must be >= 2)"
+        )),
         // Recurse into sub-expressions
         CircuitExpr::BinOp { lhs, rhs, .. }
         | CircuitExpr::Comparison { lhs, rhs, .. }
@@ -296,7 +327,8 @@ fn validate_expr(expr: &CircuitExpr) -> Result<(), String> {
             Ok(())
         }
         CircuitExpr::RangeCheck { value, .. } => validate_expr(value),
-        CircuitExpr::MerkleVerify { root, leaf, .. } => {
+        CircuitExpr::MerkleVerify { root, leaf, .. }
+        | CircuitExpr::MerkleVerifyArity { root, leaf, .. } => {
             validate_expr(root)?;
             validate_expr(leaf)
         }
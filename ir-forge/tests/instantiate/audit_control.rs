@@ -24,6 +24,7 @@ fn audit_for_array_empty() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![CircuitNode::For {
             var: "x".into(),
@@ -33,6 +34,7 @@ fn audit_for_array_empty() {
         }],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     // Need "arr" in env as an empty array — not possible from the
     // public API without a LetArray node. Use a LetArray with empty elements instead.
@@ -49,6 +51,7 @@ fn audit_instantiate_rejects_huge_capture_loop() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![CaptureDef {
             name: "n".into(),
             usage: CaptureUsage::StructureOnly,
@@ -64,6 +67,7 @@ fn audit_instantiate_rejects_huge_capture_loop() {
         }],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let captures: HashMap<String, FieldElement<Bn254Fr>> = [(
         "n".to_string(),
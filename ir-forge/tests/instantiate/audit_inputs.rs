@@ -8,12 +8,15 @@ fn audit_bool_input_gets_range_check() {
             name: "flag".into(),
             array_size: None,
             ir_type: IrType::Bool,
+            default: None,
         }],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let ir = prove_ir
         .instantiate_lysis::<Bn254Fr>(&HashMap::new())
@@ -37,12 +40,15 @@ fn audit_bool_array_input_gets_range_checks() {
             name: "flags".into(),
             array_size: Some(ArraySize::Literal(3)),
             ir_type: IrType::Bool,
+            default: None,
         }],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let ir = prove_ir
         .instantiate_lysis::<Bn254Fr>(&HashMap::new())
@@ -33,6 +33,7 @@ fn instantiate_missing_capture_error() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![CaptureDef {
             name: "secret".into(),
             usage: CaptureUsage::CircuitInput,
@@ -40,6 +41,7 @@ fn instantiate_missing_capture_error() {
         body: vec![],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let result = prove_ir.instantiate_lysis::<Bn254Fr>(&HashMap::new());
     assert!(result.is_err(), "should fail with missing capture");
@@ -55,8 +57,10 @@ fn audit_capture_both_is_witness_input() {
             name: "out".into(),
             array_size: None,
             ir_type: IrType::Field,
+            default: None,
         }],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![CaptureDef {
             name: "n".into(),
             usage: CaptureUsage::Both,
@@ -82,6 +86,7 @@ fn audit_capture_both_is_witness_input() {
         ],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let captures: HashMap<String, FieldElement<Bn254Fr>> =
         [("n".to_string(), FieldElement::<Bn254Fr>::from_u64(3))]
@@ -114,6 +119,7 @@ fn audit_both_capture_emits_assert_eq() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![CaptureDef {
             name: "n".into(),
             usage: CaptureUsage::Both,
@@ -129,6 +135,7 @@ fn audit_both_capture_emits_assert_eq() {
         }],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let captures: HashMap<String, FieldElement<Bn254Fr>> =
         [("n".to_string(), FieldElement::<Bn254Fr>::from_u64(3))]
@@ -2,8 +2,8 @@ pub(super) use std::collections::HashMap;
 
 pub(super) use ir_core::{Instruction, IrProgram, IrType, SsaVar, Visibility};
 pub(super) use ir_forge::{
-    ArraySize, CaptureDef, CaptureUsage, CircuitExpr, CircuitNode, ForRange, ProveIR,
-    ProveInputDecl, ProveIrError,
+    ArraySize, CaptureDef, CaptureUsage, CircuitExpr, CircuitNode, ExtendedInstruction, ForRange,
+    ProveIR, ProveInputDecl, ProveIrError,
 };
 pub(super) use memory::{Bn254Fr, FieldElement};
 
@@ -31,6 +31,33 @@ fn instantiate_comparison_gt_desugars_to_lt() {
     assert!(has_is_lt, "a > b should desugar to IsLt(b, a)");
 }
 
+#[test]
+fn instantiate_comparison_gt_ge_keep_operand_order() {
+    // Before the walker runs, `>` and `>=` are IsGt/IsGe over the
+    // operands as written (`a` is SsaVar(0), `b` SsaVar(1)).
+    let program =
+        ir_forge::test_utils::compile_circuit("public a\npublic b\nassert(a > b)\nassert(a >= b)")
+            .unwrap();
+    let extended = program
+        .instantiate_extended::<Bn254Fr>(&HashMap::new())
+        .unwrap();
+    let plain: Vec<&Instruction> = extended
+        .body
+        .iter()
+        .filter_map(|i| match i {
+            ExtendedInstruction::Plain(inst) => Some(inst),
+            _ => None,
+        })
+        .collect();
+    let (a, b) = (SsaVar(0), SsaVar(1));
+    assert!(plain
+        .iter()
+        .any(|i| matches!(i, Instruction::IsGt { lhs, rhs, .. } if *lhs == a && *rhs == b)));
+    assert!(plain
+        .iter()
+        .any(|i| matches!(i, Instruction::IsGe { lhs, rhs, .. } if *lhs == a && *rhs == b)));
+}
+
 // --- Boolean ops ---
 
 #[test]
@@ -59,6 +86,44 @@ fn instantiate_bool_lowers_and_to_mul() {
     assert_eq!(iseqs, 2, "two `== 1` comparisons");
 }
 
+#[test]
+fn instantiate_enforces_each_bool_operand_once() {
+    // assert_bool(a), then bool_and and bool_not on `a` and `b`: one
+    // `x * (x - 1) == 0` per wire, plus the two assert_eqs.
+    let ir = compile_and_instantiate(
+        "public out\npublic neg\nwitness a\nwitness b\n\
+         assert_bool(a)\n\
+         assert_eq(bool_and(a, b), out)\n\
+         assert_eq(bool_not(b), neg)",
+    );
+    let asserts = ir
+        .instructions
+        .iter()
+        .filter(|i| matches!(i, Instruction::AssertEq { .. }))
+        .count();
+    assert_eq!(asserts, 4, "{:?}", ir.instructions);
+}
+
+#[test]
+fn instantiate_rejects_constant_zero_divisor_with_span() {
+    let program = ir_forge::test_utils::compile_circuit(
+        "public out\nwitness a\nlet k = 1 - 1\nassert_eq(a / k, out)",
+    )
+    .unwrap();
+    let err = program
+        .instantiate_lysis::<Bn254Fr>(&HashMap::new())
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            ir_forge::LysisInstantiateError::Instantiate(ProveIrError::DivisionByZero {
+                span: Some(_)
+            })
+        ),
+        "expected DivisionByZero with a span, got: {err}"
+    );
+}
+
 // --- Function inlining ---
 
 #[test]
@@ -21,10 +21,12 @@ fn adversarial_wrong_version() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     }
     .to_bytes(PrimeId::Bn254)
     .unwrap();
@@ -44,12 +46,15 @@ fn adversarial_truncated_payload() {
             name: "x".into(),
             array_size: None,
             ir_type: IrType::Field,
+            default: None,
         }],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     }
     .to_bytes(PrimeId::Bn254)
     .unwrap();
@@ -83,6 +88,7 @@ fn adversarial_invalid_field_const_rejected_at_instantiation() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![CircuitNode::Let {
             name: "x".into(),
@@ -91,6 +97,7 @@ fn adversarial_invalid_field_const_rejected_at_instantiation() {
         }],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
 
     // Serialization + deserialization succeeds (FieldConst is just bytes)
@@ -117,6 +124,7 @@ fn adversarial_poseidon_many_empty_rejected() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![CircuitNode::Expr {
             expr: CircuitExpr::PoseidonMany(vec![]),
@@ -124,6 +132,7 @@ fn adversarial_poseidon_many_empty_rejected() {
         }],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     // Serialize directly with bincode (bypass to_bytes header)
     let payload = bincode::serialize(&ir).unwrap();
@@ -146,6 +155,7 @@ fn adversarial_range_check_zero_bits_rejected() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![CircuitNode::Expr {
             expr: CircuitExpr::RangeCheck {
@@ -156,6 +166,7 @@ fn adversarial_range_check_zero_bits_rejected() {
         }],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let payload = bincode::serialize(&ir).unwrap();
     let mut bytes = Vec::new();
@@ -176,6 +187,7 @@ fn adversarial_range_check_oversized_bits_rejected() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![CircuitNode::Expr {
             expr: CircuitExpr::RangeCheck {
@@ -186,6 +198,7 @@ fn adversarial_range_check_oversized_bits_rejected() {
         }],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let payload = bincode::serialize(&ir).unwrap();
     let mut bytes = Vec::new();
@@ -209,12 +222,15 @@ fn adversarial_array_size_unknown_capture_rejected() {
             name: "arr".into(),
             array_size: Some(ArraySize::Capture("ghost".into())),
             ir_type: IrType::Field,
+            default: None,
         }],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![], // no capture named "ghost"
         body: vec![],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let err = ir.validate().unwrap_err();
     assert!(
@@ -230,6 +246,7 @@ fn adversarial_for_range_unknown_capture_rejected() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![], // no capture named "missing"
         body: vec![CircuitNode::For {
             var: "i".into(),
@@ -242,6 +259,7 @@ fn adversarial_for_range_unknown_capture_rejected() {
         }],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let err = ir.validate().unwrap_err();
     assert!(
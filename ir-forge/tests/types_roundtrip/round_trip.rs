@@ -6,10 +6,12 @@ fn round_trip_empty() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     assert_round_trip(&ir);
 }
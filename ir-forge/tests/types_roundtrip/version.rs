@@ -1,15 +1,17 @@
 use super::*;
 
 #[test]
-fn v3_and_v4_rejected_with_recompile_message() {
+fn legacy_versions_rejected_with_recompile_message() {
     let ir = ProveIR {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     // v3 blob (no prime byte)
     let payload = bincode::serialize(&ir).unwrap();
@@ -34,6 +36,42 @@ fn v3_and_v4_rejected_with_recompile_message() {
         err.contains("no longer supported") && err.contains("recompile"),
         "v4 error should mention recompile: {err}"
     );
+
+    // v6 blob (CircuitExpr tags before MerkleVerifyArity)
+    let mut bytes_v6 = Vec::new();
+    bytes_v6.extend_from_slice(b"ACHP");
+    bytes_v6.push(6);
+    bytes_v6.push(PrimeId::Bn254.to_byte());
+    bytes_v6.extend_from_slice(&payload);
+    let err = ProveIR::from_bytes(&bytes_v6).unwrap_err();
+    assert!(
+        err.contains("no longer supported") && err.contains("recompile"),
+        "v6 error should mention recompile: {err}"
+    );
+
+    // v7 blob (ProveIR before the `outputs` field)
+    let mut bytes_v7 = Vec::new();
+    bytes_v7.extend_from_slice(b"ACHP");
+    bytes_v7.push(7);
+    bytes_v7.push(PrimeId::Bn254.to_byte());
+    bytes_v7.extend_from_slice(&payload);
+    let err = ProveIR::from_bytes(&bytes_v7).unwrap_err();
+    assert!(
+        err.contains("no longer supported") && err.contains("recompile"),
+        "v7 error should mention recompile: {err}"
+    );
+
+    // v8 blob (ProveInputDecl before the `default` field)
+    let mut bytes_v8 = Vec::new();
+    bytes_v8.extend_from_slice(b"ACHP");
+    bytes_v8.push(8);
+    bytes_v8.push(PrimeId::Bn254.to_byte());
+    bytes_v8.extend_from_slice(&payload);
+    let err = ProveIR::from_bytes(&bytes_v8).unwrap_err();
+    assert!(
+        err.contains("no longer supported") && err.contains("recompile"),
+        "v8 error should mention recompile: {err}"
+    );
 }
 
 #[test]
@@ -42,10 +80,12 @@ fn v4_roundtrip_with_each_prime() {
         name: Some("test".into()),
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     for prime in [PrimeId::Bn254, PrimeId::Bls12_381, PrimeId::Goldilocks] {
         let bytes = ir.to_bytes(prime).unwrap();
@@ -61,10 +101,12 @@ fn v4_bad_prime_byte_rejected() {
         name: None,
         public_inputs: vec![],
         witness_inputs: vec![],
+        outputs: vec![],
         captures: vec![],
         body: vec![],
         capture_arrays: vec![],
         component_bodies: Default::default(),
+        shadowed_lets: Vec::new(),
     };
     let mut bytes = ir.to_bytes(PrimeId::Bn254).unwrap();
     bytes[5] = 0xFF; // invalid prime byte
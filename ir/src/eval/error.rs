@@ -1,5 +1,6 @@
 use std::fmt;
 
+use diagnostics::SpanRange;
 use memory::{FieldBackend, FieldElement};
 
 use crate::types::{IrProgram, SsaVar};
@@ -18,6 +19,8 @@ pub enum EvalError<F: FieldBackend = memory::Bn254Fr> {
         name: Option<String>,
         value: Option<FieldElement<F>>,
         message: Option<String>,
+        /// Source span of the failing `assert`, when the program has one.
+        span: Option<SpanRange>,
     },
     AssertEqFailed {
         lhs: SsaVar,
@@ -27,6 +30,8 @@ pub enum EvalError<F: FieldBackend = memory::Bn254Fr> {
         lhs_value: Option<FieldElement<F>>,
         rhs_value: Option<FieldElement<F>>,
         message: Option<String>,
+        /// Source span of the failing `assert_eq`, when the program has one.
+        span: Option<SpanRange>,
     },
     RangeCheckFailed {
         var: SsaVar,
@@ -53,6 +58,14 @@ pub(super) fn resolve_name<F: FieldBackend>(program: &IrProgram<F>, var: SsaVar)
     program.get_name(var).map(|s| s.to_string())
 }
 
+/// Write the ` at line N` suffix for an error that carries a source span.
+fn write_location(f: &mut fmt::Formatter<'_>, span: &Option<SpanRange>) -> fmt::Result {
+    match span {
+        Some(span) => write!(f, " at line {}", span.line_start),
+        None => Ok(()),
+    }
+}
+
 impl<F: FieldBackend> fmt::Display for EvalError<F> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -74,23 +87,30 @@ impl<F: FieldBackend> fmt::Display for EvalError<F> {
                 name,
                 value,
                 message,
+                span,
                 ..
             } => {
                 if let Some(msg) = message {
-                    write!(f, "assertion failed: {msg}")
+                    write!(f, "assertion failed: {msg}")?;
                 } else {
                     match (name, value) {
                         (Some(n), Some(v)) => write!(
                             f,
                             "assertion failed at '{n}' (value is {}, expected non-zero)",
                             v.to_decimal_string()
-                        ),
+                        )?,
                         (Some(n), None) => {
-                            write!(f, "assertion failed at '{n}' (expected non-zero)")
+                            write!(f, "assertion failed at '{n}' (expected non-zero)")?
                         }
-                        _ => write!(f, "assertion failed (expected non-zero)"),
+                        (None, Some(v)) => write!(
+                            f,
+                            "assertion failed: value is {}, expected non-zero",
+                            v.to_decimal_string()
+                        )?,
+                        (None, None) => write!(f, "assertion failed (expected non-zero)")?,
                     }
                 }
+                write_location(f, span)
             }
             EvalError::AssertEqFailed {
                 lhs_name,
@@ -98,10 +118,11 @@ impl<F: FieldBackend> fmt::Display for EvalError<F> {
                 lhs_value,
                 rhs_value,
                 message,
+                span,
                 ..
             } => {
                 if let Some(msg) = message {
-                    write!(f, "assert_eq failed: {msg}")
+                    write!(f, "assert_eq failed: {msg}")?;
                 } else {
                     match (lhs_name, rhs_name, lhs_value, rhs_value) {
                         (Some(a), Some(b), Some(av), Some(bv)) => write!(
@@ -109,10 +130,17 @@ impl<F: FieldBackend> fmt::Display for EvalError<F> {
                             "assert_eq failed: '{a}' (value {}) != '{b}' (value {})",
                             av.to_decimal_string(),
                             bv.to_decimal_string()
-                        ),
-                        _ => write!(f, "assert_eq failed: values are not equal"),
+                        )?,
+                        (_, _, Some(av), Some(bv)) => write!(
+                            f,
+                            "assert_eq failed: {} != {}",
+                            av.to_decimal_string(),
+                            bv.to_decimal_string()
+                        )?,
+                        _ => write!(f, "assert_eq failed: values are not equal")?,
                     }
                 }
+                write_location(f, span)
             }
             EvalError::RangeCheckFailed {
                 bits, name, value, ..
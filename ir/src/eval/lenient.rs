@@ -27,7 +27,7 @@ pub fn evaluate_lenient<F: FieldBackend + PoseidonParamsProvider>(
                 values.insert(*result, *value);
             }
             Instruction::Input { result, name, .. } => {
-                if let Some(val) = inputs.get(name) {
+                if let Some(val) = program.input_value(inputs, name) {
                     values.insert(*result, *val);
                 }
             }
@@ -148,9 +148,15 @@ pub fn evaluate_lenient<F: FieldBackend + PoseidonParamsProvider>(
                     );
                 }
             }
+            // IsGt(a, b) ≡ IsLt(b, a); IsGe(a, b) ≡ IsLe(b, a).
             Instruction::IsLt {
                 result, lhs, rhs, ..
             }
+            | Instruction::IsGt {
+                result,
+                lhs: rhs,
+                rhs: lhs,
+            }
             | Instruction::IsLtBounded {
                 result, lhs, rhs, ..
             } => {
@@ -171,6 +177,11 @@ pub fn evaluate_lenient<F: FieldBackend + PoseidonParamsProvider>(
             Instruction::IsLe {
                 result, lhs, rhs, ..
             }
+            | Instruction::IsGe {
+                result,
+                lhs: rhs,
+                rhs: lhs,
+            }
             | Instruction::IsLeBounded {
                 result, lhs, rhs, ..
             } => {
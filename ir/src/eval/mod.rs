@@ -1,7 +1,9 @@
 mod error;
 mod int;
 mod lenient;
+mod outputs;
 mod strict;
+mod trace;
 mod witness;
 
 #[cfg(test)]
@@ -9,4 +11,6 @@ mod tests;
 
 pub use error::EvalError;
 pub use lenient::evaluate_lenient;
-pub use strict::evaluate;
+pub use outputs::fill_outputs;
+pub use strict::{evaluate, evaluate_traced};
+pub use trace::{TraceEntry, TRACE_EVAL_VAR};
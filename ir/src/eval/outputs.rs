@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use constraints::PoseidonParamsProvider;
+use memory::{FieldBackend, FieldElement};
+
+use crate::types::{Instruction, IrProgram};
+
+use super::{evaluate_lenient, EvalError};
+
+/// Compute the value of every circuit output missing from `inputs`.
+///
+/// An `output y = expr` lowers to a public `Input` named `y` plus
+/// `AssertEq(y, expr)`, so the prover's value for `y` is whatever `expr`
+/// evaluates to. Outputs may read earlier outputs, hence one lenient
+/// evaluation per round until every output is known. Values the caller
+/// already supplied are left untouched and checked by the constraint.
+pub fn fill_outputs<F: FieldBackend + PoseidonParamsProvider>(
+    program: &IrProgram<F>,
+    outputs: &[String],
+    inputs: &mut HashMap<String, FieldElement<F>>,
+) -> Result<(), Box<EvalError<F>>> {
+    let mut pending: Vec<&String> = outputs
+        .iter()
+        .filter(|name| !inputs.contains_key(*name))
+        .collect();
+
+    while !pending.is_empty() {
+        let (values, _) = evaluate_lenient(program, inputs);
+        let before = pending.len();
+        pending.retain(|name| {
+            let Some(var) = program.iter().find_map(|inst| match inst {
+                Instruction::Input {
+                    result, name: n, ..
+                } if n == *name => Some(*result),
+                _ => None,
+            }) else {
+                return true;
+            };
+            let value = program.iter().find_map(|inst| match inst {
+                Instruction::AssertEq { lhs, rhs, .. } if *lhs == var => values.get(rhs).copied(),
+                Instruction::AssertEq { lhs, rhs, .. } if *rhs == var => values.get(lhs).copied(),
+                _ => None,
+            });
+            match value {
+                Some(v) => {
+                    inputs.insert((*name).clone(), v);
+                    false
+                }
+                None => true,
+            }
+        });
+        if pending.len() == before {
+            return Err(Box::new(EvalError::MissingInput(pending[0].clone())));
+        }
+    }
+    Ok(())
+}
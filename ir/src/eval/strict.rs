@@ -8,9 +8,19 @@ use crate::types::{Instruction, IrProgram, SsaVar};
 
 use super::error::resolve_name;
 use super::int::{fits_in_bits, int_divmod_field};
+use super::trace::{trace_eval_enabled, TraceEntry};
 use super::witness::dispatch_witness_call;
 use super::EvalError;
 
+type EvalResult<F> = Result<HashMap<SsaVar, FieldElement<F>>, Box<EvalError<F>>>;
+
+/// Evaluate `program` on `inputs`, returning every SSA value.
+///
+/// With `ACH_TRACE_EVAL=1` in the environment, each assignment is also
+/// printed to stderr as it happens, one `ach-trace-eval` line per
+/// instruction (see [`TraceEntry`]), so the steps leading up to a failed
+/// assertion stay visible.
+///
 /// ```
 /// use std::collections::HashMap;
 /// use ir::IrLowering;
@@ -28,6 +38,57 @@ pub fn evaluate<F: FieldBackend + PoseidonParamsProvider>(
     program: &IrProgram<F>,
     inputs: &HashMap<String, FieldElement<F>>,
 ) -> Result<HashMap<SsaVar, FieldElement<F>>, Box<EvalError<F>>> {
+    if trace_eval_enabled() {
+        use std::io::Write;
+
+        let mut err = std::io::stderr().lock();
+        return evaluate_with(
+            program,
+            inputs,
+            Some(&mut |entry: TraceEntry<F>| {
+                let _ = writeln!(err, "ach-trace-eval {entry}");
+            }),
+        );
+    }
+    evaluate_with(program, inputs, None)
+}
+
+/// [`evaluate`] that also records a [`TraceEntry`] per instruction.
+///
+/// The trace is returned alongside the result rather than inside it so
+/// that, when evaluation fails, it still holds every assignment up to
+/// the failing instruction.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use ir::IrLowering;
+/// use ir::eval::evaluate_traced;
+/// use memory::FieldElement;
+///
+/// let prog: ir::types::IrProgram =
+///     IrLowering::lower_circuit("let z = x * y", &[], &["x", "y"]).unwrap();
+/// let inputs: HashMap<_, _> = [("x", 6), ("y", 7)]
+///     .into_iter()
+///     .map(|(n, v)| (n.to_string(), FieldElement::from_u64(v)))
+///     .collect();
+/// let (result, trace) = evaluate_traced(&prog, &inputs);
+/// assert!(result.is_ok());
+/// assert!(trace.iter().any(|e| e.op == "Mul" && e.value == FieldElement::from_u64(42)));
+/// ```
+pub fn evaluate_traced<F: FieldBackend + PoseidonParamsProvider>(
+    program: &IrProgram<F>,
+    inputs: &HashMap<String, FieldElement<F>>,
+) -> (EvalResult<F>, Vec<TraceEntry<F>>) {
+    let mut trace = Vec::new();
+    let result = evaluate_with(program, inputs, Some(&mut |entry| trace.push(entry)));
+    (result, trace)
+}
+
+fn evaluate_with<F: FieldBackend + PoseidonParamsProvider>(
+    program: &IrProgram<F>,
+    inputs: &HashMap<String, FieldElement<F>>,
+    mut on_step: Option<&mut dyn FnMut(TraceEntry<F>)>,
+) -> EvalResult<F> {
     let mut values: HashMap<SsaVar, FieldElement<F>> = HashMap::new();
     let mut poseidon_params: Option<PoseidonParams<F>> = None;
 
@@ -40,14 +101,14 @@ pub fn evaluate<F: FieldBackend + PoseidonParamsProvider>(
             .ok_or_else(|| Box::new(EvalError::UndefinedVar(*var)))
     };
 
-    for inst in &program.instructions {
+    for (ir_index, inst) in program.instructions.iter().enumerate() {
         match inst {
             Instruction::Const { result, value } => {
                 values.insert(*result, *value);
             }
             Instruction::Input { result, name, .. } => {
-                let val = inputs
-                    .get(name)
+                let val = program
+                    .input_value(inputs, name)
                     .copied()
                     .ok_or_else(|| Box::new(EvalError::MissingInput(name.clone())))?;
                 values.insert(*result, val);
@@ -130,6 +191,7 @@ pub fn evaluate<F: FieldBackend + PoseidonParamsProvider>(
                         lhs_value: Some(a),
                         rhs_value: Some(b),
                         message: message.clone(),
+                        span: program.get_span(*result).cloned(),
                     }));
                 }
                 values.insert(*result, a);
@@ -146,6 +208,7 @@ pub fn evaluate<F: FieldBackend + PoseidonParamsProvider>(
                         name: resolve_name(program, *operand),
                         value: Some(v),
                         message: message.clone(),
+                        span: program.get_span(*result).cloned(),
                     }));
                 }
                 values.insert(*result, v);
@@ -201,7 +264,13 @@ pub fn evaluate<F: FieldBackend + PoseidonParamsProvider>(
                 };
                 values.insert(*result, neq);
             }
+            // IsGt(a, b) ≡ IsLt(b, a); IsGe(a, b) ≡ IsLe(b, a).
             Instruction::IsLt { result, lhs, rhs }
+            | Instruction::IsGt {
+                result,
+                lhs: rhs,
+                rhs: lhs,
+            }
             | Instruction::IsLtBounded {
                 result, lhs, rhs, ..
             } => {
@@ -220,6 +289,11 @@ pub fn evaluate<F: FieldBackend + PoseidonParamsProvider>(
                 );
             }
             Instruction::IsLe { result, lhs, rhs }
+            | Instruction::IsGe {
+                result,
+                lhs: rhs,
+                rhs: lhs,
+            }
             | Instruction::IsLeBounded {
                 result, lhs, rhs, ..
             } => {
@@ -290,6 +364,17 @@ pub fn evaluate<F: FieldBackend + PoseidonParamsProvider>(
                 )?;
             }
         }
+        if let Some(on_step) = on_step.as_mut() {
+            let var = inst.result_var();
+            if let Some(&value) = values.get(&var) {
+                on_step(TraceEntry {
+                    ir_index,
+                    op: inst.op_name(),
+                    var,
+                    value,
+                });
+            }
+        }
     }
 
     Ok(values)
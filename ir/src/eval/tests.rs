@@ -5,6 +5,7 @@ use memory::FieldElement;
 mod assertions;
 mod basics;
 mod errors;
+mod trace;
 
 fn empty_inputs() -> HashMap<String, FieldElement> {
     HashMap::new()
@@ -2,6 +2,7 @@ use super::{empty_inputs, fe};
 use crate::eval::{evaluate, EvalError};
 use crate::types::{Instruction, IrProgram};
 use memory::FieldElement;
+use std::collections::HashMap;
 
 #[test]
 fn eval_assert_ok() {
@@ -60,6 +61,22 @@ fn eval_assert_fail_with_message() {
     );
 }
 
+#[test]
+fn eval_source_assert_message_reported() {
+    let p: IrProgram =
+        crate::IrLowering::lower_circuit("assert(x == y, \"mismatch\")", &[], &["x", "y"]).unwrap();
+    let inputs = [("x", 1), ("y", 2)]
+        .into_iter()
+        .map(|(n, v)| (n.to_string(), fe(v)))
+        .collect();
+    let err = evaluate(&p, &inputs).unwrap_err();
+    assert!(
+        matches!(&*err, EvalError::AssertionFailed { message: Some(m), .. } if m == "mismatch"),
+        "expected the source message, got: {err}"
+    );
+    assert_eq!(err.to_string(), "assertion failed: mismatch at line 1");
+}
+
 #[test]
 fn eval_assert_eq_fail() {
     let mut p = IrProgram::new();
@@ -146,3 +163,31 @@ fn eval_range_check_fail() {
     let err = evaluate(&p, &empty_inputs()).unwrap_err();
     assert!(matches!(*err, EvalError::RangeCheckFailed { .. }));
 }
+
+#[test]
+fn eval_source_assert_eq_reports_values_and_line() {
+    let inputs: HashMap<String, FieldElement> = [("x", 3), ("y", 4)]
+        .into_iter()
+        .map(|(n, v)| (n.to_string(), fe(v)))
+        .collect();
+
+    let p: IrProgram =
+        crate::IrLowering::lower_circuit("\nassert_eq(x, y)", &[], &["x", "y"]).unwrap();
+    let err = evaluate(&p, &inputs).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "assert_eq failed: 'x' (value 3) != 'y' (value 4) at line 2"
+    );
+
+    // Operands without a source name still report their values.
+    let p: IrProgram =
+        crate::IrLowering::lower_circuit("\nassert_eq(x * 1, y)", &[], &["x", "y"]).unwrap();
+    let err = evaluate(&p, &inputs).unwrap_err();
+    match &*err {
+        EvalError::AssertEqFailed {
+            span: Some(span), ..
+        } => assert_eq!(span.line_start, 2),
+        other => panic!("expected AssertEqFailed with a span, got: {other:?}"),
+    }
+    assert_eq!(err.to_string(), "assert_eq failed: 3 != 4 at line 2");
+}
@@ -0,0 +1,37 @@
+use super::fe;
+use crate::eval::evaluate_traced;
+use crate::IrLowering;
+
+#[test]
+fn traced_eval_records_product() {
+    let p = IrLowering::lower_circuit("let z = x * y", &[], &["x", "y"]).unwrap();
+    let inputs = [("x".to_string(), fe(6)), ("y".to_string(), fe(7))].into();
+    let (result, trace) = evaluate_traced(&p, &inputs);
+    let values = result.unwrap();
+
+    assert_eq!(trace.len(), p.len());
+    let mul = trace.iter().find(|e| e.op == "Mul").unwrap();
+    assert_eq!(mul.value, fe(42));
+    assert_eq!(values[&mul.var], fe(42));
+    assert_eq!(
+        mul.to_string(),
+        format!("ir={} op=Mul {} = 42", mul.ir_index, mul.var)
+    );
+}
+
+#[test]
+fn traced_eval_keeps_steps_before_failure() {
+    let p = IrLowering::lower_circuit("let z = x * y\nassert_eq(z, out)", &["out"], &["x", "y"])
+        .unwrap();
+    let inputs = [
+        ("x".to_string(), fe(6)),
+        ("y".to_string(), fe(7)),
+        ("out".to_string(), fe(41)),
+    ]
+    .into();
+    let (result, trace) = evaluate_traced(&p, &inputs);
+
+    assert!(result.is_err());
+    assert!(trace.iter().any(|e| e.op == "Mul" && e.value == fe(42)));
+    assert!(trace.iter().all(|e| e.op != "AssertEq"));
+}
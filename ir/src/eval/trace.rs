@@ -0,0 +1,38 @@
+use memory::{FieldBackend, FieldElement};
+
+use crate::types::SsaVar;
+
+/// Env var that makes [`evaluate`](super::evaluate) print every SSA
+/// assignment to stderr.
+pub const TRACE_EVAL_VAR: &str = "ACH_TRACE_EVAL";
+
+/// One evaluated instruction: the SSA variable it defined and its value.
+///
+/// `Display` renders the `ACH_TRACE_EVAL=1` line body:
+///
+/// ```text
+/// ir=2 op=Mul %2 = 42
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry<F: FieldBackend> {
+    /// Position of the instruction in the program.
+    pub ir_index: usize,
+    /// Instruction variant name, as in [`Instruction::op_name`](crate::types::Instruction::op_name).
+    pub op: &'static str,
+    pub var: SsaVar,
+    pub value: FieldElement<F>,
+}
+
+impl<F: FieldBackend> std::fmt::Display for TraceEntry<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ir={} op={} {} = {}",
+            self.ir_index, self.op, self.var, self.value
+        )
+    }
+}
+
+pub(super) fn trace_eval_enabled() -> bool {
+    std::env::var(TRACE_EVAL_VAR).as_deref() == Ok("1")
+}
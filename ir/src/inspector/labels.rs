@@ -26,6 +26,8 @@ pub(super) fn node_kind<F: FieldBackend>(inst: &Instruction<F>) -> NodeKind {
         Instruction::IsNeq { .. } => NodeKind::IsNeq,
         Instruction::IsLt { .. } => NodeKind::IsLt,
         Instruction::IsLe { .. } => NodeKind::IsLe,
+        Instruction::IsGt { .. } => NodeKind::IsGt,
+        Instruction::IsGe { .. } => NodeKind::IsGe,
         Instruction::IsLtBounded { .. } => NodeKind::IsLtBounded,
         Instruction::IsLeBounded { .. } => NodeKind::IsLeBounded,
         Instruction::Decompose { .. } => NodeKind::RangeCheck,
@@ -76,6 +78,8 @@ pub(super) fn node_label<F: FieldBackend>(inst: &Instruction<F>, program: &IrPro
         Instruction::IsNeq { .. } => "IsNeq".to_string(),
         Instruction::IsLt { .. } => "IsLt".to_string(),
         Instruction::IsLe { .. } => "IsLe".to_string(),
+        Instruction::IsGt { .. } => "IsGt".to_string(),
+        Instruction::IsGe { .. } => "IsGe".to_string(),
         Instruction::IsLtBounded { bitwidth, .. } => format!("IsLtBounded({bitwidth})"),
         Instruction::IsLeBounded { bitwidth, .. } => format!("IsLeBounded({bitwidth})"),
         Instruction::Decompose {
@@ -96,6 +96,8 @@ pub enum NodeKind {
     IsNeq,
     IsLt,
     IsLe,
+    IsGt,
+    IsGe,
     IsLtBounded,
     IsLeBounded,
     WitnessCall,
@@ -4,7 +4,7 @@ use memory::{FieldBackend, FieldElement};
 use crate::error::{IrError, OptSpan};
 use crate::types::{Instruction, IrType, SsaVar};
 
-use super::{to_ir_span, EnvValue, IrLowering};
+use super::{expect_scalar, to_ir_span, EnvValue, IrLowering};
 
 impl<F: FieldBackend> IrLowering<F> {
     pub(super) fn lower_call(
@@ -13,6 +13,18 @@ impl<F: FieldBackend> IrLowering<F> {
         args: &[&Expr],
         span: &Span,
     ) -> Result<SsaVar, IrError> {
+        let value = self.lower_call_value(callee, args, span)?;
+        expect_scalar(value, to_ir_span(span))
+    }
+
+    /// Like [`lower_call`](Self::lower_call), but lets inlined user
+    /// functions return an array. Builtins always produce a scalar.
+    pub(super) fn lower_call_value(
+        &mut self,
+        callee: &Expr,
+        args: &[&Expr],
+        span: &Span,
+    ) -> Result<EnvValue, IrError> {
         let sp = to_ir_span(span);
         // Identifier or DotAccess callees are supported
         let name = match callee {
@@ -23,7 +35,7 @@ impl<F: FieldBackend> IrLowering<F> {
                     // Synthesize args: the object becomes the sole argument
                     let mut method_args: Vec<&Expr> = vec![object.as_ref()];
                     method_args.extend(args.iter());
-                    return self.lower_len(&method_args, sp);
+                    return self.lower_len(&method_args, sp).map(EnvValue::Scalar);
                 }
                 // module.func() → qualified name "module::func"
                 if let Expr::Ident { name: module, .. } = object.as_ref() {
@@ -43,17 +55,33 @@ impl<F: FieldBackend> IrLowering<F> {
             }
         };
 
-        match name.as_str() {
+        if name == "bits" {
+            return self.lower_bits(args, sp).map(EnvValue::Array);
+        }
+
+        let scalar = match name.as_str() {
             "assert_eq" => self.lower_assert_eq(args, sp),
+            "assert_all_eq" => self.lower_assert_all_eq(args, sp),
+            "assert_in_set" => self.lower_assert_in_set(args, sp),
             "assert" => self.lower_assert(args, sp),
+            "assert_bool" => self.lower_assert_bool(args, sp),
             "poseidon" => self.lower_poseidon(args, sp),
             "mux" => self.lower_mux(args, sp),
             "range_check" => self.lower_range_check(args, sp),
             "len" => self.lower_len(args, sp),
             "poseidon_many" => self.lower_poseidon_many(args, sp),
             "merkle_verify" => self.lower_merkle_verify(args, span),
-            _ => self.lower_user_fn_call(&name, args, sp),
-        }
+            "inv" => self.lower_inv(args, sp),
+            "neg" => self.lower_neg(args, sp),
+            "merkle_root" => self.lower_merkle_root(args, sp),
+            "dot" => self.lower_dot(args, sp),
+            "popcount" => self.lower_popcount(args, sp),
+            "is_power_of_two" => self.lower_is_power_of_two(args, sp),
+            "bool_and" | "bool_or" | "bool_xor" => self.lower_bool_binary(&name, args, sp),
+            "bool_not" => self.lower_bool_not(args, sp),
+            _ => return self.lower_user_fn_call(&name, args, sp),
+        };
+        scalar.map(EnvValue::Scalar)
     }
 
     fn lower_assert_eq(&mut self, args: &[&Expr], sp: OptSpan) -> Result<SsaVar, IrError> {
@@ -89,6 +117,106 @@ impl<F: FieldBackend> IrLowering<F> {
             rhs: b,
             message,
         });
+        if let Some(span) = sp {
+            self.program.set_span(v, *span);
+        }
+        Ok(v)
+    }
+
+    /// `assert_all_eq(a, b, c, ...)`: `a == b`, `a == c`, ... as separate
+    /// `AssertEq`s. Returns the last one's result.
+    fn lower_assert_all_eq(&mut self, args: &[&Expr], sp: OptSpan) -> Result<SsaVar, IrError> {
+        if args.len() < 2 {
+            return Err(IrError::WrongArgumentCount {
+                builtin: "assert_all_eq".into(),
+                expected: 2,
+                got: args.len(),
+                span: sp,
+            });
+        }
+        let first = self.lower_expr(args[0])?;
+        let mut last = first;
+        for arg in &args[1..] {
+            let other = self.lower_expr(arg)?;
+            let v = self.program.fresh_var();
+            self.program.push(Instruction::AssertEq {
+                result: v,
+                lhs: first,
+                rhs: other,
+                message: None,
+            });
+            if let Some(span) = &sp {
+                self.program.set_span(v, (**span).clone());
+            }
+            last = v;
+        }
+        Ok(last)
+    }
+
+    /// `assert_in_set(x, [a, b, c])`: `(x - a) * (x - b) * (x - c) == 0`,
+    /// one `Mul` per extra set element and a final `AssertEq` to zero.
+    fn lower_assert_in_set(&mut self, args: &[&Expr], sp: OptSpan) -> Result<SsaVar, IrError> {
+        if args.len() != 2 {
+            return Err(IrError::WrongArgumentCount {
+                builtin: "assert_in_set".into(),
+                expected: 2,
+                got: args.len(),
+                span: sp,
+            });
+        }
+        let x = self.lower_expr(args[0])?;
+        let set = match self.resolve_arg_value(args[1])? {
+            EnvValue::Array(v) => v,
+            EnvValue::Scalar(_) => {
+                return Err(IrError::TypeMismatch {
+                    expected: "array".into(),
+                    got: "scalar".into(),
+                    span: sp,
+                })
+            }
+        };
+
+        let mut product: Option<SsaVar> = None;
+        for member in set {
+            let diff = self.program.fresh_var();
+            self.program.push(Instruction::Sub {
+                result: diff,
+                lhs: x,
+                rhs: member,
+            });
+            self.program.set_type(diff, IrType::Field);
+            product = Some(match product {
+                None => diff,
+                Some(acc) => {
+                    let v = self.program.fresh_var();
+                    self.program.push(Instruction::Mul {
+                        result: v,
+                        lhs: acc,
+                        rhs: diff,
+                    });
+                    self.program.set_type(v, IrType::Field);
+                    v
+                }
+            });
+        }
+        // Arrays are never empty in circuits, so at least one factor exists.
+        let product = product.expect("assert_in_set over an empty array");
+
+        let zero = self.program.fresh_var();
+        self.program.push(Instruction::Const {
+            result: zero,
+            value: FieldElement::<F>::zero(),
+        });
+        let v = self.program.fresh_var();
+        self.program.push(Instruction::AssertEq {
+            result: v,
+            lhs: product,
+            rhs: zero,
+            message: None,
+        });
+        if let Some(span) = sp {
+            self.program.set_span(v, *span);
+        }
         Ok(v)
     }
 
@@ -123,6 +251,51 @@ impl<F: FieldBackend> IrLowering<F> {
             operand,
             message,
         });
+        if let Some(span) = sp {
+            self.program.set_span(v, *span);
+        }
+        Ok(v)
+    }
+
+    /// `assert_bool(x)` — enforce `x * (x - 1) == 0`.
+    ///
+    /// Lowered to the Circom-style pattern `bool_prop` already detects,
+    /// so `x` joins the proven-boolean set and later `mux`, `&&`, `!`
+    /// and `assert` on it skip their own boolean enforcement.
+    fn lower_assert_bool(&mut self, args: &[&Expr], sp: OptSpan) -> Result<SsaVar, IrError> {
+        if args.len() != 1 {
+            return Err(IrError::WrongArgumentCount {
+                builtin: "assert_bool".into(),
+                expected: 1,
+                got: args.len(),
+                span: sp,
+            });
+        }
+        let operand = self.lower_expr(args[0])?;
+        let one = self.emit_const(FieldElement::<F>::one());
+        let minus_one = self.program.fresh_var();
+        self.program.push(Instruction::Sub {
+            result: minus_one,
+            lhs: operand,
+            rhs: one,
+        });
+        let product = self.program.fresh_var();
+        self.program.push(Instruction::Mul {
+            result: product,
+            lhs: operand,
+            rhs: minus_one,
+        });
+        let zero = self.emit_const(FieldElement::<F>::zero());
+        let v = self.program.fresh_var();
+        self.program.push(Instruction::AssertEq {
+            result: v,
+            lhs: product,
+            rhs: zero,
+            message: None,
+        });
+        if let Some(span) = sp {
+            self.program.set_span(v, *span);
+        }
         Ok(v)
     }
 
@@ -147,6 +320,54 @@ impl<F: FieldBackend> IrLowering<F> {
         Ok(v)
     }
 
+    /// `inv(x)` — multiplicative inverse, lowered as `1 / x`. The backend
+    /// emits an inverse witness with `x * inv = 1`, which also asserts
+    /// `x != 0`.
+    fn lower_inv(&mut self, args: &[&Expr], sp: OptSpan) -> Result<SsaVar, IrError> {
+        if args.len() != 1 {
+            return Err(IrError::WrongArgumentCount {
+                builtin: "inv".into(),
+                expected: 1,
+                got: args.len(),
+                span: sp,
+            });
+        }
+        let operand = self.lower_expr(args[0])?;
+        if self.get_const_value(operand).is_some_and(|v| v.is_zero()) {
+            return Err(IrError::DivisionByZero { span: sp });
+        }
+        let one = self.program.fresh_var();
+        self.program.push(Instruction::Const {
+            result: one,
+            value: FieldElement::<F>::one(),
+        });
+        let v = self.program.fresh_var();
+        self.program.push(Instruction::Div {
+            result: v,
+            lhs: one,
+            rhs: operand,
+        });
+        self.program.set_type(v, IrType::Field);
+        Ok(v)
+    }
+
+    /// `neg(x)` — additive inverse `-x` (no constraints).
+    fn lower_neg(&mut self, args: &[&Expr], sp: OptSpan) -> Result<SsaVar, IrError> {
+        if args.len() != 1 {
+            return Err(IrError::WrongArgumentCount {
+                builtin: "neg".into(),
+                expected: 1,
+                got: args.len(),
+                span: sp,
+            });
+        }
+        let operand = self.lower_expr(args[0])?;
+        let v = self.program.fresh_var();
+        self.program.push(Instruction::Neg { result: v, operand });
+        self.program.set_type(v, IrType::Field);
+        Ok(v)
+    }
+
     fn lower_mux(&mut self, args: &[&Expr], sp: OptSpan) -> Result<SsaVar, IrError> {
         if args.len() != 3 {
             return Err(IrError::WrongArgumentCount {
@@ -209,6 +430,294 @@ impl<F: FieldBackend> IrLowering<F> {
         Ok(v)
     }
 
+    /// `bool_and(a, b)`, `bool_or(a, b)`, `bool_xor(a, b)` on single
+    /// boolean wires. `And`/`Or` boolean-enforce both operands (unless
+    /// already proven boolean) and cost one multiplication; `bool_xor`
+    /// reuses that product as `a + b - 2ab`.
+    fn lower_bool_binary(
+        &mut self,
+        name: &str,
+        args: &[&Expr],
+        sp: OptSpan,
+    ) -> Result<SsaVar, IrError> {
+        if args.len() != 2 {
+            return Err(IrError::WrongArgumentCount {
+                builtin: name.into(),
+                expected: 2,
+                got: args.len(),
+                span: sp,
+            });
+        }
+        let lhs = self.lower_expr(args[0])?;
+        let rhs = self.lower_expr(args[1])?;
+        let v = self.program.fresh_var();
+        match name {
+            "bool_or" => self.program.push(Instruction::Or {
+                result: v,
+                lhs,
+                rhs,
+            }),
+            _ => self.program.push(Instruction::And {
+                result: v,
+                lhs,
+                rhs,
+            }),
+        };
+        self.program.set_type(v, IrType::Bool);
+        if name != "bool_xor" {
+            return Ok(v);
+        }
+
+        let two = self.emit_const(FieldElement::<F>::from_u64(2));
+        let twice = self.program.fresh_var();
+        self.program.push(Instruction::Mul {
+            result: twice,
+            lhs: two,
+            rhs: v,
+        });
+        let sum = self.program.fresh_var();
+        self.program.push(Instruction::Add {
+            result: sum,
+            lhs,
+            rhs,
+        });
+        let xor = self.program.fresh_var();
+        self.program.push(Instruction::Sub {
+            result: xor,
+            lhs: sum,
+            rhs: twice,
+        });
+        self.program.set_type(xor, IrType::Bool);
+        Ok(xor)
+    }
+
+    /// `bool_not(a)` — `1 - a`, boolean-enforcing `a`.
+    fn lower_bool_not(&mut self, args: &[&Expr], sp: OptSpan) -> Result<SsaVar, IrError> {
+        if args.len() != 1 {
+            return Err(IrError::WrongArgumentCount {
+                builtin: "bool_not".into(),
+                expected: 1,
+                got: args.len(),
+                span: sp,
+            });
+        }
+        let operand = self.lower_expr(args[0])?;
+        let v = self.program.fresh_var();
+        self.program.push(Instruction::Not { result: v, operand });
+        self.program.set_type(v, IrType::Bool);
+        Ok(v)
+    }
+
+    /// `bits(x, n)`: decompose `x` into `n` boolean wires, LSB first.
+    ///
+    /// Emits a single `Decompose`, which constrains every bit to be boolean
+    /// and `Σ bit_i * 2^i == x` — so `x` is also range-checked to `n` bits.
+    fn lower_bits(&mut self, args: &[&Expr], sp: OptSpan) -> Result<Vec<SsaVar>, IrError> {
+        self.lower_decompose("bits", args, sp)
+    }
+
+    /// `popcount(x, n)`: the number of set bits of `x`, as the sum of its
+    /// `bits(x, n)` decomposition (so `x` is range-checked to `n` bits).
+    fn lower_popcount(&mut self, args: &[&Expr], sp: OptSpan) -> Result<SsaVar, IrError> {
+        let bits = self.lower_decompose("popcount", args, sp)?;
+        Ok(self.sum_bits(&bits))
+    }
+
+    /// `is_power_of_two(x, n)`: assert that `x` has exactly one set bit
+    /// among its low `n` bits, i.e. `popcount(x, n) == 1`. Zero fails.
+    fn lower_is_power_of_two(&mut self, args: &[&Expr], sp: OptSpan) -> Result<SsaVar, IrError> {
+        let bits = self.lower_decompose("is_power_of_two", args, sp.clone())?;
+        let count = self.sum_bits(&bits);
+        let one = self.emit_const(FieldElement::<F>::one());
+        let v = self.program.fresh_var();
+        self.program.push(Instruction::AssertEq {
+            result: v,
+            lhs: count,
+            rhs: one,
+            message: Some("value is not a power of two".into()),
+        });
+        if let Some(span) = sp {
+            self.program.set_span(v, *span);
+        }
+        Ok(v)
+    }
+
+    /// Sum boolean wires with linear `Add`s (no extra constraints).
+    fn sum_bits(&mut self, bits: &[SsaVar]) -> SsaVar {
+        let mut acc = bits[0];
+        for &b in &bits[1..] {
+            let v = self.program.fresh_var();
+            self.program.push(Instruction::Add {
+                result: v,
+                lhs: acc,
+                rhs: b,
+            });
+            self.program.set_type(v, IrType::Field);
+            acc = v;
+        }
+        acc
+    }
+
+    /// Shared by `bits`, `popcount` and `is_power_of_two`: lower `(x, n)`
+    /// to a `Decompose` of `x` into `n` boolean wires, LSB first.
+    fn lower_decompose(
+        &mut self,
+        builtin: &str,
+        args: &[&Expr],
+        sp: OptSpan,
+    ) -> Result<Vec<SsaVar>, IrError> {
+        if args.len() != 2 {
+            return Err(IrError::WrongArgumentCount {
+                builtin: builtin.into(),
+                expected: 2,
+                got: args.len(),
+                span: sp,
+            });
+        }
+        let operand = self.lower_expr(args[0])?;
+        let bits_var = self.lower_expr(args[1])?;
+
+        let bits_fe = self.get_const_value(bits_var).ok_or_else(|| {
+            IrError::UnsupportedOperation(
+                format!("{builtin}() bit count must be a constant integer"),
+                sp.clone(),
+            )
+        })?;
+        let num_bits = super::field_to_u64(&bits_fe)
+            .filter(|&n| n > 0 && n < F::MODULUS_BIT_SIZE as u64)
+            .ok_or_else(|| {
+                IrError::UnsupportedOperation(
+                    format!(
+                        "{builtin}() bit count must be between 1 and {} (a full-width decomposition is not unique)",
+                        F::MODULUS_BIT_SIZE - 1
+                    ),
+                    sp.clone(),
+                )
+            })? as u32;
+
+        let result = self.program.fresh_var();
+        let bit_results: Vec<SsaVar> = (0..num_bits).map(|_| self.program.fresh_var()).collect();
+        for &b in &bit_results {
+            self.program.set_type(b, IrType::Bool);
+        }
+        self.program.push(Instruction::Decompose {
+            result,
+            bit_results: bit_results.clone(),
+            operand,
+            num_bits,
+        });
+        Ok(bit_results)
+    }
+
+    /// `merkle_root(leaves)` — root of the binary Poseidon Merkle tree
+    /// over `leaves`, hashing adjacent pairs level by level. The number of
+    /// leaves must be a power of two; a single leaf is its own root.
+    fn lower_merkle_root(&mut self, args: &[&Expr], sp: OptSpan) -> Result<SsaVar, IrError> {
+        if args.len() != 1 {
+            return Err(IrError::WrongArgumentCount {
+                builtin: "merkle_root".into(),
+                expected: 1,
+                got: args.len(),
+                span: sp,
+            });
+        }
+        let mut level = match self.resolve_arg_value(args[0])? {
+            EnvValue::Array(v) => v,
+            EnvValue::Scalar(_) => {
+                return Err(IrError::TypeMismatch {
+                    expected: "array".into(),
+                    got: "scalar".into(),
+                    span: sp,
+                })
+            }
+        };
+        if !level.len().is_power_of_two() {
+            return Err(IrError::UnsupportedOperation(
+                format!(
+                    "merkle_root requires a power-of-two number of leaves, got {}",
+                    level.len()
+                ),
+                sp,
+            ));
+        }
+
+        while level.len() > 1 {
+            let mut next = Vec::with_capacity(level.len() / 2);
+            for pair in level.chunks_exact(2) {
+                let v = self.program.fresh_var();
+                self.program.push(Instruction::PoseidonHash {
+                    result: v,
+                    left: pair[0],
+                    right: pair[1],
+                });
+                self.program.set_type(v, IrType::Field);
+                next.push(v);
+            }
+            level = next;
+        }
+        Ok(level[0])
+    }
+
+    /// `dot(a, b)`: inner product of two equal-length arrays, one `Mul`
+    /// per index summed with linear `Add`s.
+    fn lower_dot(&mut self, args: &[&Expr], sp: OptSpan) -> Result<SsaVar, IrError> {
+        if args.len() != 2 {
+            return Err(IrError::WrongArgumentCount {
+                builtin: "dot".into(),
+                expected: 2,
+                got: args.len(),
+                span: sp,
+            });
+        }
+        let mut operands = Vec::with_capacity(2);
+        for arg in args {
+            match self.resolve_arg_value(arg)? {
+                EnvValue::Array(v) => operands.push(v),
+                EnvValue::Scalar(_) => {
+                    return Err(IrError::TypeMismatch {
+                        expected: "array".into(),
+                        got: "scalar".into(),
+                        span: sp,
+                    })
+                }
+            }
+        }
+        let (a, b) = (&operands[0], &operands[1]);
+        if a.len() != b.len() {
+            return Err(IrError::ArrayLengthMismatch {
+                expected: a.len(),
+                got: b.len(),
+                span: sp,
+            });
+        }
+
+        let mut acc: Option<SsaVar> = None;
+        for (&x, &y) in a.iter().zip(b) {
+            let product = self.program.fresh_var();
+            self.program.push(Instruction::Mul {
+                result: product,
+                lhs: x,
+                rhs: y,
+            });
+            self.program.set_type(product, IrType::Field);
+            acc = Some(match acc {
+                None => product,
+                Some(sum) => {
+                    let v = self.program.fresh_var();
+                    self.program.push(Instruction::Add {
+                        result: v,
+                        lhs: sum,
+                        rhs: product,
+                    });
+                    self.program.set_type(v, IrType::Field);
+                    v
+                }
+            });
+        }
+        // Arrays are never empty in circuits, so at least one product exists.
+        Ok(acc.expect("dot over empty arrays"))
+    }
+
     fn lower_len(&mut self, args: &[&Expr], sp: OptSpan) -> Result<SsaVar, IrError> {
         if args.len() != 1 {
             return Err(IrError::WrongArgumentCount {
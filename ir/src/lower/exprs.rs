@@ -1,13 +1,35 @@
 use achronyme_parser::ast::*;
-use memory::{FieldBackend, FieldElement};
+use memory::{BigInt, BigIntWidth, FieldBackend, FieldElement};
 
 use crate::error::IrError;
 use crate::types::{Instruction, IrType, SsaVar};
 
-use super::{field_to_u64, to_ir_span, EnvValue, IrLowering};
+use super::{expect_scalar, field_to_u64, to_ir_span, EnvValue, IrLowering};
 
 impl<F: FieldBackend> IrLowering<F> {
+    /// Lower a scalar expression. Every instruction it emits is given the
+    /// span of the innermost sub-expression that produced it.
     pub(super) fn lower_expr(&mut self, expr: &Expr) -> Result<SsaVar, IrError> {
+        let first = self.program.next_var;
+        let v = self.lower_expr_unspanned(expr)?;
+        self.stamp_spans(first, expr.span());
+        Ok(v)
+    }
+
+    /// Give every variable allocated since `first` that has no span yet
+    /// the span of `expr`. Sub-expressions stamp themselves first, so only
+    /// the instructions this expression emitted directly are left.
+    fn stamp_spans(&mut self, first: u64, span: &Span) {
+        let span = diagnostics::SpanRange::from(span);
+        for id in first..self.program.next_var {
+            self.program
+                .var_spans
+                .entry(SsaVar(id))
+                .or_insert_with(|| span.clone());
+        }
+    }
+
+    fn lower_expr_unspanned(&mut self, expr: &Expr) -> Result<SsaVar, IrError> {
         match expr {
             Expr::Number { value, span, .. } => self.lower_number(value, span),
             Expr::FieldLit {
@@ -36,13 +58,7 @@ impl<F: FieldBackend> IrLowering<F> {
             }
             Expr::Ident { name, span, .. } => {
                 let sp = to_ir_span(span);
-                // Try direct lookup first, then prefixed for module-internal vars
-                let val = self.env.get(name.as_str()).or_else(|| {
-                    self.fn_call_prefix
-                        .as_ref()
-                        .and_then(|prefix| self.env.get(&format!("{prefix}::{name}")))
-                });
-                match val {
+                match self.lookup_env(name) {
                     Some(EnvValue::Scalar(v)) => Ok(*v),
                     Some(EnvValue::Array(_)) => Err(IrError::TypeMismatch {
                         expected: "scalar".into(),
@@ -72,7 +88,18 @@ impl<F: FieldBackend> IrLowering<F> {
                 span,
                 ..
             } => self.lower_for(var, iterable, body, span),
-            Expr::Block { block, .. } => self.lower_block(block),
+            Expr::Block { block, .. } => {
+                let value = self.lower_block(block)?;
+                expect_scalar(value, to_ir_span(&block.span))
+            }
+            Expr::While {
+                max: Some(_), span, ..
+            } => Err(IrError::UnsupportedOperation(
+                "bounded `while` loops need `mut` variables to make progress; \
+                 compile them as a `circuit` instead"
+                    .into(),
+                to_ir_span(span),
+            )),
             Expr::While { span, .. } | Expr::Forever { span, .. } => {
                 Err(IrError::UnboundedLoop(to_ir_span(span)))
             }
@@ -125,10 +152,13 @@ impl<F: FieldBackend> IrLowering<F> {
                     to_ir_span(span),
                 ))
             }
-            Expr::BigIntLit { span, .. } => Err(IrError::TypeNotConstrainable(
-                "BigInt".into(),
-                to_ir_span(span),
-            )),
+            Expr::BigIntLit {
+                value,
+                width,
+                radix,
+                span,
+                ..
+            } => self.lower_bigint_lit(value, *width, radix, span),
             Expr::StaticAccess { span, .. } => Err(IrError::UnsupportedOperation(
                 "static access (Type::MEMBER) is not supported in circuit mode".into(),
                 to_ir_span(span),
@@ -140,6 +170,135 @@ impl<F: FieldBackend> IrLowering<F> {
         }
     }
 
+    /// Lower an expression that may evaluate to an array: array literals,
+    /// array identifiers, and blocks or inlined calls ending in one.
+    /// Everything else is lowered as a scalar via [`lower_expr`](Self::lower_expr).
+    pub(super) fn lower_expr_value(&mut self, expr: &Expr) -> Result<EnvValue, IrError> {
+        let first = self.program.next_var;
+        let value = self.lower_expr_value_unspanned(expr)?;
+        self.stamp_spans(first, expr.span());
+        Ok(value)
+    }
+
+    fn lower_expr_value_unspanned(&mut self, expr: &Expr) -> Result<EnvValue, IrError> {
+        match expr {
+            Expr::Array { elements, span, .. } => {
+                if elements.is_empty() {
+                    return Err(IrError::UnsupportedOperation(
+                        "empty arrays are not allowed in circuits".into(),
+                        to_ir_span(span),
+                    ));
+                }
+                let vars = elements
+                    .iter()
+                    .map(|e| self.lower_expr(e))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(EnvValue::Array(vars))
+            }
+            Expr::Ident { name, .. } => match self.lookup_env(name) {
+                Some(EnvValue::Array(elems)) => Ok(EnvValue::Array(elems.clone())),
+                _ => self.lower_expr(expr).map(EnvValue::Scalar),
+            },
+            Expr::Call {
+                callee, args, span, ..
+            } => {
+                let arg_vals: Vec<&Expr> = args.iter().map(|a| &a.value).collect();
+                self.lower_call_value(callee, &arg_vals, span)
+            }
+            Expr::Block { block, .. } => self.lower_block(block),
+            Expr::UnaryOp {
+                op: UnaryOp::Neg,
+                operand,
+                ..
+            } => self.lower_neg_value(operand),
+            Expr::BinOp {
+                op: BinOp::Mul,
+                lhs,
+                rhs,
+                span,
+                ..
+            } => self.lower_mul_value(lhs, rhs, span),
+            _ => self.lower_expr(expr).map(EnvValue::Scalar),
+        }
+    }
+
+    /// `-operand` where the operand may be an array: arrays negate
+    /// element-wise, scalars lower exactly as in [`lower_unary`](Self::lower_unary).
+    fn lower_neg_value(&mut self, operand: &Expr) -> Result<EnvValue, IrError> {
+        // --x → x, as in `lower_unary`.
+        if let Expr::UnaryOp {
+            op: UnaryOp::Neg,
+            operand: inner,
+            ..
+        } = operand
+        {
+            return self.lower_expr_value(inner);
+        }
+        Ok(match self.lower_expr_value(operand)? {
+            EnvValue::Scalar(v) => EnvValue::Scalar(self.emit_neg(v)),
+            EnvValue::Array(vars) => {
+                EnvValue::Array(vars.into_iter().map(|v| self.emit_neg(v)).collect())
+            }
+        })
+    }
+
+    /// `lhs * rhs` where one side may be an array: an array times a scalar
+    /// scales every element. Multiplying two arrays is rejected — it could
+    /// mean element-wise or a dot product (`dot`).
+    fn lower_mul_value(
+        &mut self,
+        lhs: &Expr,
+        rhs: &Expr,
+        span: &Span,
+    ) -> Result<EnvValue, IrError> {
+        let l = self.lower_expr_value(lhs)?;
+        let r = self.lower_expr_value(rhs)?;
+        Ok(match (l, r) {
+            (EnvValue::Scalar(l), EnvValue::Scalar(r)) => EnvValue::Scalar(self.emit_mul(l, r)),
+            (EnvValue::Array(vars), EnvValue::Scalar(k))
+            | (EnvValue::Scalar(k), EnvValue::Array(vars)) => {
+                EnvValue::Array(vars.into_iter().map(|v| self.emit_mul(v, k)).collect())
+            }
+            (EnvValue::Array(_), EnvValue::Array(_)) => {
+                return Err(IrError::UnsupportedOperation(
+                    "cannot multiply two arrays: `*` only scales an array by a scalar; \
+                     use `dot(a, b)` for the inner product, or compare arrays element by \
+                     element with `assert_eq`"
+                        .into(),
+                    to_ir_span(span),
+                ))
+            }
+        })
+    }
+
+    fn emit_neg(&mut self, operand: SsaVar) -> SsaVar {
+        let v = self.program.fresh_var();
+        self.program.push(Instruction::Neg { result: v, operand });
+        self.program.set_type(v, IrType::Field);
+        v
+    }
+
+    fn emit_mul(&mut self, lhs: SsaVar, rhs: SsaVar) -> SsaVar {
+        let v = self.program.fresh_var();
+        self.program.push(Instruction::Mul {
+            result: v,
+            lhs,
+            rhs,
+        });
+        self.program.set_type(v, IrType::Field);
+        v
+    }
+
+    /// Look up a variable, falling back to the module-prefixed name while
+    /// inlining a module function.
+    pub(super) fn lookup_env(&self, name: &str) -> Option<&EnvValue> {
+        self.env.get(name).or_else(|| {
+            self.fn_call_prefix
+                .as_ref()
+                .and_then(|prefix| self.env.get(&format!("{prefix}::{name}")))
+        })
+    }
+
     fn lower_number(&mut self, s: &str, span: &Span) -> Result<SsaVar, IrError> {
         if s.contains('.') {
             return Err(IrError::TypeNotConstrainable(
@@ -147,49 +306,85 @@ impl<F: FieldBackend> IrLowering<F> {
                 to_ir_span(span),
             ));
         }
-        let (negative, digits) = if let Some(rest) = s.strip_prefix('-') {
-            (true, rest)
-        } else {
-            (false, s)
-        };
-        let fe = FieldElement::from_decimal_str(digits)
+        let fe = FieldElement::from_signed_decimal_str(s)
             .ok_or_else(|| IrError::parse_error(format!("invalid integer: {s}")))?;
         let v = self.program.fresh_var();
-        if negative {
-            let pos = self.program.fresh_var();
-            self.program.push(Instruction::Const {
-                result: pos,
-                value: fe,
-            });
-            self.program.set_type(pos, IrType::Field);
-            self.program.push(Instruction::Neg {
-                result: v,
-                operand: pos,
-            });
-        } else {
-            self.program.push(Instruction::Const {
-                result: v,
-                value: fe,
-            });
-        }
+        self.program.push(Instruction::Const {
+            result: v,
+            value: fe,
+        });
         self.program.set_type(v, IrType::Field);
         Ok(v)
     }
 
+    /// A `0p{x,b}...` literal used as a circuit constant. Parsed at 256 bits
+    /// so a value at or above the field modulus is rejected rather than
+    /// silently reduced.
     fn lower_field_lit(
         &mut self,
         value: &str,
         radix: &FieldRadix,
         span: &Span,
     ) -> Result<SsaVar, IrError> {
-        let fe = match radix {
-            FieldRadix::Decimal => FieldElement::from_decimal_str(value),
-            FieldRadix::Hex => FieldElement::from_hex_str(value),
-            FieldRadix::Binary => FieldElement::from_binary_str(value),
+        let bi = match radix {
+            FieldRadix::Decimal => BigInt::from_decimal_str(value, BigIntWidth::W256),
+            FieldRadix::Hex => BigInt::from_hex_str(value, BigIntWidth::W256),
+            FieldRadix::Binary => BigInt::from_binary_str(value, BigIntWidth::W256),
         }
         .ok_or_else(|| {
             IrError::parse_error(format!("invalid field literal at line {}", span.line_start))
         })?;
+        let fe = bi.to_field().ok_or_else(|| {
+            IrError::UnsupportedOperation(
+                "field literal is not below the field modulus".into(),
+                to_ir_span(span),
+            )
+        })?;
+        let v = self.program.fresh_var();
+        self.program.push(Instruction::Const {
+            result: v,
+            value: fe,
+        });
+        self.program.set_type(v, IrType::Field);
+        Ok(v)
+    }
+
+    /// A `0i<width>{x,d,b}...` literal used as a circuit constant. The value
+    /// must fit its declared width and be below the field modulus.
+    fn lower_bigint_lit(
+        &mut self,
+        value: &str,
+        width: u16,
+        radix: &BigIntRadix,
+        span: &Span,
+    ) -> Result<SsaVar, IrError> {
+        let w = match width {
+            256 => BigIntWidth::W256,
+            512 => BigIntWidth::W512,
+            _ => {
+                return Err(IrError::parse_error(format!(
+                    "invalid BigInt width {width} at line {}",
+                    span.line_start
+                )))
+            }
+        };
+        let bi = match radix {
+            BigIntRadix::Hex => BigInt::from_hex_str(value, w),
+            BigIntRadix::Decimal => BigInt::from_decimal_str(value, w),
+            BigIntRadix::Binary => BigInt::from_binary_str(value, w),
+        }
+        .ok_or_else(|| {
+            IrError::parse_error(format!(
+                "invalid {width}-bit BigInt literal at line {}",
+                span.line_start
+            ))
+        })?;
+        let fe = bi.to_field().ok_or_else(|| {
+            IrError::UnsupportedOperation(
+                "BigInt constant is not below the field modulus".into(),
+                to_ir_span(span),
+            )
+        })?;
         let v = self.program.fresh_var();
         self.program.push(Instruction::Const {
             result: v,
@@ -237,6 +432,13 @@ impl<F: FieldBackend> IrLowering<F> {
             BinOp::Div => {
                 let l = self.lower_expr(lhs)?;
                 let r = self.lower_expr(rhs)?;
+                // A constant-zero divisor has no inverse: reject it here with
+                // the source location instead of at R1CS time.
+                if self.get_const_value(r).is_some_and(|v| v.is_zero()) {
+                    return Err(IrError::DivisionByZero {
+                        span: to_ir_span(span),
+                    });
+                }
                 let v = self.program.fresh_var();
                 self.program
                     .push(Instruction::Div { result: v, lhs: l, rhs: r });
@@ -251,16 +453,22 @@ impl<F: FieldBackend> IrLowering<F> {
                 let base = self.lower_expr(lhs)?;
                 let exp_var = self.lower_expr(rhs)?;
 
+                if self.is_negative_const(exp_var) {
+                    return Err(IrError::UnsupportedOperation(
+                        "negative exponents are not supported in circuits".into(),
+                        to_ir_span(span),
+                    ));
+                }
                 let exp_val = self.get_const_value(exp_var).ok_or_else(|| {
                     IrError::UnsupportedOperation(
                         "exponent must be a constant integer in circuits (x^n is unrolled to n multiplications at compile time)".into(),
-                        None,
+                        to_ir_span(span),
                     )
                 })?;
                 let exp_u64 = field_to_u64(&exp_val).ok_or_else(|| {
                     IrError::UnsupportedOperation(
                         "exponent too large for circuit compilation".into(),
-                        None,
+                        to_ir_span(span),
                     )
                 })?;
 
@@ -313,22 +521,20 @@ impl<F: FieldBackend> IrLowering<F> {
                 Ok(v)
             }
             BinOp::Gt => {
-                // a > b  ≡  b < a
                 let l = self.lower_expr(lhs)?;
                 let r = self.lower_expr(rhs)?;
                 let v = self.program.fresh_var();
                 self.program
-                    .push(Instruction::IsLt { result: v, lhs: r, rhs: l });
+                    .push(Instruction::IsGt { result: v, lhs: l, rhs: r });
                 self.program.set_type(v, IrType::Bool);
                 Ok(v)
             }
             BinOp::Ge => {
-                // a >= b  ≡  b <= a
                 let l = self.lower_expr(lhs)?;
                 let r = self.lower_expr(rhs)?;
                 let v = self.program.fresh_var();
                 self.program
-                    .push(Instruction::IsLe { result: v, lhs: r, rhs: l });
+                    .push(Instruction::IsGe { result: v, lhs: l, rhs: r });
                 self.program.set_type(v, IrType::Bool);
                 Ok(v)
             }
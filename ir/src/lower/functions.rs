@@ -7,17 +7,23 @@ use crate::error::{IrError, OptSpan};
 use crate::types::{Instruction, IrType, SsaVar};
 
 use super::{
-    annotation_to_ir_type, field_to_u64, to_ir_span, type_compatible, EnvValue, FnDef, IrLowering,
+    annotation_to_ir_type, expect_scalar, field_to_u64, to_ir_span, type_compatible, EnvValue,
+    FnDef, IrLowering,
 };
 
 impl<F: FieldBackend> IrLowering<F> {
     /// Handle a call to a user-defined function (inline the body).
+    ///
+    /// The result is whatever the body's final expression evaluates to, so a
+    /// helper ending in `[lo, hi]` returns an array of two signals. A
+    /// top-level `return expr` supplies the result instead (see
+    /// [`Self::lower_fn_body`]).
     pub(super) fn lower_user_fn_call(
         &mut self,
         name: &str,
         args: &[&Expr],
         sp: OptSpan,
-    ) -> Result<SsaVar, IrError> {
+    ) -> Result<EnvValue, IrError> {
         // Try direct lookup first, then prefixed lookup for internal module calls
         let (resolved_name, fn_def) = match self.fn_table.get(name).cloned() {
             Some(fd) => (name.to_string(), fd),
@@ -108,35 +114,20 @@ impl<F: FieldBackend> IrLowering<F> {
         }
 
         // Lower the function body directly (no re-parsing!)
-        let mut result = self.lower_block(&fn_def.body)?;
-
-        // Set return type if declared
-        if let Some(ref ret_ann) = fn_def.return_type {
-            let ret_ty = annotation_to_ir_type(ret_ann, sp.clone())?;
-            if let Some(inferred) = self.program.get_type(result) {
-                if !type_compatible(ret_ty, inferred) {
-                    return Err(IrError::AnnotationMismatch {
-                        name: format!("{resolved_name}() return"),
-                        declared: ret_ty.to_string(),
-                        inferred: inferred.to_string(),
-                        span: sp.clone(),
-                    });
-                }
-                self.program.set_type(result, ret_ty);
-            } else if ret_ty == IrType::Bool {
-                // Untyped return value with Bool return type — emit enforcement
-                let enforced = self.program.fresh_var();
-                self.program.push(Instruction::RangeCheck {
-                    result: enforced,
-                    operand: result,
-                    bits: 1,
-                });
-                self.program.set_type(enforced, IrType::Bool);
-                result = enforced;
-            } else {
-                self.program.set_type(result, ret_ty);
+        let result = match self.lower_fn_body(&fn_def.body)? {
+            EnvValue::Scalar(v) => {
+                EnvValue::Scalar(self.apply_scalar_return_type(&resolved_name, &fn_def, v, &sp)?)
             }
-        }
+            EnvValue::Array(vars) => match fn_def.return_type {
+                Some(ref ret_ann) => EnvValue::Array(self.apply_array_annotation(
+                    &format!("{resolved_name}() return"),
+                    ret_ann,
+                    vars,
+                    sp.clone(),
+                )?),
+                None => EnvValue::Array(vars),
+            },
+        };
 
         // Restore env and fn_call_prefix
         for (param, old_val) in saved {
@@ -155,6 +146,71 @@ impl<F: FieldBackend> IrLowering<F> {
         Ok(result)
     }
 
+    /// Lower an inlined function body. A top-level `return` ends it: the
+    /// statements before it are lowered, its value becomes the result, and
+    /// anything after it is unreachable and skipped. Returns nested in
+    /// branches or loops are left to `lower_stmt`, which rejects them.
+    fn lower_fn_body(&mut self, body: &Block) -> Result<EnvValue, IrError> {
+        let Some(pos) = body
+            .stmts
+            .iter()
+            .position(|s| matches!(s, Stmt::Return { .. }))
+        else {
+            return self.lower_block(body);
+        };
+
+        let mut stmts = body.stmts[..pos].to_vec();
+        if let Stmt::Return {
+            value: Some(expr), ..
+        } = &body.stmts[pos]
+        {
+            stmts.push(Stmt::Expr(expr.clone()));
+        }
+        self.lower_block(&Block {
+            stmts,
+            span: body.span.clone(),
+        })
+    }
+
+    /// Check and stamp a declared return type on a scalar function result,
+    /// enforcing `Bool` with a `RangeCheck` when the value is untyped.
+    fn apply_scalar_return_type(
+        &mut self,
+        resolved_name: &str,
+        fn_def: &FnDef,
+        mut result: SsaVar,
+        sp: &OptSpan,
+    ) -> Result<SsaVar, IrError> {
+        let Some(ref ret_ann) = fn_def.return_type else {
+            return Ok(result);
+        };
+        let ret_ty = annotation_to_ir_type(ret_ann, sp.clone())?;
+        if let Some(inferred) = self.program.get_type(result) {
+            if !type_compatible(ret_ty, inferred) {
+                return Err(IrError::AnnotationMismatch {
+                    name: format!("{resolved_name}() return"),
+                    declared: ret_ty.to_string(),
+                    inferred: inferred.to_string(),
+                    span: sp.clone(),
+                });
+            }
+            self.program.set_type(result, ret_ty);
+        } else if ret_ty == IrType::Bool {
+            // Untyped return value with Bool return type — emit enforcement
+            let enforced = self.program.fresh_var();
+            self.program.push(Instruction::RangeCheck {
+                result: enforced,
+                operand: result,
+                bits: 1,
+            });
+            self.program.set_type(enforced, IrType::Bool);
+            result = enforced;
+        } else {
+            self.program.set_type(result, ret_ty);
+        }
+        Ok(result)
+    }
+
     pub(super) fn lower_index(
         &mut self,
         object: &Expr,
@@ -214,6 +270,12 @@ impl<F: FieldBackend> IrLowering<F> {
     /// constraints (assert_eq, assert, etc.) are emitted unconditionally.
     /// The MUX only selects which *value* to return. This is an inherent
     /// limitation of arithmetic circuits — there is no conditional execution.
+    ///
+    /// `else if` chains nest: `if c1 { a } else if c2 { b } else { c }`
+    /// becomes `mux(c1, a, mux(c2, b, c))`. The conditions need not be
+    /// mutually exclusive — the first true one wins, as in the VM — and
+    /// every Mux condition is boolean-enforced by the backends, so each
+    /// level is a well-defined two-way selection.
     pub(super) fn lower_if(
         &mut self,
         condition: &Expr,
@@ -221,10 +283,10 @@ impl<F: FieldBackend> IrLowering<F> {
         else_branch: Option<&ElseBranch>,
     ) -> Result<SsaVar, IrError> {
         let cond = self.lower_expr(condition)?;
-        let if_true = self.lower_block(then_block)?;
+        let if_true = self.lower_block_scalar(then_block)?;
 
         let if_false = match else_branch {
-            Some(ElseBranch::Block(block)) => self.lower_block(block)?,
+            Some(ElseBranch::Block(block)) => self.lower_block_scalar(block)?,
             Some(ElseBranch::If(if_expr)) => self.lower_expr(if_expr)?,
             None => {
                 let v = self.program.fresh_var();
@@ -265,12 +327,12 @@ impl<F: FieldBackend> IrLowering<F> {
         match iterable {
             ForIterable::Range { start, end } => {
                 let iterations = end.saturating_sub(*start);
-                if iterations > super::MAX_UNROLL_ITERATIONS {
+                if iterations > self.max_unroll {
                     return Err(IrError::UnsupportedOperation(
                         format!(
                             "for loop range {start}..{end} has {iterations} iterations, \
                              exceeding the maximum of {}",
-                            super::MAX_UNROLL_ITERATIONS
+                            self.max_unroll
                         ),
                         to_ir_span(span),
                     ));
@@ -284,7 +346,7 @@ impl<F: FieldBackend> IrLowering<F> {
                         value: FieldElement::<F>::from_u64(i),
                     });
                     self.env.insert(var.to_string(), EnvValue::Scalar(cv));
-                    last = Some(self.lower_block(body)?);
+                    last = Some(self.lower_block_scalar(body)?);
                 }
 
                 self.env.remove(var);
@@ -310,7 +372,7 @@ impl<F: FieldBackend> IrLowering<F> {
                         for elem_var in &elems {
                             self.env
                                 .insert(var.to_string(), EnvValue::Scalar(*elem_var));
-                            last = Some(self.lower_block(body)?);
+                            last = Some(self.lower_block_scalar(body)?);
                         }
                         self.env.remove(var);
                         return Ok(last.unwrap_or_else(|| {
@@ -345,8 +407,11 @@ impl<F: FieldBackend> IrLowering<F> {
         }
     }
 
-    pub(super) fn lower_block(&mut self, block: &Block) -> Result<SsaVar, IrError> {
+    /// Lower a block and return the value of its final expression (a zero
+    /// constant if the block ends in a statement).
+    pub(super) fn lower_block(&mut self, block: &Block) -> Result<EnvValue, IrError> {
         let outer_keys: HashSet<String> = self.env.keys().cloned().collect();
+        self.let_scopes.push(HashSet::new());
         let mut last_var = None;
 
         for stmt in &block.stmts {
@@ -362,7 +427,7 @@ impl<F: FieldBackend> IrLowering<F> {
                     last_var = None;
                 }
                 Stmt::Expr(expr) => {
-                    last_var = Some(self.lower_expr(expr)?);
+                    last_var = Some(self.lower_expr_value(expr)?);
                 }
                 Stmt::FnDecl {
                     name,
@@ -389,6 +454,7 @@ impl<F: FieldBackend> IrLowering<F> {
         }
 
         self.env.retain(|k, _| outer_keys.contains(k));
+        self.let_scopes.pop();
 
         Ok(last_var.unwrap_or_else(|| {
             let v = self.program.fresh_var();
@@ -396,10 +462,16 @@ impl<F: FieldBackend> IrLowering<F> {
                 result: v,
                 value: FieldElement::<F>::zero(),
             });
-            v
+            EnvValue::Scalar(v)
         }))
     }
 
+    /// Lower a block whose value must be a single wire (`if` branches, loop bodies).
+    pub(super) fn lower_block_scalar(&mut self, block: &Block) -> Result<SsaVar, IrError> {
+        let value = self.lower_block(block)?;
+        expect_scalar(value, to_ir_span(&block.span))
+    }
+
     /// Square-and-multiply exponentiation in the IR.
     pub(super) fn pow_by_squaring(&mut self, base: SsaVar, exp: u64) -> Result<SsaVar, IrError> {
         if exp == 0 {
@@ -452,14 +524,26 @@ impl<F: FieldBackend> IrLowering<F> {
 
     /// Look up the constant value of an SSA variable (if it was defined by a Const instruction).
     pub(super) fn get_const_value(&self, var: SsaVar) -> Option<FieldElement<F>> {
-        for inst in &self.program.instructions {
-            if let Instruction::Const { result, value } = inst {
-                if *result == var {
-                    return Some(*value);
-                }
-            }
+        self.const_index
+            .borrow_mut()
+            .value(&self.program.instructions, var)
+    }
+
+    /// Whether `var` is a negated positive integer constant: a `Neg` of a
+    /// non-zero `Const`, or a `Const` of the form `p - n` for a non-zero
+    /// `n` that fits in a `u64`.
+    pub(super) fn is_negative_const(&self, var: SsaVar) -> bool {
+        let small = |fe: FieldElement<F>| !fe.is_zero() && field_to_u64(&fe).is_some();
+        if let Some(value) = self.get_const_value(var) {
+            return field_to_u64(&value).is_none() && small(value.neg());
         }
-        None
+        let operand = self
+            .const_index
+            .borrow_mut()
+            .negated_operand(&self.program.instructions, var);
+        operand
+            .and_then(|operand| self.get_const_value(operand))
+            .is_some_and(small)
     }
 
     /// Emit a constant field element and return its SSA variable.
@@ -477,8 +561,8 @@ impl<F: FieldBackend> IrLowering<F> {
                 return Ok(ev.clone());
             }
         }
-        // Otherwise lower as scalar expression
-        let v = self.lower_expr(expr)?;
-        Ok(EnvValue::Scalar(v))
+        // Otherwise lower it, keeping array-valued expressions such as
+        // `-xs` or `xs * 2` as arrays
+        self.lower_expr_value(expr)
     }
 }
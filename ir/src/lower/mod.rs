@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
@@ -14,8 +15,10 @@ mod functions;
 mod module;
 mod stmts;
 
-/// Maximum number of iterations allowed when statically unrolling a `for` loop.
-/// Prevents DoS via `for i in 0..1000000` which would generate millions of IR instructions.
+/// Default maximum number of iterations allowed when statically unrolling a
+/// `for` loop. Prevents DoS via `for i in 0..1000000` which would generate
+/// millions of IR instructions. Override per lowering with
+/// [`IrLowering::max_unroll`].
 pub const MAX_UNROLL_ITERATIONS: u64 = 10_000;
 
 /// Convert an AST span to a boxed SpanRange for error reporting.
@@ -30,6 +33,18 @@ pub(super) enum EnvValue {
     Array(Vec<SsaVar>),
 }
 
+/// Unwrap a scalar [`EnvValue`], rejecting arrays where a single wire is expected.
+pub(super) fn expect_scalar(value: EnvValue, span: OptSpan) -> Result<SsaVar, IrError> {
+    match value {
+        EnvValue::Scalar(v) => Ok(v),
+        EnvValue::Array(_) => Err(IrError::TypeMismatch {
+            expected: "scalar".into(),
+            got: "array".into(),
+            span,
+        }),
+    }
+}
+
 /// A user-defined function stored for inlining.
 #[derive(Clone, Debug)]
 pub(super) struct FnDef {
@@ -117,6 +132,80 @@ pub struct IrLowering<F: FieldBackend = Bn254Fr> {
     /// When inlining `mod::func`, this is set to `"mod"` so that calls to
     /// `helper()` inside the body resolve to `mod::helper`.
     pub(super) fn_call_prefix: Option<String>,
+    /// Names `let`-bound in each open block, innermost last. Only used to
+    /// record shadowing `let`s; name resolution goes through `env`.
+    pub(super) let_scopes: Vec<HashSet<String>>,
+    /// `Const` / `Neg` lookups for `get_const_value` and
+    /// `is_negative_const`, caught up lazily with the program.
+    pub(super) const_index: RefCell<ConstIndex<F>>,
+    /// Iteration cap for statically unrolled `for` loops. Defaults to
+    /// [`MAX_UNROLL_ITERATIONS`].
+    pub max_unroll: u64,
+}
+
+/// Index of the `Const` values and `Neg` operands among the emitted
+/// instructions, so constant lookups cost O(1) instead of a scan of the
+/// whole program. Instructions are appended by many call sites, so the
+/// index is brought up to date on lookup rather than on push.
+pub(super) struct ConstIndex<F: FieldBackend> {
+    /// Instructions before this position have been indexed.
+    scanned: usize,
+    values: HashMap<SsaVar, FieldElement<F>>,
+    negated: HashMap<SsaVar, SsaVar>,
+}
+
+impl<F: FieldBackend> ConstIndex<F> {
+    fn new() -> Self {
+        Self {
+            scanned: 0,
+            values: HashMap::new(),
+            negated: HashMap::new(),
+        }
+    }
+
+    /// Index the instructions emitted since the last call. Lowering never
+    /// rewrites an instruction in place; the stream only grows, or is cut
+    /// back through [`ConstIndex::truncate`].
+    fn catch_up(&mut self, instructions: &[Instruction<F>]) {
+        for inst in &instructions[self.scanned..] {
+            match inst {
+                Instruction::Const { result, value } => {
+                    self.values.insert(*result, *value);
+                }
+                Instruction::Neg { result, operand } => {
+                    self.negated.insert(*result, *operand);
+                }
+                _ => {}
+            }
+        }
+        self.scanned = instructions.len();
+    }
+
+    /// Rewind after the program was cut back to `len` instructions, so
+    /// whatever is emitted at those positions next gets indexed. Entries
+    /// for the removed variables stay behind; they are never referenced
+    /// again.
+    pub(super) fn truncate(&mut self, len: usize) {
+        self.scanned = self.scanned.min(len);
+    }
+
+    pub(super) fn value(
+        &mut self,
+        instructions: &[Instruction<F>],
+        var: SsaVar,
+    ) -> Option<FieldElement<F>> {
+        self.catch_up(instructions);
+        self.values.get(&var).copied()
+    }
+
+    pub(super) fn negated_operand(
+        &mut self,
+        instructions: &[Instruction<F>],
+        var: SsaVar,
+    ) -> Option<SsaVar> {
+        self.catch_up(instructions);
+        self.negated.get(&var).copied()
+    }
 }
 
 impl<F: FieldBackend> Default for IrLowering<F> {
@@ -136,6 +225,9 @@ impl<F: FieldBackend> IrLowering<F> {
             loading_modules: HashSet::new(),
             loaded_modules: HashMap::new(),
             fn_call_prefix: None,
+            let_scopes: vec![HashSet::new()],
+            const_index: RefCell::new(ConstIndex::new()),
+            max_unroll: MAX_UNROLL_ITERATIONS,
         }
     }
 
@@ -155,6 +247,24 @@ impl<F: FieldBackend> IrLowering<F> {
         );
     }
 
+    /// Note a `let` binding of `name` in the innermost block, recording it as
+    /// shadowed if that block already bound the name. Unrolled loop bodies
+    /// and repeated inlining revisit the same `let`, so each source span is
+    /// recorded once.
+    pub(super) fn record_let_binding(&mut self, name: &str, span: &Span) {
+        let scope = self
+            .let_scopes
+            .last_mut()
+            .expect("the top-level scope is never popped");
+        if scope.insert(name.to_string()) {
+            return;
+        }
+        let entry = (name.to_string(), diagnostics::SpanRange::from(span));
+        if !self.program.shadowed_lets.contains(&entry) {
+            self.program.shadowed_lets.push(entry);
+        }
+    }
+
     /// Declare a public input and emit an `Input` instruction.
     pub fn declare_public(&mut self, name: &str) -> SsaVar {
         let v = self.program.fresh_var();
@@ -196,6 +306,7 @@ impl<F: FieldBackend> IrLowering<F> {
                 v
             })
             .collect();
+        self.program.set_input_array(name.to_string(), size);
         self.env
             .insert(name.to_string(), EnvValue::Array(vars.clone()));
         vars
@@ -216,6 +327,7 @@ impl<F: FieldBackend> IrLowering<F> {
                 v
             })
             .collect();
+        self.program.set_input_array(name.to_string(), size);
         self.env
             .insert(name.to_string(), EnvValue::Array(vars.clone()));
         vars
@@ -254,6 +366,27 @@ impl<F: FieldBackend> IrLowering<F> {
         public: &[&str],
         witness: &[&str],
         base_path: PathBuf,
+    ) -> Result<IrProgram<F>, IrError> {
+        Self::lower_circuit_impl(source, public, witness, base_path, MAX_UNROLL_ITERATIONS)
+    }
+
+    /// [`lower_circuit`](Self::lower_circuit) with a custom cap on unrolled
+    /// `for` loop iterations instead of [`MAX_UNROLL_ITERATIONS`].
+    pub fn lower_circuit_with_max_unroll(
+        source: &str,
+        public: &[&str],
+        witness: &[&str],
+        max_unroll: u64,
+    ) -> Result<IrProgram<F>, IrError> {
+        Self::lower_circuit_impl(source, public, witness, PathBuf::from("."), max_unroll)
+    }
+
+    fn lower_circuit_impl(
+        source: &str,
+        public: &[&str],
+        witness: &[&str],
+        base_path: PathBuf,
+        max_unroll: u64,
     ) -> Result<IrProgram<F>, IrError> {
         let pub_decls = parse_decl_specs(public)?;
         let wit_decls = parse_decl_specs(witness)?;
@@ -274,6 +407,7 @@ impl<F: FieldBackend> IrLowering<F> {
 
         let mut lowering = IrLowering::new();
         lowering.base_path = Some(base_path);
+        lowering.max_unroll = max_unroll;
         for (name, size) in &pub_decls {
             if let Some(n) = size {
                 lowering.declare_public_array(name, *n);
@@ -325,6 +459,25 @@ impl<F: FieldBackend> IrLowering<F> {
     pub fn lower_self_contained_with_base(
         source: &str,
         base_path: PathBuf,
+    ) -> Result<(Vec<String>, Vec<String>, IrProgram<F>), IrError> {
+        Self::lower_self_contained_impl(source, base_path, MAX_UNROLL_ITERATIONS)
+    }
+
+    /// [`lower_self_contained`](Self::lower_self_contained) with a custom cap
+    /// on unrolled `for` loop iterations instead of [`MAX_UNROLL_ITERATIONS`].
+    #[allow(clippy::type_complexity)]
+    pub fn lower_self_contained_with_max_unroll(
+        source: &str,
+        max_unroll: u64,
+    ) -> Result<(Vec<String>, Vec<String>, IrProgram<F>), IrError> {
+        Self::lower_self_contained_impl(source, PathBuf::from("."), max_unroll)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn lower_self_contained_impl(
+        source: &str,
+        base_path: PathBuf,
+        max_unroll: u64,
     ) -> Result<(Vec<String>, Vec<String>, IrProgram<F>), IrError> {
         let (ast_program, parse_errors) = ast_parse_program(source);
         if let Some(err) = parse_errors
@@ -334,36 +487,30 @@ impl<F: FieldBackend> IrLowering<F> {
             return Err(IrError::ParseError(Box::new(err.clone())));
         }
 
-        let mut pub_decls: Vec<(String, Option<usize>, Option<TypeAnnotation>, Span)> = Vec::new();
-        let mut wit_decls: Vec<(String, Option<usize>, Option<TypeAnnotation>, Span)> = Vec::new();
+        let mut pub_decls: Vec<(&InputDecl, &Span)> = Vec::new();
+        let mut wit_decls: Vec<(&InputDecl, &Span)> = Vec::new();
         for stmt in &ast_program.stmts {
             match stmt {
                 Stmt::PublicDecl { names, span } => {
-                    for decl in names {
-                        pub_decls.push((
-                            decl.name.clone(),
-                            decl.array_size,
-                            decl.type_ann.clone(),
-                            span.clone(),
-                        ));
-                    }
+                    pub_decls.extend(names.iter().map(|decl| (decl, span)));
                 }
                 Stmt::WitnessDecl { names, span } => {
-                    for decl in names {
-                        wit_decls.push((
-                            decl.name.clone(),
-                            decl.array_size,
-                            decl.type_ann.clone(),
-                            span.clone(),
-                        ));
-                    }
+                    wit_decls.extend(names.iter().map(|decl| (decl, span)));
                 }
                 _ => {}
             }
         }
 
         let mut pub_names = Vec::new();
-        for (name, size, _, _) in &pub_decls {
+        for (
+            InputDecl {
+                name,
+                array_size: size,
+                ..
+            },
+            _,
+        ) in &pub_decls
+        {
             if let Some(n) = size {
                 for i in 0..*n {
                     pub_names.push(format!("{name}_{i}"));
@@ -373,7 +520,15 @@ impl<F: FieldBackend> IrLowering<F> {
             }
         }
         let mut wit_names = Vec::new();
-        for (name, size, _, _) in &wit_decls {
+        for (
+            InputDecl {
+                name,
+                array_size: size,
+                ..
+            },
+            _,
+        ) in &wit_decls
+        {
             if let Some(n) = size {
                 for i in 0..*n {
                     wit_names.push(format!("{name}_{i}"));
@@ -392,30 +547,35 @@ impl<F: FieldBackend> IrLowering<F> {
 
         let mut lowering = IrLowering::new();
         lowering.base_path = Some(base_path);
-        for (name, size, type_ann, span) in &pub_decls {
+        lowering.max_unroll = max_unroll;
+        for (decl, span) in &pub_decls {
+            let name = &decl.name;
             lowering.record_input_span(name, span);
-            if let Some(n) = size {
-                let vars = lowering.declare_public_array(name, *n);
-                if let Some(ann) = type_ann {
+            lowering.lower_input_default(name, decl.array_size, decl.default.as_ref(), span)?;
+            if let Some(n) = decl.array_size {
+                let vars = lowering.declare_public_array(name, n);
+                if let Some(ann) = &decl.type_ann {
                     lowering.enforce_input_type_ann(ann, &vars, span)?;
                 }
             } else {
                 let v = lowering.declare_public(name);
-                if let Some(ann) = type_ann {
+                if let Some(ann) = &decl.type_ann {
                     lowering.enforce_input_type_ann(ann, &[v], span)?;
                 }
             }
         }
-        for (name, size, type_ann, span) in &wit_decls {
+        for (decl, span) in &wit_decls {
+            let name = &decl.name;
             lowering.record_input_span(name, span);
-            if let Some(n) = size {
-                let vars = lowering.declare_witness_array(name, *n);
-                if let Some(ann) = type_ann {
+            lowering.lower_input_default(name, decl.array_size, decl.default.as_ref(), span)?;
+            if let Some(n) = decl.array_size {
+                let vars = lowering.declare_witness_array(name, n);
+                if let Some(ann) = &decl.type_ann {
                     lowering.enforce_input_type_ann(ann, &vars, span)?;
                 }
             } else {
                 let v = lowering.declare_witness(name);
-                if let Some(ann) = type_ann {
+                if let Some(ann) = &decl.type_ann {
                     lowering.enforce_input_type_ann(ann, &[v], span)?;
                 }
             }
@@ -433,3 +593,49 @@ impl<F: FieldBackend> IrLowering<F> {
         Ok((pub_names, wit_names, lowering.program))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn konst(result: u64, value: u64) -> Instruction<Bn254Fr> {
+        Instruction::Const {
+            result: SsaVar(result),
+            value: FieldElement::from_u64(value),
+        }
+    }
+
+    #[test]
+    fn const_index_scans_each_instruction_once() {
+        let mut index = ConstIndex::<Bn254Fr>::new();
+        let mut insts = vec![konst(0, 1), konst(1, 2)];
+        assert_eq!(
+            index.value(&insts, SsaVar(1)),
+            Some(FieldElement::from_u64(2))
+        );
+
+        // Rewriting an already-indexed slot is invisible: later lookups
+        // only look at instructions pushed since the last one.
+        insts[1] = konst(1, 99);
+        insts.push(konst(2, 3));
+        assert_eq!(
+            index.value(&insts, SsaVar(2)),
+            Some(FieldElement::from_u64(3))
+        );
+        assert_eq!(
+            index.value(&insts, SsaVar(1)),
+            Some(FieldElement::from_u64(2))
+        );
+        assert_eq!(index.scanned, 3);
+
+        // After a truncate the cut positions are indexed again.
+        insts.truncate(1);
+        index.truncate(1);
+        insts.push(konst(3, 4));
+        assert_eq!(
+            index.value(&insts, SsaVar(3)),
+            Some(FieldElement::from_u64(4))
+        );
+        assert_eq!(index.scanned, 2);
+    }
+}
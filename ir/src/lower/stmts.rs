@@ -1,8 +1,9 @@
 use achronyme_parser::ast::*;
 use memory::FieldBackend;
 
-use crate::error::IrError;
-use crate::types::{Instruction, IrType, SsaVar};
+use crate::error::{IrError, OptSpan};
+use crate::passes::const_fold::constant_fold;
+use crate::types::{Instruction, IrProgram, IrType, SsaVar};
 
 use super::{annotation_to_ir_type, to_ir_span, type_compatible, EnvValue, FnDef, IrLowering};
 
@@ -51,10 +52,10 @@ impl<F: FieldBackend> IrLowering<F> {
                 );
                 Ok(None)
             }
-            Stmt::Expr(expr) => {
-                let v = self.lower_expr(expr)?;
-                Ok(Some(v))
-            }
+            Stmt::Expr(expr) => match self.lower_expr_value(expr)? {
+                EnvValue::Scalar(v) => Ok(Some(v)),
+                EnvValue::Array(_) => Ok(None),
+            },
             Stmt::MutDecl { span, .. } => Err(IrError::UnsupportedOperation(
                 "mutable variables are not supported in circuits (circuit variables are immutable — use 'let' instead)".into(),
                 to_ir_span(span),
@@ -75,10 +76,14 @@ impl<F: FieldBackend> IrLowering<F> {
                 "continue is not supported in circuits (loops must have statically-known bounds for unrolling)".into(),
                 to_ir_span(span),
             )),
-            Stmt::Return { span, .. } => Err(IrError::UnsupportedOperation(
+            Stmt::Return { span, .. } if self.call_stack.is_empty() => Err(IrError::UnsupportedOperation(
                 "return is not supported in circuits (circuits are flat constraint systems — use the final expression as the result)".into(),
                 to_ir_span(span),
             )),
+            Stmt::Return { span, .. } => Err(IrError::UnsupportedOperation(
+                "return is only supported as a top-level statement of a function body in circuits (conditional and nested returns cannot be inlined)".into(),
+                to_ir_span(span),
+            )),
             Stmt::Import {
                 path, alias, span, ..
             } => {
@@ -104,6 +109,12 @@ impl<F: FieldBackend> IrLowering<F> {
                 "circuit imports are not supported inside circuits".into(),
                 to_ir_span(span),
             )),
+            // Outputs need their public wire laid out after every input,
+            // which only the ProveIR pipeline arranges.
+            Stmt::OutputDecl { span, .. } => Err(IrError::UnsupportedOperation(
+                "output declarations are only supported in `circuit` declarations".into(),
+                to_ir_span(span),
+            )),
             Stmt::Error { .. } => Ok(None),
         }
     }
@@ -111,6 +122,7 @@ impl<F: FieldBackend> IrLowering<F> {
     fn lower_public_decl(&mut self, names: &[InputDecl], span: &Span) -> Result<(), IrError> {
         for decl in names {
             self.record_input_span(&decl.name, span);
+            self.lower_input_default(&decl.name, decl.array_size, decl.default.as_ref(), span)?;
             if let Some(size) = decl.array_size {
                 let vars = self.declare_public_array(&decl.name, size);
                 if let Some(ref ann) = decl.type_ann {
@@ -129,6 +141,7 @@ impl<F: FieldBackend> IrLowering<F> {
     fn lower_witness_decl(&mut self, names: &[InputDecl], span: &Span) -> Result<(), IrError> {
         for decl in names {
             self.record_input_span(&decl.name, span);
+            self.lower_input_default(&decl.name, decl.array_size, decl.default.as_ref(), span)?;
             if let Some(size) = decl.array_size {
                 let vars = self.declare_witness_array(&decl.name, size);
                 if let Some(ref ann) = decl.type_ann {
@@ -144,6 +157,47 @@ impl<F: FieldBackend> IrLowering<F> {
         Ok(())
     }
 
+    /// Record the `= expr` default of input `name`, if any. The expression
+    /// must fold to a constant; it is lowered into a scratch tail of the
+    /// program and discarded, so a default emits no instructions.
+    pub(super) fn lower_input_default(
+        &mut self,
+        name: &str,
+        array_size: Option<usize>,
+        default: Option<&Expr>,
+        span: &Span,
+    ) -> Result<(), IrError> {
+        let Some(expr) = default else {
+            return Ok(());
+        };
+        if array_size.is_some() {
+            return Err(IrError::UnsupportedOperation(
+                format!("array input `{name}` cannot have a default value"),
+                to_ir_span(span),
+            ));
+        }
+        let mark = self.program.len();
+        let var = self.lower_expr(expr)?;
+        let mut scratch = IrProgram::<F>::new();
+        scratch.set_instructions(self.program.instructions.split_off(mark));
+        self.const_index.borrow_mut().truncate(mark);
+        constant_fold(&mut scratch);
+        let value = scratch
+            .iter()
+            .find_map(|inst| match inst {
+                Instruction::Const { result, value } if *result == var => Some(*value),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                IrError::UnsupportedOperation(
+                    format!("default value of input `{name}` must be a compile-time constant"),
+                    to_ir_span(span),
+                )
+            })?;
+        self.program.set_input_default(name.to_string(), value);
+        Ok(())
+    }
+
     /// Apply a type annotation to input variables, emitting `RangeCheck(v, 1)` for
     /// `: Bool` annotations. Input variables are always untyped at declaration time,
     /// so Bool enforcement is always needed for soundness.
@@ -181,86 +235,21 @@ impl<F: FieldBackend> IrLowering<F> {
         value: &Expr,
         span: &Span,
     ) -> Result<(), IrError> {
-        // Check if RHS is an array literal
-        if let Expr::Array {
-            elements,
-            span: arr_span,
-            ..
-        } = value
-        {
-            let sp = to_ir_span(arr_span);
-            if elements.is_empty() {
-                return Err(IrError::UnsupportedOperation(
-                    "empty arrays are not allowed in circuits".into(),
-                    sp,
-                ));
-            }
-            let mut vars = elements
-                .iter()
-                .map(|e| self.lower_expr(e))
-                .collect::<Result<Vec<_>, _>>()?;
-            // Validate and enforce types from annotation if provided
-            if let Some(ann) = type_ann {
-                // Reject scalar annotations on array values
-                if !ann.is_array() {
-                    return Err(IrError::TypeMismatch {
-                        expected: format!("{ann}[{}]", vars.len()),
-                        got: format!("{ann}"),
-                        span: to_ir_span(span),
-                    });
-                }
-                // Validate array size matches annotation
-                let expected_size = ann.array_len();
-                if let Some(expected) = expected_size {
-                    if vars.len() != expected {
-                        return Err(IrError::ArrayLengthMismatch {
-                            expected,
-                            got: vars.len(),
-                            span: to_ir_span(arr_span),
-                        });
+        self.record_let_binding(name, span);
+        let v = match self.lower_expr_value(value)? {
+            EnvValue::Scalar(v) => v,
+            EnvValue::Array(vars) => {
+                // Array literal, array alias, or an inlined call returning an array
+                let vars = match type_ann {
+                    Some(ann) => {
+                        self.apply_array_annotation(name, ann, vars, to_ir_span(value.span()))?
                     }
-                }
-                let elem_ty = annotation_to_ir_type(ann, to_ir_span(arr_span))?;
-                if elem_ty == IrType::Bool {
-                    // For Bool arrays, enforce each element
-                    for (i, v) in vars.iter_mut().enumerate() {
-                        if let Some(inferred) = self.program.get_type(*v) {
-                            if !type_compatible(elem_ty, inferred) {
-                                return Err(IrError::AnnotationMismatch {
-                                    name: format!("{name}[{i}]"),
-                                    declared: elem_ty.to_string(),
-                                    inferred: inferred.to_string(),
-                                    span: to_ir_span(arr_span),
-                                });
-                            }
-                            // Already typed and compatible (e.g., Bool) — keep as-is
-                        } else {
-                            // Untyped element — emit RangeCheck for enforcement
-                            let enforced = self.program.fresh_var();
-                            self.program.push(Instruction::RangeCheck {
-                                result: enforced,
-                                operand: *v,
-                                bits: 1,
-                            });
-                            self.program.set_type(enforced, IrType::Bool);
-                            *v = enforced;
-                        }
-                    }
-                } else {
-                    // Field[N]: only stamp if element doesn't already have a
-                    // more specific type (Bool is subtype of Field).
-                    for v in &vars {
-                        if self.program.get_type(*v) != Some(IrType::Bool) {
-                            self.program.set_type(*v, elem_ty);
-                        }
-                    }
-                }
+                    None => vars,
+                };
+                self.env.insert(name.to_string(), EnvValue::Array(vars));
+                return Ok(());
             }
-            self.env.insert(name.to_string(), EnvValue::Array(vars));
-            return Ok(());
-        }
-
-        let v = self.lower_expr(value)?;
+        };
 
         // Validate type annotation if present
         let bound_var = if let Some(ann) = type_ann {
@@ -310,4 +299,70 @@ impl<F: FieldBackend> IrLowering<F> {
             .insert(name.to_string(), EnvValue::Scalar(bound_var));
         Ok(())
     }
+
+    /// Validate an array value against a type annotation and enforce its
+    /// element type: each untyped element of a `Bool[N]` gets a
+    /// `RangeCheck(v, 1)`, while `Field[N]` only stamps the type.
+    pub(super) fn apply_array_annotation(
+        &mut self,
+        name: &str,
+        ann: &TypeAnnotation,
+        mut vars: Vec<SsaVar>,
+        sp: OptSpan,
+    ) -> Result<Vec<SsaVar>, IrError> {
+        // Reject scalar annotations on array values
+        if !ann.is_array() {
+            return Err(IrError::TypeMismatch {
+                expected: format!("{ann}[{}]", vars.len()),
+                got: format!("{ann}"),
+                span: sp,
+            });
+        }
+        // Validate array size matches annotation
+        if let Some(expected) = ann.array_len() {
+            if vars.len() != expected {
+                return Err(IrError::ArrayLengthMismatch {
+                    expected,
+                    got: vars.len(),
+                    span: sp,
+                });
+            }
+        }
+        let elem_ty = annotation_to_ir_type(ann, sp.clone())?;
+        if elem_ty == IrType::Bool {
+            // For Bool arrays, enforce each element
+            for (i, v) in vars.iter_mut().enumerate() {
+                if let Some(inferred) = self.program.get_type(*v) {
+                    if !type_compatible(elem_ty, inferred) {
+                        return Err(IrError::AnnotationMismatch {
+                            name: format!("{name}[{i}]"),
+                            declared: elem_ty.to_string(),
+                            inferred: inferred.to_string(),
+                            span: sp,
+                        });
+                    }
+                    // Already typed and compatible (e.g., Bool) — keep as-is
+                } else {
+                    // Untyped element — emit RangeCheck for enforcement
+                    let enforced = self.program.fresh_var();
+                    self.program.push(Instruction::RangeCheck {
+                        result: enforced,
+                        operand: *v,
+                        bits: 1,
+                    });
+                    self.program.set_type(enforced, IrType::Bool);
+                    *v = enforced;
+                }
+            }
+        } else {
+            // Field[N]: only stamp if element doesn't already have a
+            // more specific type (Bool is subtype of Field).
+            for v in &vars {
+                if self.program.get_type(*v) != Some(IrType::Bool) {
+                    self.program.set_type(*v, elem_ty);
+                }
+            }
+        }
+        Ok(vars)
+    }
 }
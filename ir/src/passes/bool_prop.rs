@@ -13,7 +13,7 @@ use crate::types::{Instruction, IrProgram, IrType, SsaVar};
 /// Forward pass that computes the set of SSA variables proven to be boolean
 /// (i.e., their value is always 0 or 1).
 ///
-/// Seeds: `Const(0)`, `Const(1)`, `IsEq`, `IsNeq`, `IsLt`, `IsLe` results,
+/// Seeds: `Const(0)`, `Const(1)`, `IsEq`, `IsNeq`, `IsLt`, `IsLe`, `IsGt`, `IsGe` results,
 /// `Decompose` bit results, and any variable annotated as `Bool` via `program.var_types`.
 ///
 /// Pattern detection:
@@ -72,6 +72,8 @@ pub(crate) fn proven_boolean_dense<F: FieldBackend>(
             | Instruction::IsNeq { result, .. }
             | Instruction::IsLt { result, .. }
             | Instruction::IsLe { result, .. }
+            | Instruction::IsGt { result, .. }
+            | Instruction::IsGe { result, .. }
             | Instruction::IsLtBounded { result, .. }
             | Instruction::IsLeBounded { result, .. } => {
                 booleans.insert(*result);
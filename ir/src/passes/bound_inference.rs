@@ -1,5 +1,6 @@
-//! Bound inference pass: rewrites unbounded IsLt/IsLe to IsLtBounded/IsLeBounded
-//! when both operands have proven bitwidth bounds from prior RangeCheck instructions.
+//! Bound inference pass: rewrites unbounded IsLt/IsLe (and IsGt/IsGe, with the
+//! operands swapped) to IsLtBounded/IsLeBounded when both operands have proven
+//! bitwidth bounds from prior RangeCheck instructions.
 //!
 //! This is the core of the D7 optimization. Without bounds, IsLt uses full 252-bit
 //! decomposition (~761 constraints). With bounds, IsLtBounded uses n+1 bit decomposition
@@ -21,6 +22,7 @@ use memory::FieldBackend;
 use crate::types::{Instruction, IrProgram, SsaVar};
 
 /// Result of the bound inference pass.
+#[derive(Default)]
 pub struct BoundInferenceResult {
     /// Number of IsLt/IsLe rewritten to bounded variants.
     pub rewritten: usize,
@@ -69,7 +71,10 @@ pub fn bound_inference<F: FieldBackend>(
         let mut unbounded = Vec::new();
         for inst in &program.instructions {
             match inst {
-                Instruction::IsLt { result, lhs, rhs } | Instruction::IsLe { result, lhs, rhs } => {
+                Instruction::IsLt { result, lhs, rhs }
+                | Instruction::IsLe { result, lhs, rhs }
+                | Instruction::IsGt { result, lhs, rhs }
+                | Instruction::IsGe { result, lhs, rhs } => {
                     unbounded.push((*result, *lhs, *rhs));
                 }
                 _ => {}
@@ -115,6 +120,36 @@ pub fn bound_inference<F: FieldBackend>(
                     unbounded.push((*result, *lhs, *rhs));
                 }
             }
+            // IsGt/IsGe look bounds up in source order, then lower to the
+            // bounded variant with the operands swapped.
+            Instruction::IsGt { result, lhs, rhs } => {
+                if let (Some(&ba), Some(&bb)) = (bounds.get(lhs), bounds.get(rhs)) {
+                    let bitwidth = ba.max(bb);
+                    *inst = Instruction::IsLtBounded {
+                        result: *result,
+                        lhs: *rhs,
+                        rhs: *lhs,
+                        bitwidth,
+                    };
+                    rewritten += 1;
+                } else {
+                    unbounded.push((*result, *lhs, *rhs));
+                }
+            }
+            Instruction::IsGe { result, lhs, rhs } => {
+                if let (Some(&ba), Some(&bb)) = (bounds.get(lhs), bounds.get(rhs)) {
+                    let bitwidth = ba.max(bb);
+                    *inst = Instruction::IsLeBounded {
+                        result: *result,
+                        lhs: *rhs,
+                        rhs: *lhs,
+                        bitwidth,
+                    };
+                    rewritten += 1;
+                } else {
+                    unbounded.push((*result, *lhs, *rhs));
+                }
+            }
             _ => {}
         }
     }
@@ -183,6 +218,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn rewrites_isgt_to_swapped_islt_bounded() {
+        let mut p = make_program_with_rangecheck_and_islt();
+        p.instructions[4] = Instruction::IsGt {
+            result: SsaVar(4),
+            lhs: SsaVar(0),
+            rhs: SsaVar(1),
+        };
+        let result = bound_inference(&mut p, &HashMap::new());
+        assert_eq!(result.rewritten, 1);
+        match &p.instructions[4] {
+            Instruction::IsLtBounded {
+                bitwidth, lhs, rhs, ..
+            } => {
+                assert_eq!(*bitwidth, 64);
+                assert_eq!(*lhs, SsaVar(1));
+                assert_eq!(*rhs, SsaVar(0));
+            }
+            other => panic!("expected IsLtBounded, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unbounded_isge_reports_source_order() {
+        let mut p = make_program_with_rangecheck_and_islt();
+        p.instructions.remove(3); // drop b's RangeCheck
+        p.instructions[3] = Instruction::IsGe {
+            result: SsaVar(4),
+            lhs: SsaVar(0),
+            rhs: SsaVar(1),
+        };
+        let result = bound_inference(&mut p, &HashMap::new());
+        assert_eq!(result.rewritten, 0);
+        assert!(matches!(p.instructions[3], Instruction::IsGe { .. }));
+        assert_eq!(result.unbounded, vec![(SsaVar(4), SsaVar(0), SsaVar(1))]);
+    }
+
     #[test]
     fn no_rewrite_when_one_unbounded() {
         let mut p: IrProgram = IrProgram::new();
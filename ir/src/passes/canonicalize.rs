@@ -120,7 +120,9 @@ fn rewrite_all_vars<F: FieldBackend>(inst: &mut Instruction<F>, renamer: &HashMa
         | Instruction::IsEq { result, lhs, rhs }
         | Instruction::IsNeq { result, lhs, rhs }
         | Instruction::IsLt { result, lhs, rhs }
-        | Instruction::IsLe { result, lhs, rhs } => {
+        | Instruction::IsLe { result, lhs, rhs }
+        | Instruction::IsGt { result, lhs, rhs }
+        | Instruction::IsGe { result, lhs, rhs } => {
             r(result);
             r(lhs);
             r(rhs);
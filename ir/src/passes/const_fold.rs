@@ -7,13 +7,14 @@ use std::collections::HashMap;
 
 use memory::{FieldBackend, FieldElement};
 
-use crate::types::{Instruction, IrProgram, SsaVar};
+use crate::types::{Instruction, IrProgram, IrType, SsaVar};
 
 /// Constant folding pass.
 ///
 /// Forward pass O(n). Tracks which SSA variables have known constant values.
 /// If all operands of an arithmetic instruction are constants, replaces the
-/// instruction with a `Const`.
+/// instruction with a `Const`. A division by a known non-zero constant
+/// `x / k` becomes the scalar multiply `x * inv(k)`.
 pub fn constant_fold<F: FieldBackend>(program: &mut IrProgram<F>) {
     let mut constants: HashMap<SsaVar, FieldElement<F>> = HashMap::new();
     // Decompose(Const(k), N) can't be replaced in-place (1 → N+1 instructions).
@@ -25,6 +26,9 @@ pub fn constant_fold<F: FieldBackend>(program: &mut IrProgram<F>) {
     // first entry only — every subsequent Decompose's bit_results are
     // dropped.
     let mut decompose_expansions: Vec<(usize, FieldElement<F>, Vec<SsaVar>)> = Vec::new();
+    // Div(x, Const(k)) → Mul(x, Const(inv k)) needs a fresh Const for
+    // the inverse, so it is also deferred to Loop 2, keyed by index.
+    let mut div_rewrites: Vec<(usize, FieldElement<F>)> = Vec::new();
 
     for (idx, inst) in program.instructions.iter_mut().enumerate() {
         match inst {
@@ -188,6 +192,12 @@ pub fn constant_fold<F: FieldBackend>(program: &mut IrProgram<F>) {
                                 value: folded,
                             };
                         }
+                    // x / k → x * inv(k). A zero k has no inverse and is
+                    // left for the backend to reject.
+                    } else if let (None, Some(b)) = (lhs_val, rhs_val) {
+                        if let Some(inv) = b.inv() {
+                            div_rewrites.push((idx, inv));
+                        }
                     }
                 }
             }
@@ -368,7 +378,13 @@ pub fn constant_fold<F: FieldBackend>(program: &mut IrProgram<F>) {
                     };
                 }
             }
+            // IsGt(a, b) ≡ IsLt(b, a); IsGe(a, b) ≡ IsLe(b, a).
             Instruction::IsLt { result, lhs, rhs }
+            | Instruction::IsGt {
+                result,
+                lhs: rhs,
+                rhs: lhs,
+            }
             | Instruction::IsLtBounded {
                 result, lhs, rhs, ..
             } => {
@@ -390,6 +406,11 @@ pub fn constant_fold<F: FieldBackend>(program: &mut IrProgram<F>) {
                 }
             }
             Instruction::IsLe { result, lhs, rhs }
+            | Instruction::IsGe {
+                result,
+                lhs: rhs,
+                rhs: lhs,
+            }
             | Instruction::IsLeBounded {
                 result, lhs, rhs, ..
             } => {
@@ -449,15 +470,52 @@ pub fn constant_fold<F: FieldBackend>(program: &mut IrProgram<F>) {
     }
 
     // Expand constant Decompose: insert Const instructions for each bit
-    // immediately after the Decompose's original position. The
-    // decompose_expansions vec is naturally sorted by `idx` because we
-    // pushed in walk order; we sweep it in lockstep with the drain.
-    if !decompose_expansions.is_empty() {
-        let total_extra: usize = decompose_expansions.iter().map(|(_, _, b)| b.len()).sum();
+    // immediately after the Decompose's original position, and replace
+    // each rewritten Div with `Const(inv k)` + `Mul`. Both vecs are
+    // naturally sorted by `idx` because we pushed in walk order; we sweep
+    // them in lockstep with the drain.
+    if !decompose_expansions.is_empty() || !div_rewrites.is_empty() {
+        let div_rewrites: Vec<(usize, SsaVar, FieldElement<F>)> = div_rewrites
+            .into_iter()
+            .map(|(idx, inv)| {
+                let v = program.fresh_var();
+                program.set_type(v, IrType::Field);
+                // The inverse constant stands in for the divisor: report it
+                // at the division's source location.
+                let div = program.instructions()[idx].result_var();
+                if let Some(span) = program.get_span(div).cloned() {
+                    program.set_span(v, span);
+                }
+                (idx, v, inv)
+            })
+            .collect();
+        let total_extra: usize = decompose_expansions
+            .iter()
+            .map(|(_, _, b)| b.len())
+            .sum::<usize>()
+            + div_rewrites.len();
         let mut new_instructions = Vec::with_capacity(program.len() + total_extra);
         let mut next_exp = 0usize;
+        let mut next_div = 0usize;
 
         for (idx, inst) in program.drain_instructions().enumerate() {
+            if next_div < div_rewrites.len() && div_rewrites[next_div].0 == idx {
+                let (_, inv_var, inv) = div_rewrites[next_div];
+                let Instruction::Div { result, lhs, .. } = inst else {
+                    unreachable!("div_rewrites only records Div instructions");
+                };
+                new_instructions.push(Instruction::Const {
+                    result: inv_var,
+                    value: inv,
+                });
+                new_instructions.push(Instruction::Mul {
+                    result,
+                    lhs,
+                    rhs: inv_var,
+                });
+                next_div += 1;
+                continue;
+            }
             new_instructions.push(inst);
             if next_exp < decompose_expansions.len() && decompose_expansions[next_exp].0 == idx {
                 let (_, val, bits) = &decompose_expansions[next_exp];
@@ -30,6 +30,8 @@ enum CseKey {
     IsNeq(SsaVar, SsaVar),
     IsLt(SsaVar, SsaVar),
     IsLe(SsaVar, SsaVar),
+    IsGt(SsaVar, SsaVar),
+    IsGe(SsaVar, SsaVar),
     IsLtBounded(SsaVar, SsaVar, u32),
     IsLeBounded(SsaVar, SsaVar, u32),
     IntDiv(SsaVar, SsaVar, u32),
@@ -58,6 +60,8 @@ fn cse_key<F: FieldBackend>(inst: &Instruction<F>) -> Option<CseKey> {
         Instruction::IsNeq { lhs, rhs, .. } => Some(CseKey::IsNeq(*lhs, *rhs)),
         Instruction::IsLt { lhs, rhs, .. } => Some(CseKey::IsLt(*lhs, *rhs)),
         Instruction::IsLe { lhs, rhs, .. } => Some(CseKey::IsLe(*lhs, *rhs)),
+        Instruction::IsGt { lhs, rhs, .. } => Some(CseKey::IsGt(*lhs, *rhs)),
+        Instruction::IsGe { lhs, rhs, .. } => Some(CseKey::IsGe(*lhs, *rhs)),
         Instruction::IsLtBounded {
             lhs, rhs, bitwidth, ..
         } => Some(CseKey::IsLtBounded(*lhs, *rhs, *bitwidth)),
@@ -139,6 +143,8 @@ fn canonicalize_key(key: &CseKey, replacements: &FxHashMap<SsaVar, SsaVar>) -> C
         CseKey::IsNeq(a, b) => CseKey::IsNeq(r(a), r(b)),
         CseKey::IsLt(a, b) => CseKey::IsLt(r(a), r(b)),
         CseKey::IsLe(a, b) => CseKey::IsLe(r(a), r(b)),
+        CseKey::IsGt(a, b) => CseKey::IsGt(r(a), r(b)),
+        CseKey::IsGe(a, b) => CseKey::IsGe(r(a), r(b)),
         CseKey::IsLtBounded(a, b, w) => CseKey::IsLtBounded(r(a), r(b), *w),
         CseKey::IsLeBounded(a, b, w) => CseKey::IsLeBounded(r(a), r(b), *w),
         CseKey::IntDiv(a, b, w) => CseKey::IntDiv(r(a), r(b), *w),
@@ -176,6 +182,8 @@ fn rewrite_operands<F: FieldBackend>(
         | Instruction::IsNeq { lhs, rhs, .. }
         | Instruction::IsLt { lhs, rhs, .. }
         | Instruction::IsLe { lhs, rhs, .. }
+        | Instruction::IsGt { lhs, rhs, .. }
+        | Instruction::IsGe { lhs, rhs, .. }
         | Instruction::IsLtBounded { lhs, rhs, .. }
         | Instruction::IsLeBounded { lhs, rhs, .. } => {
             r(lhs);
@@ -16,8 +16,10 @@
 //!
 //! Streams the reference handles via degenerate-input fallbacks
 //! (duplicate definitions — which every `Decompose` produces, since
-//! its result aliases its operand) and the windowed interner modes
-//! take [`reference_fallback`]: materialize + `optimize()` verbatim.
+//! its result aliases its operand), divisions by a constant (folded
+//! to a multiply by a fresh inverse `Const`) and the windowed
+//! interner modes take [`reference_fallback`]: materialize +
+//! `optimize()` verbatim.
 //! `cse` needs no fused counterpart: hash-consing guarantees no two
 //! pure instructions share `(kind, operands)`, and constant folding
 //! never rewrites operands, so CSE is structurally zero on interner
@@ -48,7 +50,7 @@ pub struct FusedOutcome<F: FieldBackend> {
     pub stats: OptimizeStats,
     /// `true` when the stream took the materialize + `optimize()`
     /// reference path (windowed interner, duplicate definitions,
-    /// `Decompose`-bearing streams).
+    /// `Decompose`-bearing streams, constant divisors).
     pub used_fallback: bool,
 }
 
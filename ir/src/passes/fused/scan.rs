@@ -31,6 +31,10 @@ pub(super) enum ScanPoison {
     /// stream non-SSA by construction; the constant-operand expansion
     /// also only exists on the materialized pass.
     Decompose,
+    /// `x / k` with a non-constant `x` and a constant invertible `k`:
+    /// the reference fold rewrites it to `x * inv(k)` with a fresh
+    /// `Const`, which only exists on the materialized pass.
+    ConstDivisor,
     /// Stream too large for the u32 event indices.
     TooManyEvents,
 }
@@ -385,6 +389,14 @@ pub(super) fn scan<F: FieldBackend>(interner: &NodeInterner<F>) -> Result<Scan<F
                     constants.insert(r, val);
                     out.folded.insert(e32, val);
                 } else {
+                    if let NodeKey::Div(l, rh) = key {
+                        let divisor = constants.get(&idx(*rh));
+                        if !constants.contains_key(&idx(*l))
+                            && divisor.is_some_and(|k| !k.is_zero() && *k != FieldElement::one())
+                        {
+                            return Err(ScanPoison::ConstDivisor);
+                        }
+                    }
                     for_each_key_operand(key, |op| out.count_use(op));
                     match key {
                         NodeKey::IsLt(l, rh) => out.cmps.push(CmpSite {
@@ -335,6 +335,40 @@ fn decompose_stream_takes_reference_fallback() {
     );
 }
 
+#[test]
+fn constant_divisor_takes_reference_fallback() {
+    // `x / 4` becomes `x * inv(4)` with a fresh Const on the reference
+    // fold; `x / 1` stays a Div and keeps the fast path.
+    assert_parity(
+        |sink| {
+            let x = input(sink, "x");
+            let k = konst(sink, 4);
+            let q = sink.intern_pure(InstructionKind::Div {
+                result: NodeId::PLACEHOLDER,
+                lhs: x,
+                rhs: k,
+            });
+            let y = input(sink, "y");
+            assert_eq_effect(sink, q, y);
+        },
+        true,
+    );
+    assert_parity(
+        |sink| {
+            let x = input(sink, "x");
+            let one = konst(sink, 1);
+            let q = sink.intern_pure(InstructionKind::Div {
+                result: NodeId::PLACEHOLDER,
+                lhs: x,
+                rhs: one,
+            });
+            let y = input(sink, "y");
+            assert_eq_effect(sink, q, y);
+        },
+        false,
+    );
+}
+
 #[test]
 fn bound_rewrite_colliding_with_existing_bounded_takes_fallback() {
     // A pre-existing IsLtBounded{a,b,64} plus an unbounded IsLt(a,b)
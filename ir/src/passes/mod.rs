@@ -9,12 +9,14 @@ pub mod dense;
 pub mod fused;
 pub mod taint;
 pub mod validate;
+pub mod verify;
 
 pub use canonicalize::canonicalize_ssa;
 
 use memory::FieldBackend;
 
 use crate::types::{Instruction, IrProgram};
+use crate::IrError;
 
 /// Statistics returned by the optimization pipeline.
 pub struct OptimizeStats {
@@ -38,10 +40,38 @@ pub struct OptimizeStats {
     pub bit_pattern_booleans: usize,
 }
 
-/// Run all optimization passes on the IR program.
+/// How much of the optimization pipeline [`optimize_with`] runs.
 ///
-/// Applies constant folding and dead code elimination.
-/// Returns statistics about what was optimized.
+/// Lower levels are for debugging: comparing constraint counts across
+/// levels isolates which pass changed (or broke) a circuit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum OptLevel {
+    /// No passes; the program is left exactly as lowered.
+    O0,
+    /// Constant folding and dead code elimination.
+    O1,
+    /// The full pipeline: constant folding, bit-pattern bound
+    /// inference, CSE, and dead code elimination.
+    #[default]
+    O2,
+}
+
+impl OptLevel {
+    /// Parse a numeric level (`0`, `1`, `2`), as given to `--opt-level`.
+    pub fn from_level(level: u8) -> Option<Self> {
+        match level {
+            0 => Some(Self::O0),
+            1 => Some(Self::O1),
+            2 => Some(Self::O2),
+            _ => None,
+        }
+    }
+}
+
+/// Run all optimization passes on the IR program ([`OptLevel::O2`]).
+///
+/// Applies constant folding, bound inference, CSE, and dead code
+/// elimination. Returns statistics about what was optimized.
 ///
 /// ```
 /// use ir::IrLowering;
@@ -58,7 +88,46 @@ pub struct OptimizeStats {
 /// assert_eq!(stats.total_after, prog.len());
 /// ```
 pub fn optimize<F: FieldBackend>(program: &mut IrProgram<F>) -> OptimizeStats {
+    optimize_with(program, OptLevel::O2)
+}
+
+/// Run the optimization passes selected by `level`.
+///
+/// [`OptLevel::O2`] is exactly [`optimize`]. Stats for passes that did
+/// not run are zero.
+///
+/// ```
+/// use ir::IrLowering;
+/// use ir::passes::{optimize_with, OptLevel};
+///
+/// let mut prog: ir::types::IrProgram = IrLowering::lower_circuit(
+///     "let a = 2 + 3\nassert_eq(x, a)",
+///     &["x"],
+///     &[],
+/// ).unwrap();
+/// let before = prog.len();
+/// let stats = optimize_with(&mut prog, OptLevel::O0);
+/// assert_eq!(prog.len(), before);
+/// assert_eq!(stats.total_after, before);
+/// ```
+pub fn optimize_with<F: FieldBackend>(
+    program: &mut IrProgram<F>,
+    level: OptLevel,
+) -> OptimizeStats {
     let total_before = program.len();
+    if level == OptLevel::O0 {
+        return OptimizeStats {
+            total_before,
+            const_fold_converted: 0,
+            cse_eliminated: 0,
+            dce_eliminated: 0,
+            tautological_asserts_eliminated: 0,
+            total_after: total_before,
+            bound_inference: Default::default(),
+            bit_pattern_bounds: 0,
+            bit_pattern_booleans: 0,
+        };
+    }
 
     // Count Const instructions before folding
     let consts_before = program
@@ -80,23 +149,40 @@ pub fn optimize<F: FieldBackend>(program: &mut IrProgram<F>) -> OptimizeStats {
     let before = snapshot(program);
     const_fold::constant_fold(program);
     validate::assert_no_dangling_ssa_vars_with_before(program, before.as_deref(), "const_fold");
-    // Boolean propagation and bit-pattern detection both resolve
-    // defining instructions and constants; build the dense indices once
-    // and share them. Both passes only read the program, so the indices
-    // stay valid until bound_inference mutates it below.
-    let bp_result = {
-        let def_index = dense::DefIndex::build(program);
-        let const_index = dense::ConstIndex::build(program);
-        let proven_booleans = bool_prop::proven_boolean_dense(program, &def_index, &const_index);
-        bit_pattern::detect_bit_patterns_with(program, &def_index, &const_index, &proven_booleans)
+    debug_verify(program, "const_fold");
+    let (bit_pattern_bounds, bit_pattern_booleans, bi_result) = if level >= OptLevel::O2 {
+        // Boolean propagation and bit-pattern detection both resolve
+        // defining instructions and constants; build the dense indices once
+        // and share them. Both passes only read the program, so the indices
+        // stay valid until bound_inference mutates it below.
+        let bp_result = {
+            let def_index = dense::DefIndex::build(program);
+            let const_index = dense::ConstIndex::build(program);
+            let proven_booleans =
+                bool_prop::proven_boolean_dense(program, &def_index, &const_index);
+            bit_pattern::detect_bit_patterns_with(
+                program,
+                &def_index,
+                &const_index,
+                &proven_booleans,
+            )
+        };
+        let before = snapshot(program);
+        let bi_result = bound_inference::bound_inference(program, &bp_result.bounds);
+        validate::assert_no_dangling_ssa_vars_with_before(
+            program,
+            before.as_deref(),
+            "bound_inference",
+        );
+        debug_verify(program, "bound_inference");
+        (
+            bp_result.bounds.len(),
+            bp_result.booleans_detected,
+            bi_result,
+        )
+    } else {
+        (0, 0, Default::default())
     };
-    let before = snapshot(program);
-    let bi_result = bound_inference::bound_inference(program, &bp_result.bounds);
-    validate::assert_no_dangling_ssa_vars_with_before(
-        program,
-        before.as_deref(),
-        "bound_inference",
-    );
 
     // Count Const instructions after folding — difference = folded
     let consts_after = program
@@ -105,9 +191,15 @@ pub fn optimize<F: FieldBackend>(program: &mut IrProgram<F>) -> OptimizeStats {
         .count();
     let const_fold_converted = consts_after.saturating_sub(consts_before);
 
-    let before = snapshot(program);
-    let cse_eliminated = cse::common_subexpression_elimination(program);
-    validate::assert_no_dangling_ssa_vars_with_before(program, before.as_deref(), "cse");
+    let cse_eliminated = if level >= OptLevel::O2 {
+        let before = snapshot(program);
+        let eliminated = cse::common_subexpression_elimination(program);
+        validate::assert_no_dangling_ssa_vars_with_before(program, before.as_deref(), "cse");
+        debug_verify(program, "cse");
+        eliminated
+    } else {
+        0
+    };
 
     // Count tautological AssertEq(x, x) before DCE removes them
     let tautological_before = program
@@ -119,6 +211,7 @@ pub fn optimize<F: FieldBackend>(program: &mut IrProgram<F>) -> OptimizeStats {
     let before = snapshot(program);
     dce::dead_code_elimination(program);
     validate::assert_no_dangling_ssa_vars_with_before(program, before.as_deref(), "dce");
+    debug_verify(program, "dce");
     let dce_eliminated = before_dce
         .saturating_sub(program.len())
         .saturating_sub(tautological_before);
@@ -133,8 +226,22 @@ pub fn optimize<F: FieldBackend>(program: &mut IrProgram<F>) -> OptimizeStats {
         tautological_asserts_eliminated: tautological_before,
         total_after,
         bound_inference: bi_result,
-        bit_pattern_bounds: bp_result.bounds.len(),
-        bit_pattern_booleans: bp_result.booleans_detected,
+        bit_pattern_bounds,
+        bit_pattern_booleans,
+    }
+}
+
+/// Fail fast if `pass` left `program` malformed. Debug builds only; in
+/// release the check is compiled out.
+fn debug_verify<F: FieldBackend>(program: &IrProgram<F>, pass: &'static str) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    if let Err(mut err) = verify::verify_well_formed(program) {
+        if let IrError::MalformedIr { pass: p, .. } = &mut err {
+            *p = Some(pass.to_string());
+        }
+        panic!("{err}");
     }
 }
 
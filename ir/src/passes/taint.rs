@@ -43,6 +43,9 @@ pub enum TaintWarning {
         visibility: Visibility,
         span: Option<SpanRange>,
     },
+    /// A `let` that re-binds a name already `let`-bound in the same block.
+    /// Legal — the later binding wins — but easy to write by accident.
+    Shadowed { name: String, span: SpanRange },
 }
 
 impl TaintWarning {
@@ -50,6 +53,7 @@ impl TaintWarning {
         match self {
             TaintWarning::UnderConstrained { span, .. }
             | TaintWarning::UnusedInput { span, .. } => span.as_ref(),
+            TaintWarning::Shadowed { span, .. } => Some(span),
         }
     }
 }
@@ -78,6 +82,12 @@ impl fmt::Display for TaintWarning {
                 };
                 write!(f, "{vis} input `{name}` is unused")
             }
+            TaintWarning::Shadowed { name, .. } => {
+                write!(
+                    f,
+                    "`let {name}` shadows an earlier `{name}` in the same scope"
+                )
+            }
         }
     }
 }
@@ -85,7 +95,8 @@ impl fmt::Display for TaintWarning {
 /// Run taint analysis on an IR program.
 ///
 /// Returns the taint map and a list of warnings about under-constrained
-/// or unused inputs.
+/// or unused inputs, followed by any shadowing `let`s recorded during
+/// lowering.
 pub fn taint_analysis<F: FieldBackend>(
     program: &IrProgram<F>,
 ) -> (HashMap<SsaVar, Taint>, Vec<TaintWarning>) {
@@ -194,6 +205,8 @@ pub fn taint_analysis<F: FieldBackend>(
             | Instruction::IsNeq { result, lhs, rhs }
             | Instruction::IsLt { result, lhs, rhs }
             | Instruction::IsLe { result, lhs, rhs }
+            | Instruction::IsGt { result, lhs, rhs }
+            | Instruction::IsGe { result, lhs, rhs }
             | Instruction::IsLtBounded {
                 result, lhs, rhs, ..
             }
@@ -294,6 +307,16 @@ pub fn taint_analysis<F: FieldBackend>(
         }
     }
 
+    warnings.extend(
+        program
+            .shadowed_lets
+            .iter()
+            .map(|(name, span)| TaintWarning::Shadowed {
+                name: name.clone(),
+                span: span.clone(),
+            }),
+    );
+
     (taints, warnings)
 }
 
@@ -0,0 +1,79 @@
+//! IR well-formedness checker.
+//!
+//! Unlike [`super::validate`], which is an env-gated debugging aid that
+//! panics with a rich diagnostic, this is a cheap structural check that
+//! returns an [`IrError`]. `optimize_with` runs it after every pass in
+//! debug builds, so a pass that leaves a dangling or doubly-defined
+//! `SsaVar` fails at the pass that broke it rather than later in the
+//! R1CS/Plonkish backend.
+
+use std::collections::HashSet;
+
+use memory::FieldBackend;
+
+use crate::types::{Instruction, IrProgram, SsaVar};
+use crate::IrError;
+
+/// Check the SSA invariants of `program`:
+///
+/// - every result `SsaVar` (including `Decompose` bits and extra
+///   `WitnessCall` outputs) is assigned exactly once, below `next_var`.
+///   The one sanctioned exception is the alias-style `Decompose`, whose
+///   `result` is its own operand: it re-binds an existing var rather
+///   than defining a new one;
+/// - every operand references a var defined by an earlier instruction,
+///   so references to vars whose definition a pass eliminated are caught.
+///
+/// ```
+/// use ir::passes::verify::verify_well_formed;
+/// use ir::IrLowering;
+///
+/// let prog: ir::types::IrProgram =
+///     IrLowering::lower_circuit("assert_eq(x * y, z)", &["z"], &["x", "y"]).unwrap();
+/// assert!(verify_well_formed(&prog).is_ok());
+/// ```
+pub fn verify_well_formed<F: FieldBackend>(program: &IrProgram<F>) -> Result<(), IrError> {
+    let malformed = |var: SsaVar, reason: String| IrError::MalformedIr {
+        pass: None,
+        var,
+        reason,
+    };
+
+    let mut defined: HashSet<SsaVar> = HashSet::with_capacity(program.len());
+    for (idx, inst) in program.instructions.iter().enumerate() {
+        for op in inst.operands() {
+            if !defined.contains(&op) {
+                return Err(malformed(
+                    op,
+                    format!("is used by instruction #{idx} ({inst}) before any definition"),
+                ));
+            }
+        }
+        let aliases_operand = matches!(
+            inst,
+            Instruction::Decompose { result, operand, .. } if result == operand
+        );
+        let primary = (!aliases_operand).then(|| inst.result_var());
+        let results = primary
+            .into_iter()
+            .chain(inst.extra_result_vars().iter().copied());
+        for result in results {
+            if result.0 >= program.next_var {
+                return Err(malformed(
+                    result,
+                    format!(
+                        "is defined by instruction #{idx} ({inst}) but was never allocated (next_var is {})",
+                        program.next_var
+                    ),
+                ));
+            }
+            if !defined.insert(result) {
+                return Err(malformed(
+                    result,
+                    format!("is defined more than once (again by instruction #{idx}: {inst})"),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
@@ -11,7 +11,7 @@ pub enum ConstraintCategory {
     RangeCheck,
     /// PoseidonHash
     Hash,
-    /// IsEq, IsNeq, IsLt, IsLe, IsLtBounded, IsLeBounded
+    /// IsEq, IsNeq, IsLt, IsLe, IsGt, IsGe, IsLtBounded, IsLeBounded
     Comparison,
     /// And, Or, Not
     Boolean,
@@ -143,7 +143,9 @@ impl CircuitStats {
                     let cost = is_lt_cost(&range_bounds, lhs, rhs);
                     (ConstraintCategory::Comparison, cost)
                 }
-                Instruction::IsLe { lhs, rhs, .. } => {
+                Instruction::IsLe { lhs, rhs, .. }
+                | Instruction::IsGt { lhs, rhs, .. }
+                | Instruction::IsGe { lhs, rhs, .. } => {
                     let cost = is_lt_cost(&range_bounds, lhs, rhs);
                     (ConstraintCategory::Comparison, cost)
                 }
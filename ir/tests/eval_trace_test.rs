@@ -0,0 +1,45 @@
+//! `ACH_TRACE_EVAL=1` makes `ir::eval::evaluate` log one line per SSA
+//! assignment to stderr. The test re-runs itself in a child process
+//! with the var set and parses the child's stderr.
+
+use std::collections::HashMap;
+use std::process::Command;
+
+use ir::eval::{evaluate, TRACE_EVAL_VAR};
+use ir::IrLowering;
+use memory::{Bn254Fr, FieldElement};
+
+#[test]
+fn trace_eval_logs_each_assignment() {
+    let program = IrLowering::<Bn254Fr>::lower_circuit("let z = x * y", &[], &["x", "y"]).unwrap();
+
+    if std::env::var_os(TRACE_EVAL_VAR).is_some() {
+        // Child: the parent reads the trace off our stderr.
+        let inputs: HashMap<_, _> = [("x", 6), ("y", 7)]
+            .into_iter()
+            .map(|(n, v)| (n.to_string(), FieldElement::from_u64(v)))
+            .collect();
+        evaluate(&program, &inputs).unwrap();
+        return;
+    }
+
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["trace_eval_logs_each_assignment", "--exact"])
+        .env(TRACE_EVAL_VAR, "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "child test failed: {output:?}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    let lines: Vec<&str> = stderr
+        .lines()
+        .filter_map(|line| line.strip_prefix("ach-trace-eval "))
+        .collect();
+    assert_eq!(lines.len(), program.len(), "one line per instruction");
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.contains("op=Mul") && line.ends_with(" = 42")),
+        "no Mul = 42 entry in trace:\n{stderr}"
+    );
+}
@@ -150,6 +150,9 @@ fn clone_program(p: &IrProgram) -> IrProgram {
         var_types: p.var_types.clone(),
         input_spans: p.input_spans.clone(),
         var_spans: p.var_spans.clone(),
+        input_defaults: p.input_defaults.clone(),
+        input_arrays: p.input_arrays.clone(),
+        shadowed_lets: p.shadowed_lets.clone(),
     }
 }
 
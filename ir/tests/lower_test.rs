@@ -1,4 +1,4 @@
-use ir::{Instruction, IrLowering, Visibility};
+use ir::{Instruction, IrLowering, SsaVar, Visibility};
 use memory::FieldElement;
 
 /// Helper: lower a circuit with given public/witness inputs.
@@ -30,3 +30,6 @@ mod typing_basics;
 
 #[path = "lower_test/typing_enforcement.rs"]
 mod typing_enforcement;
+
+#[path = "lower_test/spans.rs"]
+mod spans;
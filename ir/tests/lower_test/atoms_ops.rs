@@ -55,6 +55,27 @@ fn lower_very_large_integer_literal() {
     }
 }
 
+#[test]
+fn lower_bigint_literal_constant() {
+    // A 200-bit value: 50 hex digits with the top bit of the first set.
+    let hex = "d0123456789abcdef0123456789abcdef0123456789abcdef0";
+    let insts = lower(&format!("0i256x{hex}"), &[], &[]);
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::Const { .. })), 1);
+    let Instruction::Const { value, .. } = &insts[0] else {
+        panic!("expected Const for BigInt literal");
+    };
+    assert_eq!(*value, FieldElement::from_hex_str(hex).unwrap());
+    assert_eq!(value.to_canonical()[3] >> 7, 1, "bit 199 is set");
+}
+
+#[test]
+fn lower_bigint_literal_at_modulus_rejected() {
+    let p = "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+    let err =
+        IrLowering::<memory::Bn254Fr>::lower_circuit(&format!("0i256x{p}"), &[], &[]).unwrap_err();
+    assert!(err.to_string().contains("field modulus"), "{err}");
+}
+
 #[test]
 fn lower_identifier_lookup() {
     let insts = lower("x", &["x"], &[]);
@@ -90,6 +111,26 @@ fn lower_division() {
     assert_eq!(count(&insts, |i| matches!(i, Instruction::Div { .. })), 1);
 }
 
+#[test]
+fn lower_division_by_constant_zero_rejected_with_span() {
+    let result = IrLowering::<memory::Bn254Fr>::lower_circuit("let y = x + 1\ny / 0", &[], &["x"]);
+    let Err(err) = result else {
+        panic!("x / 0 must be rejected");
+    };
+    let ir::IrError::DivisionByZero { span } = &err else {
+        panic!("expected DivisionByZero, got {err:?}");
+    };
+    let span = span.as_deref().expect("division by zero must carry a span");
+    assert_eq!((span.line_start, span.col_start), (2, 1));
+    assert!(format!("{err}").contains("division by zero"));
+}
+
+#[test]
+fn lower_division_by_nonzero_constant_allowed() {
+    let insts = lower("x / 2", &[], &["x"]);
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::Div { .. })), 1);
+}
+
 #[test]
 fn lower_complex_expression() {
     // x * y + z
@@ -134,6 +175,18 @@ fn lower_power_zero() {
     assert!(matches!(last, Instruction::Const { value, .. } if *value == FieldElement::ONE));
 }
 
+#[test]
+fn lower_power_negative_exponent_rejected() {
+    let err = IrLowering::<memory::Bn254Fr>::lower_circuit("x ^ -2", &[], &["x"]).unwrap_err();
+    match err {
+        ir::IrError::UnsupportedOperation(msg, span) => {
+            assert_eq!(msg, "negative exponents are not supported in circuits");
+            assert!(span.is_some(), "error should carry the source span");
+        }
+        other => panic!("expected UnsupportedOperation, got {other:?}"),
+    }
+}
+
 // ============================================================================
 // Let bindings (aliasing)
 // ============================================================================
@@ -28,6 +28,176 @@ fn lower_mux() {
     assert_eq!(count(&insts, |i| matches!(i, Instruction::Mux { .. })), 1);
 }
 
+#[test]
+fn lower_bits() {
+    let insts = lower("let b = bits(x, 4)\nassert_eq(b[3], b[0])", &[], &["x"]);
+    let decomposes: Vec<_> = insts
+        .iter()
+        .filter_map(|i| match i {
+            Instruction::Decompose {
+                bit_results,
+                num_bits,
+                ..
+            } => Some((bit_results.len(), *num_bits)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(decomposes, vec![(4, 4)]);
+}
+
+#[test]
+fn lower_merkle_root() {
+    // 4 leaves → 2 + 1 hashes.
+    let insts = lower(
+        "let leaves = [a, b, c, d]\nassert_eq(merkle_root(leaves), r)",
+        &["r"],
+        &["a", "b", "c", "d"],
+    );
+    assert_eq!(
+        count(&insts, |i| matches!(i, Instruction::PoseidonHash { .. })),
+        3
+    );
+}
+
+#[test]
+fn lower_merkle_root_of_array_expression() {
+    // Array-valued arguments need not be bound to a name first.
+    let insts = lower(
+        "let leaves = [a, b]\nassert_eq(merkle_root(-(leaves * 2)), r)",
+        &["r"],
+        &["a", "b"],
+    );
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::Mul { .. })), 2);
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::Neg { .. })), 2);
+    assert_eq!(
+        count(&insts, |i| matches!(i, Instruction::PoseidonHash { .. })),
+        1
+    );
+}
+
+#[test]
+fn lower_dot() {
+    // One Mul per index, summed with linear Adds.
+    let insts = lower("dot(a, b)", &[], &["a[3]", "b[3]"]);
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::Mul { .. })), 3);
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::Add { .. })), 2);
+}
+
+#[test]
+fn lower_dot_rejects_scalar_argument() {
+    let err =
+        IrLowering::<memory::Bn254Fr>::lower_circuit("dot(a, x)", &[], &["a[2]", "x"]).unwrap_err();
+    assert!(
+        matches!(err, ir::IrError::TypeMismatch { ref expected, .. } if expected == "array"),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn lower_assert_all_eq() {
+    // The first argument is compared against each of the others.
+    let insts = lower("assert_all_eq(a, b, c, d)", &["a"], &["b", "c", "d"]);
+    let pairs: Vec<_> = insts
+        .iter()
+        .filter_map(|i| match i {
+            Instruction::AssertEq { lhs, rhs, .. } => Some((*lhs, *rhs)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(pairs.len(), 3);
+    assert!(pairs.iter().all(|(lhs, _)| *lhs == pairs[0].0));
+}
+
+#[test]
+fn lower_assert_all_eq_needs_two_arguments() {
+    let err =
+        IrLowering::<memory::Bn254Fr>::lower_circuit("assert_all_eq(a)", &["a"], &[]).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            ir::IrError::WrongArgumentCount {
+                expected: 2,
+                got: 1,
+                ..
+            }
+        ),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn lower_assert_in_set_is_a_product_chain() {
+    // (x - 1) * (x - 2) * (x - 3) == 0: three Subs, two Muls, one AssertEq.
+    let insts = lower("assert_in_set(x, [1, 2, 3])", &["x"], &[]);
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::Sub { .. })), 3);
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::Mul { .. })), 2);
+    assert_eq!(
+        count(&insts, |i| matches!(i, Instruction::AssertEq { .. })),
+        1
+    );
+}
+
+#[test]
+fn lower_assert_in_set_rejects_scalar_set() {
+    let err = IrLowering::<memory::Bn254Fr>::lower_circuit("assert_in_set(x, 3)", &["x"], &[])
+        .unwrap_err();
+    assert!(matches!(err, ir::IrError::TypeMismatch { .. }), "{err:?}");
+}
+
+#[test]
+fn lower_merkle_root_rejects_non_power_of_two() {
+    let err = IrLowering::<memory::Bn254Fr>::lower_circuit(
+        "let leaves = [a, b, c]\nmerkle_root(leaves)",
+        &[],
+        &["a", "b", "c"],
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("power-of-two"), "{err}");
+}
+
+#[test]
+fn lower_popcount_and_is_power_of_two() {
+    let insts = lower(
+        "assert_eq(popcount(x, 4), 3)\nis_power_of_two(y, 8)",
+        &[],
+        &["x", "y"],
+    );
+    let widths: Vec<_> = insts
+        .iter()
+        .filter_map(|i| match i {
+            Instruction::Decompose { num_bits, .. } => Some(*num_bits),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(widths, vec![4, 8]);
+    // 3 adds for the 4-bit sum, 7 for the 8-bit one
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::Add { .. })), 10);
+    assert_eq!(
+        count(&insts, |i| matches!(i, Instruction::AssertEq { .. })),
+        2
+    );
+}
+
+#[test]
+fn lower_popcount_requires_constant_width() {
+    let err =
+        IrLowering::<memory::Bn254Fr>::lower_circuit("let n = x\npopcount(y, n)", &[], &["x", "y"])
+            .unwrap_err();
+    assert!(err.to_string().contains("popcount() bit count"), "{err}");
+}
+
+#[test]
+fn lower_bool_builtins() {
+    let insts = lower(
+        "let t = bool_xor(a, b)\nlet u = bool_or(a, bool_not(b))",
+        &[],
+        &["a", "b"],
+    );
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::And { .. })), 1);
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::Or { .. })), 1);
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::Not { .. })), 1);
+}
+
 // ============================================================================
 // Control flow
 // ============================================================================
@@ -74,6 +244,23 @@ fn lower_for_uses_iterator() {
     );
 }
 
+#[test]
+fn lower_for_respects_max_unroll() {
+    let source = "for i in 0..20000 {\nassert_eq(x, x)\n}";
+    let err = IrLowering::<memory::Bn254Fr>::lower_circuit(source, &[], &["x"])
+        .expect_err("20000 iterations exceed the default limit");
+    assert!(err.to_string().contains("10000"), "{err}");
+
+    let insts =
+        IrLowering::<memory::Bn254Fr>::lower_circuit_with_max_unroll(source, &[], &["x"], 50_000)
+            .expect("lowering failed")
+            .into_instructions();
+    assert_eq!(
+        count(&insts, |i| matches!(i, Instruction::AssertEq { .. })),
+        20_000
+    );
+}
+
 // ============================================================================
 // Blocks
 // ============================================================================
@@ -85,6 +272,22 @@ fn lower_block() {
     assert_eq!(count(&insts, |i| matches!(i, Instruction::Add { .. })), 1);
 }
 
+#[test]
+fn lower_fn_with_top_level_return() {
+    // The `let` after the return is unreachable and must not be lowered.
+    let insts = lower(
+        "fn add(a, b) {\nreturn a + b\nlet dead = a * b\n}\nassert_eq(add(x, y), z)",
+        &["z"],
+        &["x", "y"],
+    );
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::Add { .. })), 1);
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::Mul { .. })), 0);
+    assert_eq!(
+        count(&insts, |i| matches!(i, Instruction::AssertEq { .. })),
+        1
+    );
+}
+
 // ============================================================================
 // Error cases
 // ============================================================================
@@ -101,6 +304,21 @@ fn lower_unsupported_while() {
     assert!(result.is_err());
 }
 
+#[test]
+fn lower_conditional_return_rejected() {
+    let err = IrLowering::<memory::Bn254Fr>::lower_circuit(
+        "fn f(a) {\nif a == 0 { return 1 }\na\n}\nassert_eq(f(x), x)",
+        &[],
+        &["x"],
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("top-level statement of a function body"),
+        "{err}"
+    );
+}
+
 #[test]
 fn lower_wrong_assert_eq_args() {
     let result = IrLowering::<memory::Bn254Fr>::lower_circuit("assert_eq(x)", &[], &["x"]);
@@ -279,21 +497,37 @@ fn lower_is_le() {
     assert_eq!(count(&insts, |i| matches!(i, Instruction::IsLe { .. })), 1);
 }
 
+/// SSA var of the `Input` named `name`.
+fn input_var(insts: &[Instruction], name: &str) -> SsaVar {
+    insts
+        .iter()
+        .find_map(|i| match i {
+            Instruction::Input {
+                result, name: n, ..
+            } if n == name => Some(*result),
+            _ => None,
+        })
+        .unwrap()
+}
+
 #[test]
-fn lower_gt_as_lt_swapped() {
-    // x > y should lower to IsLt(y, x) — swapped args
+fn lower_is_gt_keeps_operand_order() {
     let insts = lower("x > y", &[], &["x", "y"]);
-    assert_eq!(count(&insts, |i| matches!(i, Instruction::IsLt { .. })), 1);
-    // Should NOT have IsLe
-    assert_eq!(count(&insts, |i| matches!(i, Instruction::IsLe { .. })), 0);
+    let (x, y) = (input_var(&insts, "x"), input_var(&insts, "y"));
+    assert!(insts
+        .iter()
+        .any(|i| matches!(i, Instruction::IsGt { lhs, rhs, .. } if *lhs == x && *rhs == y)));
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::IsLt { .. })), 0);
 }
 
 #[test]
-fn lower_ge_as_le_swapped() {
-    // x >= y should lower to IsLe(y, x) — swapped args
+fn lower_is_ge_keeps_operand_order() {
     let insts = lower("x >= y", &[], &["x", "y"]);
-    assert_eq!(count(&insts, |i| matches!(i, Instruction::IsLe { .. })), 1);
-    assert_eq!(count(&insts, |i| matches!(i, Instruction::IsLt { .. })), 0);
+    let (x, y) = (input_var(&insts, "x"), input_var(&insts, "y"));
+    assert!(insts
+        .iter()
+        .any(|i| matches!(i, Instruction::IsGe { lhs, rhs, .. } if *lhs == x && *rhs == y)));
+    assert_eq!(count(&insts, |i| matches!(i, Instruction::IsLe { .. })), 0);
 }
 
 #[test]
@@ -386,6 +620,23 @@ fn error_undeclared_has_span() {
     assert!(msg.contains("x"), "should mention variable name");
 }
 
+#[test]
+fn parse_error_has_span() {
+    let result = IrLowering::<memory::Bn254Fr>::lower_circuit("assert_eq(x +)", &[], &["x"]);
+    let Err(ir::IrError::ParseError(diag)) = result else {
+        panic!("expected a parse error, got: {result:?}");
+    };
+    assert_eq!(diag.primary_span.line_start, 1);
+    assert_eq!(diag.primary_span.col_start, 14, "should point at `)`");
+
+    let err = ir::IrError::ParseError(diag);
+    assert!(
+        err.to_string().starts_with("[1:14"),
+        "parse error should include source span, got: {err}"
+    );
+    assert_eq!(err.to_diagnostic().primary_span.line_start, 1);
+}
+
 #[test]
 fn lower_wrong_assert_args() {
     let result = IrLowering::<memory::Bn254Fr>::lower_circuit("assert(x, y)", &[], &["x", "y"]);
@@ -442,6 +693,18 @@ fn lower_self_contained_duplicate_rejected() {
     }
 }
 
+// ============================================================================
+// Constant lookups
+// ============================================================================
+
+#[test]
+fn const_lookup_after_folded_input_default() {
+    // The default's scratch instructions are dropped; the constant `2`
+    // emitted afterwards at the same positions must still be found.
+    let source = "witness a = (2 ^ 3 + 1 + 1 + 1) / 1\nwitness b\nassert_eq(b ^ 2, a)";
+    IrLowering::<memory::Bn254Fr>::lower_circuit(source, &[], &[]).unwrap();
+}
+
 // ============================================================================
 // T4: DCE safety — RangeCheck / PoseidonHash must survive optimization
 // ============================================================================
@@ -0,0 +1,86 @@
+use ir::types::IrProgram;
+
+use super::*;
+
+fn lower_program(source: &str, public: &[&str], witness: &[&str]) -> IrProgram {
+    IrLowering::<memory::Bn254Fr>::lower_circuit(source, public, witness).expect("lowering failed")
+}
+
+/// `(line_start, col_start, line_end, col_end)` of `inst`'s result.
+fn span_of(p: &IrProgram, inst: &Instruction) -> (usize, usize, usize, usize) {
+    let s = p
+        .get_span(inst.result_var())
+        .unwrap_or_else(|| panic!("no span for {inst}"));
+    (s.line_start, s.col_start, s.line_end, s.col_end)
+}
+
+/// Instructions other than `Input` (whose spans live in `input_spans`)
+/// that have no source span.
+fn unspanned(p: &IrProgram) -> Vec<String> {
+    p.iter()
+        .filter(|i| !matches!(i, Instruction::Input { .. }))
+        .filter(|i| p.get_span(i.result_var()).is_none())
+        .map(|i| i.to_string())
+        .collect()
+}
+
+#[test]
+fn assert_eq_carries_line_and_col() {
+    let p = lower_program("let s = x + y\n  assert_eq(s, y)", &["y"], &["x"]);
+    let assert = p
+        .iter()
+        .find(|i| matches!(i, Instruction::AssertEq { .. }))
+        .unwrap();
+    assert_eq!(span_of(&p, assert), (2, 3, 2, 18));
+}
+
+#[test]
+fn every_instruction_gets_the_innermost_span() {
+    let p = lower_program("let s = x * y + 3\nassert_eq(s, z)", &["z"], &["x", "y"]);
+    assert!(unspanned(&p).is_empty(), "{:?}", unspanned(&p));
+
+    let mul = p
+        .iter()
+        .find(|i| matches!(i, Instruction::Mul { .. }))
+        .unwrap();
+    assert_eq!(span_of(&p, mul), (1, 9, 1, 14), "`x * y`");
+    let add = p
+        .iter()
+        .find(|i| matches!(i, Instruction::Add { .. }))
+        .unwrap();
+    assert_eq!(span_of(&p, add), (1, 9, 1, 18), "`x * y + 3`");
+}
+
+#[test]
+fn inlined_function_body_keeps_its_own_span() {
+    let p = lower_program("fn sq(a) { a * a }\nassert_eq(sq(x), y)", &["y"], &["x"]);
+    let mul = p
+        .iter()
+        .find(|i| matches!(i, Instruction::Mul { .. }))
+        .unwrap();
+    assert_eq!(
+        span_of(&p, mul).0,
+        1,
+        "body instructions point into `fn sq`"
+    );
+}
+
+#[test]
+fn spans_survive_optimization() {
+    // `x / 4` is rewritten to `x * inv(4)` with a fresh constant, which
+    // inherits the division's span.
+    let mut p = lower_program("let q = x / 4\nassert_eq(q * q, y)", &["y"], &["x"]);
+    ir::passes::optimize(&mut p);
+    assert!(unspanned(&p).is_empty(), "{:?}", unspanned(&p));
+
+    let assert = p
+        .iter()
+        .find(|i| matches!(i, Instruction::AssertEq { .. }))
+        .unwrap();
+    assert_eq!(span_of(&p, assert).0, 2);
+    let inv = p
+        .iter()
+        .find(|i| matches!(i, Instruction::Const { value, .. } if *value == FieldElement::from_u64(4).inv().unwrap()))
+        .expect("x / 4 should fold to a multiplication by inv(4)");
+    assert_eq!(span_of(&p, inv), (1, 9, 1, 14), "`x / 4`");
+}
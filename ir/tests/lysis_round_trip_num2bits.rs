@@ -205,6 +205,8 @@ fn operands(inst: &Instruction<Bn254Fr>) -> Vec<SsaVar> {
         | I::IsNeq { lhs, rhs, .. }
         | I::IsLt { lhs, rhs, .. }
         | I::IsLe { lhs, rhs, .. }
+        | I::IsGt { lhs, rhs, .. }
+        | I::IsGe { lhs, rhs, .. }
         | I::And { lhs, rhs, .. }
         | I::Or { lhs, rhs, .. }
         | I::AssertEq { lhs, rhs, .. } => vec![*lhs, *rhs],
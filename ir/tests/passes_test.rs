@@ -6,3 +6,5 @@ mod const_fold_logic;
 mod dce;
 #[path = "passes_test/optimize.rs"]
 mod optimize;
+#[path = "passes_test/verify.rs"]
+mod verify;
@@ -414,3 +414,73 @@ fn const_fold_div_self_witness_not_folded() {
         p.instructions()[1]
     );
 }
+
+#[test]
+fn const_fold_div_by_constant_becomes_scalar_mul() {
+    // x / 2 → Const(inv 2) + Mul(x, inv): a constant-coefficient term
+    let mut p: IrProgram = IrProgram::new();
+    let x = p.fresh_var();
+    p.push(Instruction::Input {
+        result: x,
+        name: "x".into(),
+        visibility: ir::Visibility::Witness,
+    });
+    let two = p.fresh_var();
+    p.push(Instruction::Const {
+        result: two,
+        value: FieldElement::from_u64(2),
+    });
+    let q = p.fresh_var();
+    p.push(Instruction::Div {
+        result: q,
+        lhs: x,
+        rhs: two,
+    });
+
+    const_fold::constant_fold(&mut p);
+
+    assert_eq!(p.len(), 4);
+    let Instruction::Const {
+        result: inv_var,
+        value: inv,
+    } = p.instructions()[2]
+    else {
+        panic!("expected Const(inv 2), got {:?}", p.instructions()[2]);
+    };
+    assert_eq!(inv.mul(&FieldElement::from_u64(2)), FieldElement::ONE);
+    assert!(
+        matches!(p.instructions()[3], Instruction::Mul { result, lhs, rhs }
+            if result == q && lhs == x && rhs == inv_var),
+        "expected Mul(x, inv 2), got {:?}",
+        p.instructions()[3]
+    );
+    assert!(inv_var.0 >= 3, "inverse must be a fresh variable");
+}
+
+#[test]
+fn const_fold_div_by_zero_constant_left_for_backend() {
+    // x / 0 has no inverse: the Div survives for the backend to reject
+    let mut p: IrProgram = IrProgram::new();
+    let x = p.fresh_var();
+    p.push(Instruction::Input {
+        result: x,
+        name: "x".into(),
+        visibility: ir::Visibility::Witness,
+    });
+    let zero = p.fresh_var();
+    p.push(Instruction::Const {
+        result: zero,
+        value: FieldElement::ZERO,
+    });
+    let q = p.fresh_var();
+    p.push(Instruction::Div {
+        result: q,
+        lhs: x,
+        rhs: zero,
+    });
+
+    const_fold::constant_fold(&mut p);
+
+    assert_eq!(p.len(), 3);
+    assert!(matches!(p.instructions()[2], Instruction::Div { .. }));
+}
@@ -1,5 +1,5 @@
-use ir::passes::optimize;
-use ir::types::{Instruction, IrProgram};
+use ir::passes::{optimize, optimize_with, OptLevel};
+use ir::types::{Instruction, IrProgram, Visibility};
 use memory::FieldElement;
 
 #[test]
@@ -30,3 +30,122 @@ fn optimize_full_pipeline() {
     assert!(p.len() < before, "optimize should reduce instruction count");
     assert_eq!(p.len(), 0, "all unused consts should be removed");
 }
+
+/// `out = x*y + x*y + (2 + 3)` with an unused `x*y`: CSE merges the
+/// products, constant folding collapses `2 + 3`, DCE drops the dead mul.
+fn redundant_program() -> IrProgram {
+    let mut p: IrProgram = IrProgram::new();
+    let x = p.fresh_var();
+    p.push(Instruction::Input {
+        result: x,
+        name: "x".into(),
+        visibility: Visibility::Witness,
+    });
+    let y = p.fresh_var();
+    p.push(Instruction::Input {
+        result: y,
+        name: "y".into(),
+        visibility: Visibility::Witness,
+    });
+    let m1 = p.fresh_var();
+    p.push(Instruction::Mul {
+        result: m1,
+        lhs: x,
+        rhs: y,
+    });
+    let m2 = p.fresh_var();
+    p.push(Instruction::Mul {
+        result: m2,
+        lhs: x,
+        rhs: y,
+    });
+    let dead = p.fresh_var();
+    p.push(Instruction::Mul {
+        result: dead,
+        lhs: x,
+        rhs: y,
+    });
+    let sum = p.fresh_var();
+    p.push(Instruction::Add {
+        result: sum,
+        lhs: m1,
+        rhs: m2,
+    });
+    let two = p.fresh_var();
+    p.push(Instruction::Const {
+        result: two,
+        value: FieldElement::from_u64(2),
+    });
+    let three = p.fresh_var();
+    p.push(Instruction::Const {
+        result: three,
+        value: FieldElement::from_u64(3),
+    });
+    let k = p.fresh_var();
+    p.push(Instruction::Add {
+        result: k,
+        lhs: two,
+        rhs: three,
+    });
+    let out = p.fresh_var();
+    p.push(Instruction::Add {
+        result: out,
+        lhs: sum,
+        rhs: k,
+    });
+    let expected = p.fresh_var();
+    p.push(Instruction::Input {
+        result: expected,
+        name: "out".into(),
+        visibility: Visibility::Public,
+    });
+    let eq = p.fresh_var();
+    p.push(Instruction::AssertEq {
+        result: eq,
+        lhs: out,
+        rhs: expected,
+        message: None,
+    });
+    p
+}
+
+#[test]
+fn opt_level_o0_is_a_no_op() {
+    let mut p = redundant_program();
+    let before = p.len();
+    let stats = optimize_with(&mut p, OptLevel::O0);
+    assert_eq!(p.len(), before);
+    assert_eq!(stats.total_before, before);
+    assert_eq!(stats.total_after, before);
+}
+
+#[test]
+fn opt_level_o2_reduces_redundant_circuit() {
+    let mut p = redundant_program();
+    let before = p.len();
+    let stats = optimize_with(&mut p, OptLevel::O2);
+    assert!(p.len() < before, "O2 should shrink {before} instructions");
+    assert!(stats.cse_eliminated > 0, "duplicate x*y should be merged");
+}
+
+#[test]
+fn opt_level_o1_skips_cse() {
+    let mut o1 = redundant_program();
+    let o1_stats = optimize_with(&mut o1, OptLevel::O1);
+    assert_eq!(o1_stats.cse_eliminated, 0);
+    assert!(o1_stats.dce_eliminated > 0, "dead x*y should be removed");
+
+    let mut o2 = redundant_program();
+    optimize_with(&mut o2, OptLevel::O2);
+    assert!(o2.len() < o1.len());
+    assert!(o1.len() < redundant_program().len());
+}
+
+#[test]
+fn opt_level_from_level() {
+    assert_eq!(OptLevel::from_level(0), Some(OptLevel::O0));
+    assert_eq!(OptLevel::from_level(1), Some(OptLevel::O1));
+    assert_eq!(OptLevel::from_level(2), Some(OptLevel::O2));
+    assert_eq!(OptLevel::from_level(3), None);
+    assert_eq!(OptLevel::default(), OptLevel::O2);
+}
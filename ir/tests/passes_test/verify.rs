@@ -0,0 +1,105 @@
+use ir::passes::optimize;
+use ir::passes::verify::verify_well_formed;
+use ir::types::{Instruction, IrProgram, SsaVar, Visibility};
+use ir::IrError;
+use memory::FieldElement;
+
+/// `assert_eq(x * 3, y)` with public `y`, witness `x`.
+fn scaled_program() -> IrProgram {
+    let mut p: IrProgram = IrProgram::new();
+    let x = p.fresh_var();
+    p.push(Instruction::Input {
+        result: x,
+        name: "x".into(),
+        visibility: Visibility::Witness,
+    });
+    let y = p.fresh_var();
+    p.push(Instruction::Input {
+        result: y,
+        name: "y".into(),
+        visibility: Visibility::Public,
+    });
+    let three = p.fresh_var();
+    p.push(Instruction::Const {
+        result: three,
+        value: FieldElement::from_u64(3),
+    });
+    let prod = p.fresh_var();
+    p.push(Instruction::Mul {
+        result: prod,
+        lhs: x,
+        rhs: three,
+    });
+    let eq = p.fresh_var();
+    p.push(Instruction::AssertEq {
+        result: eq,
+        lhs: prod,
+        rhs: y,
+        message: None,
+    });
+    p
+}
+
+#[test]
+fn verify_accepts_lowered_and_optimized_programs() {
+    let mut p = scaled_program();
+    verify_well_formed(&p).unwrap();
+    optimize(&mut p);
+    verify_well_formed(&p).unwrap();
+}
+
+#[test]
+fn verify_flags_use_of_eliminated_var() {
+    let mut p = scaled_program();
+    // Drop the `Const 3` that the Mul still reads, as a buggy pass might.
+    p.instructions.remove(2);
+    let err = verify_well_formed(&p).unwrap_err();
+    let IrError::MalformedIr { pass, var, .. } = &err else {
+        panic!("expected MalformedIr, got {err}");
+    };
+    assert_eq!(*var, SsaVar(2));
+    assert!(pass.is_none());
+    assert!(err.to_string().contains("before any definition"), "{err}");
+}
+
+#[test]
+fn verify_flags_duplicate_definition() {
+    let mut p = scaled_program();
+    p.push(Instruction::Const {
+        result: SsaVar(3),
+        value: FieldElement::from_u64(7),
+    });
+    let err = verify_well_formed(&p).unwrap_err();
+    assert!(
+        matches!(err, IrError::MalformedIr { var: SsaVar(3), .. }),
+        "{err}"
+    );
+    assert!(err.to_string().contains("more than once"), "{err}");
+}
+
+#[test]
+fn verify_flags_unallocated_result() {
+    let mut p = scaled_program();
+    let past_end = SsaVar(p.next_var);
+    p.push(Instruction::Const {
+        result: past_end,
+        value: FieldElement::from_u64(1),
+    });
+    let err = verify_well_formed(&p).unwrap_err();
+    assert!(err.to_string().contains("never allocated"), "{err}");
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "malformed IR after `const_fold`")]
+fn optimize_fails_fast_on_corrupted_program() {
+    let mut p = scaled_program();
+    // Redefine the witness input: every pass leaves this in place, so the
+    // first post-pass check reports it.
+    p.push(Instruction::Input {
+        result: SsaVar(0),
+        name: "x_again".into(),
+        visibility: Visibility::Witness,
+    });
+    optimize(&mut p);
+}
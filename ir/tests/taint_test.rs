@@ -411,3 +411,63 @@ fn taint_div_self_is_constant() {
     let (taints, _) = taint_analysis(&p);
     assert_eq!(taints[&SsaVar(1)], Taint::Constant);
 }
+
+#[test]
+fn taint_shadowing_let_warns() {
+    use ir::IrLowering;
+
+    let source = "let x = a * b\nlet x = x + 1\nassert_eq(x, out)";
+    let program: IrProgram = IrLowering::lower_circuit(source, &["out"], &["a", "b"]).unwrap();
+    let (_, warnings) = taint_analysis(&program);
+    assert_eq!(warnings.len(), 1, "got: {warnings:?}");
+    match &warnings[0] {
+        TaintWarning::Shadowed { name, span } => {
+            assert_eq!(name, "x");
+            assert_eq!(span.line_start, 2);
+        }
+        other => panic!("expected Shadowed, got {other:?}"),
+    }
+}
+
+#[test]
+fn taint_distinct_lets_and_block_scopes_do_not_warn() {
+    use ir::IrLowering;
+
+    // Distinct names at top level, and a loop body that re-binds `y` on
+    // every unrolled iteration (each iteration is a fresh block).
+    let source = r#"
+let x = a * b
+let y = x + 1
+for i in 0..3 {
+    let y = y * a
+    assert_eq(y, y)
+}
+assert_eq(y, out)
+"#;
+    let program: IrProgram = IrLowering::lower_circuit(source, &["out"], &["a", "b"]).unwrap();
+    let (_, warnings) = taint_analysis(&program);
+    assert!(
+        !warnings
+            .iter()
+            .any(|w| matches!(w, TaintWarning::Shadowed { .. })),
+        "got: {warnings:?}"
+    );
+}
+
+#[test]
+fn taint_shadowing_in_unrolled_loop_warns_once() {
+    use ir::IrLowering;
+
+    let source = "for i in 0..4 {\n    let t = a * a\n    let t = t + i\n    assert_eq(t, out)\n}";
+    let program: IrProgram = IrLowering::lower_circuit(source, &["out"], &["a"]).unwrap();
+    let shadowed: Vec<_> = taint_analysis(&program)
+        .1
+        .into_iter()
+        .filter(|w| matches!(w, TaintWarning::Shadowed { .. }))
+        .collect();
+    assert_eq!(shadowed.len(), 1, "got: {shadowed:?}");
+    assert_eq!(
+        shadowed[0].to_string(),
+        "`let t` shadows an earlier `t` in the same scope"
+    );
+}
@@ -1,4 +1,5 @@
 use super::{BigInt, BigIntWidth};
+use crate::field::{FieldBackend, FieldElement};
 
 impl BigInt {
     /// Convert to a vector of bits (LSB-first, each element is 0 or 1).
@@ -43,6 +44,28 @@ impl BigInt {
         Some(Self { limbs, width })
     }
 
+    /// Convert to a field element. Returns `None` if the value is not
+    /// below the field modulus — it is never silently reduced.
+    ///
+    /// ```
+    /// use memory::bigint::{BigInt, BigIntWidth};
+    /// use memory::FieldElement;
+    ///
+    /// let b = BigInt::from_u64(42, BigIntWidth::W512);
+    /// let fe: Option<FieldElement> = b.to_field();
+    /// assert_eq!(fe, Some(FieldElement::from_u64(42)));
+    /// ```
+    pub fn to_field<F: FieldBackend>(&self) -> Option<FieldElement<F>> {
+        if self.limbs[4..].iter().any(|&l| l != 0) {
+            return None;
+        }
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(&self.limbs) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        FieldElement::from_le_bytes(&bytes)
+    }
+
     /// Format as hex string (no prefix, lowercase, minimal).
     ///
     /// ```
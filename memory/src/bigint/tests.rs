@@ -343,3 +343,22 @@ fn test_shr_cross_limb() {
     let b = a.shr(64);
     assert_eq!(b, BigInt::from_u64(1, BigIntWidth::W256));
 }
+
+#[test]
+fn test_to_field_rejects_values_above_modulus() {
+    use crate::field::{Bn254Fr, FieldElement};
+
+    let hex = "123456789abcdef0123456789abcdef0123456789abcdef012";
+    let b = BigInt::from_hex_str(hex, BigIntWidth::W256).unwrap();
+    assert_eq!(
+        b.to_field::<Bn254Fr>(),
+        FieldElement::<Bn254Fr>::from_hex_str(hex)
+    );
+
+    // The BN254 modulus itself, and anything needing more than 4 limbs.
+    let p = "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+    let at_modulus = BigInt::from_hex_str(p, BigIntWidth::W256).unwrap();
+    assert_eq!(at_modulus.to_field::<Bn254Fr>(), None);
+    let wide = BigInt::from_u64(1, BigIntWidth::W512).shl(256).unwrap();
+    assert_eq!(wide.to_field::<Bn254Fr>(), None);
+}
@@ -0,0 +1,174 @@
+//! Fixed header of a compiled `.achb` bytecode file.
+//!
+//! Layout of the current version (`0x0C`), all integers little-endian:
+//!
+//! | Offset | Size | Field                                   |
+//! |--------|------|-----------------------------------------|
+//! | 0      | 3    | magic `ACH`                             |
+//! | 3      | 1    | format version                          |
+//! | 4      | 1    | flags (v0x0C+, see [`BinaryHeader::flags`]) |
+//! | 5      | 1    | [`PrimeId`] byte (v0x0B+)               |
+//! | 6      | 2    | `max_slots` of the main function        |
+//!
+//! The string, field, bigint and bytes tables, the constant pool, the
+//! prototypes and the main code follow, each prefixed by its own count;
+//! `akron`'s loader reads those. Older versions lack the flags byte
+//! (< 0x0C, read as no flags) and the prime byte (< 0x0B, read as BN254).
+
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use crate::field::PrimeId;
+
+#[cfg(test)]
+mod tests;
+
+/// The three bytes every `.achb` file starts with.
+pub const MAGIC: &[u8; 3] = b"ACH";
+
+/// Version written by [`BinaryHeader::write`].
+pub const VERSION: u8 = 0x0C;
+
+/// Oldest version [`BinaryHeader::read`] still accepts.
+pub const MIN_VERSION: u8 = 0x09;
+
+/// Reserved: a source line table section follows the main code.
+pub const FLAG_LINE_TABLE: u8 = 1 << 0;
+
+/// Reserved: strings are stored in a deduplicated pool section.
+pub const FLAG_STRING_POOL: u8 = 1 << 1;
+
+/// Flags this build knows how to read. The reserved bits above name
+/// sections no writer emits yet, so a file setting them is rejected.
+const SUPPORTED_FLAGS: u8 = 0;
+
+/// Why a `.achb` header could not be read.
+#[derive(Debug)]
+pub enum BinaryHeaderError {
+    Io(io::Error),
+    /// The file does not start with `ACH`.
+    BadMagic([u8; 3]),
+    /// The version byte is outside `MIN_VERSION..=VERSION`.
+    UnsupportedVersion(u8),
+    /// The header sets flags this build cannot read.
+    UnsupportedFlags(u8),
+    /// The prime byte names no known field.
+    UnknownPrime(u8),
+}
+
+impl fmt::Display for BinaryHeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryHeaderError::Io(e) => write!(f, "I/O error: {e}"),
+            BinaryHeaderError::BadMagic(found) => write!(
+                f,
+                "not an Achronyme binary: expected magic `ACH`, found {found:02x?}"
+            ),
+            BinaryHeaderError::UnsupportedVersion(v) if *v > VERSION => write!(
+                f,
+                "binary format version 0x{v:02X} is newer than this build supports \
+                 (0x{MIN_VERSION:02X}..=0x{VERSION:02X}); upgrade ach to run it"
+            ),
+            BinaryHeaderError::UnsupportedVersion(v) => write!(
+                f,
+                "binary format version 0x{v:02X} is too old (supported: \
+                 0x{MIN_VERSION:02X}..=0x{VERSION:02X}); recompile the source with `ach compile`"
+            ),
+            BinaryHeaderError::UnsupportedFlags(flags) => write!(
+                f,
+                "binary sets header flags 0x{:02X} that this build cannot read",
+                flags & !SUPPORTED_FLAGS
+            ),
+            BinaryHeaderError::UnknownPrime(b) => {
+                write!(f, "unknown PrimeId byte 0x{b:02x} in bytecode header")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BinaryHeaderError {}
+
+impl From<io::Error> for BinaryHeaderError {
+    fn from(e: io::Error) -> Self {
+        BinaryHeaderError::Io(e)
+    }
+}
+
+/// The fixed-size prefix of a `.achb` file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BinaryHeader {
+    /// Format version; sections after the header depend on it.
+    pub version: u8,
+    /// Bit set of optional sections (`FLAG_*`). Zero for every file
+    /// written today.
+    pub flags: u8,
+    /// Prime field the program was compiled for.
+    pub prime_id: PrimeId,
+    /// Register window of the main function.
+    pub max_slots: u16,
+}
+
+impl BinaryHeader {
+    /// A current-version header with no flags.
+    pub fn new(prime_id: PrimeId, max_slots: u16) -> Self {
+        Self {
+            version: VERSION,
+            flags: 0,
+            prime_id,
+            max_slots,
+        }
+    }
+
+    /// Whether `bytes` start like a `.achb` file this build can load.
+    pub fn sniff(bytes: &[u8]) -> bool {
+        bytes.len() > MAGIC.len()
+            && bytes.starts_with(MAGIC)
+            && (MIN_VERSION..=VERSION).contains(&bytes[MAGIC.len()])
+    }
+
+    /// Read and validate a header in any supported version.
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, BinaryHeaderError> {
+        let mut magic = [0u8; 3];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(BinaryHeaderError::BadMagic(magic));
+        }
+        let version = read_u8(reader)?;
+        if !(MIN_VERSION..=VERSION).contains(&version) {
+            return Err(BinaryHeaderError::UnsupportedVersion(version));
+        }
+        let flags = if version >= 0x0C { read_u8(reader)? } else { 0 };
+        if flags & !SUPPORTED_FLAGS != 0 {
+            return Err(BinaryHeaderError::UnsupportedFlags(flags));
+        }
+        let prime_id = if version >= 0x0B {
+            let b = read_u8(reader)?;
+            PrimeId::from_byte(b).ok_or(BinaryHeaderError::UnknownPrime(b))?
+        } else {
+            PrimeId::Bn254
+        };
+        let mut slots = [0u8; 2];
+        reader.read_exact(&mut slots)?;
+        Ok(Self {
+            version,
+            flags,
+            prime_id,
+            max_slots: u16::from_le_bytes(slots),
+        })
+    }
+
+    /// Write the header in the current layout. `self.version` must be
+    /// [`VERSION`]: older layouts are read-only.
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        assert_eq!(self.version, VERSION, "only the current version is written");
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[self.version, self.flags, self.prime_id.to_byte()])?;
+        writer.write_all(&self.max_slots.to_le_bytes())
+    }
+}
+
+fn read_u8<R: Read>(reader: &mut R) -> io::Result<u8> {
+    let mut b = [0u8; 1];
+    reader.read_exact(&mut b)?;
+    Ok(b[0])
+}
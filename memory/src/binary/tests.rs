@@ -0,0 +1,68 @@
+use super::*;
+
+#[test]
+fn header_round_trips() {
+    let header = BinaryHeader::new(PrimeId::Goldilocks, 42);
+    let mut bytes = Vec::new();
+    header.write(&mut bytes).unwrap();
+    assert_eq!(&bytes[..5], b"ACH\x0C\x00");
+    assert!(BinaryHeader::sniff(&bytes));
+    assert_eq!(BinaryHeader::read(&mut bytes.as_slice()).unwrap(), header);
+}
+
+#[test]
+fn reads_older_layouts() {
+    // v0x0B: no flags byte
+    let v0b = [b'A', b'C', b'H', 0x0B, PrimeId::Bls12_381.to_byte(), 7, 0];
+    let header = BinaryHeader::read(&mut v0b.as_slice()).unwrap();
+    assert_eq!(
+        header,
+        BinaryHeader {
+            version: 0x0B,
+            flags: 0,
+            prime_id: PrimeId::Bls12_381,
+            max_slots: 7,
+        }
+    );
+    // v0x0A: no prime byte either
+    let v0a = [b'A', b'C', b'H', 0x0A, 3, 1];
+    let header = BinaryHeader::read(&mut v0a.as_slice()).unwrap();
+    assert_eq!(header.prime_id, PrimeId::Bn254);
+    assert_eq!(header.max_slots, 259);
+}
+
+#[test]
+fn old_version_is_descriptive() {
+    let err = BinaryHeader::read(&mut b"ACH\x07\x00\x00".as_slice()).unwrap_err();
+    assert!(matches!(err, BinaryHeaderError::UnsupportedVersion(0x07)));
+    assert!(err.to_string().contains("0x07 is too old"), "{err}");
+    assert!(err.to_string().contains("ach compile"), "{err}");
+    assert!(!BinaryHeader::sniff(b"ACH\x07"));
+}
+
+#[test]
+fn newer_version_is_descriptive() {
+    let err = BinaryHeader::read(&mut b"ACH\x0D\x00\x00\x00\x00".as_slice()).unwrap_err();
+    assert!(err.to_string().contains("newer than this build"), "{err}");
+}
+
+#[test]
+fn reserved_flags_rejected() {
+    let mut header = BinaryHeader::new(PrimeId::Bn254, 1);
+    header.flags = FLAG_LINE_TABLE;
+    let mut bytes = Vec::new();
+    header.write(&mut bytes).unwrap();
+    let err = BinaryHeader::read(&mut bytes.as_slice()).unwrap_err();
+    assert!(
+        matches!(err, BinaryHeaderError::UnsupportedFlags(_)),
+        "{err}"
+    );
+}
+
+#[test]
+fn bad_magic_and_prime_rejected() {
+    let err = BinaryHeader::read(&mut b"ELF\x0C\x00\x00\x00\x00".as_slice()).unwrap_err();
+    assert!(matches!(err, BinaryHeaderError::BadMagic(_)));
+    let err = BinaryHeader::read(&mut b"ACH\x0C\x00\xEE\x00\x00".as_slice()).unwrap_err();
+    assert!(matches!(err, BinaryHeaderError::UnknownPrime(0xEE)));
+}
@@ -31,6 +31,10 @@ pub trait FieldBackend:
     /// Byte size of a canonical field element (32 for 254/255/256-bit, 8 for 64-bit).
     const BYTE_SIZE: usize;
 
+    /// A generator of the multiplicative group `F*` (the conventional
+    /// choice shared with arkworks / plonky2 for this field).
+    const MULTIPLICATIVE_GENERATOR: u64;
+
     // ========================================================================
     // Constants
     // ========================================================================
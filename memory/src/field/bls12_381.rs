@@ -33,6 +33,7 @@ impl FieldBackend for Bls12_381Fr {
     const PRIME_ID: PrimeId = PrimeId::Bls12_381;
     const MODULUS_BIT_SIZE: u32 = 255;
     const BYTE_SIZE: usize = 32;
+    const MULTIPLICATIVE_GENERATOR: u64 = 7;
 
     // ========================================================================
     // Constants
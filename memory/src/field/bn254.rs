@@ -19,6 +19,7 @@ impl FieldBackend for Bn254Fr {
     const PRIME_ID: PrimeId = PrimeId::Bn254;
     const MODULUS_BIT_SIZE: u32 = 254;
     const BYTE_SIZE: usize = 32;
+    const MULTIPLICATIVE_GENERATOR: u64 = 5;
 
     // ========================================================================
     // Constants
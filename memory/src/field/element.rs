@@ -15,6 +15,12 @@ pub struct FieldElement<F: FieldBackend = Bn254Fr> {
 }
 
 // Manual trait impls — PhantomData is always Eq/Hash/PartialEq regardless of F.
+//
+// Equality and hashing use the raw representation directly. Every backend
+// keeps it fully reduced (Montgomery form below p for BN254/BLS12-381, a
+// plain u64 below p for Goldilocks), so it is a bijection with the
+// canonical value and comparing it is equivalent to comparing
+// `to_canonical()`, without the conversion.
 impl<F: FieldBackend> PartialEq for FieldElement<F> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -28,6 +34,24 @@ impl<F: FieldBackend> std::hash::Hash for FieldElement<F> {
     }
 }
 
+/// Total order by canonical integer value (`0 < 1 < ... < p - 1`), so
+/// elements can key a `BTreeMap` or be sorted deterministically. Montgomery
+/// form does not preserve order, hence the conversion.
+impl<F: FieldBackend> Ord for FieldElement<F> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Little-endian limbs: compare from the most significant one.
+        self.to_canonical()
+            .iter()
+            .rev()
+            .cmp(other.to_canonical().iter().rev())
+    }
+}
+impl<F: FieldBackend> PartialOrd for FieldElement<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 // ============================================================================
 // Generic API (works for ANY backend)
 // ============================================================================
@@ -159,6 +183,15 @@ impl<F: FieldBackend> FieldElement<F> {
         F::from_decimal_str(s).map(Self::from_repr)
     }
 
+    /// Parse from decimal string with an optional leading `-`, mapping
+    /// `-n` to `p - n`. Magnitudes wider than `i64` are accepted.
+    pub fn from_signed_decimal_str(s: &str) -> Option<Self> {
+        match s.strip_prefix('-') {
+            Some(magnitude) => Self::from_decimal_str(magnitude).map(|v| v.neg()),
+            None => Self::from_decimal_str(s),
+        }
+    }
+
     /// Parse from hex string (with or without "0x" prefix).
     pub fn from_hex_str(s: &str) -> Option<Self> {
         F::from_hex_str(s).map(Self::from_repr)
@@ -173,6 +206,11 @@ impl<F: FieldBackend> FieldElement<F> {
     pub fn modulus_le_bytes() -> [u8; 32] {
         F::modulus_le_bytes()
     }
+
+    /// A generator of the multiplicative group of the field.
+    pub fn multiplicative_generator() -> Self {
+        Self::from_u64(F::MULTIPLICATIVE_GENERATOR)
+    }
 }
 
 // ============================================================================
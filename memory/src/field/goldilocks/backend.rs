@@ -20,6 +20,7 @@ impl FieldBackend for GoldilocksFr {
     const PRIME_ID: PrimeId = PrimeId::Goldilocks;
     const MODULUS_BIT_SIZE: u32 = 64;
     const BYTE_SIZE: usize = 8;
+    const MULTIPLICATIVE_GENERATOR: u64 = 7;
 
     // ========================================================================
     // Constants
@@ -15,7 +15,10 @@ mod family;
 pub mod goldilocks;
 mod prime_id;
 pub mod profile;
+#[cfg(feature = "random")]
+mod random;
 mod simd;
+mod sqrt;
 
 pub use arithmetic::MODULUS;
 pub use backend::FieldBackend;
@@ -25,6 +28,8 @@ pub use element::FieldElement;
 pub use family::FieldFamily;
 pub use goldilocks::GoldilocksFr;
 pub use prime_id::PrimeId;
+#[cfg(feature = "random")]
+pub use random::SplitMix64;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,92 @@
+//! Random field elements for tests and fuzzing harnesses.
+//!
+//! [`FieldElement::random`] draws a uniformly distributed canonical element
+//! from any [`RngCore`] by rejection sampling: fill `MODULUS_BIT_SIZE` random
+//! bits and retry until the value is below the modulus. [`SplitMix64`] is a
+//! tiny deterministic generator so tests can reproduce a failing sample from
+//! its seed without pulling in a full RNG crate.
+
+use rand_core::{impls, Error, RngCore};
+
+use super::{FieldBackend, FieldElement};
+
+impl<F: FieldBackend> FieldElement<F> {
+    /// Sample a uniformly distributed element of the field.
+    ///
+    /// ```
+    /// use memory::field::SplitMix64;
+    /// use memory::FieldElement;
+    ///
+    /// let mut rng = SplitMix64::new(7);
+    /// let a: FieldElement = FieldElement::random(&mut rng);
+    /// let b: FieldElement = FieldElement::random(&mut rng);
+    /// assert_ne!(a, b);
+    /// ```
+    pub fn random(rng: &mut impl RngCore) -> Self {
+        let bits = F::MODULUS_BIT_SIZE as usize;
+        let full_bytes = bits / 8;
+        let top_mask = (1u8 << (bits % 8)).wrapping_sub(1);
+        loop {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes[..full_bytes + usize::from(top_mask != 0)]);
+            if top_mask != 0 {
+                bytes[full_bytes] &= top_mask;
+            }
+            if let Some(repr) = F::from_le_bytes(&bytes) {
+                return Self::from_repr(repr);
+            }
+        }
+    }
+
+    /// Deterministic element derived from `seed`: the first
+    /// [`random`](Self::random) sample of a [`SplitMix64`] seeded with it.
+    ///
+    /// ```
+    /// use memory::FieldElement;
+    ///
+    /// let a: FieldElement = FieldElement::from_seed(42);
+    /// assert_eq!(a, FieldElement::from_seed(42));
+    /// assert_ne!(a, FieldElement::from_seed(43));
+    /// ```
+    pub fn from_seed(seed: u64) -> Self {
+        Self::random(&mut SplitMix64::new(seed))
+    }
+}
+
+/// SplitMix64 — a fast, statistically solid 64-bit PRNG (Steele et al.).
+///
+/// Not cryptographically secure; meant for reproducible tests only.
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Create a generator from a 64-bit seed.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl RngCore for SplitMix64 {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
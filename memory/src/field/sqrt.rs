@@ -0,0 +1,132 @@
+//! Small-exponent powers, quadratic residuosity and square roots.
+//!
+//! These are native (off-circuit) helpers: Poseidon parameter generation
+//! and curve point decompression need them, circuits never do. Square roots
+//! use Tonelli–Shanks over the backend's `pow`, so they work for every
+//! backend regardless of the 2-adicity of `p - 1`.
+
+use super::{FieldBackend, FieldElement};
+
+/// The prime modulus as little-endian limbs.
+fn modulus_limbs<F: FieldBackend>() -> [u64; 4] {
+    let bytes = F::modulus_le_bytes();
+    std::array::from_fn(|i| u64::from_le_bytes(bytes[i * 8..(i + 1) * 8].try_into().unwrap()))
+}
+
+/// `limbs >> shift` for `shift < 64`.
+fn shr(limbs: [u64; 4], shift: u32) -> [u64; 4] {
+    if shift == 0 {
+        return limbs;
+    }
+    std::array::from_fn(|i| {
+        let hi = if i + 1 < 4 {
+            limbs[i + 1] << (64 - shift)
+        } else {
+            0
+        };
+        (limbs[i] >> shift) | hi
+    })
+}
+
+/// `p - 1 = q · 2^s` with `q` odd, as `(q, s)`.
+fn split_two_power<F: FieldBackend>() -> ([u64; 4], u32) {
+    let mut q = modulus_limbs::<F>();
+    q[0] -= 1;
+    let mut s = 0u32;
+    while q[0] & 1 == 0 {
+        let step = q[0].trailing_zeros().min(63);
+        q = shr(q, step);
+        s += step;
+    }
+    (q, s)
+}
+
+/// `(p - 1) / 2`, the Euler criterion exponent. `p` is odd, so `p - 1`
+/// never borrows out of the low limb.
+fn half_order<F: FieldBackend>() -> [u64; 4] {
+    let mut p_minus_1 = modulus_limbs::<F>();
+    p_minus_1[0] -= 1;
+    shr(p_minus_1, 1)
+}
+
+impl<F: FieldBackend> FieldElement<F> {
+    /// Modular exponentiation by a machine-word exponent.
+    ///
+    /// ```
+    /// use memory::FieldElement;
+    ///
+    /// let x: FieldElement = FieldElement::from_u64(3);
+    /// assert_eq!(x.pow_u64(4), FieldElement::from_u64(81));
+    /// assert_eq!(x.pow_u64(0), FieldElement::ONE);
+    /// ```
+    pub fn pow_u64(&self, exp: u64) -> Self {
+        self.pow(&[exp, 0, 0, 0])
+    }
+
+    /// Legendre symbol via Euler's criterion: `0` for zero, `1` for a
+    /// nonzero square, `-1` for a non-residue.
+    pub fn legendre_symbol(&self) -> i8 {
+        if self.is_zero() {
+            return 0;
+        }
+        if self.pow(&half_order::<F>()) == Self::one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Square root by Tonelli–Shanks. Returns `None` for non-residues.
+    ///
+    /// Either root may be returned; the other is its negation.
+    ///
+    /// ```
+    /// use memory::FieldElement;
+    ///
+    /// let r: FieldElement = FieldElement::from_u64(9).sqrt().unwrap();
+    /// assert!(r == FieldElement::from_u64(3) || r == FieldElement::from_u64(3).neg());
+    /// ```
+    pub fn sqrt(&self) -> Option<Self> {
+        match self.legendre_symbol() {
+            0 => return Some(Self::zero()),
+            -1 => return None,
+            _ => {}
+        }
+
+        let (q, s) = split_two_power::<F>();
+
+        // Any non-residue generates the 2-Sylow subgroup.
+        let mut z = Self::from_u64(2);
+        while z.legendre_symbol() != -1 {
+            z = z.add(&Self::one());
+        }
+
+        // (q + 1) / 2 = (q >> 1) + 1 since q is odd.
+        let mut q_plus_1_half = shr(q, 1);
+        q_plus_1_half[0] += 1;
+
+        let mut m = s;
+        let mut c = z.pow(&q);
+        let mut t = self.pow(&q);
+        let mut r = self.pow(&q_plus_1_half);
+
+        while t != Self::one() {
+            // Least i in (0, m) with t^(2^i) == 1.
+            let mut i = 0;
+            let mut t2i = t;
+            while t2i != Self::one() {
+                t2i = t2i.mul(&t2i);
+                i += 1;
+            }
+            let mut b = c;
+            for _ in 0..m - i - 1 {
+                b = b.mul(&b);
+            }
+            m = i;
+            c = b.mul(&b);
+            t = t.mul(&c);
+            r = r.mul(&b);
+        }
+        Some(r)
+    }
+}
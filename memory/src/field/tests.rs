@@ -150,6 +150,18 @@ fn test_pow() {
 fn test_from_i64_negative() {
     let a = FE::from_i64(-1);
     assert_eq!(a, FE::from_u64(0).sub(&FE::ONE));
+
+    // Canonical form is `p - |x|`, and it round-trips.
+    assert_eq!(
+        limbs_to_bigint(&a.to_canonical()),
+        limbs_to_bigint(&MODULUS) - 1u32
+    );
+    assert_eq!(FE::from_canonical(a.to_canonical()), a);
+
+    let b = FE::from_i64(-12345);
+    assert_eq!(b, FE::from_u64(12345).neg());
+    assert_eq!(FE::from_canonical(b.to_canonical()), b);
+    assert_eq!(FE::from_i64(i64::MIN), FE::from_u64(1 << 63).neg());
 }
 
 #[test]
@@ -164,6 +176,21 @@ fn test_from_decimal_str() {
     assert_eq!(fe, FE::from_u64(42));
 }
 
+#[test]
+fn test_from_signed_decimal_str() {
+    assert_eq!(FE::from_signed_decimal_str("42"), Some(FE::from_u64(42)));
+    assert_eq!(FE::from_signed_decimal_str("-3"), Some(FE::from_i64(-3)));
+    assert_eq!(FE::from_signed_decimal_str("-0"), Some(FE::zero()));
+    // -(2^70) doesn't fit in i64.
+    let two_pow_70 = FE::from_u64(1 << 35).mul(&FE::from_u64(1 << 35));
+    assert_eq!(
+        FE::from_signed_decimal_str("-1180591620717411303424"),
+        Some(two_pow_70.neg())
+    );
+    assert_eq!(FE::from_signed_decimal_str("--3"), None);
+    assert_eq!(FE::from_signed_decimal_str("-"), None);
+}
+
 #[test]
 fn test_from_hex_str() {
     let fe = FE::from_hex_str("0x2a").unwrap();
@@ -443,3 +470,281 @@ fn test_vector_montgomery_r() {
         "R constant must match 2^256 mod p"
     );
 }
+
+/// Random canonical element in `[0, p)`, returned as (field element, bigint).
+/// Reduces 256 random bits through `BigUint` so the reference value never
+/// touches the Montgomery code under test.
+fn random_element(rng: &mut SplitMix64) -> (FE, num_bigint::BigUint) {
+    use rand_core::RngCore;
+    let p = limbs_to_bigint(&MODULUS);
+    let limbs = [
+        rng.next_u64(),
+        rng.next_u64(),
+        rng.next_u64(),
+        rng.next_u64(),
+    ];
+    let value = limbs_to_bigint(&limbs) % &p;
+    let fe = FE::from_decimal_str(&value.to_str_radix(10)).unwrap();
+    (fe, value)
+}
+
+#[test]
+fn test_mul_matches_schoolbook_random() {
+    let p = limbs_to_bigint(&MODULUS);
+    let mut rng = SplitMix64::new(0x5eed_0001);
+    for i in 0..2000 {
+        let (a, a_big) = random_element(&mut rng);
+        let (b, b_big) = random_element(&mut rng);
+        let expected = (&a_big * &b_big) % &p;
+        assert_eq!(
+            limbs_to_bigint(&a.mul(&b).to_canonical()),
+            expected,
+            "iteration {i}: {a_big} * {b_big}"
+        );
+    }
+}
+
+#[test]
+fn test_montgomery_mul_matches_schoolbook_random() {
+    use num_traits::One;
+    let p = limbs_to_bigint(&MODULUS);
+    let r_inv = {
+        let r: num_bigint::BigUint = (num_bigint::BigUint::one() << 256u32) % &p;
+        r.modpow(&(&p - 2u32), &p)
+    };
+    let mut rng = SplitMix64::new(0x5eed_0002);
+    for i in 0..2000 {
+        let (_, a_big) = random_element(&mut rng);
+        let (_, b_big) = random_element(&mut rng);
+        let mut a = [0u64; 4];
+        let mut b = [0u64; 4];
+        for (dst, src) in [(&mut a, &a_big), (&mut b, &b_big)] {
+            for (j, d) in src.to_u64_digits().into_iter().enumerate() {
+                dst[j] = d;
+            }
+        }
+        // montgomery_mul(a, b) = a * b * R^-1 mod p, for any a, b < p.
+        let expected = (&a_big * &b_big % &p) * &r_inv % &p;
+        assert_eq!(
+            limbs_to_bigint(&montgomery_mul(&a, &b)),
+            expected,
+            "iteration {i}: {a_big} * {b_big}"
+        );
+    }
+}
+
+// ============================================================================
+// Random sampling
+// ============================================================================
+
+fn modulus_of<F: FieldBackend>() -> num_bigint::BigUint {
+    num_bigint::BigUint::from_bytes_le(&F::modulus_le_bytes())
+}
+
+fn assert_random_is_canonical<F: FieldBackend>() {
+    let p = modulus_of::<F>();
+    let half = &p >> 1u32;
+    let mut rng = SplitMix64::new(0x5eed_0003);
+    let samples = 4000;
+    let mut upper_half = 0;
+    for _ in 0..samples {
+        let x = FieldElement::<F>::random(&mut rng);
+        let big = num_bigint::BigUint::from_bytes_le(&x.to_le_bytes());
+        assert!(
+            big < p,
+            "{:?}: sample {big} not below the modulus",
+            F::PRIME_ID
+        );
+        if big >= half {
+            upper_half += 1;
+        }
+    }
+    // Uniform over [0, p): about half the samples land in [p/2, p).
+    // 4000 samples put 45%..55% beyond six standard deviations.
+    assert!(
+        (samples * 45 / 100..samples * 55 / 100).contains(&upper_half),
+        "{:?}: {upper_half}/{samples} samples in the upper half",
+        F::PRIME_ID
+    );
+}
+
+#[test]
+fn test_random_is_canonical_and_uniform() {
+    assert_random_is_canonical::<Bn254Fr>();
+    assert_random_is_canonical::<Bls12_381Fr>();
+    assert_random_is_canonical::<GoldilocksFr>();
+}
+
+#[test]
+fn test_random_accepts_any_rng() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let mut rng = StdRng::seed_from_u64(1);
+    let a = FE::random(&mut rng);
+    let b = FE::random(&mut rng);
+    assert_ne!(a, b);
+    assert!(limbs_to_bigint(&a.to_canonical()) < limbs_to_bigint(&MODULUS));
+}
+
+#[test]
+fn test_from_seed_is_deterministic() {
+    use rand_core::RngCore;
+    for seed in [0, 1, 42, u64::MAX] {
+        assert_eq!(FE::from_seed(seed), FE::from_seed(seed));
+        assert_eq!(
+            FieldElement::<GoldilocksFr>::from_seed(seed),
+            FieldElement::<GoldilocksFr>::from_seed(seed)
+        );
+    }
+    assert_ne!(FE::from_seed(1), FE::from_seed(2));
+    // Same stream as seeding the generator directly.
+    assert_eq!(FE::from_seed(9), FE::random(&mut SplitMix64::new(9)));
+    // Pin the stream so an accidental change to SplitMix64 is caught.
+    assert_eq!(
+        SplitMix64::new(0).next_u64(),
+        0xe220_a839_7b1d_cdaf,
+        "SplitMix64 reference output for seed 0"
+    );
+}
+
+// ============================================================================
+// pow_u64 / legendre_symbol / sqrt
+// ============================================================================
+
+#[test]
+fn test_pow_u64() {
+    assert_eq!(FE::from_u64(3).pow_u64(0), FE::ONE);
+    assert_eq!(FE::ZERO.pow_u64(0), FE::ONE);
+    assert_eq!(FE::from_u64(2).pow_u64(10), FE::from_u64(1024));
+    assert_eq!(
+        FE::from_u64(7).pow_u64(5),
+        FE::from_u64(7).pow(&[5, 0, 0, 0])
+    );
+}
+
+#[test]
+fn test_legendre_symbol() {
+    assert_eq!(FE::ZERO.legendre_symbol(), 0);
+    assert_eq!(FE::ONE.legendre_symbol(), 1);
+    assert_eq!(FE::from_u64(16).legendre_symbol(), 1);
+    // 5 is the smallest quadratic non-residue mod the BN254 scalar prime.
+    assert_eq!(FE::from_u64(5).legendre_symbol(), -1);
+    assert_eq!(FE::from_u64(5).mul(&FE::from_u64(9)).legendre_symbol(), -1);
+}
+
+fn assert_sqrt_roundtrip<F: FieldBackend>() {
+    let mut rng = SplitMix64::new(0x5eed_0004);
+    for _ in 0..50 {
+        let x = FieldElement::<F>::random(&mut rng);
+        let square = x.mul(&x);
+        let root = square.sqrt().expect("a square has a root");
+        assert!(
+            root == x || root == x.neg(),
+            "{:?}: wrong root",
+            F::PRIME_ID
+        );
+    }
+    assert_eq!(
+        FieldElement::<F>::zero().sqrt(),
+        Some(FieldElement::<F>::zero())
+    );
+}
+
+#[test]
+fn test_sqrt_of_squares() {
+    let root = FE::from_u64(49).sqrt().unwrap();
+    assert!(root == FE::from_u64(7) || root == FE::from_u64(7).neg());
+    assert_sqrt_roundtrip::<Bn254Fr>();
+    assert_sqrt_roundtrip::<Bls12_381Fr>();
+    assert_sqrt_roundtrip::<GoldilocksFr>();
+}
+
+#[test]
+fn test_sqrt_of_non_residue_is_none() {
+    assert_eq!(FE::from_u64(5).sqrt(), None);
+    let mut rng = SplitMix64::new(0x5eed_0005);
+    let mut non_residues = 0;
+    for _ in 0..50 {
+        let x = FE::random(&mut rng);
+        if x.legendre_symbol() == -1 {
+            assert_eq!(x.sqrt(), None);
+            non_residues += 1;
+        }
+    }
+    assert!(non_residues > 0);
+}
+
+#[test]
+fn test_multiplicative_generator_is_non_residue() {
+    // Necessary for a generator of F*: its square roots would otherwise
+    // confine it to the index-2 subgroup.
+    assert_eq!(
+        FieldElement::<Bn254Fr>::multiplicative_generator().legendre_symbol(),
+        -1
+    );
+    assert_eq!(
+        FieldElement::<Bls12_381Fr>::multiplicative_generator().legendre_symbol(),
+        -1
+    );
+
+    // Goldilocks p - 1 = 2^32 · 3 · 5 · 17 · 257 · 65537 factors fully,
+    // so check the order is exactly p - 1.
+    let g = FieldElement::<GoldilocksFr>::multiplicative_generator();
+    let order = 0xFFFF_FFFF_0000_0000u64;
+    for q in [2, 3, 5, 17, 257, 65537] {
+        assert_ne!(g.pow_u64(order / q), FieldElement::one(), "q = {q}");
+    }
+}
+
+/// Build 7 three ways (directly, by arithmetic and from decimal), then
+/// check equality, hashing and ordering agree with the integer values.
+fn assert_canonical_eq_hash_ord<F: FieldBackend>() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::BTreeMap;
+    use std::hash::{Hash, Hasher};
+
+    let hash = |x: &FieldElement<F>| {
+        let mut h = DefaultHasher::new();
+        x.hash(&mut h);
+        h.finish()
+    };
+    let direct = FieldElement::<F>::from_u64(7);
+    let computed = FieldElement::<F>::from_u64(3).add(&FieldElement::<F>::from_u64(4));
+    let parsed = FieldElement::<F>::from_decimal_str("7").unwrap();
+    let wrapped = FieldElement::<F>::from_i64(-1).add(&FieldElement::<F>::from_u64(8));
+    for x in [computed, parsed, wrapped] {
+        assert_eq!(x, direct);
+        assert_eq!(hash(&x), hash(&direct));
+        assert_eq!(x.cmp(&direct), std::cmp::Ordering::Equal);
+    }
+
+    // p - 1 is the largest element, whatever its Montgomery form.
+    let max = FieldElement::<F>::from_i64(-1);
+    let mut xs = vec![
+        max,
+        direct,
+        FieldElement::<F>::one(),
+        FieldElement::<F>::zero(),
+    ];
+    xs.sort();
+    assert_eq!(
+        xs,
+        [
+            FieldElement::<F>::zero(),
+            FieldElement::<F>::one(),
+            direct,
+            max
+        ]
+    );
+    assert!(FieldElement::<F>::from_u64(1 << 40) > FieldElement::<F>::from_u64(u32::MAX as u64));
+
+    let mut map = BTreeMap::new();
+    map.insert(direct, "seven");
+    assert_eq!(map.get(&computed), Some(&"seven"));
+}
+
+#[test]
+fn test_canonical_eq_hash_and_ord() {
+    assert_canonical_eq_hash_ord::<Bn254Fr>();
+    assert_canonical_eq_hash_ord::<Bls12_381Fr>();
+    assert_canonical_eq_hash_ord::<GoldilocksFr>();
+}
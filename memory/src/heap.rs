@@ -34,6 +34,9 @@ pub struct Heap {
     // GC Metrics
     pub bytes_allocated: usize,
     pub next_gc_threshold: usize,
+    /// Live-object count that also triggers a collection, so many small
+    /// objects are reclaimed before their bytes reach `next_gc_threshold`.
+    pub next_gc_object_threshold: usize,
     pub request_gc: bool,
     gc_lock_depth: u32,
     pub max_heap_bytes: usize,
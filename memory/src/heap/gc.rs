@@ -1,10 +1,11 @@
 use super::{
-    objects::circom_handle_cost, CircomHandle, Closure, Function, Heap, IteratorObj, ProofObject,
-    Upvalue, UpvalueLocation,
+    general::MIN_GC_OBJECT_THRESHOLD, objects::circom_handle_cost, CircomHandle, Closure, Function,
+    Heap, IteratorObj, ProofObject, Upvalue, UpvalueLocation,
 };
 use crate::bigint::BigInt;
 use crate::field::FieldElement;
 use crate::Value;
+use std::collections::HashMap;
 
 /// Set a mark bit in a bitmap vec. Returns true if was previously unmarked.
 /// Free function to enable split-borrow in `trace()` — takes `&mut Vec<u64>`
@@ -127,12 +128,14 @@ impl Heap {
 
     pub fn sweep(&mut self) {
         let mut freed_bytes: usize = 0;
+        let mut freed_objects: u64 = 0;
 
         // Strings
         for i in 0..self.strings.data.len() {
             let idx = i as u32;
             if !self.strings.is_marked(idx) && !self.strings.is_free(idx) {
                 self.strings.mark_free(idx);
+                freed_objects += 1;
                 freed_bytes += self.strings.data[i].capacity();
                 self.strings.data[i] = String::new();
             }
@@ -144,17 +147,31 @@ impl Heap {
             let idx = i as u32;
             if !self.lists.is_marked(idx) && !self.lists.is_free(idx) {
                 self.lists.mark_free(idx);
+                freed_objects += 1;
                 freed_bytes += self.lists.data[i].capacity() * std::mem::size_of::<Value>();
                 self.lists.data[i] = Vec::new();
             }
         }
         self.lists.clear_marks();
 
+        // Maps
+        for i in 0..self.maps.data.len() {
+            let idx = i as u32;
+            if !self.maps.is_marked(idx) && !self.maps.is_free(idx) {
+                self.maps.mark_free(idx);
+                freed_objects += 1;
+                freed_bytes += self.maps.data[i].capacity() * Self::map_entry_size();
+                self.maps.data[i] = HashMap::new();
+            }
+        }
+        self.maps.clear_marks();
+
         // Functions
         for i in 0..self.functions.data.len() {
             let idx = i as u32;
             if !self.functions.is_marked(idx) && !self.functions.is_free(idx) {
                 self.functions.mark_free(idx);
+                freed_objects += 1;
                 let f = &self.functions.data[i];
                 freed_bytes += f.chunk.capacity() * 4;
                 freed_bytes += f.constants.capacity() * std::mem::size_of::<Value>();
@@ -177,6 +194,7 @@ impl Heap {
             let idx = i as u32;
             if !self.closures.is_marked(idx) && !self.closures.is_free(idx) {
                 self.closures.mark_free(idx);
+                freed_objects += 1;
                 let c = &self.closures.data[i];
                 freed_bytes += std::mem::size_of::<Closure>() + c.upvalues.len() * 4;
 
@@ -193,6 +211,7 @@ impl Heap {
             let idx = i as u32;
             if !self.upvalues.is_marked(idx) && !self.upvalues.is_free(idx) {
                 self.upvalues.mark_free(idx);
+                freed_objects += 1;
                 freed_bytes += std::mem::size_of::<Upvalue>();
 
                 self.upvalues.data[i] = Upvalue {
@@ -208,6 +227,7 @@ impl Heap {
             let idx = i as u32;
             if !self.iterators.is_marked(idx) && !self.iterators.is_free(idx) {
                 self.iterators.mark_free(idx);
+                freed_objects += 1;
                 freed_bytes += std::mem::size_of::<IteratorObj>();
                 self.iterators.data[i] = IteratorObj {
                     source: Value::nil(),
@@ -222,6 +242,7 @@ impl Heap {
             let idx = i as u32;
             if !self.fields.is_marked(idx) && !self.fields.is_free(idx) {
                 self.fields.mark_free(idx);
+                freed_objects += 1;
                 freed_bytes += std::mem::size_of::<FieldElement>();
                 self.fields.data[i] = FieldElement::ZERO;
             }
@@ -233,6 +254,7 @@ impl Heap {
             let idx = i as u32;
             if !self.proofs.is_marked(idx) && !self.proofs.is_free(idx) {
                 self.proofs.mark_free(idx);
+                freed_objects += 1;
                 let p = &self.proofs.data[i];
                 freed_bytes += std::mem::size_of::<ProofObject>()
                     + p.proof_json.capacity()
@@ -252,6 +274,7 @@ impl Heap {
             let idx = i as u32;
             if !self.bigints.is_marked(idx) && !self.bigints.is_free(idx) {
                 self.bigints.mark_free(idx);
+                freed_objects += 1;
                 let bi = &self.bigints.data[i];
                 freed_bytes += std::mem::size_of::<BigInt>() + std::mem::size_of_val(bi.limbs());
                 self.bigints.data[i] = BigInt::zero(crate::bigint::BigIntWidth::W256);
@@ -264,6 +287,7 @@ impl Heap {
             let idx = i as u32;
             if !self.bytes.is_marked(idx) && !self.bytes.is_free(idx) {
                 self.bytes.mark_free(idx);
+                freed_objects += 1;
                 freed_bytes += self.bytes.data[i].capacity();
                 self.bytes.data[i] = Vec::new();
             }
@@ -275,6 +299,7 @@ impl Heap {
             let idx = i as u32;
             if !self.circom_handles.is_marked(idx) && !self.circom_handles.is_free(idx) {
                 self.circom_handles.mark_free(idx);
+                freed_objects += 1;
                 freed_bytes += circom_handle_cost(&self.circom_handles.data[i]);
                 self.circom_handles.data[i] = CircomHandle {
                     library_id: 0,
@@ -286,6 +311,7 @@ impl Heap {
         self.circom_handles.clear_marks();
 
         self.stats.total_freed_bytes += freed_bytes as u64;
+        self.stats.total_freed_objects += freed_objects;
 
         // Recompute bytes_allocated from surviving objects (self-correcting).
         // This eliminates drift from untracked mutations (push, insert, etc.)
@@ -297,6 +323,11 @@ impl Heap {
         let grow = self.bytes_allocated.saturating_mul(2);
         let hysteresis = self.next_gc_threshold.saturating_mul(3) / 2;
         self.next_gc_threshold = grow.max(hysteresis).max(1024 * 1024);
+
+        // Same policy for the live-object trigger.
+        let grow = self.live_objects().saturating_mul(2);
+        let hysteresis = self.next_gc_object_threshold.saturating_mul(3) / 2;
+        self.next_gc_object_threshold = grow.max(hysteresis).max(MIN_GC_OBJECT_THRESHOLD);
     }
 
     /// Recompute bytes_allocated by summing live object costs.
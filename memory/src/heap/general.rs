@@ -1,6 +1,9 @@
 use super::{GcStats, Heap};
 use crate::arena::Arena;
 
+/// Floor for [`Heap::next_gc_object_threshold`].
+pub(super) const MIN_GC_OBJECT_THRESHOLD: usize = 100_000;
+
 impl Default for Heap {
     fn default() -> Self {
         Self::new()
@@ -25,6 +28,7 @@ impl Heap {
 
             bytes_allocated: 0,
             next_gc_threshold: 1024 * 1024, // Start at 1MB
+            next_gc_object_threshold: MIN_GC_OBJECT_THRESHOLD,
             request_gc: false,
             gc_lock_depth: 0,
             max_heap_bytes: usize::MAX,
@@ -37,7 +41,7 @@ impl Heap {
         if self.bytes_allocated > self.stats.peak_heap_bytes {
             self.stats.peak_heap_bytes = self.bytes_allocated;
         }
-        if self.gc_lock_depth == 0 && self.bytes_allocated > self.next_gc_threshold {
+        if self.gc_lock_depth == 0 && self.should_collect() {
             self.request_gc = true;
         }
         if self.bytes_allocated > self.max_heap_bytes {
@@ -96,5 +100,22 @@ impl Heap {
 
     pub fn should_collect(&self) -> bool {
         self.bytes_allocated > self.next_gc_threshold
+            || self.live_objects() > self.next_gc_object_threshold
+    }
+
+    /// Number of live (allocated, not yet swept) objects across all arenas.
+    pub fn live_objects(&self) -> usize {
+        self.strings.live_count()
+            + self.lists.live_count()
+            + self.maps.live_count()
+            + self.functions.live_count()
+            + self.upvalues.live_count()
+            + self.closures.live_count()
+            + self.iterators.live_count()
+            + self.fields.live_count()
+            + self.proofs.live_count()
+            + self.bigints.live_count()
+            + self.bytes.live_count()
+            + self.circom_handles.live_count()
     }
 }
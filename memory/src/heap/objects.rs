@@ -4,6 +4,7 @@ use crate::Value;
 pub struct GcStats {
     pub collections: u64,
     pub total_freed_bytes: u64,
+    pub total_freed_objects: u64,
     pub peak_heap_bytes: usize,
     pub total_gc_time_ns: u64,
 }
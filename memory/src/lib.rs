@@ -1,5 +1,6 @@
 pub(crate) mod arena;
 pub mod bigint;
+pub mod binary;
 pub mod field;
 pub mod heap;
 pub(crate) mod limb_ops;
@@ -11,6 +12,7 @@ mod value_tests;
 
 pub use arena::ArenaError;
 pub use bigint::{BigInt, BigIntError, BigIntWidth};
+pub use binary::{BinaryHeader, BinaryHeaderError};
 pub use field::{
     Bls12_381Fr, Bn254Fr, FieldBackend, FieldElement, FieldFamily, GoldilocksFr, PrimeId,
 };
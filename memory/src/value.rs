@@ -62,7 +62,7 @@ const _: () = assert!(TAG_CIRCOM_HANDLE < 16, "tag must fit in 4 bits");
 ///
 /// If you're writing a bytecode loader or another module that genuinely
 /// needs the raw bits, add a validated accessor inside `memory/`.
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct Value(pub(crate) u64);
 
@@ -33,6 +33,29 @@ fn test_gc_stats_freed_bytes_accumulate() {
     );
 }
 
+#[test]
+fn test_gc_sweeps_unreachable_maps_and_counts_objects() {
+    let mut heap = Heap::new();
+    let s = heap.alloc_string("kept".into()).expect("alloc");
+    let mut entries = std::collections::HashMap::new();
+    entries.insert("k".to_string(), Value::string(s));
+    let kept = heap.alloc_map(entries).expect("alloc");
+    let dropped = heap.alloc_map(Default::default()).expect("alloc");
+    heap.alloc_list(vec![Value::int(1)]).expect("alloc");
+    assert_eq!(heap.live_objects(), 4);
+
+    heap.trace(vec![Value::map(kept)]);
+    heap.sweep();
+    assert!(heap.get_map(kept).is_some());
+    assert!(heap.get_map(dropped).is_none(), "unreachable map not swept");
+    assert!(
+        !heap.is_string_free(s),
+        "string held by a live map was swept"
+    );
+    assert_eq!(heap.live_objects(), 2);
+    assert_eq!(heap.stats.total_freed_objects, 2);
+}
+
 #[test]
 fn test_gc_stats_peak_never_decreases() {
     let mut heap = Heap::new();
@@ -69,6 +69,9 @@ impl ProveHandler for ProveEngine {
                         inputs.insert(elem_name, *fe);
                     }
                 }
+                // Outputs are computed below unless the caller pinned them.
+                None if prove_ir.outputs.contains(&input.name)
+                    && !scope_values.contains_key(&input.name) => {}
                 None => {
                     let fe = scope_values.get(&input.name).ok_or_else(|| {
                         ProveError::IrLowering(format!(
@@ -90,6 +93,8 @@ impl ProveHandler for ProveEngine {
             })?;
             inputs.insert(cap.name.clone(), *fe);
         }
+        ir::eval::fill_outputs(&program, &prove_ir.outputs, &mut inputs)
+            .map_err(|e| ProveError::IrLowering(format!("cannot compute circuit output: {e}")))?;
 
         // 4b. Circom witness hints: templates imported via `import { T } from "x.circom"`
         //     may produce `CircuitNode::WitnessHint { name, hint }` nodes for intermediate
@@ -15,6 +15,9 @@ use memory::FieldElement;
 
 use crate::groth16;
 
+/// A BN254 Groth16 proof: π_A, π_C ∈ G1 and π_B ∈ G2.
+pub type Proof = ark_groth16::Proof<Bn254>;
+
 // ============================================================================
 // Public API (BN254-specialized wrappers)
 // ============================================================================
@@ -91,7 +94,9 @@ fn g2_to_json(p: &<Bn254 as Pairing>::G2Affine) -> serde_json::Value {
     ])
 }
 
-fn serialize_proof_json(proof: &ark_groth16::Proof<Bn254>) -> String {
+/// Serialize a proof as snarkjs `proof.json` (`pi_a`, `pi_b`, `pi_c` with
+/// decimal string coordinates).
+pub fn serialize_proof_json(proof: &Proof) -> String {
     let obj = serde_json::json!({
         "pi_a": g1_to_json(&proof.a),
         "pi_b": g2_to_json(&proof.b),
@@ -125,6 +130,36 @@ fn serialize_vkey_json(vk: &ark_groth16::VerifyingKey<Bn254>, num_pub: usize) ->
     serde_json::to_string_pretty(&obj).unwrap()
 }
 
+// ============================================================================
+// Binary serialization (compressed points, BN254)
+// ============================================================================
+
+/// Length of [`proof_to_bytes`] output: compressed π_A (32) + π_B (64) + π_C (32).
+pub const PROOF_BYTES_LEN: usize = 128;
+
+/// Encode a proof as its three compressed curve points, in order π_A, π_B, π_C.
+pub fn proof_to_bytes(proof: &Proof) -> Vec<u8> {
+    use ark_serialize::CanonicalSerialize;
+    let mut buf = Vec::with_capacity(PROOF_BYTES_LEN);
+    proof
+        .serialize_compressed(&mut buf)
+        .expect("serializing into a Vec cannot fail");
+    buf
+}
+
+/// Decode a [`proof_to_bytes`] encoding. Each point is checked to be on the
+/// curve and in the prime-order subgroup; trailing bytes are rejected.
+pub fn proof_from_bytes(bytes: &[u8]) -> Result<Proof, String> {
+    use ark_serialize::CanonicalDeserialize;
+    if bytes.len() != PROOF_BYTES_LEN {
+        return Err(format!(
+            "proof must be {PROOF_BYTES_LEN} bytes, got {}",
+            bytes.len()
+        ));
+    }
+    Proof::deserialize_compressed(bytes).map_err(|e| format!("invalid proof bytes: {e}"))
+}
+
 // ============================================================================
 // Solidity calldata formatting (BN254, EIP-197)
 // ============================================================================
@@ -248,7 +283,7 @@ fn json_to_g2(val: &serde_json::Value) -> Result<G2Affine, String> {
 }
 
 /// Deserialize a snarkjs-format proof JSON string into an ark Proof.
-pub fn deserialize_proof_json(json_str: &str) -> Result<ark_groth16::Proof<Bn254>, String> {
+pub fn deserialize_proof_json(json_str: &str) -> Result<Proof, String> {
     let obj: serde_json::Value =
         serde_json::from_str(json_str).map_err(|e| format!("invalid proof JSON: {e}"))?;
     let a = json_to_g1(&obj["pi_a"])?;
@@ -59,7 +59,8 @@ pub fn register_module(
                     });
                 }
                 let qualified = qualify(&prefix, name);
-                table.insert(
+                insert_module_symbol(
+                    table,
                     qualified.clone(),
                     CallableKind::UserFn {
                         qualified_name: qualified,
@@ -88,7 +89,8 @@ pub fn register_module(
                     continue;
                 }
                 let qualified = qualify(&prefix, name);
-                table.insert(
+                insert_module_symbol(
+                    table,
                     qualified.clone(),
                     CallableKind::Constant {
                         qualified_name: qualified,
@@ -108,6 +110,20 @@ pub fn register_module(
     Ok(())
 }
 
+/// Insert a module symbol. A root-module name that matches a builtin
+/// shadows it, the way a local binding would.
+fn insert_module_symbol(table: &mut SymbolTable, qualified: String, kind: CallableKind) {
+    let is_builtin = matches!(
+        table.lookup(&qualified).map(|id| table.get(id)),
+        Some(CallableKind::Builtin { .. })
+    );
+    if is_builtin {
+        table.shadow_builtin(qualified, kind);
+    } else {
+        table.insert(qualified, kind);
+    }
+}
+
 /// Convenience wrapper: register every module in the graph in
 /// reverse-topological order (the order [`ModuleGraph::iter_ids`]
 /// yields). Dependencies always register before dependents, so the
@@ -133,16 +149,12 @@ pub fn register_all(table: &mut SymbolTable, graph: &ModuleGraph) -> Result<(),
 /// ## Name-collision policy
 ///
 /// Builtins go in under their bare name. If a root module also declares
-/// `fn poseidon() {...}`, the later [`register_module`] call will panic
-/// on duplicate insert. A future refinement may reject user shadowing
-/// with a diagnostic or let user-defined symbols win; until then, the
-/// production call order is:
+/// `fn map() {...}`, the user definition wins: the later
+/// [`register_module`] call re-points the bare name at it via
+/// [`SymbolTable::shadow_builtin`]. The production call order is:
 ///
 /// 1. `register_builtins(&mut table)` — populates bare builtin names.
 /// 2. `register_all(&mut table, &graph)` — populates module symbols.
-///
-/// so any collision surfaces as a clear table panic instead of a silent
-/// dispatch mismatch.
 pub fn register_builtins(table: &mut SymbolTable) {
     let n = table.builtin_registry().len();
     for i in 0..n {
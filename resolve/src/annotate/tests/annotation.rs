@@ -235,6 +235,25 @@ fn register_builtins_populates_bare_names() {
     }
 }
 
+#[test]
+fn root_fn_shadows_builtin_of_same_name() {
+    let mut src = MockSource::default();
+    src.add("main", "fn map(a, b) { a + b }\nlet x = map(1, 2)");
+    let graph = ModuleGraph::build("main", &mut src).expect("build");
+    let table = build_full_table(&graph);
+    let annotations = annotate_program(&graph, &table).annotations;
+
+    let map_id = table.lookup("map").expect("map registered");
+    assert!(matches!(table.get(map_id), CallableKind::UserFn { .. }));
+    let root = graph.get(graph.root());
+    let ident_ids = find_idents(&root.program, "map");
+    assert_eq!(ident_ids.len(), 1);
+    assert_eq!(
+        annotations.get(&(graph.root(), ident_ids[0])),
+        Some(&map_id)
+    );
+}
+
 // Suppress the unused-import warning for TypedParam — imported to
 // document the param-walking contract even though tests use string
 // inputs that parse into them.
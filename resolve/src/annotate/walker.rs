@@ -34,6 +34,15 @@ pub(super) fn walk_stmt(ctx: &mut AnnotateCtx, stmt: &Stmt) {
             let kind = classify_let_rhs(ctx, value);
             ctx.add_local(name, kind);
         }
+        Stmt::OutputDecl { name, value, .. } => {
+            walk_expr(ctx, value);
+            ctx.add_local(name, LocalKind::Plain);
+        }
+        Stmt::PublicDecl { names, .. } | Stmt::WitnessDecl { names, .. } => {
+            for default in names.iter().filter_map(|d| d.default.as_ref()) {
+                walk_expr(ctx, default);
+            }
+        }
         Stmt::Assignment { target, value, .. } => {
             walk_expr(ctx, target);
             walk_expr(ctx, value);
@@ -63,9 +72,8 @@ pub(super) fn walk_stmt(ctx: &mut AnnotateCtx, stmt: &Stmt) {
         Stmt::Return { value: None, .. } => {}
         Stmt::Expr(e) => walk_expr(ctx, e),
         Stmt::Export { inner, .. } => walk_stmt(ctx, inner),
-        // PublicDecl, WitnessDecl, Import, SelectiveImport, ExportList,
-        // ImportCircuit, Break, Continue, Error — no embedded exprs to
-        // walk.
+        // Import, SelectiveImport, ExportList, ImportCircuit, Break,
+        // Continue, Error — no embedded exprs to walk.
         _ => {}
     }
 }
@@ -13,6 +13,7 @@ fn test_table() -> SymbolTable {
     // 0: poseidon (Both)
     reg.push(BuiltinEntry {
         name: "poseidon",
+        description: "",
         arity: Arity::Fixed(2),
         availability: Availability::Both,
         vm_fn: Some(VmFnHandle::PLACEHOLDER),
@@ -21,6 +22,7 @@ fn test_table() -> SymbolTable {
     // 1: print (Vm)
     reg.push(BuiltinEntry {
         name: "print",
+        description: "",
         arity: Arity::Variadic,
         availability: Availability::Vm,
         vm_fn: Some(VmFnHandle::PLACEHOLDER),
@@ -29,6 +31,7 @@ fn test_table() -> SymbolTable {
     // 2: range_check (ProveIr)
     reg.push(BuiltinEntry {
         name: "range_check",
+        description: "",
         arity: Arity::Fixed(2),
         availability: Availability::ProveIr,
         vm_fn: None,
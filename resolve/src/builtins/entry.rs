@@ -13,6 +13,8 @@ pub struct BuiltinEntry {
     /// The canonical name users write in source code. `poseidon`,
     /// `assert_eq`, `mux`, etc. Unique within the registry.
     pub name: &'static str,
+    /// One-line summary shown by `ach list-builtins`.
+    pub description: &'static str,
     /// Expected argument count. Checked by the resolver pass before
     /// dispatch; individual impls may validate further.
     pub arity: Arity,
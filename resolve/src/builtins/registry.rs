@@ -23,27 +23,30 @@ pub struct BuiltinRegistry {
 /// Convenience for building a [`BuiltinEntry`] inline in
 /// [`BuiltinRegistry::default()`].
 macro_rules! entry {
-    (vm $name:literal, $arity:expr, vm = $vm_idx:literal) => {
+    (vm $name:literal, $arity:expr, vm = $vm_idx:literal, $desc:literal) => {
         BuiltinEntry {
             name: $name,
+            description: $desc,
             arity: $arity,
             availability: Availability::Vm,
             vm_fn: Some(VmFnHandle($vm_idx)),
             prove_ir_lower: None,
         }
     };
-    (prove $name:literal, $arity:expr, prove = $prove_idx:literal) => {
+    (prove $name:literal, $arity:expr, prove = $prove_idx:literal, $desc:literal) => {
         BuiltinEntry {
             name: $name,
+            description: $desc,
             arity: $arity,
             availability: Availability::ProveIr,
             vm_fn: None,
             prove_ir_lower: Some(ProveIrLowerHandle($prove_idx)),
         }
     };
-    (both $name:literal, $arity:expr, vm = $vm_idx:literal, prove = $prove_idx:literal) => {
+    (both $name:literal, $arity:expr, vm = $vm_idx:literal, prove = $prove_idx:literal, $desc:literal) => {
         BuiltinEntry {
             name: $name,
+            description: $desc,
             arity: $arity,
             availability: Availability::Both,
             vm_fn: Some(VmFnHandle($vm_idx)),
@@ -72,41 +75,64 @@ impl Default for BuiltinRegistry {
     /// - **11 Vm-only**: `print`, `typeof`, `time`, `proof_json`,
     ///   `proof_public`, `proof_vkey`, `verify_proof`, `gc_stats`,
     ///   `bigint256`, `bigint512`, `from_bits`
-    /// - **6 ProveIr-only**: `range_check`, `merkle_verify`, `len`,
-    ///   `assert_eq`, `int_div`, `int_mod`
+    /// - **25 ProveIr-only**: `range_check`, `merkle_verify`, `len`,
+    ///   `assert_eq`, `int_div`, `int_mod`, `merkle_verify_arity`, `fold`,
+    ///   `poseidon_domain`, `poseidon2`, `inv`, `neg`, `merkle_root`,
+    ///   `assert_all_eq`, `assert_in_set`, `popcount`, `is_power_of_two`,
+    ///   `bool_and`, `bool_or`, `bool_xor`, `bool_not`, `assert_bool`, `dot`,
+    ///   `map`, `bits`
     ///
-    /// Total: **21 builtins**.
+    /// Total: **40 builtins**.
     fn default() -> Self {
         let entries = vec![
             // ── VM-only (11) ───────────────────────────────────────
             // VmFnHandle = positional index in builtin_modules()
-            entry!(vm "print",         Arity::Variadic,   vm = 0),
-            entry!(vm "typeof",        Arity::Fixed(1),   vm = 1),
+            entry!(vm "print",         Arity::Variadic,   vm = 0, "Print values to stdout"),
+            entry!(vm "typeof",        Arity::Fixed(1),   vm = 1, "Type name of a value"),
             // Handle 2 is `assert` — registered as Both below.
-            entry!(vm "time",          Arity::Fixed(0),   vm = 3),
-            entry!(vm "proof_json",    Arity::Fixed(1),   vm = 4),
-            entry!(vm "proof_public",  Arity::Fixed(1),   vm = 5),
-            entry!(vm "proof_vkey",    Arity::Fixed(1),   vm = 6),
+            entry!(vm "time",          Arity::Fixed(0),   vm = 3, "Current Unix time in milliseconds"),
+            entry!(vm "proof_json",    Arity::Fixed(1),   vm = 4, "Proof as a JSON string"),
+            entry!(vm "proof_public",  Arity::Fixed(1),   vm = 5, "Public inputs of a proof, as JSON"),
+            entry!(vm "proof_vkey",    Arity::Fixed(1),   vm = 6, "Verification key of a proof, as JSON"),
             // Handles 7-8 (poseidon, poseidon_many) are Both — below.
-            entry!(vm "verify_proof",  Arity::Fixed(1),   vm = 9),
-            entry!(vm "gc_stats",      Arity::Fixed(0),   vm = 10),
+            entry!(vm "verify_proof",  Arity::Fixed(1),   vm = 9, "Verify a proof"),
+            entry!(vm "gc_stats",      Arity::Fixed(0),   vm = 10, "Garbage-collector statistics map"),
             // Handle 11 is `mux` — Both, below.
-            entry!(vm "bigint256",     Arity::Fixed(1),   vm = 12),
-            entry!(vm "bigint512",     Arity::Fixed(1),   vm = 13),
-            entry!(vm "from_bits",     Arity::Fixed(2),   vm = 14),
+            entry!(vm "bigint256",     Arity::Fixed(1),   vm = 12, "Convert to a 256-bit BigInt"),
+            entry!(vm "bigint512",     Arity::Fixed(1),   vm = 13, "Convert to a 512-bit BigInt"),
+            entry!(vm "from_bits",     Arity::Fixed(2),   vm = 14, "BigInt of a given width from a bit list"),
             // ── Both (4) ───────────────────────────────────────────
-            entry!(both "poseidon",      Arity::Fixed(2), vm = 7,  prove = 0),
-            entry!(both "poseidon_many", Arity::Variadic, vm = 8,  prove = 1),
-            entry!(both "assert",        Arity::Fixed(1), vm = 2,  prove = 7),
-            entry!(both "mux",           Arity::Fixed(3), vm = 11, prove = 2),
-            // ── ProveIR-only (6) ───────────────────────────────────
+            entry!(both "poseidon",      Arity::Fixed(2), vm = 7,  prove = 0, "Poseidon 2-to-1 hash"),
+            entry!(both "poseidon_many", Arity::Variadic, vm = 8,  prove = 1, "Left-fold Poseidon over its arguments"),
+            entry!(both "assert",        Arity::Fixed(1), vm = 2,  prove = 7, "Enforce boolean true"),
+            entry!(both "mux",           Arity::Fixed(3), vm = 11, prove = 2, "Conditional select"),
+            // ── ProveIR-only (25) ─────────────────────────────────
             // ProveIrLowerHandle = slot in dispatch_builtin_by_handle.
-            entry!(prove "range_check",   Arity::Fixed(2),    prove = 3),
-            entry!(prove "merkle_verify", Arity::Fixed(4),    prove = 4),
-            entry!(prove "len",           Arity::Fixed(1),    prove = 5),
-            entry!(prove "assert_eq",     Arity::Range(2, 3), prove = 6),
-            entry!(prove "int_div",       Arity::Fixed(3),    prove = 8),
-            entry!(prove "int_mod",       Arity::Fixed(3),    prove = 9),
+            entry!(prove "range_check",   Arity::Fixed(2),    prove = 3, "Value fits in N bits"),
+            entry!(prove "merkle_verify", Arity::Fixed(4),    prove = 4, "Merkle membership proof"),
+            entry!(prove "len",           Arity::Fixed(1),    prove = 5, "Compile-time array length"),
+            entry!(prove "assert_eq",     Arity::Range(2, 3), prove = 6, "Enforce equality, with an optional message"),
+            entry!(prove "int_div",       Arity::Fixed(3),    prove = 8, "Integer quotient of N-bit values"),
+            entry!(prove "int_mod",       Arity::Fixed(3),    prove = 9, "Integer remainder of N-bit values"),
+            entry!(prove "merkle_verify_arity", Arity::Fixed(5), prove = 10, "k-ary Merkle proof (one-hot indices)"),
+            entry!(prove "fold",          Arity::Fixed(3),    prove = 11, "Unrolled reduction with \"add\", \"mul\" or \"poseidon\""),
+            entry!(prove "poseidon_domain", Arity::Fixed(3),  prove = 12, "Poseidon 2-to-1 hash with a domain tag"),
+            entry!(prove "poseidon2",     Arity::Fixed(2),    prove = 13, "Poseidon2 2-to-1 hash (BN254 only)"),
+            entry!(prove "inv",           Arity::Fixed(1),    prove = 14, "Multiplicative inverse; asserts x != 0"),
+            entry!(prove "neg",           Arity::Fixed(1),    prove = 15, "Additive inverse -x"),
+            entry!(prove "merkle_root",   Arity::Fixed(1),    prove = 16, "Poseidon Merkle root of a power-of-two leaf array"),
+            entry!(prove "assert_all_eq", Arity::Variadic,    prove = 17, "Enforce that all arguments (at least 2) are equal"),
+            entry!(prove "assert_in_set", Arity::Fixed(2),    prove = 18, "Enforce that a value is one of an array's elements"),
+            entry!(prove "popcount",      Arity::Fixed(2),    prove = 19, "Number of set bits among the low N bits"),
+            entry!(prove "is_power_of_two", Arity::Fixed(2),  prove = 20, "Enforce exactly one set bit among the low N bits"),
+            entry!(prove "bool_and",      Arity::Fixed(2),    prove = 21, "Boolean AND of two 0/1 values"),
+            entry!(prove "bool_or",       Arity::Fixed(2),    prove = 22, "Boolean OR of two 0/1 values"),
+            entry!(prove "bool_xor",      Arity::Fixed(2),    prove = 23, "Boolean XOR of two 0/1 values"),
+            entry!(prove "bool_not",      Arity::Fixed(1),    prove = 24, "Boolean NOT of a 0/1 value"),
+            entry!(prove "assert_bool",   Arity::Fixed(1),    prove = 25, "Enforce that a value is 0 or 1"),
+            entry!(prove "dot",           Arity::Fixed(2),    prove = 26, "Inner product of two equal-length arrays"),
+            entry!(prove "map",           Arity::Fixed(2),    prove = 27, "Element-wise \"neg\", \"square\", \"inv\" or \"not\" (let-bound array)"),
+            entry!(prove "bits",          Arity::Fixed(2),    prove = 28, "Decompose into N boolean wires, LSB first (let-bound array)"),
         ];
 
         let registry = Self { entries };
@@ -163,7 +189,7 @@ impl BuiltinRegistry {
         self.entries.is_empty()
     }
 
-    /// Look up a builtin by name. Linear scan, but there are ~23 entries
+    /// Look up a builtin by name. Linear scan, but there are ~24 entries
     /// and the resolver pass consults this at most once per call site.
     /// Convenience wrapper over [`BuiltinRegistry::lookup_index`] +
     /// [`BuiltinRegistry::get`].
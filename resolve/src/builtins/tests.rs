@@ -5,6 +5,7 @@ use super::*;
 fn entry(name: &'static str, availability: Availability, vm: bool, prove: bool) -> BuiltinEntry {
     BuiltinEntry {
         name,
+        description: "",
         arity: Arity::Fixed(1),
         availability,
         vm_fn: if vm {
@@ -159,12 +160,12 @@ fn default_registry_audits_ok() {
 }
 
 #[test]
-fn default_registry_has_21_entries() {
+fn default_registry_has_40_entries() {
     let reg = BuiltinRegistry::default();
     assert_eq!(
         reg.len(),
-        21,
-        "expected 21 production builtins, got {}",
+        40,
+        "expected 40 production builtins, got {}",
         reg.len()
     );
 }
@@ -188,9 +189,9 @@ fn default_registry_availability_counts() {
         .filter(|e| e.availability == Availability::Both)
         .count();
     assert_eq!(vm_only, 11, "expected 11 Vm-only builtins");
-    assert_eq!(prove_only, 6, "expected 6 ProveIr-only builtins");
+    assert_eq!(prove_only, 25, "expected 25 ProveIr-only builtins");
     assert_eq!(both, 4, "expected 4 Both builtins");
-    assert_eq!(vm_only + prove_only + both, 21);
+    assert_eq!(vm_only + prove_only + both, 40);
 }
 
 #[test]
@@ -257,6 +258,39 @@ fn default_registry_prove_handles_are_unique() {
             );
         }
     }
-    // 3 Both + 7 ProveIr-only = 10 unique prove handles.
-    assert_eq!(seen.len(), 10);
+    // 4 Both + 25 ProveIr-only = 29 unique prove handles.
+    assert_eq!(seen.len(), 29);
+}
+
+#[test]
+fn default_registry_circuit_builtin_arities() {
+    let reg = BuiltinRegistry::default();
+    let expected = [
+        ("assert_eq", Arity::Range(2, 3)),
+        ("assert", Arity::Fixed(1)),
+        ("poseidon", Arity::Fixed(2)),
+        ("poseidon_many", Arity::Variadic),
+        ("mux", Arity::Fixed(3)),
+        ("range_check", Arity::Fixed(2)),
+        ("len", Arity::Fixed(1)),
+        ("merkle_verify", Arity::Fixed(4)),
+    ];
+    for (name, arity) in expected {
+        let entry = reg
+            .lookup(name)
+            .unwrap_or_else(|| panic!("`{name}` missing"));
+        assert_eq!(entry.arity, arity, "arity of `{name}`");
+        assert!(entry.availability.includes_prove_ir(), "`{name}`");
+    }
+}
+
+#[test]
+fn default_registry_entries_have_descriptions() {
+    for entry in BuiltinRegistry::default().entries() {
+        assert!(
+            !entry.description.is_empty() && !entry.description.contains('\n'),
+            "`{}` needs a one-line description",
+            entry.name
+        );
+    }
 }
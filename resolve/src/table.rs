@@ -115,6 +115,26 @@ impl SymbolTable {
         id
     }
 
+    /// Re-point a builtin's bare name at a user definition that shadows
+    /// it. The builtin keeps its [`SymbolId`], but name lookups now find
+    /// the new symbol. Panics unless `qualified_name` currently names a
+    /// [`CallableKind::Builtin`].
+    pub fn shadow_builtin(
+        &mut self,
+        qualified_name: impl Into<String>,
+        kind: CallableKind,
+    ) -> SymbolId {
+        let qualified_name = qualified_name.into();
+        let shadowed = self.lookup(&qualified_name).map(|id| self.get(id));
+        if !matches!(shadowed, Some(CallableKind::Builtin { .. })) {
+            panic!("SymbolTable::shadow_builtin: `{qualified_name}` is not a builtin");
+        }
+        let id = SymbolId(self.symbols.len() as u32);
+        self.symbols.push(kind);
+        self.by_qualified_name.insert(qualified_name, id);
+        id
+    }
+
     /// Look up a symbol by its fully qualified name.
     pub fn lookup(&self, qualified_name: &str) -> Option<SymbolId> {
         self.by_qualified_name.get(qualified_name).copied()
@@ -432,6 +452,7 @@ mod tests {
         let mut reg = BuiltinRegistry::new();
         reg.push(BuiltinEntry {
             name: "broken",
+            description: "",
             arity: Arity::Fixed(1),
             availability: Availability::Both,
             // Missing vm_fn — should fail audit
@@ -452,6 +473,7 @@ mod tests {
         let mut reg = BuiltinRegistry::new();
         reg.push(BuiltinEntry {
             name: "poseidon",
+            description: "",
             arity: Arity::Fixed(2),
             availability: Availability::Both,
             vm_fn: Some(VmFnHandle::PLACEHOLDER),
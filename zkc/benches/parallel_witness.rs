@@ -0,0 +1,53 @@
+//! Witness generation for a 256-leaf Merkle tree: serial replay vs.
+//! `generate_parallel`, which fills each tree level's independent
+//! Poseidon hashes on the rayon pool.
+//!
+//! Run with `cargo bench -p zkc --features rayon --bench parallel_witness`.
+
+use std::collections::HashMap;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use ir::IrLowering;
+use memory::FieldElement;
+use zkc::r1cs_backend::R1CSCompiler;
+use zkc::witness::WitnessGenerator;
+
+const LEAVES: usize = 256;
+
+fn merkle_generator() -> (WitnessGenerator, HashMap<String, FieldElement>) {
+    let leaves_decl = format!("leaves[{LEAVES}]");
+    let program = IrLowering::lower_circuit(
+        "assert_eq(merkle_root(leaves), root)",
+        &["root"],
+        &[leaves_decl.as_str()],
+    )
+    .unwrap();
+    let mut inputs: HashMap<String, FieldElement> = (0..LEAVES)
+        .map(|i| (format!("leaves_{i}"), FieldElement::from_u64(i as u64 + 1)))
+        .collect();
+    ir::eval::fill_outputs(&program, &["root".to_string()], &mut inputs)
+        .unwrap_or_else(|_| panic!("cannot derive `root`"));
+
+    let mut rc = R1CSCompiler::new();
+    rc.compile_ir(&program).unwrap();
+    (WitnessGenerator::from_compiler(&rc), inputs)
+}
+
+fn bench_parallel_witness(c: &mut Criterion) {
+    let (generator, inputs) = merkle_generator();
+    assert_eq!(
+        generator.generate(&inputs).unwrap(),
+        generator.generate_parallel(&inputs).unwrap()
+    );
+
+    c.bench_function("parallel_witness/merkle_256/serial", |b| {
+        b.iter(|| generator.generate(black_box(&inputs)).unwrap())
+    });
+    c.bench_function("parallel_witness/merkle_256/parallel", |b| {
+        b.iter(|| generator.generate_parallel(black_box(&inputs)).unwrap())
+    });
+}
+
+criterion_group!(benches, bench_parallel_witness);
+criterion_main!(benches);
@@ -0,0 +1,117 @@
+//! Poseidon witness filling: one `PoseidonWitnessCtx` shared across a
+//! 1000-hash replay.
+//!
+//! A counting global allocator checks that a fill pass allocates only the
+//! context's own scratch buffers before the timing runs.
+//!
+//! Run with `cargo bench -p zkc --bench poseidon_witness`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use constraints::poseidon::PoseidonParams;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use ir::IrLowering;
+use memory::FieldElement;
+use zkc::r1cs_backend::R1CSCompiler;
+use zkc::witness::{PoseidonWitnessCtx, WitnessOp};
+
+const HASHES: usize = 1_000;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// `(left, right, internal_start, internal_count)` of one `PoseidonHash` op.
+type HashOp = (
+    constraints::r1cs::Variable,
+    constraints::r1cs::Variable,
+    usize,
+    usize,
+);
+
+/// Compile a `HASHES`-long Poseidon chain and return its honest witness
+/// plus the recorded hash ops.
+fn poseidon_chain() -> (Vec<FieldElement>, Vec<HashOp>) {
+    let mut src = String::from("let h0 = a\n");
+    for i in 0..HASHES {
+        src.push_str(&format!("let h{} = poseidon(h{i}, b)\n", i + 1));
+    }
+    src.push_str(&format!("assert_eq(h{HASHES}, out)"));
+    let program = IrLowering::lower_circuit(&src, &["out"], &["a", "b"]).unwrap();
+    let mut inputs: HashMap<String, FieldElement> = [("a", 1), ("b", 2)]
+        .into_iter()
+        .map(|(n, v)| (n.to_string(), FieldElement::from_u64(v)))
+        .collect();
+    ir::eval::fill_outputs(&program, &["out".to_string()], &mut inputs)
+        .unwrap_or_else(|_| panic!("cannot derive `out`"));
+
+    let mut rc = R1CSCompiler::new();
+    let witness = rc.compile_ir_with_witness(&program, &inputs).unwrap();
+    let ops = rc
+        .witness_ops
+        .iter()
+        .filter_map(|op| match op {
+            WitnessOp::PoseidonHash {
+                left,
+                right,
+                internal_start,
+                internal_count,
+                ..
+            } => Some((*left, *right, *internal_start, *internal_count)),
+            _ => None,
+        })
+        .collect();
+    (witness, ops)
+}
+
+fn fill_shared(params: &PoseidonParams, witness: &mut [FieldElement], ops: &[HashOp]) {
+    let mut ctx = PoseidonWitnessCtx::new(params);
+    for &(l, r, start, count) in ops {
+        ctx.fill(witness, l, r, start, count).unwrap();
+    }
+}
+
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn bench_poseidon(c: &mut Criterion) {
+    let params = PoseidonParams::bn254_t3();
+    let (honest, ops) = poseidon_chain();
+    assert_eq!(ops.len(), HASHES);
+    let mut witness = honest.clone();
+
+    let allocations = allocations_during(|| fill_shared(&params, &mut witness, &ops));
+    assert_eq!(witness, honest);
+    // The context's `state` and `old` buffers; nothing per hash.
+    assert_eq!(
+        allocations, 2,
+        "{HASHES}-hash replay allocated {allocations} times"
+    );
+
+    c.bench_function("poseidon_witness/shared_ctx", |b| {
+        b.iter(|| fill_shared(&params, black_box(&mut witness), &ops))
+    });
+}
+
+criterion_group!(benches, bench_poseidon);
+criterion_main!(benches);
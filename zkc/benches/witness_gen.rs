@@ -0,0 +1,87 @@
+//! Witness generation: op replay vs. eval-seeded fill.
+//!
+//! Compares `compile_ir_with_witness` (evaluate for validation, then
+//! replay every recorded `WitnessOp`) against
+//! `compile_ir_with_eval_witness` (reuse the evaluator's SSA values and
+//! replay only gadget ops) on an arithmetic-heavy chain and on a
+//! comparison-heavy circuit where most wires are gadget internals.
+//!
+//! Run with `cargo bench -p zkc --bench witness_gen`.
+
+use std::collections::HashMap;
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+use ir::types::IrProgram;
+use ir::IrLowering;
+use memory::FieldElement;
+use zkc::r1cs_backend::R1CSCompiler;
+
+const CHAIN_LEN: usize = 2_000;
+
+/// `v_{i+1} = v_i * x + y`, `CHAIN_LEN` times.
+fn arithmetic_chain() -> IrProgram {
+    let mut src = String::from("let v0 = x\n");
+    for i in 0..CHAIN_LEN {
+        src.push_str(&format!("let v{} = v{i} * x + y\n", i + 1));
+    }
+    src.push_str(&format!("assert_eq(out, v{CHAIN_LEN})"));
+    IrLowering::lower_circuit(&src, &["out"], &["x", "y"]).unwrap()
+}
+
+/// 64 bounded comparisons folded into a sum.
+fn comparison_heavy() -> IrProgram {
+    let mut src = String::from("range_check(x, 32)\nlet s0 = 0\n");
+    for i in 0..64 {
+        src.push_str(&format!(
+            "let s{} = s{i} + mux(x < {}, 1, 0)\n",
+            i + 1,
+            i * 1000
+        ));
+    }
+    src.push_str("assert_eq(out, s64)");
+    IrLowering::lower_circuit(&src, &["out"], &["x"]).unwrap()
+}
+
+/// Build the input map, deriving the public `out` from the circuit.
+fn inputs_for(program: &IrProgram, pairs: &[(&str, u64)]) -> HashMap<String, FieldElement> {
+    let mut inputs: HashMap<String, FieldElement> = pairs
+        .iter()
+        .map(|(n, v)| (n.to_string(), FieldElement::from_u64(*v)))
+        .collect();
+    ir::eval::fill_outputs(program, &["out".to_string()], &mut inputs)
+        .unwrap_or_else(|_| panic!("cannot derive `out`"));
+    inputs
+}
+
+fn bench_program(c: &mut Criterion, name: &str, program: &IrProgram, pairs: &[(&str, u64)]) {
+    let inputs = inputs_for(program, pairs);
+
+    c.bench_function(&format!("{name}/replay"), |b| {
+        b.iter_batched(
+            R1CSCompiler::new,
+            |mut rc| black_box(rc.compile_ir_with_witness(program, &inputs).unwrap()),
+            BatchSize::LargeInput,
+        );
+    });
+    c.bench_function(&format!("{name}/eval_seeded"), |b| {
+        b.iter_batched(
+            R1CSCompiler::new,
+            |mut rc| black_box(rc.compile_ir_with_eval_witness(program, &inputs).unwrap()),
+            BatchSize::LargeInput,
+        );
+    });
+}
+
+fn bench(c: &mut Criterion) {
+    bench_program(
+        c,
+        "arithmetic_chain",
+        &arithmetic_chain(),
+        &[("x", 3), ("y", 7)],
+    );
+    bench_program(c, "comparison_heavy", &comparison_heavy(), &[("x", 31_337)]);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -29,6 +29,23 @@ pub enum R1CSError {
     },
     /// An error during IR evaluation (early validation).
     EvalError(String),
+    /// Declared inputs with neither a supplied value nor a default,
+    /// in declaration order.
+    MissingInput(Vec<String>),
+    /// An element of a declared array input (`witness path[3]`) has no
+    /// value: `{array}_{index}` is absent from the input map.
+    MissingArrayElement { array: String, index: usize },
+    /// The R1CS witness disagrees with `ir::eval` on an SSA value — a
+    /// lowering bug, reported by `check_witness_against_eval`.
+    WitnessDivergence {
+        var: ir::types::SsaVar,
+        ir_index: usize,
+        expected: String,
+        actual: String,
+    },
+    /// A recorded witness op reads a wire that is neither an input nor
+    /// written by an earlier op — replaying it would read a zero.
+    WitnessOpOrder { op_index: usize, wire: usize },
 }
 
 impl fmt::Display for R1CSError {
@@ -75,6 +92,35 @@ impl fmt::Display for R1CSError {
                 )
             }
             R1CSError::EvalError(msg) => write!(f, "evaluation error: {msg}"),
+            R1CSError::MissingInput(names) => {
+                let list = names
+                    .iter()
+                    .map(|n| format!("`{n}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let plural = if names.len() == 1 { "" } else { "s" };
+                write!(f, "missing input{plural}: {list}")
+            }
+            R1CSError::MissingArrayElement { array, index } => {
+                write!(
+                    f,
+                    "missing element `{array}_{index}` of array input `{array}`"
+                )
+            }
+            R1CSError::WitnessDivergence {
+                var,
+                ir_index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "witness diverges from IR evaluation at {var} (instruction {ir_index}): \
+                 evaluated {expected}, R1CS wire holds {actual}"
+            ),
+            R1CSError::WitnessOpOrder { op_index, wire } => write!(
+                f,
+                "witness op #{op_index} reads wire {wire} before any earlier op writes it"
+            ),
         }
     }
 }
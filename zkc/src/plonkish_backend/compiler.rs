@@ -32,6 +32,9 @@ pub struct PlonkishCompiler<F: FieldBackend = Bn254Fr> {
     pub bindings: HashMap<String, CellRef>,
     pub public_inputs: Vec<String>,
     pub witnesses: Vec<String>,
+    // Input-map keys past the end of a declared array input, collected by
+    // `compile_ir_with_witness` (same format as the R1CS backend's)
+    pub input_warnings: Vec<String>,
     pub(super) instance_row: usize,
     pub(super) current_row: usize,
     // Witness ops trace
@@ -51,6 +51,7 @@ impl<F: FieldBackend> PlonkishCompiler<F> {
             bindings: HashMap::new(),
             public_inputs: Vec::new(),
             witnesses: Vec::new(),
+            input_warnings: Vec::new(),
             instance_row: 0,
             current_row: 0,
             witness_ops: Vec::new(),
@@ -142,6 +143,9 @@ impl<F: FieldBackend> PlonkishCompiler<F> {
     /// 1. Evaluates the IR with concrete inputs for early validation.
     /// 2. Compiles IR → Plonkish constraints.
     /// 3. Generates the witness by replaying ops into assignments.
+    ///
+    /// Surplus array elements in `inputs` are ignored and recorded in
+    /// `input_warnings`.
     pub fn compile_ir_with_witness(
         &mut self,
         program: &IrProgram<F>,
@@ -154,6 +158,8 @@ impl<F: FieldBackend> PlonkishCompiler<F> {
         let _ssa_values = ir::eval::evaluate(program, inputs)
             .map_err(|e| PlonkishError::MissingInput(format!("evaluation error: {e}")))?;
 
+        self.input_warnings = crate::r1cs_witness::unexpected_input_warnings(program, inputs);
+
         // 2. Compile constraints
         self.compile_ir(program)?;
 
@@ -198,21 +198,16 @@ impl<F: FieldBackend> constraints::ConstraintBackend<F> for PlonkishCompiler<F>
                 self.compile_is_neq(*result, lhs, rhs)?;
             }
             IrInstruction::IsLt { result, lhs, rhs } => {
-                let a_val = self.lookup_val(lhs)?;
-                let b_val = self.lookup_val(rhs)?;
-                let a_cell = self.materialize_val(&a_val)?;
-                let b_cell = self.materialize_val(&b_val)?;
-                let bound_a = self.range_bounds.get(lhs).copied();
-                let bound_b = self.range_bounds.get(rhs).copied();
-                let bound = match (bound_a, bound_b) {
-                    (Some(ba), Some(bb)) => Some(ba.max(bb)),
-                    _ => None,
-                };
-                let lt_cell = self.emit_is_lt_bounded(a_cell, b_cell, bound)?;
-                self.val_map.insert(*result, PlonkVal::Cell(lt_cell));
+                self.compile_is_lt(*result, lhs, rhs, false)?;
+            }
+            IrInstruction::IsGt { result, lhs, rhs } => {
+                self.compile_is_lt(*result, lhs, rhs, true)?;
             }
             IrInstruction::IsLe { result, lhs, rhs } => {
-                self.compile_is_le(*result, lhs, rhs)?;
+                self.compile_is_le(*result, lhs, rhs, false)?;
+            }
+            IrInstruction::IsGe { result, lhs, rhs } => {
+                self.compile_is_le(*result, lhs, rhs, true)?;
             }
             IrInstruction::IsLtBounded {
                 result,
@@ -159,28 +159,69 @@ impl<F: FieldBackend> PlonkishCompiler<F> {
         self.compile_one_minus(result, eq_cell)
     }
 
+    /// `IsLt` (`swap = false`) or `IsGt` (`swap = true`, `a > b ≡ b < a`).
+    /// Bounds are looked up on the operands as written.
+    pub(super) fn compile_is_lt(
+        &mut self,
+        result: SsaVar,
+        lhs: &SsaVar,
+        rhs: &SsaVar,
+        swap: bool,
+    ) -> Result<(), PlonkishError>
+    where
+        F: PoseidonParamsProvider,
+    {
+        let (a_cell, b_cell, bound) = self.ordered_operands(lhs, rhs)?;
+        let lt_cell = if swap {
+            self.emit_is_lt_bounded(b_cell, a_cell, bound)?
+        } else {
+            self.emit_is_lt_bounded(a_cell, b_cell, bound)?
+        };
+        self.val_map.insert(result, PlonkVal::Cell(lt_cell));
+        Ok(())
+    }
+
+    /// `IsLe` (`swap = false`) or `IsGe` (`swap = true`).
     pub(super) fn compile_is_le(
         &mut self,
         result: SsaVar,
         lhs: &SsaVar,
         rhs: &SsaVar,
+        swap: bool,
     ) -> Result<(), PlonkishError>
     where
         F: PoseidonParamsProvider,
     {
         // a <= b ≡ !(b < a) ≡ 1 - IsLt(b, a)
+        // a >= b ≡ !(a < b) ≡ 1 - IsLt(a, b)
+        let (a_cell, b_cell, bound) = self.ordered_operands(lhs, rhs)?;
+        let lt_cell = if swap {
+            self.emit_is_lt_bounded(a_cell, b_cell, bound)?
+        } else {
+            self.emit_is_lt_bounded(b_cell, a_cell, bound)?
+        };
+        self.compile_one_minus(result, lt_cell)
+    }
+
+    /// Materialize both comparison operands and the shared bound (the
+    /// wider of the two), if both are range-checked.
+    fn ordered_operands(
+        &mut self,
+        lhs: &SsaVar,
+        rhs: &SsaVar,
+    ) -> Result<(CellRef, CellRef, Option<u32>), PlonkishError> {
         let a_val = self.lookup_val(lhs)?;
         let b_val = self.lookup_val(rhs)?;
         let a_cell = self.materialize_val(&a_val)?;
         let b_cell = self.materialize_val(&b_val)?;
-        let bound_a = self.range_bounds.get(lhs).copied();
-        let bound_b = self.range_bounds.get(rhs).copied();
-        let bound = match (bound_a, bound_b) {
+        let bound = match (
+            self.range_bounds.get(lhs).copied(),
+            self.range_bounds.get(rhs).copied(),
+        ) {
             (Some(ba), Some(bb)) => Some(ba.max(bb)),
             _ => None,
         };
-        let lt_cell = self.emit_is_lt_bounded(b_cell, a_cell, bound)?;
-        self.compile_one_minus(result, lt_cell)
+        Ok((a_cell, b_cell, bound))
     }
 
     pub(super) fn compile_is_le_bounded(
@@ -5,7 +5,7 @@ use constraints::r1cs_optimize::{R1CSOptimizeResult, SubstitutionMap};
 use constraints::PoseidonParamsProvider;
 use memory::field::PrimeId;
 use memory::{Bn254Fr, FieldBackend, FieldElement};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
@@ -17,10 +17,13 @@ use crate::witness::WitnessOp;
 
 mod api;
 mod counters;
+mod debug_verify;
 mod dispatch;
 mod int_divmod;
 mod lc_map;
 mod proving_parts;
+mod witness_check;
+mod witness_labels;
 
 pub use counters::{
     r1cs_kind_profile_enabled, reset_boolcheck_counters, reset_r1cs_kind_profile,
@@ -28,6 +31,7 @@ pub use counters::{
     BC_DECOMPOSE, BC_DECOMPOSE_1BIT, BC_ENFORCE_N_RANGE, BC_IS_LT_VIA_BITS, BC_MUX_COND, BC_NOT,
     BC_OR_LHS, BC_OR_RHS, BC_RANGE_CHECK,
 };
+pub use debug_verify::DebugFailure;
 use lc_map::{LcMap, UsedSsaSet};
 pub use lc_map::{LcMapShapeCounts, R1CSRetainedStats};
 
@@ -58,6 +62,14 @@ pub struct R1CSCompiler<F: FieldBackend = Bn254Fr> {
     pub public_inputs: Vec<String>,
     /// Names of variables declared as private witnesses (in declaration order).
     pub witnesses: Vec<String>,
+    /// Declared input defaults (`witness x = 5`) copied from the compiled
+    /// program; the witness fill uses them for inputs the caller omits.
+    pub input_defaults: HashMap<String, FieldElement<F>>,
+    /// Input-map keys that look like elements past the end of a declared
+    /// array input (`path_3` for `witness path[3]`), collected by
+    /// `compile_ir_with_witness`. They are ignored; callers decide whether
+    /// to report them.
+    pub input_warnings: Vec<String>,
     /// Cached Poseidon parameters. Initialized on first `poseidon()` call.
     pub(crate) poseidon_params: Option<PoseidonParams<F>>,
     /// Witness generation trace: records each intermediate variable allocation.
@@ -90,6 +102,11 @@ pub struct R1CSCompiler<F: FieldBackend = Bn254Fr> {
     /// emission path, freeing ~16 B per emitted constraint plus Vec capacity
     /// tail — material on circuits emitting tens of millions of constraints.
     track_constraint_origins: bool,
+    /// Constraints emitted per 1-indexed source line, accumulated by
+    /// `compile_ir` from `constraint_origins` and the program's `var_spans`.
+    /// Constraints whose defining instruction carries no span are left out.
+    /// Read via [`R1CSCompiler::constraint_profile`].
+    line_profile: BTreeMap<usize, usize>,
     /// Toggle for retaining input-name metadata while compiling IR inputs.
     ///
     /// Normal compilers keep the `bindings`, `public_inputs`, and `witnesses`
@@ -128,6 +145,11 @@ pub struct R1CSCompiler<F: FieldBackend = Bn254Fr> {
     /// redundant pass. Default `false` (validate) — leaving it false keeps the
     /// produced witness and error behavior identical to before.
     pub(crate) skip_eval_validation: bool,
+    /// Log one line per IR instruction to stderr from `compile_ir`, with
+    /// the constraints and variables it added. Read from
+    /// `ACH_TRACE_CONSTRAINTS=1` at construction so the untraced path
+    /// never checks the environment.
+    pub(crate) trace_constraints: bool,
     /// Variable substitution map from R1CS linear constraint elimination.
     /// Set by `optimize_r1cs()`. Used by witness generation to compute
     /// values for substituted-away wires.
@@ -157,6 +179,18 @@ pub struct R1CSCompiler<F: FieldBackend = Bn254Fr> {
     /// the cached result instead of emitting duplicate constraints.
     #[allow(clippy::type_complexity)]
     divmod_cache: HashMap<(SsaVar, SsaVar, u32), (LinearCombination<F>, LinearCombination<F>)>,
+    /// Wires allocated by `materialize_lc`, keyed by the simplified term
+    /// list of the LC they equal. Materializing the same LC again (the same
+    /// multi-term operand fed to two hashes, a range check and a comparison,
+    /// …) reuses the existing wire instead of emitting another equality
+    /// constraint and `AssignLC` op. Keys are canonical (sorted, merged,
+    /// zero-free), so two LCs share a wire only when they are the same
+    /// linear form. Cleared with the other per-program caches.
+    pub(crate) materialized_lcs: HashMap<Vec<(Variable, FieldElement<F>)>, Variable>,
+    /// Whether `materialize_lc` consults and fills `materialized_lcs`.
+    /// Compile-only sizing probes turn it off so resident memory stays
+    /// bounded by the emission window rather than every materialized LC.
+    pub(crate) reuse_materialized_lcs: bool,
     /// Content-hash intern table for Artik bytecode payloads.
     /// Holds `Arc<[u8]>` so identical payloads emitted at multiple
     /// `WitnessCall` sites share one heap allocation. A flat `Vec`
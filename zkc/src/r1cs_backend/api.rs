@@ -14,6 +14,8 @@ impl<F: FieldBackend> R1CSCompiler<F> {
             bindings: HashMap::new(),
             public_inputs: Vec::new(),
             witnesses: Vec::new(),
+            input_defaults: HashMap::new(),
+            input_warnings: Vec::new(),
             prime_id: PrimeId::Bn254,
             poseidon_params: None,
             witness_ops: SegmentedVec::new(),
@@ -21,17 +23,21 @@ impl<F: FieldBackend> R1CSCompiler<F> {
             bool_enforced: std::collections::HashSet::new(),
             constraint_origins: Vec::new(),
             track_constraint_origins: true,
+            line_profile: BTreeMap::new(),
             track_input_metadata: true,
             forward_assert_eq_collapse: false,
             direct_linear_mul: false,
             record_witness_ops: true,
             skip_eval_validation: false,
+            trace_constraints: std::env::var("ACH_TRACE_CONSTRAINTS").as_deref() == Ok("1"),
             substitution_map: None,
             lc_map: LcMap::new(),
             lc_cache_term_limit: None,
             used_ssa: UsedSsaSet::new(),
             range_bounds: HashMap::new(),
             divmod_cache: HashMap::new(),
+            materialized_lcs: HashMap::new(),
+            reuse_materialized_lcs: true,
             artik_program_intern: Vec::new(),
             artik_memo: None,
         }
@@ -132,6 +138,7 @@ impl<F: FieldBackend> R1CSCompiler<F> {
     pub fn new_compile_only_direct_linear_mul() -> Self {
         let mut c = Self::new_direct_linear_mul();
         c.record_witness_ops = false;
+        c.reuse_materialized_lcs = false;
         c.cs.disable_constraint_retention();
         match std::env::var("ACH_R1CS_COMPILE_ONLY_COLLAPSE").as_deref() {
             Ok("1") | Ok("true") | Ok("full") => c.cs.enable_incremental_collapse(),
@@ -183,6 +190,7 @@ impl<F: FieldBackend> R1CSCompiler<F> {
             bool_enforced_len: self.bool_enforced.len(),
             range_bounds_len: self.range_bounds.len(),
             divmod_cache_len: self.divmod_cache.len(),
+            materialized_lcs_len: self.materialized_lcs.len(),
             artik_program_intern_len: self.artik_program_intern.len(),
         }
     }
@@ -256,6 +264,11 @@ impl<F: FieldBackend> R1CSCompiler<F> {
         })
     }
 
+    /// The wire `var` lives on, when its cached LC is exactly one variable.
+    pub(crate) fn ssa_wire(&self, var: &SsaVar) -> Option<Variable> {
+        self.lc_map.get_variable(var)
+    }
+
     pub(super) fn mark_ssa_used(&mut self, var: SsaVar) {
         self.used_ssa.mark(var);
     }
@@ -421,7 +434,63 @@ impl<F: FieldBackend> R1CSCompiler<F> {
         self.used_ssa.clear();
         self.range_bounds.clear();
         self.divmod_cache.clear();
-        <Self as constraints::ConstraintBackend<F>>::compile_ir(self, program)
+        self.materialized_lcs.clear();
+        let origins_before = self.constraint_origins.len();
+        self.input_defaults.extend(
+            program
+                .input_defaults
+                .iter()
+                .map(|(name, value)| (name.clone(), *value)),
+        );
+        if self.trace_constraints {
+            self.compile_ir_traced(program)?;
+        } else {
+            <Self as constraints::ConstraintBackend<F>>::compile_ir(self, program)?;
+        }
+        for origin in &self.constraint_origins[origins_before..] {
+            if let Some(span) = program.get_span(origin.result_var) {
+                *self.line_profile.entry(span.line_start).or_insert(0) += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// `compile_ir` under `ACH_TRACE_CONSTRAINTS=1`: one stderr line per
+    /// instruction, as space-separated `key=value` fields:
+    ///
+    /// ```text
+    /// ach-trace-constraints ir=3 op=PoseidonHash constraints=+361 variables=+362
+    /// ```
+    fn compile_ir_traced(&mut self, program: &IrProgram<F>) -> Result<(), R1CSError>
+    where
+        F: PoseidonParamsProvider,
+    {
+        use std::io::Write;
+
+        let mut err = std::io::stderr().lock();
+        for (ir_idx, inst) in program.iter().enumerate() {
+            let (c0, v0) = (self.cs.num_constraints(), self.cs.num_variables());
+            <Self as constraints::ConstraintBackend<F>>::compile_instruction(self, ir_idx, inst)?;
+            let _ = writeln!(
+                err,
+                "ach-trace-constraints ir={ir_idx} op={} constraints={:+} variables={:+}",
+                inst.op_name(),
+                self.cs.num_constraints() as i64 - c0 as i64,
+                self.cs.num_variables() as i64 - v0 as i64,
+            );
+        }
+        Ok(())
+    }
+
+    /// Number of constraints attributed to each 1-indexed source line.
+    ///
+    /// Filled by [`compile_ir`](Self::compile_ir) (and therefore
+    /// `compile_ir_with_witness`) from the spans the program carries;
+    /// counts are taken at emission time, before `optimize_r1cs*`. Empty
+    /// for compilers built with [`new_lean`](Self::new_lean), which do
+    /// not track constraint origins.
+    pub fn constraint_profile(&self) -> BTreeMap<usize, usize> {
+        self.line_profile.clone()
     }
 
     /// Streaming counterpart of [`compile_ir`](Self::compile_ir): consume
@@ -441,6 +510,7 @@ impl<F: FieldBackend> R1CSCompiler<F> {
         self.used_ssa.clear();
         self.range_bounds.clear();
         self.divmod_cache.clear();
+        self.materialized_lcs.clear();
         <Self as constraints::ConstraintBackend<F>>::compile_instructions(self, instructions)
     }
 
@@ -448,9 +518,9 @@ impl<F: FieldBackend> R1CSCompiler<F> {
     /// [`compile_instructions`](Self::compile_instructions). Consumes
     /// owned instructions from any [`IntoIterator`] source like the
     /// single-batch entry point, but does **not** clear the per-program
-    /// caches (`lc_map`, `range_bounds`, `divmod_cache`) on entry —
-    /// state carries across calls so operands defined in an earlier
-    /// batch remain resolvable in a later batch.
+    /// caches (`lc_map`, `range_bounds`, `divmod_cache`, `materialized_lcs`)
+    /// on entry — state carries across calls so operands defined in an
+    /// earlier batch remain resolvable in a later batch.
     ///
     /// Intended for feeding a single program in multiple batches, one
     /// batch per emission chunk from a chunk-draining lysis sink. The
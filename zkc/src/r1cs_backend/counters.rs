@@ -58,7 +58,7 @@ pub fn reset_boolcheck_counters() {
     BC_DECOMPOSE_1BIT.store(0, Ordering::Relaxed);
 }
 
-const R1CS_KIND_PROFILE_LEN: usize = 25;
+const R1CS_KIND_PROFILE_LEN: usize = 27;
 
 #[derive(Clone, Copy, Default)]
 pub struct R1csKindProfileEntry {
@@ -136,14 +136,16 @@ fn r1cs_kind_profile_index<F: FieldBackend>(inst: &IrInstruction<F>) -> usize {
         IrInstruction::IsNeq { .. } => 14,
         IrInstruction::IsLt { .. } => 15,
         IrInstruction::IsLe { .. } => 16,
-        IrInstruction::IsLtBounded { .. } => 17,
-        IrInstruction::IsLeBounded { .. } => 18,
-        IrInstruction::IntDiv { .. } => 19,
-        IrInstruction::IntMod { .. } => 20,
-        IrInstruction::AssertEq { .. } => 21,
-        IrInstruction::Assert { .. } => 22,
-        IrInstruction::RangeCheck { .. } => 23,
-        IrInstruction::WitnessCall(_) => 24,
+        IrInstruction::IsGt { .. } => 17,
+        IrInstruction::IsGe { .. } => 18,
+        IrInstruction::IsLtBounded { .. } => 19,
+        IrInstruction::IsLeBounded { .. } => 20,
+        IrInstruction::IntDiv { .. } => 21,
+        IrInstruction::IntMod { .. } => 22,
+        IrInstruction::AssertEq { .. } => 23,
+        IrInstruction::Assert { .. } => 24,
+        IrInstruction::RangeCheck { .. } => 25,
+        IrInstruction::WitnessCall(_) => 26,
     }
 }
 
@@ -165,6 +167,8 @@ const R1CS_KIND_PROFILE_LABELS: [&str; R1CS_KIND_PROFILE_LEN] = [
     "IsNeq",
     "IsLt",
     "IsLe",
+    "IsGt",
+    "IsGe",
     "IsLtBounded",
     "IsLeBounded",
     "IntDiv",
@@ -0,0 +1,136 @@
+//! "My witness doesn't satisfy the circuit": find the first failing gate
+//! and point at the IR instruction — and source span — that emitted it.
+//!
+//! `ConstraintSystem::verify` only reports a constraint index. This joins
+//! that index with `constraint_origins` and the program's `var_spans`, and
+//! reads the row's `A`, `B` and `C` back through
+//! `ConstraintSystem::eval_constraint` so the mismatch is visible.
+
+use std::fmt;
+
+use constraints::r1cs::ConstraintError;
+use ir::error::OptSpan;
+
+use super::*;
+
+/// Why [`R1CSCompiler::debug_verify`] rejected a witness.
+#[derive(Debug)]
+pub enum DebugFailure<F: FieldBackend = Bn254Fr> {
+    /// The witness itself is malformed (wrong length, `witness[0] != 1`,
+    /// out-of-range wire), so no single gate is to blame.
+    Witness(ConstraintError),
+    /// The first constraint, in emission order, with `A·B ≠ C`.
+    Unsatisfied {
+        constraint: usize,
+        a: FieldElement<F>,
+        b: FieldElement<F>,
+        c: FieldElement<F>,
+        /// Index of the emitting instruction in the program, when origins
+        /// were tracked and still line up with the constraint system.
+        ir_index: Option<usize>,
+        /// The emitting instruction, rendered as in `--dump-ir`.
+        instruction: Option<String>,
+        /// Source span of the emitting instruction's result, if any.
+        span: OptSpan,
+    },
+}
+
+impl<F: FieldBackend> fmt::Display for DebugFailure<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DebugFailure::Witness(e) => write!(f, "malformed witness: {e}"),
+            DebugFailure::Unsatisfied {
+                constraint,
+                a,
+                b,
+                c,
+                ir_index,
+                instruction,
+                span,
+            } => {
+                if let Some(span) = span {
+                    write!(f, "[{span}] ")?;
+                }
+                write!(
+                    f,
+                    "constraint {constraint} is not satisfied: A·B = {} but C = {} (A = {}, B = {})",
+                    a.mul(b).to_decimal_string(),
+                    c.to_decimal_string(),
+                    a.to_decimal_string(),
+                    b.to_decimal_string()
+                )?;
+                if let (Some(idx), Some(inst)) = (ir_index, instruction) {
+                    write!(f, "\n  emitted by instruction {idx}: {inst}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<F: FieldBackend> std::error::Error for DebugFailure<F> {}
+
+impl<F: FieldBackend> R1CSCompiler<F> {
+    /// Like `cs.verify(witness)`, but on failure report the first failing
+    /// constraint with its evaluated `A`/`B`/`C` and the instruction of
+    /// `program` that emitted it.
+    ///
+    /// `program` must be the one passed to `compile_ir`. Attribution needs
+    /// constraint origins, so it is dropped for [`new_lean`](Self::new_lean)
+    /// compilers and after `optimize_r1cs*` rewrites the system.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zkc::r1cs_backend::{DebugFailure, R1CSCompiler};
+    /// use zkc::witness::WitnessGenerator;
+    /// use ir::IrLowering;
+    /// use memory::FieldElement;
+    ///
+    /// let prog: ir::types::IrProgram =
+    ///     IrLowering::lower_circuit("assert_eq(x * y, z)", &["z"], &["x", "y"]).unwrap();
+    /// let mut rc = R1CSCompiler::new();
+    /// rc.compile_ir(&prog).unwrap();
+    ///
+    /// let inputs: HashMap<_, _> = [("z", 42), ("x", 6), ("y", 7)]
+    ///     .into_iter()
+    ///     .map(|(n, v)| (n.to_string(), FieldElement::from_u64(v)))
+    ///     .collect();
+    /// let mut witness = WitnessGenerator::from_compiler(&rc).generate(&inputs).unwrap();
+    /// assert!(rc.debug_verify(&prog, &witness).is_ok());
+    ///
+    /// witness[1] = FieldElement::from_u64(41); // z
+    /// let err = rc.debug_verify(&prog, &witness).unwrap_err();
+    /// assert!(matches!(err, DebugFailure::Unsatisfied { .. }));
+    /// ```
+    pub fn debug_verify(
+        &self,
+        program: &IrProgram<F>,
+        witness: &[FieldElement<F>],
+    ) -> Result<(), DebugFailure<F>> {
+        let constraint = match self.cs.verify(witness) {
+            Ok(()) => return Ok(()),
+            Err(ConstraintError::ConstraintUnsatisfied(i)) => i,
+            Err(e) => return Err(DebugFailure::Witness(e)),
+        };
+
+        let (a, b, c) = self
+            .cs
+            .eval_constraint(constraint, witness)
+            .map_err(DebugFailure::Witness)?;
+
+        let origin = (self.constraint_origins.len() == self.cs.num_constraints())
+            .then(|| &self.constraint_origins[constraint]);
+        let instruction = origin.and_then(|o| program.instructions().get(o.ir_index));
+        Err(DebugFailure::Unsatisfied {
+            constraint,
+            a,
+            b,
+            c,
+            ir_index: origin.map(|o| o.ir_index),
+            instruction: instruction.map(|inst| inst.to_string()),
+            span: origin
+                .and_then(|o| program.get_span(o.result_var))
+                .map(|s| Box::new(s.clone())),
+        })
+    }
+}
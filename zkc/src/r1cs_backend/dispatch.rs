@@ -1,5 +1,6 @@
 use super::counters::record_r1cs_kind_profile;
 use super::*;
+use constraints::{CircuitHasher, PoseidonHasher};
 
 impl<F: FieldBackend> constraints::ConstraintBackend<F> for R1CSCompiler<F> {
     type Error = R1CSError;
@@ -46,13 +47,17 @@ impl<F: FieldBackend> constraints::ConstraintBackend<F> for R1CSCompiler<F> {
             IrInstruction::Add { result, lhs, rhs } => {
                 let a = self.lookup_lc(lhs)?;
                 let b = self.lookup_lc(rhs)?;
-                let out = self.auto_materialize(a + b);
+                let mut sum = a + b;
+                sum.simplify_in_place();
+                let out = self.auto_materialize(sum);
                 self.cache_lc(*result, out);
             }
             IrInstruction::Sub { result, lhs, rhs } => {
                 let a = self.lookup_lc(lhs)?;
                 let b = self.lookup_lc(rhs)?;
-                let out = self.auto_materialize(a - b);
+                let mut diff = a - b;
+                diff.simplify_in_place();
+                let out = self.auto_materialize(diff);
                 self.cache_lc(*result, out);
             }
             IrInstruction::Neg { result, operand } => {
@@ -81,8 +86,14 @@ impl<F: FieldBackend> constraints::ConstraintBackend<F> for R1CSCompiler<F> {
                 let then_lc = self.lookup_lc(if_true)?;
                 let else_lc = self.lookup_lc(if_false)?;
 
-                // Skip boolean enforcement if cond is proven boolean or already enforced
-                if !self.proven_boolean.contains(cond) && self.bool_enforced.insert(*cond) {
+                // Skip boolean enforcement if cond is proven boolean, already
+                // enforced, or a constant 0/1 (the constraint would be trivial)
+                if !self.proven_boolean.contains(cond)
+                    && !cond_lc
+                        .constant_value()
+                        .is_some_and(|c| c.is_zero() || c == FieldElement::<F>::one())
+                    && self.bool_enforced.insert(*cond)
+                {
                     BC_MUX_COND.fetch_add(1, Ordering::Relaxed);
                     let one = LinearCombination::from_constant(FieldElement::<F>::one());
                     let one_minus_cond = one - cond_lc.clone();
@@ -250,56 +261,23 @@ impl<F: FieldBackend> constraints::ConstraintBackend<F> for R1CSCompiler<F> {
                 self.cache_lc(*result, one - eq_lc);
             }
             IrInstruction::IsLt { result, lhs, rhs } => {
-                let a = self.lookup_lc(lhs)?;
-                let b = self.lookup_lc(rhs)?;
-                let bound_a = self.range_bounds.get(lhs).copied();
-                let bound_b = self.range_bounds.get(rhs).copied();
-                let default_bits = self.default_range_bits();
-
-                let effective_bits = match (bound_a, bound_b) {
-                    (Some(ba), Some(bb)) => ba.max(bb),
-                    _ => {
-                        if bound_a.is_none() {
-                            self.enforce_default_range(&a);
-                        }
-                        if bound_b.is_none() {
-                            self.enforce_default_range(&b);
-                        }
-                        default_bits
-                    }
-                };
-
-                let offset =
-                    power_of_two_generic::<F>(effective_bits).sub(&FieldElement::<F>::one());
-                let diff = b - a + LinearCombination::from_constant(offset);
-                let lt_lc = self.compile_is_lt_via_bits(&diff, effective_bits + 1);
+                let lt_lc = self.compile_unbounded_lt(lhs, rhs, false)?;
                 self.cache_lc(*result, lt_lc);
             }
+            IrInstruction::IsGt { result, lhs, rhs } => {
+                // a > b  ≡  b < a
+                let gt_lc = self.compile_unbounded_lt(lhs, rhs, true)?;
+                self.cache_lc(*result, gt_lc);
+            }
             IrInstruction::IsLe { result, lhs, rhs } => {
                 // a <= b  ≡  !(b < a)  ≡  1 - IsLt(b, a)
-                let a = self.lookup_lc(lhs)?;
-                let b = self.lookup_lc(rhs)?;
-                let bound_a = self.range_bounds.get(lhs).copied();
-                let bound_b = self.range_bounds.get(rhs).copied();
-                let default_bits = self.default_range_bits();
-
-                let effective_bits = match (bound_a, bound_b) {
-                    (Some(ba), Some(bb)) => ba.max(bb),
-                    _ => {
-                        if bound_a.is_none() {
-                            self.enforce_default_range(&a);
-                        }
-                        if bound_b.is_none() {
-                            self.enforce_default_range(&b);
-                        }
-                        default_bits
-                    }
-                };
-
-                let offset =
-                    power_of_two_generic::<F>(effective_bits).sub(&FieldElement::<F>::one());
-                let diff = a - b + LinearCombination::from_constant(offset);
-                let lt_lc = self.compile_is_lt_via_bits(&diff, effective_bits + 1);
+                let lt_lc = self.compile_unbounded_lt(lhs, rhs, true)?;
+                let one = LinearCombination::from_constant(FieldElement::<F>::one());
+                self.cache_lc(*result, one - lt_lc);
+            }
+            IrInstruction::IsGe { result, lhs, rhs } => {
+                // a >= b  ≡  !(a < b)  ≡  1 - IsLt(a, b)
+                let lt_lc = self.compile_unbounded_lt(lhs, rhs, false)?;
                 let one = LinearCombination::from_constant(FieldElement::<F>::one());
                 self.cache_lc(*result, one - lt_lc);
             }
@@ -365,12 +343,7 @@ impl<F: FieldBackend> constraints::ConstraintBackend<F> for R1CSCompiler<F> {
                 let params = self.poseidon_params.as_ref().unwrap();
 
                 let internal_start = self.cs.num_variables();
-                let hash_var = constraints::poseidon::poseidon_hash_circuit(
-                    &mut self.cs,
-                    params,
-                    left_var,
-                    right_var,
-                );
+                let hash_var = PoseidonHasher::new(params).hash2(&mut self.cs, left_var, right_var);
                 let internal_count = self.cs.num_variables() - internal_start;
 
                 self.push_witness_op(WitnessOp::PoseidonHash {
@@ -487,3 +460,42 @@ impl<F: FieldBackend> constraints::ConstraintBackend<F> for R1CSCompiler<F> {
         Ok(())
     }
 }
+
+impl<F: FieldBackend> R1CSCompiler<F> {
+    /// Emit `lhs < rhs` (or `rhs < lhs` when `swap`) for an unbounded
+    /// comparison and return the result bit.
+    ///
+    /// Range bounds are looked up on `lhs` / `rhs` as written in the
+    /// instruction, and operands without one are range-checked to the
+    /// field default in that same order, so `IsGt` / `IsGe` emit exactly
+    /// what an operand-swapped `IsLt` / `IsLe` would for the same bounds.
+    fn compile_unbounded_lt(
+        &mut self,
+        lhs: &SsaVar,
+        rhs: &SsaVar,
+        swap: bool,
+    ) -> Result<LinearCombination<F>, R1CSError> {
+        let a = self.lookup_lc(lhs)?;
+        let b = self.lookup_lc(rhs)?;
+        let bound_a = self.range_bounds.get(lhs).copied();
+        let bound_b = self.range_bounds.get(rhs).copied();
+        let default_bits = self.default_range_bits();
+
+        let effective_bits = match (bound_a, bound_b) {
+            (Some(ba), Some(bb)) => ba.max(bb),
+            _ => {
+                if bound_a.is_none() {
+                    self.enforce_default_range(&a);
+                }
+                if bound_b.is_none() {
+                    self.enforce_default_range(&b);
+                }
+                default_bits
+            }
+        };
+
+        let offset = power_of_two_generic::<F>(effective_bits).sub(&FieldElement::<F>::one());
+        let diff = if swap { a - b } else { b - a } + LinearCombination::from_constant(offset);
+        Ok(self.compile_is_lt_via_bits(&diff, effective_bits + 1))
+    }
+}
@@ -59,6 +59,7 @@ pub struct R1CSRetainedStats {
     pub bool_enforced_len: usize,
     pub range_bounds_len: usize,
     pub divmod_cache_len: usize,
+    pub materialized_lcs_len: usize,
     pub artik_program_intern_len: usize,
 }
 
@@ -151,6 +152,14 @@ impl<F: FieldBackend> LcMapSegment<F> {
         }
     }
 
+    fn variable(&self, offset: usize) -> Option<Variable> {
+        let slot = self.slots.get(offset).copied()?;
+        match LcTag::from_slot(slot) {
+            LcTag::Variable => Some(Variable((slot >> Self::TAG_BITS) as usize)),
+            _ => None,
+        }
+    }
+
     fn shape_counts(&self, counts: &mut LcMapShapeCounts) {
         for slot in &self.slots {
             let payload = (*slot >> Self::TAG_BITS) as usize;
@@ -248,6 +257,18 @@ impl<F: FieldBackend> LcMap<F> {
             .map(|entry| entry.to_lc())
     }
 
+    /// The single wire `var` is cached as, without materializing terms.
+    pub(super) fn get_variable(&self, var: &SsaVar) -> Option<Variable> {
+        let idx = var.0 as usize;
+        if idx >= self.keep_prefix_vars && idx < self.min_retained_idx {
+            return None;
+        }
+        self.segments
+            .get(idx / self.segment_len)
+            .and_then(Option::as_ref)
+            .and_then(|segment| segment.variable(idx % self.segment_len))
+    }
+
     #[cfg(test)]
     pub(super) fn get_entry(&self, var: &SsaVar) -> Option<LcMapEntry<F>> {
         let idx = var.0 as usize;
@@ -33,6 +33,7 @@ impl<F: FieldBackend> R1CSCompiler<F> {
         self.used_ssa.clear();
         self.range_bounds = HashMap::new();
         self.divmod_cache = HashMap::new();
+        self.materialized_lcs = HashMap::new();
         self.bool_enforced = std::collections::HashSet::new();
         self.proven_boolean = std::collections::HashSet::new();
     }
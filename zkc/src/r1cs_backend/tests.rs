@@ -3,6 +3,8 @@ use super::*;
 use ir::types::{Instruction, IrProgram, SsaVar, Visibility as IrVisibility};
 
 mod cache_modes;
+mod gadgets;
 mod maps;
 mod origins;
 mod streaming_intern;
+mod witness_labels;
@@ -298,3 +298,64 @@ fn count_only_collapse_absorbs_cache_rows_without_substitution_retention() {
         "compile-only count mode must not retain eliminated-wire replacements"
     );
 }
+#[test]
+fn repeated_materialization_reuses_the_first_wire() {
+    let mut prog: IrProgram<Bn254Fr> = IrProgram::new();
+    let input = |prog: &mut IrProgram<Bn254Fr>, name: &str| {
+        let v = prog.fresh_var();
+        prog.push(Instruction::Input {
+            result: v,
+            name: name.into(),
+            visibility: IrVisibility::Witness,
+        });
+        v
+    };
+    let a = input(&mut prog, "a");
+    let b = input(&mut prog, "b");
+    let c = input(&mut prog, "c");
+    let d = input(&mut prog, "d");
+    let add = |prog: &mut IrProgram<Bn254Fr>, lhs, rhs| {
+        let result = prog.fresh_var();
+        prog.push(Instruction::Add { result, lhs, rhs });
+        result
+    };
+    let ab = add(&mut prog, a, b);
+    // Same linear form, different term order.
+    let ba = add(&mut prog, b, a);
+    let ac = add(&mut prog, a, c);
+    for (lhs, rhs) in [(ab, c), (ba, d), (ac, d)] {
+        let result = prog.fresh_var();
+        prog.push(Instruction::Mul { result, lhs, rhs });
+    }
+
+    let mut fresh = R1CSCompiler::<Bn254Fr>::new();
+    fresh.reuse_materialized_lcs = false;
+    fresh.compile_ir(&prog).unwrap();
+
+    let mut reused = R1CSCompiler::<Bn254Fr>::new();
+    reused.compile_ir(&prog).unwrap();
+
+    assert_eq!(
+        fresh.cs.num_constraints(),
+        6,
+        "three materializations plus three products"
+    );
+    assert_eq!(
+        reused.cs.num_constraints(),
+        5,
+        "b+a reuses the a+b wire; a+c still gets its own"
+    );
+    assert_eq!(reused.cs.num_variables() + 1, fresh.cs.num_variables());
+    assert_eq!(reused.witness_ops.len() + 1, fresh.witness_ops.len());
+    assert_eq!(reused.retained_stats().materialized_lcs_len, 2);
+
+    let inputs: HashMap<String, FieldElement<Bn254Fr>> = [("a", 2), ("b", 3), ("c", 5), ("d", 7)]
+        .into_iter()
+        .map(|(name, v)| (name.to_string(), FieldElement::from_u64(v)))
+        .collect();
+    let witness = reused.fill_witness(&inputs).unwrap();
+    reused.cs.verify(&witness).unwrap();
+
+    reused.compile_ir(&IrProgram::new()).unwrap();
+    assert_eq!(reused.retained_stats().materialized_lcs_len, 0);
+}
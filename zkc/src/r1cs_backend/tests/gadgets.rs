@@ -0,0 +1,24 @@
+use super::*;
+
+#[test]
+fn multiply_lcs_by_zero_or_one_skips_the_rescaled_copy() {
+    let mut rc = R1CSCompiler::<Bn254Fr>::new();
+    let x = LinearCombination::from_variable(rc.cs.alloc_witness());
+    let y = LinearCombination::from_variable(rc.cs.alloc_witness());
+    let xy = x.clone() + y.clone();
+    let zero = LinearCombination::from_constant(FieldElement::zero());
+    let one = LinearCombination::from_constant(FieldElement::one());
+
+    // `xy * 0` would keep both terms with zero coefficients.
+    assert!(rc.multiply_lcs(&xy, &zero).terms().is_empty());
+    assert!(rc.multiply_lcs(&zero, &xy).terms().is_empty());
+    assert_eq!(rc.multiply_lcs(&one, &xy).terms(), xy.terms());
+    assert_eq!(rc.multiply_lcs(&xy, &one).terms(), xy.terms());
+
+    let three = LinearCombination::from_constant(FieldElement::from_u64(3));
+    assert_eq!(
+        rc.multiply_lcs(&three, &x).terms(),
+        (x * FieldElement::from_u64(3)).terms()
+    );
+    assert_eq!(rc.cs.num_constraints(), 0);
+}
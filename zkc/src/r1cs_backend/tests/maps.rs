@@ -420,3 +420,47 @@ fn lc_map_compile_ir_path_pins_cap_eq_len_on_every_populated_slot() {
         }
     }
 }
+
+#[test]
+fn add_sub_cache_simplified_lc() {
+    // (x + x) - x must be cached as the single term `1·x`, not three
+    // separate `x` entries, so the product below needs no materialization.
+    let mut prog: IrProgram<Bn254Fr> = IrProgram::new();
+    let x = prog.fresh_var();
+    prog.push(Instruction::Input {
+        result: x,
+        name: "x".into(),
+        visibility: IrVisibility::Witness,
+    });
+    let y = prog.fresh_var();
+    prog.push(Instruction::Input {
+        result: y,
+        name: "y".into(),
+        visibility: IrVisibility::Witness,
+    });
+    let double = prog.fresh_var();
+    prog.push(Instruction::Add {
+        result: double,
+        lhs: x,
+        rhs: x,
+    });
+    let back = prog.fresh_var();
+    prog.push(Instruction::Sub {
+        result: back,
+        lhs: double,
+        rhs: x,
+    });
+    let product = prog.fresh_var();
+    prog.push(Instruction::Mul {
+        result: product,
+        lhs: back,
+        rhs: y,
+    });
+
+    let mut compiler: R1CSCompiler<Bn254Fr> = R1CSCompiler::new();
+    compiler.compile_ir(&prog).unwrap();
+    let lc = compiler.lc_map.get(&back).unwrap();
+    assert_eq!(lc.terms().len(), 1);
+    assert_eq!(lc.as_single_variable(), compiler.lc_map.get_variable(&x));
+    assert_eq!(compiler.cs.num_constraints(), 1);
+}
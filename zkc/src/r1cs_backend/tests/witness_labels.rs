@@ -0,0 +1,70 @@
+use super::*;
+
+/// `out = x * y` with `out` public and `x`, `y` private.
+fn mul_program() -> IrProgram {
+    let mut prog: IrProgram = IrProgram::new();
+    let out = prog.fresh_var();
+    prog.push(Instruction::Input {
+        result: out,
+        name: "out".into(),
+        visibility: IrVisibility::Public,
+    });
+    let x = prog.fresh_var();
+    prog.push(Instruction::Input {
+        result: x,
+        name: "x".into(),
+        visibility: IrVisibility::Witness,
+    });
+    let y = prog.fresh_var();
+    prog.push(Instruction::Input {
+        result: y,
+        name: "y".into(),
+        visibility: IrVisibility::Witness,
+    });
+    let prod = prog.fresh_var();
+    prog.push(Instruction::Mul {
+        result: prod,
+        lhs: x,
+        rhs: y,
+    });
+    let eq = prog.fresh_var();
+    prog.push(Instruction::AssertEq {
+        result: eq,
+        lhs: prod,
+        rhs: out,
+        message: None,
+    });
+    prog
+}
+
+#[test]
+fn witness_labels_name_inputs_and_intermediates() {
+    let mut compiler = R1CSCompiler::new();
+    compiler.compile_ir(&mul_program()).unwrap();
+    let labels = compiler.witness_labels();
+
+    assert_eq!(labels.len(), compiler.cs.num_variables());
+    assert_eq!(labels[0], "ONE");
+    for name in ["out", "x", "y"] {
+        let var = compiler.bindings[name];
+        assert_eq!(labels[var.index()], name);
+    }
+    assert!(labels.iter().any(|l| l == "mul_result"), "{labels:?}");
+}
+
+#[test]
+fn sym_file_maps_inputs_to_wires() {
+    let mut compiler = R1CSCompiler::new();
+    compiler.compile_ir(&mul_program()).unwrap();
+    let sym = constraints::write_sym(&compiler.witness_labels());
+
+    assert_eq!(sym.lines().count(), compiler.cs.num_variables() - 1);
+    for name in ["out", "x", "y"] {
+        let idx = compiler.bindings[name].index();
+        assert!(
+            sym.lines()
+                .any(|l| l == format!("{idx},{idx},0,main.{name}")),
+            "{name} not at wire {idx}:\n{sym}"
+        );
+    }
+}
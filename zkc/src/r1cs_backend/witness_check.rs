@@ -0,0 +1,90 @@
+//! Debug cross-check between `ir::eval` and the R1CS witness.
+//!
+//! Every SSA value the compiler caches as a linear combination must
+//! evaluate, over the generated witness, to the value `ir::eval` computes
+//! for it. A divergence means the lowering of that instruction is wrong
+//! even if the constraint system happens to be satisfied (e.g. a result
+//! wired to a dangling or stale wire).
+
+use super::*;
+
+impl<F: FieldBackend> R1CSCompiler<F> {
+    /// Compile `program`, generate its witness, and check it against
+    /// `ir::eval::evaluate` SSA value by SSA value.
+    ///
+    /// Returns the witness on success, or [`R1CSError::WitnessDivergence`]
+    /// for the first instruction (in program order) whose result disagrees.
+    /// Meant for tests and debugging: it keeps the whole evaluation in
+    /// memory and re-walks the program.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zkc::r1cs_backend::R1CSCompiler;
+    /// use ir::IrLowering;
+    /// use memory::FieldElement;
+    ///
+    /// let prog: ir::types::IrProgram =
+    ///     IrLowering::lower_circuit("assert_eq(x * y, z)", &["z"], &["x", "y"]).unwrap();
+    /// let inputs: HashMap<_, _> = [("z", 42), ("x", 6), ("y", 7)]
+    ///     .into_iter()
+    ///     .map(|(n, v)| (n.to_string(), FieldElement::from_u64(v)))
+    ///     .collect();
+    ///
+    /// let mut rc = R1CSCompiler::new();
+    /// let witness = rc.check_witness_against_eval(&prog, &inputs).unwrap();
+    /// assert!(rc.cs.verify(&witness).is_ok());
+    /// ```
+    pub fn check_witness_against_eval(
+        &mut self,
+        program: &IrProgram<F>,
+        inputs: &HashMap<String, FieldElement<F>>,
+    ) -> Result<Vec<FieldElement<F>>, R1CSError>
+    where
+        F: PoseidonParamsProvider,
+    {
+        self.compile_ir(program)?;
+        let witness = self.fill_witness(inputs)?;
+        self.compare_witness_with_eval(program, inputs, &witness)?;
+        Ok(witness)
+    }
+
+    /// Compare an already-built `witness` against `ir::eval::evaluate`.
+    ///
+    /// Must be called after `compile_ir` on the same instance and before
+    /// anything releases the SSA → LC cache. SSA values the compiler never
+    /// cached (and values pruned by the lean compiler modes) are skipped.
+    pub fn compare_witness_with_eval(
+        &self,
+        program: &IrProgram<F>,
+        inputs: &HashMap<String, FieldElement<F>>,
+        witness: &[FieldElement<F>],
+    ) -> Result<(), R1CSError>
+    where
+        F: PoseidonParamsProvider,
+    {
+        let values = ir::eval::evaluate(program, inputs)
+            .map_err(|e| R1CSError::EvalError(format!("{e}")))?;
+
+        for (ir_index, inst) in program.iter().enumerate() {
+            let results =
+                std::iter::once(inst.result_var()).chain(inst.extra_result_vars().iter().copied());
+            for var in results {
+                let (Some(expected), Some(lc)) = (values.get(&var), self.lc_map.get(&var)) else {
+                    continue;
+                };
+                let actual = lc
+                    .evaluate(witness)
+                    .map_err(|e| R1CSError::EvalError(e.to_string()))?;
+                if actual != *expected {
+                    return Err(R1CSError::WitnessDivergence {
+                        var,
+                        ir_index,
+                        expected: expected.to_decimal_string(),
+                        actual: actual.to_decimal_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+//! Human-readable labels for R1CS wires, for witness dumps.
+
+use super::*;
+
+impl<F: FieldBackend> R1CSCompiler<F> {
+    /// Label every wire of the compiled circuit, indexed like the witness.
+    ///
+    /// Wire 0 is `"ONE"`, declared inputs carry their names from
+    /// `bindings`, and intermediate wires are named after the
+    /// [`WitnessOp`] that computes them (`"mul_result"`,
+    /// `"poseidon_internal_N"`, ...). Wires no op accounts for are
+    /// labeled `"wire_N"`. Must be called after `compile_ir`.
+    pub fn witness_labels(&self) -> Vec<String> {
+        let n = self.cs.num_variables();
+        let mut labels: Vec<Option<String>> = vec![None; n];
+        let mut set = |var: &Variable, label: String| {
+            if let Some(slot) = labels.get_mut(var.index()) {
+                *slot = Some(label);
+            }
+        };
+
+        for op in &self.witness_ops {
+            match op {
+                WitnessOp::AssignLC { target, .. } => set(target, "lc_result".into()),
+                WitnessOp::Multiply { target, .. } => set(target, "mul_result".into()),
+                WitnessOp::Inverse { target, .. } => set(target, "inv_result".into()),
+                WitnessOp::BitExtract {
+                    target, bit_index, ..
+                } => set(target, format!("bit_{bit_index}")),
+                WitnessOp::IsZero {
+                    target_inv,
+                    target_result,
+                    ..
+                } => {
+                    set(target_inv, "is_zero_inv".into());
+                    set(target_result, "is_zero_result".into());
+                }
+                WitnessOp::IntDivMod { q, r, .. } => {
+                    set(q, "div_quotient".into());
+                    set(r, "div_remainder".into());
+                }
+                WitnessOp::PoseidonHash {
+                    output,
+                    internal_start,
+                    internal_count,
+                    ..
+                } => {
+                    for k in 0..*internal_count {
+                        set(
+                            &Variable(internal_start + k),
+                            format!("poseidon_internal_{k}"),
+                        );
+                    }
+                    set(output, "poseidon_output".into());
+                }
+                WitnessOp::ArtikCall { outputs, .. } => {
+                    for (k, out) in outputs.iter().enumerate() {
+                        set(out, format!("artik_output_{k}"));
+                    }
+                }
+            }
+        }
+
+        for (name, var) in &self.bindings {
+            set(var, name.clone());
+        }
+        set(&Variable::ONE, "ONE".into());
+
+        labels
+            .into_iter()
+            .enumerate()
+            .map(|(i, label)| label.unwrap_or_else(|| format!("wire_{i}")))
+            .collect()
+    }
+}
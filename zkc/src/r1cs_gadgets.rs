@@ -23,12 +23,20 @@ impl<F: FieldBackend> R1CSCompiler<F> {
     /// Convert a `LinearCombination` to a `Variable`.
     ///
     /// If the LC is already a single variable with coefficient 1, returns it
-    /// directly (0 constraints). Otherwise allocates a fresh witness variable
-    /// and enforces equality (1 constraint).
+    /// directly (0 constraints). If the same LC (up to term order and
+    /// duplicate merging) was materialized earlier in this program, returns
+    /// that wire (0 constraints). Otherwise allocates a fresh witness
+    /// variable and enforces equality (1 constraint).
     pub(crate) fn materialize_lc(&mut self, lc: &LinearCombination<F>) -> Variable {
         if let Some(var) = lc.as_single_variable() {
             return var;
         }
+        let key = self
+            .reuse_materialized_lcs
+            .then(|| lc.simplify().into_terms());
+        if let Some(&var) = key.as_ref().and_then(|k| self.materialized_lcs.get(k)) {
+            return var;
+        }
         let var = self.cs.alloc_witness();
         self.push_witness_op(WitnessOp::AssignLC {
             target: var,
@@ -36,6 +44,9 @@ impl<F: FieldBackend> R1CSCompiler<F> {
         });
         self.cs
             .enforce_equal(lc.clone(), LinearCombination::from_variable(var));
+        if let Some(key) = key {
+            self.materialized_lcs.insert(key, var);
+        }
         var
     }
 
@@ -53,10 +64,10 @@ impl<F: FieldBackend> R1CSCompiler<F> {
     ) -> LinearCombination<F> {
         // Constant * anything → scalar mul (0 constraints)
         if let Some(scalar) = a.constant_value() {
-            return b.clone() * scalar;
+            return scale_lc(b, scalar);
         }
         if let Some(scalar) = b.constant_value() {
-            return a.clone() * scalar;
+            return scale_lc(a, scalar);
         }
         if self.direct_linear_mul {
             if !self.record_witness_ops {
@@ -110,7 +121,8 @@ impl<F: FieldBackend> R1CSCompiler<F> {
 
     /// Divide two LCs. If denominator is constant, uses scalar inverse
     /// multiplication (0 constraints). Otherwise allocates inverse +
-    /// product witnesses (2 constraints).
+    /// product witnesses (2 constraints), or only the inverse (1
+    /// constraint) when the numerator is constant, as in `inv(x)`.
     pub(crate) fn divide_lcs(
         &mut self,
         num: &LinearCombination<F>,
@@ -130,6 +142,9 @@ impl<F: FieldBackend> R1CSCompiler<F> {
             operand: den.clone(),
         });
         let den_inv_lc = LinearCombination::from_variable(den_inv);
+        if let Some(scalar) = num.constant_value() {
+            return Ok(den_inv_lc * scalar);
+        }
         let out = self.cs.mul_lc(num, &den_inv_lc);
         self.push_witness_op(WitnessOp::Multiply {
             target: out,
@@ -231,3 +246,18 @@ impl<F: FieldBackend> R1CSCompiler<F> {
         top_bit_lc
     }
 }
+
+/// `lc * scalar`. A 0 or 1 scalar needs no rescaled copy: the product is
+/// the empty LC or `lc` itself.
+fn scale_lc<F: FieldBackend>(
+    lc: &LinearCombination<F>,
+    scalar: FieldElement<F>,
+) -> LinearCombination<F> {
+    if scalar.is_zero() {
+        LinearCombination::zero()
+    } else if scalar == FieldElement::<F>::one() {
+        lc.clone()
+    } else {
+        lc.clone() * scalar
+    }
+}
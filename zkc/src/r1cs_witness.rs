@@ -3,11 +3,76 @@ use std::collections::HashMap;
 
 use constraints::PoseidonParamsProvider;
 
+use constraints::r1cs::Variable;
+
 use crate::error::R1CSError;
 use crate::r1cs_backend::R1CSCompiler;
-use crate::witness::{fill_poseidon_witness, int_divmod_field_pub, WitnessOp};
+use crate::witness::{
+    first_out_of_order_read, int_divmod_field_pub, PoseidonWitnessCtx, WitnessOp,
+};
+
+use ir::types::{Instruction, IrProgram};
+
+/// Reject an input map that leaves any declared input without a value,
+/// naming every missing input at once. Array inputs are checked first so
+/// a short array reports the first absent element by index.
+fn check_inputs<F: FieldBackend>(
+    program: &IrProgram<F>,
+    inputs: &HashMap<String, FieldElement<F>>,
+) -> Result<(), R1CSError> {
+    let mut arrays: Vec<(&String, &usize)> = program.input_arrays.iter().collect();
+    arrays.sort();
+    for (array, &size) in arrays {
+        if let Some(index) = (0..size).find(|i| {
+            program
+                .input_value(inputs, &format!("{array}_{i}"))
+                .is_none()
+        }) {
+            return Err(R1CSError::MissingArrayElement {
+                array: array.clone(),
+                index,
+            });
+        }
+    }
+
+    let missing: Vec<String> = program
+        .iter()
+        .filter_map(|inst| match inst {
+            Instruction::Input { name, .. } if program.input_value(inputs, name).is_none() => {
+                Some(name.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(R1CSError::MissingInput(missing))
+    }
+}
 
-use ir::types::IrProgram;
+/// One warning per key of `inputs` that names an element past the end of a
+/// declared array input, e.g. `path_3` when `path` has 3 elements. Sorted
+/// by key. Shared with the Plonkish backend.
+pub(crate) fn unexpected_input_warnings<F: FieldBackend>(
+    program: &IrProgram<F>,
+    inputs: &HashMap<String, FieldElement<F>>,
+) -> Vec<String> {
+    let mut extra: Vec<&String> = inputs
+        .keys()
+        .filter(|key| {
+            key.rsplit_once('_').is_some_and(|(array, index)| {
+                let size = program.input_arrays.get(array);
+                matches!((size, index.parse::<usize>()), (Some(&n), Ok(i)) if i >= n)
+            })
+        })
+        .collect();
+    extra.sort();
+    extra
+        .into_iter()
+        .map(|key| format!("unexpected input `{key}`: past the end of its declared array"))
+        .collect()
+}
 
 /// Witness generation methods for R1CSCompiler.
 impl<F: FieldBackend> R1CSCompiler<F> {
@@ -55,6 +120,8 @@ impl<F: FieldBackend> R1CSCompiler<F> {
     where
         F: PoseidonParamsProvider,
     {
+        self.check_input_map(program, inputs)?;
+
         // 1. Evaluate IR — early validation (skippable).
         if !self.skip_eval_validation {
             ir::eval::evaluate(program, inputs)
@@ -68,6 +135,18 @@ impl<F: FieldBackend> R1CSCompiler<F> {
         self.fill_witness(inputs)
     }
 
+    /// Validate `inputs` against the program's declared inputs and record
+    /// surplus array elements in `input_warnings`.
+    fn check_input_map(
+        &mut self,
+        program: &IrProgram<F>,
+        inputs: &HashMap<String, FieldElement<F>>,
+    ) -> Result<(), R1CSError> {
+        check_inputs(program, inputs)?;
+        self.input_warnings = unexpected_input_warnings(program, inputs);
+        Ok(())
+    }
+
     /// Build the witness vector for an already-compiled circuit by replaying
     /// the recorded `witness_ops` with concrete input values.
     ///
@@ -83,146 +162,336 @@ impl<F: FieldBackend> R1CSCompiler<F> {
     where
         F: PoseidonParamsProvider,
     {
-        // 3. Allocate the witness vector.
+        let mut witness = self.input_witness(inputs)?;
+        self.replay_witness_ops(&mut witness, None, false)?;
+        self.apply_substitutions(&mut witness)?;
+        Ok(witness)
+    }
+
+    /// [`fill_witness`](Self::fill_witness) that consumes the recorded
+    /// `witness_ops` while replaying them.
+    ///
+    /// Each op is dropped once applied and each `SegmentedVec` segment is
+    /// freed as the replay moves past it, so on very large circuits the
+    /// op log shrinks while the witness vector grows instead of both
+    /// staying resident until the call returns. Pair it with
+    /// [`compile_instructions_streaming`](Self::compile_instructions_streaming)
+    /// fed from a chunked generator to keep neither the full IR program
+    /// nor the full op log alive at peak.
+    ///
+    /// `witness_ops` is empty afterwards: a second fill, or a
+    /// `WitnessGenerator` built from this compiler, has nothing to
+    /// replay.
+    pub fn fill_witness_draining(
+        &mut self,
+        inputs: &HashMap<String, FieldElement<F>>,
+    ) -> Result<Vec<FieldElement<F>>, R1CSError>
+    where
+        F: PoseidonParamsProvider,
+    {
+        let mut witness = self.input_witness(inputs)?;
+        self.replay_witness_ops(&mut witness, None, true)?;
+        self.apply_substitutions(&mut witness)?;
+        Ok(witness)
+    }
+
+    /// Compile an SSA IR program and build its witness from the IR
+    /// evaluator's values instead of replaying every recorded op.
+    ///
+    /// `ir::eval::evaluate` already computes every SSA value (and doubles as
+    /// the early validation of `compile_ir_with_witness`). Each value whose
+    /// linear combination is a single wire is written straight into the
+    /// witness, and only ops with a target still unknown — gadget
+    /// internals such as IsZero inverses, bit decompositions and Poseidon
+    /// rounds — are replayed. Produces the same witness as
+    /// [`compile_ir_with_witness`](Self::compile_ir_with_witness) while
+    /// skipping the replay of plain arithmetic.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zkc::r1cs_backend::R1CSCompiler;
+    /// use ir::IrLowering;
+    /// use memory::FieldElement;
+    ///
+    /// let prog: ir::types::IrProgram =
+    ///     IrLowering::lower_circuit("assert_eq(x * y, z)", &["z"], &["x", "y"]).unwrap();
+    /// let inputs: HashMap<_, _> = [("z", 42), ("x", 6), ("y", 7)]
+    ///     .into_iter()
+    ///     .map(|(n, v)| (n.to_string(), FieldElement::from_u64(v)))
+    ///     .collect();
+    ///
+    /// let mut rc = R1CSCompiler::new();
+    /// let witness = rc.compile_ir_with_eval_witness(&prog, &inputs).unwrap();
+    /// assert!(rc.cs.verify(&witness).is_ok());
+    /// ```
+    pub fn compile_ir_with_eval_witness(
+        &mut self,
+        program: &IrProgram<F>,
+        inputs: &HashMap<String, FieldElement<F>>,
+    ) -> Result<Vec<FieldElement<F>>, R1CSError>
+    where
+        F: PoseidonParamsProvider,
+    {
+        self.check_input_map(program, inputs)?;
+        let values = ir::eval::evaluate(program, inputs)
+            .map_err(|e| R1CSError::EvalError(format!("{e}")))?;
+        self.compile_ir(program)?;
+
+        let mut witness = self.input_witness(inputs)?;
+        let mut known = vec![false; witness.len()];
+        known[0] = true;
+        for name in self.public_inputs.iter().chain(self.witnesses.iter()) {
+            known[self.bindings[name].index()] = true;
+        }
+        for (var, value) in &values {
+            if let Some(wire) = self.ssa_wire(var) {
+                witness[wire.index()] = *value;
+                known[wire.index()] = true;
+            }
+        }
+
+        self.replay_witness_ops(&mut witness, Some(&known), false)?;
+        self.apply_substitutions(&mut witness)?;
+        Ok(witness)
+    }
+
+    /// Allocate the witness vector with the constant wire and every
+    /// declared input filled in.
+    fn input_witness(
+        &self,
+        inputs: &HashMap<String, FieldElement<F>>,
+    ) -> Result<Vec<FieldElement<F>>, R1CSError> {
         let mut witness = vec![FieldElement::<F>::zero(); self.cs.num_variables()];
         witness[0] = FieldElement::<F>::one();
 
-        // 3a. Fill inputs. With early validation skipped, a missing input or
-        //     unbound signal must surface as an error here, not an index panic.
+        // `fill_witness` callers have no program to check up front, so a
+        // missing input or unbound signal must surface here, not as an
+        // index panic.
+        let mut missing = Vec::new();
         for name in self.public_inputs.iter().chain(self.witnesses.iter()) {
             let var = self
                 .bindings
                 .get(name)
                 .ok_or_else(|| R1CSError::EvalError(format!("unbound input signal `{name}`")))?;
-            let val = inputs
-                .get(name)
-                .ok_or_else(|| R1CSError::EvalError(format!("missing input `{name}`")))?;
-            witness[var.index()] = *val;
+            match inputs.get(name).or_else(|| self.input_defaults.get(name)) {
+                Some(val) => witness[var.index()] = *val,
+                None => missing.push(name.clone()),
+            }
         }
+        if !missing.is_empty() {
+            return Err(R1CSError::MissingInput(missing));
+        }
+        Ok(witness)
+    }
 
-        // 3b. Replay witness ops (which may have been filtered by optimize_r1cs).
+    /// Replay the recorded witness ops (which may have been filtered by
+    /// `optimize_r1cs`). With `known`, ops whose targets are all already
+    /// filled are skipped. With `drain`, the ops are moved out of
+    /// `witness_ops` and freed as they are applied.
+    fn replay_witness_ops(
+        &mut self,
+        witness: &mut [FieldElement<F>],
+        known: Option<&[bool]>,
+        drain: bool,
+    ) -> Result<(), R1CSError>
+    where
+        F: PoseidonParamsProvider,
+    {
+        self.check_witness_op_order(known)?;
+        let mut drained = drain.then(|| std::mem::take(&mut self.witness_ops));
         // Take the Artik cache out for the duration of the replay so each
         // `ArtikCall` can borrow it mutably without conflicting with the
         // immutable borrow of `witness_ops`; it is restored afterward.
         let mut artik_memo = self.artik_memo.take();
-        for op in &self.witness_ops {
-            match op {
-                WitnessOp::AssignLC { target, lc } => {
-                    witness[target.index()] = lc
-                        .evaluate(&witness)
-                        .map_err(|e| R1CSError::EvalError(e.to_string()))?;
-                }
-                WitnessOp::Multiply { target, a, b } => {
-                    let a_val = a
-                        .evaluate(&witness)
-                        .map_err(|e| R1CSError::EvalError(e.to_string()))?;
-                    let b_val = b
-                        .evaluate(&witness)
-                        .map_err(|e| R1CSError::EvalError(e.to_string()))?;
-                    witness[target.index()] = a_val.mul(&b_val);
-                }
-                WitnessOp::Inverse { target, operand } => {
-                    let val = operand
-                        .evaluate(&witness)
-                        .map_err(|e| R1CSError::EvalError(e.to_string()))?;
-                    witness[target.index()] = val.inv().ok_or_else(|| {
-                        R1CSError::EvalError(format!("division by zero at wire {}", target.index()))
-                    })?;
-                }
-                WitnessOp::BitExtract {
-                    target,
-                    source,
-                    bit_index,
-                } => {
-                    let val = source
-                        .evaluate(&witness)
-                        .map_err(|e| R1CSError::EvalError(e.to_string()))?;
-                    let limbs = val.to_canonical();
-                    let li = (*bit_index / 64) as usize;
-                    let bp = *bit_index % 64;
-                    let bit = if li < 4 { (limbs[li] >> bp) & 1 } else { 0 };
-                    witness[target.index()] = FieldElement::<F>::from_u64(bit);
-                }
-                WitnessOp::IsZero {
-                    diff,
-                    target_inv,
-                    target_result,
-                } => {
-                    let d = diff
-                        .evaluate(&witness)
-                        .map_err(|e| R1CSError::EvalError(e.to_string()))?;
-                    if d.is_zero() {
-                        witness[target_inv.index()] = FieldElement::<F>::zero();
-                        witness[target_result.index()] = FieldElement::<F>::one();
-                    } else {
-                        witness[target_inv.index()] = d
-                            .inv()
-                            .ok_or_else(|| R1CSError::EvalError("IsZero inverse failed".into()))?;
-                        witness[target_result.index()] = FieldElement::<F>::zero();
+        // One Poseidon context for the whole replay: parameters are
+        // resolved once and its scratch state is reused across hashes.
+        let mut poseidon = self.poseidon_params.as_ref().map(PoseidonWitnessCtx::new);
+        let mut replay = |op: &WitnessOp<F>| {
+            if known.is_some_and(|known| op_targets_known(op, known)) {
+                return Ok(());
+            }
+            self.apply_witness_op(op, witness, artik_memo.as_mut(), poseidon.as_mut())
+        };
+        let result = match drained.as_mut() {
+            Some(ops) => ops.drain().try_for_each(|op| replay(&op)),
+            None => self.witness_ops.iter().try_for_each(replay),
+        };
+        self.artik_memo = artik_memo;
+        result
+    }
+
+    /// Verify that every op only reads wires that are inputs, already
+    /// `known`, or written by an earlier op. Replay fills the witness in
+    /// trace order, so a misordered op would otherwise read a zero and
+    /// silently produce a wrong witness.
+    fn check_witness_op_order(&self, known: Option<&[bool]>) -> Result<(), R1CSError> {
+        let mut filled = match known {
+            Some(known) => known.to_vec(),
+            None => {
+                let mut filled = vec![false; self.cs.num_variables()];
+                filled[0] = true;
+                for name in self.public_inputs.iter().chain(self.witnesses.iter()) {
+                    if let Some(var) = self.bindings.get(name) {
+                        filled[var.index()] = true;
                     }
                 }
-                WitnessOp::IntDivMod { q, r, lhs, rhs } => {
-                    let a = witness[lhs.index()];
-                    let b = witness[rhs.index()];
-                    let a_limbs = a.to_canonical();
-                    let b_limbs = b.to_canonical();
-                    let (q_val, r_val) = int_divmod_field_pub::<F>(&a_limbs, &b_limbs);
-                    witness[q.index()] = q_val;
-                    witness[r.index()] = r_val;
+                filled
+            }
+        };
+        match first_out_of_order_read(&self.witness_ops, &mut filled, |op| {
+            known.is_some_and(|known| op_targets_known(op, known))
+        }) {
+            Some((op_index, wire)) => Err(R1CSError::WitnessOpOrder { op_index, wire }),
+            None => Ok(()),
+        }
+    }
+
+    fn apply_witness_op(
+        &self,
+        op: &WitnessOp<F>,
+        witness: &mut [FieldElement<F>],
+        artik_memo: Option<&mut artik::ArtikMemo<F>>,
+        poseidon: Option<&mut PoseidonWitnessCtx<'_, F>>,
+    ) -> Result<(), R1CSError>
+    where
+        F: PoseidonParamsProvider,
+    {
+        match op {
+            WitnessOp::AssignLC { target, lc } => {
+                witness[target.index()] = lc
+                    .evaluate(witness)
+                    .map_err(|e| R1CSError::EvalError(e.to_string()))?;
+            }
+            WitnessOp::Multiply { target, a, b } => {
+                let a_val = a
+                    .evaluate(witness)
+                    .map_err(|e| R1CSError::EvalError(e.to_string()))?;
+                let b_val = b
+                    .evaluate(witness)
+                    .map_err(|e| R1CSError::EvalError(e.to_string()))?;
+                witness[target.index()] = a_val.mul(&b_val);
+            }
+            WitnessOp::Inverse { target, operand } => {
+                let val = operand
+                    .evaluate(witness)
+                    .map_err(|e| R1CSError::EvalError(e.to_string()))?;
+                witness[target.index()] = val.inv().ok_or_else(|| {
+                    R1CSError::EvalError(format!("division by zero at wire {}", target.index()))
+                })?;
+            }
+            WitnessOp::BitExtract {
+                target,
+                source,
+                bit_index,
+            } => {
+                let val = source
+                    .evaluate(witness)
+                    .map_err(|e| R1CSError::EvalError(e.to_string()))?;
+                let limbs = val.to_canonical();
+                let li = (*bit_index / 64) as usize;
+                let bp = *bit_index % 64;
+                let bit = if li < 4 { (limbs[li] >> bp) & 1 } else { 0 };
+                witness[target.index()] = FieldElement::<F>::from_u64(bit);
+            }
+            WitnessOp::IsZero {
+                diff,
+                target_inv,
+                target_result,
+            } => {
+                let d = diff
+                    .evaluate(witness)
+                    .map_err(|e| R1CSError::EvalError(e.to_string()))?;
+                if d.is_zero() {
+                    witness[target_inv.index()] = FieldElement::<F>::zero();
+                    witness[target_result.index()] = FieldElement::<F>::one();
+                } else {
+                    witness[target_inv.index()] = d
+                        .inv()
+                        .ok_or_else(|| R1CSError::EvalError("IsZero inverse failed".into()))?;
+                    witness[target_result.index()] = FieldElement::<F>::zero();
                 }
-                WitnessOp::PoseidonHash {
-                    left,
-                    right,
-                    internal_start,
-                    internal_count,
-                    ..
-                } => {
-                    let params = self.poseidon_params.as_ref().ok_or_else(|| {
-                        R1CSError::EvalError("poseidon params not initialized".into())
-                    })?;
-                    fill_poseidon_witness(
-                        &mut witness,
-                        params,
-                        *left,
-                        *right,
-                        *internal_start,
-                        *internal_count,
-                    )
+            }
+            WitnessOp::IntDivMod { q, r, lhs, rhs } => {
+                let a = witness[lhs.index()];
+                let b = witness[rhs.index()];
+                let a_limbs = a.to_canonical();
+                let b_limbs = b.to_canonical();
+                let (q_val, r_val) = int_divmod_field_pub::<F>(&a_limbs, &b_limbs);
+                witness[q.index()] = q_val;
+                witness[r.index()] = r_val;
+            }
+            WitnessOp::PoseidonHash {
+                left,
+                right,
+                internal_start,
+                internal_count,
+                ..
+            } => {
+                let ctx = poseidon.ok_or_else(|| {
+                    R1CSError::EvalError("poseidon params not initialized".into())
+                })?;
+                ctx.fill(witness, *left, *right, *internal_start, *internal_count)
                     .map_err(|e| R1CSError::EvalError(format!("{e}")))?;
-                }
-                WitnessOp::ArtikCall {
+            }
+            WitnessOp::ArtikCall {
+                outputs,
+                inputs,
+                program_bytes,
+            } => {
+                crate::witness::dispatch_artik_call::<F>(
                     outputs,
                     inputs,
                     program_bytes,
-                } => {
-                    crate::witness::dispatch_artik_call::<F>(
-                        outputs,
-                        inputs,
-                        program_bytes,
-                        &mut witness,
-                        artik_memo.as_mut(),
-                    )
-                    .map_err(|e| R1CSError::EvalError(format!("{e}")))?;
-                }
+                    witness,
+                    artik_memo,
+                )
+                .map_err(|e| R1CSError::EvalError(format!("{e}")))?;
             }
         }
-        self.artik_memo = artik_memo;
+        Ok(())
+    }
 
-        // 3c. Post-fixup: fill substituted-away wires from substitution map.
-        // The op replay above already pre-filled every wire (eliminated
-        // ones included) with its honest value, so this single arbitrary-
-        // order pass is correct even where a value chains through another
-        // eliminated wire: that wire already holds its honest value. This
-        // relies on `witness_ops` staying un-pruned (the map is never used
-        // as the sole source of an eliminated wire's value).
+    /// Post-fixup: fill substituted-away wires from the substitution map.
+    ///
+    /// The op replay already pre-filled every wire (eliminated ones
+    /// included) with its honest value, so this single arbitrary-order
+    /// pass is correct even where a value chains through another
+    /// eliminated wire: that wire already holds its honest value. This
+    /// relies on `witness_ops` staying un-pruned (the map is never used
+    /// as the sole source of an eliminated wire's value).
+    fn apply_substitutions(&self, witness: &mut [FieldElement<F>]) -> Result<(), R1CSError> {
         if let Some(subs) = &self.substitution_map {
             for (var_idx, lc) in subs {
                 witness[*var_idx] = lc
-                    .evaluate(&witness)
+                    .evaluate(witness)
                     .map_err(|e| R1CSError::EvalError(e.to_string()))?;
             }
         }
+        Ok(())
+    }
+}
 
-        Ok(witness)
+/// True when every wire `op` writes is already marked in `known`.
+fn op_targets_known<F: FieldBackend>(op: &WitnessOp<F>, known: &[bool]) -> bool {
+    let is_known = |var: &Variable| known[var.index()];
+    match op {
+        WitnessOp::AssignLC { target, .. }
+        | WitnessOp::Multiply { target, .. }
+        | WitnessOp::Inverse { target, .. }
+        | WitnessOp::BitExtract { target, .. } => is_known(target),
+        WitnessOp::IsZero {
+            target_inv,
+            target_result,
+            ..
+        } => is_known(target_inv) && is_known(target_result),
+        WitnessOp::IntDivMod { q, r, .. } => is_known(q) && is_known(r),
+        WitnessOp::PoseidonHash {
+            internal_start,
+            internal_count,
+            ..
+        } => known[*internal_start..*internal_start + *internal_count]
+            .iter()
+            .all(|k| *k),
+        WitnessOp::ArtikCall { outputs, .. } => outputs.iter().all(is_known),
     }
 }
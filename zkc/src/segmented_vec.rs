@@ -23,7 +23,7 @@
 //! handful of items.
 //!
 //! API surface is intentionally minimal — `push`, `len`, `iter`,
-//! `iter_mut`, `retain`, `clear`, `Clone` — matching exactly the
+//! `iter_mut`, `retain`, `clear`, `drain`, `Clone` — matching exactly the
 //! operations the `witness_ops` consumers actually use. No indexed
 //! access is exposed; callers that need `IndexMut` (e.g. R1CS
 //! constraint-vector mutation) want a different wrapper with uniform
@@ -100,6 +100,15 @@ impl<T> SegmentedVec<T> {
         self.len = 0;
     }
 
+    /// Move every element out in insertion order, leaving the container
+    /// empty. Each segment's buffer is freed as soon as the iterator
+    /// moves past it, so a consumer that replays and discards elements
+    /// releases memory one segment at a time.
+    pub fn drain(&mut self) -> impl Iterator<Item = T> {
+        self.len = 0;
+        std::mem::take(&mut self.segments).into_iter().flatten()
+    }
+
     /// Iterate elements in insertion order across all segments.
     pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
         self.segments.iter().flat_map(|s| s.iter())
@@ -339,4 +348,18 @@ mod tests {
         let second_seg_cap = v.segments[1].capacity();
         assert_eq!(second_seg_cap, 4);
     }
+
+    #[test]
+    fn drain_yields_insertion_order_and_empties() {
+        let mut v: SegmentedVec<u32> = SegmentedVec::with_segment_max(3);
+        for i in 0..8 {
+            v.push(i);
+        }
+        let drained: Vec<u32> = v.drain().collect();
+        assert_eq!(drained, (0..8).collect::<Vec<_>>());
+        assert!(v.is_empty());
+        assert_eq!(v.segment_count(), 0);
+        v.push(42);
+        assert_eq!(v.iter().copied().collect::<Vec<_>>(), vec![42]);
+    }
 }
@@ -8,6 +8,7 @@ mod u256;
 pub(crate) use artik::dispatch_artik_call;
 pub use error::WitnessError;
 pub use generator::WitnessGenerator;
+pub(crate) use op::first_out_of_order_read;
 pub use op::WitnessOp;
-pub(crate) use poseidon::fill_poseidon_witness;
+pub use poseidon::PoseidonWitnessCtx;
 pub use u256::int_divmod_field_pub;
@@ -18,6 +18,9 @@ pub enum WitnessError {
         primary_output: usize,
         reason: String,
     },
+    /// Op `op_index` of the trace reads `wire` before any earlier op
+    /// writes it, so replay would compute it from a zero.
+    OpOrder { op_index: usize, wire: usize },
 }
 
 impl fmt::Display for WitnessError {
@@ -39,6 +42,10 @@ impl fmt::Display for WitnessError {
                 f,
                 "Artik witness call failed at wire {primary_output}: {reason}"
             ),
+            WitnessError::OpOrder { op_index, wire } => write!(
+                f,
+                "witness op #{op_index} reads wire {wire} before any earlier op writes it"
+            ),
         }
     }
 }
@@ -7,7 +7,8 @@ use constraints::PoseidonParamsProvider;
 use memory::{Bn254Fr, FieldBackend, FieldElement};
 
 use super::{
-    dispatch_artik_call, fill_poseidon_witness, int_divmod_field_pub, WitnessError, WitnessOp,
+    dispatch_artik_call, first_out_of_order_read, int_divmod_field_pub, PoseidonWitnessCtx,
+    WitnessError, WitnessOp,
 };
 
 // ============================================================================
@@ -33,6 +34,7 @@ pub struct WitnessGenerator<F: FieldBackend = Bn254Fr> {
     num_variables: usize,
     public_inputs: Vec<(String, Variable)>,
     witnesses: Vec<(String, Variable)>,
+    input_defaults: HashMap<String, FieldElement<F>>,
     poseidon_params: Option<PoseidonParams<F>>,
     /// Substitution map from R1CS optimization (if optimize_r1cs was called).
     substitution_map: Option<SubstitutionMap<F>>,
@@ -61,6 +63,7 @@ impl<F: FieldBackend> WitnessGenerator<F> {
             num_variables: compiler.cs.num_variables(),
             public_inputs,
             witnesses,
+            input_defaults: compiler.input_defaults.clone(),
             poseidon_params: compiler.poseidon_params.clone(),
             substitution_map: compiler.substitution_map.clone(),
         }
@@ -106,44 +109,165 @@ impl<F: FieldBackend> WitnessGenerator<F> {
     where
         F: PoseidonParamsProvider,
     {
+        let mut witness = self.seed_witness(inputs)?;
+        self.check_op_order()?;
+
+        // Replay ops to compute all intermediate wires
+        let mut poseidon = self.poseidon_params.as_ref().map(PoseidonWitnessCtx::new);
+        for op in &self.ops {
+            self.execute_op(op, &mut witness, memo.as_deref_mut(), poseidon.as_mut())?;
+        }
+
+        self.apply_substitutions(&mut witness)?;
+        Ok(witness)
+    }
+
+    /// Like [`Self::generate`], but computes runs of independent Poseidon
+    /// hashes on the rayon thread pool.
+    ///
+    /// Ops are still replayed in trace order; consecutive `PoseidonHash`
+    /// ops are collected into a batch as long as none reads a wire written
+    /// by an earlier op of the same batch, and any other op flushes the
+    /// batch first. Every hash in a batch therefore sees exactly the
+    /// operand values the serial replay would give it, and results are
+    /// written back in trace order, so the witness is identical to
+    /// [`Self::generate`]'s. Pays off for Merkle trees and other wide hash
+    /// layers; a pure hash chain degenerates to the serial path.
+    #[cfg(feature = "rayon")]
+    pub fn generate_parallel(
+        &self,
+        inputs: &HashMap<String, FieldElement<F>>,
+    ) -> Result<Vec<FieldElement<F>>, WitnessError>
+    where
+        F: PoseidonParamsProvider,
+    {
+        let mut witness = self.seed_witness(inputs)?;
+        self.check_op_order()?;
+
+        let mut poseidon = self.poseidon_params.as_ref().map(PoseidonWitnessCtx::new);
+        let mut batch = PoseidonBatch::default();
+        for op in &self.ops {
+            if let WitnessOp::PoseidonHash {
+                left,
+                right,
+                internal_start,
+                internal_count,
+                ..
+            } = op
+            {
+                let job = PoseidonJob {
+                    left: *left,
+                    right: *right,
+                    start: *internal_start,
+                    count: *internal_count,
+                };
+                if batch.reads_output_of(&job) {
+                    self.flush_poseidon_batch(&mut batch, &mut witness, poseidon.as_mut())?;
+                }
+                batch.push(job);
+                continue;
+            }
+            self.flush_poseidon_batch(&mut batch, &mut witness, poseidon.as_mut())?;
+            self.execute_op(op, &mut witness, None, poseidon.as_mut())?;
+        }
+        self.flush_poseidon_batch(&mut batch, &mut witness, poseidon.as_mut())?;
+
+        self.apply_substitutions(&mut witness)?;
+        Ok(witness)
+    }
+
+    /// Compute every hash of `batch` concurrently, write the internal
+    /// wires back in trace order and leave `batch` empty. A single hash
+    /// is filled in place with the caller's context.
+    #[cfg(feature = "rayon")]
+    fn flush_poseidon_batch(
+        &self,
+        batch: &mut PoseidonBatch,
+        witness: &mut [FieldElement<F>],
+        poseidon: Option<&mut PoseidonWitnessCtx<'_, F>>,
+    ) -> Result<(), WitnessError> {
+        use rayon::prelude::*;
+
+        if batch.jobs.is_empty() {
+            return Ok(());
+        }
+        let ctx = poseidon.ok_or_else(|| {
+            WitnessError::MissingInput("poseidon parameters not initialized".into())
+        })?;
+        if let [job] = batch.jobs[..] {
+            ctx.fill(witness, job.left, job.right, job.start, job.count)?;
+        } else {
+            let params = ctx.params();
+            let read: &[FieldElement<F>] = witness;
+            let filled: Vec<Vec<FieldElement<F>>> = batch
+                .jobs
+                .par_iter()
+                .map_init(
+                    || PoseidonWitnessCtx::new(params),
+                    |ctx, job| {
+                        let mut out = vec![FieldElement::<F>::zero(); job.count];
+                        ctx.fill_into(&mut out, read[job.left.index()], read[job.right.index()]);
+                        out
+                    },
+                )
+                .collect();
+            for (job, out) in batch.jobs.iter().zip(filled) {
+                witness[job.start..job.start + job.count].copy_from_slice(&out);
+            }
+        }
+        batch.clear();
+        Ok(())
+    }
+
+    /// A zeroed witness with the constant-one wire, public inputs and
+    /// declared witnesses filled.
+    fn seed_witness(
+        &self,
+        inputs: &HashMap<String, FieldElement<F>>,
+    ) -> Result<Vec<FieldElement<F>>, WitnessError> {
         let mut witness = vec![FieldElement::<F>::zero(); self.num_variables];
         witness[0] = FieldElement::<F>::one();
 
-        // Fill public inputs
-        for (name, var) in &self.public_inputs {
+        // Fill public inputs, then declared witnesses; omitted ones take
+        // their declared default.
+        for (name, var) in self.public_inputs.iter().chain(&self.witnesses) {
             let val = inputs
                 .get(name)
+                .or_else(|| self.input_defaults.get(name))
                 .ok_or_else(|| WitnessError::MissingInput(name.clone()))?;
             witness[var.index()] = *val;
         }
+        Ok(witness)
+    }
 
-        // Fill declared witnesses
-        for (name, var) in &self.witnesses {
-            let val = inputs
-                .get(name)
-                .ok_or_else(|| WitnessError::MissingInput(name.clone()))?;
-            witness[var.index()] = *val;
+    /// Verify that every op only reads the constant wire, declared inputs
+    /// or wires written by an earlier op, as `R1CSCompiler`'s replay does.
+    fn check_op_order(&self) -> Result<(), WitnessError> {
+        let mut filled = vec![false; self.num_variables];
+        filled[0] = true;
+        for (_, var) in self.public_inputs.iter().chain(&self.witnesses) {
+            filled[var.index()] = true;
         }
-
-        // Replay ops to compute all intermediate wires
-        for op in &self.ops {
-            self.execute_op(op, &mut witness, memo.as_deref_mut())?;
+        match first_out_of_order_read(&self.ops, &mut filled, |_| false) {
+            Some((op_index, wire)) => Err(WitnessError::OpOrder { op_index, wire }),
+            None => Ok(()),
         }
+    }
 
-        // Post-fixup: fill substituted-away wires from substitution map.
-        // The op replay above already pre-filled every wire with its honest
-        // value, so this single arbitrary-order pass is correct even where a
-        // value chains through another eliminated wire (the substitution map
-        // is acyclic but not necessarily fully flattened).
+    /// Post-fixup: fill substituted-away wires from substitution map.
+    /// The op replay already pre-filled every wire with its honest value,
+    /// so this single arbitrary-order pass is correct even where a value
+    /// chains through another eliminated wire (the substitution map is
+    /// acyclic but not necessarily fully flattened).
+    fn apply_substitutions(&self, witness: &mut [FieldElement<F>]) -> Result<(), WitnessError> {
         if let Some(subs) = &self.substitution_map {
             for (var_idx, lc) in subs {
                 witness[*var_idx] = lc
-                    .evaluate(&witness)
+                    .evaluate(witness)
                     .map_err(|e| WitnessError::MissingInput(e.to_string()))?;
             }
         }
-
-        Ok(witness)
+        Ok(())
     }
 
     /// Execute a single `WitnessOp`, filling in the target wire(s).
@@ -152,6 +276,7 @@ impl<F: FieldBackend> WitnessGenerator<F> {
         op: &WitnessOp<F>,
         witness: &mut [FieldElement<F>],
         memo: Option<&mut artik::ArtikMemo<F>>,
+        poseidon: Option<&mut PoseidonWitnessCtx<'_, F>>,
     ) -> Result<(), WitnessError>
     where
         F: PoseidonParamsProvider,
@@ -237,7 +362,10 @@ impl<F: FieldBackend> WitnessGenerator<F> {
                 internal_start,
                 internal_count,
             } => {
-                self.fill_poseidon(witness, *left, *right, *internal_start, *internal_count)?;
+                let ctx = poseidon.ok_or_else(|| {
+                    WitnessError::MissingInput("poseidon parameters not initialized".into())
+                })?;
+                ctx.fill(witness, *left, *right, *internal_start, *internal_count)?;
             }
             WitnessOp::ArtikCall {
                 outputs,
@@ -249,22 +377,46 @@ impl<F: FieldBackend> WitnessGenerator<F> {
         }
         Ok(())
     }
+}
 
-    /// Fill the ~361 internal Poseidon wires by replaying the permutation natively.
-    fn fill_poseidon(
-        &self,
-        witness: &mut [FieldElement<F>],
-        left: Variable,
-        right: Variable,
-        internal_start: usize,
-        internal_count: usize,
-    ) -> Result<(), WitnessError>
-    where
-        F: PoseidonParamsProvider,
-    {
-        let params = self.poseidon_params.as_ref().ok_or_else(|| {
-            WitnessError::MissingInput("poseidon parameters not initialized".into())
-        })?;
-        fill_poseidon_witness(witness, params, left, right, internal_start, internal_count)
+/// One `PoseidonHash` op waiting in a [`PoseidonBatch`].
+#[cfg(feature = "rayon")]
+#[derive(Clone, Copy)]
+struct PoseidonJob {
+    left: Variable,
+    right: Variable,
+    start: usize,
+    count: usize,
+}
+
+/// Consecutive hashes that can run concurrently, plus the span of wires
+/// they write. The span is a conservative dependency check: an operand
+/// inside it forces a flush even if it lies between two jobs' outputs.
+#[cfg(feature = "rayon")]
+#[derive(Default)]
+struct PoseidonBatch {
+    jobs: Vec<PoseidonJob>,
+    written: std::ops::Range<usize>,
+}
+
+#[cfg(feature = "rayon")]
+impl PoseidonBatch {
+    fn reads_output_of(&self, job: &PoseidonJob) -> bool {
+        self.written.contains(&job.left.index()) || self.written.contains(&job.right.index())
+    }
+
+    fn push(&mut self, job: PoseidonJob) {
+        let end = job.start + job.count;
+        self.written = if self.jobs.is_empty() {
+            job.start..end
+        } else {
+            self.written.start.min(job.start)..self.written.end.max(end)
+        };
+        self.jobs.push(job);
+    }
+
+    fn clear(&mut self) {
+        self.jobs.clear();
+        self.written = 0..0;
     }
 }
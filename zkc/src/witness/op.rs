@@ -78,3 +78,92 @@ pub enum WitnessOp<F: FieldBackend = Bn254Fr> {
         program_bytes: Arc<[u8]>,
     },
 }
+
+impl<F: FieldBackend> WitnessOp<F> {
+    /// Call `f` with every wire this op reads from the witness.
+    pub fn for_each_read(&self, mut f: impl FnMut(Variable)) {
+        let mut lc_reads = |lc: &LinearCombination<F>| {
+            for (var, _) in lc.terms() {
+                f(*var);
+            }
+        };
+        match self {
+            WitnessOp::AssignLC { lc, .. } => lc_reads(lc),
+            WitnessOp::Multiply { a, b, .. } => {
+                lc_reads(a);
+                lc_reads(b);
+            }
+            WitnessOp::Inverse { operand: lc, .. }
+            | WitnessOp::BitExtract { source: lc, .. }
+            | WitnessOp::IsZero { diff: lc, .. } => lc_reads(lc),
+            WitnessOp::IntDivMod { lhs, rhs, .. } => {
+                f(*lhs);
+                f(*rhs);
+            }
+            WitnessOp::PoseidonHash { left, right, .. } => {
+                f(*left);
+                f(*right);
+            }
+            WitnessOp::ArtikCall { inputs, .. } => inputs.iter().copied().for_each(f),
+        }
+    }
+
+    /// Call `f` with every wire this op writes into the witness.
+    pub fn for_each_write(&self, mut f: impl FnMut(Variable)) {
+        match self {
+            WitnessOp::AssignLC { target, .. }
+            | WitnessOp::Multiply { target, .. }
+            | WitnessOp::Inverse { target, .. }
+            | WitnessOp::BitExtract { target, .. } => f(*target),
+            WitnessOp::IsZero {
+                target_inv,
+                target_result,
+                ..
+            } => {
+                f(*target_inv);
+                f(*target_result);
+            }
+            WitnessOp::IntDivMod { q, r, .. } => {
+                f(*q);
+                f(*r);
+            }
+            WitnessOp::PoseidonHash {
+                output,
+                internal_start,
+                internal_count,
+                ..
+            } => {
+                (*internal_start..*internal_start + *internal_count).for_each(|i| f(Variable(i)));
+                f(*output);
+            }
+            WitnessOp::ArtikCall { outputs, .. } => outputs.iter().copied().for_each(f),
+        }
+    }
+}
+
+/// Find the first op in `ops` that reads a wire which is neither set in
+/// `filled` nor written by an earlier op, returning its index and that
+/// wire. `filled` starts as the wires known before replay and gains each
+/// checked op's writes. Ops for which `skip` holds are passed over.
+pub(crate) fn first_out_of_order_read<'a, F: FieldBackend + 'a>(
+    ops: impl IntoIterator<Item = &'a WitnessOp<F>>,
+    filled: &mut [bool],
+    skip: impl Fn(&WitnessOp<F>) -> bool,
+) -> Option<(usize, usize)> {
+    for (op_index, op) in ops.into_iter().enumerate() {
+        if skip(op) {
+            continue;
+        }
+        let mut unfilled = None;
+        op.for_each_read(|var| {
+            if unfilled.is_none() && !filled[var.index()] {
+                unfilled = Some(var.index());
+            }
+        });
+        if let Some(wire) = unfilled {
+            return Some((op_index, wire));
+        }
+        op.for_each_write(|var| filled[var.index()] = true);
+    }
+    None
+}
@@ -4,105 +4,130 @@ use memory::{FieldBackend, FieldElement};
 
 use super::WitnessError;
 
-/// Fill the ~361 internal Poseidon wires by replaying the permutation natively.
+/// Reusable state for filling many Poseidon hashes against one parameter set.
 ///
-/// This must replicate *exactly* the variable allocation order of
-/// `poseidon_hash_circuit` → `poseidon_permutation_circuit` in
-/// `constraints/src/poseidon.rs`.
-#[allow(clippy::needless_range_loop)]
-pub(crate) fn fill_poseidon_witness<F: FieldBackend>(
-    witness: &mut [FieldElement<F>],
-    params: &PoseidonParams<F>,
-    left: Variable,
-    right: Variable,
-    internal_start: usize,
-    internal_count: usize,
-) -> Result<(), WitnessError> {
-    let total_rounds = params.r_f + params.r_p;
-    let half_f = params.r_f / 2;
-
-    let mut var_idx = internal_start;
-
-    // First wire: capacity = 0
-    witness[var_idx] = FieldElement::<F>::zero();
-    var_idx += 1;
-
-    // Initial state: [capacity=0, left, right]
-    let mut state = [
-        FieldElement::<F>::zero(),
-        witness[left.index()],
-        witness[right.index()],
-    ];
-
-    for r in 0..total_rounds {
-        // 1. Add round constants
-        for i in 0..params.t {
-            state[i] = state[i].add(&params.round_constants[r * params.t + i]);
+/// Holds the parameters, the partial-round window and two width-`t` scratch
+/// buffers, so a replay over thousands of `PoseidonHash` ops resolves the
+/// parameters once and allocates nothing per hash. Create one per replay
+/// and call [`fill`](Self::fill) for each op.
+pub struct PoseidonWitnessCtx<'a, F: FieldBackend> {
+    params: &'a PoseidonParams<F>,
+    /// Rounds `partial.start..partial.end` apply the S-box to `state[0]` only.
+    partial: std::ops::Range<usize>,
+    state: Vec<FieldElement<F>>,
+    old: Vec<FieldElement<F>>,
+}
+
+impl<'a, F: FieldBackend> PoseidonWitnessCtx<'a, F> {
+    pub fn new(params: &'a PoseidonParams<F>) -> Self {
+        let half_f = params.r_f / 2;
+        Self {
+            params,
+            partial: half_f..half_f + params.r_p,
+            state: vec![FieldElement::<F>::zero(); params.t],
+            old: vec![FieldElement::<F>::zero(); params.t],
         }
+    }
+
+    /// The parameter set this context fills against.
+    pub fn params(&self) -> &'a PoseidonParams<F> {
+        self.params
+    }
+
+    /// Fill the ~361 internal Poseidon wires by replaying the permutation natively.
+    ///
+    /// This must replicate *exactly* the variable allocation order of
+    /// `poseidon_hash_circuit` → `poseidon_permutation_circuit` in
+    /// `constraints/src/poseidon/circuit.rs`.
+    pub fn fill(
+        &mut self,
+        witness: &mut [FieldElement<F>],
+        left: Variable,
+        right: Variable,
+        internal_start: usize,
+        internal_count: usize,
+    ) -> Result<(), WitnessError> {
+        let (left, right) = (witness[left.index()], witness[right.index()]);
+        self.fill_into(
+            &mut witness[internal_start..internal_start + internal_count],
+            left,
+            right,
+        );
+        Ok(())
+    }
+
+    /// [`fill`](Self::fill) with the operand values already read and the
+    /// internal wires addressed from 0, so a hash can be computed into a
+    /// buffer that does not alias the witness.
+    pub(crate) fn fill_into(
+        &mut self,
+        out: &mut [FieldElement<F>],
+        left: FieldElement<F>,
+        right: FieldElement<F>,
+    ) {
+        let params = self.params;
+        let t = params.t;
+        let state = &mut self.state[..];
+        let old = &mut self.old[..];
+
+        let mut var_idx = 0;
+
+        // First wire: capacity = domain tag (0 by default)
+        out[var_idx] = params.domain_tag;
+        var_idx += 1;
 
-        // 2. S-box layer
-        if r < half_f || r >= half_f + params.r_p {
-            // Full round: S-box on all 3 elements
-            for i in 0..params.t {
-                let x = state[i];
+        // Initial state: [capacity, left, right]
+        state[0] = params.domain_tag;
+        state[1] = left;
+        state[2] = right;
+
+        for r in 0..params.r_f + params.r_p {
+            let is_partial = self.partial.contains(&r);
+
+            // 1. Add round constants
+            for (s, rc) in state.iter_mut().zip(&params.round_constants[r * t..]) {
+                *s = s.add(rc);
+            }
+
+            // 2. S-box layer: all elements in full rounds, state[0] in partial
+            let sboxed = if is_partial { 1 } else { t };
+            for s in &mut state[..sboxed] {
+                let x = *s;
                 let x2 = x.mul(&x);
-                witness[var_idx] = x2;
-                var_idx += 1;
                 let x4 = x2.mul(&x2);
-                witness[var_idx] = x4;
-                var_idx += 1;
                 let x5 = x4.mul(&x);
-                witness[var_idx] = x5;
-                var_idx += 1;
-                state[i] = x5;
+                out[var_idx..var_idx + 3].copy_from_slice(&[x2, x4, x5]);
+                var_idx += 3;
+                *s = x5;
             }
-        } else {
-            // Partial round: S-box on state[0] only
-            let x = state[0];
-            let x2 = x.mul(&x);
-            witness[var_idx] = x2;
-            var_idx += 1;
-            let x4 = x2.mul(&x2);
-            witness[var_idx] = x4;
-            var_idx += 1;
-            let x5 = x4.mul(&x);
-            witness[var_idx] = x5;
-            var_idx += 1;
-            state[0] = x5;
-        }
 
-        // 3. MDS matrix multiplication
-        let old = state;
-        for i in 0..params.t {
-            state[i] = FieldElement::<F>::zero();
-            for j in 0..params.t {
-                state[i] = state[i].add(&params.mds[i][j].mul(&old[j]));
+            // 3. MDS matrix multiplication
+            old.copy_from_slice(state);
+            for (s, row) in state.iter_mut().zip(&params.mds) {
+                *s = row
+                    .iter()
+                    .zip(old.iter())
+                    .fold(FieldElement::<F>::zero(), |acc, (m, o)| acc.add(&m.mul(o)));
             }
-        }
 
-        // 4. Materialize state[1..] in partial rounds
-        if r >= half_f && r < half_f + params.r_p {
-            for i in 1..params.t {
-                witness[var_idx] = state[i];
-                var_idx += 1;
+            // 4. Materialize state[1..] in partial rounds
+            if is_partial {
+                out[var_idx..var_idx + t - 1].copy_from_slice(&state[1..]);
+                var_idx += t - 1;
             }
         }
-    }
 
-    // Output state materialization (3 variables)
-    for i in 0..params.t {
-        witness[var_idx] = state[i];
-        var_idx += 1;
-    }
+        // Output state materialization (t variables)
+        out[var_idx..var_idx + t].copy_from_slice(state);
+        var_idx += t;
 
-    // Sanity check: we filled exactly the expected number of wires
-    debug_assert_eq!(
-        var_idx - internal_start,
-        internal_count,
-        "Poseidon fill mismatch: filled {} wires but expected {}",
-        var_idx - internal_start,
-        internal_count
-    );
-
-    Ok(())
+        // Sanity check: we filled exactly the expected number of wires
+        debug_assert_eq!(
+            var_idx,
+            out.len(),
+            "Poseidon fill mismatch: filled {} wires but expected {}",
+            var_idx,
+            out.len()
+        );
+    }
 }
@@ -349,5 +349,54 @@ fn bool_prop_assert_marks_operand_boolean() {
     );
 }
 
+#[test]
+fn bool_prop_assert_bool_marks_operand_boolean() {
+    let program =
+        ir::IrLowering::<Bn254Fr>::lower_circuit("assert_bool(flag)", &[], &["flag"]).unwrap();
+    let flag = program
+        .iter()
+        .find_map(|inst| match inst {
+            Instruction::Input { result, name, .. } if name == "flag" => Some(*result),
+            _ => None,
+        })
+        .unwrap();
+
+    let set = compute_proven_boolean(&program);
+    assert!(
+        set.contains(&flag),
+        "assert_bool operand should be proven boolean"
+    );
+}
+
+#[test]
+fn assert_bool_then_mux_enforces_flag_once() {
+    // assert_bool(flag) emits flag * (flag - 1) == 0; with bool_prop the
+    // mux reuses it instead of adding its own flag * (1 - flag) == 0.
+    let source = "assert_bool(flag)\nassert_eq(mux(flag, a, b), out)";
+    let (without, with) = constraint_counts_with_without(
+        &["out"],
+        &["flag", "a", "b"],
+        source,
+        &[("out", 5), ("flag", 1), ("a", 5), ("b", 9)],
+    );
+    assert_eq!(without - with, 1, "mux should skip its enforcement");
+
+    let program =
+        ir::IrLowering::<Bn254Fr>::lower_circuit(source, &["out"], &["flag", "a", "b"]).unwrap();
+    let mut compiler = R1CSCompiler::<Bn254Fr>::new();
+    compiler.set_proven_boolean(compute_proven_boolean(&program));
+    compiler.compile_ir(&program).unwrap();
+    // The one remaining enforcement still rejects a non-boolean flag:
+    // flag = 2 would select 2 * (a - b) + b = 1 without it.
+    let input_map: HashMap<String, FieldElement> = [("out", 1), ("flag", 2), ("a", 5), ("b", 9)]
+        .into_iter()
+        .map(|(n, v)| (n.to_string(), FieldElement::from_u64(v)))
+        .collect();
+    let witness = WitnessGenerator::from_compiler(&compiler)
+        .generate(&input_map)
+        .unwrap();
+    assert!(compiler.cs.verify(&witness).is_err());
+}
+
 #[path = "bool_prop_test/soundness.rs"]
 mod soundness;
@@ -272,6 +272,71 @@ fn circuit_poseidon_zero_inputs() {
     );
 }
 
+#[test]
+fn circuit_poseidon_many_matches_native() {
+    let p = constraints::poseidon::PoseidonParams::bn254_t3();
+    for n in [3u64, 7] {
+        let names: Vec<String> = (1..=n).map(|i| format!("x{i}")).collect();
+        let values: Vec<FieldElement> = (1..=n).map(fe).collect();
+        let expected = constraints::poseidon::poseidon_hash_many(&p, &values);
+
+        let mut source = String::from("public expected\n");
+        for name in &names {
+            source.push_str(&format!("witness {name}\n"));
+        }
+        source.push_str(&format!(
+            "assert_eq(poseidon_many({}), expected)",
+            names.join(", ")
+        ));
+
+        let mut inputs: Vec<(&str, FieldElement)> = names
+            .iter()
+            .map(String::as_str)
+            .zip(values.iter().copied())
+            .collect();
+        inputs.push(("expected", expected));
+        compile_and_verify(&source, &inputs);
+    }
+}
+
+// ============================================================================
+// Field literals
+// ============================================================================
+
+#[test]
+fn circuit_hex_field_literal_constant() {
+    let source = "public out\nwitness a\nassert_eq(a * 0pxDEADBEEF, out)";
+    compile_and_verify(source, &[("a", fe(2)), ("out", fe(2 * 0xDEAD_BEEF))]);
+    compile_expect_fail(source, &[("a", fe(2)), ("out", fe(0xDEAD_BEEF))]);
+}
+
+#[test]
+fn circuit_field_literal_radixes_agree() {
+    compile_and_verify(
+        "public out\nassert_eq(0pxFF + 0p255 + 0pb11111111, out)",
+        &[("out", fe(3 * 255))],
+    );
+}
+
+#[test]
+fn circuit_field_literal_p_minus_1() {
+    let p_minus_1 =
+        fe_str("21888242871839275222246405745257275088548364400416034343698204186575808495616");
+    compile_and_verify(
+        "public out\nassert_eq(0px30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000, out)",
+        &[("out", p_minus_1)],
+    );
+}
+
+#[test]
+fn circuit_field_literal_at_modulus_rejected() {
+    let err = IrLowering::<memory::Bn254Fr>::lower_self_contained(
+        "public out\nassert_eq(0px30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001, out)",
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("field modulus"), "{err}");
+}
+
 // ============================================================================
 // Constraint count regression
 // ============================================================================
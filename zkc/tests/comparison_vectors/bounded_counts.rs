@@ -46,6 +46,36 @@ fn range_bounded_isle_8bit() {
     );
 }
 
+/// `x > y` is its own `IsGt` instruction: bounds are read from `x` and
+/// `y` as written, and the 8-bit decomposition matches `y < x` exactly.
+#[test]
+fn range_bounded_isgt_8bit_matches_swapped_islt() {
+    let gt = compile_and_verify(
+        "witness x\nwitness y\nrange_check(x, 8)\nrange_check(y, 8)\npublic out\nassert_eq(x > y, out)",
+        &[("x", fe(200)), ("y", fe(100)), ("out", fe(1))],
+    );
+    let lt = compile_and_verify(
+        "witness x\nwitness y\nrange_check(x, 8)\nrange_check(y, 8)\npublic out\nassert_eq(y < x, out)",
+        &[("x", fe(200)), ("y", fe(100)), ("out", fe(1))],
+    );
+    assert_eq!(gt, lt);
+    assert!(gt < 100, "range-bounded IsGt should be << 760, got: {gt}");
+}
+
+#[test]
+fn range_bounded_isge_8bit_matches_swapped_isle() {
+    let ge = compile_and_verify(
+        "witness x\nwitness y\nrange_check(x, 8)\nrange_check(y, 8)\npublic out\nassert_eq(x >= y, out)",
+        &[("x", fe(100)), ("y", fe(100)), ("out", fe(1))],
+    );
+    let le = compile_and_verify(
+        "witness x\nwitness y\nrange_check(x, 8)\nrange_check(y, 8)\npublic out\nassert_eq(y <= x, out)",
+        &[("x", fe(100)), ("y", fe(100)), ("out", fe(1))],
+    );
+    assert_eq!(ge, le);
+    assert!(ge < 100, "range-bounded IsGe should be << 760, got: {ge}");
+}
+
 // ============================================================================
 // Constraint count benchmarks — the core Phase II metric
 // Source: Table 1 from research document.
@@ -0,0 +1,74 @@
+//! `ACH_TRACE_CONSTRAINTS=1` makes `compile_ir` log one line per IR
+//! instruction to stderr. The env var is read when the compiler is
+//! built, so the test re-runs itself in a child process with the var set
+//! and parses the child's stderr.
+
+use std::process::Command;
+
+use ir::IrLowering;
+use memory::Bn254Fr;
+use zkc::r1cs_backend::R1CSCompiler;
+
+const TRACE_VAR: &str = "ACH_TRACE_CONSTRAINTS";
+
+#[test]
+fn trace_logs_constraints_per_instruction() {
+    let program = IrLowering::<Bn254Fr>::lower_circuit(
+        "range_check(a, 8)\nassert_eq(poseidon(a, b), out)",
+        &["out"],
+        &["a", "b"],
+    )
+    .unwrap();
+
+    if std::env::var_os(TRACE_VAR).is_some() {
+        // Child: the parent reads the trace off our stderr.
+        R1CSCompiler::<Bn254Fr>::new().compile_ir(&program).unwrap();
+        return;
+    }
+
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["trace_logs_constraints_per_instruction", "--exact"])
+        .env(TRACE_VAR, "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "child test failed: {output:?}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    let entries: Vec<(String, i64)> = stderr
+        .lines()
+        .filter_map(|line| line.strip_prefix("ach-trace-constraints "))
+        .map(|rest| {
+            let field = |key: &str| {
+                rest.split(' ')
+                    .find_map(|kv| kv.strip_prefix(key)?.strip_prefix('='))
+                    .unwrap_or_else(|| panic!("no `{key}` in `{rest}`"))
+                    .to_string()
+            };
+            (field("op"), field("constraints").parse().unwrap())
+        })
+        .collect();
+    assert_eq!(entries.len(), program.len(), "one line per instruction");
+
+    let added = |op: &str| {
+        entries
+            .iter()
+            .find(|(name, _)| name == op)
+            .unwrap_or_else(|| panic!("no `{op}` entry in trace:\n{stderr}"))
+            .1
+    };
+    assert!(
+        added("PoseidonHash") > 300,
+        "Poseidon adds ~361 constraints"
+    );
+    assert!(
+        added("RangeCheck") >= 8,
+        "an 8-bit range check adds bit checks"
+    );
+
+    // The per-instruction deltas account for every constraint of an
+    // untraced compile.
+    let mut compiler = R1CSCompiler::<Bn254Fr>::new();
+    compiler.compile_ir(&program).unwrap();
+    let total: i64 = entries.iter().map(|(_, n)| n).sum();
+    assert_eq!(total, compiler.cs.num_constraints() as i64);
+}
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use ir::IrLowering;
 use memory::FieldElement;
+use zkc::error::R1CSError;
 use zkc::r1cs_backend::R1CSCompiler;
 use zkc::witness::WitnessGenerator;
 
@@ -28,10 +29,16 @@ fn assert_witness_equivalence(
 
     // New path: compile_ir_with_witness
     let mut c2 = R1CSCompiler::new();
-    c2.set_proven_boolean(proven);
+    c2.set_proven_boolean(proven.clone());
     let w2 = c2.compile_ir_with_witness(&program, inputs).unwrap();
     c2.cs.verify(&w2).unwrap();
 
+    // Eval-seeded path: same witness without replaying plain arithmetic.
+    let mut c4 = R1CSCompiler::new();
+    c4.set_proven_boolean(proven);
+    let w4 = c4.compile_ir_with_eval_witness(&program, inputs).unwrap();
+    assert_eq!(w2, w4, "eval-seeded witness diverges from op replay");
+
     assert_eq!(
         w1.len(),
         w2.len(),
@@ -40,6 +47,11 @@ fn assert_witness_equivalence(
         w2.len()
     );
     assert_eq!(w1, w2, "witness mismatch between old and new paths");
+
+    // Every cached SSA value agrees with the IR evaluator.
+    let mut c3 = R1CSCompiler::new();
+    let w3 = c3.check_witness_against_eval(&program, inputs).unwrap();
+    assert_eq!(w2, w3);
 }
 
 fn fe(n: u64) -> FieldElement {
@@ -129,6 +141,21 @@ fn equiv_poseidon_chained() {
     );
 }
 
+#[test]
+fn equiv_mixed_gadgets() {
+    // Arithmetic, division, IsZero, a bounded comparison (bit
+    // decomposition), a mux and a Poseidon hash in one circuit.
+    let params = constraints::poseidon::PoseidonParams::bn254_t3();
+    let h = constraints::poseidon::poseidon_hash(&params, fe(17), fe(5));
+    let inputs = make_inputs_fe(&[("a", fe(12)), ("b", fe(5)), ("h", h), ("out", fe(17))]);
+    assert_witness_equivalence(
+        &["h", "out"],
+        &["a", "b"],
+        "range_check(a, 8)\nrange_check(b, 8)\nlet s = a * b / (a - b) + a - b\nlet big = a > b\nlet same = a == b\nlet sel = if big { a + b } else { s }\nassert_eq(same, 0)\nassert_eq(out, sel)\nassert_eq(h, poseidon(sel, b))",
+        &inputs,
+    );
+}
+
 #[test]
 fn equiv_boolean_ops() {
     let inputs = make_inputs(&[("a", 1), ("b", 0), ("out", 1)]);
@@ -230,3 +257,66 @@ fn eval_catches_missing_input_early() {
     let msg = format!("{}", err.unwrap_err());
     assert!(msg.contains("missing input"), "got: {msg}");
 }
+
+// ============================================================================
+// IR evaluation vs. R1CS witness cross-check
+// ============================================================================
+
+#[test]
+fn cross_check_detects_tampered_wire() {
+    let program = IrLowering::lower_circuit(
+        "let p = x * y\nassert_eq(out, p + 1)",
+        &["out"],
+        &["x", "y"],
+    )
+    .unwrap();
+    let inputs = make_inputs(&[("x", 6), ("y", 7), ("out", 43)]);
+
+    let mut rc = R1CSCompiler::new();
+    let mut witness = rc.check_witness_against_eval(&program, &inputs).unwrap();
+
+    // Corrupt the product wire: the last wire the compiler allocated.
+    let product = witness.len() - 1;
+    witness[product] = fe(41);
+
+    match rc.compare_witness_with_eval(&program, &inputs, &witness) {
+        Err(R1CSError::WitnessDivergence {
+            expected, actual, ..
+        }) => {
+            assert_eq!(expected, "42");
+            assert_eq!(actual, "41");
+        }
+        other => panic!("expected WitnessDivergence, got {other:?}"),
+    }
+}
+
+#[test]
+fn cross_check_reports_first_divergence() {
+    let program = IrLowering::lower_circuit(
+        "let a = x * x\nlet b = a * x\nassert_eq(out, b)",
+        &["out"],
+        &["x"],
+    )
+    .unwrap();
+    let inputs = make_inputs(&[("x", 3), ("out", 27)]);
+
+    let mut rc = R1CSCompiler::new();
+    let mut witness = rc.check_witness_against_eval(&program, &inputs).unwrap();
+
+    // Corrupt the input wire `x`: every value derived from it diverges too,
+    // but the report must name the `x` input instruction itself.
+    let x_wire = rc.lookup("x").unwrap().index();
+    witness[x_wire] = fe(4);
+
+    let err = rc
+        .compare_witness_with_eval(&program, &inputs, &witness)
+        .unwrap_err();
+    let R1CSError::WitnessDivergence { var, ir_index, .. } = err else {
+        panic!("expected WitnessDivergence, got {err:?}");
+    };
+    assert!(matches!(
+        program.instructions()[ir_index],
+        ir::Instruction::Input { ref name, .. } if name == "x"
+    ));
+    assert_eq!(program.get_name(var), Some("x"));
+}
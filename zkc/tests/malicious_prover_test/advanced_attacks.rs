@@ -85,6 +85,42 @@ fn a6_mux_condition_pminus1() {
     );
 }
 
+#[test]
+fn a6_else_if_chain_forged_inner_condition() {
+    // `if c1 { a } else if c2 { b } else { d }` lowers to
+    // mux(c1, a, mux(c2, b, d)); each level enforces its own condition.
+    let (compiler, mut w) = compile_valid_witness(
+        "let r = if c1 { a } else if c2 { b } else { d }\nassert_eq(r, out)",
+        &["out"],
+        &["c1", "c2", "a", "b", "d"],
+        &[
+            ("out", fe(20)),
+            ("c1", fe(0)),
+            ("c2", fe(1)),
+            ("a", fe(10)),
+            ("b", fe(20)),
+            ("d", fe(30)),
+        ],
+    );
+    let c2 = compiler.lookup("c2").unwrap().index();
+    for forged in [fe(2), p_minus_1()] {
+        let mut w = w.clone();
+        w[c2] = forged;
+        assert!(
+            compiler.cs.verify(&w).is_err(),
+            "A6: non-boolean inner else-if condition must be rejected"
+        );
+    }
+
+    // Claiming both conditions true cannot select the second branch.
+    let c1 = compiler.lookup("c1").unwrap().index();
+    w[c1] = fe(1);
+    assert!(
+        compiler.cs.verify(&w).is_err(),
+        "A6: else-if branch must not be selectable while an earlier condition holds"
+    );
+}
+
 // ============================================================================
 // A7. Division inverse forgery — provide wrong modular inverse
 // ============================================================================
@@ -0,0 +1,87 @@
+//! `WitnessGenerator::generate_parallel` must reproduce the serial replay
+//! exactly. Run with `cargo test -p zkc --features rayon`.
+#![cfg(feature = "rayon")]
+
+use std::collections::HashMap;
+
+use ir::types::IrProgram;
+use ir::IrLowering;
+use memory::FieldElement;
+use zkc::r1cs_backend::R1CSCompiler;
+use zkc::witness::WitnessGenerator;
+
+const LEAVES: usize = 256;
+
+/// `merkle_root` over `LEAVES` witness leaves, checked against a public
+/// root; returns the program and a satisfying input map.
+fn merkle_circuit() -> (IrProgram, HashMap<String, FieldElement>) {
+    let leaves_decl = format!("leaves[{LEAVES}]");
+    let program = IrLowering::lower_circuit(
+        "assert_eq(merkle_root(leaves), root)",
+        &["root"],
+        &[leaves_decl.as_str()],
+    )
+    .unwrap();
+    let mut inputs: HashMap<String, FieldElement> = (0..LEAVES)
+        .map(|i| {
+            (
+                format!("leaves_{i}"),
+                FieldElement::from_u64(i as u64 * 7 + 3),
+            )
+        })
+        .collect();
+    ir::eval::fill_outputs(&program, &["root".to_string()], &mut inputs)
+        .unwrap_or_else(|_| panic!("cannot derive `root`"));
+    (program, inputs)
+}
+
+#[test]
+fn parallel_matches_serial_on_256_leaf_merkle() {
+    let (program, inputs) = merkle_circuit();
+    let mut rc = R1CSCompiler::new();
+    rc.compile_ir(&program).unwrap();
+    let generator = WitnessGenerator::from_compiler(&rc);
+
+    let serial = generator.generate(&inputs).unwrap();
+    let parallel = generator.generate_parallel(&inputs).unwrap();
+    assert_eq!(serial, parallel);
+    rc.cs.verify(&parallel).unwrap();
+
+    // Deterministic across runs, whatever the thread interleaving.
+    assert_eq!(generator.generate_parallel(&inputs).unwrap(), parallel);
+}
+
+#[test]
+fn parallel_matches_serial_after_optimize() {
+    let (program, inputs) = merkle_circuit();
+    let mut rc = R1CSCompiler::new();
+    rc.compile_ir(&program).unwrap();
+    rc.optimize_r1cs();
+    let generator = WitnessGenerator::from_compiler(&rc);
+
+    let parallel = generator.generate_parallel(&inputs).unwrap();
+    assert_eq!(generator.generate(&inputs).unwrap(), parallel);
+    rc.cs.verify(&parallel).unwrap();
+}
+
+#[test]
+fn parallel_respects_hash_chain_dependencies() {
+    // Each hash reads the previous one's output: every batch is flushed
+    // before the next hash, and the result must still match.
+    let src = "let h1 = poseidon(a, b)\nlet h2 = poseidon(h1, b)\n\
+               let h3 = poseidon(h2, h1)\nassert_eq(h3, out)";
+    let program = IrLowering::lower_circuit(src, &["out"], &["a", "b"]).unwrap();
+    let mut inputs: HashMap<String, FieldElement> = [("a", 1), ("b", 2)]
+        .into_iter()
+        .map(|(n, v)| (n.to_string(), FieldElement::from_u64(v)))
+        .collect();
+    ir::eval::fill_outputs(&program, &["out".to_string()], &mut inputs)
+        .unwrap_or_else(|_| panic!("cannot derive `out`"));
+
+    let mut rc = R1CSCompiler::new();
+    rc.compile_ir(&program).unwrap();
+    let generator = WitnessGenerator::from_compiler(&rc);
+    let parallel = generator.generate_parallel(&inputs).unwrap();
+    assert_eq!(generator.generate(&inputs).unwrap(), parallel);
+    rc.cs.verify(&parallel).unwrap();
+}
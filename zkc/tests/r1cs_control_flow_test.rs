@@ -247,6 +247,47 @@ fn test_if_else_if_chain() {
     assert_eq!(rc.cs.num_constraints(), 7);
 }
 
+#[test]
+fn test_if_else_if_chain_selects_each_branch() {
+    let src = "let r = if c1 { a } else if c2 { b } else { c }; assert_eq(r, out)";
+    let wit = |c1, c2| [("c1", c1), ("c2", c2), ("a", 10), ("b", 20), ("c", 30)];
+    ir_compile_and_verify(src, &[("out", 10)], &wit(1, 0));
+    ir_compile_and_verify(src, &[("out", 20)], &wit(0, 1));
+    ir_compile_and_verify(src, &[("out", 30)], &wit(0, 0));
+}
+
+#[test]
+fn test_if_else_if_chain_first_true_condition_wins() {
+    // Conditions need not be mutually exclusive: like the VM, the chain
+    // picks the first branch whose condition holds.
+    let src = "let r = if c1 { a } else if c2 { b } else { c }; assert_eq(r, out)";
+    ir_compile_and_verify(
+        src,
+        &[("out", 10)],
+        &[("c1", 1), ("c2", 1), ("a", 10), ("b", 20), ("c", 30)],
+    );
+
+    let mut prog =
+        IrLowering::<Bn254Fr>::lower_circuit(src, &["out"], &["c1", "c2", "a", "b", "c"]).unwrap();
+    ir::passes::optimize(&mut prog);
+    let inputs: HashMap<String, FieldElement> = [
+        ("out", 20),
+        ("c1", 1),
+        ("c2", 1),
+        ("a", 10),
+        ("b", 20),
+        ("c", 30),
+    ]
+    .iter()
+    .map(|(n, v)| (n.to_string(), FieldElement::from_u64(*v)))
+    .collect();
+    let mut rc = R1CSCompiler::<Bn254Fr>::new();
+    assert!(
+        rc.compile_ir_with_witness(&prog, &inputs).is_err(),
+        "second branch must not be selectable while the first condition holds"
+    );
+}
+
 // ====================================================================
 // Rejection tests
 // ====================================================================
@@ -183,3 +183,115 @@ fn ir_array_index_with_for_counter() {
 assert_eq(total, sum)"#,
     );
 }
+
+#[test]
+fn ir_array_negation_elementwise() {
+    let x = |v: u64| FieldElement::from_u64(v);
+    ir_array_verify(
+        &[("n_0", x(3).neg()), ("n_1", x(5).neg()), ("n_2", x(0))],
+        &[("arr_0", x(3)), ("arr_1", x(5)), ("arr_2", x(0))],
+        &["n[3]"],
+        &["arr[3]"],
+        r#"let neg = -arr
+assert_eq(neg[0], n[0])
+assert_eq(neg[1], n[1])
+assert_eq(neg[2], n[2])
+assert_eq(len(neg), 3)"#,
+    );
+}
+
+#[test]
+fn ir_array_scalar_multiply() {
+    let x = |v: u64| FieldElement::from_u64(v);
+    ir_array_verify(
+        &[("d_0", x(2)), ("d_1", x(14)), ("d_2", x(40))],
+        &[("arr_0", x(1)), ("arr_1", x(7)), ("arr_2", x(20))],
+        &["d[3]"],
+        &["arr[3]"],
+        r#"let doubled = arr * 2
+let also = 2 * arr
+assert_eq(doubled[0], d[0])
+assert_eq(doubled[1], d[1])
+assert_eq(doubled[2], d[2])
+assert_eq(also[2], d[2])"#,
+    );
+}
+
+#[test]
+fn ir_array_negated_scaled_by_witness() {
+    // Scaling by a witness `k` costs one multiplication per element.
+    let x = |v: u64| FieldElement::from_u64(v);
+    ir_array_verify(
+        &[("out", x(18).neg())],
+        &[
+            ("arr_0", x(1)),
+            ("arr_1", x(2)),
+            ("arr_2", x(3)),
+            ("k", x(3)),
+        ],
+        &["out"],
+        &["arr[3]", "k"],
+        r#"let scaled = -(arr * k)
+let total = scaled[0] + scaled[1] + scaled[2]
+assert_eq(total, out)"#,
+    );
+}
+
+#[test]
+fn ir_array_times_array_rejected() {
+    let err =
+        IrLowering::<Bn254Fr>::lower_circuit("let p = a * b", &[], &["a[2]", "b[2]"]).unwrap_err();
+    let msg = err.to_string();
+    assert!(
+        msg.contains("cannot multiply two arrays"),
+        "expected array * array error, got: {msg}"
+    );
+}
+
+#[test]
+fn ir_dot_product_of_literals() {
+    ir_array_verify(
+        &[("out", FieldElement::from_u64(32))],
+        &[],
+        &["out"],
+        &[],
+        "assert_eq(dot([1, 2, 3], [4, 5, 6]), out)",
+    );
+}
+
+#[test]
+fn ir_dot_product_of_witness_arrays() {
+    let x = |v: u64| FieldElement::from_u64(v);
+    ir_array_verify(
+        // 1*4 + 2*5 + 3*6
+        &[("out", x(32))],
+        &[
+            ("a_0", x(1)),
+            ("a_1", x(2)),
+            ("a_2", x(3)),
+            ("b_0", x(4)),
+            ("b_1", x(5)),
+            ("b_2", x(6)),
+        ],
+        &["out"],
+        &["a[3]", "b[3]"],
+        "assert_eq(dot(a, b), out)",
+    );
+}
+
+#[test]
+fn ir_dot_product_length_mismatch() {
+    let err =
+        IrLowering::<Bn254Fr>::lower_circuit("dot(a, [x, x])", &[], &["a[3]", "x"]).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            ir::IrError::ArrayLengthMismatch {
+                expected: 3,
+                got: 2,
+                ..
+            }
+        ),
+        "expected ArrayLengthMismatch, got: {err:?}"
+    );
+}
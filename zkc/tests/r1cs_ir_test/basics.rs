@@ -92,3 +92,20 @@ fn ir_constant_mul() {
 fn ir_constant_add() {
     ir_pipeline_verify(&[("out", 15)], &[("x", 10)], "assert_eq(x + 5, out)");
 }
+
+#[test]
+fn ir_constant_beyond_u64() {
+    // 2^64 + 1 does not fit a u64; it must parse as a full field element.
+    let big = FieldElement::from_decimal_str("18446744073709551617").unwrap();
+    let x = FieldElement::from_u64(2);
+    ir_pipeline_verify_fe(
+        &[("out", x.add(&big))],
+        &[("x", x)],
+        "assert_eq(x + 18446744073709551617, out)",
+    );
+    ir_pipeline_verify_fe(
+        &[("out", x.sub(&big))],
+        &[("x", x)],
+        "assert_eq(x + -18446744073709551617, out)",
+    );
+}
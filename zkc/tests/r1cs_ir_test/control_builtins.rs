@@ -125,3 +125,73 @@ fn ir_optimized_poseidon() {
     let w = gen.generate(&inputs).unwrap();
     compiler.cs.verify(&w).unwrap();
 }
+
+#[test]
+fn ir_bits_decomposes_lsb_first() {
+    // bits(5, 4) = [1, 0, 1, 0] and the bits reconstruct x
+    ir_pipeline_verify(
+        &[],
+        &[("x", 5)],
+        "let b = bits(x, 4)\n\
+         assert_eq(b[0], 1)\n\
+         assert_eq(b[1], 0)\n\
+         assert_eq(b[2], 1)\n\
+         assert_eq(b[3], 0)\n\
+         assert_eq(b[0] + 2 * b[1] + 4 * b[2] + 8 * b[3], x)",
+    );
+}
+
+#[test]
+fn ir_bits_len_matches_width() {
+    ir_pipeline_verify(
+        &[("n", 8)],
+        &[("x", 200)],
+        "let b = bits(x, 8)\nassert_eq(len(b), n)",
+    );
+}
+
+#[test]
+fn ir_bits_zero_width_rejected() {
+    let result = IrLowering::<Bn254Fr>::lower_circuit("let b = bits(x, 0)", &[], &["x"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn ir_popcount_sums_bits() {
+    // popcount(7, 4) = 0b0111 → 3
+    ir_pipeline_verify(&[("out", 3)], &[("x", 7)], "assert_eq(popcount(x, 4), out)");
+}
+
+#[test]
+fn ir_is_power_of_two_accepts_single_bit() {
+    ir_pipeline_verify(&[], &[("x", 8)], "is_power_of_two(x, 8)");
+}
+
+#[test]
+fn ir_bool_builtins_truth_tables() {
+    let source = "assert_eq(bool_and(a, b), and_out)\n\
+                  assert_eq(bool_or(a, b), or_out)\n\
+                  assert_eq(bool_xor(a, b), xor_out)\n\
+                  assert_eq(bool_not(a), not_out)";
+    for (a, b) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+        ir_pipeline_verify(
+            &[
+                ("and_out", a & b),
+                ("or_out", a | b),
+                ("xor_out", a ^ b),
+                ("not_out", 1 - a),
+            ],
+            &[("a", a), ("b", b)],
+            source,
+        );
+    }
+}
+
+#[test]
+fn ir_bool_xor_one_one_is_zero() {
+    ir_pipeline_verify(
+        &[("out", 0)],
+        &[("a", 1), ("b", 1)],
+        "assert_eq(bool_xor(a, b), out)",
+    );
+}
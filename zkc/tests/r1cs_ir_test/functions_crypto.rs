@@ -146,6 +146,40 @@ fn ir_fn_forward_reference_rejected() {
     );
 }
 
+#[test]
+fn ir_fn_array_return_indexed() {
+    // fn split(x, y) { [x + y, x * y] } — caller binds the pair and indexes it
+    ir_only_verify_fe(
+        &[
+            ("sum", FieldElement::from_u64(13)),
+            ("prod", FieldElement::from_u64(30)),
+        ],
+        &[
+            ("a", FieldElement::from_u64(3)),
+            ("b", FieldElement::from_u64(10)),
+        ],
+        "fn split(x, y) { [x + y, x * y] }\n\
+         let p = split(a, b)\n\
+         assert_eq(p[0], sum)\n\
+         assert_eq(p[1], prod)",
+    );
+}
+
+#[test]
+fn ir_fn_array_return_used_as_scalar_rejected() {
+    let result = IrLowering::<Bn254Fr>::lower_circuit(
+        "fn split(x) { [x, x] }\nassert_eq(split(a), a)",
+        &[],
+        &["a"],
+    );
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(
+        format!("{err}").contains("array"),
+        "expected TypeMismatch, got: {err}"
+    );
+}
+
 // ============================================================================
 // Crypto builtins
 // ============================================================================
@@ -129,3 +129,177 @@ fn ir_or_non_boolean_input_fails() {
         "a=3 should fail boolean enforcement in Or operator"
     );
 }
+
+#[test]
+fn ir_bits_wrong_bit_assignment_rejected() {
+    let source = "let b = bits(x, 4)\nassert_eq(b[0] + b[3], b[0] + b[3])";
+    let program = IrLowering::<Bn254Fr>::lower_circuit(source, &[], &["x"]).unwrap();
+
+    let mut compiler = R1CSCompiler::<Bn254Fr>::new();
+    compiler.compile_ir(&program).unwrap();
+
+    let gen = WitnessGenerator::from_compiler(&compiler);
+    let mut inputs = HashMap::new();
+    inputs.insert("x".into(), FieldElement::from_u64(5));
+    let w = gen.generate(&inputs).unwrap();
+    compiler
+        .cs
+        .verify(&w)
+        .expect("valid decomposition must verify");
+
+    // Wire 0 = ONE, wire 1 = x; every later boolean wire is a bit of x.
+    // Flipping any one of them breaks Σ b_i * 2^i == x.
+    let mut flipped = 0;
+    for i in 2..w.len() {
+        if w[i] != FieldElement::ZERO && w[i] != FieldElement::ONE {
+            continue;
+        }
+        let mut forged = w.clone();
+        forged[i] = FieldElement::ONE.sub(&w[i]);
+        assert!(
+            compiler.cs.verify(&forged).is_err(),
+            "flipping bit wire {i} must fail verification"
+        );
+        flipped += 1;
+    }
+    assert_eq!(flipped, 4, "expected exactly four bit wires");
+}
+
+#[test]
+fn ir_bits_value_out_of_range_rejected() {
+    // 20 does not fit in 4 bits: no assignment of b0..b3 sums to it
+    let source = "let b = bits(x, 4)";
+    let program = IrLowering::<Bn254Fr>::lower_circuit(source, &[], &["x"]).unwrap();
+
+    let mut compiler = R1CSCompiler::<Bn254Fr>::new();
+    compiler.compile_ir(&program).unwrap();
+
+    let gen = WitnessGenerator::from_compiler(&compiler);
+    let mut inputs = HashMap::new();
+    inputs.insert("x".into(), FieldElement::from_u64(20));
+    let w = gen.generate(&inputs).unwrap();
+    assert!(
+        compiler.cs.verify(&w).is_err(),
+        "bits(20, 4) must fail verification"
+    );
+}
+
+#[test]
+fn ir_is_power_of_two_rejects_two_set_bits() {
+    // 6 = 0b110 has popcount 2
+    let program =
+        IrLowering::<Bn254Fr>::lower_circuit("is_power_of_two(x, 8)", &[], &["x"]).unwrap();
+
+    let mut compiler = R1CSCompiler::<Bn254Fr>::new();
+    compiler.compile_ir(&program).unwrap();
+
+    let gen = WitnessGenerator::from_compiler(&compiler);
+    let mut inputs = HashMap::new();
+    inputs.insert("x".into(), FieldElement::from_u64(6));
+    let w = gen.generate(&inputs).unwrap();
+    assert!(
+        compiler.cs.verify(&w).is_err(),
+        "is_power_of_two(6, 8) must fail verification"
+    );
+}
+
+#[test]
+fn ir_is_power_of_two_rejects_zero() {
+    let program =
+        IrLowering::<Bn254Fr>::lower_circuit("is_power_of_two(x, 8)", &[], &["x"]).unwrap();
+
+    let mut compiler = R1CSCompiler::<Bn254Fr>::new();
+    compiler.compile_ir(&program).unwrap();
+
+    let gen = WitnessGenerator::from_compiler(&compiler);
+    let mut inputs = HashMap::new();
+    inputs.insert("x".into(), FieldElement::ZERO);
+    let w = gen.generate(&inputs).unwrap();
+    assert!(
+        compiler.cs.verify(&w).is_err(),
+        "is_power_of_two(0, 8) must fail verification"
+    );
+}
+
+#[test]
+fn ir_debug_verify_localizes_failing_gate() {
+    use zkc::r1cs_backend::DebugFailure;
+
+    let source = "let p = x * y\nassert_eq(p, z)";
+    let program = IrLowering::<Bn254Fr>::lower_circuit(source, &["z"], &["x", "y"]).unwrap();
+
+    let mut compiler = R1CSCompiler::<Bn254Fr>::new();
+    compiler.compile_ir(&program).unwrap();
+
+    let gen = WitnessGenerator::from_compiler(&compiler);
+    let mut inputs = HashMap::new();
+    inputs.insert("z".into(), FieldElement::from_u64(42));
+    inputs.insert("x".into(), FieldElement::from_u64(6));
+    inputs.insert("y".into(), FieldElement::from_u64(7));
+    let mut w = gen.generate(&inputs).unwrap();
+    compiler.debug_verify(&program, &w).unwrap();
+
+    // Wire 1 is the public `z`: claim 6 * 7 = 41.
+    w[1] = FieldElement::from_u64(41);
+    let err = compiler.debug_verify(&program, &w).unwrap_err();
+    let DebugFailure::Unsatisfied {
+        a,
+        b,
+        c,
+        instruction,
+        span,
+        ..
+    } = &err
+    else {
+        panic!("expected an unsatisfied gate, got {err}");
+    };
+    assert_ne!(a.mul(b), *c);
+    assert!(
+        instruction.as_deref().unwrap().contains("AssertEq"),
+        "{instruction:?}"
+    );
+    assert_eq!(span.as_ref().unwrap().line_start, 2);
+
+    let rendered = err.to_string();
+    assert!(rendered.starts_with("[2:1"), "{rendered}");
+    assert!(rendered.contains("is not satisfied: A·B = "), "{rendered}");
+}
+
+#[test]
+fn ir_debug_verify_reports_malformed_witness() {
+    use zkc::r1cs_backend::DebugFailure;
+
+    let program = IrLowering::<Bn254Fr>::lower_circuit("assert_eq(x, z)", &["z"], &["x"]).unwrap();
+    let mut compiler = R1CSCompiler::<Bn254Fr>::new();
+    compiler.compile_ir(&program).unwrap();
+
+    let err = compiler
+        .debug_verify(&program, &[FieldElement::ONE])
+        .unwrap_err();
+    assert!(matches!(err, DebugFailure::Witness(_)), "{err}");
+}
+
+#[test]
+fn ir_bool_xor_non_boolean_input_fails() {
+    // 2 xor 0 would be 2 under a + b - 2ab; booleanity must reject it
+    let program = IrLowering::<Bn254Fr>::lower_circuit(
+        "assert_eq(bool_xor(a, b), out)",
+        &["out"],
+        &["a", "b"],
+    )
+    .unwrap();
+
+    let mut compiler = R1CSCompiler::<Bn254Fr>::new();
+    compiler.compile_ir(&program).unwrap();
+
+    let gen = WitnessGenerator::from_compiler(&compiler);
+    let mut inputs = HashMap::new();
+    inputs.insert("out".into(), FieldElement::from_u64(2));
+    inputs.insert("a".into(), FieldElement::from_u64(2));
+    inputs.insert("b".into(), FieldElement::ZERO);
+    let w = gen.generate(&inputs).unwrap();
+    assert!(
+        compiler.cs.verify(&w).is_err(),
+        "bool_xor(2, 0) must fail boolean enforcement"
+    );
+}
@@ -3,8 +3,9 @@ use std::collections::HashMap;
 use constraints::poseidon::{poseidon_hash, PoseidonParams};
 use ir::IrLowering;
 use memory::{Bn254Fr, FieldElement};
+use zkc::error::R1CSError;
 use zkc::r1cs_backend::R1CSCompiler;
-use zkc::witness::{WitnessError, WitnessGenerator};
+use zkc::witness::{PoseidonWitnessCtx, WitnessError, WitnessGenerator, WitnessOp};
 
 /// Helper: build compiler via IR pipeline, generate witness, verify.
 fn compile_and_verify(public: &[(&str, u64)], witness: &[(&str, u64)], source: &str) {
@@ -145,6 +146,37 @@ fn test_division_witness() {
     );
 }
 
+#[test]
+fn test_inv_builtin_witness() {
+    compile_and_verify(&[], &[("x", 7)], "assert_eq(inv(x) * x, 1)");
+}
+
+#[test]
+fn test_inv_builtin_costs_one_constraint() {
+    let program = IrLowering::<Bn254Fr>::lower_circuit("let y = inv(x)", &[], &["x"]).unwrap();
+    let mut rc = R1CSCompiler::<Bn254Fr>::new();
+    rc.compile_ir(&program).unwrap();
+    assert_eq!(rc.cs.num_constraints(), 1);
+}
+
+#[test]
+fn test_inv_of_zero_fails_witness_generation() {
+    let program =
+        IrLowering::<Bn254Fr>::lower_circuit("assert_eq(inv(x) * x, 1)", &[], &["x"]).unwrap();
+    let inputs = HashMap::from([("x".to_string(), FieldElement::from_u64(0))]);
+    let mut rc = R1CSCompiler::<Bn254Fr>::new();
+    let err = rc
+        .compile_ir_with_witness(&program, &inputs)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("division by zero"), "got: {err}");
+}
+
+#[test]
+fn test_neg_builtin_witness() {
+    compile_and_verify(&[("out", 0)], &[("x", 7)], "assert_eq(neg(x) + x, out)");
+}
+
 // ====================================================================
 // Test 6: MUX — flag=1 → selects a
 // ====================================================================
@@ -211,6 +243,62 @@ fn test_chained_poseidon_witness() {
     );
 }
 
+#[test]
+fn shared_poseidon_ctx_matches_per_hash_fill() {
+    // The replay fills every hash through one `PoseidonWitnessCtx`; stale
+    // scratch state carried between hashes would diverge from a fresh
+    // context per hash.
+    let params = PoseidonParams::bn254_t3();
+    let a = FieldElement::from_u64(10);
+    let b = FieldElement::from_u64(20);
+    let mut expected = a;
+    for _ in 0..8 {
+        expected = poseidon_hash(&params, expected, b);
+    }
+
+    let mut source = String::from("let h0 = a\n");
+    for i in 0..8 {
+        source.push_str(&format!("let h{} = poseidon(h{i}, b)\n", i + 1));
+    }
+    source.push_str("assert_eq(h8, out)");
+    let program = IrLowering::<Bn254Fr>::lower_circuit(&source, &["out"], &["a", "b"]).unwrap();
+    let mut inputs = HashMap::new();
+    inputs.insert("a".to_string(), a);
+    inputs.insert("b".to_string(), b);
+    inputs.insert("out".to_string(), expected);
+
+    let mut rc = R1CSCompiler::<Bn254Fr>::new();
+    let shared = rc.compile_ir_with_witness(&program, &inputs).unwrap();
+    rc.cs.verify(&shared).unwrap();
+
+    let mut per_hash = shared.clone();
+    let mut hashes = 0;
+    for op in &rc.witness_ops {
+        if let WitnessOp::PoseidonHash {
+            left,
+            right,
+            internal_start,
+            internal_count,
+            ..
+        } = op
+        {
+            per_hash[*internal_start..*internal_start + *internal_count].fill(FieldElement::zero());
+            PoseidonWitnessCtx::new(&params)
+                .fill(
+                    &mut per_hash,
+                    *left,
+                    *right,
+                    *internal_start,
+                    *internal_count,
+                )
+                .unwrap();
+            hashes += 1;
+        }
+    }
+    assert_eq!(hashes, 8);
+    assert_eq!(shared, per_hash);
+}
+
 // ====================================================================
 // Test 10: Merkle path depth-1 — mux + poseidon + assert_eq
 // ====================================================================
@@ -296,6 +384,7 @@ fn test_missing_input_error() {
     inputs.insert("a".into(), FieldElement::from_u64(6));
 
     let err = gen.generate(&inputs).unwrap_err();
+    assert_eq!(err.to_string(), "missing input for variable `out`");
     match err {
         WitnessError::MissingInput(name) => {
             assert_eq!(name, "out"); // public inputs checked first
@@ -378,6 +467,79 @@ fn skip_eval_validation_missing_input_errors_without_panic() {
     assert!(result.is_err(), "missing input must error, not panic");
 }
 
+#[test]
+fn partial_inputs_report_every_missing_name() {
+    let program =
+        IrLowering::<Bn254Fr>::lower_circuit("assert_eq(a * b, out)", &["out"], &["a", "b"])
+            .unwrap();
+    let mut inputs = HashMap::new();
+    inputs.insert("out".to_string(), FieldElement::from_u64(42));
+
+    let expected = vec!["a".to_string(), "b".to_string()];
+    for skip in [false, true] {
+        let mut rc = R1CSCompiler::<Bn254Fr>::new();
+        rc.set_skip_eval_validation(skip);
+        let err = rc.compile_ir_with_witness(&program, &inputs).unwrap_err();
+        assert!(
+            matches!(&err, R1CSError::MissingInput(names) if *names == expected),
+            "skip={skip}: {err:?}"
+        );
+        assert_eq!(err.to_string(), "missing inputs: `a`, `b`");
+    }
+
+    // `fill_witness` after a plain `compile_ir` reports the same names.
+    let mut rc = R1CSCompiler::<Bn254Fr>::new();
+    rc.compile_ir(&program).unwrap();
+    let err = rc.fill_witness(&inputs).unwrap_err();
+    assert!(matches!(&err, R1CSError::MissingInput(names) if *names == expected));
+}
+
+#[test]
+fn under_supplied_array_input_reports_missing_element() {
+    let (_, _, program) = IrLowering::<Bn254Fr>::lower_self_contained(
+        "public root\nwitness path[3]\nassert_eq(path[0] + path[1] + path[2], root)",
+    )
+    .unwrap();
+    let inputs: HashMap<String, FieldElement> = [("root", 3), ("path_0", 1), ("path_1", 2)]
+        .into_iter()
+        .map(|(n, v)| (n.to_string(), FieldElement::from_u64(v)))
+        .collect();
+
+    let mut rc = R1CSCompiler::<Bn254Fr>::new();
+    let err = rc.compile_ir_with_witness(&program, &inputs).unwrap_err();
+    assert!(
+        matches!(&err, R1CSError::MissingArrayElement { array, index: 2 } if array == "path"),
+        "{err:?}"
+    );
+    assert_eq!(
+        err.to_string(),
+        "missing element `path_2` of array input `path`"
+    );
+}
+
+#[test]
+fn surplus_array_element_is_reported_as_warning() {
+    let (_, _, program) = IrLowering::<Bn254Fr>::lower_self_contained(
+        "public root\nwitness path[2]\nassert_eq(path[0] + path[1], root)",
+    )
+    .unwrap();
+    let inputs: HashMap<String, FieldElement> =
+        [("root", 3), ("path_0", 1), ("path_1", 2), ("path_2", 9)]
+            .into_iter()
+            .map(|(n, v)| (n.to_string(), FieldElement::from_u64(v)))
+            .collect();
+
+    let mut rc = R1CSCompiler::<Bn254Fr>::new();
+    let witness = rc.compile_ir_with_witness(&program, &inputs).unwrap();
+    assert!(rc.cs.verify(&witness).is_ok());
+    assert_eq!(rc.input_warnings.len(), 1);
+    assert!(
+        rc.input_warnings[0].contains("`path_2`"),
+        "{:?}",
+        rc.input_warnings
+    );
+}
+
 #[test]
 fn skip_eval_validation_violating_input_caught_by_verify() {
     // With early validation skipped, a constraint-violating witness is no
@@ -464,3 +626,54 @@ fn split_flow_survives_optimize_and_substitution_fixup() {
     let cs = rc.into_constraint_system();
     cs.verify(&witness).unwrap();
 }
+
+#[test]
+fn misordered_witness_ops_are_rejected() {
+    // `x * y * z` records two Multiply ops; the second reads the first's
+    // target. Replaying them in reverse must be refused up front instead
+    // of reading a zero for the intermediate product.
+    let program = IrLowering::<Bn254Fr>::lower_circuit(
+        "assert_eq(x * y * z, out)",
+        &["out"],
+        &["x", "y", "z"],
+    )
+    .unwrap();
+    let inputs: HashMap<String, FieldElement> = [("x", 2), ("y", 3), ("z", 7), ("out", 42)]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), FieldElement::from_u64(v)))
+        .collect();
+
+    let mut rc = R1CSCompiler::<Bn254Fr>::new();
+    rc.compile_ir(&program).unwrap();
+    let ops: Vec<WitnessOp> = rc.witness_ops.iter().cloned().collect();
+    assert!(ops.len() >= 2, "expected chained ops, got {}", ops.len());
+
+    rc.witness_ops.clear();
+    for op in ops.iter().rev().cloned() {
+        rc.witness_ops.push(op);
+    }
+    let err = rc.fill_witness(&inputs).unwrap_err();
+    assert!(
+        matches!(err, R1CSError::WitnessOpOrder { op_index: 0, .. }),
+        "{err:?}"
+    );
+    let err = WitnessGenerator::from_compiler(&rc)
+        .generate(&inputs)
+        .unwrap_err();
+    assert!(
+        matches!(err, WitnessError::OpOrder { op_index: 0, .. }),
+        "{err:?}"
+    );
+
+    // The trace as recorded still replays.
+    rc.witness_ops.clear();
+    for op in ops {
+        rc.witness_ops.push(op);
+    }
+    let witness = WitnessGenerator::from_compiler(&rc)
+        .generate(&inputs)
+        .unwrap();
+    rc.cs.verify(&witness).unwrap();
+    let witness = rc.fill_witness(&inputs).unwrap();
+    rc.cs.verify(&witness).unwrap();
+}
@@ -0,0 +1,121 @@
+//! Peak-heap budget for streaming compilation of a large generated
+//! circuit.
+//!
+//! A counting global allocator tracks live and peak heap bytes. The same
+//! synthetic 500k-instruction multiplication chain is compiled twice:
+//! once materialized (`IrProgram` + `compile_ir` + `fill_witness`) and
+//! once streamed (chunked `compile_instructions_streaming` +
+//! `fill_witness_draining`). The streamed run must stay under a fixed
+//! budget and below the materialized peak.
+//!
+//! Both runs live in a single `#[test]` so no other test in this binary
+//! allocates concurrently and skews the counters.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ir::types::{Instruction, IrProgram, SsaVar, Visibility};
+use memory::{Bn254Fr, FieldElement};
+use zkc::r1cs_backend::R1CSCompiler;
+
+type F = Bn254Fr;
+
+const INSTRUCTIONS: u64 = 500_000;
+const CHUNK: u64 = 4_096;
+/// Peak heap allowed for the streamed compile + witness fill.
+const STREAMING_BUDGET: usize = 256 << 20;
+
+struct CountingAlloc;
+
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let live = LIVE.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+        PEAK.fetch_max(live, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Run `f` and return its result plus the peak heap growth above the
+/// live bytes at entry.
+fn peak_during<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let base = LIVE.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    let out = f();
+    (out, PEAK.load(Ordering::Relaxed) - base)
+}
+
+/// Instruction `i` of the chain: `v0 = input x`, `v{i} = v{i-1} * x`.
+fn chain_instruction(i: u64) -> Instruction<F> {
+    if i == 0 {
+        Instruction::Input {
+            result: SsaVar(0),
+            name: "x".into(),
+            visibility: Visibility::Witness,
+        }
+    } else {
+        Instruction::Mul {
+            result: SsaVar(i),
+            lhs: SsaVar(i - 1),
+            rhs: SsaVar(0),
+        }
+    }
+}
+
+fn inputs() -> HashMap<String, FieldElement<F>> {
+    HashMap::from([("x".to_string(), FieldElement::from_u64(3))])
+}
+
+#[test]
+fn streaming_500k_instruction_chain_stays_within_budget() {
+    let inputs = inputs();
+
+    let (materialized, materialized_peak) = peak_during(|| {
+        let mut program = IrProgram::<F>::new();
+        for i in 0..INSTRUCTIONS {
+            program.push(chain_instruction(i));
+        }
+        let mut rc = R1CSCompiler::<F>::new_prover();
+        rc.compile_ir(&program).unwrap();
+        let witness = rc.fill_witness(&inputs).unwrap();
+        (rc.cs.num_constraints(), witness.last().copied())
+    });
+
+    let ((streamed, streamed_last, rc), streaming_peak) = peak_during(|| {
+        let mut rc = R1CSCompiler::<F>::new_prover();
+        for start in (0..INSTRUCTIONS).step_by(CHUNK as usize) {
+            let end = (start + CHUNK).min(INSTRUCTIONS);
+            let chunk: Vec<_> = (start..end).map(chain_instruction).collect();
+            rc.compile_instructions_streaming(chunk).unwrap();
+        }
+        let witness = rc.fill_witness_draining(&inputs).unwrap();
+        assert!(rc.cs.verify(&witness).is_ok());
+        (rc.cs.num_constraints(), witness.last().copied(), rc)
+    });
+
+    assert_eq!(streamed, INSTRUCTIONS as usize - 1);
+    assert_eq!((streamed, streamed_last), materialized);
+    assert!(
+        rc.witness_ops.is_empty(),
+        "draining fill must consume the op log"
+    );
+    assert!(
+        streaming_peak <= STREAMING_BUDGET,
+        "streaming peak {streaming_peak} B exceeds budget {STREAMING_BUDGET} B"
+    );
+    assert!(
+        streaming_peak < materialized_peak,
+        "streaming peak {streaming_peak} B not below materialized peak {materialized_peak} B"
+    );
+}